@@ -18,6 +18,13 @@ pub static DTYPE_CATEGORICAL_NEW: &str = "_PL_CATEGORICAL2";
 
 pub static PARQUET_EMPTY_STRUCT: &str = "_PL_EMPTY_STRUCT";
 
+/// Extension name of the canonical Arrow `VARIANT` type, a struct of a `metadata` and a `value`
+/// binary field holding the Parquet variant encoding.
+pub static PARQUET_VARIANT_EXTENSION_NAME: &str = "arrow.parquet.variant";
+
+/// Extension name of the canonical Arrow `UUID` type, 16-byte big-endian values.
+pub static ARROW_UUID_EXTENSION_NAME: &str = "arrow.uuid";
+
 pub static MAINTAIN_PL_TYPE: &str = "maintain_type";
 pub static PL_KEY: &str = "pl";
 
@@ -9,8 +9,9 @@ use std::collections::BTreeMap;
 use std::sync::Arc;
 
 pub use field::{
-    DTYPE_CATEGORICAL_LEGACY, DTYPE_CATEGORICAL_NEW, DTYPE_ENUM_VALUES_LEGACY,
-    DTYPE_ENUM_VALUES_NEW, Field, MAINTAIN_PL_TYPE, PARQUET_EMPTY_STRUCT, PL_KEY,
+    ARROW_UUID_EXTENSION_NAME, DTYPE_CATEGORICAL_LEGACY, DTYPE_CATEGORICAL_NEW,
+    DTYPE_ENUM_VALUES_LEGACY, DTYPE_ENUM_VALUES_NEW, Field, MAINTAIN_PL_TYPE, PARQUET_EMPTY_STRUCT,
+    PARQUET_VARIANT_EXTENSION_NAME, PL_KEY,
 };
 pub use physical_type::*;
 use polars_utils::pl_str::PlSmallStr;
@@ -566,7 +567,7 @@ impl From<PrimitiveType> for ArrowDataType {
             PrimitiveType::UInt32 => ArrowDataType::UInt32,
             PrimitiveType::UInt64 => ArrowDataType::UInt64,
             PrimitiveType::UInt128 => ArrowDataType::UInt128,
-            PrimitiveType::Int256 => ArrowDataType::Decimal256(32, 32),
+            PrimitiveType::Int256 => ArrowDataType::Decimal256(76, 0),
             PrimitiveType::Float16 => ArrowDataType::Float16,
             PrimitiveType::Float32 => ArrowDataType::Float32,
             PrimitiveType::Float64 => ArrowDataType::Float64,
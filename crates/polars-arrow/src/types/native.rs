@@ -7,7 +7,7 @@ use half;
 use polars_utils::float16::pf16;
 use polars_utils::min_max::MinMax;
 use polars_utils::nulls::IsNull;
-use polars_utils::total_ord::{TotalEq, TotalOrd};
+use polars_utils::total_ord::{ToTotalOrd, TotalEq, TotalOrd};
 
 use super::PrimitiveType;
 use super::aligned_bytes::*;
@@ -472,8 +472,7 @@ impl Neg for i256 {
 
     #[inline]
     fn neg(self) -> Self::Output {
-        let (a, b) = self.0.into_words();
-        Self(ethnum::I256::from_words(-a, b))
+        Self(-self.0)
     }
 }
 
@@ -506,6 +505,21 @@ impl TotalOrd for i256 {
     }
 }
 
+impl ToTotalOrd for i256 {
+    type TotalOrdItem = i256;
+    type SourceItem = i256;
+
+    #[inline]
+    fn to_total_ord(&self) -> Self::TotalOrdItem {
+        *self
+    }
+
+    #[inline]
+    fn peel_total_ord(ord_item: Self::TotalOrdItem) -> Self::SourceItem {
+        ord_item
+    }
+}
+
 impl MinMax for i256 {
     fn nan_min_lt(&self, other: &Self) -> bool {
         self < other
@@ -525,7 +539,7 @@ impl NativeType for i256 {
     #[inline]
     fn to_le_bytes(&self) -> Self::Bytes {
         let mut bytes = [0u8; 32];
-        let (a, b) = self.0.into_words();
+        let (b, a) = self.0.into_words();
         let a = a.to_le_bytes();
         (0..16).for_each(|i| {
             bytes[i] = a[i];
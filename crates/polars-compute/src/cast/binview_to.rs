@@ -5,6 +5,8 @@ use arrow::bitmap::MutableBitmap;
 use arrow::datatypes::{ArrowDataType, Field, TimeUnit};
 use arrow::offset::Offset;
 use arrow::types::NativeType;
+#[cfg(feature = "dtype-decimal256")]
+use arrow::types::i256;
 use bytemuck::cast_slice_mut;
 use chrono::Datelike;
 use num_traits::FromBytes;
@@ -15,6 +17,8 @@ use super::binary_to::Parse;
 use super::temporal::EPOCH_DAYS_FROM_CE;
 #[cfg(feature = "dtype-decimal")]
 use crate::decimal::str_to_dec128;
+#[cfg(feature = "dtype-decimal256")]
+use crate::decimal::str_to_dec256;
 
 pub(super) const RFC3339: &str = "%Y-%m-%dT%H:%M:%S%.f%:z";
 
@@ -115,6 +119,20 @@ pub fn binview_to_decimal(
     .to(ArrowDataType::Decimal(precision, scale))
 }
 
+#[cfg(feature = "dtype-decimal256")]
+pub fn binview_to_decimal256(
+    array: &BinaryViewArray,
+    precision: usize,
+    scale: usize,
+) -> PrimitiveArray<i256> {
+    PrimitiveArray::<i256>::from_trusted_len_iter(
+        array
+            .iter()
+            .map(|val| val.and_then(|val| str_to_dec256(val, precision, scale, false))),
+    )
+    .to(ArrowDataType::Decimal256(precision, scale))
+}
+
 pub(super) fn utf8view_to_naive_timestamp_dyn(
     from: &dyn Array,
     time_unit: TimeUnit,
@@ -127,3 +127,191 @@ pub(super) fn decimal_to_utf8view_dyn(from: &dyn Array) -> Utf8ViewArray {
     let from = from.as_any().downcast_ref().unwrap();
     decimal_to_utf8view(from)
 }
+
+#[cfg(feature = "dtype-decimal256")]
+mod dec256 {
+    use arrow::types::i256;
+
+    use super::*;
+    use crate::decimal::{
+        DecimalFmtBuffer, dec128_to_dec256, dec256_fits, dec256_rescale, dec256_to_dec128,
+        dec256_to_f64, dec256_to_i128,
+    };
+
+    fn decimal256_prec_scale(from: &PrimitiveArray<i256>) -> (usize, usize) {
+        if let ArrowDataType::Decimal256(p, s) = from.dtype().to_storage() {
+            (*p, *s)
+        } else {
+            panic!("internal error: i256 is always a decimal")
+        }
+    }
+
+    /// Returns a [`PrimitiveArray<i256>`] with the cast values. Values become null on overflow.
+    pub fn decimal256_to_decimal256(
+        from: &PrimitiveArray<i256>,
+        to_precision: usize,
+        to_scale: usize,
+    ) -> PrimitiveArray<i256> {
+        let (from_precision, from_scale) = decimal256_prec_scale(from);
+
+        if to_scale == from_scale && to_precision >= from_precision {
+            // Increasing precision is always allowed.
+            return from
+                .clone()
+                .to(ArrowDataType::Decimal256(to_precision, to_scale));
+        }
+
+        let it = from.iter().map(|opt_x| {
+            let x = *(opt_x?);
+            if to_scale == from_scale {
+                Some(x).filter(|x| dec256_fits(*x, to_precision))
+            } else {
+                dec256_rescale(x, from_scale, to_precision, to_scale)
+            }
+        });
+        PrimitiveArray::<i256>::from_trusted_len_iter(it)
+            .to(ArrowDataType::Decimal256(to_precision, to_scale))
+    }
+
+    pub(in crate::cast) fn decimal256_to_decimal256_dyn(
+        from: &dyn Array,
+        to_precision: usize,
+        to_scale: usize,
+    ) -> PolarsResult<Box<dyn Array>> {
+        let from = from.as_any().downcast_ref().unwrap();
+        Ok(Box::new(decimal256_to_decimal256(
+            from,
+            to_precision,
+            to_scale,
+        )))
+    }
+
+    /// Returns a [`PrimitiveArray<i256>`] with the cast values. Values become null on overflow.
+    pub fn decimal_to_decimal256(
+        from: &PrimitiveArray<i128>,
+        to_precision: usize,
+        to_scale: usize,
+    ) -> PrimitiveArray<i256> {
+        let from_scale = if let ArrowDataType::Decimal(_, s) = from.dtype().to_storage() {
+            *s
+        } else {
+            panic!("internal error: i128 is always a decimal")
+        };
+
+        let it = from
+            .iter()
+            .map(|opt_x| dec128_to_dec256(*(opt_x?), from_scale, to_precision, to_scale));
+        PrimitiveArray::<i256>::from_trusted_len_iter(it)
+            .to(ArrowDataType::Decimal256(to_precision, to_scale))
+    }
+
+    pub(in crate::cast) fn decimal_to_decimal256_dyn(
+        from: &dyn Array,
+        to_precision: usize,
+        to_scale: usize,
+    ) -> PolarsResult<Box<dyn Array>> {
+        let from = from.as_any().downcast_ref().unwrap();
+        Ok(Box::new(decimal_to_decimal256(
+            from,
+            to_precision,
+            to_scale,
+        )))
+    }
+
+    /// Returns a [`PrimitiveArray<i128>`] with the cast values. Values become null on overflow.
+    pub fn decimal256_to_decimal(
+        from: &PrimitiveArray<i256>,
+        to_precision: usize,
+        to_scale: usize,
+    ) -> PrimitiveArray<i128> {
+        let (_, from_scale) = decimal256_prec_scale(from);
+
+        let it = from
+            .iter()
+            .map(|opt_x| dec256_to_dec128(*(opt_x?), from_scale, to_precision, to_scale));
+        PrimitiveArray::<i128>::from_trusted_len_iter(it)
+            .to(ArrowDataType::Decimal(to_precision, to_scale))
+    }
+
+    pub(in crate::cast) fn decimal256_to_decimal_dyn(
+        from: &dyn Array,
+        to_precision: usize,
+        to_scale: usize,
+    ) -> PolarsResult<Box<dyn Array>> {
+        let from = from.as_any().downcast_ref().unwrap();
+        Ok(Box::new(decimal256_to_decimal(
+            from,
+            to_precision,
+            to_scale,
+        )))
+    }
+
+    /// Returns a [`PrimitiveArray<T>`] with the cast values. Values are `None` on overflow
+    pub fn decimal256_to_float<T>(from: &PrimitiveArray<i256>) -> PrimitiveArray<T>
+    where
+        T: NativeType + Float,
+        f64: AsPrimitive<T>,
+    {
+        let (_, from_scale) = decimal256_prec_scale(from);
+
+        let it = from
+            .iter()
+            .map(|opt_x| Some(dec256_to_f64(*(opt_x?), from_scale).as_()));
+        PrimitiveArray::<T>::from_trusted_len_iter(it)
+    }
+
+    pub(in crate::cast) fn decimal256_to_float_dyn<T>(
+        from: &dyn Array,
+    ) -> PolarsResult<Box<dyn Array>>
+    where
+        T: NativeType + Float,
+        f64: AsPrimitive<T>,
+    {
+        let from = from.as_any().downcast_ref().unwrap();
+        Ok(Box::new(decimal256_to_float::<T>(from)))
+    }
+
+    /// Returns a [`PrimitiveArray<T>`] with the cast values. Values are `None` on overflow
+    pub fn decimal256_to_integer<T>(from: &PrimitiveArray<i256>) -> PrimitiveArray<T>
+    where
+        T: NativeType + NumCast,
+    {
+        let (_, from_scale) = decimal256_prec_scale(from);
+
+        let it = from
+            .iter()
+            .map(|opt_x| T::from(dec256_to_i128(*(opt_x?), from_scale)?));
+        PrimitiveArray::<T>::from_trusted_len_iter(it)
+    }
+
+    pub(in crate::cast) fn decimal256_to_integer_dyn<T>(
+        from: &dyn Array,
+    ) -> PolarsResult<Box<dyn Array>>
+    where
+        T: NativeType + NumCast,
+    {
+        let from = from.as_any().downcast_ref().unwrap();
+        Ok(Box::new(decimal256_to_integer::<T>(from)))
+    }
+
+    /// Returns a [`Utf8ViewArray`] where every element is the utf8 representation of the decimal.
+    pub(in crate::cast) fn decimal256_to_utf8view(from: &PrimitiveArray<i256>) -> Utf8ViewArray {
+        let (_, from_scale) = decimal256_prec_scale(from);
+
+        let mut mutable = MutableBinaryViewArray::with_capacity(from.len());
+        let mut fmt_buf = DecimalFmtBuffer::new();
+        for &x in from.values().iter() {
+            mutable.push_value_ignore_validity(fmt_buf.format_dec256(x, from_scale, false, false))
+        }
+
+        mutable.freeze().with_validity(from.validity().cloned())
+    }
+
+    pub(in crate::cast) fn decimal256_to_utf8view_dyn(from: &dyn Array) -> Utf8ViewArray {
+        let from = from.as_any().downcast_ref().unwrap();
+        decimal256_to_utf8view(from)
+    }
+}
+
+#[cfg(feature = "dtype-decimal256")]
+pub use dec256::*;
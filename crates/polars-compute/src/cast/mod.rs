@@ -13,6 +13,8 @@ use arrow::bitmap::MutableBitmap;
 pub use binary_to::*;
 #[cfg(feature = "dtype-decimal")]
 pub use binview_to::binview_to_decimal;
+#[cfg(feature = "dtype-decimal256")]
+pub use binview_to::binview_to_decimal256;
 use binview_to::utf8view_to_primitive_dyn;
 pub use binview_to::utf8view_to_utf8;
 pub use boolean_to::*;
@@ -558,6 +560,10 @@ pub fn cast(
                 Decimal(precision, scale) => {
                     Ok(binview_to_decimal(&arr.to_binview(), *precision, *scale).to_boxed())
                 },
+                #[cfg(feature = "dtype-decimal256")]
+                Decimal256(precision, scale) => {
+                    Ok(binview_to_decimal256(&arr.to_binview(), *precision, *scale).to_boxed())
+                },
                 _ => polars_bail!(InvalidOperation:
                     "casting from {from_type:?} to {to_type:?} not supported",
                 ),
@@ -621,6 +627,8 @@ pub fn cast(
             ),
             #[cfg(feature = "dtype-decimal")]
             Decimal(_, _) => Ok(decimal_to_utf8view_dyn(array).boxed()),
+            #[cfg(feature = "dtype-decimal256")]
+            Decimal256(_, _) => Ok(decimal256_to_utf8view_dyn(array).boxed()),
             _ => from_to_binview(array, from_type, to_type)
                 .map(|arr| unsafe { arr.to_utf8view_unchecked() }.boxed()),
         },
@@ -997,6 +1005,66 @@ pub fn cast(
         (Decimal(_, _), Float64) => decimal_to_float_dyn::<f64>(array),
         #[cfg(feature = "dtype-decimal")]
         (Decimal(_, _), Decimal(to_p, to_s)) => decimal_to_decimal_dyn(array, *to_p, *to_s),
+        #[cfg(feature = "dtype-decimal256")]
+        (UInt8, Decimal256(p, s)) => integer_to_decimal256_dyn::<u8>(array, *p, *s),
+        #[cfg(feature = "dtype-decimal256")]
+        (UInt16, Decimal256(p, s)) => integer_to_decimal256_dyn::<u16>(array, *p, *s),
+        #[cfg(feature = "dtype-decimal256")]
+        (UInt32, Decimal256(p, s)) => integer_to_decimal256_dyn::<u32>(array, *p, *s),
+        #[cfg(feature = "dtype-decimal256")]
+        (UInt64, Decimal256(p, s)) => integer_to_decimal256_dyn::<u64>(array, *p, *s),
+        #[cfg(all(feature = "dtype-decimal256", feature = "dtype-u128"))]
+        (UInt128, Decimal256(p, s)) => integer_to_decimal256_dyn::<u128>(array, *p, *s),
+        #[cfg(feature = "dtype-decimal256")]
+        (Int8, Decimal256(p, s)) => integer_to_decimal256_dyn::<i8>(array, *p, *s),
+        #[cfg(feature = "dtype-decimal256")]
+        (Int16, Decimal256(p, s)) => integer_to_decimal256_dyn::<i16>(array, *p, *s),
+        #[cfg(feature = "dtype-decimal256")]
+        (Int32, Decimal256(p, s)) => integer_to_decimal256_dyn::<i32>(array, *p, *s),
+        #[cfg(feature = "dtype-decimal256")]
+        (Int64, Decimal256(p, s)) => integer_to_decimal256_dyn::<i64>(array, *p, *s),
+        #[cfg(all(feature = "dtype-decimal256", feature = "dtype-i128"))]
+        (Int128, Decimal256(p, s)) => integer_to_decimal256_dyn::<i128>(array, *p, *s),
+        #[cfg(all(feature = "dtype-decimal256", feature = "dtype-f16"))]
+        (Float16, Decimal256(p, s)) => float_to_decimal256_dyn::<pf16>(array, *p, *s),
+        #[cfg(feature = "dtype-decimal256")]
+        (Float32, Decimal256(p, s)) => float_to_decimal256_dyn::<f32>(array, *p, *s),
+        #[cfg(feature = "dtype-decimal256")]
+        (Float64, Decimal256(p, s)) => float_to_decimal256_dyn::<f64>(array, *p, *s),
+        #[cfg(feature = "dtype-decimal256")]
+        (Decimal256(_, _), UInt8) => decimal256_to_integer_dyn::<u8>(array),
+        #[cfg(feature = "dtype-decimal256")]
+        (Decimal256(_, _), UInt16) => decimal256_to_integer_dyn::<u16>(array),
+        #[cfg(feature = "dtype-decimal256")]
+        (Decimal256(_, _), UInt32) => decimal256_to_integer_dyn::<u32>(array),
+        #[cfg(feature = "dtype-decimal256")]
+        (Decimal256(_, _), UInt64) => decimal256_to_integer_dyn::<u64>(array),
+        #[cfg(all(feature = "dtype-decimal256", feature = "dtype-u128"))]
+        (Decimal256(_, _), UInt128) => decimal256_to_integer_dyn::<u128>(array),
+        #[cfg(feature = "dtype-decimal256")]
+        (Decimal256(_, _), Int8) => decimal256_to_integer_dyn::<i8>(array),
+        #[cfg(feature = "dtype-decimal256")]
+        (Decimal256(_, _), Int16) => decimal256_to_integer_dyn::<i16>(array),
+        #[cfg(feature = "dtype-decimal256")]
+        (Decimal256(_, _), Int32) => decimal256_to_integer_dyn::<i32>(array),
+        #[cfg(feature = "dtype-decimal256")]
+        (Decimal256(_, _), Int64) => decimal256_to_integer_dyn::<i64>(array),
+        #[cfg(all(feature = "dtype-decimal256", feature = "dtype-i128"))]
+        (Decimal256(_, _), Int128) => decimal256_to_integer_dyn::<i128>(array),
+        #[cfg(all(feature = "dtype-decimal256", feature = "dtype-f16"))]
+        (Decimal256(_, _), Float16) => decimal256_to_float_dyn::<pf16>(array),
+        #[cfg(feature = "dtype-decimal256")]
+        (Decimal256(_, _), Float32) => decimal256_to_float_dyn::<f32>(array),
+        #[cfg(feature = "dtype-decimal256")]
+        (Decimal256(_, _), Float64) => decimal256_to_float_dyn::<f64>(array),
+        #[cfg(feature = "dtype-decimal256")]
+        (Decimal256(_, _), Decimal256(to_p, to_s)) => {
+            decimal256_to_decimal256_dyn(array, *to_p, *to_s)
+        },
+        #[cfg(feature = "dtype-decimal256")]
+        (Decimal(_, _), Decimal256(to_p, to_s)) => decimal_to_decimal256_dyn(array, *to_p, *to_s),
+        #[cfg(feature = "dtype-decimal256")]
+        (Decimal256(_, _), Decimal(to_p, to_s)) => decimal256_to_decimal_dyn(array, *to_p, *to_s),
         // end numeric casts
 
         // temporal casts
@@ -6,6 +6,8 @@ use arrow::compute::arity::unary;
 use arrow::datatypes::{ArrowDataType, TimeUnit};
 use arrow::offset::{Offset, Offsets};
 use arrow::types::NativeType;
+#[cfg(feature = "dtype-decimal256")]
+use arrow::types::i256;
 use num_traits::AsPrimitive;
 #[cfg(feature = "dtype-decimal")]
 use num_traits::Float;
@@ -18,6 +20,8 @@ use super::CastOptionsImpl;
 use super::temporal::*;
 #[cfg(feature = "dtype-decimal")]
 use crate::decimal::{dec128_verify_prec_scale, f64_to_dec128, i128_to_dec128};
+#[cfg(feature = "dtype-decimal256")]
+use crate::decimal::{dec256_verify_prec_scale, f64_to_dec256, i128_to_dec256};
 
 pub trait SerPrimitive {
     fn write(f: &mut Vec<u8>, val: Self) -> usize
@@ -291,6 +295,59 @@ pub(super) fn float_to_decimal_dyn<T: NativeType + Float + AsPrimitive<f64>>(
     Ok(Box::new(float_to_decimal::<T>(from, precision, scale)))
 }
 
+/// Returns a [`PrimitiveArray<i256>`] with the cast values. Values are `None` on overflow
+#[cfg(feature = "dtype-decimal256")]
+pub fn integer_to_decimal256<T: NativeType + AsPrimitive<i128>>(
+    from: &PrimitiveArray<T>,
+    to_precision: usize,
+    to_scale: usize,
+) -> PrimitiveArray<i256> {
+    assert!(dec256_verify_prec_scale(to_precision, to_scale).is_ok());
+    let values = from
+        .iter()
+        .map(|x| i128_to_dec256(x?.as_(), to_precision, to_scale));
+    PrimitiveArray::<i256>::from_trusted_len_iter(values)
+        .to(ArrowDataType::Decimal256(to_precision, to_scale))
+}
+
+#[cfg(feature = "dtype-decimal256")]
+pub(super) fn integer_to_decimal256_dyn<T>(
+    from: &dyn Array,
+    precision: usize,
+    scale: usize,
+) -> PolarsResult<Box<dyn Array>>
+where
+    T: NativeType + AsPrimitive<i128>,
+{
+    let from = from.as_any().downcast_ref().unwrap();
+    Ok(Box::new(integer_to_decimal256::<T>(from, precision, scale)))
+}
+
+/// Returns a [`PrimitiveArray<i256>`] with the cast values. Values are `None` on overflow
+#[cfg(feature = "dtype-decimal256")]
+pub fn float_to_decimal256<T: NativeType + Float + AsPrimitive<f64>>(
+    from: &PrimitiveArray<T>,
+    to_precision: usize,
+    to_scale: usize,
+) -> PrimitiveArray<i256> {
+    assert!(dec256_verify_prec_scale(to_precision, to_scale).is_ok());
+    let values = from
+        .iter()
+        .map(|x| f64_to_dec256(x?.as_(), to_precision, to_scale));
+    PrimitiveArray::<i256>::from_trusted_len_iter(values)
+        .to(ArrowDataType::Decimal256(to_precision, to_scale))
+}
+
+#[cfg(feature = "dtype-decimal256")]
+pub(super) fn float_to_decimal256_dyn<T: NativeType + Float + AsPrimitive<f64>>(
+    from: &dyn Array,
+    precision: usize,
+    scale: usize,
+) -> PolarsResult<Box<dyn Array>> {
+    let from = from.as_any().downcast_ref().unwrap();
+    Ok(Box::new(float_to_decimal256::<T>(from, precision, scale)))
+}
+
 /// Cast [`PrimitiveArray`] as a [`PrimitiveArray`]
 /// Same as `number as to_number_type` in rust
 pub fn primitive_as_primitive<I, O>(
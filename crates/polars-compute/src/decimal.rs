@@ -32,6 +32,11 @@ use std::cmp::Ordering;
 
 use polars_error::{PolarsResult, polars_ensure};
 
+#[cfg(feature = "dtype-decimal256")]
+mod dec256;
+#[cfg(feature = "dtype-decimal256")]
+pub use dec256::*;
+
 /// The maximum precision of a Decimal128.
 pub const DEC128_MAX_PREC: usize = 38;
 
@@ -659,6 +664,79 @@ pub fn dec128_sign(x: i128, s: usize) -> i128 {
     }
 }
 
+/// The integer backing a decimal, abstracting over the operations needed to
+/// share parsing between the different decimal widths.
+trait DecimalInt: Copy + Eq {
+    const MAX_PREC: usize;
+    const ZERO: Self;
+    const ONE: Self;
+
+    /// Parses an optionally signed integer, skipping leading zeros.
+    fn parse_skipped(bytes: &[u8]) -> Option<Self>;
+
+    /// Parses a non-empty unsigned integer without leading zeros.
+    fn parse_pos(bytes: &[u8]) -> Option<Self>;
+
+    /// Converts an integer to a decimal with the given precision and scale.
+    fn int_to_dec(self, p: usize, s: usize) -> Option<Self>;
+
+    fn mul_pow10(self, e: usize) -> Option<Self>;
+
+    fn fits(self, p: usize) -> bool;
+
+    fn is_odd(self) -> bool;
+
+    fn add(self, other: Self) -> Self;
+
+    fn neg(self) -> Self;
+}
+
+impl DecimalInt for i128 {
+    const MAX_PREC: usize = DEC128_MAX_PREC;
+    const ZERO: Self = 0;
+    const ONE: Self = 1;
+
+    #[inline]
+    fn parse_skipped(bytes: &[u8]) -> Option<Self> {
+        atoi_simd::parse_skipped(bytes).ok()
+    }
+
+    #[inline]
+    fn parse_pos(bytes: &[u8]) -> Option<Self> {
+        atoi_simd::parse_pos(bytes).ok()
+    }
+
+    #[inline]
+    fn int_to_dec(self, p: usize, s: usize) -> Option<Self> {
+        i128_to_dec128(self, p, s)
+    }
+
+    #[inline]
+    fn mul_pow10(self, e: usize) -> Option<Self> {
+        mul_128_pow10(self, e)
+    }
+
+    #[inline]
+    fn fits(self, p: usize) -> bool {
+        dec128_fits(self, p)
+    }
+
+    #[inline]
+    fn is_odd(self) -> bool {
+        self % 2 != 0
+    }
+
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        self + other
+    }
+
+    #[inline]
+    fn neg(self) -> Self {
+        -self
+    }
+}
+
 /// Deserialize bytes to a single i128 representing a decimal, at a specified
 /// precision and scale. The number is checked to ensure it fits within the
 /// specified precision and scale.  Consistent with float parsing, no decimal
@@ -669,6 +747,10 @@ pub fn dec128_sign(x: i128, s: usize) -> i128 {
 /// to true.
 pub fn str_to_dec128(bytes: &[u8], p: usize, s: usize, decimal_comma: bool) -> Option<i128> {
     assert!(dec128_verify_prec_scale(p, s).is_ok());
+    str_to_dec(bytes, p, s, decimal_comma)
+}
+
+fn str_to_dec<T: DecimalInt>(bytes: &[u8], p: usize, s: usize, decimal_comma: bool) -> Option<T> {
     let exp_pos = bytes
         .iter()
         .position(|b| *b == b'e' || *b == b'E')
@@ -683,8 +765,7 @@ pub fn str_to_dec128(bytes: &[u8], p: usize, s: usize, decimal_comma: bool) -> O
 
     if frac_bytes.is_empty() && exp_bytes.is_empty() {
         // Integer-only fast path.
-        let n: i128 = atoi_simd::parse_skipped(int_bytes).ok()?;
-        return i128_to_dec128(n, p, s);
+        return T::parse_skipped(int_bytes)?.int_to_dec(p, s);
     }
 
     // Skip sign and separator to get clean integers.
@@ -725,7 +806,7 @@ pub fn str_to_dec128(bytes: &[u8], p: usize, s: usize, decimal_comma: bool) -> O
         }
         if (-comb_scale) as usize > int_bytes.len() {
             // All digits are valid (so no error), but also irrelevant.
-            return Some(0);
+            return Some(T::ZERO);
         }
 
         next_digit = int_bytes[int_part_len];
@@ -761,56 +842,61 @@ pub fn str_to_dec128(bytes: &[u8], p: usize, s: usize, decimal_comma: bool) -> O
         frac_bytes = rest;
     }
 
-    let mut pint: i128 = if int_bytes.is_empty() {
-        0
+    let mut pint = if int_bytes.is_empty() {
+        T::ZERO
     } else {
-        atoi_simd::parse_pos(int_bytes).ok()?
+        T::parse_pos(int_bytes)?
     };
 
-    let mut pfrac: i128 = if frac_bytes.is_empty() {
-        0
+    let mut pfrac = if frac_bytes.is_empty() {
+        T::ZERO
     } else {
-        atoi_simd::parse_pos(frac_bytes).ok()?
+        T::parse_pos(frac_bytes)?
     };
 
     // Round-to-even.
     if next_digit > b'5' || next_digit == b'5' && !all_zero_after {
-        pfrac += 1;
+        pfrac = pfrac.add(T::ONE);
     } else if next_digit == b'5' {
         if comb_scale <= 0 {
-            pint += pint % 2;
-        } else {
-            pfrac += pfrac % 2;
+            if pint.is_odd() {
+                pint = pint.add(T::ONE);
+            }
+        } else if pfrac.is_odd() {
+            pfrac = pfrac.add(T::ONE);
         }
     }
 
     // Apply scales.
-    if pint > 0 {
-        if int_scale > DEC128_MAX_PREC {
+    if pint != T::ZERO {
+        if int_scale > T::MAX_PREC {
             return None;
         }
-        pint = mul_128_pow10(pint, int_scale)?;
+        pint = pint.mul_pow10(int_scale)?;
     }
 
-    if pfrac > 0 {
-        if frac_scale > DEC128_MAX_PREC {
+    if pfrac != T::ZERO {
+        if frac_scale > T::MAX_PREC {
             return None;
         }
-        pfrac = mul_128_pow10(pfrac, frac_scale)?;
+        pfrac = pfrac.mul_pow10(frac_scale)?;
     }
 
-    let ret = pint + pfrac;
-    if !dec128_fits(ret, p) {
+    let ret = pint.add(pfrac);
+    if !ret.fits(p) {
         return None;
     }
-    if negative { Some(-ret) } else { Some(ret) }
+    if negative { Some(ret.neg()) } else { Some(ret) }
 }
 
-const DEC128_MAX_LEN: usize = 39 + 2;
+#[cfg(not(feature = "dtype-decimal256"))]
+const DEC_MAX_LEN: usize = 39 + 2;
+#[cfg(feature = "dtype-decimal256")]
+const DEC_MAX_LEN: usize = 77 + 2;
 
 #[derive(Clone, Copy)]
 pub struct DecimalFmtBuffer {
-    data: [u8; DEC128_MAX_LEN],
+    data: [u8; DEC_MAX_LEN],
     len: usize,
 }
 
@@ -824,7 +910,7 @@ impl DecimalFmtBuffer {
     #[inline]
     pub const fn new() -> Self {
         Self {
-            data: [0; DEC128_MAX_LEN],
+            data: [0; DEC_MAX_LEN],
             len: 0,
         }
     }
@@ -836,12 +922,23 @@ impl DecimalFmtBuffer {
         trim_zeros: bool,
         decimal_comma: bool,
     ) -> &str {
-        let decimal_sep = if decimal_comma { b',' } else { b'.' };
-
         let mut itoa_buf = itoa::Buffer::new();
         let xs = itoa_buf.format(x.unsigned_abs()).as_bytes();
+        self.format_digits(x < 0, xs, scale, trim_zeros, decimal_comma)
+    }
+
+    /// Formats the decimal digits `xs` of the absolute value of a decimal.
+    fn format_digits(
+        &mut self,
+        negative: bool,
+        xs: &[u8],
+        scale: usize,
+        trim_zeros: bool,
+        decimal_comma: bool,
+    ) -> &str {
+        let decimal_sep = if decimal_comma { b',' } else { b'.' };
 
-        if x >= 0 {
+        if !negative {
             self.len = 0;
         } else {
             self.data[0] = b'-';
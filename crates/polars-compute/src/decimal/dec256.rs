@@ -0,0 +1,588 @@
+//! Decimal256 kernels, backed by a 256-bit integer.
+//!
+//! These mirror the Decimal128 kernels in the parent module, falling back to
+//! 512-bit intermediates where the 128-bit kernels use 256-bit ones.
+
+use std::cmp::Ordering;
+use std::sync::LazyLock;
+
+use arrow::types::i256;
+use ethnum::{I256, U256};
+use polars_error::{PolarsResult, polars_ensure};
+
+use super::{DecimalFmtBuffer, DecimalInt, dec128_fits, widening_mul_256};
+
+/// The maximum precision of a Decimal256.
+pub const DEC256_MAX_PREC: usize = 76;
+
+pub fn dec256_verify_prec_scale(p: usize, s: usize) -> PolarsResult<()> {
+    polars_ensure!((1..=DEC256_MAX_PREC).contains(&p), InvalidOperation: "precision must be between 1 and 76");
+    polars_ensure!(s <= p, InvalidOperation: "scale must be less than or equal to precision");
+    Ok(())
+}
+
+pub static POW10_I256: LazyLock<[I256; DEC256_MAX_PREC + 1]> = LazyLock::new(|| {
+    let mut out = [I256::ONE; DEC256_MAX_PREC + 1];
+    for i in 1..=DEC256_MAX_PREC {
+        out[i] = out[i - 1] * I256::new(10);
+    }
+    out
+});
+
+pub static POW10_F64_256: LazyLock<[f64; DEC256_MAX_PREC + 1]> =
+    LazyLock::new(|| std::array::from_fn(|i| i256_to_f64(POW10_I256[i])));
+
+#[inline]
+fn u256_to_i256(x: U256) -> I256 {
+    let (hi, lo) = x.into_words();
+    I256::from_words(hi as i128, lo as i128)
+}
+
+#[inline]
+fn i256_to_f64(x: I256) -> f64 {
+    // TODO: correctly rounded result. This rounds multiple times.
+    let (hi, lo) = x.unsigned_abs().into_words();
+    let r = hi as f64 * 2.0f64.powi(128) + lo as f64;
+    if x.is_negative() { -r } else { r }
+}
+
+#[inline]
+fn to_limbs(x: U256) -> super::U256 {
+    let (hi, lo) = x.into_words();
+    super::U256([lo as u64, (lo >> 64) as u64, hi as u64, (hi >> 64) as u64])
+}
+
+#[inline]
+fn from_limbs(x: super::U256) -> U256 {
+    U256::from_words(
+        super::u128_from_lo_hi(x.0[2], x.0[3]),
+        super::u128_from_lo_hi(x.0[0], x.0[1]),
+    )
+}
+
+/// Returns the 512-bit product of a and b as (lo, hi).
+#[inline]
+fn widening_mul_u256(a: U256, b: U256) -> (U256, U256) {
+    let (lo, hi) = widening_mul_256(to_limbs(a), to_limbs(b));
+    (from_limbs(lo), from_limbs(hi))
+}
+
+/// Adds x to the 512-bit number (lo, hi), assuming it doesn't overflow.
+#[inline]
+fn add_512(lo: U256, hi: U256, x: U256) -> (U256, U256) {
+    let (lo, carry) = lo.overflowing_add(x);
+    (lo, if carry { hi + U256::ONE } else { hi })
+}
+
+/// Divides the 512-bit number (lo, hi) by d, returning the quotient and remainder.
+///
+/// Returns None if the quotient doesn't fit in a U256.
+fn divrem_512_256(lo: U256, hi: U256, d: U256) -> Option<(U256, U256)> {
+    if hi >= d {
+        return None;
+    }
+    if hi == U256::ZERO {
+        return Some((lo / d, lo % d));
+    }
+
+    // Schoolbook shift-subtract division, only used for very large operands.
+    let mut q = U256::ZERO;
+    let mut r = hi;
+    for i in (0..256).rev() {
+        let top_bit = r >> 255u32 != U256::ZERO;
+        r = (r << 1u32) | ((lo >> i as u32) & U256::ONE);
+        if top_bit || r >= d {
+            r = r.wrapping_sub(d);
+            q |= U256::ONE << i as u32;
+        }
+    }
+    Some((q, r))
+}
+
+/// Returns x * 10^e, with e <= DEC256_MAX_PREC.
+///
+/// Returns None if the multiplication overflows.
+#[inline]
+fn mul_256_pow10(x: I256, e: usize) -> Option<I256> {
+    x.checked_mul(POW10_I256[e])
+}
+
+/// Returns round(x / 10^e), with e <= DEC256_MAX_PREC, rounding to nearest even.
+#[inline]
+fn div_256_pow10(x: I256, e: usize) -> I256 {
+    if e == 0 {
+        return x;
+    }
+
+    let n = x.unsigned_abs();
+    let d = POW10_I256[e].unsigned_abs();
+    let z = n + d / U256::new(2); // Can't overflow, n <= 2^255 and d < 2^253.
+    let mut ret = z / d;
+    if z % d == U256::ZERO && ret & U256::ONE == U256::ONE {
+        ret -= U256::ONE;
+    }
+    let ret = u256_to_i256(ret);
+    if x.is_negative() { -ret } else { ret }
+}
+
+#[inline]
+fn dec256_fits_inner(x: I256, p: usize) -> bool {
+    x.unsigned_abs() < POW10_I256[p].unsigned_abs()
+}
+
+/// Returns whether the given Decimal256 fits in the given precision.
+#[inline]
+pub fn dec256_fits(x: i256, p: usize) -> bool {
+    dec256_fits_inner(x.0, p)
+}
+
+/// Converts a Decimal256 with the given scale to an i128, rounding to nearest
+/// even. Returns None if the value doesn't fit.
+#[inline]
+pub fn dec256_to_i128(x: i256, s: usize) -> Option<i128> {
+    i128::try_from(div_256_pow10(x.0, s)).ok()
+}
+
+/// Converts an i128 to a Decimal256 with the given precision and scale,
+/// returning None if the value doesn't fit.
+#[inline]
+pub fn i128_to_dec256(x: i128, p: usize, s: usize) -> Option<i256> {
+    let r = mul_256_pow10(I256::new(x), s)?;
+    dec256_fits_inner(r, p).then_some(i256(r))
+}
+
+/// Converts a Decimal256 with the given scale to a f64.
+#[inline]
+pub fn dec256_to_f64(x: i256, s: usize) -> f64 {
+    // TODO: correctly rounded result. This rounds multiple times.
+    i256_to_f64(x.0) / POW10_F64_256[s]
+}
+
+/// Converts a f64 to a Decimal256 with the given precision and scale, returning
+/// None if the value doesn't fit.
+#[inline]
+pub fn f64_to_dec256(x: f64, p: usize, s: usize) -> Option<i256> {
+    // TODO: correctly rounded result. This rounds multiple times.
+    #[allow(clippy::neg_cmp_op_on_partial_ord)]
+    if !(x.abs() < POW10_F64_256[p]) {
+        // Comparison will fail for NaN, making us return None.
+        return None;
+    }
+    let v = (x * POW10_F64_256[s]).round_ties_even();
+
+    // v is an integer below 2^253, so splitting it in words is exact.
+    let two_128 = 2.0f64.powi(128);
+    let abs = v.abs();
+    let hi = (abs / two_128).floor();
+    let lo = abs - hi * two_128;
+    let r = u256_to_i256(U256::from_words(hi as u128, lo as u128));
+    Some(i256(if v < 0.0 { -r } else { r }))
+}
+
+#[inline]
+fn dec256_rescale_inner(x: I256, old_s: usize, new_p: usize, new_s: usize) -> Option<I256> {
+    let r = if new_s < old_s {
+        div_256_pow10(x, old_s - new_s)
+    } else if new_s > old_s {
+        mul_256_pow10(x, new_s - old_s)?
+    } else {
+        x
+    };
+
+    dec256_fits_inner(r, new_p).then_some(r)
+}
+
+/// Converts between two Decimal256s, with a new precision and scale, returning
+/// None if the value doesn't fit.
+#[inline]
+pub fn dec256_rescale(x: i256, old_s: usize, new_p: usize, new_s: usize) -> Option<i256> {
+    dec256_rescale_inner(x.0, old_s, new_p, new_s).map(i256)
+}
+
+/// Converts a Decimal128 to a Decimal256 with a new precision and scale,
+/// returning None if the value doesn't fit.
+#[inline]
+pub fn dec128_to_dec256(x: i128, old_s: usize, new_p: usize, new_s: usize) -> Option<i256> {
+    dec256_rescale_inner(I256::new(x), old_s, new_p, new_s).map(i256)
+}
+
+/// Converts a Decimal256 to a Decimal128 with a new precision and scale,
+/// returning None if the value doesn't fit.
+#[inline]
+pub fn dec256_to_dec128(x: i256, old_s: usize, new_p: usize, new_s: usize) -> Option<i128> {
+    let r = dec256_rescale_inner(x.0, old_s, new_p, new_s)?;
+    i128::try_from(r).ok().filter(|r| dec128_fits(*r, new_p))
+}
+
+/// Adds two Decimal256s, assuming they have the same scale.
+#[inline]
+pub fn dec256_add(l: i256, r: i256, p: usize) -> Option<i256> {
+    l.0.checked_add(r.0)
+        .filter(|x| dec256_fits_inner(*x, p))
+        .map(i256)
+}
+
+/// Subs two Decimal256s, assuming they have the same scale.
+#[inline]
+pub fn dec256_sub(l: i256, r: i256, p: usize) -> Option<i256> {
+    l.0.checked_sub(r.0)
+        .filter(|x| dec256_fits_inner(*x, p))
+        .map(i256)
+}
+
+/// Multiplies two Decimal256s, assuming they have the same scale s.
+#[inline]
+pub fn dec256_mul(l: i256, r: i256, p: usize, s: usize) -> Option<i256> {
+    // Computes round(l * r / 10^s), rounding to nearest even.
+    let (l, r) = (l.0, r.0);
+    if let Some(prod) = l.checked_mul(r) {
+        // Fast path, product representable as I256.
+        let ret = div_256_pow10(prod, s);
+        return dec256_fits_inner(ret, p).then_some(i256(ret));
+    }
+
+    let negative = l.is_negative() ^ r.is_negative();
+    let (lo, hi) = widening_mul_u256(l.unsigned_abs(), r.unsigned_abs());
+    let d = POW10_I256[s].unsigned_abs();
+    let (lo, hi) = add_512(lo, hi, d / U256::new(2));
+    let (mut retu, rem) = divrem_512_256(lo, hi, d)?;
+    if s > 0 && rem == U256::ZERO && retu & U256::ONE == U256::ONE {
+        retu -= U256::ONE;
+    }
+    if retu >= POW10_I256[p].unsigned_abs() {
+        return None;
+    }
+    let ret = u256_to_i256(retu);
+    Some(i256(if negative { -ret } else { ret }))
+}
+
+/// Divides two Decimal256s, assuming they have the same scale s.
+#[inline]
+pub fn dec256_div(l: i256, r: i256, p: usize, s: usize) -> Option<i256> {
+    let (l, r) = (l.0, r.0);
+    if r == I256::ZERO {
+        return None;
+    }
+
+    let negative = l.is_negative() ^ r.is_negative();
+    let lu = l.unsigned_abs();
+    let ru = r.unsigned_abs();
+    let m = POW10_I256[s].unsigned_abs();
+
+    // Computes round((l / r) * 10^s), rounding to nearest even.
+    let (mut retu, rem) = match lu
+        .checked_mul(m)
+        .and_then(|n| n.checked_add(ru / U256::new(2)))
+    {
+        // Fast path, intermediate product representable as U256.
+        Some(z) => (z / ru, z % ru),
+        None => {
+            let (lo, hi) = widening_mul_u256(lu, m);
+            let (lo, hi) = add_512(lo, hi, ru / U256::new(2));
+            divrem_512_256(lo, hi, ru)?
+        },
+    };
+
+    // Round to nearest even.
+    if ru & U256::ONE == U256::ZERO && retu & U256::ONE == U256::ONE && rem == U256::ZERO {
+        retu -= U256::ONE;
+    }
+
+    if retu >= POW10_I256[p].unsigned_abs() {
+        return None;
+    }
+    let ret = u256_to_i256(retu);
+    Some(i256(if negative { -ret } else { ret }))
+}
+
+/// Checks if two Decimal256s are equal in value.
+#[inline]
+pub fn dec256_eq(lv: i256, ls: usize, rv: i256, rs: usize) -> bool {
+    let (mut lv, mut rv) = (lv.0, rv.0);
+    // Rescale to largest scale. If this overflows the numbers can't be equal anyway.
+    if ls < rs {
+        let Some(scaled_lv) = mul_256_pow10(lv, rs - ls) else {
+            return false;
+        };
+        lv = scaled_lv;
+    } else if ls > rs {
+        let Some(scaled_rv) = mul_256_pow10(rv, ls - rs) else {
+            return false;
+        };
+        rv = scaled_rv;
+    }
+
+    lv == rv
+}
+
+/// Checks how two Decimal256s compare.
+#[inline]
+pub fn dec256_cmp(lv: i256, ls: usize, rv: i256, rs: usize) -> Ordering {
+    let (mut lv, mut rv) = (lv.0, rv.0);
+    // Rescale to largest scale. If this overflows we know the magnitude of the
+    // (attempted) rescaled number is larger and we can resolve the answer just
+    // using its sign.
+    if ls < rs {
+        let Some(scaled_lv) = mul_256_pow10(lv, rs - ls) else {
+            return if lv.is_negative() {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            };
+        };
+        lv = scaled_lv;
+    } else if ls > rs {
+        let Some(scaled_rv) = mul_256_pow10(rv, ls - rs) else {
+            return if rv.is_positive() {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            };
+        };
+        rv = scaled_rv;
+    }
+
+    lv.cmp(&rv)
+}
+
+impl DecimalInt for i256 {
+    const MAX_PREC: usize = DEC256_MAX_PREC;
+    const ZERO: Self = i256(I256::ZERO);
+    const ONE: Self = i256(I256::ONE);
+
+    fn parse_skipped(mut bytes: &[u8]) -> Option<Self> {
+        let negative = match bytes.first() {
+            Some(sign @ (b'+' | b'-')) => {
+                bytes = &bytes[1..];
+                *sign == b'-'
+            },
+            _ => false,
+        };
+        if bytes.is_empty() {
+            return None;
+        }
+        while let Some((b'0', rest)) = bytes.split_first() {
+            bytes = rest;
+        }
+        let x = if bytes.is_empty() {
+            Self::ZERO
+        } else {
+            Self::parse_pos(bytes)?
+        };
+        Some(if negative { x.neg() } else { x })
+    }
+
+    fn parse_pos(bytes: &[u8]) -> Option<Self> {
+        if bytes.is_empty() {
+            return None;
+        }
+        // Parse in chunks of 18 digits, which always fit in an u64.
+        let mut acc = I256::ZERO;
+        for chunk in bytes.chunks(18) {
+            let mut v: u64 = 0;
+            for b in chunk {
+                if !b.is_ascii_digit() {
+                    return None;
+                }
+                v = v * 10 + (b - b'0') as u64;
+            }
+            acc = mul_256_pow10(acc, chunk.len())?.checked_add(I256::new(v as i128))?;
+        }
+        Some(i256(acc))
+    }
+
+    #[inline]
+    fn int_to_dec(self, p: usize, s: usize) -> Option<Self> {
+        let r = mul_256_pow10(self.0, s)?;
+        dec256_fits_inner(r, p).then_some(i256(r))
+    }
+
+    #[inline]
+    fn mul_pow10(self, e: usize) -> Option<Self> {
+        mul_256_pow10(self.0, e).map(i256)
+    }
+
+    #[inline]
+    fn fits(self, p: usize) -> bool {
+        dec256_fits_inner(self.0, p)
+    }
+
+    #[inline]
+    fn is_odd(self) -> bool {
+        self.0 & I256::ONE == I256::ONE
+    }
+
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        i256(self.0 + other.0)
+    }
+
+    #[inline]
+    fn neg(self) -> Self {
+        i256(-self.0)
+    }
+}
+
+/// Deserialize bytes to a single i256 representing a decimal, at a specified
+/// precision and scale. Behaves exactly like [`str_to_dec128`](super::str_to_dec128),
+/// but for precisions up to [`DEC256_MAX_PREC`].
+pub fn str_to_dec256(bytes: &[u8], p: usize, s: usize, decimal_comma: bool) -> Option<i256> {
+    assert!(dec256_verify_prec_scale(p, s).is_ok());
+    super::str_to_dec(bytes, p, s, decimal_comma)
+}
+
+impl DecimalFmtBuffer {
+    pub fn format_dec256(
+        &mut self,
+        x: i256,
+        scale: usize,
+        trim_zeros: bool,
+        decimal_comma: bool,
+    ) -> &str {
+        use std::io::Write;
+
+        let mut digits = [0u8; 78];
+        let n = {
+            let mut cursor = &mut digits[..];
+            write!(cursor, "{}", x.0.unsigned_abs()).unwrap();
+            78 - cursor.len()
+        };
+        self.format_digits(
+            x.0.is_negative(),
+            &digits[..n],
+            scale,
+            trim_zeros,
+            decimal_comma,
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use bigdecimal::{BigDecimal, RoundingMode};
+    use num_bigint::{BigInt, Sign};
+    use num_traits::Signed;
+    use rand::prelude::*;
+
+    use super::*;
+
+    fn bigdecimal_to_dec256(x: &BigDecimal, p: usize, s: usize) -> Option<i256> {
+        let n = x
+            .with_scale_round(s as i64, RoundingMode::HalfEven)
+            .into_bigint_and_scale()
+            .0;
+        if n.abs() < BigInt::from(10u8).pow(p as u32) {
+            str_to_dec256(n.to_string().as_bytes(), p, 0, false)
+        } else {
+            None
+        }
+    }
+
+    fn to_bigint(x: i256) -> BigInt {
+        x.0.to_string().parse().unwrap()
+    }
+
+    fn dec256_to_bigdecimal(x: i256, s: usize) -> BigDecimal {
+        BigDecimal::from_bigint(to_bigint(x), s as i64)
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn test_str_to_dec256() {
+        fn str_to_dec256_dot(bytes: &[u8], p: usize, s: usize) -> Option<i256> {
+            str_to_dec256(bytes, p, s, false)
+        }
+        fn dec(x: i128) -> Option<i256> {
+            Some(i256(I256::new(x)))
+        }
+
+        assert_eq!(str_to_dec256_dot(b"12.09", 8, 2), dec(1209));
+        assert_eq!(str_to_dec256_dot(b"-1.5", 8, 2), dec(-150));
+        assert_eq!(str_to_dec256_dot(b"0.125", 8, 2), dec(12));
+        assert_eq!(str_to_dec256_dot(b"0.135", 8, 2), dec(14));
+        assert_eq!(str_to_dec256_dot(b"12ABC.34", 8, 5), None);
+        assert_eq!(str_to_dec256_dot(b"1e2", 8, 2), dec(10000));
+        assert_eq!(str_to_dec256_dot(b"-000", 8, 2), dec(0));
+        assert_eq!(str_to_dec256_dot(b"", 8, 2), None);
+
+        let big = "9".repeat(76);
+        assert_eq!(
+            str_to_dec256_dot(big.as_bytes(), 76, 0),
+            Some(i256(POW10_I256[76] - I256::ONE))
+        );
+        assert_eq!(str_to_dec256_dot(format!("{big}9").as_bytes(), 76, 0), None);
+        assert_eq!(str_to_dec256_dot(big.as_bytes(), 76, 1), None);
+    }
+
+    #[test]
+    fn test_str_dec256_roundtrip() {
+        let mut r = SmallRng::seed_from_u64(42);
+        let mut buf = DecimalFmtBuffer::new();
+        for &(p, s) in &[(76, 0), (76, 38), (76, 76), (50, 10), (39, 2)] {
+            for _ in 0..1_000 {
+                let x = i256(I256::from_words(r.random(), r.random()) % POW10_I256[p]);
+                for d_comma in [false, true] {
+                    let fmt = buf.format_dec256(x, s, false, d_comma);
+                    assert_eq!(str_to_dec256(fmt.as_bytes(), p, s, d_comma), Some(x));
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn test_mul_div() {
+        let mut r = SmallRng::seed_from_u64(42);
+        for &(p, s) in &[(76, 0), (76, 20), (76, 38), (60, 30), (40, 5)] {
+            for _ in 0..1_000 {
+                let digits = r.random_range(1..=p);
+                let x = i256(I256::from_words(r.random(), r.random()) % POW10_I256[digits]);
+                let digits = r.random_range(1..=p);
+                let y = i256(I256::from_words(r.random(), r.random()) % POW10_I256[digits]);
+                let (xb, yb) = (dec256_to_bigdecimal(x, s), dec256_to_bigdecimal(y, s));
+
+                assert_eq!(dec256_mul(x, y, p, s), bigdecimal_to_dec256(&(&xb * &yb), p, s));
+                if y.0 == I256::ZERO {
+                    assert!(dec256_div(x, y, p, s).is_none());
+                } else {
+                    // Exact round-half-even of x * 10^s / y.
+                    let num = to_bigint(x) * to_bigint(i256(POW10_I256[s]));
+                    let den = to_bigint(y);
+                    let (q, rem) = (&num / &den, &num % &den);
+                    let twice = rem.abs() * 2;
+                    let q = if twice > den.abs() || (twice == den.abs() && (&q % 2) != BigInt::from(0)) {
+                        if (num.sign() == Sign::Minus) ^ (den.sign() == Sign::Minus) { q - 1 } else { q + 1 }
+                    } else {
+                        q
+                    };
+                    let expected = BigDecimal::from_bigint(q, s as i64);
+                    assert_eq!(dec256_div(x, y, p, s), bigdecimal_to_dec256(&expected, p, s));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_conversions() {
+        let x = i128_to_dec256(i128::MAX, 76, 30).unwrap();
+        assert_eq!(dec256_to_i128(x, 30), Some(i128::MAX));
+        assert_eq!(dec256_to_dec128(x, 30, 38, 0), None);
+        assert_eq!(
+            dec256_to_dec128(dec128_to_dec256(12345, 2, 76, 40).unwrap(), 40, 38, 3),
+            Some(123450)
+        );
+        let big = -1.5 * 2.0f64.powi(200);
+        assert_eq!(dec256_to_f64(f64_to_dec256(big, 76, 0).unwrap(), 0), big);
+        assert_eq!(f64_to_dec256(1e76, 76, 0), None);
+        assert_eq!(f64_to_dec256(f64::NAN, 76, 0), None);
+        assert_eq!(
+            dec256_cmp(i256(I256::new(15)), 1, i256(I256::new(150)), 2),
+            Ordering::Equal
+        );
+        assert!(dec256_eq(i256(I256::new(-15)), 1, i256(I256::new(-150)), 2));
+        assert_eq!(
+            dec256_cmp(i256(POW10_I256[75]), 0, i256(I256::ONE), 76),
+            Ordering::Greater
+        );
+    }
+}
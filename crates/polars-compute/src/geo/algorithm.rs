@@ -0,0 +1,356 @@
+use super::{Coord, Geometry, LineString, Polygon};
+
+fn cross(a: Coord, b: Coord) -> f64 {
+    a.x * b.y - b.x * a.y
+}
+
+/// The segments of a ring or line string, closing rings that are not explicitly closed.
+fn ring_segments(ring: &[Coord]) -> impl Iterator<Item = (Coord, Coord)> + '_ {
+    let closing = match (ring.first(), ring.last()) {
+        (Some(first), Some(last)) if first != last => Some((*last, *first)),
+        _ => None,
+    };
+    ring.windows(2).map(|w| (w[0], w[1])).chain(closing)
+}
+
+/// The signed area and the first moments of a ring.
+fn ring_moments(ring: &[Coord]) -> (f64, f64, f64) {
+    let (mut a, mut mx, mut my) = (0.0, 0.0, 0.0);
+    for (p, q) in ring_segments(ring) {
+        let c = cross(p, q);
+        a += c;
+        mx += (p.x + q.x) * c;
+        my += (p.y + q.y) * c;
+    }
+    (a / 2.0, mx / 6.0, my / 6.0)
+}
+
+/// The area, and the first moments, of a polygon with holes. The orientation of the rings is
+/// ignored.
+fn polygon_moments(rings: &Polygon) -> (f64, f64, f64) {
+    let (mut a, mut mx, mut my) = (0.0, 0.0, 0.0);
+    for (i, ring) in rings.iter().enumerate() {
+        let (ra, rmx, rmy) = ring_moments(ring);
+        // The exterior counts positively, holes negatively.
+        let sign = ra.signum() * if i == 0 { 1.0 } else { -1.0 };
+        a += sign * ra;
+        mx += sign * rmx;
+        my += sign * rmy;
+    }
+    (a, mx, my)
+}
+
+pub(super) fn polygon_area(rings: &Polygon) -> f64 {
+    polygon_moments(rings).0
+}
+
+pub(super) fn line_length(line: &[Coord]) -> f64 {
+    line.windows(2)
+        .map(|w| (w[1].x - w[0].x).hypot(w[1].y - w[0].y))
+        .sum()
+}
+
+/// The parts of a geometry by dimension, with collections flattened.
+#[derive(Default)]
+struct Parts<'a> {
+    points: Vec<Coord>,
+    lines: Vec<&'a LineString>,
+    polygons: Vec<&'a Polygon>,
+}
+
+impl<'a> Parts<'a> {
+    fn new(g: &'a Geometry) -> Self {
+        let mut parts = Self::default();
+        parts.add(g);
+        parts
+    }
+
+    fn add(&mut self, g: &'a Geometry) {
+        match g {
+            Geometry::Point(p) => self.points.extend(p),
+            Geometry::MultiPoint(points) => self.points.extend(points),
+            Geometry::LineString(line) => self.lines.push(line),
+            Geometry::MultiLineString(lines) => self.lines.extend(lines),
+            Geometry::Polygon(rings) => self.polygons.push(rings),
+            Geometry::MultiPolygon(polygons) => self.polygons.extend(polygons),
+            Geometry::GeometryCollection(geometries) => geometries.iter().for_each(|g| self.add(g)),
+        }
+    }
+
+    /// All segments, with points and single-coordinate lines as degenerate segments.
+    fn segments(&self) -> impl Iterator<Item = (Coord, Coord)> + '_ {
+        let points = self.points.iter().map(|p| (*p, *p));
+        let lines = self.lines.iter().flat_map(|line| match line.as_slice() {
+            [p] => vec![(*p, *p)],
+            line => line.windows(2).map(|w| (w[0], w[1])).collect(),
+        });
+        points.chain(lines).chain(self.boundary_segments())
+    }
+
+    fn boundary_segments(&self) -> impl Iterator<Item = (Coord, Coord)> + '_ {
+        self.polygons
+            .iter()
+            .flat_map(|rings| rings.iter())
+            .flat_map(|ring| ring_segments(ring))
+    }
+
+    /// A coordinate of every part.
+    fn representative_points(&self) -> impl Iterator<Item = Coord> + '_ {
+        let lines = self.lines.iter().filter_map(|l| l.first().copied());
+        let polygons = self
+            .polygons
+            .iter()
+            .filter_map(|rings| rings.first().and_then(|r| r.first()).copied());
+        self.points.iter().copied().chain(lines).chain(polygons)
+    }
+
+    /// The location of `p` relative to this geometry. The points of line strings all count as
+    /// interior.
+    fn locate(&self, p: Coord) -> Location {
+        let mut location = Location::Exterior;
+        for rings in &self.polygons {
+            match locate_in_polygon(p, rings) {
+                Location::Interior => return Location::Interior,
+                Location::Boundary => location = Location::Boundary,
+                Location::Exterior => {},
+            }
+        }
+        if location == Location::Exterior
+            && (self.points.contains(&p)
+                || self
+                    .lines
+                    .iter()
+                    .any(|l| l.windows(2).any(|w| on_segment(p, w[0], w[1]))))
+        {
+            location = Location::Interior;
+        }
+        location
+    }
+}
+
+pub(super) fn centroid(g: &Geometry) -> Option<Coord> {
+    let parts = Parts::new(g);
+
+    let (mut a, mut mx, mut my) = (0.0, 0.0, 0.0);
+    for rings in &parts.polygons {
+        let (pa, pmx, pmy) = polygon_moments(rings);
+        a += pa;
+        mx += pmx;
+        my += pmy;
+    }
+    if a > 0.0 {
+        return Some(Coord::new(mx / a, my / a));
+    }
+
+    let (mut len, mut sx, mut sy) = (0.0, 0.0, 0.0);
+    let rings = parts.polygons.iter().flat_map(|rings| rings.iter());
+    for line in parts.lines.iter().copied().chain(rings) {
+        for w in line.windows(2) {
+            let l = (w[1].x - w[0].x).hypot(w[1].y - w[0].y);
+            len += l;
+            sx += l * (w[0].x + w[1].x) / 2.0;
+            sy += l * (w[0].y + w[1].y) / 2.0;
+        }
+    }
+    if len > 0.0 {
+        return Some(Coord::new(sx / len, sy / len));
+    }
+
+    let (mut n, mut sx, mut sy) = (0usize, 0.0, 0.0);
+    g.for_each_coord(&mut |c| {
+        n += 1;
+        sx += c.x;
+        sy += c.y;
+    });
+    (n > 0).then(|| Coord::new(sx / n as f64, sy / n as f64))
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Location {
+    Interior,
+    Boundary,
+    Exterior,
+}
+
+fn orientation(a: Coord, b: Coord, c: Coord) -> f64 {
+    (b.x - a.x) * (c.y - a.y) - (b.y - a.y) * (c.x - a.x)
+}
+
+fn on_segment(p: Coord, a: Coord, b: Coord) -> bool {
+    orientation(a, b, p) == 0.0
+        && p.x >= a.x.min(b.x)
+        && p.x <= a.x.max(b.x)
+        && p.y >= a.y.min(b.y)
+        && p.y <= a.y.max(b.y)
+}
+
+fn segments_intersect((p1, p2): (Coord, Coord), (q1, q2): (Coord, Coord)) -> bool {
+    let o1 = orientation(p1, p2, q1);
+    let o2 = orientation(p1, p2, q2);
+    let o3 = orientation(q1, q2, p1);
+    let o4 = orientation(q1, q2, p2);
+    if o1 * o2 < 0.0 && o3 * o4 < 0.0 {
+        return true;
+    }
+    on_segment(q1, p1, p2)
+        || on_segment(q2, p1, p2)
+        || on_segment(p1, q1, q2)
+        || on_segment(p2, q1, q2)
+}
+
+/// Whether the segments cross at a single point in the interior of both.
+fn segments_cross((p1, p2): (Coord, Coord), (q1, q2): (Coord, Coord)) -> bool {
+    orientation(p1, p2, q1) * orientation(p1, p2, q2) < 0.0
+        && orientation(q1, q2, p1) * orientation(q1, q2, p2) < 0.0
+}
+
+fn point_segment_distance(p: Coord, a: Coord, b: Coord) -> f64 {
+    let (dx, dy) = (b.x - a.x, b.y - a.y);
+    let len2 = dx * dx + dy * dy;
+    let t = if len2 == 0.0 {
+        0.0
+    } else {
+        (((p.x - a.x) * dx + (p.y - a.y) * dy) / len2).clamp(0.0, 1.0)
+    };
+    (p.x - (a.x + t * dx)).hypot(p.y - (a.y + t * dy))
+}
+
+fn segment_distance(p: (Coord, Coord), q: (Coord, Coord)) -> f64 {
+    if segments_intersect(p, q) {
+        return 0.0;
+    }
+    point_segment_distance(p.0, q.0, q.1)
+        .min(point_segment_distance(p.1, q.0, q.1))
+        .min(point_segment_distance(q.0, p.0, p.1))
+        .min(point_segment_distance(q.1, p.0, p.1))
+}
+
+/// Even-odd test of whether `p` lies inside `ring`, for points not on the ring.
+fn in_ring(p: Coord, ring: &[Coord]) -> bool {
+    let mut inside = false;
+    for (a, b) in ring_segments(ring) {
+        if (a.y > p.y) != (b.y > p.y) && p.x < a.x + (p.y - a.y) / (b.y - a.y) * (b.x - a.x) {
+            inside = !inside;
+        }
+    }
+    inside
+}
+
+fn locate_in_polygon(p: Coord, rings: &Polygon) -> Location {
+    let on_boundary = rings
+        .iter()
+        .any(|ring| ring_segments(ring).any(|(a, b)| on_segment(p, a, b)));
+    if on_boundary {
+        return Location::Boundary;
+    }
+    match rings.split_first() {
+        Some((exterior, holes)) if in_ring(p, exterior) && !holes.iter().any(|h| in_ring(p, h)) => {
+            Location::Interior
+        },
+        _ => Location::Exterior,
+    }
+}
+
+/// Whether the geometries have at least one point in common.
+pub fn intersects(a: &Geometry, b: &Geometry) -> bool {
+    let (Some(bbox_a), Some(bbox_b)) = (a.bbox(), b.bbox()) else {
+        return false;
+    };
+    if !bbox_a.intersects(&bbox_b) {
+        return false;
+    }
+    let (pa, pb) = (Parts::new(a), Parts::new(b));
+    if pa
+        .segments()
+        .any(|sa| pb.segments().any(|sb| segments_intersect(sa, sb)))
+    {
+        return true;
+    }
+    // Without crossing boundaries, one geometry can still lie inside a polygon of the other.
+    let inside = |outer: &Parts<'_>, inner: &Parts<'_>| {
+        inner.representative_points().any(|p| {
+            outer
+                .polygons
+                .iter()
+                .any(|rings| locate_in_polygon(p, rings) != Location::Exterior)
+        })
+    };
+    inside(&pa, &pb) || inside(&pb, &pa)
+}
+
+/// Whether no point of `b` lies outside of `a`, and at least one point of `b` lies in the
+/// interior of `a`.
+///
+/// Containment is decided on the vertices and segment midpoints of `b`, and the crossings of the
+/// boundaries, which is exact for polygons without collinear overlapping edges.
+pub fn contains(a: &Geometry, b: &Geometry) -> bool {
+    let (Some(bbox_a), Some(bbox_b)) = (a.bbox(), b.bbox()) else {
+        return false;
+    };
+    if bbox_b.min_x < bbox_a.min_x
+        || bbox_b.min_y < bbox_a.min_y
+        || bbox_b.max_x > bbox_a.max_x
+        || bbox_b.max_y > bbox_a.max_y
+    {
+        return false;
+    }
+    let (pa, pb) = (Parts::new(a), Parts::new(b));
+
+    let mut any_interior = false;
+    let mut check = |p: Coord| match pa.locate(p) {
+        Location::Exterior => false,
+        Location::Interior => {
+            any_interior = true;
+            true
+        },
+        Location::Boundary => true,
+    };
+
+    for (p, q) in pb.segments() {
+        let mid = Coord::new((p.x + q.x) / 2.0, (p.y + q.y) / 2.0);
+        if !(check(p) && check(q) && check(mid)) {
+            return false;
+        }
+        if pa.boundary_segments().any(|s| segments_cross((p, q), s)) {
+            return false;
+        }
+    }
+
+    for rings in &pb.polygons {
+        // A boundary vertex of `a` inside a polygon of `b` means the polygon leaves `a`, e.g.
+        // over a hole.
+        let b_polygon = Parts {
+            polygons: vec![*rings],
+            ..Default::default()
+        };
+        if pa
+            .boundary_segments()
+            .any(|(p, _)| b_polygon.locate(p) == Location::Interior)
+        {
+            return false;
+        }
+        let (area, mx, my) = polygon_moments(rings);
+        if area > 0.0 {
+            let c = Coord::new(mx / area, my / area);
+            if b_polygon.locate(c) == Location::Interior && !check(c) {
+                return false;
+            }
+        }
+    }
+
+    any_interior
+}
+
+/// The minimum distance between the geometries, or `None` if either is empty.
+pub fn distance(a: &Geometry, b: &Geometry) -> Option<f64> {
+    if a.is_empty() || b.is_empty() {
+        return None;
+    }
+    if intersects(a, b) {
+        return Some(0.0);
+    }
+    let (pa, pb) = (Parts::new(a), Parts::new(b));
+    pa.segments()
+        .flat_map(|sa| pb.segments().map(move |sb| segment_distance(sa, sb)))
+        .reduce(f64::min)
+}
@@ -0,0 +1,237 @@
+//! Planar geometries stored as [WKB], with conversion from and to [WKT] and spatial predicates.
+//!
+//! [WKB]: https://libgeos.org/specifications/wkb/
+//! [WKT]: https://libgeos.org/specifications/wkt/
+
+mod algorithm;
+mod wkb;
+mod wkt;
+
+pub use algorithm::{contains, distance, intersects};
+
+/// A planar coordinate. Z and M values are dropped when reading.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Coord {
+    pub x: f64,
+    pub y: f64,
+}
+
+impl Coord {
+    pub fn new(x: f64, y: f64) -> Self {
+        Self { x, y }
+    }
+}
+
+/// An axis-aligned bounding box.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Rect {
+    pub min_x: f64,
+    pub min_y: f64,
+    pub max_x: f64,
+    pub max_y: f64,
+}
+
+impl Rect {
+    fn from_coord(c: Coord) -> Self {
+        Self {
+            min_x: c.x,
+            min_y: c.y,
+            max_x: c.x,
+            max_y: c.y,
+        }
+    }
+
+    fn extend(&mut self, c: Coord) {
+        self.min_x = self.min_x.min(c.x);
+        self.min_y = self.min_y.min(c.y);
+        self.max_x = self.max_x.max(c.x);
+        self.max_y = self.max_y.max(c.y);
+    }
+
+    pub fn intersects(&self, other: &Rect) -> bool {
+        self.min_x <= other.max_x
+            && other.min_x <= self.max_x
+            && self.min_y <= other.max_y
+            && other.min_y <= self.max_y
+    }
+}
+
+/// A line string or polygon ring.
+pub type LineString = Vec<Coord>;
+
+/// A polygon given by its exterior ring followed by its holes.
+pub type Polygon = Vec<LineString>;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Geometry {
+    /// A point, `None` if empty.
+    Point(Option<Coord>),
+    LineString(LineString),
+    Polygon(Polygon),
+    MultiPoint(Vec<Coord>),
+    MultiLineString(Vec<LineString>),
+    MultiPolygon(Vec<Polygon>),
+    GeometryCollection(Vec<Geometry>),
+}
+
+impl Geometry {
+    /// The OGC name of the geometry type, e.g. `"Polygon"`.
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Geometry::Point(_) => "Point",
+            Geometry::LineString(_) => "LineString",
+            Geometry::Polygon(_) => "Polygon",
+            Geometry::MultiPoint(_) => "MultiPoint",
+            Geometry::MultiLineString(_) => "MultiLineString",
+            Geometry::MultiPolygon(_) => "MultiPolygon",
+            Geometry::GeometryCollection(_) => "GeometryCollection",
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bbox().is_none()
+    }
+
+    /// Calls `f` on every coordinate.
+    pub fn for_each_coord(&self, f: &mut impl FnMut(Coord)) {
+        match self {
+            Geometry::Point(p) => p.iter().copied().for_each(f),
+            Geometry::LineString(line) | Geometry::MultiPoint(line) => {
+                line.iter().copied().for_each(f)
+            },
+            Geometry::Polygon(rings) | Geometry::MultiLineString(rings) => {
+                rings.iter().flatten().copied().for_each(f)
+            },
+            Geometry::MultiPolygon(polygons) => {
+                polygons.iter().flatten().flatten().copied().for_each(f)
+            },
+            Geometry::GeometryCollection(geometries) => {
+                geometries.iter().for_each(|g| g.for_each_coord(f))
+            },
+        }
+    }
+
+    /// The bounding box, or `None` if the geometry is empty.
+    pub fn bbox(&self) -> Option<Rect> {
+        let mut rect: Option<Rect> = None;
+        self.for_each_coord(&mut |c| match &mut rect {
+            Some(rect) => rect.extend(c),
+            None => rect = Some(Rect::from_coord(c)),
+        });
+        rect
+    }
+
+    /// The area of the polygonal parts.
+    pub fn area(&self) -> f64 {
+        match self {
+            Geometry::Polygon(rings) => algorithm::polygon_area(rings),
+            Geometry::MultiPolygon(polygons) => polygons.iter().map(algorithm::polygon_area).sum(),
+            Geometry::GeometryCollection(geometries) => geometries.iter().map(|g| g.area()).sum(),
+            _ => 0.0,
+        }
+    }
+
+    /// The length of the lineal parts, or the perimeter of the polygonal parts.
+    pub fn length(&self) -> f64 {
+        match self {
+            Geometry::LineString(line) => algorithm::line_length(line),
+            Geometry::Polygon(rings) | Geometry::MultiLineString(rings) => {
+                rings.iter().map(|r| algorithm::line_length(r)).sum()
+            },
+            Geometry::MultiPolygon(polygons) => polygons
+                .iter()
+                .flatten()
+                .map(|r| algorithm::line_length(r))
+                .sum(),
+            Geometry::GeometryCollection(geometries) => geometries.iter().map(|g| g.length()).sum(),
+            Geometry::Point(_) | Geometry::MultiPoint(_) => 0.0,
+        }
+    }
+
+    /// The centroid of the highest-dimensional parts, or `None` if the geometry is empty.
+    pub fn centroid(&self) -> Option<Coord> {
+        algorithm::centroid(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn wkt(s: &str) -> Geometry {
+        Geometry::from_wkt(s).unwrap()
+    }
+
+    #[test]
+    fn test_wkb_wkt_roundtrip() {
+        for s in [
+            "POINT (1 2)",
+            "POINT EMPTY",
+            "LINESTRING (0 0, 1 1, 2 0)",
+            "POLYGON ((0 0, 4 0, 4 4, 0 4, 0 0), (1 1, 2 1, 2 2, 1 1))",
+            "MULTIPOINT ((0 0), (1 1))",
+            "MULTILINESTRING ((0 0, 1 1), (2 2, 3 3))",
+            "MULTIPOLYGON (((0 0, 1 0, 1 1, 0 0)), ((2 2, 3 2, 3 3, 2 2)))",
+            "GEOMETRYCOLLECTION (POINT (1 2), LINESTRING (0 0, 1 1))",
+        ] {
+            let g = wkt(s);
+            assert_eq!(g.to_wkt(), s);
+            assert_eq!(Geometry::from_wkb(&g.to_wkb()).unwrap(), g);
+        }
+        assert_eq!(
+            wkt("point z (1 2 3)"),
+            Geometry::Point(Some(Coord::new(1.0, 2.0)))
+        );
+        assert_eq!(
+            wkt("MULTIPOINT (0 0, 1 1)"),
+            wkt("MULTIPOINT ((0 0), (1 1))")
+        );
+        assert!(Geometry::from_wkt("POINT (1)").is_err());
+    }
+
+    #[test]
+    fn test_measures() {
+        let square = wkt("POLYGON ((0 0, 4 0, 4 4, 0 4, 0 0), (1 1, 3 1, 3 3, 1 3, 1 1))");
+        assert_eq!(square.area(), 12.0);
+        assert_eq!(square.length(), 24.0);
+        assert_eq!(square.centroid(), Some(Coord::new(2.0, 2.0)));
+        assert_eq!(
+            square.bbox(),
+            Some(Rect {
+                min_x: 0.0,
+                min_y: 0.0,
+                max_x: 4.0,
+                max_y: 4.0
+            })
+        );
+        assert_eq!(
+            wkt("LINESTRING (0 0, 2 0, 2 2)").centroid(),
+            Some(Coord::new(1.5, 0.5))
+        );
+        assert_eq!(wkt("POINT EMPTY").centroid(), None);
+    }
+
+    #[test]
+    fn test_predicates() {
+        let square = wkt("POLYGON ((0 0, 4 0, 4 4, 0 4, 0 0))");
+        let ring = wkt("POLYGON ((0 0, 4 0, 4 4, 0 4, 0 0), (1 1, 3 1, 3 3, 1 3, 1 1))");
+        let inner = wkt("POINT (2 2)");
+
+        assert!(contains(&square, &inner));
+        assert!(!contains(&ring, &inner));
+        assert!(!contains(&square, &wkt("POINT (4 2)")));
+        assert!(intersects(&square, &wkt("POINT (4 2)")));
+        assert!(contains(&square, &wkt("LINESTRING (1 1, 3 3)")));
+        assert!(!contains(&square, &wkt("LINESTRING (1 1, 5 5)")));
+        assert!(contains(&square, &square));
+
+        assert!(intersects(&ring, &wkt("LINESTRING (-1 2, 5 2)")));
+        assert!(!intersects(&ring, &inner));
+        assert_eq!(distance(&ring, &inner), Some(1.0));
+        assert_eq!(
+            distance(&wkt("POINT (0 0)"), &wkt("POINT (3 4)")),
+            Some(5.0)
+        );
+        assert_eq!(distance(&square, &wkt("POINT EMPTY")), None);
+    }
+}
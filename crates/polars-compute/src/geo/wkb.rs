@@ -0,0 +1,223 @@
+use polars_error::{PolarsResult, polars_bail, polars_err};
+
+use super::{Coord, Geometry, LineString, Polygon};
+
+const POINT: u32 = 1;
+const LINE_STRING: u32 = 2;
+const POLYGON: u32 = 3;
+const MULTI_POINT: u32 = 4;
+const MULTI_LINE_STRING: u32 = 5;
+const MULTI_POLYGON: u32 = 6;
+const GEOMETRY_COLLECTION: u32 = 7;
+
+/// EWKB flags in the type code.
+const EWKB_Z: u32 = 0x8000_0000;
+const EWKB_M: u32 = 0x4000_0000;
+const EWKB_SRID: u32 = 0x2000_0000;
+
+fn malformed() -> polars_error::PolarsError {
+    polars_err!(ComputeError: "malformed WKB geometry")
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    little_endian: bool,
+}
+
+impl Reader<'_> {
+    fn take<const N: usize>(&mut self) -> PolarsResult<[u8; N]> {
+        let Some((head, rest)) = self.bytes.split_first_chunk::<N>() else {
+            return Err(malformed());
+        };
+        self.bytes = rest;
+        Ok(*head)
+    }
+
+    fn u32(&mut self) -> PolarsResult<u32> {
+        let b = self.take::<4>()?;
+        Ok(if self.little_endian {
+            u32::from_le_bytes(b)
+        } else {
+            u32::from_be_bytes(b)
+        })
+    }
+
+    fn f64(&mut self) -> PolarsResult<f64> {
+        let b = self.take::<8>()?;
+        Ok(if self.little_endian {
+            f64::from_le_bytes(b)
+        } else {
+            f64::from_be_bytes(b)
+        })
+    }
+
+    /// Reads a count, checking it against the remaining bytes to not allocate for garbage.
+    fn len(&mut self, min_item_size: usize) -> PolarsResult<usize> {
+        let n = self.u32()? as usize;
+        if n.saturating_mul(min_item_size) > self.bytes.len() {
+            return Err(malformed());
+        }
+        Ok(n)
+    }
+
+    fn coord(&mut self, dims: usize) -> PolarsResult<Coord> {
+        let c = Coord::new(self.f64()?, self.f64()?);
+        for _ in 2..dims {
+            self.f64()?;
+        }
+        Ok(c)
+    }
+
+    fn coords(&mut self, dims: usize) -> PolarsResult<LineString> {
+        let n = self.len(8 * dims)?;
+        (0..n).map(|_| self.coord(dims)).collect()
+    }
+
+    fn rings(&mut self, dims: usize) -> PolarsResult<Polygon> {
+        let n = self.len(4)?;
+        (0..n).map(|_| self.coords(dims)).collect()
+    }
+
+    fn geometry(&mut self) -> PolarsResult<Geometry> {
+        self.little_endian = match self.take::<1>()? {
+            [0] => false,
+            [1] => true,
+            _ => return Err(malformed()),
+        };
+        let code = self.u32()?;
+        if code & EWKB_SRID != 0 {
+            self.u32()?;
+        }
+        let ewkb_dims = 2 + (code & EWKB_Z != 0) as usize + (code & EWKB_M != 0) as usize;
+        let code = code & 0x0FFF_FFFF;
+        // ISO WKB adds 1000 for Z, 2000 for M and 3000 for ZM.
+        let dims = match code / 1000 {
+            0 => ewkb_dims,
+            1 | 2 => 3,
+            3 => 4,
+            _ => return Err(malformed()),
+        };
+
+        Ok(match code % 1000 {
+            POINT => {
+                let c = self.coord(dims)?;
+                // Empty points are encoded with NaN coordinates.
+                Geometry::Point((!(c.x.is_nan() && c.y.is_nan())).then_some(c))
+            },
+            LINE_STRING => Geometry::LineString(self.coords(dims)?),
+            POLYGON => Geometry::Polygon(self.rings(dims)?),
+            MULTI_POINT => Geometry::MultiPoint(
+                self.parts(|g| match g {
+                    Geometry::Point(p) => Ok(p),
+                    _ => Err(malformed()),
+                })?
+                .into_iter()
+                .flatten()
+                .collect(),
+            ),
+            MULTI_LINE_STRING => Geometry::MultiLineString(self.parts(|g| match g {
+                Geometry::LineString(l) => Ok(l),
+                _ => Err(malformed()),
+            })?),
+            MULTI_POLYGON => Geometry::MultiPolygon(self.parts(|g| match g {
+                Geometry::Polygon(p) => Ok(p),
+                _ => Err(malformed()),
+            })?),
+            GEOMETRY_COLLECTION => Geometry::GeometryCollection(self.parts(Ok)?),
+            t => polars_bail!(ComputeError: "unsupported WKB geometry type {}", t),
+        })
+    }
+
+    fn parts<T>(&mut self, f: impl Fn(Geometry) -> PolarsResult<T>) -> PolarsResult<Vec<T>> {
+        let n = self.len(5)?;
+        (0..n).map(|_| f(self.geometry()?)).collect()
+    }
+}
+
+fn write_u32(out: &mut Vec<u8>, v: u32) {
+    out.extend_from_slice(&v.to_le_bytes());
+}
+
+fn write_coord(out: &mut Vec<u8>, c: Coord) {
+    out.extend_from_slice(&c.x.to_le_bytes());
+    out.extend_from_slice(&c.y.to_le_bytes());
+}
+
+fn write_header(out: &mut Vec<u8>, code: u32) {
+    out.push(1);
+    write_u32(out, code);
+}
+
+fn write_coords(out: &mut Vec<u8>, coords: &[Coord]) {
+    write_u32(out, coords.len() as u32);
+    coords.iter().for_each(|c| write_coord(out, *c));
+}
+
+fn write_rings(out: &mut Vec<u8>, rings: &[LineString]) {
+    write_u32(out, rings.len() as u32);
+    rings.iter().for_each(|r| write_coords(out, r));
+}
+
+impl Geometry {
+    /// Parses a geometry from (ISO or extended) WKB. Z and M values are dropped.
+    pub fn from_wkb(bytes: &[u8]) -> PolarsResult<Self> {
+        let mut reader = Reader {
+            bytes,
+            little_endian: true,
+        };
+        reader.geometry()
+    }
+
+    /// Writes the geometry as little-endian 2D WKB.
+    pub fn write_wkb(&self, out: &mut Vec<u8>) {
+        match self {
+            Geometry::Point(p) => {
+                write_header(out, POINT);
+                write_coord(out, p.unwrap_or(Coord::new(f64::NAN, f64::NAN)));
+            },
+            Geometry::LineString(line) => {
+                write_header(out, LINE_STRING);
+                write_coords(out, line);
+            },
+            Geometry::Polygon(rings) => {
+                write_header(out, POLYGON);
+                write_rings(out, rings);
+            },
+            Geometry::MultiPoint(points) => {
+                write_header(out, MULTI_POINT);
+                write_u32(out, points.len() as u32);
+                for p in points {
+                    write_header(out, POINT);
+                    write_coord(out, *p);
+                }
+            },
+            Geometry::MultiLineString(lines) => {
+                write_header(out, MULTI_LINE_STRING);
+                write_u32(out, lines.len() as u32);
+                for line in lines {
+                    write_header(out, LINE_STRING);
+                    write_coords(out, line);
+                }
+            },
+            Geometry::MultiPolygon(polygons) => {
+                write_header(out, MULTI_POLYGON);
+                write_u32(out, polygons.len() as u32);
+                for rings in polygons {
+                    write_header(out, POLYGON);
+                    write_rings(out, rings);
+                }
+            },
+            Geometry::GeometryCollection(geometries) => {
+                write_header(out, GEOMETRY_COLLECTION);
+                write_u32(out, geometries.len() as u32);
+                geometries.iter().for_each(|g| g.write_wkb(out));
+            },
+        }
+    }
+
+    pub fn to_wkb(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        self.write_wkb(&mut out);
+        out
+    }
+}
@@ -0,0 +1,223 @@
+use std::fmt::Write;
+
+use polars_error::{PolarsResult, polars_bail};
+
+use super::{Coord, Geometry, LineString, Polygon};
+
+struct Parser<'a> {
+    s: &'a str,
+    input: &'a str,
+}
+
+impl<'a> Parser<'a> {
+    fn skip_whitespace(&mut self) {
+        self.s = self.s.trim_start();
+    }
+
+    fn error<T>(&self) -> PolarsResult<T> {
+        polars_bail!(ComputeError: "invalid WKT geometry '{}'", self.input)
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.skip_whitespace();
+        self.s.chars().next()
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.s = &self.s[1..];
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, c: char) -> PolarsResult<()> {
+        if self.eat(c) { Ok(()) } else { self.error() }
+    }
+
+    fn word(&mut self) -> Option<&'a str> {
+        self.skip_whitespace();
+        let end = self
+            .s
+            .find(|c: char| !c.is_ascii_alphabetic())
+            .unwrap_or(self.s.len());
+        let (word, rest) = self.s.split_at(end);
+        self.s = rest;
+        (!word.is_empty()).then_some(word)
+    }
+
+    /// Consumes `EMPTY` if it comes next.
+    fn eat_empty(&mut self) -> bool {
+        let s = self.s;
+        match self.word() {
+            Some(w) if w.eq_ignore_ascii_case("EMPTY") => true,
+            _ => {
+                self.s = s;
+                false
+            },
+        }
+    }
+
+    fn number(&mut self) -> Option<f64> {
+        self.skip_whitespace();
+        let end = self
+            .s
+            .find(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '+')))
+            .unwrap_or(self.s.len());
+        let v = self.s[..end].parse().ok()?;
+        self.s = &self.s[end..];
+        Some(v)
+    }
+
+    /// Parses a coordinate, dropping Z and M values.
+    fn coord(&mut self) -> PolarsResult<Coord> {
+        let (Some(x), Some(y)) = (self.number(), self.number()) else {
+            return self.error();
+        };
+        while self.number().is_some() {}
+        Ok(Coord::new(x, y))
+    }
+
+    /// Parses a parenthesized list of items, or `EMPTY`.
+    fn list<T>(
+        &mut self,
+        mut item: impl FnMut(&mut Self) -> PolarsResult<T>,
+    ) -> PolarsResult<Vec<T>> {
+        if self.eat_empty() {
+            return Ok(Vec::new());
+        }
+        self.expect('(')?;
+        let mut out = vec![item(self)?];
+        while self.eat(',') {
+            out.push(item(self)?);
+        }
+        self.expect(')')?;
+        Ok(out)
+    }
+
+    fn line(&mut self) -> PolarsResult<LineString> {
+        self.list(Self::coord)
+    }
+
+    fn polygon(&mut self) -> PolarsResult<Polygon> {
+        self.list(Self::line)
+    }
+
+    fn geometry(&mut self) -> PolarsResult<Geometry> {
+        let Some(kind) = self.word() else {
+            return self.error();
+        };
+        let kind = kind.to_ascii_uppercase();
+        // Skip a dimension qualifier, the coordinates tell the dimension.
+        let s = self.s;
+        match self.word() {
+            Some(w) if ["Z", "M", "ZM"].iter().any(|d| w.eq_ignore_ascii_case(d)) => {},
+            _ => self.s = s,
+        }
+
+        Ok(match kind.as_str() {
+            "POINT" => {
+                if self.eat_empty() {
+                    Geometry::Point(None)
+                } else {
+                    self.expect('(')?;
+                    let c = self.coord()?;
+                    self.expect(')')?;
+                    Geometry::Point(Some(c))
+                }
+            },
+            "LINESTRING" => Geometry::LineString(self.line()?),
+            "POLYGON" => Geometry::Polygon(self.polygon()?),
+            "MULTIPOINT" => {
+                // Points may or may not be parenthesized.
+                let points = self.list(|p| {
+                    if p.eat_empty() {
+                        Ok(None)
+                    } else if p.eat('(') {
+                        let c = p.coord()?;
+                        p.expect(')')?;
+                        Ok(Some(c))
+                    } else {
+                        p.coord().map(Some)
+                    }
+                })?;
+                Geometry::MultiPoint(points.into_iter().flatten().collect())
+            },
+            "MULTILINESTRING" => Geometry::MultiLineString(self.list(Self::line)?),
+            "MULTIPOLYGON" => Geometry::MultiPolygon(self.list(Self::polygon)?),
+            "GEOMETRYCOLLECTION" => Geometry::GeometryCollection(self.list(Self::geometry)?),
+            _ => return self.error(),
+        })
+    }
+}
+
+fn write_coord(out: &mut String, c: Coord) {
+    write!(out, "{} {}", c.x, c.y).unwrap();
+}
+
+fn write_list<T>(out: &mut String, items: &[T], mut f: impl FnMut(&mut String, &T)) {
+    if items.is_empty() {
+        out.push_str("EMPTY");
+        return;
+    }
+    out.push('(');
+    for (i, item) in items.iter().enumerate() {
+        if i > 0 {
+            out.push_str(", ");
+        }
+        f(out, item);
+    }
+    out.push(')');
+}
+
+fn write_line(out: &mut String, line: &LineString) {
+    write_list(out, line, |out, c| write_coord(out, *c));
+}
+
+fn write_polygon(out: &mut String, rings: &Polygon) {
+    write_list(out, rings, write_line);
+}
+
+impl Geometry {
+    /// Parses a geometry from WKT. Z and M values are dropped.
+    pub fn from_wkt(wkt: &str) -> PolarsResult<Self> {
+        let mut parser = Parser { s: wkt, input: wkt };
+        let geometry = parser.geometry()?;
+        if parser.peek().is_some() {
+            return parser.error();
+        }
+        Ok(geometry)
+    }
+
+    pub fn write_wkt(&self, out: &mut String) {
+        out.push_str(&self.type_name().to_ascii_uppercase());
+        out.push(' ');
+        match self {
+            Geometry::Point(None) => out.push_str("EMPTY"),
+            Geometry::Point(Some(c)) => {
+                out.push('(');
+                write_coord(out, *c);
+                out.push(')');
+            },
+            Geometry::LineString(line) => write_line(out, line),
+            Geometry::Polygon(rings) => write_polygon(out, rings),
+            Geometry::MultiPoint(points) => write_list(out, points, |out, c| {
+                out.push('(');
+                write_coord(out, *c);
+                out.push(')');
+            }),
+            Geometry::MultiLineString(lines) => write_list(out, lines, write_line),
+            Geometry::MultiPolygon(polygons) => write_list(out, polygons, write_polygon),
+            Geometry::GeometryCollection(geometries) => {
+                write_list(out, geometries, |out, g| g.write_wkt(out))
+            },
+        }
+    }
+
+    pub fn to_wkt(&self) -> String {
+        let mut out = String::new();
+        self.write_wkt(&mut out);
+        out
+    }
+}
@@ -20,6 +20,8 @@ pub mod find_validity_mismatch;
 pub mod float_sum;
 #[cfg(feature = "gather")]
 pub mod gather;
+#[cfg(feature = "geo")]
+pub mod geo;
 pub mod horizontal_flatten;
 #[cfg(feature = "approx_unique")]
 pub mod hyperloglogplus;
@@ -34,6 +36,8 @@ pub mod size;
 pub mod sum;
 pub mod trim_lists_to_normalized_offsets;
 pub mod unique;
+#[cfg(feature = "variant")]
+pub mod variant;
 
 // Trait to enable the scalar blanket implementation.
 pub trait NotSimdPrimitive: NativeType {}
@@ -0,0 +1,326 @@
+use arrow::array::{
+    Array, BinaryArray, BinaryViewArray, BooleanArray, FixedSizeBinaryArray, ListArray,
+    MutableBinaryViewArray, PrimitiveArray, StructArray, Utf8Array, Utf8ViewArray,
+};
+use arrow::datatypes::{
+    ArrowDataType, ExtensionType, Field, PARQUET_VARIANT_EXTENSION_NAME, TimeUnit,
+};
+use polars_error::{PolarsResult, polars_bail, polars_err};
+use polars_utils::pl_str::PlSmallStr;
+
+use super::{VariantMetadata, VariantRef, VariantValue, split_variant};
+
+/// An encoded empty metadata dictionary directly followed by a variant null.
+pub(super) const NULL_VARIANT: &[u8] = &[0x01, 0x00, 0x00, 0x00];
+
+/// The Arrow type of an unshredded variant: an extension over a struct of the `metadata` and
+/// `value` binaries.
+pub fn variant_arrow_dtype() -> ArrowDataType {
+    wrap_extension(vec![
+        Field::new(
+            PlSmallStr::from_static("metadata"),
+            ArrowDataType::BinaryView,
+            false,
+        ),
+        Field::new(
+            PlSmallStr::from_static("value"),
+            ArrowDataType::BinaryView,
+            false,
+        ),
+    ])
+}
+
+pub(super) fn wrap_extension(fields: Vec<Field>) -> ArrowDataType {
+    ArrowDataType::Extension(Box::new(ExtensionType {
+        name: PlSmallStr::from_static(PARQUET_VARIANT_EXTENSION_NAME),
+        inner: ArrowDataType::Struct(fields),
+        metadata: None,
+    }))
+}
+
+/// Splits stored variants into the `metadata` and `value` struct of [`variant_arrow_dtype`].
+pub fn variant_to_struct(arr: &BinaryViewArray) -> PolarsResult<StructArray> {
+    let mut metadata = MutableBinaryViewArray::<[u8]>::with_capacity(arr.len());
+    let mut value = MutableBinaryViewArray::<[u8]>::with_capacity(arr.len());
+    for opt in arr.iter() {
+        let (m, v) = split_variant(opt.unwrap_or(NULL_VARIANT))?;
+        metadata.push_value(m);
+        value.push_value(v);
+    }
+    StructArray::try_new(
+        variant_arrow_dtype(),
+        arr.len(),
+        vec![metadata.freeze().boxed(), value.freeze().boxed()],
+        arr.validity().cloned(),
+    )
+}
+
+/// Converts a variant struct read from Arrow or Parquet back into stored variants.
+///
+/// Shredded variants, which store parts of the value in a `typed_value` column, are reassembled.
+pub fn struct_to_variant(arr: &StructArray) -> PolarsResult<BinaryViewArray> {
+    let field = |name: &str| {
+        arr.fields()
+            .iter()
+            .position(|f| f.name == name)
+            .map(|i| arr.values()[i].as_ref())
+    };
+    let metadata = binary_values(
+        field("metadata")
+            .ok_or_else(|| polars_err!(ComputeError: "variant struct has no 'metadata' field"))?,
+    )?;
+    let value = field("value").map(binary_values).transpose()?;
+    let typed_value = field("typed_value");
+
+    let mut out = MutableBinaryViewArray::<[u8]>::with_capacity(arr.len());
+    for row in 0..arr.len() {
+        if !arr.is_valid(row) {
+            out.push_null();
+            continue;
+        }
+        let metadata = VariantMetadata::try_new(metadata.value(row))?;
+
+        // Unshredded values can be copied as is.
+        if typed_value.is_none_or(|t| !t.is_valid(row)) {
+            match value.as_ref().filter(|v| v.is_valid(row)) {
+                Some(v) => {
+                    let value = VariantRef::from_parts(metadata, v.value(row))?;
+                    out.push_value(value.to_bytes());
+                },
+                None => out.push_value(NULL_VARIANT),
+            }
+            continue;
+        }
+
+        let value = unshred(metadata, value.as_ref(), typed_value, row)?;
+        out.push_value(value.unwrap_or(VariantValue::Null).encode());
+    }
+    Ok(out.freeze())
+}
+
+fn binary_values(arr: &dyn Array) -> PolarsResult<BinaryViewArray> {
+    Ok(match arr.dtype().to_storage() {
+        ArrowDataType::BinaryView => arr
+            .as_any()
+            .downcast_ref::<BinaryViewArray>()
+            .unwrap()
+            .clone(),
+        ArrowDataType::Binary => {
+            let arr = arr.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
+            MutableBinaryViewArray::<[u8]>::from_iter(arr.iter()).freeze()
+        },
+        ArrowDataType::LargeBinary => {
+            let arr = arr.as_any().downcast_ref::<BinaryArray<i64>>().unwrap();
+            MutableBinaryViewArray::<[u8]>::from_iter(arr.iter()).freeze()
+        },
+        dt => polars_bail!(ComputeError: "variant binary field has unexpected type {:?}", dt),
+    })
+}
+
+/// Reassembles the value of a shredded variant (group) at `row`. Returns `None` if the value is
+/// missing.
+fn unshred(
+    metadata: VariantMetadata<'_>,
+    value: Option<&BinaryViewArray>,
+    typed_value: Option<&dyn Array>,
+    row: usize,
+) -> PolarsResult<Option<VariantValue>> {
+    let residual = value
+        .filter(|v| v.is_valid(row))
+        .map(|v| VariantRef::from_parts(metadata, v.value(row)))
+        .transpose()?;
+    let Some(typed_value) = typed_value.filter(|t| t.is_valid(row)) else {
+        return residual.map(|r| r.to_value()).transpose();
+    };
+
+    let value = match typed_value.dtype().to_storage() {
+        ArrowDataType::Struct(_) => {
+            let typed = typed_value.as_any().downcast_ref::<StructArray>().unwrap();
+            let mut fields = Vec::with_capacity(typed.fields().len());
+            for (field, group) in typed.fields().iter().zip(typed.values()) {
+                if let Some(v) = unshred_group(metadata, group.as_ref(), row)? {
+                    fields.push((field.name.to_string(), v));
+                }
+            }
+            // A partially shredded object stores the remaining fields in `value`.
+            if let Some(residual) = residual {
+                match residual.to_value()? {
+                    VariantValue::Object(rest) => fields.extend(rest),
+                    _ => polars_bail!(
+                        ComputeError: "malformed variant: shredded object has a non-object residual"
+                    ),
+                }
+            }
+            VariantValue::Object(fields)
+        },
+        ArrowDataType::LargeList(_) => {
+            let typed = typed_value
+                .as_any()
+                .downcast_ref::<ListArray<i64>>()
+                .unwrap();
+            unshred_list(
+                metadata,
+                typed.values().as_ref(),
+                typed.offsets().start_end(row),
+            )?
+        },
+        ArrowDataType::List(_) => {
+            let typed = typed_value
+                .as_any()
+                .downcast_ref::<ListArray<i32>>()
+                .unwrap();
+            unshred_list(
+                metadata,
+                typed.values().as_ref(),
+                typed.offsets().start_end(row),
+            )?
+        },
+        _ => typed_to_value(typed_value, row)?,
+    };
+    Ok(Some(value))
+}
+
+/// Reassembles the `{value, typed_value}` group of a shredded object field or array element.
+fn unshred_group(
+    metadata: VariantMetadata<'_>,
+    group: &dyn Array,
+    row: usize,
+) -> PolarsResult<Option<VariantValue>> {
+    let Some(group) = group.as_any().downcast_ref::<StructArray>() else {
+        polars_bail!(ComputeError: "malformed variant: shredded field is not a group")
+    };
+    if !group.is_valid(row) {
+        return Ok(None);
+    }
+    let field = |name: &str| {
+        group
+            .fields()
+            .iter()
+            .position(|f| f.name == name)
+            .map(|i| group.values()[i].as_ref())
+    };
+    let value = field("value").map(binary_values).transpose()?;
+    unshred(metadata, value.as_ref(), field("typed_value"), row)
+}
+
+fn unshred_list(
+    metadata: VariantMetadata<'_>,
+    elements: &dyn Array,
+    (start, end): (usize, usize),
+) -> PolarsResult<VariantValue> {
+    let values = (start..end)
+        .map(|i| Ok(unshred_group(metadata, elements, i)?.unwrap_or(VariantValue::Null)))
+        .collect::<PolarsResult<_>>()?;
+    Ok(VariantValue::Array(values))
+}
+
+/// Converts a non-null value of a shredded primitive column.
+fn typed_to_value(arr: &dyn Array, row: usize) -> PolarsResult<VariantValue> {
+    fn prim<T: arrow::types::NativeType>(arr: &dyn Array, row: usize) -> T {
+        arr.as_any()
+            .downcast_ref::<PrimitiveArray<T>>()
+            .unwrap()
+            .value(row)
+    }
+
+    use ArrowDataType as D;
+    Ok(match arr.dtype().to_storage() {
+        D::Boolean => VariantValue::Boolean(
+            arr.as_any()
+                .downcast_ref::<BooleanArray>()
+                .unwrap()
+                .value(row),
+        ),
+        D::Int8 => VariantValue::Int8(prim(arr, row)),
+        D::Int16 => VariantValue::Int16(prim(arr, row)),
+        D::Int32 => VariantValue::Int32(prim(arr, row)),
+        D::Int64 => VariantValue::Int64(prim(arr, row)),
+        D::Float32 => VariantValue::Float(prim(arr, row)),
+        D::Float64 => VariantValue::Double(prim(arr, row)),
+        D::Decimal32(_, scale) => VariantValue::Decimal4 {
+            value: prim(arr, row),
+            scale: *scale as u8,
+        },
+        D::Decimal64(_, scale) => VariantValue::Decimal8 {
+            value: prim(arr, row),
+            scale: *scale as u8,
+        },
+        D::Decimal(_, scale) => VariantValue::Decimal16 {
+            value: prim(arr, row),
+            scale: *scale as u8,
+        },
+        D::Date32 => VariantValue::Date(prim(arr, row)),
+        D::Time64(TimeUnit::Microsecond) => VariantValue::TimeNtz(prim(arr, row)),
+        D::Time64(TimeUnit::Nanosecond) => VariantValue::TimeNtz(prim::<i64>(arr, row) / 1000),
+        D::Timestamp(unit, tz) => {
+            let v: i64 = prim(arr, row);
+            match (unit, tz.is_some()) {
+                (TimeUnit::Nanosecond, true) => VariantValue::TimestampNanos(v),
+                (TimeUnit::Nanosecond, false) => VariantValue::TimestampNtzNanos(v),
+                (unit, utc) => {
+                    let v = match unit {
+                        TimeUnit::Second => v * 1_000_000,
+                        TimeUnit::Millisecond => v * 1_000,
+                        _ => v,
+                    };
+                    if utc {
+                        VariantValue::Timestamp(v)
+                    } else {
+                        VariantValue::TimestampNtz(v)
+                    }
+                },
+            }
+        },
+        D::Utf8View => VariantValue::String(
+            arr.as_any()
+                .downcast_ref::<Utf8ViewArray>()
+                .unwrap()
+                .value(row)
+                .to_string(),
+        ),
+        D::Utf8 => VariantValue::String(
+            arr.as_any()
+                .downcast_ref::<Utf8Array<i32>>()
+                .unwrap()
+                .value(row)
+                .to_string(),
+        ),
+        D::LargeUtf8 => VariantValue::String(
+            arr.as_any()
+                .downcast_ref::<Utf8Array<i64>>()
+                .unwrap()
+                .value(row)
+                .to_string(),
+        ),
+        D::BinaryView => VariantValue::Binary(
+            arr.as_any()
+                .downcast_ref::<BinaryViewArray>()
+                .unwrap()
+                .value(row)
+                .to_vec(),
+        ),
+        D::Binary => VariantValue::Binary(
+            arr.as_any()
+                .downcast_ref::<BinaryArray<i32>>()
+                .unwrap()
+                .value(row)
+                .to_vec(),
+        ),
+        D::LargeBinary => VariantValue::Binary(
+            arr.as_any()
+                .downcast_ref::<BinaryArray<i64>>()
+                .unwrap()
+                .value(row)
+                .to_vec(),
+        ),
+        D::FixedSizeBinary(16) => VariantValue::Uuid(
+            arr.as_any()
+                .downcast_ref::<FixedSizeBinaryArray>()
+                .unwrap()
+                .value(row)
+                .try_into()
+                .unwrap(),
+        ),
+        dt => polars_bail!(ComputeError: "unsupported shredded variant type {:?}", dt),
+    })
+}
@@ -0,0 +1,296 @@
+use polars_error::PolarsResult;
+
+use super::metadata::write_metadata;
+use super::{
+    BASIC_TYPE_ARRAY, BASIC_TYPE_OBJECT, BASIC_TYPE_PRIMITIVE, BASIC_TYPE_SHORT_STRING,
+    MAX_SHORT_STRING_LEN, VariantPrimitive, VariantRef, VariantView, int_size, primitive,
+    write_uint,
+};
+
+/// An owned variant value that can be encoded.
+#[derive(Clone, Debug, PartialEq)]
+pub enum VariantValue {
+    Null,
+    Boolean(bool),
+    Int8(i8),
+    Int16(i16),
+    Int32(i32),
+    Int64(i64),
+    Float(f32),
+    Double(f64),
+    Decimal4 {
+        value: i32,
+        scale: u8,
+    },
+    Decimal8 {
+        value: i64,
+        scale: u8,
+    },
+    Decimal16 {
+        value: i128,
+        scale: u8,
+    },
+    Date(i32),
+    Timestamp(i64),
+    TimestampNtz(i64),
+    TimestampNanos(i64),
+    TimestampNtzNanos(i64),
+    TimeNtz(i64),
+    Binary(Vec<u8>),
+    String(String),
+    Uuid([u8; 16]),
+    Array(Vec<VariantValue>),
+    /// Fields of an object, if a key occurs more than once the last value wins.
+    Object(Vec<(String, VariantValue)>),
+}
+
+impl VariantValue {
+    /// An integer stored in the smallest type that fits it.
+    pub fn int(v: i64) -> Self {
+        if let Ok(v) = i8::try_from(v) {
+            Self::Int8(v)
+        } else if let Ok(v) = i16::try_from(v) {
+            Self::Int16(v)
+        } else if let Ok(v) = i32::try_from(v) {
+            Self::Int32(v)
+        } else {
+            Self::Int64(v)
+        }
+    }
+
+    /// Encodes this value as a stored variant, the metadata directly followed by the value.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut keys = Vec::new();
+        self.collect_keys(&mut keys);
+        keys.sort_unstable();
+        keys.dedup();
+
+        let mut out = Vec::new();
+        write_metadata(&mut out, &keys);
+        self.write_value(&mut out, &keys);
+        out
+    }
+
+    fn collect_keys<'a>(&'a self, keys: &mut Vec<&'a str>) {
+        match self {
+            Self::Array(values) => values.iter().for_each(|v| v.collect_keys(keys)),
+            Self::Object(fields) => {
+                for (k, v) in fields {
+                    keys.push(k.as_str());
+                    v.collect_keys(keys);
+                }
+            },
+            _ => {},
+        }
+    }
+
+    fn write_value(&self, out: &mut Vec<u8>, keys: &[&str]) {
+        let header = |type_id: u8| (type_id << 2) | BASIC_TYPE_PRIMITIVE;
+        match self {
+            Self::Null => out.push(header(primitive::NULL)),
+            Self::Boolean(true) => out.push(header(primitive::TRUE)),
+            Self::Boolean(false) => out.push(header(primitive::FALSE)),
+            Self::Int8(v) => {
+                out.push(header(primitive::INT8));
+                out.extend_from_slice(&v.to_le_bytes());
+            },
+            Self::Int16(v) => {
+                out.push(header(primitive::INT16));
+                out.extend_from_slice(&v.to_le_bytes());
+            },
+            Self::Int32(v) => {
+                out.push(header(primitive::INT32));
+                out.extend_from_slice(&v.to_le_bytes());
+            },
+            Self::Int64(v) => {
+                out.push(header(primitive::INT64));
+                out.extend_from_slice(&v.to_le_bytes());
+            },
+            Self::Float(v) => {
+                out.push(header(primitive::FLOAT));
+                out.extend_from_slice(&v.to_le_bytes());
+            },
+            Self::Double(v) => {
+                out.push(header(primitive::DOUBLE));
+                out.extend_from_slice(&v.to_le_bytes());
+            },
+            Self::Decimal4 { value, scale } => {
+                out.extend_from_slice(&[header(primitive::DECIMAL4), *scale]);
+                out.extend_from_slice(&value.to_le_bytes());
+            },
+            Self::Decimal8 { value, scale } => {
+                out.extend_from_slice(&[header(primitive::DECIMAL8), *scale]);
+                out.extend_from_slice(&value.to_le_bytes());
+            },
+            Self::Decimal16 { value, scale } => {
+                out.extend_from_slice(&[header(primitive::DECIMAL16), *scale]);
+                out.extend_from_slice(&value.to_le_bytes());
+            },
+            Self::Date(v) => {
+                out.push(header(primitive::DATE));
+                out.extend_from_slice(&v.to_le_bytes());
+            },
+            Self::Timestamp(v) => {
+                out.push(header(primitive::TIMESTAMP));
+                out.extend_from_slice(&v.to_le_bytes());
+            },
+            Self::TimestampNtz(v) => {
+                out.push(header(primitive::TIMESTAMP_NTZ));
+                out.extend_from_slice(&v.to_le_bytes());
+            },
+            Self::TimestampNanos(v) => {
+                out.push(header(primitive::TIMESTAMP_NANOS));
+                out.extend_from_slice(&v.to_le_bytes());
+            },
+            Self::TimestampNtzNanos(v) => {
+                out.push(header(primitive::TIMESTAMP_NTZ_NANOS));
+                out.extend_from_slice(&v.to_le_bytes());
+            },
+            Self::TimeNtz(v) => {
+                out.push(header(primitive::TIME_NTZ));
+                out.extend_from_slice(&v.to_le_bytes());
+            },
+            Self::Binary(v) => {
+                out.push(header(primitive::BINARY));
+                write_uint(out, v.len(), 4);
+                out.extend_from_slice(v);
+            },
+            Self::String(v) if v.len() <= MAX_SHORT_STRING_LEN => {
+                out.push(((v.len() as u8) << 2) | BASIC_TYPE_SHORT_STRING);
+                out.extend_from_slice(v.as_bytes());
+            },
+            Self::String(v) => {
+                out.push(header(primitive::STRING));
+                write_uint(out, v.len(), 4);
+                out.extend_from_slice(v.as_bytes());
+            },
+            Self::Uuid(v) => {
+                out.push(header(primitive::UUID));
+                out.extend_from_slice(v);
+            },
+            Self::Array(values) => {
+                let encoded = values
+                    .iter()
+                    .map(|v| {
+                        let mut buf = Vec::new();
+                        v.write_value(&mut buf, keys);
+                        buf
+                    })
+                    .collect::<Vec<_>>();
+                let elements = encoded.iter().map(|v| v.as_slice()).collect::<Vec<_>>();
+                write_array_raw(out, &elements);
+            },
+            Self::Object(fields) => {
+                // Sort by key, keeping the last occurrence of duplicate keys.
+                let mut sorted = fields.iter().rev().collect::<Vec<_>>();
+                sorted.sort_by(|a, b| a.0.cmp(&b.0));
+                sorted.dedup_by(|a, b| a.0 == b.0);
+
+                let encoded = sorted
+                    .iter()
+                    .map(|(k, v)| {
+                        let id = keys.binary_search(&k.as_str()).unwrap();
+                        let mut buf = Vec::new();
+                        v.write_value(&mut buf, keys);
+                        (id, buf)
+                    })
+                    .collect::<Vec<_>>();
+                let fields = encoded
+                    .iter()
+                    .map(|(id, v)| (*id, v.as_slice()))
+                    .collect::<Vec<_>>();
+                write_object_raw(out, &fields);
+            },
+        }
+    }
+}
+
+/// Writes an object from already encoded field values. The fields must be sorted by the keys
+/// their ids refer to.
+pub(super) fn write_object_raw(out: &mut Vec<u8>, fields: &[(usize, &[u8])]) {
+    let num = fields.len();
+    let max_id = fields.iter().map(|(id, _)| *id).max().unwrap_or(0);
+    let total: usize = fields.iter().map(|(_, v)| v.len()).sum();
+    let is_large = num > u8::MAX as usize;
+    let id_size = int_size(max_id);
+    let offset_size = int_size(total);
+
+    let value_header =
+        ((is_large as u8) << 4) | (((id_size - 1) as u8) << 2) | (offset_size - 1) as u8;
+    out.push((value_header << 2) | BASIC_TYPE_OBJECT);
+    write_uint(out, num, if is_large { 4 } else { 1 });
+    for (id, _) in fields {
+        write_uint(out, *id, id_size);
+    }
+    let mut offset = 0;
+    write_uint(out, offset, offset_size);
+    for (_, v) in fields {
+        offset += v.len();
+        write_uint(out, offset, offset_size);
+    }
+    for (_, v) in fields {
+        out.extend_from_slice(v);
+    }
+}
+
+/// Writes an array from already encoded element values.
+pub(super) fn write_array_raw(out: &mut Vec<u8>, elements: &[&[u8]]) {
+    let num = elements.len();
+    let total: usize = elements.iter().map(|v| v.len()).sum();
+    let is_large = num > u8::MAX as usize;
+    let offset_size = int_size(total);
+
+    let value_header = ((is_large as u8) << 2) | (offset_size - 1) as u8;
+    out.push((value_header << 2) | BASIC_TYPE_ARRAY);
+    write_uint(out, num, if is_large { 4 } else { 1 });
+    let mut offset = 0;
+    write_uint(out, offset, offset_size);
+    for v in elements {
+        offset += v.len();
+        write_uint(out, offset, offset_size);
+    }
+    for v in elements {
+        out.extend_from_slice(v);
+    }
+}
+
+impl VariantRef<'_> {
+    /// Decodes this value into an owned [`VariantValue`].
+    pub fn to_value(&self) -> PolarsResult<VariantValue> {
+        use VariantPrimitive as P;
+        Ok(match self.view()? {
+            VariantView::Primitive(p) => match p {
+                P::Null => VariantValue::Null,
+                P::Boolean(v) => VariantValue::Boolean(v),
+                P::Int8(v) => VariantValue::Int8(v),
+                P::Int16(v) => VariantValue::Int16(v),
+                P::Int32(v) => VariantValue::Int32(v),
+                P::Int64(v) => VariantValue::Int64(v),
+                P::Float(v) => VariantValue::Float(v),
+                P::Double(v) => VariantValue::Double(v),
+                P::Decimal4 { value, scale } => VariantValue::Decimal4 { value, scale },
+                P::Decimal8 { value, scale } => VariantValue::Decimal8 { value, scale },
+                P::Decimal16 { value, scale } => VariantValue::Decimal16 { value, scale },
+                P::Date(v) => VariantValue::Date(v),
+                P::Timestamp(v) => VariantValue::Timestamp(v),
+                P::TimestampNtz(v) => VariantValue::TimestampNtz(v),
+                P::TimestampNanos(v) => VariantValue::TimestampNanos(v),
+                P::TimestampNtzNanos(v) => VariantValue::TimestampNtzNanos(v),
+                P::TimeNtz(v) => VariantValue::TimeNtz(v),
+                P::Binary(v) => VariantValue::Binary(v.to_vec()),
+                P::String(v) => VariantValue::String(v.to_string()),
+                P::Uuid(v) => VariantValue::Uuid(v),
+            },
+            VariantView::Array(arr) => VariantValue::Array(
+                (0..arr.len())
+                    .map(|i| arr.get(i)?.to_value())
+                    .collect::<PolarsResult<_>>()?,
+            ),
+            VariantView::Object(obj) => VariantValue::Object(
+                (0..obj.len())
+                    .map(|i| Ok((obj.key(i)?.to_string(), obj.value(i)?.to_value()?)))
+                    .collect::<PolarsResult<_>>()?,
+            ),
+        })
+    }
+}
@@ -0,0 +1,212 @@
+use std::fmt::Write;
+
+use polars_error::{PolarsResult, polars_err};
+
+use super::{VariantPrimitive, VariantRef, VariantValue, VariantView};
+
+/// Parses a JSON document into a stored variant.
+pub fn json_to_variant(json: &[u8]) -> PolarsResult<Vec<u8>> {
+    let value: serde_json::Value = serde_json::from_slice(json)
+        .map_err(|e| polars_err!(ComputeError: "invalid JSON for variant: {}", e))?;
+    Ok(from_json(value).encode())
+}
+
+fn from_json(value: serde_json::Value) -> VariantValue {
+    use serde_json::Value;
+    match value {
+        Value::Null => VariantValue::Null,
+        Value::Bool(v) => VariantValue::Boolean(v),
+        Value::Number(n) => match n.as_i64() {
+            Some(v) => VariantValue::int(v),
+            None => VariantValue::Double(n.as_f64().unwrap_or(f64::NAN)),
+        },
+        Value::String(v) => VariantValue::String(v),
+        Value::Array(values) => VariantValue::Array(values.into_iter().map(from_json).collect()),
+        Value::Object(fields) => {
+            VariantValue::Object(fields.into_iter().map(|(k, v)| (k, from_json(v))).collect())
+        },
+    }
+}
+
+impl VariantRef<'_> {
+    /// Writes this value as JSON. Temporal values are written as ISO 8601 strings, binary values
+    /// as base64 strings and non-finite floats as strings.
+    pub fn write_json(&self, out: &mut String) -> PolarsResult<()> {
+        use VariantPrimitive as P;
+        match self.view()? {
+            VariantView::Primitive(p) => match p {
+                P::Null => out.push_str("null"),
+                P::Boolean(v) => write!(out, "{v}").unwrap(),
+                P::Int8(v) => write!(out, "{v}").unwrap(),
+                P::Int16(v) => write!(out, "{v}").unwrap(),
+                P::Int32(v) => write!(out, "{v}").unwrap(),
+                P::Int64(v) => write!(out, "{v}").unwrap(),
+                P::Float(v) if v.is_finite() => write!(out, "{v:?}").unwrap(),
+                P::Double(v) if v.is_finite() => write!(out, "{v:?}").unwrap(),
+                P::Float(v) => write!(out, "\"{v}\"").unwrap(),
+                P::Double(v) => write!(out, "\"{v}\"").unwrap(),
+                P::Decimal4 { value, scale } => write_decimal(out, value as i128, scale),
+                P::Decimal8 { value, scale } => write_decimal(out, value as i128, scale),
+                P::Decimal16 { value, scale } => write_decimal(out, value, scale),
+                P::Date(days) => {
+                    out.push('"');
+                    write_date(out, days as i64);
+                    out.push('"');
+                },
+                P::Timestamp(v) => write_timestamp(out, v, 1_000_000, true),
+                P::TimestampNtz(v) => write_timestamp(out, v, 1_000_000, false),
+                P::TimestampNanos(v) => write_timestamp(out, v, 1_000_000_000, true),
+                P::TimestampNtzNanos(v) => write_timestamp(out, v, 1_000_000_000, false),
+                P::TimeNtz(v) => {
+                    out.push('"');
+                    write_time(out, v.rem_euclid(86_400_000_000), 1_000_000);
+                    out.push('"');
+                },
+                P::Binary(v) => {
+                    out.push('"');
+                    write_base64(out, v);
+                    out.push('"');
+                },
+                P::String(v) => write_json_string(out, v),
+                P::Uuid(v) => {
+                    out.push('"');
+                    for (i, b) in v.iter().enumerate() {
+                        if matches!(i, 4 | 6 | 8 | 10) {
+                            out.push('-');
+                        }
+                        write!(out, "{b:02x}").unwrap();
+                    }
+                    out.push('"');
+                },
+            },
+            VariantView::Array(arr) => {
+                out.push('[');
+                for i in 0..arr.len() {
+                    if i > 0 {
+                        out.push(',');
+                    }
+                    arr.get(i)?.write_json(out)?;
+                }
+                out.push(']');
+            },
+            VariantView::Object(obj) => {
+                out.push('{');
+                for i in 0..obj.len() {
+                    if i > 0 {
+                        out.push(',');
+                    }
+                    write_json_string(out, obj.key(i)?);
+                    out.push(':');
+                    obj.value(i)?.write_json(out)?;
+                }
+                out.push('}');
+            },
+        }
+        Ok(())
+    }
+
+    /// This value as a JSON string.
+    pub fn to_json(&self) -> PolarsResult<String> {
+        let mut out = String::new();
+        self.write_json(&mut out)?;
+        Ok(out)
+    }
+}
+
+fn write_json_string(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+fn write_decimal(out: &mut String, value: i128, scale: u8) {
+    if scale == 0 {
+        write!(out, "{value}").unwrap();
+        return;
+    }
+    let digits = value.unsigned_abs().to_string();
+    let scale = scale as usize;
+    if value < 0 {
+        out.push('-');
+    }
+    if digits.len() > scale {
+        let (int, frac) = digits.split_at(digits.len() - scale);
+        write!(out, "{int}.{frac}").unwrap();
+    } else {
+        write!(out, "0.{digits:0>scale$}").unwrap();
+    }
+}
+
+/// Writes the date `days` after the unix epoch as `YYYY-MM-DD`.
+fn write_date(out: &mut String, days: i64) {
+    // Howard Hinnant's `civil_from_days`.
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + (month <= 2) as i64;
+    write!(out, "{year:04}-{month:02}-{day:02}").unwrap();
+}
+
+/// Writes a time of day given in `units_per_second` as `HH:MM:SS[.fraction]`.
+fn write_time(out: &mut String, v: i64, units_per_second: i64) {
+    let secs = v / units_per_second;
+    let frac = v % units_per_second;
+    write!(
+        out,
+        "{:02}:{:02}:{:02}",
+        secs / 3600,
+        (secs / 60) % 60,
+        secs % 60
+    )
+    .unwrap();
+    if frac != 0 {
+        let width = if units_per_second == 1_000_000 { 6 } else { 9 };
+        write!(out, ".{frac:0width$}").unwrap();
+    }
+}
+
+fn write_timestamp(out: &mut String, v: i64, units_per_second: i64, utc: bool) {
+    let units_per_day = 86_400 * units_per_second;
+    out.push('"');
+    write_date(out, v.div_euclid(units_per_day));
+    out.push('T');
+    write_time(out, v.rem_euclid(units_per_day), units_per_second);
+    if utc {
+        out.push_str("+00:00");
+    }
+    out.push('"');
+}
+
+fn write_base64(out: &mut String, bytes: &[u8]) {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    for chunk in bytes.chunks(3) {
+        let b = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or(0),
+            chunk.get(2).copied().unwrap_or(0),
+        ];
+        let n = ((b[0] as u32) << 16) | ((b[1] as u32) << 8) | b[2] as u32;
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(ALPHABET[((n >> (18 - 6 * i)) & 0x3F) as usize] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+}
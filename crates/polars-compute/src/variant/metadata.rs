@@ -0,0 +1,125 @@
+use polars_error::{PolarsResult, polars_ensure};
+
+use super::{VERSION, int_size, malformed, read_uint, write_uint};
+
+/// The dictionary of object keys of a variant.
+#[derive(Clone, Copy, Debug)]
+pub struct VariantMetadata<'a> {
+    bytes: &'a [u8],
+    offset_size: usize,
+    dict_size: usize,
+    sorted: bool,
+}
+
+impl<'a> VariantMetadata<'a> {
+    /// Parses the metadata at the start of `bytes`. Trailing bytes are ignored.
+    pub fn try_new(bytes: &'a [u8]) -> PolarsResult<Self> {
+        let header = *bytes.first().ok_or_else(|| malformed("empty metadata"))?;
+        polars_ensure!(
+            header & 0x0F == VERSION,
+            ComputeError: "unsupported variant metadata version {}", header & 0x0F
+        );
+        let sorted = header & 0x10 != 0;
+        let offset_size = ((header >> 6) & 0x03) as usize + 1;
+        let dict_size = read_uint(bytes, 1, offset_size)?;
+
+        let offsets_start = 1 + offset_size;
+        let strings_start = offsets_start + (dict_size + 1) * offset_size;
+        let strings_len = read_uint(bytes, offsets_start + dict_size * offset_size, offset_size)?;
+        let len = strings_start + strings_len;
+        polars_ensure!(len <= bytes.len(), ComputeError: "malformed variant: metadata is truncated");
+
+        Ok(Self {
+            bytes: &bytes[..len],
+            offset_size,
+            dict_size,
+            sorted,
+        })
+    }
+
+    /// Number of keys in the dictionary.
+    pub fn len(&self) -> usize {
+        self.dict_size
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.dict_size == 0
+    }
+
+    /// Length of the encoded metadata.
+    pub fn size_in_bytes(&self) -> usize {
+        self.bytes.len()
+    }
+
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// Get the key with dictionary id `id`.
+    pub fn get(&self, id: usize) -> PolarsResult<&'a str> {
+        polars_ensure!(
+            id < self.dict_size,
+            ComputeError: "malformed variant: field id {} out of bounds for a dictionary of {} keys",
+            id, self.dict_size
+        );
+        let offsets_start = 1 + self.offset_size;
+        let strings_start = offsets_start + (self.dict_size + 1) * self.offset_size;
+        let start = read_uint(
+            self.bytes,
+            offsets_start + id * self.offset_size,
+            self.offset_size,
+        )?;
+        let end = read_uint(
+            self.bytes,
+            offsets_start + (id + 1) * self.offset_size,
+            self.offset_size,
+        )?;
+        let bytes = self
+            .bytes
+            .get(strings_start + start..strings_start + end)
+            .ok_or_else(|| malformed("key out of bounds"))?;
+        std::str::from_utf8(bytes).map_err(|_| malformed("key is not valid utf-8"))
+    }
+
+    /// Find the dictionary id of `key`.
+    pub fn find(&self, key: &str) -> PolarsResult<Option<usize>> {
+        if self.sorted {
+            let (mut lo, mut hi) = (0, self.dict_size);
+            while lo < hi {
+                let mid = lo + (hi - lo) / 2;
+                match self.get(mid)?.cmp(key) {
+                    std::cmp::Ordering::Less => lo = mid + 1,
+                    std::cmp::Ordering::Greater => hi = mid,
+                    std::cmp::Ordering::Equal => return Ok(Some(mid)),
+                }
+            }
+            Ok(None)
+        } else {
+            for id in 0..self.dict_size {
+                if self.get(id)? == key {
+                    return Ok(Some(id));
+                }
+            }
+            Ok(None)
+        }
+    }
+}
+
+/// Writes a metadata dictionary of the given keys. The keys must be sorted and unique.
+pub(super) fn write_metadata(out: &mut Vec<u8>, keys: &[&str]) {
+    debug_assert!(keys.windows(2).all(|w| w[0] < w[1]));
+    let strings_len: usize = keys.iter().map(|k| k.len()).sum();
+    let offset_size = int_size(strings_len.max(keys.len()));
+
+    out.push(VERSION | 0x10 | (((offset_size - 1) as u8) << 6));
+    write_uint(out, keys.len(), offset_size);
+    let mut offset = 0;
+    write_uint(out, offset, offset_size);
+    for key in keys {
+        offset += key.len();
+        write_uint(out, offset, offset_size);
+    }
+    for key in keys {
+        out.extend_from_slice(key.as_bytes());
+    }
+}
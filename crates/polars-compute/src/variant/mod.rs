@@ -0,0 +1,186 @@
+//! Kernels for the Parquet `VARIANT` binary encoding.
+//!
+//! A variant value is stored as two buffers: the `metadata`, a dictionary of the object keys used
+//! in the value, and the `value` itself which refers to keys by their index in the dictionary.
+//! Polars stores a variant as a single binary value holding the metadata directly followed by the
+//! value. The metadata is self-describing, so the two can always be split again.
+//!
+//! See <https://github.com/apache/parquet-format/blob/master/VariantEncoding.md> for the
+//! specification of the encoding, and `VariantShredding.md` next to it for the shredded layout
+//! that is used when writing to Parquet.
+mod arrow;
+mod builder;
+mod json;
+mod metadata;
+mod path;
+mod shred;
+mod value;
+
+pub use arrow::{struct_to_variant, variant_arrow_dtype, variant_to_struct};
+pub use builder::VariantValue;
+pub use json::json_to_variant;
+pub use metadata::VariantMetadata;
+pub use path::{VariantPath, VariantPathElement};
+use polars_error::{PolarsError, PolarsResult, polars_err};
+pub use shred::{shred_variant, shredded_variant_arrow_dtype};
+pub use value::{VariantArray, VariantObject, VariantPrimitive, VariantRef, VariantView};
+
+/// The version of the encoding written and understood by these kernels.
+const VERSION: u8 = 1;
+
+const BASIC_TYPE_PRIMITIVE: u8 = 0;
+const BASIC_TYPE_SHORT_STRING: u8 = 1;
+const BASIC_TYPE_OBJECT: u8 = 2;
+const BASIC_TYPE_ARRAY: u8 = 3;
+
+/// Longest string that is stored with the compact short string header.
+const MAX_SHORT_STRING_LEN: usize = 63;
+
+/// Type ids of the primitive values.
+mod primitive {
+    pub const NULL: u8 = 0;
+    pub const TRUE: u8 = 1;
+    pub const FALSE: u8 = 2;
+    pub const INT8: u8 = 3;
+    pub const INT16: u8 = 4;
+    pub const INT32: u8 = 5;
+    pub const INT64: u8 = 6;
+    pub const DOUBLE: u8 = 7;
+    pub const DECIMAL4: u8 = 8;
+    pub const DECIMAL8: u8 = 9;
+    pub const DECIMAL16: u8 = 10;
+    pub const DATE: u8 = 11;
+    pub const TIMESTAMP: u8 = 12;
+    pub const TIMESTAMP_NTZ: u8 = 13;
+    pub const FLOAT: u8 = 14;
+    pub const BINARY: u8 = 15;
+    pub const STRING: u8 = 16;
+    pub const TIME_NTZ: u8 = 17;
+    pub const TIMESTAMP_NANOS: u8 = 18;
+    pub const TIMESTAMP_NTZ_NANOS: u8 = 19;
+    pub const UUID: u8 = 20;
+}
+
+fn malformed(what: &str) -> PolarsError {
+    polars_err!(ComputeError: "malformed variant: {}", what)
+}
+
+/// Reads a little-endian unsigned integer of `size` bytes at `offset`.
+fn read_uint(bytes: &[u8], offset: usize, size: usize) -> PolarsResult<usize> {
+    let bytes = bytes
+        .get(offset..offset + size)
+        .ok_or_else(|| malformed("out of bounds read"))?;
+    Ok(bytes
+        .iter()
+        .rev()
+        .fold(0usize, |acc, b| (acc << 8) | *b as usize))
+}
+
+fn read_array<const N: usize>(bytes: &[u8], offset: usize) -> PolarsResult<[u8; N]> {
+    bytes
+        .get(offset..offset + N)
+        .map(|b| b.try_into().unwrap())
+        .ok_or_else(|| malformed("out of bounds read"))
+}
+
+/// Number of bytes needed to store `v` as an unsigned integer, between 1 and 4.
+fn int_size(v: usize) -> usize {
+    match v {
+        0..=0xFF => 1,
+        0x100..=0xFFFF => 2,
+        0x1_0000..=0xFF_FFFF => 3,
+        _ => 4,
+    }
+}
+
+fn write_uint(out: &mut Vec<u8>, v: usize, size: usize) {
+    out.extend_from_slice(&(v as u32).to_le_bytes()[..size]);
+}
+
+/// Splits a stored variant into its metadata and value.
+pub fn split_variant(bytes: &[u8]) -> PolarsResult<(&[u8], &[u8])> {
+    let metadata = VariantMetadata::try_new(bytes)?;
+    Ok(bytes.split_at(metadata.size_in_bytes()))
+}
+
+#[cfg(test)]
+mod test {
+    use arrow::array::{Array, BinaryViewArray};
+    use arrow::datatypes::ArrowDataType;
+
+    use super::*;
+
+    fn to_json(bytes: &[u8]) -> String {
+        VariantRef::try_new(bytes).unwrap().to_json().unwrap()
+    }
+
+    #[test]
+    fn test_json_roundtrip() {
+        let json = r#"{"b":[1,300,-70000,1.5,"x"],"a":null,"c":{"d":true,"e":"a long string that does not fit in a short string header at all"}}"#;
+        let encoded = json_to_variant(json.as_bytes()).unwrap();
+        assert_eq!(
+            to_json(&encoded),
+            r#"{"a":null,"b":[1,300,-70000,1.5,"x"],"c":{"d":true,"e":"a long string that does not fit in a short string header at all"}}"#
+        );
+    }
+
+    #[test]
+    fn test_get_path() {
+        let encoded = json_to_variant(br#"{"a":{"b":[10,{"c":"x"}]}}"#).unwrap();
+        let v = VariantRef::try_new(&encoded).unwrap();
+
+        let get = |path: &str| {
+            v.get_path(&VariantPath::parse(path).unwrap())
+                .unwrap()
+                .map(|v| v.to_json().unwrap())
+        };
+        assert_eq!(get("$.a.b[0]").as_deref(), Some("10"));
+        assert_eq!(get("a['b'][1].c").as_deref(), Some("\"x\""));
+        assert_eq!(get("$.a.missing"), None);
+        assert_eq!(get("$.a.b[5]"), None);
+        assert_eq!(
+            v.get_path(&VariantPath::parse("$.a.b").unwrap())
+                .unwrap()
+                .unwrap()
+                .type_name()
+                .unwrap(),
+            "array"
+        );
+    }
+
+    #[test]
+    fn test_shred_roundtrip() {
+        let rows = [
+            Some(r#"{"id":1,"name":"a","extra":[1,2]}"#),
+            Some(r#"{"id":"not an int","nested":{"x":2.5}}"#),
+            None,
+            Some(r#"[1,2,3]"#),
+            Some(r#"{"nested":{"x":1.0,"y":false}}"#),
+        ];
+        let encoded = rows
+            .iter()
+            .map(|r| r.map(|r| json_to_variant(r.as_bytes()).unwrap()))
+            .collect::<Vec<_>>();
+        let arr = BinaryViewArray::from_slice(&encoded);
+
+        let paths = [
+            (VariantPath::parse("id").unwrap(), ArrowDataType::Int64),
+            (VariantPath::parse("name").unwrap(), ArrowDataType::Utf8View),
+            (
+                VariantPath::parse("nested.x").unwrap(),
+                ArrowDataType::Float64,
+            ),
+        ];
+        let shredded = shred_variant(&arr, &paths).unwrap();
+        assert_eq!(
+            shredded.dtype(),
+            &shredded_variant_arrow_dtype(&paths).unwrap()
+        );
+
+        let unshredded = struct_to_variant(&shredded).unwrap();
+        for (row, expected) in rows.iter().enumerate() {
+            let expected = expected.map(|r| to_json(&json_to_variant(r.as_bytes()).unwrap()));
+            assert_eq!(unshredded.get(row).map(to_json), expected);
+        }
+    }
+}
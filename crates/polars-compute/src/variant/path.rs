@@ -0,0 +1,100 @@
+use std::fmt;
+
+use polars_error::{PolarsResult, polars_bail};
+use polars_utils::pl_str::PlSmallStr;
+
+/// A step into a variant value.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum VariantPathElement {
+    /// A field of an object.
+    Field(PlSmallStr),
+    /// An element of an array.
+    Index(usize),
+}
+
+/// A path into a variant value, e.g. `$.a.b[0]` or `a['b c']`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct VariantPath(Vec<VariantPathElement>);
+
+impl VariantPath {
+    pub fn new(elements: Vec<VariantPathElement>) -> Self {
+        Self(elements)
+    }
+
+    pub fn elements(&self) -> &[VariantPathElement] {
+        &self.0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Parses a path. Fields are separated by `.` or given in brackets as quoted strings, array
+    /// indices are given in brackets. A leading `$` is optional.
+    pub fn parse(path: &str) -> PolarsResult<Self> {
+        let mut elements = Vec::new();
+        let mut rest = path.strip_prefix('$').unwrap_or(path);
+        // Allow the first field without a leading dot.
+        let mut expect_dot = path.starts_with('$');
+
+        while !rest.is_empty() {
+            if let Some(r) = rest.strip_prefix('[') {
+                let end = match r.chars().next() {
+                    Some(q @ ('\'' | '"')) => {
+                        let Some(close) = r[1..].find(q) else {
+                            polars_bail!(InvalidOperation: "unterminated quote in variant path '{}'", path)
+                        };
+                        elements.push(VariantPathElement::Field(r[1..1 + close].into()));
+                        2 + close
+                    },
+                    _ => {
+                        let close = r.find(']').unwrap_or(r.len());
+                        let Ok(idx) = r[..close].trim().parse::<usize>() else {
+                            polars_bail!(InvalidOperation: "invalid index in variant path '{}'", path)
+                        };
+                        elements.push(VariantPathElement::Index(idx));
+                        close
+                    },
+                };
+                let Some(r) = r[end..].strip_prefix(']') else {
+                    polars_bail!(InvalidOperation: "expected ']' in variant path '{}'", path)
+                };
+                rest = r;
+            } else {
+                let r = match rest.strip_prefix('.') {
+                    Some(r) => r,
+                    None if !expect_dot => rest,
+                    None => {
+                        polars_bail!(InvalidOperation: "expected '.' or '[' in variant path '{}'", path)
+                    },
+                };
+                let end = r.find(['.', '[']).unwrap_or(r.len());
+                if end == 0 {
+                    polars_bail!(InvalidOperation: "empty field name in variant path '{}'", path)
+                }
+                elements.push(VariantPathElement::Field(r[..end].into()));
+                rest = &r[end..];
+            }
+            expect_dot = true;
+        }
+        Ok(Self(elements))
+    }
+}
+
+impl fmt::Display for VariantPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("$")?;
+        for element in &self.0 {
+            match element {
+                VariantPathElement::Field(name)
+                    if !name.is_empty() && !name.contains(['.', '[', ']', '\'']) =>
+                {
+                    write!(f, ".{name}")?
+                },
+                VariantPathElement::Field(name) => write!(f, "[\"{name}\"]")?,
+                VariantPathElement::Index(idx) => write!(f, "[{idx}]")?,
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,328 @@
+use arrow::array::{
+    Array, BinaryViewArray, BooleanArray, MutableBinaryViewArray, PrimitiveArray, StructArray,
+    Utf8ViewArray,
+};
+use arrow::bitmap::Bitmap;
+use arrow::datatypes::{ArrowDataType, Field};
+use arrow::types::NativeType;
+use polars_error::{PolarsResult, polars_bail};
+use polars_utils::pl_str::PlSmallStr;
+
+use super::arrow::{NULL_VARIANT, wrap_extension};
+use super::builder::write_object_raw;
+use super::{VariantPath, VariantPathElement, VariantPrimitive, VariantRef, VariantView};
+
+/// The requested shredding of a variant (sub)value.
+enum Node {
+    Leaf(ArrowDataType),
+    Object(Vec<(PlSmallStr, Node)>),
+}
+
+impl Node {
+    fn try_new(paths: &[(VariantPath, ArrowDataType)]) -> PolarsResult<Self> {
+        let mut root = Node::Object(Vec::new());
+        for (path, dtype) in paths {
+            polars_ensure_shreddable(path, dtype)?;
+            let mut node = &mut root;
+            for (i, element) in path.elements().iter().enumerate() {
+                let VariantPathElement::Field(name) = element else {
+                    polars_bail!(InvalidOperation: "variant shredding only supports object fields, got path '{}'", path)
+                };
+                let Node::Object(fields) = node else {
+                    polars_bail!(InvalidOperation: "conflicting variant shredding paths at '{}'", path)
+                };
+                let is_leaf = i + 1 == path.elements().len();
+                let pos = match fields.iter().position(|(n, _)| n == name) {
+                    Some(pos) => pos,
+                    None => {
+                        let child = if is_leaf {
+                            Node::Leaf(dtype.clone())
+                        } else {
+                            Node::Object(Vec::new())
+                        };
+                        fields.push((name.clone(), child));
+                        fields.len() - 1
+                    },
+                };
+                node = &mut fields[pos].1;
+                if is_leaf && !matches!(node, Node::Leaf(dt) if dt == dtype) {
+                    polars_bail!(InvalidOperation: "conflicting variant shredding paths at '{}'", path)
+                }
+            }
+        }
+        Ok(root)
+    }
+
+    /// The type of the `typed_value` column of this node.
+    fn typed_dtype(&self) -> ArrowDataType {
+        match self {
+            Node::Leaf(dtype) => dtype.clone(),
+            Node::Object(fields) => ArrowDataType::Struct(
+                fields
+                    .iter()
+                    .map(|(name, node)| {
+                        Field::new(
+                            name.clone(),
+                            ArrowDataType::Struct(node.group_fields()),
+                            false,
+                        )
+                    })
+                    .collect(),
+            ),
+        }
+    }
+
+    /// The `value` and `typed_value` fields of a shredded group.
+    fn group_fields(&self) -> Vec<Field> {
+        vec![
+            Field::new(
+                PlSmallStr::from_static("value"),
+                ArrowDataType::BinaryView,
+                true,
+            ),
+            Field::new(
+                PlSmallStr::from_static("typed_value"),
+                self.typed_dtype(),
+                true,
+            ),
+        ]
+    }
+}
+
+fn polars_ensure_shreddable(path: &VariantPath, dtype: &ArrowDataType) -> PolarsResult<()> {
+    use ArrowDataType as D;
+    if path.is_empty() {
+        polars_bail!(InvalidOperation: "cannot shred the root of a variant, give a field path")
+    }
+    match dtype {
+        D::Boolean
+        | D::Int8
+        | D::Int16
+        | D::Int32
+        | D::Int64
+        | D::Float32
+        | D::Float64
+        | D::Utf8View
+        | D::BinaryView
+        | D::Date32 => Ok(()),
+        dt => polars_bail!(InvalidOperation: "cannot shred variant field '{}' to {:?}", path, dt),
+    }
+}
+
+/// The Arrow type of variants shredded by [`shred_variant`].
+pub fn shredded_variant_arrow_dtype(
+    paths: &[(VariantPath, ArrowDataType)],
+) -> PolarsResult<ArrowDataType> {
+    let root = Node::try_new(paths)?;
+    let mut fields = vec![Field::new(
+        PlSmallStr::from_static("metadata"),
+        ArrowDataType::BinaryView,
+        false,
+    )];
+    fields.extend(root.group_fields());
+    Ok(wrap_extension(fields))
+}
+
+/// Shreds the given object fields of stored variants into typed columns.
+///
+/// Values that don't have the requested type stay in the binary `value` column, and fields that
+/// are not shredded are kept in a residual object in the `value` column of their parent.
+pub fn shred_variant(
+    arr: &BinaryViewArray,
+    paths: &[(VariantPath, ArrowDataType)],
+) -> PolarsResult<StructArray> {
+    let root = Node::try_new(paths)?;
+    let dtype = shredded_variant_arrow_dtype(paths)?;
+
+    let mut metadata = MutableBinaryViewArray::<[u8]>::with_capacity(arr.len());
+    let mut values = Vec::with_capacity(arr.len());
+    for opt in arr.iter() {
+        match opt {
+            Some(bytes) => {
+                let v = VariantRef::try_new(bytes)?;
+                metadata.push_value(v.metadata().as_bytes());
+                values.push(Some(v));
+            },
+            None => {
+                metadata.push_value(&NULL_VARIANT[..3]);
+                values.push(None);
+            },
+        }
+    }
+
+    let (value, typed_value) = shred_node(&root, &values)?;
+    StructArray::try_new(
+        dtype,
+        arr.len(),
+        vec![metadata.freeze().boxed(), value.boxed(), typed_value],
+        arr.validity().cloned(),
+    )
+}
+
+/// Shreds the values of one node, `None` being a missing value. Returns the `value` and
+/// `typed_value` columns.
+fn shred_node(
+    node: &Node,
+    values: &[Option<VariantRef<'_>>],
+) -> PolarsResult<(BinaryViewArray, Box<dyn Array>)> {
+    match node {
+        Node::Leaf(dtype) => shred_leaf(dtype, values),
+        Node::Object(fields) => {
+            let mut value = MutableBinaryViewArray::<[u8]>::with_capacity(values.len());
+            let mut validity = Vec::with_capacity(values.len());
+            let mut children = vec![Vec::with_capacity(values.len()); fields.len()];
+            let mut buf = Vec::new();
+
+            for v in values {
+                let obj = match v.map(|v| v.view()).transpose()? {
+                    Some(VariantView::Object(obj)) => obj,
+                    _ => {
+                        value.push(v.map(|v| v.value_bytes()));
+                        validity.push(false);
+                        children.iter_mut().for_each(|c| c.push(None));
+                        continue;
+                    },
+                };
+
+                for ((name, _), child) in fields.iter().zip(children.iter_mut()) {
+                    child.push(obj.get(name)?);
+                }
+
+                let mut residual = Vec::new();
+                for i in 0..obj.len() {
+                    let key = obj.key(i)?;
+                    if !fields.iter().any(|(name, _)| name == key) {
+                        residual.push((obj.field_id(i)?, obj.value(i)?.value_bytes()));
+                    }
+                }
+                if residual.is_empty() {
+                    value.push_null();
+                } else {
+                    buf.clear();
+                    write_object_raw(&mut buf, &residual);
+                    value.push_value(buf.as_slice());
+                }
+                validity.push(true);
+            }
+
+            let validity = Bitmap::from(validity);
+            let groups = fields
+                .iter()
+                .zip(children)
+                .map(|((_, child), values)| {
+                    let (value, typed_value) = shred_node(child, &values)?;
+                    Ok(StructArray::new(
+                        ArrowDataType::Struct(child.group_fields()),
+                        values.len(),
+                        vec![value.boxed(), typed_value],
+                        None,
+                    )
+                    .boxed())
+                })
+                .collect::<PolarsResult<Vec<_>>>()?;
+            let typed_value = StructArray::new(
+                node.typed_dtype(),
+                values.len(),
+                groups,
+                (validity.unset_bits() > 0).then_some(validity),
+            );
+            Ok((value.freeze(), typed_value.boxed()))
+        },
+    }
+}
+
+fn shred_leaf(
+    dtype: &ArrowDataType,
+    values: &[Option<VariantRef<'_>>],
+) -> PolarsResult<(BinaryViewArray, Box<dyn Array>)> {
+    use VariantPrimitive as P;
+
+    fn int(p: &VariantPrimitive<'_>) -> Option<i64> {
+        match p {
+            P::Int8(v) => Some(*v as i64),
+            P::Int16(v) => Some(*v as i64),
+            P::Int32(v) => Some(*v as i64),
+            P::Int64(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    fn collect<T>(
+        values: &[Option<VariantRef<'_>>],
+        f: impl Fn(&VariantPrimitive<'_>) -> Option<T>,
+    ) -> PolarsResult<(BinaryViewArray, Vec<Option<T>>)> {
+        let mut value = MutableBinaryViewArray::<[u8]>::with_capacity(values.len());
+        let mut typed = Vec::with_capacity(values.len());
+        for v in values {
+            let t = match v.map(|v| v.view()).transpose()? {
+                Some(VariantView::Primitive(p)) => f(&p),
+                _ => None,
+            };
+            // Values that can't be shredded, including variant nulls, stay in `value`.
+            value.push(if t.is_some() {
+                None
+            } else {
+                v.map(|v| v.value_bytes())
+            });
+            typed.push(t);
+        }
+        Ok((value.freeze(), typed))
+    }
+
+    fn primitive<T: NativeType>(
+        values: &[Option<VariantRef<'_>>],
+        dtype: &ArrowDataType,
+        f: impl Fn(&VariantPrimitive<'_>) -> Option<T>,
+    ) -> PolarsResult<(BinaryViewArray, Box<dyn Array>)> {
+        let (value, typed) = collect(values, f)?;
+        let typed = PrimitiveArray::<T>::from(typed).to(dtype.clone());
+        Ok((value, typed.boxed()))
+    }
+
+    use ArrowDataType as D;
+    match dtype {
+        D::Boolean => {
+            let (value, typed) = collect(values, |p| match p {
+                P::Boolean(v) => Some(*v),
+                _ => None,
+            })?;
+            Ok((value, BooleanArray::from(typed).boxed()))
+        },
+        D::Int8 => primitive(values, dtype, |p| int(p).and_then(|v| i8::try_from(v).ok())),
+        D::Int16 => primitive(values, dtype, |p| {
+            int(p).and_then(|v| i16::try_from(v).ok())
+        }),
+        D::Int32 => primitive(values, dtype, |p| {
+            int(p).and_then(|v| i32::try_from(v).ok())
+        }),
+        D::Int64 => primitive(values, dtype, int),
+        D::Float32 => primitive(values, dtype, |p| match p {
+            P::Float(v) => Some(*v),
+            _ => None,
+        }),
+        D::Float64 => primitive(values, dtype, |p| match p {
+            P::Float(v) => Some(*v as f64),
+            P::Double(v) => Some(*v),
+            _ => None,
+        }),
+        D::Date32 => primitive(values, dtype, |p| match p {
+            P::Date(v) => Some(*v),
+            _ => None,
+        }),
+        D::Utf8View => {
+            let (value, typed) = collect(values, |p| match p {
+                P::String(v) => Some(v.to_string()),
+                _ => None,
+            })?;
+            Ok((value, Utf8ViewArray::from_slice(typed).boxed()))
+        },
+        D::BinaryView => {
+            let (value, typed) = collect(values, |p| match p {
+                P::Binary(v) => Some(v.to_vec()),
+                _ => None,
+            })?;
+            Ok((value, BinaryViewArray::from_slice(typed).boxed()))
+        },
+        _ => unreachable!(),
+    }
+}
@@ -0,0 +1,382 @@
+use polars_error::{PolarsResult, polars_bail};
+
+use super::path::{VariantPath, VariantPathElement};
+use super::{
+    BASIC_TYPE_ARRAY, BASIC_TYPE_OBJECT, BASIC_TYPE_PRIMITIVE, BASIC_TYPE_SHORT_STRING,
+    VariantMetadata, malformed, primitive, read_array, read_uint,
+};
+
+/// A borrowed variant value together with the metadata its object keys refer to.
+#[derive(Clone, Copy, Debug)]
+pub struct VariantRef<'a> {
+    metadata: VariantMetadata<'a>,
+    value: &'a [u8],
+}
+
+/// A decoded primitive variant value.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum VariantPrimitive<'a> {
+    Null,
+    Boolean(bool),
+    Int8(i8),
+    Int16(i16),
+    Int32(i32),
+    Int64(i64),
+    Float(f32),
+    Double(f64),
+    Decimal4 {
+        value: i32,
+        scale: u8,
+    },
+    Decimal8 {
+        value: i64,
+        scale: u8,
+    },
+    Decimal16 {
+        value: i128,
+        scale: u8,
+    },
+    /// Days since the unix epoch.
+    Date(i32),
+    /// Microseconds since the unix epoch, adjusted to UTC.
+    Timestamp(i64),
+    /// Microseconds since the unix epoch, without a timezone.
+    TimestampNtz(i64),
+    /// Nanoseconds since the unix epoch, adjusted to UTC.
+    TimestampNanos(i64),
+    /// Nanoseconds since the unix epoch, without a timezone.
+    TimestampNtzNanos(i64),
+    /// Microseconds since midnight.
+    TimeNtz(i64),
+    Binary(&'a [u8]),
+    String(&'a str),
+    Uuid([u8; 16]),
+}
+
+/// A decoded view into a variant value.
+#[derive(Clone, Copy, Debug)]
+pub enum VariantView<'a> {
+    Primitive(VariantPrimitive<'a>),
+    Object(VariantObject<'a>),
+    Array(VariantArray<'a>),
+}
+
+/// A variant object, its fields are sorted by key.
+#[derive(Clone, Copy, Debug)]
+pub struct VariantObject<'a> {
+    metadata: VariantMetadata<'a>,
+    value: &'a [u8],
+    num_fields: usize,
+    id_size: usize,
+    offset_size: usize,
+    ids_start: usize,
+    offsets_start: usize,
+    values_start: usize,
+}
+
+/// A variant array.
+#[derive(Clone, Copy, Debug)]
+pub struct VariantArray<'a> {
+    metadata: VariantMetadata<'a>,
+    value: &'a [u8],
+    num_elements: usize,
+    offset_size: usize,
+    offsets_start: usize,
+    values_start: usize,
+}
+
+/// Returns the size of the value starting at the front of `value`.
+fn value_size(value: &[u8]) -> PolarsResult<usize> {
+    let header = *value.first().ok_or_else(|| malformed("empty value"))?;
+    let value_header = header >> 2;
+    Ok(match header & 0x03 {
+        BASIC_TYPE_PRIMITIVE => match value_header {
+            primitive::NULL | primitive::TRUE | primitive::FALSE => 1,
+            primitive::INT8 => 2,
+            primitive::INT16 => 3,
+            primitive::INT32 | primitive::DATE | primitive::FLOAT => 5,
+            primitive::INT64
+            | primitive::DOUBLE
+            | primitive::TIMESTAMP
+            | primitive::TIMESTAMP_NTZ
+            | primitive::TIMESTAMP_NANOS
+            | primitive::TIMESTAMP_NTZ_NANOS
+            | primitive::TIME_NTZ => 9,
+            primitive::DECIMAL4 => 6,
+            primitive::DECIMAL8 => 10,
+            primitive::DECIMAL16 => 18,
+            primitive::UUID => 17,
+            primitive::BINARY | primitive::STRING => 5 + read_uint(value, 1, 4)?,
+            id => polars_bail!(ComputeError: "malformed variant: unknown primitive type id {}", id),
+        },
+        BASIC_TYPE_SHORT_STRING => 1 + value_header as usize,
+        BASIC_TYPE_OBJECT => {
+            let (num_size, id_size, offset_size) = object_sizes(value_header);
+            let num = read_uint(value, 1, num_size)?;
+            let offsets_start = 1 + num_size + num * id_size;
+            let values_start = offsets_start + (num + 1) * offset_size;
+            values_start + read_uint(value, offsets_start + num * offset_size, offset_size)?
+        },
+        BASIC_TYPE_ARRAY => {
+            let (num_size, offset_size) = array_sizes(value_header);
+            let num = read_uint(value, 1, num_size)?;
+            let offsets_start = 1 + num_size;
+            let values_start = offsets_start + (num + 1) * offset_size;
+            values_start + read_uint(value, offsets_start + num * offset_size, offset_size)?
+        },
+        _ => unreachable!(),
+    })
+}
+
+fn object_sizes(value_header: u8) -> (usize, usize, usize) {
+    let num_size = if value_header & 0x10 != 0 { 4 } else { 1 };
+    let id_size = ((value_header >> 2) & 0x03) as usize + 1;
+    let offset_size = (value_header & 0x03) as usize + 1;
+    (num_size, id_size, offset_size)
+}
+
+fn array_sizes(value_header: u8) -> (usize, usize) {
+    let num_size = if value_header & 0x04 != 0 { 4 } else { 1 };
+    let offset_size = (value_header & 0x03) as usize + 1;
+    (num_size, offset_size)
+}
+
+impl<'a> VariantRef<'a> {
+    /// Parses a stored variant, the metadata directly followed by the value.
+    pub fn try_new(bytes: &'a [u8]) -> PolarsResult<Self> {
+        let metadata = VariantMetadata::try_new(bytes)?;
+        Self::from_parts(metadata, &bytes[metadata.size_in_bytes()..])
+    }
+
+    /// Creates a variant from its metadata and the bytes of a value. Trailing bytes are ignored.
+    pub fn from_parts(metadata: VariantMetadata<'a>, value: &'a [u8]) -> PolarsResult<Self> {
+        let size = value_size(value)?;
+        let value = value
+            .get(..size)
+            .ok_or_else(|| malformed("value is truncated"))?;
+        Ok(Self { metadata, value })
+    }
+
+    pub fn metadata(&self) -> VariantMetadata<'a> {
+        self.metadata
+    }
+
+    /// The encoded value, without the metadata.
+    pub fn value_bytes(&self) -> &'a [u8] {
+        self.value
+    }
+
+    /// Encodes this value as a stored variant, the metadata directly followed by the value.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(self.metadata.size_in_bytes() + self.value.len());
+        out.extend_from_slice(self.metadata.as_bytes());
+        out.extend_from_slice(self.value);
+        out
+    }
+
+    pub fn view(&self) -> PolarsResult<VariantView<'a>> {
+        let v = self.value;
+        let header = v[0];
+        let value_header = header >> 2;
+        Ok(match header & 0x03 {
+            BASIC_TYPE_PRIMITIVE => VariantView::Primitive(self.primitive(value_header)?),
+            BASIC_TYPE_SHORT_STRING => {
+                let s = std::str::from_utf8(&v[1..])
+                    .map_err(|_| malformed("string is not valid utf-8"))?;
+                VariantView::Primitive(VariantPrimitive::String(s))
+            },
+            BASIC_TYPE_OBJECT => {
+                let (num_size, id_size, offset_size) = object_sizes(value_header);
+                let num_fields = read_uint(v, 1, num_size)?;
+                let ids_start = 1 + num_size;
+                let offsets_start = ids_start + num_fields * id_size;
+                VariantView::Object(VariantObject {
+                    metadata: self.metadata,
+                    value: v,
+                    num_fields,
+                    id_size,
+                    offset_size,
+                    ids_start,
+                    offsets_start,
+                    values_start: offsets_start + (num_fields + 1) * offset_size,
+                })
+            },
+            BASIC_TYPE_ARRAY => {
+                let (num_size, offset_size) = array_sizes(value_header);
+                let num_elements = read_uint(v, 1, num_size)?;
+                let offsets_start = 1 + num_size;
+                VariantView::Array(VariantArray {
+                    metadata: self.metadata,
+                    value: v,
+                    num_elements,
+                    offset_size,
+                    offsets_start,
+                    values_start: offsets_start + (num_elements + 1) * offset_size,
+                })
+            },
+            _ => unreachable!(),
+        })
+    }
+
+    fn primitive(&self, type_id: u8) -> PolarsResult<VariantPrimitive<'a>> {
+        use VariantPrimitive as P;
+        let v = self.value;
+        Ok(match type_id {
+            primitive::NULL => P::Null,
+            primitive::TRUE => P::Boolean(true),
+            primitive::FALSE => P::Boolean(false),
+            primitive::INT8 => P::Int8(i8::from_le_bytes(read_array(v, 1)?)),
+            primitive::INT16 => P::Int16(i16::from_le_bytes(read_array(v, 1)?)),
+            primitive::INT32 => P::Int32(i32::from_le_bytes(read_array(v, 1)?)),
+            primitive::INT64 => P::Int64(i64::from_le_bytes(read_array(v, 1)?)),
+            primitive::FLOAT => P::Float(f32::from_le_bytes(read_array(v, 1)?)),
+            primitive::DOUBLE => P::Double(f64::from_le_bytes(read_array(v, 1)?)),
+            primitive::DECIMAL4 => P::Decimal4 {
+                scale: v[1],
+                value: i32::from_le_bytes(read_array(v, 2)?),
+            },
+            primitive::DECIMAL8 => P::Decimal8 {
+                scale: v[1],
+                value: i64::from_le_bytes(read_array(v, 2)?),
+            },
+            primitive::DECIMAL16 => P::Decimal16 {
+                scale: v[1],
+                value: i128::from_le_bytes(read_array(v, 2)?),
+            },
+            primitive::DATE => P::Date(i32::from_le_bytes(read_array(v, 1)?)),
+            primitive::TIMESTAMP => P::Timestamp(i64::from_le_bytes(read_array(v, 1)?)),
+            primitive::TIMESTAMP_NTZ => P::TimestampNtz(i64::from_le_bytes(read_array(v, 1)?)),
+            primitive::TIMESTAMP_NANOS => P::TimestampNanos(i64::from_le_bytes(read_array(v, 1)?)),
+            primitive::TIMESTAMP_NTZ_NANOS => {
+                P::TimestampNtzNanos(i64::from_le_bytes(read_array(v, 1)?))
+            },
+            primitive::TIME_NTZ => P::TimeNtz(i64::from_le_bytes(read_array(v, 1)?)),
+            primitive::UUID => P::Uuid(read_array(v, 1)?),
+            primitive::BINARY => P::Binary(&v[5..]),
+            primitive::STRING => P::String(
+                std::str::from_utf8(&v[5..]).map_err(|_| malformed("string is not valid utf-8"))?,
+            ),
+            id => polars_bail!(ComputeError: "malformed variant: unknown primitive type id {}", id),
+        })
+    }
+
+    /// Name of the type of this value, e.g. `"int64"` or `"object"`.
+    pub fn type_name(&self) -> PolarsResult<&'static str> {
+        use VariantPrimitive as P;
+        Ok(match self.view()? {
+            VariantView::Object(_) => "object",
+            VariantView::Array(_) => "array",
+            VariantView::Primitive(p) => match p {
+                P::Null => "null",
+                P::Boolean(_) => "boolean",
+                P::Int8(_) => "int8",
+                P::Int16(_) => "int16",
+                P::Int32(_) => "int32",
+                P::Int64(_) => "int64",
+                P::Float(_) => "float",
+                P::Double(_) => "double",
+                P::Decimal4 { .. } | P::Decimal8 { .. } | P::Decimal16 { .. } => "decimal",
+                P::Date(_) => "date",
+                P::Timestamp(_) | P::TimestampNanos(_) => "timestamp",
+                P::TimestampNtz(_) | P::TimestampNtzNanos(_) => "timestamp_ntz",
+                P::TimeNtz(_) => "time",
+                P::Binary(_) => "binary",
+                P::String(_) => "string",
+                P::Uuid(_) => "uuid",
+            },
+        })
+    }
+
+    /// Follows `path` into this value. Returns `None` if a field or index along the path does
+    /// not exist.
+    pub fn get_path(&self, path: &VariantPath) -> PolarsResult<Option<VariantRef<'a>>> {
+        let mut current = *self;
+        for element in path.elements() {
+            let next = match (element, current.view()?) {
+                (VariantPathElement::Field(name), VariantView::Object(obj)) => obj.get(name)?,
+                (VariantPathElement::Index(idx), VariantView::Array(arr)) => {
+                    if *idx < arr.len() {
+                        Some(arr.get(*idx)?)
+                    } else {
+                        None
+                    }
+                },
+                _ => None,
+            };
+            match next {
+                Some(next) => current = next,
+                None => return Ok(None),
+            }
+        }
+        Ok(Some(current))
+    }
+}
+
+impl<'a> VariantObject<'a> {
+    pub fn len(&self) -> usize {
+        self.num_fields
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.num_fields == 0
+    }
+
+    /// The dictionary id of the key of the `i`-th field.
+    pub fn field_id(&self, i: usize) -> PolarsResult<usize> {
+        read_uint(self.value, self.ids_start + i * self.id_size, self.id_size)
+    }
+
+    pub fn key(&self, i: usize) -> PolarsResult<&'a str> {
+        self.metadata.get(self.field_id(i)?)
+    }
+
+    pub fn value(&self, i: usize) -> PolarsResult<VariantRef<'a>> {
+        let offset = read_uint(
+            self.value,
+            self.offsets_start + i * self.offset_size,
+            self.offset_size,
+        )?;
+        let value = self
+            .value
+            .get(self.values_start + offset..)
+            .ok_or_else(|| malformed("field offset out of bounds"))?;
+        VariantRef::from_parts(self.metadata, value)
+    }
+
+    /// Get the value of the field with key `key`.
+    pub fn get(&self, key: &str) -> PolarsResult<Option<VariantRef<'a>>> {
+        let (mut lo, mut hi) = (0, self.num_fields);
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            match self.key(mid)?.cmp(key) {
+                std::cmp::Ordering::Less => lo = mid + 1,
+                std::cmp::Ordering::Greater => hi = mid,
+                std::cmp::Ordering::Equal => return self.value(mid).map(Some),
+            }
+        }
+        Ok(None)
+    }
+}
+
+impl<'a> VariantArray<'a> {
+    pub fn len(&self) -> usize {
+        self.num_elements
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.num_elements == 0
+    }
+
+    pub fn get(&self, i: usize) -> PolarsResult<VariantRef<'a>> {
+        let offset = read_uint(
+            self.value,
+            self.offsets_start + i * self.offset_size,
+            self.offset_size,
+        )?;
+        let value = self
+            .value
+            .get(self.values_start + offset..)
+            .ok_or_else(|| malformed("element offset out of bounds"))?;
+        VariantRef::from_parts(self.metadata, value)
+    }
+}
@@ -0,0 +1,73 @@
+use polars_compute::decimal::{
+    DEC256_MAX_PREC, dec256_add, dec256_div, dec256_mul, dec256_rescale, dec256_sub,
+};
+
+use super::*;
+use crate::prelude::arity::broadcast_try_binary_elementwise;
+
+/// Rescales both sides to the largest scale and applies `op`, raising on overflow.
+fn decimal256_arithmetic(
+    lhs: &Decimal256Chunked,
+    rhs: &Decimal256Chunked,
+    op_name: &str,
+    op: impl Fn(i256, i256, usize, usize) -> Option<i256>,
+) -> PolarsResult<Decimal256Chunked> {
+    let left_s = lhs.scale();
+    let right_s = rhs.scale();
+    let scale = left_s.max(right_s);
+    let prec = DEC256_MAX_PREC;
+    let phys = broadcast_try_binary_elementwise(lhs.physical(), rhs.physical(), |opt_l, opt_r| {
+        let (Some(l), Some(r)) = (opt_l, opt_r) else {
+            return PolarsResult::Ok(None);
+        };
+        let ls = dec256_rescale(l, left_s, prec, scale).ok_or_else(|| {
+            polars_err!(ComputeError: "overflow in Decimal256 cast for {l} from scale {left_s} to {scale}")
+        })?;
+        let rs = dec256_rescale(r, right_s, prec, scale).ok_or_else(|| {
+            polars_err!(ComputeError: "overflow in Decimal256 cast for {r} from scale {right_s} to {scale}")
+        })?;
+        let ret = op(ls, rs, prec, scale).ok_or_else(
+            || polars_err!(ComputeError: "overflow in decimal {op_name} for {ls} and {rs}"),
+        )?;
+        Ok(Some(ret))
+    });
+    Ok(phys?.into_decimal256_unchecked(prec, scale))
+}
+
+impl Add for &Decimal256Chunked {
+    type Output = PolarsResult<Decimal256Chunked>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        decimal256_arithmetic(self, rhs, "addition", |l, r, p, _| dec256_add(l, r, p))
+    }
+}
+
+impl Sub for &Decimal256Chunked {
+    type Output = PolarsResult<Decimal256Chunked>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        decimal256_arithmetic(self, rhs, "subtraction", |l, r, p, _| dec256_sub(l, r, p))
+    }
+}
+
+impl Mul for &Decimal256Chunked {
+    type Output = PolarsResult<Decimal256Chunked>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        decimal256_arithmetic(self, rhs, "multiplication", dec256_mul)
+    }
+}
+
+impl Div for &Decimal256Chunked {
+    type Output = PolarsResult<Decimal256Chunked>;
+
+    fn div(self, rhs: Self) -> Self::Output {
+        polars_ensure!(
+            !rhs.physical().downcast_iter().any(|arr| arr
+                .iter()
+                .any(|v| v.is_some_and(|v| *v == i256::default()))),
+            ComputeError: "division by zero Decimal256"
+        );
+        decimal256_arithmetic(self, rhs, "division", dec256_div)
+    }
+}
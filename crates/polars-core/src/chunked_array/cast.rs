@@ -94,6 +94,26 @@ fn cast_impl_inner(
             }
             chunks
         },
+        #[cfg(feature = "dtype-decimal256")]
+        DataType::Decimal256(precision, scale) => {
+            let mut chunks = cast_chunks(chunks, dtype, options)?;
+            // @NOTE: We cannot cast here as that will lower the scale.
+            for chunk in chunks.iter_mut() {
+                *chunk = std::mem::take(
+                    chunk
+                        .as_any_mut()
+                        .downcast_mut::<PrimitiveArray<i256>>()
+                        .unwrap(),
+                )
+                .to(DataType::Int256.to_arrow(CompatLevel::newest()))
+                .to_boxed();
+            }
+            // SAFETY: the cast kernels only produce values that fit the precision.
+            let ca = unsafe { Int256Chunked::from_chunks(name, chunks) };
+            return Ok(ca
+                .into_decimal256_unchecked(*precision, *scale)
+                .into_series());
+        },
         _ => cast_chunks(chunks, &dtype.to_physical(), options)?,
     };
 
@@ -114,6 +134,14 @@ fn cast_impl_inner(
         Time => out.into_time(),
         #[cfg(feature = "dtype-decimal")]
         Decimal(precision, scale) => out.into_decimal(*precision, *scale)?,
+        #[cfg(feature = "dtype-interval")]
+        Interval => polars_bail!(
+            InvalidOperation: "casting from {:?} to Interval not supported", out.dtype()
+        ),
+        #[cfg(feature = "dtype-variant")]
+        Variant => polars_bail!(
+            InvalidOperation: "casting from {:?} to Variant not supported", out.dtype()
+        ),
         _ => out,
     };
 
@@ -303,6 +331,35 @@ impl ChunkCast for StringChunked {
                 let ca = Int128Chunked::from_chunk_iter(self.name().clone(), chunks);
                 Ok(ca.into_decimal_unchecked(*precision, *scale).into_series())
             },
+            #[cfg(feature = "dtype-decimal256")]
+            DataType::Decimal256(precision, scale) => {
+                let chunks = self.downcast_iter().map(|arr| {
+                    polars_compute::cast::binview_to_decimal256(
+                        &arr.to_binview(),
+                        *precision,
+                        *scale,
+                    )
+                    .to(DataType::Int256.to_arrow(CompatLevel::newest()))
+                });
+                let ca = Int256Chunked::from_chunk_iter(self.name().clone(), chunks);
+                let out = ca
+                    .into_decimal256_unchecked(*precision, *scale)
+                    .into_series();
+                if options.is_strict() && self.null_count() != out.null_count() {
+                    handle_casting_failures(&self.clone().into_series(), &out)?;
+                }
+                Ok(out)
+            },
+            #[cfg(feature = "dtype-variant")]
+            DataType::Variant => Ok(self.json_to_variant(options.is_strict())?.into_series()),
+            #[cfg(feature = "dtype-interval")]
+            DataType::Interval => {
+                let out = self.to_interval().into_series();
+                if options.is_strict() && self.null_count() != out.null_count() {
+                    handle_casting_failures(&self.clone().into_series(), &out)?;
+                }
+                Ok(out)
+            },
             #[cfg(feature = "dtype-date")]
             DataType::Date => {
                 let result = cast_chunks(&self.chunks, dtype, options)?;
@@ -373,6 +430,11 @@ impl StringChunked {
 impl ChunkCast for BinaryChunked {
     fn cast_with_options(&self, dtype: &DataType, options: CastOptions) -> PolarsResult<Series> {
         match dtype {
+            #[cfg(feature = "dtype-variant")]
+            DataType::Variant => Ok(self
+                .clone()
+                .into_variant(options.is_strict())?
+                .into_series()),
             #[cfg(feature = "dtype-struct")]
             DataType::Struct(fields) => {
                 cast_single_to_struct(self.name().clone(), &self.chunks, fields, options)
@@ -461,6 +523,12 @@ impl ChunkCast for ListChunked {
                     },
                 }
             },
+            #[cfg(feature = "dtype-map")]
+            Map(_, _) => {
+                // The struct fields are matched positionally to the key and value.
+                let map = ca.into_owned().into_map()?;
+                map.cast_with_options(dtype, options)
+            },
             #[cfg(feature = "dtype-array")]
             Array(child_type, width) => {
                 let physical_type = dtype.to_physical();
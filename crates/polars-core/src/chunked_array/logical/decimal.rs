@@ -71,6 +71,19 @@ impl LogicalType for DecimalChunked {
                     .into_series());
             },
 
+            #[cfg(feature = "dtype-decimal256")]
+            DataType::Decimal256(to_prec, to_scale) => {
+                let out = self.to_decimal256(*to_prec, *to_scale)?;
+                if cast_options.is_strict() {
+                    polars_ensure!(
+                        out.null_count() == self.null_count(),
+                        ComputeError: "strict cast failed, decimal values don't fit in {}",
+                        dtype
+                    );
+                }
+                Ok(out.into_series())
+            },
+
             dt if dt.is_primitive_numeric()
                 | matches!(dt, DataType::String | DataType::Boolean) =>
             {
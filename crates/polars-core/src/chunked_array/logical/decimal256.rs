@@ -0,0 +1,169 @@
+use std::borrow::Cow;
+
+use polars_compute::decimal::{
+    dec128_to_dec256, dec128_verify_prec_scale, dec256_fits, dec256_rescale, dec256_to_dec128,
+    dec256_verify_prec_scale,
+};
+
+use super::*;
+use crate::chunked_array::cast::cast_chunks;
+use crate::prelude::arity::unary_elementwise;
+use crate::prelude::*;
+
+pub type Decimal256Chunked = Logical<Decimal256Type, Int256Type>;
+
+impl Int256Chunked {
+    #[inline]
+    pub fn into_decimal256_unchecked(self, precision: usize, scale: usize) -> Decimal256Chunked {
+        // SAFETY: no invalid states (from a safety perspective).
+        unsafe { Decimal256Chunked::new_logical(self, DataType::Decimal256(precision, scale)) }
+    }
+
+    pub fn into_decimal256(
+        self,
+        precision: usize,
+        scale: usize,
+    ) -> PolarsResult<Decimal256Chunked> {
+        dec256_verify_prec_scale(precision, scale)?;
+        let oob = self
+            .downcast_iter()
+            .flat_map(|arr| arr.iter().flatten())
+            .find(|v| !dec256_fits(**v, precision));
+        if let Some(v) = oob {
+            polars_bail!(
+                ComputeError: "decimal precision {} can't fit values with {} digits",
+                precision,
+                v.0.unsigned_abs().to_string().len()
+            );
+        }
+        Ok(self.into_decimal256_unchecked(precision, scale))
+    }
+}
+
+impl LogicalType for Decimal256Chunked {
+    fn dtype(&self) -> &DataType {
+        &self.dtype
+    }
+
+    #[inline]
+    fn get_any_value(&self, i: usize) -> PolarsResult<AnyValue<'_>> {
+        polars_ensure!(i < self.len(), oob = i, self.len());
+        Ok(unsafe { self.get_any_value_unchecked(i) })
+    }
+
+    #[inline]
+    unsafe fn get_any_value_unchecked(&self, i: usize) -> AnyValue<'_> {
+        match self.phys.get_unchecked(i) {
+            Some(v) => AnyValue::Decimal256(v, self.precision(), self.scale()),
+            None => AnyValue::Null,
+        }
+    }
+
+    fn cast_with_options(
+        &self,
+        dtype: &DataType,
+        cast_options: CastOptions,
+    ) -> PolarsResult<Series> {
+        match dtype {
+            DataType::Decimal256(to_prec, to_scale) => {
+                let out = self.with_prec_scale(*to_prec, *to_scale)?.into_owned();
+                if cast_options.is_strict() {
+                    polars_ensure!(
+                        out.null_count() == self.null_count(),
+                        ComputeError: "strict cast failed, decimal values don't fit in {}",
+                        dtype
+                    );
+                }
+                Ok(out.into_series())
+            },
+
+            DataType::Decimal(to_prec, to_scale) => {
+                dec128_verify_prec_scale(*to_prec, *to_scale)?;
+                let old_s = self.scale();
+                let phys: Int128Chunked = unary_elementwise(&self.phys, |x| {
+                    dec256_to_dec128(x?, old_s, *to_prec, *to_scale)
+                });
+                if cast_options.is_strict() {
+                    polars_ensure!(
+                        phys.null_count() == self.null_count(),
+                        ComputeError: "strict cast failed, decimal values don't fit in {}",
+                        dtype
+                    );
+                }
+                Ok(phys
+                    .into_decimal_unchecked(*to_prec, *to_scale)
+                    .into_series())
+            },
+
+            dt if dt.is_primitive_numeric()
+                | matches!(dt, DataType::String | DataType::Boolean) =>
+            {
+                // Normally we don't set the Arrow logical type, but now we temporarily set it so
+                // we can re-use the compute cast kernels.
+                let arrow_dtype = self.dtype().to_arrow(CompatLevel::newest());
+                let chunks = self
+                    .physical()
+                    .downcast_iter()
+                    .map(|arr| arr.clone().to(arrow_dtype.clone()).to_boxed())
+                    .collect::<Vec<_>>();
+                let chunks = cast_chunks(&chunks, dtype, cast_options)?;
+                Series::try_from((self.name().clone(), chunks))
+            },
+
+            dt => {
+                polars_bail!(
+                    InvalidOperation:
+                    "casting from {:?} to {:?} not supported",
+                    self.dtype(), dt
+                )
+            },
+        }
+    }
+}
+
+impl Decimal256Chunked {
+    pub fn precision(&self) -> usize {
+        match &self.dtype {
+            DataType::Decimal256(precision, _) => *precision,
+            _ => unreachable!(),
+        }
+    }
+
+    pub fn scale(&self) -> usize {
+        match &self.dtype {
+            DataType::Decimal256(_, scale) => *scale,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Rescales to the given precision and scale. Values which don't fit become null.
+    pub fn with_prec_scale(&self, prec: usize, scale: usize) -> PolarsResult<Cow<'_, Self>> {
+        if self.precision() == prec && self.scale() == scale {
+            return Ok(Cow::Borrowed(self));
+        }
+
+        dec256_verify_prec_scale(prec, scale)?;
+        let phys = if self.scale() == scale && prec >= self.precision() {
+            // Increasing precision is always allowed.
+            self.phys.clone()
+        } else {
+            let old_s = self.scale();
+            unary_elementwise(&self.phys, |x| dec256_rescale(x?, old_s, prec, scale))
+        };
+
+        let ca = unsafe { Decimal256Chunked::new_logical(phys, DataType::Decimal256(prec, scale)) };
+        Ok(Cow::Owned(ca))
+    }
+}
+
+impl DecimalChunked {
+    /// Widens to a [`Decimal256Chunked`] with the given precision and scale. Values which don't
+    /// fit become null.
+    pub fn to_decimal256(&self, prec: usize, scale: usize) -> PolarsResult<Decimal256Chunked> {
+        dec256_verify_prec_scale(prec, scale)?;
+        let old_s = self.scale();
+        let phys: Int256Chunked =
+            unary_elementwise(&self.phys, |x| dec128_to_dec256(x?, old_s, prec, scale));
+        Ok(phys.into_decimal256_unchecked(prec, scale))
+    }
+}
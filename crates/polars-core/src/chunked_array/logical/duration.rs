@@ -1,4 +1,6 @@
 use super::*;
+#[cfg(feature = "dtype-interval")]
+use crate::chunked_array::ops::arity::unary_elementwise;
 use crate::prelude::*;
 
 pub type DurationChunked = Logical<DurationType, Int64Type>;
@@ -54,6 +56,19 @@ impl LogicalType for DurationChunked {
                 };
                 Ok(out.into_duration(to_unit).into_series())
             },
+            #[cfg(feature = "dtype-interval")]
+            Interval => {
+                let tu = self.time_unit();
+                let phys: Int128Chunked = unary_elementwise(&self.phys, |opt| {
+                    opt.and_then(|v| duration_to_interval(v, tu))
+                        .map(interval_to_i128)
+                });
+                polars_ensure!(
+                    !cast_options.is_strict() || phys.null_count() == self.null_count(),
+                    InvalidOperation: "conversion from {} to Interval overflowed", self.dtype()
+                );
+                Ok(phys.into_interval().into_series())
+            },
             dt if dt.is_primitive_numeric() => self.phys.cast_with_options(dtype, cast_options),
             dt => {
                 polars_bail!(
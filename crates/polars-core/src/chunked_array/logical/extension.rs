@@ -1,3 +1,5 @@
+use std::borrow::Cow;
+
 use crate::chunked_array::cast::CastOptions;
 use crate::prelude::*;
 
@@ -64,11 +66,31 @@ impl ExtensionChunked {
         self.storage().get(i)
     }
 
+    /// The key to compare and sort the values by, see [`ExtensionTypeImpl::comparison_key`].
+    ///
+    /// [`ExtensionTypeImpl::comparison_key`]: crate::datatypes::extension::ExtensionTypeImpl::comparison_key
+    pub fn comparison_key(&self) -> PolarsResult<Cow<'_, Series>> {
+        self.extension_type().comparison_key(&self.storage)
+    }
+
     pub fn cast_with_options(
         &self,
         dtype: &DataType,
-        _options: CastOptions,
+        options: CastOptions,
     ) -> PolarsResult<Series> {
+        if let Some(out) = self
+            .extension_type()
+            .0
+            .cast_to(&self.storage, dtype, options)
+        {
+            let out = out?;
+            polars_ensure!(
+                out.dtype() == dtype,
+                ComputeError: "cast of extension type {} returned {:?}, expected {:?}",
+                self.dtype, out.dtype(), dtype
+            );
+            return Ok(out);
+        }
         polars_bail!(ComputeError: "cannot cast extension types to {dtype:?}")
     }
 }
@@ -0,0 +1,308 @@
+use super::*;
+use crate::chunked_array::ops::arity::{unary_elementwise, unary_elementwise_values};
+use crate::prelude::*;
+
+pub type IntervalChunked = Logical<IntervalType, Int128Type>;
+
+/// Pack an interval into its physical 128-bit representation.
+///
+/// The days and nanoseconds are stored with their sign bit flipped, so that ordering the
+/// physical values orders the intervals by months, then days, then nanoseconds.
+#[inline]
+pub fn interval_to_i128(v: months_days_ns) -> i128 {
+    ((v.months() as i128) << 96)
+        | ((((v.days() as u32) ^ (1 << 31)) as i128) << 64)
+        | (((v.ns() as u64) ^ (1 << 63)) as i128)
+}
+
+/// Unpack an interval from its physical 128-bit representation.
+#[inline]
+pub fn interval_from_i128(v: i128) -> months_days_ns {
+    let months = (v >> 96) as i32;
+    let days = (((v >> 64) as u32) ^ (1 << 31)) as i32;
+    let ns = ((v as u64) ^ (1 << 63)) as i64;
+    months_days_ns::new(months, days, ns)
+}
+
+/// Parse an interval such as `"1y 2mo -3d 4h30m"`.
+///
+/// Every component is an integer followed by a unit and carries its own sign. Components may
+/// be separated by whitespace. The supported units are:
+///
+/// - `y`: year (12 months)
+/// - `q`: quarter (3 months)
+/// - `mo`: month
+/// - `w`: week (7 days)
+/// - `d`: day
+/// - `h`, `m`, `s`, `ms`, `us`, `ns`: hours down to nanoseconds
+///
+/// Returns `None` if the string is not a valid interval or if a component overflows.
+pub fn parse_interval(s: &str) -> Option<months_days_ns> {
+    let mut months = 0i32;
+    let mut days = 0i32;
+    let mut ns = 0i64;
+
+    let mut rest = s.trim();
+    if rest.is_empty() {
+        return None;
+    }
+    while !rest.is_empty() {
+        let (negative, unsigned) = match rest.as_bytes()[0] {
+            b'-' => (true, &rest[1..]),
+            b'+' => (false, &rest[1..]),
+            _ => (false, rest),
+        };
+        let n_digits = unsigned.bytes().take_while(u8::is_ascii_digit).count();
+        if n_digits == 0 {
+            return None;
+        }
+        let mut n = unsigned[..n_digits].parse::<i64>().ok()?;
+        if negative {
+            n = -n;
+        }
+        let unsigned = &unsigned[n_digits..];
+        let unit_len = unsigned
+            .find(|c: char| c.is_ascii_digit() || c.is_whitespace() || c == '-' || c == '+')
+            .unwrap_or(unsigned.len());
+
+        let add_months =
+            |months: i32, mult: i64| months.checked_add(i32::try_from(n.checked_mul(mult)?).ok()?);
+        let add_days =
+            |days: i32, mult: i64| days.checked_add(i32::try_from(n.checked_mul(mult)?).ok()?);
+        match &unsigned[..unit_len] {
+            "y" => months = add_months(months, 12)?,
+            "q" => months = add_months(months, 3)?,
+            "mo" => months = add_months(months, 1)?,
+            "w" => days = add_days(days, 7)?,
+            "d" => days = add_days(days, 1)?,
+            unit => {
+                let mult = match unit {
+                    "h" => 3_600_000_000_000,
+                    "m" => 60_000_000_000,
+                    "s" => 1_000_000_000,
+                    "ms" => 1_000_000,
+                    "us" | "µs" => 1_000,
+                    "ns" => 1,
+                    _ => return None,
+                };
+                ns = ns.checked_add(n.checked_mul(mult)?)?;
+            },
+        }
+        rest = unsigned[unit_len..].trim_start();
+    }
+    Some(months_days_ns::new(months, days, ns))
+}
+
+/// Convert an interval to a duration, counting a day as 24 hours.
+///
+/// Returns `None` if the interval has a month component, as months don't have a fixed length.
+pub fn interval_to_duration(v: months_days_ns, tu: TimeUnit) -> Option<i64> {
+    if v.months() != 0 {
+        return None;
+    }
+    let ns = (v.days() as i64)
+        .checked_mul(NS_IN_DAY)?
+        .checked_add(v.ns())?;
+    Some(match tu {
+        TimeUnit::Nanoseconds => ns,
+        TimeUnit::Microseconds => ns / 1_000,
+        TimeUnit::Milliseconds => ns / 1_000_000,
+    })
+}
+
+/// Convert a duration to an interval of nanoseconds.
+pub fn duration_to_interval(v: i64, tu: TimeUnit) -> Option<months_days_ns> {
+    let ns = match tu {
+        TimeUnit::Nanoseconds => v,
+        TimeUnit::Microseconds => v.checked_mul(1_000)?,
+        TimeUnit::Milliseconds => v.checked_mul(1_000_000)?,
+    };
+    Some(months_days_ns::new(0, 0, ns))
+}
+
+impl Int128Chunked {
+    /// Interpret the values as packed intervals, see [`interval_to_i128`].
+    pub fn into_interval(self) -> IntervalChunked {
+        // SAFETY: no invalid states.
+        unsafe { IntervalChunked::new_logical(self, DataType::Interval) }
+    }
+}
+
+impl StringChunked {
+    /// Parse the strings as intervals, see [`parse_interval`].
+    pub fn to_interval(&self) -> IntervalChunked {
+        let phys: Int128Chunked = unary_elementwise(self, |opt| {
+            opt.and_then(parse_interval).map(interval_to_i128)
+        });
+        phys.into_interval()
+    }
+}
+
+impl IntervalChunked {
+    pub fn from_intervals<I: IntoIterator<Item = Option<months_days_ns>>>(
+        name: PlSmallStr,
+        v: I,
+    ) -> Self {
+        Int128Chunked::from_iter_options(name, v.into_iter().map(|v| v.map(interval_to_i128)))
+            .into_interval()
+    }
+
+    /// Extract the months component.
+    pub fn months(&self) -> Int32Chunked {
+        unary_elementwise_values(&self.phys, |v| interval_from_i128(v).months())
+    }
+
+    /// Extract the days component.
+    pub fn days(&self) -> Int32Chunked {
+        unary_elementwise_values(&self.phys, |v| interval_from_i128(v).days())
+    }
+
+    /// Extract the nanoseconds component.
+    pub fn nanoseconds(&self) -> Int64Chunked {
+        unary_elementwise_values(&self.phys, |v| interval_from_i128(v).ns())
+    }
+
+    pub fn to_string(&self) -> StringChunked {
+        self.phys.apply_into_string_amortized(|v, buf| {
+            crate::fmt::fmt_interval_string(buf, interval_from_i128(v)).unwrap()
+        })
+    }
+}
+
+impl LogicalType for IntervalChunked {
+    fn dtype(&self) -> &DataType {
+        &self.dtype
+    }
+
+    fn get_any_value(&self, i: usize) -> PolarsResult<AnyValue<'_>> {
+        self.phys.get_any_value(i).map(|av| av.as_interval())
+    }
+
+    unsafe fn get_any_value_unchecked(&self, i: usize) -> AnyValue<'_> {
+        self.phys.get_any_value_unchecked(i).as_interval()
+    }
+
+    fn cast_with_options(
+        &self,
+        dtype: &DataType,
+        cast_options: CastOptions,
+    ) -> PolarsResult<Series> {
+        match dtype {
+            DataType::Interval => Ok(self.clone().into_series()),
+            DataType::String => Ok(self.to_string().into_series()),
+            DataType::Duration(tu) => {
+                let tu = *tu;
+                let out: Int64Chunked = unary_elementwise(&self.phys, |opt| {
+                    opt.and_then(|v| interval_to_duration(interval_from_i128(v), tu))
+                });
+                polars_ensure!(
+                    !cast_options.is_strict() || out.null_count() == self.null_count(),
+                    InvalidOperation: "cannot cast an Interval with a month component to {}", dtype
+                );
+                Ok(out.into_duration(tu).into_series())
+            },
+            dt => polars_bail!(
+                InvalidOperation:
+                "casting from {:?} to {:?} not supported",
+                self.dtype(), dt
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_interval_physical_roundtrip_and_order() {
+        let values = [
+            months_days_ns::new(-1, 0, 0),
+            months_days_ns::new(0, -3, i64::MAX),
+            months_days_ns::new(0, 0, -1),
+            months_days_ns::new(0, 0, 0),
+            months_days_ns::new(0, 0, 1),
+            months_days_ns::new(0, 1, i64::MIN),
+            months_days_ns::new(1, i32::MIN, 0),
+            months_days_ns::new(i32::MAX, i32::MAX, i64::MAX),
+        ];
+        for w in values.windows(2) {
+            assert!(interval_to_i128(w[0]) < interval_to_i128(w[1]));
+        }
+        for v in values {
+            assert_eq!(interval_from_i128(interval_to_i128(v)), v);
+        }
+    }
+
+    #[test]
+    fn test_parse_interval() {
+        assert_eq!(
+            parse_interval("1y 2mo -3d 4h30m"),
+            Some(months_days_ns::new(14, -3, 16_200_000_000_000))
+        );
+        assert_eq!(
+            parse_interval("1q2w+5us"),
+            Some(months_days_ns::new(3, 14, 5_000))
+        );
+        assert_eq!(parse_interval("0s"), Some(months_days_ns::default()));
+        assert_eq!(parse_interval(""), None);
+        assert_eq!(parse_interval("1"), None);
+        assert_eq!(parse_interval("1x"), None);
+        assert_eq!(parse_interval("d"), None);
+        assert_eq!(parse_interval("300000000y"), None);
+    }
+
+    #[test]
+    fn test_interval_string_roundtrip() {
+        let s = StringChunked::new(
+            PlSmallStr::from_static("a"),
+            &[Some("1y 2mo -3d 4h30m"), None, Some("-1mo 1ms"), Some("0s")],
+        );
+        let ca = s.to_interval();
+        assert_eq!(
+            ca.get_any_value(0).unwrap(),
+            AnyValue::Interval(months_days_ns::new(14, -3, 16_200_000_000_000))
+        );
+        assert_eq!(ca.null_count(), 1);
+
+        let out = ca.to_string();
+        assert_eq!(
+            Vec::from(&out),
+            &[
+                Some("1y 2mo -3d 4h 30m"),
+                None,
+                Some("-1mo 1ms"),
+                Some("0s")
+            ]
+        );
+        assert_eq!(Vec::from(&out.to_interval().phys), Vec::from(&ca.phys));
+    }
+
+    #[test]
+    fn test_interval_duration_cast() {
+        let ca = IntervalChunked::from_intervals(
+            PlSmallStr::from_static("a"),
+            [
+                Some(months_days_ns::new(0, 1, 1_000)),
+                Some(months_days_ns::new(1, 0, 0)),
+            ],
+        );
+        let out = ca
+            .cast_with_options(
+                &DataType::Duration(TimeUnit::Microseconds),
+                CastOptions::NonStrict,
+            )
+            .unwrap();
+        assert_eq!(
+            Vec::from(out.duration().unwrap().physical()),
+            &[Some(86_400_000_001), None]
+        );
+        assert!(
+            ca.cast_with_options(
+                &DataType::Duration(TimeUnit::Microseconds),
+                CastOptions::Strict
+            )
+            .is_err()
+        );
+    }
+}
@@ -0,0 +1,155 @@
+use arrow::array::StructArray;
+
+use super::*;
+use crate::prelude::*;
+
+pub type MapChunked = Logical<MapType, ListType>;
+
+impl ListChunked {
+    /// Interpret a list of two-field structs as a map.
+    ///
+    /// The first field of the structs holds the keys and the second field the values. The fields
+    /// are renamed to `key` and `value`.
+    pub fn into_map(self) -> PolarsResult<MapChunked> {
+        let fields = match self.inner_dtype() {
+            DataType::Struct(fields) if fields.len() == 2 => fields,
+            dt => polars_bail!(
+                SchemaMismatch: "expected a list of {{key, value}} structs to create a map, got list[{}]", dt
+            ),
+        };
+        let key = fields[0].dtype().clone();
+        let value = fields[1].dtype().clone();
+        let entries = DataType::map_entries_dtype(key.clone(), value.clone());
+
+        let phys = if self.inner_dtype() == &entries {
+            self
+        } else {
+            let arrow_entries = entries.to_physical().to_arrow(CompatLevel::newest());
+            let chunks = self
+                .downcast_iter()
+                .map(|arr| {
+                    let values = arr.values().as_any().downcast_ref::<StructArray>().unwrap();
+                    let values = StructArray::new(
+                        arrow_entries.clone(),
+                        values.len(),
+                        values.values().to_vec(),
+                        values.validity().cloned(),
+                    );
+                    ListArray::<i64>::new(
+                        ListArray::<i64>::default_datatype(arrow_entries.clone()),
+                        arr.offsets().clone(),
+                        values.boxed(),
+                        arr.validity().cloned(),
+                    )
+                    .boxed()
+                })
+                .collect();
+            // SAFETY: only the names of the struct fields changed.
+            unsafe {
+                ListChunked::from_chunks_and_dtype_unchecked(
+                    self.name().clone(),
+                    chunks,
+                    DataType::List(Box::new(entries)),
+                )
+            }
+        };
+
+        // SAFETY: the entries have the `{key, value}` layout.
+        Ok(unsafe { MapChunked::new_logical(phys, DataType::Map(Box::new(key), Box::new(value))) })
+    }
+}
+
+impl MapChunked {
+    pub fn key_dtype(&self) -> &DataType {
+        match self.dtype() {
+            DataType::Map(key, _) => key,
+            _ => unreachable!(),
+        }
+    }
+
+    pub fn value_dtype(&self) -> &DataType {
+        match self.dtype() {
+            DataType::Map(_, value) => value,
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl LogicalType for MapChunked {
+    fn dtype(&self) -> &DataType {
+        &self.dtype
+    }
+
+    fn get_any_value(&self, i: usize) -> PolarsResult<AnyValue<'_>> {
+        self.phys.get_any_value(i)
+    }
+
+    unsafe fn get_any_value_unchecked(&self, i: usize) -> AnyValue<'_> {
+        self.phys.get_any_value_unchecked(i)
+    }
+
+    fn cast_with_options(
+        &self,
+        dtype: &DataType,
+        cast_options: CastOptions,
+    ) -> PolarsResult<Series> {
+        match dtype {
+            DataType::Map(key, value) => {
+                let entries = DataType::map_entries_dtype(*key.clone(), *value.clone());
+                let phys = self
+                    .phys
+                    .cast_with_options(&DataType::List(Box::new(entries)), cast_options)?;
+                Ok(phys.list().unwrap().clone().into_map()?.into_series())
+            },
+            DataType::List(_) => self.phys.cast_with_options(dtype, cast_options),
+            dt => polars_bail!(
+                InvalidOperation:
+                "casting from {:?} to {:?} not supported",
+                self.dtype(), dt
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_list_into_map() {
+        let entries = StructChunked::from_series(
+            PlSmallStr::from_static("entries"),
+            3,
+            [
+                Series::new(PlSmallStr::from_static("k"), &["a", "b", "c"]),
+                Series::new(PlSmallStr::from_static("v"), &[1i32, 2, 3]),
+            ]
+            .iter(),
+        )
+        .unwrap()
+        .into_series();
+        let list = entries.implode().unwrap();
+
+        let map = list.into_map().unwrap();
+        assert_eq!(
+            map.dtype(),
+            &DataType::Map(Box::new(DataType::String), Box::new(DataType::Int32))
+        );
+        assert_eq!(
+            map.physical().inner_dtype(),
+            &DataType::map_entries_dtype(DataType::String, DataType::Int32)
+        );
+
+        let out = map
+            .cast(&DataType::Map(
+                Box::new(DataType::String),
+                Box::new(DataType::Int64),
+            ))
+            .unwrap();
+        assert_eq!(
+            out.dtype(),
+            &DataType::Map(Box::new(DataType::String), Box::new(DataType::Int64))
+        );
+        assert_eq!(out.len(), 1);
+    }
+}
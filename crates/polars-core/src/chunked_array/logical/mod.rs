@@ -14,6 +14,10 @@ pub use datetime::*;
 mod decimal;
 #[cfg(feature = "dtype-decimal")]
 pub use decimal::*;
+#[cfg(feature = "dtype-decimal256")]
+mod decimal256;
+#[cfg(feature = "dtype-decimal256")]
+pub use decimal256::*;
 #[cfg(feature = "dtype-duration")]
 mod duration;
 #[cfg(feature = "dtype-duration")]
@@ -22,12 +26,24 @@ pub use duration::*;
 mod extension;
 #[cfg(feature = "dtype-extension")]
 pub use extension::*;
+#[cfg(feature = "dtype-interval")]
+mod interval;
+#[cfg(feature = "dtype-interval")]
+pub use interval::*;
+#[cfg(feature = "dtype-map")]
+mod map;
 #[cfg(feature = "dtype-time")]
 mod time;
+#[cfg(feature = "dtype-variant")]
+mod variant;
 use std::marker::PhantomData;
 
+#[cfg(feature = "dtype-map")]
+pub use map::*;
 #[cfg(feature = "dtype-time")]
 pub use time::*;
+#[cfg(feature = "dtype-variant")]
+pub use variant::*;
 
 use crate::chunked_array::cast::CastOptions;
 use crate::prelude::*;
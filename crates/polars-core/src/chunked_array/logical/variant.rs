@@ -0,0 +1,162 @@
+use arrow::array::StructArray;
+use polars_compute::variant::{VariantRef, json_to_variant, struct_to_variant};
+
+use super::*;
+use crate::prelude::*;
+
+pub type VariantChunked = Logical<VariantType, BinaryType>;
+
+impl BinaryChunked {
+    /// Interpret encoded variants as a [`VariantChunked`].
+    ///
+    /// Values that are not a valid variant raise an error if `strict`, otherwise they become null.
+    pub fn into_variant(self, strict: bool) -> PolarsResult<VariantChunked> {
+        let mut invalid = false;
+        for v in self.iter().flatten() {
+            if VariantRef::try_new(v).is_err() {
+                polars_ensure!(!strict, ComputeError: "binary value is not a valid variant");
+                invalid = true;
+                break;
+            }
+        }
+        let phys = if invalid {
+            let mut phys: BinaryChunked = self
+                .iter()
+                .map(|opt| opt.filter(|v| VariantRef::try_new(v).is_ok()))
+                .collect();
+            phys.rename(self.name().clone());
+            phys
+        } else {
+            self
+        };
+        // SAFETY: all non-null values are valid variants.
+        Ok(unsafe { VariantChunked::new_logical(phys, DataType::Variant) })
+    }
+}
+
+impl StringChunked {
+    /// Parse JSON documents into variants.
+    ///
+    /// Invalid JSON raises an error if `strict`, otherwise it becomes null.
+    pub fn json_to_variant(&self, strict: bool) -> PolarsResult<VariantChunked> {
+        let mut phys: BinaryChunked = if strict {
+            self.iter()
+                .map(|opt| opt.map(|s| json_to_variant(s.as_bytes())).transpose())
+                .collect::<PolarsResult<_>>()?
+        } else {
+            self.iter()
+                .map(|opt| opt.and_then(|s| json_to_variant(s.as_bytes()).ok()))
+                .collect()
+        };
+        phys.rename(self.name().clone());
+        // SAFETY: the values were encoded as variants.
+        Ok(unsafe { VariantChunked::new_logical(phys, DataType::Variant) })
+    }
+}
+
+impl StructChunked {
+    /// Reassemble variants from a `{metadata, value}` struct, which may also hold a shredded
+    /// `typed_value` field.
+    pub fn struct_to_variant(&self) -> PolarsResult<VariantChunked> {
+        let s = self.clone().into_series();
+        let chunks = (0..s.chunks().len())
+            .map(|i| {
+                let arr = s.to_arrow(i, CompatLevel::newest());
+                let arr = arr.as_any().downcast_ref::<StructArray>().unwrap();
+                Ok(struct_to_variant(arr)?.boxed())
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let phys = unsafe {
+            BinaryChunked::from_chunks_and_dtype_unchecked(
+                self.name().clone(),
+                chunks,
+                DataType::Binary,
+            )
+        };
+        // SAFETY: `struct_to_variant` produces encoded variants.
+        Ok(unsafe { VariantChunked::new_logical(phys, DataType::Variant) })
+    }
+}
+
+impl VariantChunked {
+    /// Convert the variants to JSON strings.
+    pub fn to_json(&self) -> PolarsResult<StringChunked> {
+        let mut buf = String::new();
+        let mut out: StringChunked = self
+            .phys
+            .iter()
+            .map(|opt| {
+                opt.map(|v| {
+                    buf.clear();
+                    VariantRef::try_new(v)?.write_json(&mut buf)?;
+                    Ok(buf.clone())
+                })
+                .transpose()
+            })
+            .collect::<PolarsResult<_>>()?;
+        out.rename(self.name().clone());
+        Ok(out)
+    }
+}
+
+impl LogicalType for VariantChunked {
+    fn dtype(&self) -> &DataType {
+        &DataType::Variant
+    }
+
+    fn get_any_value(&self, i: usize) -> PolarsResult<AnyValue<'_>> {
+        self.phys.get_any_value(i)
+    }
+
+    unsafe fn get_any_value_unchecked(&self, i: usize) -> AnyValue<'_> {
+        self.phys.get_any_value_unchecked(i)
+    }
+
+    fn cast_with_options(
+        &self,
+        dtype: &DataType,
+        _cast_options: CastOptions,
+    ) -> PolarsResult<Series> {
+        match dtype {
+            DataType::Variant => Ok(self.clone().into_series()),
+            DataType::String => Ok(self.to_json()?.into_series()),
+            DataType::Binary => Ok(self.phys.clone().into_series()),
+            dt => polars_bail!(
+                InvalidOperation:
+                "casting from {:?} to {:?} not supported, use `variant.get` to extract typed values",
+                self.dtype(), dt
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_variant_json_roundtrip() {
+        let ca = StringChunked::new(
+            PlSmallStr::from_static("a"),
+            &[Some(r#"{"b": 1, "a": [true, null]}"#), None, Some("1.5")],
+        );
+        let variant = ca.json_to_variant(true).unwrap();
+        assert_eq!(variant.dtype(), &DataType::Variant);
+
+        let out = variant.to_json().unwrap();
+        assert_eq!(
+            out.iter().collect::<Vec<_>>(),
+            [Some(r#"{"a":[true,null],"b":1}"#), None, Some("1.5")]
+        );
+
+        assert!(
+            StringChunked::new(PlSmallStr::EMPTY, &["{"])
+                .json_to_variant(true)
+                .is_err()
+        );
+        let lax = StringChunked::new(PlSmallStr::EMPTY, &["{"])
+            .json_to_variant(false)
+            .unwrap();
+        assert_eq!(lax.null_count(), 1);
+    }
+}
@@ -34,6 +34,8 @@ pub(crate) unsafe fn arr_to_any_value<'a>(
     match dtype {
         DataType::String => downcast_and_pack!(Utf8ViewArray, String),
         DataType::Binary => downcast_and_pack!(BinaryViewArray, Binary),
+        #[cfg(feature = "dtype-variant")]
+        DataType::Variant => downcast_and_pack!(BinaryViewArray, Binary),
         DataType::Boolean => downcast_and_pack!(BooleanArray, Boolean),
         DataType::UInt8 => downcast_and_pack!(UInt8Array, UInt8),
         DataType::UInt16 => downcast_and_pack!(UInt16Array, UInt16),
@@ -64,6 +66,19 @@ pub(crate) unsafe fn arr_to_any_value<'a>(
                 AnyValue::List(s)
             }
         },
+        #[cfg(feature = "dtype-map")]
+        DataType::Map(key, value) => {
+            let v: ArrayRef = downcast!(LargeListArray);
+            let entries = DataType::map_entries_dtype(*key.clone(), *value.clone());
+            let s = Series::from_chunks_and_dtype_unchecked(
+                PlSmallStr::EMPTY,
+                vec![v],
+                &entries.to_physical(),
+            )
+            .from_physical_unchecked(&entries)
+            .unwrap();
+            AnyValue::List(s)
+        },
         #[cfg(feature = "dtype-array")]
         DataType::Array(dt, width) => {
             let v: ArrayRef = downcast!(FixedSizeListArray);
@@ -128,12 +143,31 @@ pub(crate) unsafe fn arr_to_any_value<'a>(
             let v = arr.value_unchecked(idx);
             AnyValue::Time(v)
         },
+        #[cfg(feature = "dtype-interval")]
+        DataType::Interval => {
+            let arr = &*(arr as *const dyn Array as *const Int128Array);
+            let v = arr.value_unchecked(idx);
+            AnyValue::Interval(interval_from_i128(v))
+        },
         #[cfg(feature = "dtype-decimal")]
         DataType::Decimal(precision, scale) => {
             let arr = &*(arr as *const dyn Array as *const Int128Array);
             let v = arr.value_unchecked(idx);
             AnyValue::Decimal(v, *precision, *scale)
         },
+        #[cfg(feature = "dtype-decimal256")]
+        DataType::Decimal256(precision, scale) => {
+            let arr = &*(arr as *const dyn Array as *const PrimitiveArray<i256>);
+            let v = arr.value_unchecked(idx);
+            AnyValue::Decimal256(v, *precision, *scale)
+        },
+        // There is no 256-bit integer `AnyValue`, physical values show up as unscaled decimals.
+        #[cfg(feature = "dtype-decimal256")]
+        DataType::Int256 => {
+            let arr = &*(arr as *const dyn Array as *const PrimitiveArray<i256>);
+            let v = arr.value_unchecked(idx);
+            AnyValue::Decimal256(v, polars_compute::decimal::DEC256_MAX_PREC, 0)
+        },
         #[cfg(feature = "dtype-extension")]
         DataType::Extension(typ, storage) => arr_to_any_value(arr, idx, storage),
         #[cfg(feature = "object")]
@@ -246,6 +280,18 @@ impl ChunkAnyValue for BinaryOffsetChunked {
     }
 }
 
+#[cfg(feature = "dtype-decimal256")]
+impl ChunkAnyValue for Int256Chunked {
+    #[inline]
+    unsafe fn get_any_value_unchecked(&self, index: usize) -> AnyValue<'_> {
+        get_any_value_unchecked!(self, index)
+    }
+
+    fn get_any_value(&self, index: usize) -> PolarsResult<AnyValue<'_>> {
+        get_any_value!(self, index)
+    }
+}
+
 impl ChunkAnyValueBypassValidity for BinaryOffsetChunked {
     #[inline]
     unsafe fn get_any_value_bypass_validity(&self, index: usize) -> AnyValue<'_> {
@@ -28,6 +28,15 @@ where
         ChunkedArray::with_chunk(name, arr)
     }
 }
+#[cfg(feature = "dtype-decimal256")]
+impl ChunkFullNull for Int256Chunked {
+    fn full_null(name: PlSmallStr, length: usize) -> Self {
+        let arr =
+            PrimitiveArray::new_null(DataType::Int256.to_arrow(CompatLevel::newest()), length);
+        ChunkedArray::with_chunk(name, arr)
+    }
+}
+
 impl ChunkFull<bool> for BooleanChunked {
     fn full(name: PlSmallStr, value: bool, length: usize) -> Self {
         let bits = Bitmap::new_with_value(value, length);
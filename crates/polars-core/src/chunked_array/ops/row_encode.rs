@@ -97,6 +97,10 @@ pub fn get_row_encoding_context(dtype: &DataType) -> Option<RowEncodingContext>
         | DataType::Date
         | DataType::Datetime(_, _)
         | DataType::Duration(_) => None,
+        #[cfg(feature = "dtype-interval")]
+        DataType::Interval => None,
+        #[cfg(feature = "dtype-variant")]
+        DataType::Variant => None,
 
         #[cfg(feature = "dtype-categorical")]
         DataType::Categorical(_, mapping) | DataType::Enum(_, mapping) => {
@@ -117,10 +121,16 @@ pub fn get_row_encoding_context(dtype: &DataType) -> Option<RowEncodingContext>
 
         #[cfg(feature = "dtype-decimal")]
         DataType::Decimal(precision, _) => Some(RowEncodingContext::Decimal(*precision)),
+        #[cfg(feature = "dtype-decimal256")]
+        DataType::Decimal256(_, _) | DataType::Int256 => None,
 
         #[cfg(feature = "dtype-array")]
         DataType::Array(dtype, _) => get_row_encoding_context(dtype),
         DataType::List(dtype) => get_row_encoding_context(dtype),
+        #[cfg(feature = "dtype-map")]
+        DataType::Map(key, value) => {
+            get_row_encoding_context(&DataType::map_entries_dtype(*key.clone(), *value.clone()))
+        },
         #[cfg(feature = "dtype-struct")]
         DataType::Struct(fs) => {
             let mut ctxts = Vec::new();
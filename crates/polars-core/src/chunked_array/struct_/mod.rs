@@ -264,6 +264,8 @@ impl StructChunked {
                 }
                 Ok(out.into_series())
             },
+            #[cfg(feature = "dtype-variant")]
+            DataType::Variant => Ok(self.struct_to_variant()?.into_series()),
             DataType::String => {
                 let len = self.len();
                 let name = self.name().clone();
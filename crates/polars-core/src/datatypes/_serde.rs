@@ -94,6 +94,10 @@ enum SerializableDataType {
     },
     #[cfg(feature = "dtype-decimal")]
     Decimal(usize, usize),
+    #[cfg(feature = "dtype-decimal256")]
+    Decimal256(usize, usize),
+    #[cfg(feature = "dtype-decimal256")]
+    Int256,
     #[cfg(feature = "object")]
     Object(String),
     #[cfg(feature = "dtype-extension")]
@@ -102,6 +106,15 @@ enum SerializableDataType {
         metadata: Option<String>,
         storage: Box<SerializableDataType>,
     },
+    #[cfg(feature = "dtype-interval")]
+    Interval,
+    #[cfg(feature = "dtype-variant")]
+    Variant,
+    #[cfg(feature = "dtype-map")]
+    Map {
+        key: Box<SerializableDataType>,
+        value: Box<SerializableDataType>,
+    },
 }
 
 impl From<&DataType> for SerializableDataType {
@@ -129,6 +142,10 @@ impl From<&DataType> for SerializableDataType {
             Datetime(tu, tz) => Self::Datetime(*tu, tz.clone()),
             Duration(tu) => Self::Duration(*tu),
             Time => Self::Time,
+            #[cfg(feature = "dtype-interval")]
+            Interval => Self::Interval,
+            #[cfg(feature = "dtype-variant")]
+            Variant => Self::Variant,
             List(dt) => Self::List(Box::new(dt.as_ref().into())),
             #[cfg(feature = "dtype-array")]
             Array(dt, width) => Self::Array(Box::new(dt.as_ref().into()), *width),
@@ -136,6 +153,11 @@ impl From<&DataType> for SerializableDataType {
             Unknown(kind) => Self::Unknown(*kind),
             #[cfg(feature = "dtype-struct")]
             Struct(flds) => Self::Struct(flds.clone()),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => Self::Map {
+                key: Box::new(key.as_ref().into()),
+                value: Box::new(value.as_ref().into()),
+            },
             #[cfg(feature = "dtype-categorical")]
             Categorical(cats, _) => Self::Categorical {
                 name: cats.name().to_string(),
@@ -152,6 +174,10 @@ impl From<&DataType> for SerializableDataType {
             },
             #[cfg(feature = "dtype-decimal")]
             Decimal(precision, scale) => Self::Decimal(*precision, *scale),
+            #[cfg(feature = "dtype-decimal256")]
+            Decimal256(precision, scale) => Self::Decimal256(*precision, *scale),
+            #[cfg(feature = "dtype-decimal256")]
+            Int256 => Self::Int256,
             #[cfg(feature = "object")]
             Object(name) => Self::Object(name.to_string()),
             #[cfg(feature = "dtype-extension")]
@@ -188,6 +214,10 @@ impl From<SerializableDataType> for DataType {
             Datetime(tu, tz) => Self::Datetime(tu, tz),
             Duration(tu) => Self::Duration(tu),
             Time => Self::Time,
+            #[cfg(feature = "dtype-interval")]
+            Interval => Self::Interval,
+            #[cfg(feature = "dtype-variant")]
+            Variant => Self::Variant,
             List(dt) => Self::List(Box::new((*dt).into())),
             #[cfg(feature = "dtype-array")]
             Array(dt, width) => Self::Array(Box::new((*dt).into()), width),
@@ -195,6 +225,8 @@ impl From<SerializableDataType> for DataType {
             Unknown(kind) => Self::Unknown(kind),
             #[cfg(feature = "dtype-struct")]
             Struct(flds) => Self::Struct(flds),
+            #[cfg(feature = "dtype-map")]
+            Map { key, value } => Self::Map(Box::new((*key).into()), Box::new((*value).into())),
             #[cfg(feature = "dtype-categorical")]
             Categorical {
                 name,
@@ -218,6 +250,10 @@ impl From<SerializableDataType> for DataType {
             },
             #[cfg(feature = "dtype-decimal")]
             Decimal(precision, scale) => Self::Decimal(precision, scale),
+            #[cfg(feature = "dtype-decimal256")]
+            Decimal256(precision, scale) => Self::Decimal256(precision, scale),
+            #[cfg(feature = "dtype-decimal256")]
+            Int256 => Self::Int256,
             #[cfg(feature = "object")]
             Object(_) => Self::Object("unknown"),
             #[cfg(feature = "dtype-extension")]
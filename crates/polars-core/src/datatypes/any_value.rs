@@ -24,6 +24,12 @@ impl Clone for OwnedObject {
     }
 }
 
+#[cfg(feature = "dtype-decimal256")]
+use polars_compute::decimal::{
+    DEC256_MAX_PREC, DecimalFmtBuffer, dec128_to_dec256, dec256_add, dec256_cmp, dec256_eq,
+    dec256_rescale, dec256_to_dec128, dec256_to_f64, dec256_to_i128, f64_to_dec256, i128_to_dec256,
+    str_to_dec256,
+};
 #[cfg(feature = "dtype-decimal")]
 use polars_compute::decimal::{
     dec128_cmp, dec128_eq, dec128_rescale, dec128_to_f64, dec128_to_i128, f64_to_dec128,
@@ -82,6 +88,9 @@ pub enum AnyValue<'a> {
     /// A 64-bit time representing the elapsed time since midnight in nanoseconds
     #[cfg(feature = "dtype-time")]
     Time(i64),
+    /// A calendar interval of months, days and nanoseconds.
+    #[cfg(feature = "dtype-interval")]
+    Interval(months_days_ns),
     #[cfg(feature = "dtype-categorical")]
     Categorical(CatSize, &'a Arc<CategoricalMapping>),
     #[cfg(feature = "dtype-categorical")]
@@ -114,6 +123,9 @@ pub enum AnyValue<'a> {
     /// A 128-bit fixed point decimal number with a precision and scale.
     #[cfg(feature = "dtype-decimal")]
     Decimal(i128, usize, usize),
+    /// A 256-bit fixed point decimal number with a precision and scale.
+    #[cfg(feature = "dtype-decimal256")]
+    Decimal256(i256, usize, usize),
 }
 
 impl AnyValue<'static> {
@@ -132,6 +144,8 @@ impl AnyValue<'static> {
             DataType::Duration(unit) => AnyValue::Duration(0, *unit),
             #[cfg(feature = "dtype-decimal")]
             DataType::Decimal(p, s) => AnyValue::Decimal(0, *p, *s),
+            #[cfg(feature = "dtype-decimal256")]
+            DataType::Decimal256(p, s) => AnyValue::Decimal256(i256::default(), *p, *s),
             _ => AnyValue::Null,
         }
     }
@@ -165,6 +179,15 @@ impl AnyValue<'static> {
             DT::Float64 => AV::Float64(numeric_to_one.into()),
             #[cfg(feature = "dtype-decimal")]
             DT::Decimal(p, s) => AV::Decimal(0, *p, *s),
+            #[cfg(feature = "dtype-decimal256")]
+            DT::Decimal256(p, s) => AV::Decimal256(i256::default(), *p, *s),
+            // There is no 256-bit integer `AnyValue`, physical values are unscaled decimals.
+            #[cfg(feature = "dtype-decimal256")]
+            DT::Int256 => AV::Decimal256(
+                i256::from_words(0, numeric_to_one.into()),
+                DEC256_MAX_PREC,
+                0,
+            ),
             DT::String => AV::String(""),
             DT::Binary => AV::Binary(&[]),
             DT::BinaryOffset => AV::Binary(&[]),
@@ -177,6 +200,8 @@ impl AnyValue<'static> {
                 feature_gated!("dtype-duration", AV::Duration(0, *time_unit))
             },
             DT::Time => feature_gated!("dtype-time", AV::Time(0)),
+            #[cfg(feature = "dtype-interval")]
+            DT::Interval => AV::Interval(months_days_ns::default()),
             #[cfg(feature = "dtype-array")]
             DT::Array(inner_dtype, width) => {
                 let inner_value =
@@ -216,6 +241,12 @@ impl AnyValue<'static> {
                     .collect(),
                 fields.clone(),
             ))),
+            #[cfg(feature = "dtype-map")]
+            DT::Map(_, _) => {
+                AnyValue::default_value(&dtype.to_physical(), numeric_to_one, num_list_values)
+            },
+            #[cfg(feature = "dtype-variant")]
+            DT::Variant => AV::Null,
             #[cfg(feature = "dtype-extension")]
             DT::Extension(_typ, storage) => {
                 AnyValue::default_value(storage, numeric_to_one, num_list_values)
@@ -262,6 +293,8 @@ impl<'a> AnyValue<'a> {
             },
             #[cfg(feature = "dtype-duration")]
             Duration(_, tu) => DataType::Duration(*tu),
+            #[cfg(feature = "dtype-interval")]
+            Interval(_) => DataType::Interval,
             #[cfg(feature = "dtype-categorical")]
             Categorical(_, _) | CategoricalOwned(_, _) => {
                 unimplemented!("can not get dtype of Categorical AnyValue")
@@ -277,6 +310,8 @@ impl<'a> AnyValue<'a> {
             StructOwned(payload) => DataType::Struct(payload.1.clone()),
             #[cfg(feature = "dtype-decimal")]
             Decimal(_, p, s) => DataType::Decimal(*p, *s),
+            #[cfg(feature = "dtype-decimal256")]
+            Decimal256(_, p, s) => DataType::Decimal256(*p, *s),
             #[cfg(feature = "object")]
             Object(o) => DataType::Object(o.type_name()),
             #[cfg(feature = "object")]
@@ -320,6 +355,14 @@ impl<'a> AnyValue<'a> {
                     NumCast::from(dec128_to_i128(*v, *s))
                 }
             },
+            #[cfg(feature = "dtype-decimal256")]
+            Decimal256(v, _p, s) => {
+                if T::is_float() {
+                    NumCast::from(dec256_to_f64(*v, *s))
+                } else {
+                    dec256_to_i128(*v, *s).and_then(NumCast::from)
+                }
+            },
             Boolean(v) => NumCast::from(if *v { 1 } else { 0 }),
             String(v) => {
                 if let Ok(val) = (*v).parse::<i128>() {
@@ -513,10 +556,34 @@ impl<'a> AnyValue<'a> {
                 AnyValue::CategoricalOwned(map.get_cat(s)?, map.clone())
             },
 
+            #[cfg(feature = "dtype-decimal256")]
+            (AnyValue::String(s), DataType::Decimal256(p, sc)) => {
+                AnyValue::Decimal256(str_to_dec256(s.as_bytes(), *p, *sc, false)?, *p, *sc)
+            },
+            #[cfg(feature = "dtype-decimal256")]
+            (AnyValue::StringOwned(s), DataType::Decimal256(p, sc)) => {
+                AnyValue::Decimal256(str_to_dec256(s.as_bytes(), *p, *sc, false)?, *p, *sc)
+            },
+
             // to string
             (AnyValue::String(v), DataType::String) => AnyValue::String(v),
             (AnyValue::StringOwned(v), DataType::String) => AnyValue::StringOwned(v.clone()),
 
+            #[cfg(feature = "dtype-interval")]
+            (AnyValue::Interval(v), DataType::String) => {
+                let mut out = std::string::String::new();
+                crate::fmt::fmt_interval_string(&mut out, *v).unwrap();
+                AnyValue::StringOwned(PlSmallStr::from_string(out))
+            },
+
+            #[cfg(feature = "dtype-decimal256")]
+            (AnyValue::Decimal256(v, _p, s), DataType::String) => {
+                let mut buf = DecimalFmtBuffer::new();
+                AnyValue::StringOwned(PlSmallStr::from_str(
+                    buf.format_dec256(*v, *s, false, false),
+                ))
+            },
+
             (av, DataType::String) => {
                 let mut tmp = vec![];
                 if av.is_unsigned_integer() {
@@ -624,6 +691,22 @@ impl<'a> AnyValue<'a> {
                 },
                 *tu_r,
             ),
+            #[cfg(feature = "dtype-interval")]
+            (AnyValue::Interval(v), DataType::Duration(tu)) => {
+                AnyValue::Duration(interval_to_duration(*v, *tu)?, *tu)
+            },
+
+            // to interval
+            #[cfg(feature = "dtype-interval")]
+            (AnyValue::String(v), DataType::Interval) => AnyValue::Interval(parse_interval(v)?),
+            #[cfg(feature = "dtype-interval")]
+            (AnyValue::StringOwned(v), DataType::Interval) => {
+                AnyValue::Interval(parse_interval(v)?)
+            },
+            #[cfg(feature = "dtype-interval")]
+            (AnyValue::Duration(v, tu), DataType::Interval) => {
+                AnyValue::Interval(duration_to_interval(*v, *tu)?)
+            },
 
             #[cfg(feature = "dtype-decimal")]
             (av, DataType::Decimal(p, s)) if av.is_integer() => {
@@ -645,6 +728,38 @@ impl<'a> AnyValue<'a> {
                 AnyValue::Decimal(converted, *p, *s)
             },
 
+            #[cfg(feature = "dtype-decimal256")]
+            (av, DataType::Decimal256(p, s)) if av.is_integer() => {
+                let int = av.try_extract::<i128>().ok()?;
+                let dec = i128_to_dec256(int, *p, *s)?;
+                AnyValue::Decimal256(dec, *p, *s)
+            },
+
+            #[cfg(feature = "dtype-decimal256")]
+            (av, DataType::Decimal256(p, s)) if av.is_float() => {
+                let f = av.try_extract::<f64>().unwrap();
+                let dec = f64_to_dec256(f, *p, *s)?;
+                AnyValue::Decimal256(dec, *p, *s)
+            },
+
+            #[cfg(feature = "dtype-decimal256")]
+            (AnyValue::Decimal(value, _old_p, old_s), DataType::Decimal256(p, s)) => {
+                let converted = dec128_to_dec256(*value, *old_s, *p, *s)?;
+                AnyValue::Decimal256(converted, *p, *s)
+            },
+
+            #[cfg(feature = "dtype-decimal256")]
+            (AnyValue::Decimal256(value, _old_p, old_s), DataType::Decimal256(p, s)) => {
+                let converted = dec256_rescale(*value, *old_s, *p, *s)?;
+                AnyValue::Decimal256(converted, *p, *s)
+            },
+
+            #[cfg(feature = "dtype-decimal256")]
+            (AnyValue::Decimal256(value, _old_p, old_s), DataType::Decimal(p, s)) => {
+                let converted = dec256_to_dec128(*value, *old_s, *p, *s)?;
+                AnyValue::Decimal(converted, *p, *s)
+            },
+
             // to self
             (av, dtype) if av.dtype() == *dtype => self.clone(),
 
@@ -729,6 +844,8 @@ impl<'a> AnyValue<'a> {
             Self::Duration(v, _) => Self::Int64(v),
             #[cfg(feature = "dtype-time")]
             Self::Time(v) => Self::Int64(v),
+            #[cfg(feature = "dtype-interval")]
+            Self::Interval(v) => Self::Int128(interval_to_i128(v)),
 
             #[cfg(feature = "dtype-categorical")]
             Self::Categorical(v, &ref m)
@@ -765,6 +882,9 @@ impl<'a> AnyValue<'a> {
 
             #[cfg(feature = "dtype-decimal")]
             Self::Decimal(v, _, _) => Self::Int128(v),
+            // There is no 256-bit integer `AnyValue`, the decimal is its own physical value.
+            #[cfg(feature = "dtype-decimal256")]
+            Self::Decimal256(..) => self,
         }
     }
 
@@ -863,6 +983,8 @@ impl AnyValue<'_> {
             },
             #[cfg(feature = "dtype-time")]
             Time(v) => v.hash(state),
+            #[cfg(feature = "dtype-interval")]
+            Interval(v) => v.hash(state),
             #[cfg(feature = "dtype-categorical")]
             Categorical(v, _) | CategoricalOwned(v, _) | Enum(v, _) | EnumOwned(v, _) => {
                 v.hash(state)
@@ -887,6 +1009,12 @@ impl AnyValue<'_> {
                 s.hash(state);
                 p.hash(state);
             },
+            #[cfg(feature = "dtype-decimal256")]
+            Decimal256(v, s, p) => {
+                v.0.hash(state);
+                s.hash(state);
+                p.hash(state);
+            },
             Null => {},
         }
     }
@@ -973,6 +1101,18 @@ impl<'a> AnyValue<'a> {
         }
     }
 
+    #[cfg(feature = "dtype-interval")]
+    pub(crate) fn as_interval(&self) -> AnyValue<'static> {
+        match self {
+            AnyValue::Int128(v) => AnyValue::Interval(interval_from_i128(*v)),
+            AnyValue::Null => AnyValue::Null,
+            av => panic!(
+                "cannot create interval from other type. dtype: {}",
+                av.dtype()
+            ),
+        }
+    }
+
     pub(crate) fn to_i128(&self) -> Option<i128> {
         match self {
             AnyValue::UInt8(v) => Some((*v).into()),
@@ -1028,6 +1168,16 @@ impl<'a> AnyValue<'a> {
 
                 Decimal(l + r, *lp, *ls)
             },
+            #[cfg(feature = "dtype-decimal256")]
+            (Decimal256(l, _lp, ls), Decimal256(r, _rp, rs)) => {
+                // Add at the larger scale with the maximum precision, like the Decimal256 sum. A
+                // result that does not fit becomes null, as in a non-strict cast.
+                let (p, s) = (DEC256_MAX_PREC, *ls.max(rs));
+                dec256_rescale(*l, *ls, p, s)
+                    .zip(dec256_rescale(*r, *rs, p, s))
+                    .and_then(|(l, r)| dec256_add(l, r, p))
+                    .map_or(Null, |v| Decimal256(v, p, s))
+            },
             _ => unimplemented!(),
         }
     }
@@ -1080,6 +1230,8 @@ impl<'a> AnyValue<'a> {
             Duration(v, tu) => Duration(v, tu),
             #[cfg(feature = "dtype-time")]
             Time(v) => Time(v),
+            #[cfg(feature = "dtype-interval")]
+            Interval(v) => Interval(v),
             List(v) => List(v),
             #[cfg(feature = "dtype-array")]
             Array(s, size) => Array(s, size),
@@ -1108,6 +1260,8 @@ impl<'a> AnyValue<'a> {
             },
             #[cfg(feature = "dtype-decimal")]
             Decimal(val, s, p) => Decimal(val, s, p),
+            #[cfg(feature = "dtype-decimal256")]
+            Decimal256(val, s, p) => Decimal256(val, s, p),
             #[cfg(feature = "dtype-categorical")]
             Categorical(cat, map) => CategoricalOwned(cat, map.clone()),
             #[cfg(feature = "dtype-categorical")]
@@ -1236,6 +1390,8 @@ impl AnyValue<'_> {
             (Binary(l), Binary(r)) => l == r,
             #[cfg(feature = "dtype-time")]
             (Time(l), Time(r)) => *l == *r,
+            #[cfg(feature = "dtype-interval")]
+            (Interval(l), Interval(r)) => l == r,
             #[cfg(all(feature = "dtype-datetime", feature = "dtype-date"))]
             (Date(l), Date(r)) => *l == *r,
             #[cfg(all(feature = "dtype-datetime", feature = "dtype-date"))]
@@ -1296,6 +1452,8 @@ impl AnyValue<'_> {
             ),
             #[cfg(feature = "dtype-decimal")]
             (Decimal(lv, _lp, ls), Decimal(rv, _rp, rs)) => dec128_eq(*lv, *ls, *rv, *rs),
+            #[cfg(feature = "dtype-decimal256")]
+            (Decimal256(lv, _lp, ls), Decimal256(rv, _rp, rs)) => dec256_eq(*lv, *ls, *rv, *rs),
             #[cfg(feature = "object")]
             (Object(l), Object(r)) => l == r,
             #[cfg(feature = "dtype-array")]
@@ -1413,6 +1571,8 @@ impl PartialOrd for AnyValue<'_> {
             },
             #[cfg(feature = "dtype-time")]
             (Time(l), Time(r)) => l.partial_cmp(r),
+            #[cfg(feature = "dtype-interval")]
+            (Interval(l), Interval(r)) => l.partial_cmp(r),
             #[cfg(feature = "dtype-categorical")]
             (Categorical(l_cat, l_map), Categorical(r_cat, r_map)) => unsafe {
                 let l_str = l_map.cat_to_str_unchecked(*l_cat);
@@ -1446,6 +1606,10 @@ impl PartialOrd for AnyValue<'_> {
             },
             #[cfg(feature = "dtype-decimal")]
             (Decimal(lv, _lp, ls), Decimal(rv, _rp, rs)) => Some(dec128_cmp(*lv, *ls, *rv, *rs)),
+            #[cfg(feature = "dtype-decimal256")]
+            (Decimal256(lv, _lp, ls), Decimal256(rv, _rp, rs)) => {
+                Some(dec256_cmp(*lv, *ls, *rv, *rs))
+            },
 
             (_, _) => {
                 unimplemented!(
@@ -1700,9 +1864,29 @@ impl From<bool> for AnyValue<'static> {
 
 #[cfg(test)]
 mod test {
-    #[cfg(feature = "dtype-categorical")]
+    #[cfg(any(feature = "dtype-categorical", feature = "dtype-decimal256"))]
     use super::*;
 
+    #[test]
+    #[cfg(feature = "dtype-decimal256")]
+    fn test_decimal256_add() {
+        // 1.25 + 0.5
+        let l = AnyValue::Decimal256(i256::from_words(0, 125), 60, 2);
+        let r = AnyValue::Decimal256(i256::from_words(0, 5), 10, 1);
+        assert_eq!(
+            l.add(&r),
+            AnyValue::Decimal256(i256::from_words(0, 175), DEC256_MAX_PREC, 2)
+        );
+
+        let max = str_to_dec256(&[b'9'; DEC256_MAX_PREC], DEC256_MAX_PREC, 0, false).unwrap();
+        let max = AnyValue::Decimal256(max, DEC256_MAX_PREC, 0);
+        assert_eq!(max.add(&max), AnyValue::Null);
+        assert_eq!(
+            AnyValue::default_value(&DataType::Int256, true, 0),
+            AnyValue::Decimal256(i256::from_words(0, 1), DEC256_MAX_PREC, 0)
+        );
+    }
+
     #[test]
     #[cfg(feature = "dtype-categorical")]
     fn test_arrow_dtypes_to_polars() {
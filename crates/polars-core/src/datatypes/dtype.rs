@@ -1,5 +1,7 @@
 use std::collections::BTreeMap;
 
+#[cfg(feature = "dtype-interval")]
+use arrow::datatypes::IntervalUnit;
 use arrow::datatypes::{
     DTYPE_CATEGORICAL_NEW, DTYPE_ENUM_VALUES_LEGACY, DTYPE_ENUM_VALUES_NEW, MAINTAIN_PL_TYPE,
     Metadata, PL_KEY,
@@ -106,6 +108,13 @@ pub enum DataType {
     /// Meaning max precision is 38.
     #[cfg(feature = "dtype-decimal")]
     Decimal(usize, usize), // (precision, scale), invariant: 1 <= precision <= 38.
+    /// Fixed point decimal type backed by a signed 256-bit integer, which allows for up to
+    /// 76 significant digits.
+    #[cfg(feature = "dtype-decimal256")]
+    Decimal256(usize, usize), // (precision, scale), invariant: 1 <= precision <= 76.
+    /// Signed 256-bit integer, only used as the physical representation of `Decimal256`.
+    #[cfg(feature = "dtype-decimal256")]
+    Int256,
     /// String data
     String,
     Binary,
@@ -120,6 +129,11 @@ pub enum DataType {
     Duration(TimeUnit),
     /// A 64-bit time representing the elapsed time since midnight in nanoseconds
     Time,
+    /// A calendar interval of months, days and nanoseconds. Stored as a 128-bit integer
+    /// with the months in the upper 32 bits, the days in the next 32 bits and the
+    /// nanoseconds in the lower 64 bits.
+    #[cfg(feature = "dtype-interval")]
+    Interval,
     /// A nested list with a fixed size in each row
     #[cfg(feature = "dtype-array")]
     Array(Box<DataType>, usize),
@@ -137,6 +151,13 @@ pub enum DataType {
     Enum(Arc<FrozenCategories>, Arc<CategoricalMapping>),
     #[cfg(feature = "dtype-struct")]
     Struct(Vec<Field>),
+    /// A map from keys to values. Stored as a list of `{key, value}` structs.
+    #[cfg(feature = "dtype-map")]
+    Map(Box<DataType>, Box<DataType>),
+    /// A semi-structured value in the Parquet variant encoding. Stored as binary, holding the
+    /// metadata of each value directly followed by the value itself.
+    #[cfg(feature = "dtype-variant")]
+    Variant,
     #[cfg(feature = "dtype-extension")]
     Extension(ExtensionTypeInstance, Box<DataType>),
     // some logical types we cannot know statically, e.g. Datetime
@@ -174,6 +195,8 @@ impl PartialEq for DataType {
                 (Duration(tu_l), Duration(tu_r)) => tu_l == tu_r,
                 #[cfg(feature = "dtype-decimal")]
                 (Decimal(p1, s1), Decimal(p2, s2)) => (p1, s1) == (p2, s2),
+                #[cfg(feature = "dtype-decimal256")]
+                (Decimal256(p1, s1), Decimal256(p2, s2)) => (p1, s1) == (p2, s2),
                 #[cfg(feature = "object")]
                 (Object(lhs), Object(rhs)) => lhs == rhs,
                 #[cfg(feature = "dtype-struct")]
@@ -184,6 +207,12 @@ impl PartialEq for DataType {
                 (Array(left_inner, left_width), Array(right_inner, right_width)) => {
                     left_width == right_width && left_inner == right_inner
                 },
+                #[cfg(feature = "dtype-map")]
+                (Map(key_l, value_l), Map(key_r, value_r)) => key_l == key_r && value_l == value_r,
+                #[cfg(feature = "dtype-extension")]
+                (Extension(typ_l, storage_l), Extension(typ_r, storage_r)) => {
+                    typ_l == typ_r && storage_l == storage_r
+                },
                 (Unknown(l), Unknown(r)) => match (l, r) {
                     (UnknownKind::Int(_), UnknownKind::Int(_)) => true,
                     _ => l == r,
@@ -243,6 +272,15 @@ impl DataType {
         ])
     }
 
+    /// Struct type of the entries of a [`DataType::Map`].
+    #[cfg(feature = "dtype-struct")]
+    pub fn map_entries_dtype(key: DataType, value: DataType) -> Self {
+        DataType::Struct(vec![
+            Field::new(PlSmallStr::from_static("key"), key),
+            Field::new(PlSmallStr::from_static("value"), value),
+        ])
+    }
+
     /// Check if the whole dtype is known.
     pub fn is_known(&self) -> bool {
         match self {
@@ -251,6 +289,8 @@ impl DataType {
             DataType::Array(inner, _) => inner.is_known(),
             #[cfg(feature = "dtype-struct")]
             DataType::Struct(fields) => fields.iter().all(|fld| fld.dtype.is_known()),
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(key, value) => key.is_known() && value.is_known(),
             DataType::Unknown(_) => false,
             _ => true,
         }
@@ -290,6 +330,11 @@ impl DataType {
                     })
                     .try_collect_vec()?,
             )),
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(key, value) => Ok(DataType::Map(
+                Box::new(key.materialize_unknown(allow_unknown)?),
+                Box::new(value.materialize_unknown(allow_unknown)?),
+            )),
             _ => Ok(self),
         }
     }
@@ -394,6 +439,8 @@ impl DataType {
                     .collect();
                 Struct(new_fields)
             },
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => Map(Box::new(key.map_leaves(f)), Box::new(value.map_leaves(f))),
             #[cfg(feature = "dtype-extension")]
             Extension(ext, storage) => Extension(ext, Box::new(storage.map_leaves(f))),
             _ => f(self),
@@ -476,8 +523,14 @@ impl DataType {
             Datetime(_, _) => Int64,
             Duration(_) => Int64,
             Time => Int64,
+            #[cfg(feature = "dtype-interval")]
+            Interval => Int128,
+            #[cfg(feature = "dtype-variant")]
+            Variant => Binary,
             #[cfg(feature = "dtype-decimal")]
             Decimal(_, _) => Int128,
+            #[cfg(feature = "dtype-decimal256")]
+            Decimal256(_, _) => Int256,
             #[cfg(feature = "dtype-categorical")]
             Categorical(cats, _) => cats.physical().dtype(),
             #[cfg(feature = "dtype-categorical")]
@@ -493,6 +546,11 @@ impl DataType {
                     .collect();
                 Struct(new_fields)
             },
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => List(Box::new(Self::map_entries_dtype(
+                key.to_physical(),
+                value.to_physical(),
+            ))),
             #[cfg(feature = "dtype-extension")]
             Extension(_, storage) => storage.to_physical(),
             _ => self.clone(),
@@ -568,6 +626,8 @@ impl DataType {
             DataType::Array(_, _) => true,
             #[cfg(feature = "dtype-struct")]
             DataType::Struct(_) => true,
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(_, _) => true,
             #[cfg(feature = "dtype-extension")]
             DataType::Extension(_, storage) => storage.is_nested(),
             _ => false,
@@ -586,6 +646,18 @@ impl DataType {
         }
     }
 
+    /// Check if this [`DataType`] is a map
+    pub fn is_map(&self) -> bool {
+        #[cfg(feature = "dtype-map")]
+        {
+            matches!(self, DataType::Map(_, _))
+        }
+        #[cfg(not(feature = "dtype-map"))]
+        {
+            false
+        }
+    }
+
     pub fn is_binary(&self) -> bool {
         matches!(self, DataType::Binary)
     }
@@ -601,6 +673,29 @@ impl DataType {
         matches!(self, DataType::Duration(..))
     }
 
+    pub fn is_interval(&self) -> bool {
+        #[cfg(feature = "dtype-interval")]
+        {
+            matches!(self, DataType::Interval)
+        }
+        #[cfg(not(feature = "dtype-interval"))]
+        {
+            false
+        }
+    }
+
+    /// Check if this [`DataType`] is a variant
+    pub fn is_variant(&self) -> bool {
+        #[cfg(feature = "dtype-variant")]
+        {
+            matches!(self, DataType::Variant)
+        }
+        #[cfg(not(feature = "dtype-variant"))]
+        {
+            false
+        }
+    }
+
     pub fn is_object(&self) -> bool {
         #[cfg(feature = "object")]
         {
@@ -620,11 +715,15 @@ impl DataType {
         use DataType::*;
         match self {
             Binary | String => true,
+            #[cfg(feature = "dtype-variant")]
+            Variant => true,
             List(inner) => inner.contains_views(),
             #[cfg(feature = "dtype-array")]
             Array(inner, _) => inner.contains_views(),
             #[cfg(feature = "dtype-struct")]
             Struct(fields) => fields.iter().any(|field| field.dtype.contains_views()),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => key.contains_views() || value.contains_views(),
             _ => false,
         }
     }
@@ -641,6 +740,8 @@ impl DataType {
             Struct(fields) => fields
                 .iter()
                 .any(|field| field.dtype.contains_categoricals()),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => key.contains_categoricals() || value.contains_categoricals(),
             _ => false,
         }
     }
@@ -655,6 +756,8 @@ impl DataType {
             Array(inner, _) => inner.contains_objects(),
             #[cfg(feature = "dtype-struct")]
             Struct(fields) => fields.iter().any(|field| field.dtype.contains_objects()),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => key.contains_objects() || value.contains_objects(),
             _ => false,
         }
     }
@@ -663,6 +766,8 @@ impl DataType {
         use DataType as D;
         match self {
             D::List(_) => true,
+            #[cfg(feature = "dtype-map")]
+            D::Map(_, _) => true,
             #[cfg(feature = "dtype-array")]
             D::Array(inner, _) => inner.contains_list_recursive(),
             #[cfg(feature = "dtype-struct")]
@@ -682,15 +787,21 @@ impl DataType {
             D::Array(inner, _) => inner.contains_unknown(),
             #[cfg(feature = "dtype-struct")]
             D::Struct(fields) => fields.iter().any(|field| field.dtype.contains_unknown()),
+            #[cfg(feature = "dtype-map")]
+            D::Map(key, value) => key.contains_unknown() || value.contains_unknown(),
             _ => false,
         }
     }
 
     /// Check if type is sortable
     pub fn is_ord(&self) -> bool {
+        if self.is_variant() {
+            return false;
+        }
         let phys = self.to_physical();
         phys.is_primitive_numeric()
             || self.is_decimal()
+            || self.is_decimal256()
             || matches!(
                 phys,
                 DataType::Binary | DataType::String | DataType::Boolean
@@ -706,6 +817,15 @@ impl DataType {
         }
     }
 
+    /// Check if this [`DataType`] is a Decimal256 type (of any scale/precision).
+    pub fn is_decimal256(&self) -> bool {
+        match self {
+            #[cfg(feature = "dtype-decimal256")]
+            DataType::Decimal256(_, _) => true,
+            _ => false,
+        }
+    }
+
     /// Check if this [`DataType`] is a basic floating point type (excludes Decimal).
     /// Note, this also includes `Unknown(UnknownKind::Float)`.
     pub fn is_float(&self) -> bool {
@@ -923,6 +1043,13 @@ impl DataType {
                 assert!(*precision >= 1 && *precision <= 38);
                 Ok(ArrowDataType::Decimal(*precision, *scale))
             },
+            #[cfg(feature = "dtype-decimal256")]
+            Decimal256(precision, scale) => {
+                assert!(*precision >= 1 && *precision <= 76);
+                Ok(ArrowDataType::Decimal256(*precision, *scale))
+            },
+            #[cfg(feature = "dtype-decimal256")]
+            Int256 => Ok(ArrowDataType::Decimal256(76, 0)),
             String => {
                 let dt = if compat_level.0 >= 1 {
                     ArrowDataType::Utf8View
@@ -946,6 +1073,10 @@ impl DataType {
             )),
             Duration(unit) => Ok(ArrowDataType::Duration(unit.to_arrow())),
             Time => Ok(ArrowDataType::Time64(ArrowTimeUnit::Nanosecond)),
+            #[cfg(feature = "dtype-interval")]
+            Interval => Ok(ArrowDataType::Interval(IntervalUnit::MonthDayNano)),
+            #[cfg(feature = "dtype-variant")]
+            Variant => Ok(polars_compute::variant::variant_arrow_dtype()),
             #[cfg(feature = "dtype-array")]
             Array(dt, width) => Ok(ArrowDataType::FixedSizeList(
                 Box::new(dt.to_arrow_field(LIST_VALUES_NAME, compat_level)),
@@ -985,15 +1116,41 @@ impl DataType {
                     .collect();
                 Ok(ArrowDataType::Struct(fields))
             },
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => {
+                // Arrow requires the keys of a map to be non-nullable.
+                let mut key = key.to_arrow_field(PlSmallStr::from_static("key"), compat_level);
+                key.is_nullable = false;
+                let value = value.to_arrow_field(PlSmallStr::from_static("value"), compat_level);
+                let entries = ArrowDataType::Struct(vec![key, value]);
+                Ok(ArrowDataType::Map(
+                    Box::new(ArrowField::new(
+                        PlSmallStr::from_static("entries"),
+                        entries,
+                        false,
+                    )),
+                    false,
+                ))
+            },
             BinaryOffset => Ok(ArrowDataType::LargeBinary),
             #[cfg(feature = "dtype-extension")]
-            Extension(typ, inner) => Ok(ArrowDataType::Extension(Box::new(
-                arrow::datatypes::ExtensionType {
-                    name: typ.name().into(),
-                    inner: inner.try_to_arrow(compat_level)?,
-                    metadata: typ.serialize_metadata().map(|m| m.into()),
-                },
-            ))),
+            Extension(typ, inner) => {
+                let inner = match inner.as_ref() {
+                    // `arrow.uuid` is defined on 16-byte fixed-size binary storage.
+                    #[cfg(feature = "dtype-uuid")]
+                    Binary if crate::datatypes::extension::is_uuid(self) => {
+                        ArrowDataType::FixedSizeBinary(16)
+                    },
+                    inner => inner.try_to_arrow(compat_level)?,
+                };
+                Ok(ArrowDataType::Extension(Box::new(
+                    arrow::datatypes::ExtensionType {
+                        name: typ.name().into(),
+                        inner,
+                        metadata: typ.serialize_metadata().map(|m| m.into()),
+                    },
+                )))
+            },
             Unknown(kind) => {
                 let dt = match kind {
                     UnknownKind::Any => ArrowDataType::Unknown,
@@ -1030,6 +1187,10 @@ impl DataType {
     pub fn matches_schema_type(&self, schema_type: &DataType) -> PolarsResult<bool> {
         match (self, schema_type) {
             (DataType::List(l), DataType::List(r)) => l.matches_schema_type(r),
+            #[cfg(feature = "dtype-map")]
+            (DataType::Map(lk, lv), DataType::Map(rk, rv)) => {
+                Ok(lk.matches_schema_type(rk)? | lv.matches_schema_type(rv)?)
+            },
             #[cfg(feature = "dtype-array")]
             (DataType::Array(l, sl), DataType::Array(r, sr)) => {
                 Ok(l.matches_schema_type(r)? && sl == sr)
@@ -1048,6 +1209,10 @@ impl DataType {
             (DataType::Null, DataType::Null) => Ok(false),
             #[cfg(feature = "dtype-decimal")]
             (DataType::Decimal(p1, s1), DataType::Decimal(p2, s2)) => Ok((p1, s1) != (p2, s2)),
+            #[cfg(feature = "dtype-decimal256")]
+            (DataType::Decimal256(p1, s1), DataType::Decimal256(p2, s2)) => {
+                Ok((p1, s1) != (p2, s2))
+            },
             // We don't allow the other way around, only if our current type is
             // null and the schema isn't we allow it.
             (DataType::Null, _) => Ok(true),
@@ -1144,6 +1309,10 @@ impl Display for DataType {
             DataType::Float64 => "f64",
             #[cfg(feature = "dtype-decimal")]
             DataType::Decimal(p, s) => return write!(f, "decimal[{p},{s}]"),
+            #[cfg(feature = "dtype-decimal256")]
+            DataType::Decimal256(p, s) => return write!(f, "decimal256[{p},{s}]"),
+            #[cfg(feature = "dtype-decimal256")]
+            DataType::Int256 => "i256",
             DataType::String => "str",
             DataType::Binary => "binary",
             DataType::BinaryOffset => "binary[offset]",
@@ -1152,6 +1321,10 @@ impl Display for DataType {
             DataType::Datetime(tu, Some(tz)) => return write!(f, "datetime[{tu}, {tz}]"),
             DataType::Duration(tu) => return write!(f, "duration[{tu}]"),
             DataType::Time => "time",
+            #[cfg(feature = "dtype-interval")]
+            DataType::Interval => "interval",
+            #[cfg(feature = "dtype-variant")]
+            DataType::Variant => "variant",
             #[cfg(feature = "dtype-array")]
             DataType::Array(_, _) => {
                 let tp = self.array_leaf_dtype().unwrap();
@@ -1173,6 +1346,8 @@ impl Display for DataType {
             DataType::Enum(_, _) => "enum",
             #[cfg(feature = "dtype-struct")]
             DataType::Struct(fields) => return write!(f, "struct[{}]", fields.len()),
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(key, value) => return write!(f, "map[{key}, {value}]"),
             #[cfg(feature = "dtype-extension")]
             DataType::Extension(typ, _) => return write!(f, "ext[{}]", typ.0.dyn_display()),
             DataType::Unknown(kind) => match kind {
@@ -1209,6 +1384,10 @@ impl std::fmt::Debug for DataType {
             BinaryOffset => write!(f, "BinaryOffset"),
             Date => write!(f, "Date"),
             Time => write!(f, "Time"),
+            #[cfg(feature = "dtype-interval")]
+            Interval => write!(f, "Interval"),
+            #[cfg(feature = "dtype-variant")]
+            Variant => write!(f, "Variant"),
             Duration(unit) => write!(f, "Duration('{unit}')"),
             Datetime(unit, opt_tz) => {
                 if let Some(tz) = opt_tz {
@@ -1219,6 +1398,10 @@ impl std::fmt::Debug for DataType {
             },
             #[cfg(feature = "dtype-decimal")]
             Decimal(p, s) => write!(f, "Decimal({p}, {s})"),
+            #[cfg(feature = "dtype-decimal256")]
+            Decimal256(p, s) => write!(f, "Decimal256({p}, {s})"),
+            #[cfg(feature = "dtype-decimal256")]
+            Int256 => write!(f, "Int256"),
             #[cfg(feature = "dtype-array")]
             Array(inner, size) => write!(f, "Array({inner:?}, {size})"),
             List(inner) => write!(f, "List({inner:?})"),
@@ -1235,6 +1418,8 @@ impl std::fmt::Debug for DataType {
                 }
                 write!(f, "}})")
             },
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => write!(f, "Map({key:?}, {value:?})"),
             #[cfg(feature = "dtype-categorical")]
             Categorical(cats, _) => {
                 if cats.is_global() {
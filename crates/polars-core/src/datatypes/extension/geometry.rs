@@ -0,0 +1,183 @@
+use std::any::Any;
+use std::borrow::Cow;
+use std::hash::BuildHasher;
+
+use polars_compute::geo::Geometry;
+use polars_error::PolarsResult;
+use polars_utils::aliases::PlFixedStateQuality;
+
+use super::{ExtensionTypeFactory, ExtensionTypeImpl, ExtensionTypeInstance};
+use crate::chunked_array::cast::CastOptions;
+use crate::prelude::*;
+
+pub const GEOARROW_WKB_EXTENSION_NAME: &str = "geoarrow.wkb";
+
+/// Planar geometries stored as well-known binary (WKB) in a `Binary` column, following the
+/// GeoArrow `geoarrow.wkb` extension type.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct GeometryType {
+    /// The GeoArrow metadata, e.g. the coordinate reference system, kept as JSON.
+    metadata: Option<String>,
+}
+
+impl GeometryType {
+    pub fn new(metadata: Option<String>) -> Self {
+        Self { metadata }
+    }
+}
+
+pub(super) struct GeometryTypeFactory;
+
+impl ExtensionTypeFactory for GeometryTypeFactory {
+    fn create_type_instance(
+        &self,
+        _name: &str,
+        _storage: &DataType,
+        metadata: Option<&str>,
+    ) -> Box<dyn ExtensionTypeImpl> {
+        Box::new(GeometryType::new(metadata.map(|s| s.to_string())))
+    }
+}
+
+impl ExtensionTypeImpl for GeometryType {
+    fn name(&self) -> Cow<'_, str> {
+        Cow::Borrowed(GEOARROW_WKB_EXTENSION_NAME)
+    }
+
+    fn serialize_metadata(&self) -> Option<Cow<'_, str>> {
+        self.metadata.as_deref().map(Cow::Borrowed)
+    }
+
+    fn dyn_clone(&self) -> Box<dyn ExtensionTypeImpl> {
+        Box::new(self.clone())
+    }
+
+    fn dyn_eq(&self, other: &dyn ExtensionTypeImpl) -> bool {
+        (other as &dyn Any)
+            .downcast_ref::<GeometryType>()
+            .is_some_and(|other| self == other)
+    }
+
+    fn dyn_hash(&self) -> u64 {
+        PlFixedStateQuality::default().hash_one(self)
+    }
+
+    fn dyn_display(&self) -> Cow<'_, str> {
+        Cow::Borrowed("geometry")
+    }
+
+    fn dyn_debug(&self) -> Cow<'_, str> {
+        Cow::Borrowed("Geometry")
+    }
+
+    fn format_value(&self, value: &AnyValue<'_>) -> Option<String> {
+        let bytes = match value {
+            AnyValue::Binary(b) => *b,
+            AnyValue::BinaryOwned(b) => b.as_slice(),
+            _ => return None,
+        };
+        Some(match Geometry::from_wkb(bytes) {
+            Ok(geom) => geom.to_wkt(),
+            Err(_) => "<invalid geometry>".to_string(),
+        })
+    }
+
+    fn cast_to(
+        &self,
+        storage: &Series,
+        dtype: &DataType,
+        options: CastOptions,
+    ) -> Option<PolarsResult<Series>> {
+        match dtype {
+            DataType::String => Some(
+                storage
+                    .binary()
+                    .and_then(|ca| wkb_to_wkt(ca, options.is_strict()))
+                    .map(|ca| ca.into_series()),
+            ),
+            _ => None,
+        }
+    }
+
+    fn cast_from(
+        &self,
+        series: &Series,
+        _storage: &DataType,
+        options: CastOptions,
+    ) -> Option<PolarsResult<Series>> {
+        match series.dtype() {
+            DataType::String => Some(
+                wkt_to_wkb(series.str().unwrap(), options.is_strict()).map(|ca| ca.into_series()),
+            ),
+            _ => None,
+        }
+    }
+}
+
+/// The geometry data type without GeoArrow metadata.
+pub fn geometry_dtype() -> DataType {
+    DataType::Extension(
+        ExtensionTypeInstance(Box::new(GeometryType::default())),
+        Box::new(DataType::Binary),
+    )
+}
+
+/// Whether `dtype` is a WKB geometry.
+pub fn is_geometry(dtype: &DataType) -> bool {
+    matches!(dtype, DataType::Extension(typ, _) if typ.name() == GEOARROW_WKB_EXTENSION_NAME)
+}
+
+/// Convert WKB values to WKT. Invalid values raise an error if `strict`, otherwise become null.
+pub fn wkb_to_wkt(ca: &BinaryChunked, strict: bool) -> PolarsResult<StringChunked> {
+    let mut buf = String::new();
+    let mut out = StringChunkedBuilder::new(ca.name().clone(), ca.len());
+    for opt_bytes in ca.iter() {
+        match opt_bytes.map(Geometry::from_wkb) {
+            Some(Ok(geom)) => {
+                buf.clear();
+                geom.write_wkt(&mut buf);
+                out.append_value(&buf);
+            },
+            Some(Err(e)) if strict => return Err(e),
+            _ => out.append_null(),
+        }
+    }
+    Ok(out.finish())
+}
+
+/// Convert WKT values to WKB. Invalid values raise an error if `strict`, otherwise become null.
+pub fn wkt_to_wkb(ca: &StringChunked, strict: bool) -> PolarsResult<BinaryChunked> {
+    let mut buf = Vec::new();
+    let mut out = BinaryChunkedBuilder::new(ca.name().clone(), ca.len());
+    for opt_s in ca.iter() {
+        match opt_s.map(Geometry::from_wkt) {
+            Some(Ok(geom)) => {
+                buf.clear();
+                geom.write_wkb(&mut buf);
+                out.append_value(&buf);
+            },
+            Some(Err(e)) if strict => return Err(e),
+            _ => out.append_null(),
+        }
+    }
+    Ok(out.finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_geometry_cast() -> PolarsResult<()> {
+        let wkt = Series::new("g".into(), [Some("POINT (1 2)"), None, Some("not wkt")]);
+        let geom = wkt.cast(&geometry_dtype())?;
+        assert!(is_geometry(geom.dtype()));
+        assert_eq!(geom.null_count(), 2);
+        assert_eq!(geom.str_value(0)?, "POINT (1 2)");
+        assert!(wkt.strict_cast(&geometry_dtype()).is_err());
+
+        let back = geom.cast(&DataType::String)?;
+        assert_eq!(back.str()?.get(0), Some("POINT (1 2)"));
+        Ok(())
+    }
+}
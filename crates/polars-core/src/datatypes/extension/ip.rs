@@ -0,0 +1,329 @@
+use std::any::Any;
+use std::borrow::Cow;
+use std::hash::BuildHasher;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use polars_error::{PolarsResult, polars_bail};
+use polars_utils::aliases::PlFixedStateQuality;
+
+use super::{ExtensionTypeFactory, ExtensionTypeImpl, ExtensionTypeInstance};
+use crate::chunked_array::cast::CastOptions;
+use crate::prelude::*;
+
+pub const IPV4_EXTENSION_NAME: &str = "polars.ipv4";
+pub const IPV6_EXTENSION_NAME: &str = "polars.ipv6";
+
+/// Version of the Internet Protocol of an IP address type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum IpVersion {
+    /// IPv4 addresses, stored as `UInt32`.
+    V4,
+    /// IPv6 addresses, stored as `UInt128`.
+    V6,
+}
+
+impl IpVersion {
+    /// The number of bits of an address.
+    pub fn num_bits(self) -> u8 {
+        match self {
+            IpVersion::V4 => 32,
+            IpVersion::V6 => 128,
+        }
+    }
+
+    pub fn storage_dtype(self) -> DataType {
+        match self {
+            IpVersion::V4 => DataType::UInt32,
+            IpVersion::V6 => DataType::UInt128,
+        }
+    }
+
+    fn extension_name(self) -> &'static str {
+        match self {
+            IpVersion::V4 => IPV4_EXTENSION_NAME,
+            IpVersion::V6 => IPV6_EXTENSION_NAME,
+        }
+    }
+
+    /// The address as an integer in network byte order.
+    pub fn addr_to_bits(self, addr: IpAddr) -> Option<u128> {
+        match (self, addr) {
+            (IpVersion::V4, IpAddr::V4(a)) => Some(u32::from(a) as u128),
+            (IpVersion::V4, IpAddr::V6(a)) => a.to_ipv4_mapped().map(|a| u32::from(a) as u128),
+            (IpVersion::V6, IpAddr::V4(a)) => Some(u128::from(a.to_ipv6_mapped())),
+            (IpVersion::V6, IpAddr::V6(a)) => Some(u128::from(a)),
+        }
+    }
+
+    pub fn bits_to_addr(self, bits: u128) -> IpAddr {
+        match self {
+            IpVersion::V4 => IpAddr::V4(Ipv4Addr::from(bits as u32)),
+            IpVersion::V6 => IpAddr::V6(Ipv6Addr::from(bits)),
+        }
+    }
+}
+
+/// IP addresses stored as their integer value in network byte order, so they sort like the
+/// addresses themselves.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct IpAddrType {
+    version: IpVersion,
+}
+
+impl IpAddrType {
+    pub fn new(version: IpVersion) -> Self {
+        Self { version }
+    }
+
+    pub fn version(&self) -> IpVersion {
+        self.version
+    }
+}
+
+pub(super) struct IpAddrTypeFactory(pub(super) IpVersion);
+
+impl ExtensionTypeFactory for IpAddrTypeFactory {
+    fn create_type_instance(
+        &self,
+        _name: &str,
+        _storage: &DataType,
+        _metadata: Option<&str>,
+    ) -> Box<dyn ExtensionTypeImpl> {
+        Box::new(IpAddrType::new(self.0))
+    }
+}
+
+impl ExtensionTypeImpl for IpAddrType {
+    fn name(&self) -> Cow<'_, str> {
+        Cow::Borrowed(self.version.extension_name())
+    }
+
+    fn serialize_metadata(&self) -> Option<Cow<'_, str>> {
+        None
+    }
+
+    fn dyn_clone(&self) -> Box<dyn ExtensionTypeImpl> {
+        Box::new(self.clone())
+    }
+
+    fn dyn_eq(&self, other: &dyn ExtensionTypeImpl) -> bool {
+        (other as &dyn Any)
+            .downcast_ref::<IpAddrType>()
+            .is_some_and(|other| self == other)
+    }
+
+    fn dyn_hash(&self) -> u64 {
+        PlFixedStateQuality::default().hash_one(self)
+    }
+
+    fn dyn_display(&self) -> Cow<'_, str> {
+        Cow::Borrowed(match self.version {
+            IpVersion::V4 => "ipv4",
+            IpVersion::V6 => "ipv6",
+        })
+    }
+
+    fn dyn_debug(&self) -> Cow<'_, str> {
+        Cow::Borrowed(match self.version {
+            IpVersion::V4 => "IPv4",
+            IpVersion::V6 => "IPv6",
+        })
+    }
+
+    fn format_value(&self, value: &AnyValue<'_>) -> Option<String> {
+        match value {
+            AnyValue::UInt32(v) => Some(Ipv4Addr::from(*v).to_string()),
+            AnyValue::UInt128(v) => Some(Ipv6Addr::from(*v).to_string()),
+            _ => None,
+        }
+    }
+
+    fn cast_to(
+        &self,
+        storage: &Series,
+        dtype: &DataType,
+        options: CastOptions,
+    ) -> Option<PolarsResult<Series>> {
+        match dtype {
+            DataType::String => {
+                Some(ip_to_string(storage, self.version).map(|ca| ca.into_series()))
+            },
+            dt if *dt == self.version.storage_dtype() => Some(Ok(storage.clone())),
+            dt => {
+                let to = ip_version(dt)?;
+                Some(convert_ip_version(
+                    storage,
+                    self.version,
+                    to,
+                    options.is_strict(),
+                ))
+            },
+        }
+    }
+
+    fn cast_from(
+        &self,
+        series: &Series,
+        _storage: &DataType,
+        options: CastOptions,
+    ) -> Option<PolarsResult<Series>> {
+        match series.dtype() {
+            DataType::String => Some(string_to_ip(
+                series.str().unwrap(),
+                self.version,
+                options.is_strict(),
+            )),
+            _ => None,
+        }
+    }
+}
+
+/// The IP address data type of `version`.
+pub fn ip_dtype(version: IpVersion) -> DataType {
+    DataType::Extension(
+        ExtensionTypeInstance(Box::new(IpAddrType::new(version))),
+        Box::new(version.storage_dtype()),
+    )
+}
+
+/// The version of `dtype` if it is an IP address.
+pub fn ip_version(dtype: &DataType) -> Option<IpVersion> {
+    match dtype {
+        DataType::Extension(typ, _) => match typ.name().as_ref() {
+            IPV4_EXTENSION_NAME => Some(IpVersion::V4),
+            IPV6_EXTENSION_NAME => Some(IpVersion::V6),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Iterate over the addresses of the `storage` of an IP address series as integers.
+pub fn ip_bits_iter(
+    storage: &Series,
+    version: IpVersion,
+) -> PolarsResult<Box<dyn Iterator<Item = Option<u128>> + '_>> {
+    Ok(match version {
+        IpVersion::V4 => Box::new(storage.u32()?.iter().map(|v| v.map(u128::from))),
+        IpVersion::V6 => Box::new(storage.u128()?.iter()),
+    })
+}
+
+/// Collect addresses given as integers into the storage of an IP address series.
+pub fn ip_bits_to_storage(
+    name: PlSmallStr,
+    iter: impl Iterator<Item = Option<u128>>,
+    version: IpVersion,
+) -> Series {
+    match version {
+        IpVersion::V4 => {
+            UInt32Chunked::from_iter_options(name, iter.map(|v| v.map(|v| v as u32))).into_series()
+        },
+        IpVersion::V6 => UInt128Chunked::from_iter_options(name, iter).into_series(),
+    }
+}
+
+/// Format the addresses of the `storage` of an IP address series.
+pub fn ip_to_string(storage: &Series, version: IpVersion) -> PolarsResult<StringChunked> {
+    let mut out = StringChunkedBuilder::new(storage.name().clone(), storage.len());
+    for opt_v in ip_bits_iter(storage, version)? {
+        match opt_v {
+            Some(v) => out.append_value(version.bits_to_addr(v).to_string()),
+            None => out.append_null(),
+        }
+    }
+    Ok(out.finish())
+}
+
+/// Parse IP addresses into the storage of `version`. IPv6 accepts IPv4 addresses as IPv4-mapped
+/// addresses, IPv4 accepts IPv4-mapped IPv6 addresses. Invalid values raise an error if
+/// `strict`, otherwise become null.
+pub fn string_to_ip(ca: &StringChunked, version: IpVersion, strict: bool) -> PolarsResult<Series> {
+    let values = ca
+        .iter()
+        .map(|opt_s| {
+            let Some(s) = opt_s else {
+                return Ok(None);
+            };
+            match s
+                .parse::<IpAddr>()
+                .ok()
+                .and_then(|a| version.addr_to_bits(a))
+            {
+                Some(v) => Ok(Some(v)),
+                None if strict => {
+                    polars_bail!(ComputeError: "invalid {} address: '{}'", version_name(version), s)
+                },
+                None => Ok(None),
+            }
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    Ok(ip_bits_to_storage(
+        ca.name().clone(),
+        values.into_iter(),
+        version,
+    ))
+}
+
+fn version_name(version: IpVersion) -> &'static str {
+    match version {
+        IpVersion::V4 => "IPv4",
+        IpVersion::V6 => "IPv6",
+    }
+}
+
+/// Convert between IPv4 and IPv4-mapped IPv6 addresses. IPv6 addresses that are not IPv4-mapped
+/// raise an error if `strict`, otherwise become null.
+fn convert_ip_version(
+    storage: &Series,
+    from: IpVersion,
+    to: IpVersion,
+    strict: bool,
+) -> PolarsResult<Series> {
+    let values = ip_bits_iter(storage, from)?
+        .map(|opt_v| {
+            let Some(v) = opt_v else {
+                return Ok(None);
+            };
+            match to.addr_to_bits(from.bits_to_addr(v)) {
+                Some(v) => Ok(Some(v)),
+                None if strict => polars_bail!(
+                    ComputeError: "{} is not an IPv4-mapped address", from.bits_to_addr(v)
+                ),
+                None => Ok(None),
+            }
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    let out = ip_bits_to_storage(storage.name().clone(), values.into_iter(), to);
+    Ok(out.into_extension(ExtensionTypeInstance(Box::new(IpAddrType::new(to)))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ip_cast() -> PolarsResult<()> {
+        let s = Series::new("ip".into(), [Some("192.168.1.10"), None, Some("300.1.1.1")]);
+        let ips = s.cast(&ip_dtype(IpVersion::V4))?;
+        assert_eq!(ip_version(ips.dtype()), Some(IpVersion::V4));
+        assert_eq!(ips.null_count(), 2);
+        assert_eq!(ips.str_value(0)?, "192.168.1.10");
+        assert_eq!(ips.to_storage().u32()?.get(0), Some(0xC0A8010A));
+        assert!(s.strict_cast(&ip_dtype(IpVersion::V4)).is_err());
+
+        let v6 = ips.cast(&ip_dtype(IpVersion::V6))?;
+        assert_eq!(v6.str_value(0)?, "::ffff:192.168.1.10");
+        let back = v6.cast(&ip_dtype(IpVersion::V4))?;
+        assert_eq!(
+            back.cast(&DataType::String)?.str()?.get(0),
+            Some("192.168.1.10")
+        );
+
+        let s = Series::new("ip".into(), ["2001:db8::1", "::1"]);
+        let ips = s.strict_cast(&ip_dtype(IpVersion::V6))?;
+        assert_eq!(ips.str_value(0)?, "2001:db8::1");
+        assert!(ips.strict_cast(&ip_dtype(IpVersion::V4)).is_err());
+        Ok(())
+    }
+}
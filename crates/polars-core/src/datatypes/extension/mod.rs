@@ -3,17 +3,44 @@ use std::borrow::Cow;
 use std::fmt::{Debug, Display};
 use std::hash::{Hash, Hasher};
 
-use crate::datatypes::DataType;
+use polars_error::{PolarsResult, polars_bail, polars_ensure};
+
+use crate::chunked_array::cast::CastOptions;
+use crate::datatypes::{AnyValue, DataType};
+use crate::series::Series;
 
 mod generic;
+#[cfg(feature = "geo")]
+mod geometry;
+#[cfg(feature = "dtype-ip")]
+mod ip;
 mod registry;
+#[cfg(feature = "dtype-uuid")]
+mod uuid;
 
 use generic::GenericExtensionType;
+#[cfg(feature = "geo")]
+pub use geometry::{
+    GEOARROW_WKB_EXTENSION_NAME, GeometryType, geometry_dtype, is_geometry, wkb_to_wkt, wkt_to_wkb,
+};
+#[cfg(feature = "dtype-ip")]
+pub use ip::{
+    IPV4_EXTENSION_NAME, IPV6_EXTENSION_NAME, IpAddrType, IpVersion, ip_bits_iter,
+    ip_bits_to_storage, ip_dtype, ip_to_string, ip_version, string_to_ip,
+};
 pub use registry::{
     UnknownExtensionTypeBehavior, get_extension_type_or_generic, get_extension_type_or_storage,
     register_extension_type, set_unknown_extension_type_behavior, unregister_extension_type,
 };
 
+#[cfg(feature = "dtype-uuid")]
+pub(crate) use self::uuid::uuid_to_fixed_size_binary;
+#[cfg(feature = "dtype-uuid")]
+pub use self::uuid::{
+    ARROW_UUID_EXTENSION_NAME, UuidType, UuidVersion, generate_uuids, is_uuid, string_to_uuid,
+    uuid_dtype, uuid_to_string,
+};
+
 pub trait ExtensionTypeFactory: 'static + Send + Sync {
     fn create_type_instance(
         &self,
@@ -23,6 +50,16 @@ pub trait ExtensionTypeFactory: 'static + Send + Sync {
     ) -> Box<dyn ExtensionTypeImpl>;
 }
 
+/// Operations for which an extension type can choose to keep its type in the result.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ExtensionOperation {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+}
+
 pub trait ExtensionTypeImpl: 'static + Send + Sync + Any {
     /// Name of the extension type.
     fn name(&self) -> Cow<'_, str>;
@@ -44,6 +81,53 @@ pub trait ExtensionTypeImpl: 'static + Send + Sync + Any {
     /// Should be a more verbose string representation, useful for debugging, in TitleCase,
     /// for example: String, Decimal(10, 2).
     fn dyn_debug(&self) -> Cow<'_, str>;
+
+    /// Format a single value for display, given as a value of the storage type.
+    ///
+    /// Returns `None` to display the value like its storage type.
+    fn format_value(&self, _value: &AnyValue<'_>) -> Option<String> {
+        None
+    }
+
+    /// Cast the `storage` of a series of this type to `dtype`.
+    ///
+    /// Returns `None` if the cast is not handled by the extension type.
+    fn cast_to(
+        &self,
+        _storage: &Series,
+        _dtype: &DataType,
+        _options: CastOptions,
+    ) -> Option<PolarsResult<Series>> {
+        None
+    }
+
+    /// Cast a series of another type to the `storage` type of this type.
+    ///
+    /// Returns `None` if the cast is not handled by the extension type.
+    fn cast_from(
+        &self,
+        _series: &Series,
+        _storage: &DataType,
+        _options: CastOptions,
+    ) -> Option<PolarsResult<Series>> {
+        None
+    }
+
+    /// Key that defines the equality and ordering of the values, given the `storage` of a series
+    /// of this type. The key must have the same length as the storage.
+    ///
+    /// Returns `None` to compare and sort the storage values themselves.
+    fn comparison_key(&self, _storage: &Series) -> Option<Series> {
+        None
+    }
+
+    /// Whether the result of `op` keeps this extension type.
+    ///
+    /// This only applies if the result has the storage type, by default the storage type is
+    /// returned.
+    fn preserves_extension(&self, _op: ExtensionOperation) -> bool {
+        false
+    }
 }
 
 #[repr(transparent)]
@@ -90,4 +174,207 @@ impl ExtensionTypeInstance {
     pub fn serialize_metadata(&self) -> Option<Cow<'_, str>> {
         self.0.serialize_metadata()
     }
+
+    pub fn format_value(&self, value: &AnyValue<'_>) -> Option<String> {
+        self.0.format_value(value)
+    }
+
+    /// The key to compare, sort and hash the values of `storage` by.
+    pub fn comparison_key<'a>(&self, storage: &'a Series) -> PolarsResult<Cow<'a, Series>> {
+        match self.0.comparison_key(storage) {
+            Some(mut key) => {
+                polars_ensure!(
+                    key.len() == storage.len(),
+                    ComputeError: "comparison key of extension type {} has length {}, expected {}",
+                    self, key.len(), storage.len()
+                );
+                key.rename(storage.name().clone());
+                Ok(Cow::Owned(key))
+            },
+            None => Ok(Cow::Borrowed(storage)),
+        }
+    }
+
+    pub fn preserves_extension(&self, op: ExtensionOperation) -> bool {
+        self.0.preserves_extension(op)
+    }
+
+    /// Whether the result of `op` keeps this extension type, given the `output` type of `op` on
+    /// the `storage` type.
+    pub fn keeps_extension(
+        &self,
+        op: ExtensionOperation,
+        storage: &DataType,
+        output: &DataType,
+    ) -> bool {
+        self.preserves_extension(op) && output == storage
+    }
+}
+
+/// Resolve the extension type of an arithmetic operation on `lhs` and `rhs`, of which at least one
+/// is an extension type. Returns the extension type and its storage type.
+pub fn arithmetic_extension_type<'a>(
+    lhs: &'a DataType,
+    rhs: &'a DataType,
+) -> PolarsResult<(&'a ExtensionTypeInstance, &'a DataType)> {
+    match (lhs, rhs) {
+        (l @ DataType::Extension(typ, storage), r @ DataType::Extension(_, _)) => {
+            polars_ensure!(
+                l == r,
+                InvalidOperation: "arithmetic on extension types {} and {} is not supported", l, r
+            );
+            Ok((typ, storage))
+        },
+        (DataType::Extension(typ, storage), _) | (_, DataType::Extension(typ, storage)) => {
+            Ok((typ, storage))
+        },
+        _ => polars_bail!(InvalidOperation: "expected an extension type, got {} and {}", lhs, rhs),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::hash::BuildHasher;
+
+    use polars_utils::aliases::PlFixedStateQuality;
+
+    use super::*;
+    use crate::prelude::*;
+
+    /// `money` stores cents as `Int64`, `ci_str` is a case-insensitive `String`.
+    #[derive(Clone)]
+    struct TestType(&'static str);
+
+    impl ExtensionTypeImpl for TestType {
+        fn name(&self) -> Cow<'_, str> {
+            Cow::Borrowed(self.0)
+        }
+
+        fn serialize_metadata(&self) -> Option<Cow<'_, str>> {
+            None
+        }
+
+        fn dyn_clone(&self) -> Box<dyn ExtensionTypeImpl> {
+            Box::new(self.clone())
+        }
+
+        fn dyn_eq(&self, other: &dyn ExtensionTypeImpl) -> bool {
+            (other as &dyn Any)
+                .downcast_ref::<TestType>()
+                .is_some_and(|other| self.0 == other.0)
+        }
+
+        fn dyn_hash(&self) -> u64 {
+            PlFixedStateQuality::default().hash_one(self.0)
+        }
+
+        fn dyn_display(&self) -> Cow<'_, str> {
+            Cow::Borrowed(self.0)
+        }
+
+        fn dyn_debug(&self) -> Cow<'_, str> {
+            Cow::Borrowed(self.0)
+        }
+
+        fn format_value(&self, value: &AnyValue<'_>) -> Option<String> {
+            match (self.0, value) {
+                ("money", AnyValue::Int64(v)) => Some(format!("${}.{:02}", v / 100, v % 100)),
+                _ => None,
+            }
+        }
+
+        fn cast_to(
+            &self,
+            storage: &Series,
+            dtype: &DataType,
+            options: CastOptions,
+        ) -> Option<PolarsResult<Series>> {
+            match (self.0, dtype) {
+                ("money", DataType::Float64) => Some(
+                    storage
+                        .cast_with_options(dtype, options)
+                        .map(|s| &s / 100.0),
+                ),
+                _ => None,
+            }
+        }
+
+        fn cast_from(
+            &self,
+            series: &Series,
+            storage: &DataType,
+            options: CastOptions,
+        ) -> Option<PolarsResult<Series>> {
+            match (self.0, series.dtype()) {
+                ("money", DataType::Float64) => {
+                    let cents = &(series * 100.0) + 0.5;
+                    Some(cents.cast_with_options(storage, options))
+                },
+                _ => None,
+            }
+        }
+
+        fn comparison_key(&self, storage: &Series) -> Option<Series> {
+            match self.0 {
+                "ci_str" => {
+                    let ca = storage.str().unwrap();
+                    let lower = ca.iter().map(|s| s.map(str::to_lowercase));
+                    Some(StringChunked::from_iter_options(ca.name().clone(), lower).into_series())
+                },
+                "bad_key" => Some(storage.head(Some(1))),
+                _ => None,
+            }
+        }
+
+        fn preserves_extension(&self, op: ExtensionOperation) -> bool {
+            self.0 == "money" && matches!(op, ExtensionOperation::Add | ExtensionOperation::Sub)
+        }
+    }
+
+    fn ext(name: &'static str, storage: Series) -> Series {
+        storage.into_extension(ExtensionTypeInstance(Box::new(TestType(name))))
+    }
+
+    #[test]
+    fn test_extension_hooks() -> PolarsResult<()> {
+        let money = ext("money", Series::new("m".into(), [150i64, 2005]));
+        assert_eq!(money.str_value(0)?, "$1.50");
+        assert_eq!(money.str_value(1)?, "$20.05");
+
+        // Addition keeps the extension type, multiplication returns the storage type.
+        let sum = (&money + &money)?;
+        assert_eq!(sum.dtype(), money.dtype());
+        assert_eq!(sum.str_value(0)?, "$3.00");
+        let product = (&money * &money)?;
+        assert_eq!(product.dtype(), &DataType::Int64);
+
+        let float = money.cast(&DataType::Float64)?;
+        assert_eq!(float.f64()?.get(1), Some(20.05));
+        let back = float.cast(money.dtype())?;
+        assert!(back.to_storage().equals(money.to_storage()));
+
+        let ci = ext("ci_str", Series::new("s".into(), ["b", "A", "a", "C"]));
+        let other = ext("ci_str", Series::new("s".into(), ["B", "a", "x", "c"]));
+        let eq = ci.equal(&other)?;
+        assert_eq!(
+            Vec::from(&eq),
+            &[Some(true), Some(true), Some(false), Some(true)]
+        );
+        let idx = ci.arg_sort(SortOptions::default().with_maintain_order(true));
+        assert_eq!(Vec::from(&idx), &[Some(1), Some(2), Some(0), Some(3)]);
+
+        // Hashing, grouping and unique use the comparison key.
+        assert_eq!(ci.n_unique()?, 3);
+        assert_eq!(ci.unique_stable()?.str_value(1)?, "A");
+        assert_eq!(ci.group_tuples(false, true)?.len(), 3);
+
+        let bad = ext("bad_key", Series::new("s".into(), ["a", "b"]));
+        let err = bad.equal(&bad).unwrap_err();
+        assert!(err.to_string().contains("comparison key"));
+        assert!(bad.n_unique().is_err());
+        assert!(bad.sort(SortOptions::default()).is_err());
+        let idx = bad.arg_sort(SortOptions::default().with_order_descending(true));
+        assert_eq!(Vec::from(&idx), &[Some(1), Some(0)]);
+        Ok(())
+    }
 }
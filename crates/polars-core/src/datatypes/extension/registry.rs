@@ -89,6 +89,27 @@ static REGISTRY: LazyLock<RwLock<PlHashMap<PlSmallStr, Option<Arc<dyn ExtensionT
     LazyLock::new(|| {
         let mut m = PlHashMap::new();
         m.insert(PlSmallStr::from_static(POLARS_OBJECT_EXTENSION_NAME), None);
+        #[cfg(feature = "geo")]
+        m.insert(
+            PlSmallStr::from_static(super::GEOARROW_WKB_EXTENSION_NAME),
+            Some(Arc::new(super::geometry::GeometryTypeFactory) as Arc<dyn ExtensionTypeFactory>),
+        );
+        #[cfg(feature = "dtype-uuid")]
+        m.insert(
+            PlSmallStr::from_static(super::ARROW_UUID_EXTENSION_NAME),
+            Some(Arc::new(super::uuid::UuidTypeFactory) as Arc<dyn ExtensionTypeFactory>),
+        );
+        #[cfg(feature = "dtype-ip")]
+        for (name, version) in [
+            (super::IPV4_EXTENSION_NAME, super::IpVersion::V4),
+            (super::IPV6_EXTENSION_NAME, super::IpVersion::V6),
+        ] {
+            m.insert(
+                PlSmallStr::from_static(name),
+                Some(Arc::new(super::ip::IpAddrTypeFactory(version))
+                    as Arc<dyn ExtensionTypeFactory>),
+            );
+        }
         RwLock::new(m)
     });
 
@@ -0,0 +1,263 @@
+use std::any::Any;
+use std::borrow::Cow;
+use std::hash::BuildHasher;
+
+use arrow::array::{BinaryViewArray, FixedSizeBinaryArray};
+pub use arrow::datatypes::ARROW_UUID_EXTENSION_NAME;
+use polars_error::{PolarsResult, polars_bail, polars_ensure, polars_err};
+use polars_utils::aliases::PlFixedStateQuality;
+use uuid::Uuid;
+
+use super::{ExtensionTypeFactory, ExtensionTypeImpl, ExtensionTypeInstance};
+use crate::chunked_array::cast::CastOptions;
+use crate::prelude::*;
+
+/// UUIDs stored as 16 big-endian bytes in a `Binary` column, following the canonical Arrow
+/// `arrow.uuid` extension type.
+///
+/// The bytes compare like the UUIDs themselves, so version 7 UUIDs sort by creation time.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct UuidType;
+
+pub(super) struct UuidTypeFactory;
+
+impl ExtensionTypeFactory for UuidTypeFactory {
+    fn create_type_instance(
+        &self,
+        _name: &str,
+        _storage: &DataType,
+        _metadata: Option<&str>,
+    ) -> Box<dyn ExtensionTypeImpl> {
+        Box::new(UuidType)
+    }
+}
+
+impl ExtensionTypeImpl for UuidType {
+    fn name(&self) -> Cow<'_, str> {
+        Cow::Borrowed(ARROW_UUID_EXTENSION_NAME)
+    }
+
+    fn serialize_metadata(&self) -> Option<Cow<'_, str>> {
+        None
+    }
+
+    fn dyn_clone(&self) -> Box<dyn ExtensionTypeImpl> {
+        Box::new(self.clone())
+    }
+
+    fn dyn_eq(&self, other: &dyn ExtensionTypeImpl) -> bool {
+        (other as &dyn Any).downcast_ref::<UuidType>().is_some()
+    }
+
+    fn dyn_hash(&self) -> u64 {
+        PlFixedStateQuality::default().hash_one(self)
+    }
+
+    fn dyn_display(&self) -> Cow<'_, str> {
+        Cow::Borrowed("uuid")
+    }
+
+    fn dyn_debug(&self) -> Cow<'_, str> {
+        Cow::Borrowed("Uuid")
+    }
+
+    fn format_value(&self, value: &AnyValue<'_>) -> Option<String> {
+        let bytes = match value {
+            AnyValue::Binary(b) => *b,
+            AnyValue::BinaryOwned(b) => b.as_slice(),
+            _ => return None,
+        };
+        Some(match Uuid::from_slice(bytes) {
+            Ok(uuid) => uuid.hyphenated().to_string(),
+            Err(_) => "<invalid uuid>".to_string(),
+        })
+    }
+
+    fn cast_to(
+        &self,
+        storage: &Series,
+        dtype: &DataType,
+        options: CastOptions,
+    ) -> Option<PolarsResult<Series>> {
+        match dtype {
+            DataType::String => Some(
+                storage
+                    .binary()
+                    .and_then(|ca| uuid_to_string(ca, options.is_strict()))
+                    .map(|ca| ca.into_series()),
+            ),
+            DataType::Binary => Some(Ok(storage.clone())),
+            _ => None,
+        }
+    }
+
+    fn cast_from(
+        &self,
+        series: &Series,
+        _storage: &DataType,
+        options: CastOptions,
+    ) -> Option<PolarsResult<Series>> {
+        match series.dtype() {
+            DataType::String => Some(
+                string_to_uuid(series.str().unwrap(), options.is_strict())
+                    .map(|ca| ca.into_series()),
+            ),
+            DataType::Binary => Some(
+                validate_uuid(series.binary().unwrap(), options.is_strict())
+                    .map(|ca| ca.into_series()),
+            ),
+            _ => None,
+        }
+    }
+}
+
+/// The UUID data type.
+pub fn uuid_dtype() -> DataType {
+    DataType::Extension(
+        ExtensionTypeInstance(Box::new(UuidType)),
+        Box::new(DataType::Binary),
+    )
+}
+
+/// Whether `dtype` is a UUID.
+pub fn is_uuid(dtype: &DataType) -> bool {
+    matches!(dtype, DataType::Extension(typ, _) if typ.name() == ARROW_UUID_EXTENSION_NAME)
+}
+
+fn parse_uuid_bytes(bytes: &[u8]) -> PolarsResult<Uuid> {
+    Uuid::from_slice(bytes)
+        .map_err(|_| polars_err!(ComputeError: "UUID value has {} bytes, expected 16", bytes.len()))
+}
+
+/// Format UUIDs in their lowercase hyphenated form. Values that are not 16 bytes raise an error
+/// if `strict`, otherwise become null.
+pub fn uuid_to_string(ca: &BinaryChunked, strict: bool) -> PolarsResult<StringChunked> {
+    let mut buf = Uuid::encode_buffer();
+    let mut out = StringChunkedBuilder::new(ca.name().clone(), ca.len());
+    for opt_bytes in ca.iter() {
+        match opt_bytes.map(parse_uuid_bytes) {
+            Some(Ok(uuid)) => out.append_value(uuid.hyphenated().encode_lower(&mut buf)),
+            Some(Err(e)) if strict => return Err(e),
+            _ => out.append_null(),
+        }
+    }
+    Ok(out.finish())
+}
+
+/// Parse UUIDs in any of the common textual forms. Invalid values raise an error if `strict`,
+/// otherwise become null.
+pub fn string_to_uuid(ca: &StringChunked, strict: bool) -> PolarsResult<BinaryChunked> {
+    let mut out = BinaryChunkedBuilder::new(ca.name().clone(), ca.len());
+    for opt_s in ca.iter() {
+        match opt_s.map(Uuid::try_parse) {
+            Some(Ok(uuid)) => out.append_value(uuid.as_bytes()),
+            Some(Err(_)) if strict => {
+                polars_bail!(ComputeError: "invalid UUID: '{}'", opt_s.unwrap())
+            },
+            _ => out.append_null(),
+        }
+    }
+    Ok(out.finish())
+}
+
+/// Check that all values are 16 bytes. Invalid values raise an error if `strict`, otherwise
+/// become null.
+fn validate_uuid(ca: &BinaryChunked, strict: bool) -> PolarsResult<BinaryChunked> {
+    if let Some(len) = ca.iter().flatten().map(|b| b.len()).find(|len| *len != 16) {
+        polars_ensure!(
+            !strict,
+            ComputeError: "UUID value has {} bytes, expected 16", len
+        );
+        return Ok(ca
+            .iter()
+            .map(|opt_b| opt_b.filter(|b| b.len() == 16))
+            .collect::<BinaryChunked>()
+            .with_name(ca.name().clone()));
+    }
+    Ok(ca.clone())
+}
+
+/// Convert the binary storage of UUIDs to the 16-byte fixed-size binary storage of `arrow.uuid`.
+/// Null values are stored as zeroes.
+///
+/// # Panics
+/// Panics if a value is not 16 bytes, which the casts to UUID rule out.
+pub(crate) fn uuid_to_fixed_size_binary(array: &BinaryViewArray) -> FixedSizeBinaryArray {
+    let mut values = Vec::with_capacity(array.len() * 16);
+    for opt_v in array.iter() {
+        let v = opt_v.unwrap_or(&[0; 16]);
+        assert_eq!(v.len(), 16, "UUID value has {} bytes, expected 16", v.len());
+        values.extend_from_slice(v);
+    }
+    FixedSizeBinaryArray::new(
+        ArrowDataType::FixedSizeBinary(16),
+        values.into(),
+        array.validity().cloned(),
+    )
+}
+
+/// Version of the UUIDs to generate.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum UuidVersion {
+    /// Random UUIDs.
+    V4,
+    /// UUIDs prefixed by a millisecond Unix timestamp, which sort by creation time.
+    V7,
+}
+
+/// Generate `len` new UUIDs.
+pub fn generate_uuids(name: PlSmallStr, len: usize, version: UuidVersion) -> Series {
+    let mut out = BinaryChunkedBuilder::new(name, len);
+    for _ in 0..len {
+        let uuid = match version {
+            UuidVersion::V4 => Uuid::new_v4(),
+            UuidVersion::V7 => Uuid::now_v7(),
+        };
+        out.append_value(uuid.as_bytes());
+    }
+    out.finish()
+        .into_series()
+        .into_extension(ExtensionTypeInstance(Box::new(UuidType)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_uuid_cast() -> PolarsResult<()> {
+        let s = Series::new(
+            "id".into(),
+            [
+                Some("67E55044-10B1-426F-9247-BB680E5FE0C8"),
+                None,
+                Some("not a uuid"),
+            ],
+        );
+        let uuids = s.cast(&uuid_dtype())?;
+        assert!(is_uuid(uuids.dtype()));
+        assert_eq!(uuids.null_count(), 2);
+        assert_eq!(uuids.str_value(0)?, "67e55044-10b1-426f-9247-bb680e5fe0c8");
+        assert!(s.strict_cast(&uuid_dtype()).is_err());
+
+        let back = uuids.cast(&DataType::String)?;
+        assert_eq!(
+            back.str()?.get(0),
+            Some("67e55044-10b1-426f-9247-bb680e5fe0c8")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_generate_uuids() -> PolarsResult<()> {
+        let v4 = generate_uuids("id".into(), 100, UuidVersion::V4);
+        assert!(is_uuid(v4.dtype()));
+        assert_eq!(v4.to_storage().n_unique()?, 100);
+
+        let v7 = generate_uuids("id".into(), 100, UuidVersion::V7);
+        let storage = v7.to_storage().binary()?;
+        assert!(storage.iter().all(|b| b.unwrap()[6] >> 4 == 7));
+        assert!(storage.iter().is_sorted());
+        Ok(())
+    }
+}
@@ -257,6 +257,12 @@ impl DataType {
             ArrowDataType::Struct(_) => {
                 panic!("activate the 'dtype-struct' feature to handle struct data types")
             },
+            #[cfg(feature = "dtype-variant")]
+            ArrowDataType::Extension(ext)
+                if ext.name.as_str() == arrow::datatypes::PARQUET_VARIANT_EXTENSION_NAME =>
+            {
+                DataType::Variant
+            },
             ArrowDataType::Extension(ext) if ext.name.as_str() == POLARS_OBJECT_EXTENSION_NAME => {
                 #[cfg(feature = "object")]
                 {
@@ -278,6 +284,8 @@ impl DataType {
             },
             #[cfg(feature = "dtype-decimal")]
             ArrowDataType::Decimal(precision, scale) => DataType::Decimal(*precision, *scale),
+            #[cfg(feature = "dtype-decimal256")]
+            ArrowDataType::Decimal256(precision, scale) => DataType::Decimal256(*precision, *scale),
             ArrowDataType::Utf8View | ArrowDataType::LargeUtf8 | ArrowDataType::Utf8 => {
                 DataType::String
             },
@@ -292,9 +300,21 @@ impl DataType {
             },
             ArrowDataType::LargeBinary | ArrowDataType::Binary => DataType::Binary,
             ArrowDataType::FixedSizeBinary(_) => DataType::Binary,
+            #[cfg(feature = "dtype-map")]
+            ArrowDataType::Map(inner, _is_sorted) => match Self::from_arrow_field(inner) {
+                DataType::Struct(fields) if fields.len() == 2 => DataType::Map(
+                    fields[0].dtype().clone().boxed(),
+                    fields[1].dtype().clone().boxed(),
+                ),
+                dt => DataType::List(dt.boxed()),
+            },
+            #[cfg(not(feature = "dtype-map"))]
             ArrowDataType::Map(inner, _is_sorted) => {
                 DataType::List(Self::from_arrow_field(inner).boxed())
             },
+            #[cfg(feature = "dtype-interval")]
+            ArrowDataType::Interval(IntervalUnit::MonthDayNano) => DataType::Interval,
+            #[cfg(not(feature = "dtype-interval"))]
             ArrowDataType::Interval(IntervalUnit::MonthDayNano) => {
                 check_allow_importing_interval_as_struct("month_day_nano_interval").unwrap();
                 feature_gated!("dtype-struct", DataType::_month_days_ns_struct_type())
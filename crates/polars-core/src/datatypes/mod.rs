@@ -37,6 +37,10 @@ use arrow::datatypes::IntegerType;
 pub use arrow::datatypes::reshape::*;
 pub use arrow::datatypes::{ArrowDataType, TimeUnit as ArrowTimeUnit};
 use arrow::types::NativeType;
+#[cfg(feature = "dtype-decimal256")]
+pub use arrow::types::i256;
+#[cfg(feature = "dtype-interval")]
+pub use arrow::types::months_days_ns;
 use bytemuck::Zeroable;
 pub use dtype::*;
 pub use field::*;
@@ -233,11 +237,19 @@ impl_polars_datatype!(BooleanType, DataType::Boolean, BooleanArray, 'a, bool, bo
 
 #[cfg(feature = "dtype-decimal")]
 impl_polars_datatype!(DecimalType, unimplemented!(), PrimitiveArray<i128>, 'a, i128, i128, i128, FalseT);
+#[cfg(feature = "dtype-decimal256")]
+impl_polars_datatype!(Int256Type, DataType::Int256, PrimitiveArray<i256>, 'a, i256, i256, i256, FalseT);
+#[cfg(feature = "dtype-decimal256")]
+impl_polars_datatype!(Decimal256Type, unimplemented!(), PrimitiveArray<i256>, 'a, i256, i256, i256, FalseT);
 impl_polars_datatype!(DatetimeType, unimplemented!(), PrimitiveArray<i64>, 'a, i64, i64, i64, FalseT);
 impl_polars_datatype!(DurationType, unimplemented!(), PrimitiveArray<i64>, 'a, i64, i64, i64, FalseT);
 impl_polars_datatype!(CategoricalType, unimplemented!(), PrimitiveArray<u32>, 'a, u32, u32, u32, FalseT);
 impl_polars_datatype!(DateType, DataType::Date, PrimitiveArray<i32>, 'a, i32, i32, i32, FalseT);
 impl_polars_datatype!(TimeType, DataType::Time, PrimitiveArray<i64>, 'a, i64, i64, i64, FalseT);
+#[cfg(feature = "dtype-interval")]
+impl_polars_datatype!(IntervalType, DataType::Interval, PrimitiveArray<i128>, 'a, i128, i128, i128, FalseT);
+#[cfg(feature = "dtype-variant")]
+impl_polars_datatype!(VariantType, DataType::Variant, BinaryViewArray, 'a, &'a [u8], Option<&'a [u8]>, Box<[u8]>, TrueT);
 
 impl_polars_categorical_datatype!(Categorical8Type, UInt8Type, u8, U8);
 impl_polars_categorical_datatype!(Categorical16Type, UInt16Type, u16, U16);
@@ -267,6 +279,24 @@ impl PolarsPhysicalType for ListType {
     }
 }
 
+#[cfg(feature = "dtype-map")]
+pub struct MapType {}
+#[cfg(feature = "dtype-map")]
+unsafe impl PolarsDataType for MapType {
+    type Physical<'a> = Box<dyn Array>;
+    type OwnedPhysical = Box<dyn Array>;
+    type ZeroablePhysical<'a> = Option<Box<dyn Array>>;
+    type Array = ListArray<i64>;
+    type IsNested = TrueT;
+    type HasViews = FalseT;
+    type IsStruct = FalseT;
+    type IsObject = FalseT;
+
+    fn get_static_dtype() -> DataType {
+        DataType::Map(Box::new(DataType::Null), Box::new(DataType::Null))
+    }
+}
+
 #[cfg(feature = "dtype-struct")]
 pub struct StructType {}
 #[cfg(feature = "dtype-struct")]
@@ -382,6 +412,8 @@ impl_phys_dtype!(BooleanType);
 impl_phys_dtype!(UInt128Type);
 #[cfg(feature = "dtype-i128")]
 impl_phys_dtype!(Int128Type);
+#[cfg(feature = "dtype-decimal256")]
+impl_phys_dtype!(Int256Type);
 
 #[cfg(feature = "dtype-array")]
 impl_phys_dtype!(FixedSizeListType);
@@ -412,6 +444,8 @@ pub type Int32Chunked = ChunkedArray<Int32Type>;
 pub type Int64Chunked = ChunkedArray<Int64Type>;
 #[cfg(feature = "dtype-i128")]
 pub type Int128Chunked = ChunkedArray<Int128Type>;
+#[cfg(feature = "dtype-decimal256")]
+pub type Int256Chunked = ChunkedArray<Int256Type>;
 #[cfg(feature = "dtype-f16")]
 pub type Float16Chunked = ChunkedArray<Float16Type>;
 pub type Float32Chunked = ChunkedArray<Float32Type>;
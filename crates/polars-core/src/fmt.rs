@@ -215,6 +215,61 @@ macro_rules! format_array {
     }};
 }
 
+#[cfg(feature = "dtype-extension")]
+fn format_extension_array(
+    f: &mut Formatter<'_>,
+    ext: &ExtensionChunked,
+    name: &str,
+    array_type: &str,
+) -> fmt::Result {
+    let typ = ext.extension_type();
+    write!(
+        f,
+        "shape: ({},)\n{}: '{}' [{}]\n[\n",
+        fmt_int_string_custom(&ext.len().to_string(), 3, "_"),
+        array_type,
+        name,
+        ext.dtype()
+    )?;
+
+    let ellipsis = get_ellipsis();
+    let truncate_len = match ext.storage().dtype() {
+        DataType::String => Some(get_str_len_limit()),
+        _ => None,
+    };
+    let write_value = |i: usize, f: &mut Formatter<'_>| -> fmt::Result {
+        let v = ext.get_any_value(i).unwrap();
+        match (typ.format_value(&v), v) {
+            (Some(s), _) => writeln!(f, "\t{s}"),
+            (None, AnyValue::String(s)) => match truncate_len.and_then(|n| s.char_indices().nth(n))
+            {
+                Some((end, _)) => writeln!(f, "\t\"{}{ellipsis}", &s[..end]),
+                None => writeln!(f, "\t\"{s}\""),
+            },
+            (None, v) => writeln!(f, "\t{v}"),
+        }
+    };
+
+    let limit = get_row_limit();
+    if ext.len() > limit {
+        let half = limit / 2;
+        let rest = limit % 2;
+        for i in 0..(half + rest) {
+            write_value(i, f)?;
+        }
+        writeln!(f, "\t{ellipsis}")?;
+        for i in (ext.len() - half)..ext.len() {
+            write_value(i, f)?;
+        }
+    } else {
+        for i in 0..ext.len() {
+            write_value(i, f)?;
+        }
+    }
+
+    write!(f, "]")
+}
+
 #[cfg(feature = "object")]
 fn format_object_array(
     f: &mut Formatter<'_>,
@@ -395,11 +450,35 @@ impl Debug for Series {
                 let dt = format!("{}", self.dtype());
                 format_array!(f, self.duration().unwrap(), &dt, self.name(), "Series")
             },
+            #[cfg(feature = "dtype-interval")]
+            DataType::Interval => {
+                format_array!(
+                    f,
+                    self.interval().unwrap(),
+                    "interval",
+                    self.name(),
+                    "Series"
+                )
+            },
             #[cfg(feature = "dtype-decimal")]
             DataType::Decimal(_, _) => {
                 let dt = format!("{}", self.dtype());
                 format_array!(f, self.decimal().unwrap(), &dt, self.name(), "Series")
             },
+            #[cfg(feature = "dtype-variant")]
+            DataType::Variant => {
+                let json = self.variant().unwrap().to_json().map_err(|_| fmt::Error)?;
+                format_array!(f, &json, "variant", self.name(), "Series")
+            },
+            #[cfg(feature = "dtype-decimal256")]
+            DataType::Decimal256(_, _) => {
+                let dt = format!("{}", self.dtype());
+                format_array!(f, self.decimal256().unwrap(), &dt, self.name(), "Series")
+            },
+            #[cfg(feature = "dtype-decimal256")]
+            DataType::Int256 => {
+                format_array!(f, self.i256().unwrap(), "i256", self.name(), "Series")
+            },
             #[cfg(feature = "dtype-array")]
             DataType::Array(_, _) => {
                 let dt = format!("{}", self.dtype());
@@ -409,6 +488,11 @@ impl Debug for Series {
                 let dt = format!("{}", self.dtype());
                 format_array!(f, self.list().unwrap(), &dt, self.name(), "Series")
             },
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(_, _) => {
+                let dt = format!("{}", self.dtype());
+                format_array!(f, self.map().unwrap(), &dt, self.name(), "Series")
+            },
             #[cfg(feature = "object")]
             DataType::Object(_) => format_object_array(f, self, self.name(), "Series"),
             #[cfg(feature = "dtype-categorical")]
@@ -449,8 +533,7 @@ impl Debug for Series {
             },
             #[cfg(feature = "dtype-extension")]
             DataType::Extension(_, _) => {
-                let dt = format!("{}", self.dtype());
-                format_array!(f, self.ext().unwrap(), &dt, self.name(), "Series")
+                format_extension_array(f, self.ext().unwrap(), self.name(), "Series")
             },
             dt => panic!("{dt:?} not impl"),
         }
@@ -1140,6 +1223,46 @@ pub fn iso_duration_string(s: &mut String, mut v: i64, unit: TimeUnit) {
     }
 }
 
+#[cfg(feature = "dtype-interval")]
+pub fn fmt_interval_string<W: Write>(f: &mut W, v: months_days_ns) -> fmt::Result {
+    // eg: "1y 2mo -3d 4h 30m". Every part carries its own sign and the nanoseconds
+    // are never folded into days, so that the string parses back into the same interval.
+    let ns = v.ns();
+    let parts = [
+        (v.months() as i64 / 12, "y"),
+        (v.months() as i64 % 12, "mo"),
+        (v.days() as i64, "d"),
+        (ns / 3_600_000_000_000, "h"),
+        (ns % 3_600_000_000_000 / 60_000_000_000, "m"),
+        (ns % 60_000_000_000 / 1_000_000_000, "s"),
+    ];
+    let fractional = ns % 1_000_000_000;
+    let fractional = if fractional % 1_000_000 == 0 {
+        (fractional / 1_000_000, "ms")
+    } else if fractional % 1_000 == 0 {
+        (fractional / 1_000, "µs")
+    } else {
+        (fractional, "ns")
+    };
+
+    let mut buffer = itoa::Buffer::new();
+    let mut wrote_part = false;
+    for (value, unit) in parts.into_iter().chain(std::iter::once(fractional)) {
+        if value != 0 {
+            if wrote_part {
+                f.write_char(' ')?;
+            }
+            f.write_str(buffer.format(value))?;
+            f.write_str(unit)?;
+            wrote_part = true;
+        }
+    }
+    if !wrote_part {
+        f.write_str("0s")?;
+    }
+    Ok(())
+}
+
 fn format_blob(f: &mut Formatter<'_>, bytes: &[u8]) -> fmt::Result {
     let ellipsis = get_ellipsis();
     let width = get_str_len_limit() * 2;
@@ -1193,6 +1316,8 @@ impl Display for AnyValue<'_> {
             },
             #[cfg(feature = "dtype-duration")]
             AnyValue::Duration(v, tu) => fmt_duration_string(f, *v, *tu),
+            #[cfg(feature = "dtype-interval")]
+            AnyValue::Interval(v) => fmt_interval_string(f, *v),
             #[cfg(feature = "dtype-time")]
             AnyValue::Time(_) => {
                 let nt: chrono::NaiveTime = self.into();
@@ -1223,6 +1348,8 @@ impl Display for AnyValue<'_> {
             AnyValue::StructOwned(payload) => fmt_struct(f, &payload.0),
             #[cfg(feature = "dtype-decimal")]
             AnyValue::Decimal(v, _prec, scale) => fmt_decimal(f, *v, *scale),
+            #[cfg(feature = "dtype-decimal256")]
+            AnyValue::Decimal256(v, _prec, scale) => fmt_decimal256(f, *v, *scale),
         }
     }
 }
@@ -1324,6 +1451,14 @@ fn fmt_decimal(f: &mut Formatter<'_>, v: i128, scale: usize) -> fmt::Result {
     f.write_str(fmt_float_string(fmt_buf.format_dec128(v, scale, trim_zeros, false)).as_str())
 }
 
+#[inline]
+#[cfg(feature = "dtype-decimal256")]
+fn fmt_decimal256(f: &mut Formatter<'_>, v: i256, scale: usize) -> fmt::Result {
+    let mut fmt_buf = polars_compute::decimal::DecimalFmtBuffer::new();
+    let trim_zeros = get_trim_decimal_zeros();
+    f.write_str(fmt_float_string(fmt_buf.format_dec256(v, scale, trim_zeros, false)).as_str())
+}
+
 #[cfg(all(
     test,
     feature = "temporal",
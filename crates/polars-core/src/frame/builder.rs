@@ -105,6 +105,24 @@ impl DataFrameBuilder {
                     let scalar_as_series = sc.scalar().clone().into_series(PlSmallStr::default());
                     builder.subslice_extend_repeated(&scalar_as_series, 0, 1, len, share);
                 },
+                Column::RunEnd(re) => {
+                    let re = re.slice(start as i64, length);
+                    for (run, len) in re.run_lengths().enumerate() {
+                        builder.subslice_extend_repeated(re.values(), run, 1, len as usize, share);
+                    }
+                },
+                Column::Dictionary(dc) => {
+                    let keys = dc.keys().slice(start as i64, length);
+                    let keys = keys.rechunk();
+                    let values = dc.values().rechunk();
+                    // SAFETY: The keys are in-bounds for the dictionary values.
+                    unsafe { builder.gather_extend(&values, keys.cont_slice().unwrap(), share) };
+                },
+                #[cfg(feature = "compress_in_memory")]
+                Column::Packed(pc) => {
+                    let s = pc.slice(start as i64, length).take_materialized_series();
+                    builder.subslice_extend(&s, 0, s.len(), share);
+                },
             }
         }
 
@@ -133,6 +151,19 @@ impl DataFrameBuilder {
                     let scalar_as_series = sc.scalar().clone().into_series(PlSmallStr::default());
                     builder.subslice_extend_repeated(&scalar_as_series, 0, 1, len * repeats, share);
                 },
+                Column::RunEnd(re) => {
+                    let s = re.as_materialized_series();
+                    builder.subslice_extend_repeated(s, start, length, repeats, share);
+                },
+                Column::Dictionary(dc) => {
+                    let s = dc.as_materialized_series();
+                    builder.subslice_extend_repeated(s, start, length, repeats, share);
+                },
+                #[cfg(feature = "compress_in_memory")]
+                Column::Packed(pc) => {
+                    let s = pc.slice(start as i64, length).take_materialized_series();
+                    builder.subslice_extend_repeated(&s, 0, s.len(), repeats, share);
+                },
             }
         }
 
@@ -162,6 +193,22 @@ impl DataFrameBuilder {
                     let scalar_as_series = sc.scalar().clone().into_series(PlSmallStr::default());
                     builder.subslice_extend_repeated(&scalar_as_series, 0, 1, len * repeats, share);
                 },
+                Column::RunEnd(re) => {
+                    let re = re.slice(start as i64, length);
+                    for (run, len) in re.run_lengths().enumerate() {
+                        let len = len as usize * repeats;
+                        builder.subslice_extend_repeated(re.values(), run, 1, len, share);
+                    }
+                },
+                Column::Dictionary(dc) => {
+                    let s = dc.as_materialized_series();
+                    builder.subslice_extend_each_repeated(s, start, length, repeats, share);
+                },
+                #[cfg(feature = "compress_in_memory")]
+                Column::Packed(pc) => {
+                    let s = pc.slice(start as i64, length).take_materialized_series();
+                    builder.subslice_extend_each_repeated(&s, 0, s.len(), repeats, share);
+                },
             }
         }
 
@@ -192,6 +239,16 @@ impl DataFrameBuilder {
                     let scalar_as_series = sc.scalar().clone().into_series(PlSmallStr::default());
                     builder.subslice_extend_repeated(&scalar_as_series, 0, 1, idxs.len(), share);
                 },
+                Column::RunEnd(re) => {
+                    builder.gather_extend(re.as_materialized_series(), idxs, share);
+                },
+                Column::Dictionary(dc) => {
+                    builder.gather_extend(dc.as_materialized_series(), idxs, share);
+                },
+                #[cfg(feature = "compress_in_memory")]
+                Column::Packed(pc) => {
+                    builder.gather_extend(pc.as_materialized_series(), idxs, share);
+                },
             }
         }
 
@@ -226,6 +283,16 @@ impl DataFrameBuilder {
                         builder.opt_gather_extend(&scalar_as_series, &trans_idxs, share);
                     }
                 },
+                Column::RunEnd(re) => {
+                    builder.opt_gather_extend(re.as_materialized_series(), idxs, share);
+                },
+                Column::Dictionary(dc) => {
+                    builder.opt_gather_extend(dc.as_materialized_series(), idxs, share);
+                },
+                #[cfg(feature = "compress_in_memory")]
+                Column::Packed(pc) => {
+                    builder.opt_gather_extend(pc.as_materialized_series(), idxs, share);
+                },
             }
         }
 
@@ -55,7 +55,11 @@ impl DataFrame {
                 .iter()
                 .map(|col| match col {
                     Column::Series(s) => Column::from(s.select_chunk(i)),
-                    Column::Scalar(_) => col.slice(prev_height as i64, chunk_size),
+                    Column::Scalar(_) | Column::RunEnd(_) | Column::Dictionary(_) => {
+                        col.slice(prev_height as i64, chunk_size)
+                    },
+                    #[cfg(feature = "compress_in_memory")]
+                    Column::Packed(_) => col.slice(prev_height as i64, chunk_size),
                 })
                 .collect::<Vec<_>>();
 
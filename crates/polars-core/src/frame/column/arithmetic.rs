@@ -14,6 +14,10 @@ fn num_op_with_broadcast<T: Num + NumCast, F: Fn(&Series, T) -> Series>(
             ScalarColumn::from_single_value_series(op(&s.as_single_value_series(), n), s.len())
                 .into()
         },
+        Column::RunEnd(s) => s.with_values(op(s.values(), n)).into(),
+        Column::Dictionary(s) => s.with_values(op(s.values(), n)).into(),
+        #[cfg(feature = "compress_in_memory")]
+        Column::Packed(s) => op(&s.materialize(), n).into(),
     }
 }
 
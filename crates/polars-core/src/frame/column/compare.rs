@@ -16,6 +16,47 @@ macro_rules! column_element_wise_broadcasting {
                     }
                 })
             },
+            (Column::RunEnd(lhs), Column::Scalar(rhs))
+                if rhs.len() == 1 || lhs.len() == rhs.len() =>
+            {
+                $op(lhs.values(), &rhs.as_single_value_series()).map(|ca| lhs.expand_bool(&ca))
+            },
+            (Column::Scalar(lhs), Column::RunEnd(rhs))
+                if lhs.len() == 1 || lhs.len() == rhs.len() =>
+            {
+                $op(&lhs.as_single_value_series(), rhs.values()).map(|ca| rhs.expand_bool(&ca))
+            },
+            (Column::Dictionary(lhs), Column::Scalar(rhs))
+                if rhs.len() == 1 || lhs.len() == rhs.len() =>
+            {
+                lhs.map_values_to_bool(|values| $op(values, &rhs.as_single_value_series()))
+            },
+            (Column::Scalar(lhs), Column::Dictionary(rhs))
+                if lhs.len() == 1 || lhs.len() == rhs.len() =>
+            {
+                rhs.map_values_to_bool(|values| $op(&lhs.as_single_value_series(), values))
+                    .map(|ca| ca.with_name(lhs.name().clone()))
+            },
+            #[cfg(feature = "compress_in_memory")]
+            (Column::Packed(lhs), Column::Scalar(rhs))
+                if rhs.len() == 1 || lhs.len() == rhs.len() =>
+            {
+                let rhs = rhs.as_single_value_series();
+                match lhs.map_values_to_bool(|values| $op(values, &rhs))? {
+                    Some(out) => Ok(out),
+                    None => $op(&lhs.materialize(), &rhs),
+                }
+            },
+            #[cfg(feature = "compress_in_memory")]
+            (Column::Scalar(lhs), Column::Packed(rhs))
+                if lhs.len() == 1 || lhs.len() == rhs.len() =>
+            {
+                let lhs = lhs.as_single_value_series();
+                match rhs.map_values_to_bool(|values| $op(&lhs, values))? {
+                    Some(out) => Ok(out.with_name(lhs.name().clone())),
+                    None => $op(&lhs, &rhs.materialize()),
+                }
+            },
             (lhs, rhs) => $op(lhs.as_materialized_series(), rhs.as_materialized_series()),
         }
     };
@@ -0,0 +1,418 @@
+use std::sync::OnceLock;
+
+use polars_error::{PolarsResult, polars_ensure};
+use polars_utils::IdxSize;
+use polars_utils::pl_str::PlSmallStr;
+
+use super::{AnyValue, Column, DataType, IntoColumn, Series};
+use crate::chunked_array::cast::CastOptions;
+use crate::prelude::*;
+
+/// A [`Column`] that stores every row as a key into a [`Series`] of dictionary values.
+///
+/// This is how dictionary encoded columns are kept when they are read from a file. Elementwise
+/// operations only have to be computed for the dictionary values, and the column is lazily
+/// materialized into a [`Series`] when that is needed.
+#[derive(Debug, Clone)]
+pub struct DictionaryColumn {
+    name: PlSmallStr,
+    /// The dictionary values. A null row points to a null value.
+    values: Series,
+    /// The index into `values` of every row.
+    keys: IdxCa,
+
+    // invariants:
+    // keys has no nulls
+    // keys are in-bounds for values
+    // materialized.name() == name
+    // materialized.len() == keys.len()
+    // materialized.dtype() == values.dtype()
+    /// A lazily materialized [`Series`] variant of this [`DictionaryColumn`]
+    materialized: OnceLock<Series>,
+}
+
+impl DictionaryColumn {
+    /// Create a new [`DictionaryColumn`] from the dictionary values and the key of every row.
+    ///
+    /// Null keys become null rows.
+    pub fn new(name: PlSmallStr, values: Series, keys: IdxCa) -> PolarsResult<Self> {
+        if let Some(max) = ChunkAgg::max(&keys) {
+            polars_ensure!(
+                (max as usize) < values.len(),
+                OutOfBounds: "dictionary key {} is out of bounds for {} values",
+                max, values.len()
+            );
+        }
+
+        // SAFETY: We just checked the keys.
+        Ok(unsafe { Self::new_unchecked(name, values, keys) })
+    }
+
+    /// # Safety
+    ///
+    /// The non-null `keys` have to be in-bounds for `values`.
+    pub unsafe fn new_unchecked(name: PlSmallStr, mut values: Series, keys: IdxCa) -> Self {
+        let keys = if keys.has_nulls() {
+            // Reuse a trailing null value that was added before.
+            let has_null_value =
+                !values.is_empty() && unsafe { values.get_unchecked(values.len() - 1) }.is_null();
+            if !has_null_value {
+                values = values.extend_constant(AnyValue::Null, 1).unwrap();
+            }
+            keys.fill_null_with_values(values.len() as IdxSize - 1)
+                .unwrap()
+        } else {
+            keys
+        };
+
+        Self {
+            values: values.with_name(name.clone()),
+            keys,
+            name,
+
+            materialized: OnceLock::new(),
+        }
+    }
+
+    pub fn new_empty(name: PlSmallStr, dtype: &DataType) -> Self {
+        // SAFETY: There are no keys.
+        unsafe {
+            Self::new_unchecked(
+                name.clone(),
+                Series::new_empty(name, dtype),
+                IdxCa::new_vec(PlSmallStr::EMPTY, Vec::new()),
+            )
+        }
+    }
+
+    pub fn name(&self) -> &PlSmallStr {
+        &self.name
+    }
+
+    pub fn dtype(&self) -> &DataType {
+        self.values.dtype()
+    }
+
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// The dictionary values.
+    pub fn values(&self) -> &Series {
+        &self.values
+    }
+
+    /// The index into the dictionary values of every row.
+    pub fn keys(&self) -> &IdxCa {
+        &self.keys
+    }
+
+    /// Create a column with the same keys but other dictionary `values`.
+    ///
+    /// This panics if `values` does not have the same length as the current values.
+    pub fn with_values(&self, values: Series) -> Self {
+        assert_eq!(values.len(), self.values.len());
+        // SAFETY: The keys are unchanged.
+        unsafe { Self::new_unchecked(values.name().clone(), values, self.keys.clone()) }
+    }
+
+    fn with_keys(&self, keys: IdxCa) -> Self {
+        // SAFETY: The keys are taken from the current keys.
+        unsafe { Self::new_unchecked(self.name.clone(), self.values.clone(), keys) }
+    }
+
+    /// Evaluate an elementwise predicate on the dictionary values and gather the result for every
+    /// row.
+    pub fn map_values_to_bool(
+        &self,
+        f: impl FnOnce(&Series) -> PolarsResult<BooleanChunked>,
+    ) -> PolarsResult<BooleanChunked> {
+        let per_value = f(&self.values)?;
+        polars_ensure!(
+            per_value.len() == self.values.len(),
+            ShapeMismatch: "expected an elementwise result of length {}, got {}",
+            self.values.len(), per_value.len()
+        );
+        // SAFETY: The keys are in-bounds.
+        let mut out = unsafe { per_value.take_unchecked(&self.keys) };
+        out.rename(self.name.clone());
+        Ok(out)
+    }
+
+    /// Materialize the [`DictionaryColumn`] into a [`Series`].
+    pub fn to_series(&self) -> Series {
+        // SAFETY: The keys are in-bounds.
+        unsafe { self.values.take_unchecked(&self.keys) }
+    }
+
+    /// Get the [`DictionaryColumn`] as [`Series`] if it was already materialized.
+    pub fn lazy_as_materialized_series(&self) -> Option<&Series> {
+        self.materialized.get()
+    }
+
+    /// Get the [`DictionaryColumn`] as [`Series`]
+    ///
+    /// This needs to materialize upon the first call. Afterwards, this is cached.
+    pub fn as_materialized_series(&self) -> &Series {
+        self.materialized.get_or_init(|| self.to_series())
+    }
+
+    /// Take the [`DictionaryColumn`] and materialize as a [`Series`] if not already done.
+    pub fn take_materialized_series(mut self) -> Series {
+        self.materialized.take().unwrap_or_else(|| self.to_series())
+    }
+
+    pub fn rename(&mut self, name: PlSmallStr) -> &mut Self {
+        if let Some(series) = self.materialized.get_mut() {
+            series.rename(name.clone());
+        }
+
+        self.values.rename(name.clone());
+        self.name = name;
+        self
+    }
+
+    pub fn cast_with_options(&self, dtype: &DataType, options: CastOptions) -> PolarsResult<Self> {
+        let values = self.values.cast_with_options(dtype, options)?;
+        Ok(self.with_values(values))
+    }
+
+    /// # Safety
+    ///
+    /// This can lead to invalid memory access in downstream code.
+    pub unsafe fn cast_unchecked(&self, dtype: &DataType) -> PolarsResult<Self> {
+        let values = unsafe { self.values.cast_unchecked(dtype) }?;
+        Ok(self.with_values(values))
+    }
+
+    /// # Safety
+    ///
+    /// `index` has to be in-bounds.
+    pub unsafe fn get_unchecked(&self, index: usize) -> AnyValue<'_> {
+        unsafe {
+            let key = self.keys.value_unchecked(index);
+            self.values.get_unchecked(key as usize)
+        }
+    }
+
+    /// Whether every row is valid.
+    fn is_valid(&self) -> BooleanChunked {
+        self.map_values_to_bool(|values| Ok(values.is_not_null()))
+            .unwrap()
+    }
+
+    pub fn null_count(&self) -> usize {
+        if !self.values.has_nulls() {
+            return 0;
+        }
+        self.len() - self.is_valid().num_trues()
+    }
+
+    pub fn has_nulls(&self) -> bool {
+        self.null_count() > 0
+    }
+
+    pub fn slice(&self, offset: i64, length: usize) -> Self {
+        self.with_keys(self.keys.slice(offset, length))
+    }
+
+    pub fn filter(&self, mask: &BooleanChunked) -> PolarsResult<Self> {
+        polars_ensure!(
+            mask.len() == self.len(),
+            ShapeMismatch: "filter's length: {} differs from that of the column: {}",
+            mask.len(), self.len()
+        );
+        Ok(self.with_keys(self.keys.filter(mask)?))
+    }
+
+    /// # Safety
+    ///
+    /// The indices have to be in-bounds.
+    pub unsafe fn take_unchecked(&self, indices: &IdxCa) -> Self {
+        self.with_keys(unsafe { self.keys.take_unchecked(indices) })
+    }
+
+    /// # Safety
+    ///
+    /// The indices have to be in-bounds.
+    pub unsafe fn take_slice_unchecked(&self, indices: &[IdxSize]) -> Self {
+        self.with_keys(unsafe { self.keys.take_unchecked(indices) })
+    }
+
+    pub fn drop_nulls(&self) -> Self {
+        if !self.values.has_nulls() {
+            return self.clone();
+        }
+        self.with_keys(self.keys.filter(&self.is_valid()).unwrap())
+    }
+
+    pub fn reverse(&self) -> Self {
+        self.with_keys(self.keys.reverse())
+    }
+}
+
+impl IntoColumn for DictionaryColumn {
+    #[inline(always)]
+    fn into_column(self) -> Column {
+        self.into()
+    }
+}
+
+impl From<DictionaryColumn> for Column {
+    #[inline]
+    fn from(value: DictionaryColumn) -> Self {
+        Self::Dictionary(value)
+    }
+}
+
+#[cfg(feature = "dsl-schema")]
+impl schemars::JsonSchema for DictionaryColumn {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "DictionaryColumn".into()
+    }
+
+    fn schema_id() -> std::borrow::Cow<'static, str> {
+        std::borrow::Cow::Borrowed(concat!(module_path!(), "::", "DictionaryColumn"))
+    }
+
+    fn json_schema(generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        serde_impl::SerializeWrap::json_schema(generator)
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use polars_error::PolarsError;
+    use polars_utils::IdxSize;
+    use polars_utils::pl_str::PlSmallStr;
+
+    use super::DictionaryColumn;
+    use crate::frame::Series;
+    use crate::prelude::IdxCa;
+
+    #[derive(serde::Serialize, serde::Deserialize)]
+    #[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+    pub struct SerializeWrap {
+        name: PlSmallStr,
+        /// The dictionary values
+        values: Series,
+        keys: Vec<IdxSize>,
+    }
+
+    impl From<&DictionaryColumn> for SerializeWrap {
+        fn from(value: &DictionaryColumn) -> Self {
+            Self {
+                name: value.name.clone(),
+                values: value.values.clone(),
+                keys: value.keys.cont_slice().map_or_else(
+                    |_| value.keys.into_no_null_iter().collect(),
+                    |keys| keys.to_vec(),
+                ),
+            }
+        }
+    }
+
+    impl TryFrom<SerializeWrap> for DictionaryColumn {
+        type Error = PolarsError;
+
+        fn try_from(value: SerializeWrap) -> Result<Self, Self::Error> {
+            let keys = IdxCa::from_vec(PlSmallStr::EMPTY, value.keys);
+            DictionaryColumn::new(value.name, value.values, keys)
+        }
+    }
+
+    impl serde::ser::Serialize for DictionaryColumn {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            SerializeWrap::from(self).serialize(serializer)
+        }
+    }
+
+    impl<'de> serde::de::Deserialize<'de> for DictionaryColumn {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            use serde::de::Error;
+
+            SerializeWrap::deserialize(deserializer)
+                .and_then(|x| DictionaryColumn::try_from(x).map_err(D::Error::custom))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn column() -> DictionaryColumn {
+        let values = Series::new("a".into(), ["x", "yy", "z"]);
+        let keys = IdxCa::new(
+            "".into(),
+            [Some(1), Some(0), None, Some(1), Some(2), None, Some(1)],
+        );
+        DictionaryColumn::new("a".into(), values, keys).unwrap()
+    }
+
+    #[test]
+    fn test_dictionary_column() -> PolarsResult<()> {
+        let dc = column();
+        let dense = dc.to_series();
+        assert_eq!(dc.len(), 7);
+        assert_eq!(dc.null_count(), 2);
+        assert_eq!(
+            Vec::from(dense.str()?),
+            &[
+                Some("yy"),
+                Some("x"),
+                None,
+                Some("yy"),
+                Some("z"),
+                None,
+                Some("yy")
+            ]
+        );
+
+        let mask = BooleanChunked::from_iter_values("".into(), (0..7).map(|i| i % 2 == 0));
+        let filtered = dc.filter(&mask)?;
+        assert!(filtered.to_series().equals_missing(&dense.filter(&mask)?));
+        assert!(
+            dc.slice(1, 4)
+                .to_series()
+                .equals_missing(&dense.slice(1, 4))
+        );
+        assert!(
+            dc.drop_nulls()
+                .to_series()
+                .equals_missing(&dense.drop_nulls())
+        );
+
+        let idxs = IdxCa::new("".into(), [Some(6), None, Some(0)]);
+        let taken = unsafe { dc.take_unchecked(&idxs) };
+        assert!(
+            taken
+                .to_series()
+                .equals_missing(&unsafe { dense.take_unchecked(&idxs) })
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_dictionary_map_values() -> PolarsResult<()> {
+        let dc = column();
+        let dense = dc.to_series();
+        let yy = Series::new("".into(), ["yy"]);
+
+        let eq = dc.map_values_to_bool(|values| values.equal_missing(&yy))?;
+        assert_eq!(Vec::from(&eq), Vec::from(&dense.equal_missing(&yy)?));
+        let eq = dc.map_values_to_bool(|values| values.equal(&yy))?;
+        assert_eq!(Vec::from(&eq), Vec::from(&dense.equal(&yy)?));
+        Ok(())
+    }
+}
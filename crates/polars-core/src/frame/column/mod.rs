@@ -2,12 +2,16 @@ use std::borrow::Cow;
 
 use arrow::bitmap::{Bitmap, BitmapBuilder};
 use arrow::trusted_len::TrustMyLength;
+pub use dictionary::DictionaryColumn;
 use num_traits::{Num, NumCast};
+#[cfg(feature = "compress_in_memory")]
+pub use packed::PackedColumn;
 use polars_compute::rolling::QuantileMethod;
 use polars_error::PolarsResult;
 use polars_utils::aliases::PlSeedableRandomStateQuality;
 use polars_utils::index::check_bounds;
 use polars_utils::pl_str::PlSmallStr;
+pub use run_end::RunEndColumn;
 pub use scalar::ScalarColumn;
 
 use self::compare_inner::{TotalEqInner, TotalOrdInner};
@@ -23,6 +27,10 @@ use crate::{HEAD_DEFAULT_LENGTH, TAIL_DEFAULT_LENGTH};
 
 mod arithmetic;
 mod compare;
+mod dictionary;
+#[cfg(feature = "compress_in_memory")]
+mod packed;
+mod run_end;
 mod scalar;
 mod series;
 
@@ -32,15 +40,22 @@ mod series;
 /// [`as_materialized_series`][Column::as_materialized_series] and
 /// [`take_materialized_series`][Column::take_materialized_series].
 ///
-/// Currently, there are two ways to represent a [`Column`].
+/// Currently, there are five ways to represent a [`Column`].
 /// 1. A [`Series`] of values
 /// 2. A [`ScalarColumn`] that repeats a single [`Scalar`]
+/// 3. A [`RunEndColumn`] that repeats a [`Series`] of values in runs
+/// 4. A [`DictionaryColumn`] that gathers every row from a [`Series`] of dictionary values
+/// 5. A `PackedColumn` that keeps integers bitpacked in memory
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 #[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
 pub enum Column {
     Series(SeriesColumn),
     Scalar(ScalarColumn),
+    RunEnd(RunEndColumn),
+    Dictionary(DictionaryColumn),
+    #[cfg(feature = "compress_in_memory")]
+    Packed(PackedColumn),
 }
 
 /// Convert `Self` into a [`Column`]
@@ -69,6 +84,16 @@ impl Column {
         Self::Scalar(ScalarColumn::new(name, scalar, length))
     }
 
+    /// Create a run-end encoded [`Column`] from the value and the exclusive end of every run.
+    #[inline]
+    pub fn new_run_end(
+        name: PlSmallStr,
+        values: Series,
+        run_ends: Vec<IdxSize>,
+    ) -> PolarsResult<Self> {
+        RunEndColumn::new(name, values, run_ends.into()).map(Self::RunEnd)
+    }
+
     pub fn new_row_index(name: PlSmallStr, offset: IdxSize, length: usize) -> PolarsResult<Column> {
         let Ok(length) = IdxSize::try_from(length) else {
             polars_bail!(
@@ -105,6 +130,10 @@ impl Column {
         match self {
             Column::Series(s) => s,
             Column::Scalar(s) => s.as_materialized_series(),
+            Column::RunEnd(s) => s.as_materialized_series(),
+            Column::Dictionary(s) => s.as_materialized_series(),
+            #[cfg(feature = "compress_in_memory")]
+            Column::Packed(s) => s.as_materialized_series(),
         }
     }
 
@@ -122,6 +151,9 @@ impl Column {
     ///
     /// * For `Column::Series` columns, simply returns the inner `Series`.
     /// * For `Column::Scalar` columns, returns an empty or unit length series.
+    /// * For `Column::RunEnd` columns, returns the value of every run.
+    /// * For `Column::Dictionary` columns, returns the dictionary values.
+    /// * For `Column::Packed` columns, returns the materialized `Series`.
     ///
     /// # Note
     /// This method is safe to use. However, care must be taken when operating on the returned
@@ -131,6 +163,10 @@ impl Column {
         match self {
             Column::Series(s) => (**s).clone(),
             Column::Scalar(s) => s.as_single_value_series(),
+            Column::RunEnd(s) => s.values().clone(),
+            Column::Dictionary(s) => s.values().clone(),
+            #[cfg(feature = "compress_in_memory")]
+            Column::Packed(s) => s.materialize().into_owned(),
         }
     }
 
@@ -143,6 +179,10 @@ impl Column {
     /// * `self` is `Column::Scalar` and if either:
     ///   * `self` is not empty and `new_s` is not of unit length.
     ///   * `self` is empty and `new_s` is not empty.
+    /// * `self` is `Column::RunEnd` and the length of `new_s` does not match the number of runs.
+    /// * `self` is `Column::Dictionary` and the length of `new_s` does not match the number of
+    ///   dictionary values.
+    /// * `self` is `Column::Packed` and the length of `new_s` does not match that of `self`.
     pub fn _to_new_from_backing(&self, new_s: Series) -> Self {
         match self {
             Column::Series(s) => {
@@ -153,6 +193,13 @@ impl Column {
                 assert_eq!(new_s.len(), s.as_single_value_series().len());
                 Column::Scalar(ScalarColumn::from_single_value_series(new_s, self.len()))
             },
+            Column::RunEnd(s) => Column::RunEnd(s.with_values(new_s)),
+            Column::Dictionary(s) => Column::Dictionary(s.with_values(new_s)),
+            #[cfg(feature = "compress_in_memory")]
+            Column::Packed(s) => {
+                assert_eq!(new_s.len(), s.len());
+                Column::Series(SeriesColumn::new(new_s))
+            },
         }
     }
 
@@ -175,6 +222,39 @@ impl Column {
                 };
                 s
             },
+            Column::RunEnd(s) => {
+                let series = std::mem::replace(
+                    s,
+                    RunEndColumn::new_empty(PlSmallStr::EMPTY, &DataType::Null),
+                )
+                .take_materialized_series();
+                *self = Column::Series(series.into());
+                let Column::Series(s) = self else {
+                    unreachable!();
+                };
+                s
+            },
+            Column::Dictionary(s) => {
+                let series = std::mem::replace(
+                    s,
+                    DictionaryColumn::new_empty(PlSmallStr::EMPTY, &DataType::Null),
+                )
+                .take_materialized_series();
+                *self = Column::Series(series.into());
+                let Column::Series(s) = self else {
+                    unreachable!();
+                };
+                s
+            },
+            #[cfg(feature = "compress_in_memory")]
+            Column::Packed(s) => {
+                let series = s.materialize().into_owned();
+                *self = Column::Series(series.into());
+                let Column::Series(s) = self else {
+                    unreachable!();
+                };
+                s
+            },
         }
     }
     /// Take [`Series`] from a [`Column`]
@@ -185,6 +265,10 @@ impl Column {
         match self {
             Column::Series(s) => s.take(),
             Column::Scalar(s) => s.take_materialized_series(),
+            Column::RunEnd(s) => s.take_materialized_series(),
+            Column::Dictionary(s) => s.take_materialized_series(),
+            #[cfg(feature = "compress_in_memory")]
+            Column::Packed(s) => s.take_materialized_series(),
         }
     }
 
@@ -193,6 +277,10 @@ impl Column {
         match self {
             Column::Series(s) => s.dtype(),
             Column::Scalar(s) => s.dtype(),
+            Column::RunEnd(s) => s.dtype(),
+            Column::Dictionary(s) => s.dtype(),
+            #[cfg(feature = "compress_in_memory")]
+            Column::Packed(s) => s.dtype(),
         }
     }
 
@@ -204,6 +292,19 @@ impl Column {
                 None => Cow::Owned(Field::new(s.name().clone(), s.dtype().clone())),
                 Some(s) => s.field(),
             },
+            Column::RunEnd(s) => match s.lazy_as_materialized_series() {
+                None => Cow::Owned(Field::new(s.name().clone(), s.dtype().clone())),
+                Some(s) => s.field(),
+            },
+            Column::Dictionary(s) => match s.lazy_as_materialized_series() {
+                None => Cow::Owned(Field::new(s.name().clone(), s.dtype().clone())),
+                Some(s) => s.field(),
+            },
+            #[cfg(feature = "compress_in_memory")]
+            Column::Packed(s) => match s.lazy_as_materialized_series() {
+                None => Cow::Owned(Field::new(s.name().clone(), s.dtype().clone())),
+                Some(s) => s.field(),
+            },
         }
     }
 
@@ -212,6 +313,10 @@ impl Column {
         match self {
             Column::Series(s) => s.name(),
             Column::Scalar(s) => s.name(),
+            Column::RunEnd(s) => s.name(),
+            Column::Dictionary(s) => s.name(),
+            #[cfg(feature = "compress_in_memory")]
+            Column::Packed(s) => s.name(),
         }
     }
 
@@ -220,6 +325,10 @@ impl Column {
         match self {
             Column::Series(s) => s.len(),
             Column::Scalar(s) => s.len(),
+            Column::RunEnd(s) => s.len(),
+            Column::Dictionary(s) => s.len(),
+            #[cfg(feature = "compress_in_memory")]
+            Column::Packed(s) => s.len(),
         }
     }
 
@@ -234,6 +343,10 @@ impl Column {
         match self {
             Column::Series(s) => _ = s.rename(name),
             Column::Scalar(s) => _ = s.rename(name),
+            Column::RunEnd(s) => _ = s.rename(name),
+            Column::Dictionary(s) => _ = s.rename(name),
+            #[cfg(feature = "compress_in_memory")]
+            Column::Packed(s) => _ = s.rename(name),
         }
     }
 
@@ -259,6 +372,28 @@ impl Column {
             _ => None,
         }
     }
+    #[inline]
+    pub fn as_run_end_column(&self) -> Option<&RunEndColumn> {
+        match self {
+            Column::RunEnd(s) => Some(s),
+            _ => None,
+        }
+    }
+    #[inline]
+    pub fn as_dictionary_column(&self) -> Option<&DictionaryColumn> {
+        match self {
+            Column::Dictionary(s) => Some(s),
+            _ => None,
+        }
+    }
+    #[inline]
+    #[cfg(feature = "compress_in_memory")]
+    pub fn as_packed_column(&self) -> Option<&PackedColumn> {
+        match self {
+            Column::Packed(s) => Some(s),
+            _ => None,
+        }
+    }
 
     // # Try to Chunked Arrays
     pub fn try_bool(&self) -> Option<&BooleanChunked> {
@@ -435,6 +570,14 @@ impl Column {
     pub fn array(&self) -> PolarsResult<&ArrayChunked> {
         self.as_materialized_series().array()
     }
+    #[cfg(feature = "dtype-map")]
+    pub fn map(&self) -> PolarsResult<&MapChunked> {
+        self.as_materialized_series().map()
+    }
+    #[cfg(feature = "dtype-variant")]
+    pub fn variant(&self) -> PolarsResult<&VariantChunked> {
+        self.as_materialized_series().variant()
+    }
     #[cfg(feature = "dtype-categorical")]
     pub fn cat<T: PolarsCategoricalType>(&self) -> PolarsResult<&CategoricalChunked<T>> {
         self.as_materialized_series().cat::<T>()
@@ -465,18 +608,42 @@ impl Column {
         match self {
             Column::Series(s) => s.cast_with_options(dtype, options).map(Column::from),
             Column::Scalar(s) => s.cast_with_options(dtype, options).map(Column::from),
+            Column::RunEnd(s) => s.cast_with_options(dtype, options).map(Column::from),
+            Column::Dictionary(s) => s.cast_with_options(dtype, options).map(Column::from),
+            #[cfg(feature = "compress_in_memory")]
+            Column::Packed(s) => s.cast_with_options(dtype, options).map(Column::from),
         }
     }
     pub fn strict_cast(&self, dtype: &DataType) -> PolarsResult<Self> {
         match self {
             Column::Series(s) => s.strict_cast(dtype).map(Column::from),
             Column::Scalar(s) => s.strict_cast(dtype).map(Column::from),
+            Column::RunEnd(s) => s
+                .cast_with_options(dtype, CastOptions::Strict)
+                .map(Column::from),
+            Column::Dictionary(s) => s
+                .cast_with_options(dtype, CastOptions::Strict)
+                .map(Column::from),
+            #[cfg(feature = "compress_in_memory")]
+            Column::Packed(s) => s
+                .cast_with_options(dtype, CastOptions::Strict)
+                .map(Column::from),
         }
     }
     pub fn cast(&self, dtype: &DataType) -> PolarsResult<Column> {
         match self {
             Column::Series(s) => s.cast(dtype).map(Column::from),
             Column::Scalar(s) => s.cast(dtype).map(Column::from),
+            Column::RunEnd(s) => s
+                .cast_with_options(dtype, CastOptions::NonStrict)
+                .map(Column::from),
+            Column::Dictionary(s) => s
+                .cast_with_options(dtype, CastOptions::NonStrict)
+                .map(Column::from),
+            #[cfg(feature = "compress_in_memory")]
+            Column::Packed(s) => s
+                .cast_with_options(dtype, CastOptions::NonStrict)
+                .map(Column::from),
         }
     }
     /// # Safety
@@ -486,6 +653,10 @@ impl Column {
         match self {
             Column::Series(s) => unsafe { s.cast_unchecked(dtype) }.map(Column::from),
             Column::Scalar(s) => unsafe { s.cast_unchecked(dtype) }.map(Column::from),
+            Column::RunEnd(s) => unsafe { s.cast_unchecked(dtype) }.map(Column::from),
+            Column::Dictionary(s) => unsafe { s.cast_unchecked(dtype) }.map(Column::from),
+            #[cfg(feature = "compress_in_memory")]
+            Column::Packed(s) => unsafe { s.cast_unchecked(dtype) }.map(Column::from),
         }
     }
 
@@ -494,6 +665,12 @@ impl Column {
         match self {
             Column::Series(s) => s.clear().into(),
             Column::Scalar(s) => s.resize(0).into(),
+            Column::RunEnd(s) => RunEndColumn::new_empty(s.name().clone(), s.dtype()).into(),
+            Column::Dictionary(s) => {
+                DictionaryColumn::new_empty(s.name().clone(), s.dtype()).into()
+            },
+            #[cfg(feature = "compress_in_memory")]
+            Column::Packed(s) => s.slice(0, 0).into(),
         }
     }
 
@@ -501,7 +678,9 @@ impl Column {
     pub fn shrink_to_fit(&mut self) {
         match self {
             Column::Series(s) => s.shrink_to_fit(),
-            Column::Scalar(_) => {},
+            Column::Scalar(_) | Column::RunEnd(_) | Column::Dictionary(_) => {},
+            #[cfg(feature = "compress_in_memory")]
+            Column::Packed(_) => {},
         }
     }
 
@@ -512,13 +691,13 @@ impl Column {
         }
 
         match self {
-            Column::Series(s) => {
+            Column::Scalar(s) => s.resize(length).into(),
+            _ => {
                 // SAFETY: Bounds check done before.
-                let av = unsafe { s.get_unchecked(index) };
+                let av = unsafe { self.get_unchecked(index) };
                 let scalar = Scalar::new(self.dtype().clone(), av.into_static());
                 Self::new_scalar(self.name().clone(), scalar, length)
             },
-            Column::Scalar(s) => s.resize(length).into(),
         }
     }
 
@@ -527,6 +706,10 @@ impl Column {
         match self {
             Self::Series(s) => s.has_nulls(),
             Self::Scalar(s) => s.has_nulls(),
+            Self::RunEnd(s) => s.has_nulls(),
+            Self::Dictionary(s) => s.has_nulls(),
+            #[cfg(feature = "compress_in_memory")]
+            Self::Packed(s) => s.has_nulls(),
         }
     }
 
@@ -537,6 +720,10 @@ impl Column {
             Self::Scalar(s) => {
                 BooleanChunked::full(s.name().clone(), s.scalar().is_null(), s.len())
             },
+            Self::RunEnd(s) => s.expand_bool(&s.values().is_null()),
+            Self::Dictionary(s) => s.map_values_to_bool(|values| Ok(values.is_null())).unwrap(),
+            #[cfg(feature = "compress_in_memory")]
+            Self::Packed(s) => !s.is_not_null(),
         }
     }
     #[inline]
@@ -546,6 +733,12 @@ impl Column {
             Self::Scalar(s) => {
                 BooleanChunked::full(s.name().clone(), !s.scalar().is_null(), s.len())
             },
+            Self::RunEnd(s) => s.expand_bool(&s.values().is_not_null()),
+            Self::Dictionary(s) => s
+                .map_values_to_bool(|values| Ok(values.is_not_null()))
+                .unwrap(),
+            #[cfg(feature = "compress_in_memory")]
+            Self::Packed(s) => s.is_not_null(),
         }
     }
 
@@ -584,6 +777,10 @@ impl Column {
                 let (_, length) = slice_offsets(offset, length, s.len());
                 s.resize(length).into()
             },
+            Column::RunEnd(s) => s.slice(offset, length).into(),
+            Column::Dictionary(s) => s.slice(offset, length).into(),
+            #[cfg(feature = "compress_in_memory")]
+            Column::Packed(s) => s.slice(offset, length).into(),
         }
     }
 
@@ -599,6 +796,10 @@ impl Column {
             Self::Series(s) => s.null_count(),
             Self::Scalar(s) if s.scalar().is_null() => s.len(),
             Self::Scalar(_) => 0,
+            Self::RunEnd(s) => s.null_count(),
+            Self::Dictionary(s) => s.null_count(),
+            #[cfg(feature = "compress_in_memory")]
+            Self::Packed(s) => s.null_count(),
         }
     }
 
@@ -647,6 +848,10 @@ impl Column {
                         .into_column()
                 }
             },
+            Self::RunEnd(s) => unsafe { s.take_iter_unchecked(indices.iter()) }.into(),
+            Self::Dictionary(s) => unsafe { s.take_unchecked(indices) }.into(),
+            #[cfg(feature = "compress_in_memory")]
+            Self::Packed(s) => unsafe { s.materialize().take_unchecked(indices) }.into(),
         }
     }
     /// # Safety
@@ -663,6 +868,12 @@ impl Column {
                 indices.len(),
             )
             .into(),
+            Self::RunEnd(s) => {
+                unsafe { s.take_iter_unchecked(indices.iter().map(|&idx| Some(idx))) }.into()
+            },
+            Self::Dictionary(s) => unsafe { s.take_slice_unchecked(indices) }.into(),
+            #[cfg(feature = "compress_in_memory")]
+            Self::Packed(s) => unsafe { s.materialize().take_slice_unchecked(indices) }.into(),
         }
     }
 
@@ -675,7 +886,11 @@ impl Column {
         series_agg: impl Fn(&Series, &GroupsType) -> Series,
     ) -> Column {
         match self {
-            Column::Series(s) => series_agg(s, groups).into_column(),
+            Column::Series(_) | Column::RunEnd(_) | Column::Dictionary(_) => {
+                series_agg(self.as_materialized_series(), groups).into_column()
+            },
+            #[cfg(feature = "compress_in_memory")]
+            Column::Packed(s) => series_agg(&s.materialize(), groups).into_column(),
             Column::Scalar(s) => {
                 if s.is_empty() {
                     return series_agg(s.as_materialized_series(), groups).into_column();
@@ -784,6 +999,14 @@ impl Column {
         unsafe { self.as_materialized_series().agg_sum(groups) }.into()
     }
 
+    /// # Safety
+    ///
+    /// Does no bounds checks, groups must be correct.
+    #[cfg(feature = "algorithm_group_by")]
+    pub unsafe fn try_agg_sum(&self, groups: &GroupsType) -> PolarsResult<Self> {
+        unsafe { self.as_materialized_series().try_agg_sum(groups) }.map(Column::from)
+    }
+
     /// # Safety
     ///
     /// Does no bounds checks, groups must be correct.
@@ -923,6 +1146,10 @@ impl Column {
         match self {
             Column::Series(s) => s.reverse().into(),
             Column::Scalar(_) => self.clone(),
+            Column::RunEnd(s) => s.reverse().into(),
+            Column::Dictionary(s) => s.reverse().into(),
+            #[cfg(feature = "compress_in_memory")]
+            Column::Packed(s) => s.materialize().reverse().into(),
         }
     }
 
@@ -942,7 +1169,10 @@ impl Column {
         // @scalar-opt
         match self {
             Column::Series(s) => s.set_sorted_flag(sorted),
-            Column::Scalar(_) => {},
+            Column::RunEnd(s) => s.set_sorted_flag(sorted),
+            Column::Scalar(_) | Column::Dictionary(_) => {},
+            #[cfg(feature = "compress_in_memory")]
+            Column::Packed(s) => s.set_sorted_flag(sorted),
         }
     }
 
@@ -952,6 +1182,15 @@ impl Column {
             Column::Scalar(_) => {
                 StatisticsFlags::IS_SORTED_ASC | StatisticsFlags::CAN_FAST_EXPLODE_LIST
             },
+            // Repeating values keeps their sortedness.
+            Column::RunEnd(s) => s.values().get_flags(),
+            Column::Dictionary(_) => StatisticsFlags::empty(),
+            #[cfg(feature = "compress_in_memory")]
+            Column::Packed(s) => {
+                let mut flags = StatisticsFlags::empty();
+                flags.set_sorted(s.is_sorted_flag());
+                flags
+            },
         }
     }
 
@@ -962,7 +1201,9 @@ impl Column {
                 s.set_flags(flags);
                 true
             },
-            Column::Scalar(_) => false,
+            Column::Scalar(_) | Column::RunEnd(_) | Column::Dictionary(_) => false,
+            #[cfg(feature = "compress_in_memory")]
+            Column::Packed(_) => false,
         }
     }
 
@@ -1190,6 +1431,10 @@ impl Column {
                     self.clone()
                 }
             },
+            Column::RunEnd(s) => s.with_values(s.values().rechunk()).into(),
+            Column::Dictionary(s) => s.with_values(s.values().rechunk()).into(),
+            #[cfg(feature = "compress_in_memory")]
+            Column::Packed(_) => self.clone(),
         }
     }
 
@@ -1245,6 +1490,10 @@ impl Column {
         match self {
             Column::Series(s) => s.drop_nulls().into_column(),
             Column::Scalar(s) => s.drop_nulls().into_column(),
+            Column::RunEnd(s) => s.drop_nulls().into_column(),
+            Column::Dictionary(s) => s.drop_nulls().into_column(),
+            #[cfg(feature = "compress_in_memory")]
+            Column::Packed(s) => s.filter(&s.is_not_null()).unwrap().into_column(),
         }
     }
 
@@ -1258,6 +1507,10 @@ impl Column {
         match self {
             Column::Series(s) => s.is_sorted_flag(),
             Column::Scalar(_) => IsSorted::Ascending,
+            Column::RunEnd(s) => s.values().is_sorted_flag(),
+            Column::Dictionary(_) => IsSorted::Not,
+            #[cfg(feature = "compress_in_memory")]
+            Column::Packed(s) => s.is_sorted_flag(),
         }
     }
 
@@ -1272,6 +1525,10 @@ impl Column {
 
                 Ok(s.resize(1).into_column())
             },
+            Column::RunEnd(s) => s.values().unique().map(Column::from),
+            Column::Dictionary(s) => s.as_materialized_series().unique().map(Column::from),
+            #[cfg(feature = "compress_in_memory")]
+            Column::Packed(s) => s.materialize().unique().map(Column::from),
         }
     }
     pub fn unique_stable(&self) -> PolarsResult<Column> {
@@ -1285,6 +1542,10 @@ impl Column {
 
                 Ok(s.resize(1).into_column())
             },
+            Column::RunEnd(s) => s.values().unique_stable().map(Column::from),
+            Column::Dictionary(s) => s.as_materialized_series().unique_stable().map(Column::from),
+            #[cfg(feature = "compress_in_memory")]
+            Column::Packed(s) => s.materialize().unique_stable().map(Column::from),
         }
     }
 
@@ -1328,6 +1589,40 @@ impl Column {
 
                 Ok(s.resize(filter.sum().unwrap() as usize).into_column())
             },
+            Column::RunEnd(s) => {
+                // Broadcasting
+                if filter.len() == 1 && s.len() != 1 {
+                    return match filter.get(0) {
+                        Some(true) => Ok(self.clone()),
+                        _ => Ok(self.clear()),
+                    };
+                }
+
+                s.filter(filter).map(Column::from)
+            },
+            Column::Dictionary(s) => {
+                // Broadcasting
+                if filter.len() == 1 && s.len() != 1 {
+                    return match filter.get(0) {
+                        Some(true) => Ok(self.clone()),
+                        _ => Ok(self.clear()),
+                    };
+                }
+
+                s.filter(filter).map(Column::from)
+            },
+            #[cfg(feature = "compress_in_memory")]
+            Column::Packed(s) => {
+                // Broadcasting
+                if filter.len() == 1 && s.len() != 1 {
+                    return match filter.get(0) {
+                        Some(true) => Ok(self.clone()),
+                        _ => Ok(self.clear()),
+                    };
+                }
+
+                s.filter(filter).map(Column::from)
+            },
         }
     }
 
@@ -1375,6 +1670,21 @@ impl Column {
                 let total = s.len() - offset;
                 Ok(s.resize(1 + (total - 1) / n).into())
             },
+            Column::RunEnd(s) => {
+                let indices = (offset..s.len()).step_by(n).map(|i| Some(i as IdxSize));
+                // SAFETY: The indices are in-bounds.
+                Ok(unsafe { s.take_iter_unchecked(indices) }.into())
+            },
+            Column::Dictionary(s) => {
+                let indices = (offset..s.len())
+                    .step_by(n)
+                    .map(|i| i as IdxSize)
+                    .collect::<Vec<_>>();
+                // SAFETY: The indices are in-bounds.
+                Ok(unsafe { s.take_slice_unchecked(&indices) }.into())
+            },
+            #[cfg(feature = "compress_in_memory")]
+            Column::Packed(s) => Ok(s.materialize().gather_every(n, offset)?.into()),
         }
     }
 
@@ -1398,6 +1708,13 @@ impl Column {
                         .map(Column::from)
                 }
             },
+            Column::RunEnd(s) => s.extend_constant(value, n).map(Column::from),
+            Column::Dictionary(s) => s
+                .as_materialized_series()
+                .extend_constant(value, n)
+                .map(Column::from),
+            #[cfg(feature = "compress_in_memory")]
+            Column::Packed(s) => s.materialize().extend_constant(value, n).map(Column::from),
         }
     }
 
@@ -1451,6 +1768,10 @@ impl Column {
         match self {
             Column::Series(s) => unsafe { s.get_unchecked(index) },
             Column::Scalar(s) => s.scalar().as_any_value(),
+            Column::RunEnd(s) => unsafe { s.get_unchecked(index) },
+            Column::Dictionary(s) => unsafe { s.get_unchecked(index) },
+            #[cfg(feature = "compress_in_memory")]
+            Column::Packed(s) => unsafe { s.get_unchecked(index) },
         }
     }
 
@@ -1498,7 +1819,19 @@ impl Column {
     }
 
     pub(crate) fn str_value(&self, index: usize) -> PolarsResult<Cow<'_, str>> {
-        Ok(self.get(index)?.str_value())
+        let av = self.get(index)?;
+        #[cfg(feature = "dtype-variant")]
+        if let (DataType::Variant, AnyValue::Binary(v)) = (self.dtype(), &av) {
+            let v = polars_compute::variant::VariantRef::try_new(v)?;
+            return Ok(Cow::Owned(v.to_json()?));
+        }
+        #[cfg(feature = "dtype-extension")]
+        if let DataType::Extension(typ, _) = self.dtype()
+            && let Some(s) = typ.format_value(&av)
+        {
+            return Ok(Cow::Owned(s));
+        }
+        Ok(av.str_value())
     }
 
     pub fn min_reduce(&self) -> PolarsResult<Scalar> {
@@ -1509,6 +1842,11 @@ impl Column {
                 // cast to a single value series. This is a tiny bit wasteful, but probably fine.
                 s.as_single_value_series().min_reduce()
             },
+            // Every value occurs at least once, so the repetitions don't matter.
+            Column::RunEnd(s) => s.values().min_reduce(),
+            Column::Dictionary(s) => s.as_materialized_series().min_reduce(),
+            #[cfg(feature = "compress_in_memory")]
+            Column::Packed(s) => s.materialize().min_reduce(),
         }
     }
     pub fn max_reduce(&self) -> PolarsResult<Scalar> {
@@ -1519,6 +1857,11 @@ impl Column {
                 // cast to a single value series. This is a tiny bit wasteful, but probably fine.
                 s.as_single_value_series().max_reduce()
             },
+            // Every value occurs at least once, so the repetitions don't matter.
+            Column::RunEnd(s) => s.values().max_reduce(),
+            Column::Dictionary(s) => s.as_materialized_series().max_reduce(),
+            #[cfg(feature = "compress_in_memory")]
+            Column::Packed(s) => s.materialize().max_reduce(),
         }
     }
     pub fn median_reduce(&self) -> PolarsResult<Scalar> {
@@ -1529,6 +1872,10 @@ impl Column {
                 // cast to a single value series. This is a tiny bit wasteful, but probably fine.
                 s.as_single_value_series().median_reduce()
             },
+            Column::RunEnd(s) => s.as_materialized_series().median_reduce(),
+            Column::Dictionary(s) => s.as_materialized_series().median_reduce(),
+            #[cfg(feature = "compress_in_memory")]
+            Column::Packed(s) => s.materialize().median_reduce(),
         }
     }
     pub fn mean_reduce(&self) -> PolarsResult<Scalar> {
@@ -1539,6 +1886,13 @@ impl Column {
                 // cast to a single value series. This is a tiny bit wasteful, but probably fine.
                 s.as_single_value_series().mean_reduce()
             },
+            Column::RunEnd(s) => match s.mean_reduce()? {
+                Some(mean) => Ok(mean),
+                None => s.as_materialized_series().mean_reduce(),
+            },
+            Column::Dictionary(s) => s.as_materialized_series().mean_reduce(),
+            #[cfg(feature = "compress_in_memory")]
+            Column::Packed(s) => s.materialize().mean_reduce(),
         }
     }
     pub fn std_reduce(&self, ddof: u8) -> PolarsResult<Scalar> {
@@ -1550,6 +1904,10 @@ impl Column {
                 let n = s.len().min(ddof as usize + 1);
                 s.as_n_values_series(n).std_reduce(ddof)
             },
+            Column::RunEnd(s) => s.as_materialized_series().std_reduce(ddof),
+            Column::Dictionary(s) => s.as_materialized_series().std_reduce(ddof),
+            #[cfg(feature = "compress_in_memory")]
+            Column::Packed(s) => s.materialize().std_reduce(ddof),
         }
     }
     pub fn var_reduce(&self, ddof: u8) -> PolarsResult<Scalar> {
@@ -1561,9 +1919,25 @@ impl Column {
                 let n = s.len().min(ddof as usize + 1);
                 s.as_n_values_series(n).var_reduce(ddof)
             },
+            Column::RunEnd(s) => s.as_materialized_series().var_reduce(ddof),
+            Column::Dictionary(s) => s.as_materialized_series().var_reduce(ddof),
+            #[cfg(feature = "compress_in_memory")]
+            Column::Packed(s) => s.materialize().var_reduce(ddof),
         }
     }
     pub fn sum_reduce(&self) -> PolarsResult<Scalar> {
+        if let Column::RunEnd(s) = self
+            && let Some(sum) = s.sum_reduce()?
+        {
+            return Ok(sum);
+        }
+        #[cfg(feature = "compress_in_memory")]
+        if let Column::Packed(s) = self
+            && let Some(sum) = s.sum_reduce()?
+        {
+            return Ok(sum);
+        }
+
         // @scalar-opt
         self.as_materialized_series().sum_reduce()
     }
@@ -1575,6 +1949,11 @@ impl Column {
                 // cast to a single value series. This is a tiny bit wasteful, but probably fine.
                 s.as_single_value_series().and_reduce()
             },
+            // Every value occurs at least once, so the repetitions don't matter.
+            Column::RunEnd(s) => s.values().and_reduce(),
+            Column::Dictionary(s) => s.as_materialized_series().and_reduce(),
+            #[cfg(feature = "compress_in_memory")]
+            Column::Packed(s) => s.materialize().and_reduce(),
         }
     }
     pub fn or_reduce(&self) -> PolarsResult<Scalar> {
@@ -1585,6 +1964,11 @@ impl Column {
                 // cast to a single value series. This is a tiny bit wasteful, but probably fine.
                 s.as_single_value_series().or_reduce()
             },
+            // Every value occurs at least once, so the repetitions don't matter.
+            Column::RunEnd(s) => s.values().or_reduce(),
+            Column::Dictionary(s) => s.as_materialized_series().or_reduce(),
+            #[cfg(feature = "compress_in_memory")]
+            Column::Packed(s) => s.materialize().or_reduce(),
         }
     }
     pub fn xor_reduce(&self) -> PolarsResult<Scalar> {
@@ -1599,12 +1983,20 @@ impl Column {
                 // elements the result should be `null`.
                 s.as_n_values_series(2 - s.len() % 2).xor_reduce()
             },
+            Column::RunEnd(s) => s.as_materialized_series().xor_reduce(),
+            Column::Dictionary(s) => s.as_materialized_series().xor_reduce(),
+            #[cfg(feature = "compress_in_memory")]
+            Column::Packed(s) => s.materialize().xor_reduce(),
         }
     }
     pub fn n_unique(&self) -> PolarsResult<usize> {
         match self {
             Column::Series(s) => s.n_unique(),
             Column::Scalar(s) => s.as_single_value_series().n_unique(),
+            Column::RunEnd(s) => s.values().n_unique(),
+            Column::Dictionary(s) => s.as_materialized_series().n_unique(),
+            #[cfg(feature = "compress_in_memory")]
+            Column::Packed(s) => s.materialize().n_unique(),
         }
     }
 
@@ -1623,6 +2015,14 @@ impl Column {
     }
 
     pub(crate) fn estimated_size(&self) -> usize {
+        #[cfg(feature = "compress_in_memory")]
+        if let Column::Packed(s) = self {
+            let materialized_size = s
+                .lazy_as_materialized_series()
+                .map_or(0, |s| s.estimated_size());
+            return s.packed_size() + materialized_size;
+        }
+
         // @scalar-opt
         self.as_materialized_series().estimated_size()
     }
@@ -1636,6 +2036,16 @@ impl Column {
 
                 Ok(self.clone())
             },
+            Column::RunEnd(s) => s
+                .as_materialized_series()
+                .sort_with(options)
+                .map(Self::from),
+            Column::Dictionary(s) => s
+                .as_materialized_series()
+                .sort_with(options)
+                .map(Self::from),
+            #[cfg(feature = "compress_in_memory")]
+            Column::Packed(s) => s.materialize().sort_with(options).map(Self::from),
         }
     }
 
@@ -1653,6 +2063,13 @@ impl Column {
         match self {
             Column::Series(s) => f(s),
             Column::Scalar(s) => Ok(f(&s.as_single_value_series())?.new_from_index(0, s.len())),
+            Column::RunEnd(s) => Ok(s.expand_bool(&f(s.values())?)),
+            Column::Dictionary(s) => s.map_values_to_bool(f),
+            #[cfg(feature = "compress_in_memory")]
+            Column::Packed(s) => match s.map_values_to_bool(&f)? {
+                Some(out) => Ok(out),
+                None => f(s.as_materialized_series()),
+            },
         }
     }
 
@@ -1670,6 +2087,10 @@ impl Column {
                 s.len(),
             )
             .into()),
+            Column::RunEnd(s) => Ok(s.with_values(f(s.values())?).into()),
+            Column::Dictionary(s) => Ok(s.with_values(f(s.values())?).into()),
+            #[cfg(feature = "compress_in_memory")]
+            Column::Packed(s) => f(&s.materialize()).map(Column::from),
         }
     }
 
@@ -1714,6 +2135,25 @@ impl Column {
 
                 Ok(ScalarColumn::from_single_value_series(op(&lhs, &rhs)?, length).into_column())
             },
+            (Column::RunEnd(lhs), Column::Scalar(rhs)) if lhs.len() == length => {
+                let values = op(lhs.values(), &rhs.as_single_value_series())?;
+                Ok(lhs.with_values(values).into_column())
+            },
+            (Column::Scalar(lhs), Column::RunEnd(rhs)) if rhs.len() == length => {
+                let values = op(&lhs.as_single_value_series(), rhs.values())?;
+                Ok(rhs.with_values(values).into_column())
+            },
+            (Column::Dictionary(lhs), Column::Scalar(rhs)) if lhs.len() == length => {
+                let values = op(lhs.values(), &rhs.as_single_value_series())?;
+                Ok(lhs.with_values(values).into_column())
+            },
+            (Column::Scalar(lhs), Column::Dictionary(rhs)) if rhs.len() == length => {
+                let values = op(&lhs.as_single_value_series(), rhs.values())?;
+                Ok(rhs.with_values(values).into_column())
+            },
+            (lhs, rhs) => {
+                op(lhs.as_materialized_series(), rhs.as_materialized_series()).map(Column::from)
+            },
         }
     }
 
@@ -1754,6 +2194,21 @@ impl Column {
                         .into_column(),
                 )
             },
+            (Column::RunEnd(lhs), Column::Scalar(rhs)) => {
+                Ok(lhs.with_values(f_rb(lhs.values(), rhs.scalar())?).into())
+            },
+            (Column::Scalar(lhs), Column::RunEnd(rhs)) => {
+                Ok(rhs.with_values(f_lb(lhs.scalar(), rhs.values())?).into())
+            },
+            (Column::Dictionary(lhs), Column::Scalar(rhs)) => {
+                Ok(lhs.with_values(f_rb(lhs.values(), rhs.scalar())?).into())
+            },
+            (Column::Scalar(lhs), Column::Dictionary(rhs)) => {
+                Ok(rhs.with_values(f_lb(lhs.scalar(), rhs.values())?).into())
+            },
+            (lhs, rhs) => {
+                f(lhs.as_materialized_series(), rhs.as_materialized_series()).map(Column::from)
+            },
         }
     }
 
@@ -1766,6 +2221,10 @@ impl Column {
                 s.as_single_value_series().approx_n_unique()?;
                 Ok(1)
             },
+            Column::RunEnd(s) => s.values().approx_n_unique(),
+            Column::Dictionary(s) => s.as_materialized_series().approx_n_unique(),
+            #[cfg(feature = "compress_in_memory")]
+            Column::Packed(s) => s.materialize().approx_n_unique(),
         }
     }
 
@@ -1773,6 +2232,10 @@ impl Column {
         match self {
             Column::Series(s) => s.n_chunks(),
             Column::Scalar(s) => s.lazy_as_materialized_series().map_or(1, |x| x.n_chunks()),
+            Column::RunEnd(s) => s.lazy_as_materialized_series().map_or(1, |x| x.n_chunks()),
+            Column::Dictionary(s) => s.lazy_as_materialized_series().map_or(1, |x| x.n_chunks()),
+            #[cfg(feature = "compress_in_memory")]
+            Column::Packed(s) => s.lazy_as_materialized_series().map_or(1, |x| x.n_chunks()),
         }
     }
 
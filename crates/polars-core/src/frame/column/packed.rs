@@ -0,0 +1,836 @@
+use std::borrow::Cow;
+use std::sync::OnceLock;
+
+use arrow::bitmap::{Bitmap, BitmapBuilder};
+use num_traits::{NumCast, ToPrimitive};
+use polars_buffer::Buffer;
+use polars_compute::filter::filter_boolean_kernel;
+use polars_error::{PolarsResult, polars_ensure};
+use polars_parquet::parquet::encoding::bitpacked::{Decoder, Unpackable, Unpacked, encode};
+use polars_utils::pl_str::PlSmallStr;
+
+use super::{AnyValue, Column, DataType, IntoColumn, Series};
+use crate::chunked_array::cast::CastOptions;
+use crate::prelude::*;
+use crate::series::IsSorted;
+use crate::utils::slice_offsets;
+use crate::with_match_physical_integer_polars_type;
+
+/// Frame of reference columns packed with more bits than this are not evaluated through a lookup
+/// of every value they can contain.
+const MAX_LOOKUP_BITS: usize = 16;
+
+/// How the packed integers map to the values of a [`PackedColumn`].
+#[derive(Debug, Clone)]
+enum Encoding {
+    /// Every row is packed as the offset from the minimum value.
+    FrameOfReference { reference: i128 },
+    /// Every row is packed as the index into the sorted, distinct physical values.
+    Dictionary { values: Series },
+}
+
+/// A [`Column`] of integers that is kept compressed in memory.
+///
+/// Every row is stored as a small unsigned integer that is bitpacked with the parquet bitpacking
+/// kernels. This is either the offset from the minimum value or the index into the distinct
+/// values, whichever is smaller. Filters, sums and comparisons with a scalar run on the packed
+/// integers, everything else decodes the column into a [`Series`].
+///
+/// Only integer columns are packed. Boolean columns are already stored as one bit per value, so
+/// packing them would not save memory.
+///
+/// The decoded [`Series`] is only cached when a reference to it is requested through
+/// [`PackedColumn::as_materialized_series`]. This is a deliberate trade-off: the [`Column`] API
+/// hands out references to a [`Series`], which have to live somewhere. The cache is dropped again
+/// by [`PackedColumn::clear_materialized`].
+#[derive(Debug, Clone)]
+pub struct PackedColumn {
+    name: PlSmallStr,
+    dtype: DataType,
+    len: usize,
+    encoding: Encoding,
+    num_bits: usize,
+    /// The bitpacked integer of every row. A null row is packed as `0`.
+    packed: Buffer<u8>,
+    validity: Option<Bitmap>,
+    /// The sortedness of the values, which is kept through slices and filters.
+    sorted: IsSorted,
+
+    // invariants:
+    // dtype.to_physical() is an integer type of at most 64 bits
+    // num_bits <= 64
+    // the packed integers are in-bounds for a dictionary encoding
+    // materialized.name() == name
+    // materialized.len() == len
+    // materialized.dtype() == dtype
+    /// A lazily materialized [`Series`] variant of this [`PackedColumn`]
+    materialized: OnceLock<Series>,
+}
+
+fn bits_needed(max: u64) -> usize {
+    (u64::BITS - max.leading_zeros()) as usize
+}
+
+fn pack(offsets: &[u64], num_bits: usize) -> Buffer<u8> {
+    fn pack_as<T: Unpackable>(unpacked: &[T], num_bits: usize) -> Buffer<u8> {
+        let num_packs = unpacked.len().div_ceil(T::Unpacked::LENGTH);
+        let mut packed = vec![0; num_packs * T::Unpacked::LENGTH * num_bits / 8];
+        encode(unpacked, num_bits, &mut packed);
+        packed.into()
+    }
+
+    if num_bits == 0 {
+        Buffer::default()
+    } else if num_bits <= 32 {
+        let offsets = offsets.iter().map(|&o| o as u32).collect::<Vec<_>>();
+        pack_as(&offsets, num_bits)
+    } else {
+        pack_as(offsets, num_bits)
+    }
+}
+
+impl PackedColumn {
+    /// Whether columns of `dtype` can be packed.
+    pub fn is_supported(dtype: &DataType) -> bool {
+        let physical = dtype.to_physical();
+        physical.is_integer()
+            && !matches!(physical, DataType::Int128 | DataType::UInt128)
+            && !dtype.is_categorical()
+            && !dtype.is_enum()
+    }
+
+    /// Pack `series`, if it is supported and that takes less memory than the dense values.
+    pub fn compress(series: &Series) -> PolarsResult<Option<Self>> {
+        if !Self::is_supported(series.dtype()) || series.is_empty() {
+            return Ok(None);
+        }
+
+        let packed = Self::encode(series)?;
+        Ok((packed.packed_size() < series.estimated_size()).then_some(packed))
+    }
+
+    /// Pack `series` with the encoding that needs the fewest bits.
+    pub fn encode(series: &Series) -> PolarsResult<Self> {
+        polars_ensure!(
+            Self::is_supported(series.dtype()),
+            InvalidOperation: "cannot pack a column of type {}", series.dtype()
+        );
+        let physical = series.to_physical_repr();
+        with_match_physical_integer_polars_type!(physical.dtype(), |$T| {
+            let ca = physical.unpack::<$T>()?;
+            let mut packed = Self::encode_impl(series.name().clone(), series.dtype().clone(), ca)?;
+            packed.sorted = series.is_sorted_flag();
+            Ok(packed)
+        })
+    }
+
+    fn encode_impl<T: PolarsIntegerType>(
+        name: PlSmallStr,
+        dtype: DataType,
+        ca: &ChunkedArray<T>,
+    ) -> PolarsResult<Self> {
+        let len = ca.len();
+        let native_bits = size_of::<T::Native>() * 8;
+        let validity = ca.rechunk_validity();
+
+        let min_max = ca.iter().flatten().fold(None, |acc, v| {
+            let v = v.to_i128().unwrap();
+            Some(acc.map_or((v, v), |(min, max): (i128, i128)| (min.min(v), max.max(v))))
+        });
+        let (reference, range_bits) = match min_max {
+            Some((min, max)) => (min, bits_needed((max - min) as u64)),
+            None => (0, 0),
+        };
+
+        let mut dictionary = None;
+        if range_bits > 0 {
+            let mut values = ca.iter().flatten().collect::<Vec<_>>();
+            values.sort_unstable_by(|a, b| a.tot_cmp(b));
+            values.dedup_by(|a, b| a.tot_eq(b));
+            let dictionary_bits = bits_needed(values.len() as u64 - 1);
+            if len * dictionary_bits + values.len() * native_bits < len * range_bits {
+                dictionary = Some((values, dictionary_bits));
+            }
+        }
+
+        let (encoding, num_bits, offsets) = match dictionary {
+            None => {
+                let offsets = ca
+                    .iter()
+                    .map(|v| v.map_or(0, |v| (v.to_i128().unwrap() - reference) as u64))
+                    .collect::<Vec<_>>();
+                let encoding = Encoding::FrameOfReference { reference };
+                (encoding, range_bits, offsets)
+            },
+            Some((values, num_bits)) => {
+                let offsets = ca
+                    .iter()
+                    .map(|v| {
+                        v.map_or(0, |v| {
+                            values.binary_search_by(|x| x.tot_cmp(&v)).unwrap() as u64
+                        })
+                    })
+                    .collect::<Vec<_>>();
+                let values = ChunkedArray::<T>::from_vec(PlSmallStr::EMPTY, values).into_series();
+                (Encoding::Dictionary { values }, num_bits, offsets)
+            },
+        };
+
+        Ok(Self {
+            name,
+            dtype,
+            len,
+            encoding,
+            num_bits,
+            packed: pack(&offsets, num_bits),
+            validity,
+            sorted: IsSorted::Not,
+
+            materialized: OnceLock::new(),
+        })
+    }
+
+    /// Create a column with the same encoding from new packed integers.
+    fn with_offsets(&self, offsets: &[u64], validity: Option<Bitmap>) -> Self {
+        debug_assert!(validity.as_ref().is_none_or(|v| v.len() == offsets.len()));
+        Self {
+            name: self.name.clone(),
+            dtype: self.dtype.clone(),
+            len: offsets.len(),
+            encoding: self.encoding.clone(),
+            num_bits: self.num_bits,
+            packed: pack(offsets, self.num_bits),
+            validity,
+            sorted: self.sorted,
+
+            materialized: OnceLock::new(),
+        }
+    }
+
+    pub fn name(&self) -> &PlSmallStr {
+        &self.name
+    }
+
+    pub fn dtype(&self) -> &DataType {
+        &self.dtype
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The number of bits every row is packed with.
+    pub fn num_bits(&self) -> usize {
+        self.num_bits
+    }
+
+    /// Whether the rows are packed as indices into the distinct values.
+    pub fn is_dictionary_encoded(&self) -> bool {
+        matches!(self.encoding, Encoding::Dictionary { .. })
+    }
+
+    /// The number of bytes that are used for the packed values, the dictionary and the validity.
+    pub fn packed_size(&self) -> usize {
+        let dictionary_size = match &self.encoding {
+            Encoding::FrameOfReference { .. } => 0,
+            Encoding::Dictionary { values } => values.estimated_size(),
+        };
+        let validity_size = self.validity.as_ref().map_or(0, |v| v.len().div_ceil(8));
+        self.packed.len() + dictionary_size + validity_size
+    }
+
+    /// Call `f` with the first row and the packed integers of consecutive rows in `start..end`.
+    fn for_each_chunk(&self, start: usize, end: usize, mut f: impl FnMut(usize, &[u64])) {
+        fn for_each_chunk_as<T: Unpackable + Into<u64>>(
+            packed: &[u8],
+            num_bits: usize,
+            len: usize,
+            start: usize,
+            end: usize,
+            f: &mut impl FnMut(usize, &[u64]),
+        ) {
+            let mut decoder = Decoder::<T>::new(packed, num_bits, len);
+            let first_pack = start / T::Unpacked::LENGTH;
+            decoder.skip_chunks(first_pack);
+
+            let mut row = first_pack * T::Unpacked::LENGTH;
+            let mut chunked = decoder.chunked();
+            let mut buffer = [0u64; 64];
+            while row < end {
+                let Some((unpacked, n)) = chunked.next_inexact() else {
+                    break;
+                };
+                for (out, v) in buffer.iter_mut().zip(&unpacked.as_ref()[..n]) {
+                    *out = (*v).into();
+                }
+                let lo = start.saturating_sub(row);
+                let hi = n.min(end - row);
+                f(row + lo, &buffer[lo..hi]);
+                row += n;
+            }
+        }
+
+        debug_assert!(start <= end && end <= self.len);
+        if start == end {
+            return;
+        }
+
+        if self.num_bits == 0 {
+            let zeros = [0u64; 64];
+            let mut row = start;
+            while row < end {
+                let n = (end - row).min(zeros.len());
+                f(row, &zeros[..n]);
+                row += n;
+            }
+        } else if self.num_bits <= 32 {
+            for_each_chunk_as::<u32>(&self.packed, self.num_bits, self.len, start, end, &mut f);
+        } else {
+            for_each_chunk_as::<u64>(&self.packed, self.num_bits, self.len, start, end, &mut f);
+        }
+    }
+
+    fn unpack(&self, start: usize, end: usize) -> Vec<u64> {
+        let mut offsets = Vec::with_capacity(end - start);
+        self.for_each_chunk(start, end, |_, chunk| offsets.extend_from_slice(chunk));
+        offsets
+    }
+
+    /// Decompress the rows in `start..end`.
+    fn decode(&self, start: usize, end: usize) -> Series {
+        let physical = self.dtype.to_physical();
+        let series = with_match_physical_integer_polars_type!(physical, |$T| {
+            self.decode_impl::<$T>(start, end).into_series()
+        });
+        // SAFETY: The packed values were created from this data type.
+        unsafe { series.from_physical_unchecked(&self.dtype) }.unwrap()
+    }
+
+    fn decode_impl<T: PolarsIntegerType>(&self, start: usize, end: usize) -> ChunkedArray<T> {
+        let mut values = Vec::with_capacity(end - start);
+        match &self.encoding {
+            Encoding::FrameOfReference { reference } => {
+                self.for_each_chunk(start, end, |_, chunk| {
+                    values.extend(
+                        chunk
+                            .iter()
+                            .map(|&o| <T::Native as NumCast>::from(reference + o as i128).unwrap()),
+                    )
+                });
+            },
+            Encoding::Dictionary { values: dictionary } => {
+                let dictionary = dictionary.unpack::<T>().unwrap().cont_slice().unwrap();
+                self.for_each_chunk(start, end, |_, chunk| {
+                    values.extend(chunk.iter().map(|&o| dictionary[o as usize]))
+                });
+            },
+        }
+
+        let validity = self
+            .validity
+            .as_ref()
+            .map(|v| v.clone().sliced(start, end - start));
+        ChunkedArray::from_vec_validity(self.name.clone(), values, validity)
+    }
+
+    /// The values that the packed integers map to, or `None` if there are too many.
+    fn lookup_values(&self) -> Option<Series> {
+        let physical = match &self.encoding {
+            Encoding::Dictionary { values } => values.clone(),
+            Encoding::FrameOfReference { .. } if self.num_bits > MAX_LOOKUP_BITS => return None,
+            Encoding::FrameOfReference { reference } => {
+                let physical = self.dtype.to_physical();
+                with_match_physical_integer_polars_type!(physical, |$T| {
+                    // Offsets that overflow the data type are never packed.
+                    let values = (0..1u64 << self.num_bits)
+                        .map_while(|o| {
+                            <<$T as PolarsNumericType>::Native as NumCast>::from(
+                                reference + o as i128,
+                            )
+                        })
+                        .collect::<Vec<_>>();
+                    ChunkedArray::<$T>::from_vec(PlSmallStr::EMPTY, values).into_series()
+                })
+            },
+        };
+        // SAFETY: The values were created from this data type.
+        Some(unsafe { physical.from_physical_unchecked(&self.dtype) }.unwrap())
+    }
+
+    /// Evaluate an elementwise predicate on every value the packed integers map to and gather the
+    /// result for every row.
+    ///
+    /// Returns `None` if the packed integers map to too many values to do this.
+    pub fn map_values_to_bool(
+        &self,
+        f: impl FnOnce(&Series) -> PolarsResult<BooleanChunked>,
+    ) -> PolarsResult<Option<BooleanChunked>> {
+        let Some(values) = self.lookup_values() else {
+            return Ok(None);
+        };
+
+        // A trailing null value gives the result for null rows.
+        let num_values = values.len();
+        let values = values
+            .with_name(self.name.clone())
+            .extend_constant(AnyValue::Null, 1)?;
+        let per_value = f(&values)?;
+        polars_ensure!(
+            per_value.len() == num_values + 1,
+            ShapeMismatch: "expected an elementwise result of length {}, got {}",
+            num_values + 1, per_value.len()
+        );
+        let per_value = per_value.iter().collect::<Vec<_>>();
+        let null_result = per_value[num_values];
+
+        let mut out_values = BitmapBuilder::with_capacity(self.len);
+        let mut out_validity = BitmapBuilder::with_capacity(self.len);
+        self.for_each_chunk(0, self.len, |row, chunk| {
+            for (i, &o) in chunk.iter().enumerate() {
+                let is_valid = self
+                    .validity
+                    .as_ref()
+                    .is_none_or(|v| unsafe { v.get_bit_unchecked(row + i) });
+                let result = if is_valid {
+                    per_value[o as usize]
+                } else {
+                    null_result
+                };
+                out_values.push(result.unwrap_or(false));
+                out_validity.push(result.is_some());
+            }
+        });
+
+        let array = BooleanArray::new(
+            ArrowDataType::Boolean,
+            out_values.freeze(),
+            out_validity.into_opt_validity(),
+        );
+        Ok(Some(BooleanChunked::with_chunk(self.name.clone(), array)))
+    }
+
+    /// The sum of all values, computed on the packed integers.
+    ///
+    /// Returns `None` if this is not supported for the data type.
+    pub fn sum_reduce(&self) -> PolarsResult<Option<Scalar>> {
+        if !self.dtype.is_integer() {
+            return Ok(None);
+        }
+        let dtype = Series::new_empty(PlSmallStr::EMPTY, &self.dtype)
+            .sum_reduce()?
+            .dtype()
+            .clone();
+
+        let mut sum = 0i128;
+        match &self.encoding {
+            Encoding::FrameOfReference { reference } => {
+                // Null rows are packed as `0`, so they don't contribute to the sum of the offsets.
+                self.for_each_chunk(0, self.len, |_, chunk| {
+                    for &o in chunk {
+                        sum = sum.wrapping_add(o as i128);
+                    }
+                });
+                let num_valid = (self.len - self.null_count()) as i128;
+                sum = sum.wrapping_add(reference.wrapping_mul(num_valid));
+            },
+            Encoding::Dictionary { values } => {
+                let mut counts = vec![0u64; values.len()];
+                self.for_each_chunk(0, self.len, |row, chunk| {
+                    for (i, &o) in chunk.iter().enumerate() {
+                        let is_valid = self
+                            .validity
+                            .as_ref()
+                            .is_none_or(|v| unsafe { v.get_bit_unchecked(row + i) });
+                        counts[o as usize] += is_valid as u64;
+                    }
+                });
+                with_match_physical_integer_polars_type!(values.dtype(), |$T| {
+                    let values = values.unpack::<$T>().unwrap();
+                    for (value, count) in values.into_no_null_iter().zip(counts) {
+                        let value = value.to_i128().unwrap();
+                        sum = sum.wrapping_add(value.wrapping_mul(count as i128));
+                    }
+                });
+            },
+        }
+
+        // Truncating wraps the sum in the same way as summing the dense values.
+        let value = match &dtype {
+            DataType::Int32 => AnyValue::Int32(sum as i32),
+            DataType::Int64 => AnyValue::Int64(sum as i64),
+            DataType::UInt32 => AnyValue::UInt32(sum as u32),
+            DataType::UInt64 => AnyValue::UInt64(sum as u64),
+            _ => return Ok(None),
+        };
+        Ok(Some(Scalar::new(dtype, value)))
+    }
+
+    /// Materialize the [`PackedColumn`] into a [`Series`].
+    pub fn to_series(&self) -> Series {
+        let mut series = self.decode(0, self.len);
+        series.set_sorted_flag(self.sorted);
+        series
+    }
+
+    /// Get the [`PackedColumn`] as [`Series`] without caching it.
+    ///
+    /// This reuses the cached [`Series`] if there is one and decodes the values otherwise, so
+    /// that operations which do not need a reference keep only the packed integers in memory.
+    pub fn materialize(&self) -> Cow<'_, Series> {
+        match self.materialized.get() {
+            Some(series) => Cow::Borrowed(series),
+            None => Cow::Owned(self.to_series()),
+        }
+    }
+
+    /// Get the [`PackedColumn`] as [`Series`] if it was already materialized.
+    pub fn lazy_as_materialized_series(&self) -> Option<&Series> {
+        self.materialized.get()
+    }
+
+    /// Get the [`PackedColumn`] as [`Series`]
+    ///
+    /// This needs to materialize upon the first call. Afterwards, the dense values are cached next
+    /// to the packed integers until [`PackedColumn::clear_materialized`] is called, prefer
+    /// [`PackedColumn::materialize`] if a reference is not needed.
+    pub fn as_materialized_series(&self) -> &Series {
+        self.materialized.get_or_init(|| self.to_series())
+    }
+
+    /// Drop the cached [`Series`], so that only the packed integers are kept in memory.
+    pub fn clear_materialized(&mut self) {
+        self.materialized.take();
+    }
+
+    /// Take the [`PackedColumn`] and materialize as a [`Series`] if not already done.
+    pub fn take_materialized_series(mut self) -> Series {
+        self.materialized.take().unwrap_or_else(|| self.to_series())
+    }
+
+    pub fn rename(&mut self, name: PlSmallStr) -> &mut Self {
+        if let Some(series) = self.materialized.get_mut() {
+            series.rename(name.clone());
+        }
+
+        self.name = name;
+        self
+    }
+
+    pub fn is_sorted_flag(&self) -> IsSorted {
+        self.sorted
+    }
+
+    pub fn set_sorted_flag(&mut self, sorted: IsSorted) {
+        if let Some(series) = self.materialized.get_mut() {
+            series.set_sorted_flag(sorted);
+        }
+
+        self.sorted = sorted;
+    }
+
+    pub fn cast_with_options(
+        &self,
+        dtype: &DataType,
+        options: CastOptions,
+    ) -> PolarsResult<Series> {
+        self.materialize().cast_with_options(dtype, options)
+    }
+
+    /// # Safety
+    ///
+    /// This can lead to invalid memory access in downstream code.
+    pub unsafe fn cast_unchecked(&self, dtype: &DataType) -> PolarsResult<Series> {
+        unsafe { self.materialize().cast_unchecked(dtype) }
+    }
+
+    /// # Safety
+    ///
+    /// `index` has to be in-bounds.
+    pub unsafe fn get_unchecked(&self, index: usize) -> AnyValue<'_> {
+        if let Some(series) = self.materialized.get() {
+            return unsafe { series.get_unchecked(index) };
+        }
+        self.decode(index, index + 1).get(0).unwrap().into_static()
+    }
+
+    pub fn null_count(&self) -> usize {
+        self.validity.as_ref().map_or(0, |v| v.unset_bits())
+    }
+
+    pub fn has_nulls(&self) -> bool {
+        self.null_count() > 0
+    }
+
+    pub fn is_not_null(&self) -> BooleanChunked {
+        match &self.validity {
+            None => BooleanChunked::full(self.name.clone(), true, self.len),
+            Some(validity) => BooleanChunked::from_bitmap(self.name.clone(), validity.clone()),
+        }
+    }
+
+    pub fn slice(&self, offset: i64, length: usize) -> Self {
+        let (offset, length) = slice_offsets(offset, length, self.len);
+        let offsets = self.unpack(offset, offset + length);
+        let validity = self
+            .validity
+            .as_ref()
+            .map(|v| v.clone().sliced(offset, length));
+        self.with_offsets(&offsets, validity)
+    }
+
+    pub fn filter(&self, mask: &BooleanChunked) -> PolarsResult<Self> {
+        polars_ensure!(
+            mask.len() == self.len(),
+            ShapeMismatch: "filter's length: {} differs from that of the column: {}",
+            mask.len(), self.len()
+        );
+        let mask = mask.rechunk();
+        let mask = mask.downcast_as_array();
+        let mask = match mask.validity() {
+            None => mask.values().clone(),
+            Some(validity) => mask.values() & validity,
+        };
+
+        let mut offsets = Vec::with_capacity(mask.set_bits());
+        self.for_each_chunk(0, self.len, |row, chunk| {
+            for (i, &o) in chunk.iter().enumerate() {
+                if unsafe { mask.get_bit_unchecked(row + i) } {
+                    offsets.push(o);
+                }
+            }
+        });
+        let validity = self
+            .validity
+            .as_ref()
+            .map(|v| filter_boolean_kernel(v, &mask));
+        Ok(self.with_offsets(&offsets, validity))
+    }
+}
+
+impl IntoColumn for PackedColumn {
+    #[inline(always)]
+    fn into_column(self) -> Column {
+        self.into()
+    }
+}
+
+impl From<PackedColumn> for Column {
+    #[inline]
+    fn from(value: PackedColumn) -> Self {
+        Self::Packed(value)
+    }
+}
+
+#[cfg(feature = "dsl-schema")]
+impl schemars::JsonSchema for PackedColumn {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "PackedColumn".into()
+    }
+
+    fn schema_id() -> std::borrow::Cow<'static, str> {
+        std::borrow::Cow::Borrowed(concat!(module_path!(), "::", "PackedColumn"))
+    }
+
+    fn json_schema(generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        serde_impl::SerializeWrap::json_schema(generator)
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use polars_error::PolarsError;
+
+    use super::PackedColumn;
+    use crate::frame::Series;
+
+    /// The packed buffers are not part of the format, the values are packed again when they are
+    /// deserialized.
+    #[derive(serde::Serialize, serde::Deserialize)]
+    #[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+    pub struct SerializeWrap {
+        series: Series,
+    }
+
+    impl From<&PackedColumn> for SerializeWrap {
+        fn from(value: &PackedColumn) -> Self {
+            Self {
+                series: value.materialize().into_owned(),
+            }
+        }
+    }
+
+    impl TryFrom<SerializeWrap> for PackedColumn {
+        type Error = PolarsError;
+
+        fn try_from(value: SerializeWrap) -> Result<Self, Self::Error> {
+            PackedColumn::encode(&value.series)
+        }
+    }
+
+    impl serde::ser::Serialize for PackedColumn {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            SerializeWrap::from(self).serialize(serializer)
+        }
+    }
+
+    impl<'de> serde::de::Deserialize<'de> for PackedColumn {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            use serde::de::Error;
+
+            SerializeWrap::deserialize(deserializer)
+                .and_then(|x| PackedColumn::try_from(x).map_err(D::Error::custom))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_packed_frame_of_reference() -> PolarsResult<()> {
+        let values = (0..1000)
+            .map(|i| (i % 7 != 3).then_some(200 + (i * 13) % 50))
+            .collect::<Int32Chunked>();
+        let dense = values.with_name("a".into()).into_series();
+        let pc = PackedColumn::compress(&dense)?.unwrap();
+        assert!(!pc.is_dictionary_encoded());
+        assert_eq!(pc.num_bits(), 6);
+        assert!(pc.to_series().equals_missing(&dense));
+        assert_eq!(pc.null_count(), dense.null_count());
+        assert_eq!(pc.sum_reduce()?.unwrap(), dense.sum_reduce()?);
+
+        let mask = BooleanChunked::from_iter_values("".into(), (0..1000).map(|i| i % 3 == 0));
+        let filtered = pc.filter(&mask)?;
+        assert!(filtered.to_series().equals_missing(&dense.filter(&mask)?));
+        assert!(
+            pc.slice(70, 300)
+                .to_series()
+                .equals_missing(&dense.slice(70, 300))
+        );
+
+        let rhs = Series::new("".into(), [220]);
+        let lt = pc.map_values_to_bool(|values| values.lt(&rhs))?.unwrap();
+        assert_eq!(Vec::from(&lt), Vec::from(&dense.lt(&rhs)?));
+        let eq = pc
+            .map_values_to_bool(|values| values.equal_missing(&rhs))?
+            .unwrap();
+        assert_eq!(Vec::from(&eq), Vec::from(&dense.equal_missing(&rhs)?));
+        Ok(())
+    }
+
+    #[test]
+    fn test_packed_dictionary() -> PolarsResult<()> {
+        let codes = [200u64, 404, 1 << 40, 500];
+        let values = (0..300)
+            .map(|i| (i % 11 != 0).then_some(codes[i % codes.len()]))
+            .collect::<UInt64Chunked>();
+        let dense = values.with_name("status".into()).into_series();
+        let pc = PackedColumn::compress(&dense)?.unwrap();
+        assert!(pc.is_dictionary_encoded());
+        assert_eq!(pc.num_bits(), 2);
+        assert!(pc.to_series().equals_missing(&dense));
+        assert_eq!(pc.sum_reduce()?.unwrap(), dense.sum_reduce()?);
+
+        let rhs = Series::new("".into(), [404u64]);
+        let eq = pc.map_values_to_bool(|values| values.equal(&rhs))?.unwrap();
+        assert_eq!(Vec::from(&eq), Vec::from(&dense.equal(&rhs)?));
+        Ok(())
+    }
+
+    #[test]
+    fn test_packed_sorted_flag() -> PolarsResult<()> {
+        let mut dense = Series::new(
+            "a".into(),
+            (0..1000).map(|i| 100 + i / 4).collect::<Vec<i64>>(),
+        );
+        dense.set_sorted_flag(IsSorted::Ascending);
+        let column = Column::from(PackedColumn::compress(&dense)?.unwrap());
+        assert_eq!(column.is_sorted_flag(), IsSorted::Ascending);
+        assert!(column.get_flags().is_sorted_ascending());
+
+        let pc = column.as_packed_column().unwrap();
+        assert_eq!(pc.slice(10, 100).is_sorted_flag(), IsSorted::Ascending);
+        let mask = BooleanChunked::from_iter_values("".into(), (0..1000).map(|i| i % 3 == 0));
+        assert_eq!(pc.filter(&mask)?.is_sorted_flag(), IsSorted::Ascending);
+
+        // Operations that do not need a reference decode without caching.
+        assert_eq!(column.max_reduce()?.value(), &AnyValue::Int64(349));
+        assert!(pc.lazy_as_materialized_series().is_none());
+        assert_eq!(
+            column.as_materialized_series().is_sorted_flag(),
+            IsSorted::Ascending
+        );
+        assert!(pc.lazy_as_materialized_series().is_some());
+
+        let mut pc = pc.clone();
+        pc.clear_materialized();
+        assert!(pc.lazy_as_materialized_series().is_none());
+        assert_eq!(pc.is_sorted_flag(), IsSorted::Ascending);
+        Ok(())
+    }
+
+    #[test]
+    fn test_compress_in_memory() -> PolarsResult<()> {
+        let status = (0..1000)
+            .map(|i| [200i64, 404, 500][i % 3])
+            .collect::<Vec<_>>();
+        let flag = status.iter().map(|s| *s == 404).collect::<Vec<_>>();
+        let mut df = df!("status" => &status, "flag" => &flag)?;
+        let expected = df.clone();
+
+        df.compress_in_memory()?;
+        assert!(df.column("status")?.as_packed_column().is_some());
+        assert!(df.column("flag")?.as_series().is_some());
+        assert!(df.equals_missing(&expected));
+
+        let rhs = Column::new_scalar(
+            "".into(),
+            Scalar::new(DataType::Int64, AnyValue::Int64(404)),
+            1,
+        );
+        let mask = df.column("status")?.equal(&rhs)?;
+        assert_eq!(
+            Vec::from(&mask),
+            Vec::from(expected.column("flag")?.bool()?)
+        );
+
+        let filtered = df.filter(&mask)?;
+        let status = filtered.column("status")?;
+        assert!(status.as_packed_column().is_some());
+        assert_eq!(status.len(), 333);
+        assert_eq!(status.sum_reduce()?.value(), &AnyValue::Int64(333 * 404));
+
+        // Compressing again drops the decoded values that were cached by a reference access.
+        df.column("status")?.as_materialized_series();
+        let packed = df.column("status")?.as_packed_column().unwrap();
+        assert!(packed.lazy_as_materialized_series().is_some());
+        df.compress_in_memory()?;
+        let packed = df.column("status")?.as_packed_column().unwrap();
+        assert!(packed.lazy_as_materialized_series().is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_packed_not_beneficial() -> PolarsResult<()> {
+        let dense = Series::new(
+            "a".into(),
+            (0..100i64)
+                .map(|i| i.wrapping_mul(0x9E37_79B9_7F4A_7C15u64 as i64))
+                .collect::<Vec<_>>(),
+        );
+        assert!(PackedColumn::compress(&dense)?.is_none());
+        assert!(PackedColumn::compress(&Series::new("a".into(), ["x"]))?.is_none());
+        Ok(())
+    }
+}
@@ -0,0 +1,600 @@
+use std::sync::OnceLock;
+
+use polars_buffer::Buffer;
+use polars_error::{PolarsResult, polars_bail, polars_ensure};
+use polars_utils::IdxSize;
+use polars_utils::pl_str::PlSmallStr;
+
+use super::{AnyValue, Column, DataType, IntoColumn, Scalar, Series};
+use crate::chunked_array::cast::CastOptions;
+use crate::prelude::*;
+use crate::series::IsSorted;
+use crate::utils::slice_offsets;
+
+/// A [`Column`] that consists of runs of repeated values, similar to Arrow's run-end encoding.
+///
+/// The `i`-th value is repeated from `run_ends[i - 1]` (or `0`) up to `run_ends[i]`. This is
+/// lazily materialized into a [`Series`].
+#[derive(Debug, Clone)]
+pub struct RunEndColumn {
+    name: PlSmallStr,
+    /// The value of every run. Neighbouring runs are allowed to have the same value.
+    values: Series,
+    /// The exclusive end of every run.
+    run_ends: Buffer<IdxSize>,
+
+    // invariants:
+    // values.len() == run_ends.len()
+    // run_ends is strictly increasing and starts above 0
+    // materialized.name() == name
+    // materialized.len() == run_ends.last()
+    // materialized.dtype() == values.dtype()
+    /// A lazily materialized [`Series`] variant of this [`RunEndColumn`]
+    materialized: OnceLock<Series>,
+}
+
+impl RunEndColumn {
+    /// Create a new [`RunEndColumn`] from the value and the exclusive end of every run.
+    pub fn new(name: PlSmallStr, values: Series, run_ends: Buffer<IdxSize>) -> PolarsResult<Self> {
+        polars_ensure!(
+            values.len() == run_ends.len(),
+            ShapeMismatch: "run-end encoded column has {} values but {} run ends",
+            values.len(), run_ends.len()
+        );
+        let mut prev = 0;
+        for &end in run_ends.iter() {
+            if end <= prev {
+                polars_bail!(ComputeError: "run ends must be strictly increasing and positive");
+            }
+            prev = end;
+        }
+
+        // SAFETY: We just checked the invariants.
+        Ok(unsafe { Self::new_unchecked(name, values, run_ends) })
+    }
+
+    /// # Safety
+    ///
+    /// `values` and `run_ends` have to be of the same length and `run_ends` has to be strictly
+    /// increasing and start above 0.
+    pub unsafe fn new_unchecked(
+        name: PlSmallStr,
+        values: Series,
+        run_ends: Buffer<IdxSize>,
+    ) -> Self {
+        debug_assert_eq!(values.len(), run_ends.len());
+        Self {
+            values: values.with_name(name.clone()),
+            name,
+            run_ends,
+
+            materialized: OnceLock::new(),
+        }
+    }
+
+    pub fn new_empty(name: PlSmallStr, dtype: &DataType) -> Self {
+        // SAFETY: There are no runs.
+        unsafe {
+            Self::new_unchecked(
+                name.clone(),
+                Series::new_empty(name, dtype),
+                Buffer::default(),
+            )
+        }
+    }
+
+    /// Run-end encode a [`Series`].
+    pub fn from_series(series: &Series) -> PolarsResult<Self> {
+        let length = series.len();
+        if length == 0 {
+            return Ok(Self::new_empty(series.name().clone(), series.dtype()));
+        }
+
+        // A run ends wherever a value differs from the next one.
+        let changes = series
+            .slice(0, length - 1)
+            .not_equal_missing(&series.slice(1, length - 1))?;
+        let mut run_ends = Vec::new();
+        let mut run_starts = vec![0];
+        let mut idx = 0 as IdxSize;
+        for changed in changes.downcast_iter().flat_map(|arr| arr.values().iter()) {
+            idx += 1;
+            if changed {
+                run_ends.push(idx);
+                run_starts.push(idx);
+            }
+        }
+        run_ends.push(length as IdxSize);
+
+        // SAFETY: The run starts are in-bounds and the run ends are strictly increasing.
+        let values = unsafe { series.take_slice_unchecked(&run_starts) };
+        let mut rc = unsafe { Self::new_unchecked(series.name().clone(), values, run_ends.into()) };
+        rc.materialized = OnceLock::from(series.clone());
+        Ok(rc)
+    }
+
+    pub fn name(&self) -> &PlSmallStr {
+        &self.name
+    }
+
+    pub fn dtype(&self) -> &DataType {
+        self.values.dtype()
+    }
+
+    pub fn len(&self) -> usize {
+        self.run_ends.last().map_or(0, |&end| end as usize)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.run_ends.is_empty()
+    }
+
+    /// The number of runs.
+    pub fn n_runs(&self) -> usize {
+        self.run_ends.len()
+    }
+
+    /// The value of every run.
+    pub fn values(&self) -> &Series {
+        &self.values
+    }
+
+    /// The exclusive end of every run.
+    pub fn run_ends(&self) -> &[IdxSize] {
+        &self.run_ends
+    }
+
+    /// The length of every run.
+    pub fn run_lengths(&self) -> impl Iterator<Item = IdxSize> + '_ {
+        let starts = std::iter::once(0).chain(self.run_ends.iter().copied());
+        self.run_ends
+            .iter()
+            .zip(starts)
+            .map(|(end, start)| end - start)
+    }
+
+    /// The index of the run that contains row `index`.
+    #[inline]
+    pub fn run_index(&self, index: usize) -> usize {
+        self.run_ends.partition_point(|&end| end as usize <= index)
+    }
+
+    /// Create a column with the same runs but other `values`.
+    ///
+    /// This panics if `values` does not have a value for every run.
+    pub fn with_values(&self, values: Series) -> Self {
+        assert_eq!(values.len(), self.n_runs());
+        // SAFETY: The runs are unchanged.
+        unsafe { Self::new_unchecked(values.name().clone(), values, self.run_ends.clone()) }
+    }
+
+    /// Repeat every value of `per_run` by the length of the corresponding run.
+    pub fn expand(&self, per_run: &Series) -> Series {
+        assert_eq!(per_run.len(), self.n_runs());
+        if self.is_empty() {
+            return per_run.clear();
+        }
+
+        let mut idxs = Vec::with_capacity(self.len());
+        for (run, length) in self.run_lengths().enumerate() {
+            idxs.extend(std::iter::repeat_n(run as IdxSize, length as usize));
+        }
+        // SAFETY: Every run has a value.
+        unsafe { per_run.take_slice_unchecked(&idxs) }
+    }
+
+    /// Repeat every boolean of `per_run` by the length of the corresponding run.
+    pub fn expand_bool(&self, per_run: &BooleanChunked) -> BooleanChunked {
+        self.expand(&per_run.clone().into_series())
+            .bool()
+            .unwrap()
+            .clone()
+    }
+
+    /// Materialize the [`RunEndColumn`] into a [`Series`].
+    pub fn to_series(&self) -> Series {
+        self.expand(&self.values)
+    }
+
+    /// Get the [`RunEndColumn`] as [`Series`] if it was already materialized.
+    pub fn lazy_as_materialized_series(&self) -> Option<&Series> {
+        self.materialized.get()
+    }
+
+    /// Get the [`RunEndColumn`] as [`Series`]
+    ///
+    /// This needs to materialize upon the first call. Afterwards, this is cached.
+    pub fn as_materialized_series(&self) -> &Series {
+        self.materialized.get_or_init(|| self.to_series())
+    }
+
+    /// Take the [`RunEndColumn`] and materialize as a [`Series`] if not already done.
+    pub fn take_materialized_series(mut self) -> Series {
+        self.materialized.take().unwrap_or_else(|| self.to_series())
+    }
+
+    /// Set the sorted flag, which applies to the run values as well.
+    pub fn set_sorted_flag(&mut self, sorted: IsSorted) {
+        if let Some(series) = self.materialized.get_mut() {
+            series.set_sorted_flag(sorted);
+        }
+
+        self.values.set_sorted_flag(sorted);
+    }
+
+    pub fn rename(&mut self, name: PlSmallStr) -> &mut Self {
+        if let Some(series) = self.materialized.get_mut() {
+            series.rename(name.clone());
+        }
+
+        self.values.rename(name.clone());
+        self.name = name;
+        self
+    }
+
+    pub fn cast_with_options(&self, dtype: &DataType, options: CastOptions) -> PolarsResult<Self> {
+        let values = self.values.cast_with_options(dtype, options)?;
+        Ok(self.with_values(values))
+    }
+
+    /// # Safety
+    ///
+    /// This can lead to invalid memory access in downstream code.
+    pub unsafe fn cast_unchecked(&self, dtype: &DataType) -> PolarsResult<Self> {
+        let values = unsafe { self.values.cast_unchecked(dtype) }?;
+        Ok(self.with_values(values))
+    }
+
+    /// # Safety
+    ///
+    /// `index` has to be in-bounds.
+    pub unsafe fn get_unchecked(&self, index: usize) -> AnyValue<'_> {
+        unsafe { self.values.get_unchecked(self.run_index(index)) }
+    }
+
+    pub fn null_count(&self) -> usize {
+        if !self.values.has_nulls() {
+            return 0;
+        }
+        self.values
+            .is_null()
+            .into_no_null_iter()
+            .zip(self.run_lengths())
+            .filter_map(|(is_null, length)| is_null.then_some(length as usize))
+            .sum()
+    }
+
+    pub fn has_nulls(&self) -> bool {
+        self.values.has_nulls()
+    }
+
+    /// Keep the runs for which `keep` returns a non-zero length, with that length.
+    fn filter_runs(&self, mut keep: impl FnMut(usize, usize, usize) -> usize) -> Self {
+        let mut run_idxs = Vec::new();
+        let mut run_ends = Vec::new();
+        let mut start = 0;
+        let mut end = 0;
+        for (run, &run_end) in self.run_ends.iter().enumerate() {
+            let length = keep(run, start, run_end as usize - start);
+            start = run_end as usize;
+            if length > 0 {
+                end += length as IdxSize;
+                run_idxs.push(run as IdxSize);
+                run_ends.push(end);
+            }
+        }
+
+        if run_idxs.len() == self.n_runs() && end as usize == self.len() {
+            return self.clone();
+        }
+        // SAFETY: The run indices are in-bounds and the run ends are strictly increasing.
+        unsafe {
+            let values = self.values.take_slice_unchecked(&run_idxs);
+            Self::new_unchecked(self.name.clone(), values, run_ends.into())
+        }
+    }
+
+    pub fn slice(&self, offset: i64, length: usize) -> Self {
+        let (offset, length) = slice_offsets(offset, length, self.len());
+        if length == 0 {
+            return Self::new_empty(self.name.clone(), self.dtype());
+        }
+
+        let first = self.run_index(offset);
+        let last = self.run_index(offset + length - 1);
+        let run_ends = self.run_ends[first..=last]
+            .iter()
+            .map(|&end| (end as usize).min(offset + length) as IdxSize - offset as IdxSize)
+            .collect::<Vec<_>>();
+        let values = self.values.slice(first as i64, last - first + 1);
+        // SAFETY: Clamping and shifting the run ends keeps them strictly increasing.
+        unsafe { Self::new_unchecked(self.name.clone(), values, run_ends.into()) }
+    }
+
+    /// Filter with a mask of the same length, counting the selected rows per run.
+    pub fn filter(&self, mask: &BooleanChunked) -> PolarsResult<Self> {
+        polars_ensure!(
+            mask.len() == self.len(),
+            ShapeMismatch: "filter's length: {} differs from that of the column: {}",
+            mask.len(), self.len()
+        );
+
+        let mask = mask.rechunk();
+        let arr = mask.downcast_as_array();
+        let bits = match arr.validity() {
+            Some(validity) => arr.values() & validity,
+            None => arr.values().clone(),
+        };
+        Ok(self.filter_runs(|_, start, length| length - bits.null_count_range(start, length)))
+    }
+
+    /// Gather the rows at `indices`, merging neighbouring rows that come from the same run.
+    ///
+    /// # Safety
+    ///
+    /// The indices have to be in-bounds.
+    pub unsafe fn take_iter_unchecked(
+        &self,
+        indices: impl Iterator<Item = Option<IdxSize>>,
+    ) -> Self {
+        let mut run_idxs: Vec<Option<IdxSize>> = Vec::new();
+        let mut run_ends = Vec::new();
+        let mut end = 0;
+        for idx in indices {
+            let run = idx.map(|idx| self.run_index(idx as usize) as IdxSize);
+            end += 1;
+            if run_idxs.last() == Some(&run) {
+                *run_ends.last_mut().unwrap() = end;
+            } else {
+                run_idxs.push(run);
+                run_ends.push(end);
+            }
+        }
+
+        let run_idxs = IdxCa::from_iter_options(PlSmallStr::EMPTY, run_idxs.into_iter());
+        // SAFETY: The run indices are in-bounds and the run ends are strictly increasing.
+        unsafe {
+            let values = self.values.take_unchecked(&run_idxs);
+            Self::new_unchecked(self.name.clone(), values, run_ends.into())
+        }
+    }
+
+    pub fn drop_nulls(&self) -> Self {
+        if !self.values.has_nulls() {
+            return self.clone();
+        }
+        let is_valid = self.values.is_not_null();
+        let mut is_valid = is_valid.into_no_null_iter();
+        self.filter_runs(|_, _, length| if is_valid.next().unwrap() { length } else { 0 })
+    }
+
+    pub fn reverse(&self) -> Self {
+        let mut end = 0;
+        let run_ends = self
+            .run_lengths()
+            .collect::<Vec<_>>()
+            .into_iter()
+            .rev()
+            .map(|length| {
+                end += length;
+                end
+            })
+            .collect::<Vec<_>>();
+        // SAFETY: The run lengths are unchanged.
+        unsafe { Self::new_unchecked(self.name.clone(), self.values.reverse(), run_ends.into()) }
+    }
+
+    /// Append a run of `n` times `value`.
+    pub fn extend_constant(&self, value: AnyValue, n: usize) -> PolarsResult<Self> {
+        if n == 0 {
+            return Ok(self.clone());
+        }
+        let values = self.values.extend_constant(value, 1)?;
+        let mut run_ends = self.run_ends.as_slice().to_vec();
+        run_ends.push((self.len() + n) as IdxSize);
+        // SAFETY: The new run is non-empty.
+        Ok(unsafe { Self::new_unchecked(self.name.clone(), values, run_ends.into()) })
+    }
+
+    /// The run lengths as a [`Series`] of `dtype`.
+    fn run_lengths_series(&self, dtype: &DataType) -> PolarsResult<Series> {
+        let lengths = IdxCa::from_vec(PlSmallStr::EMPTY, self.run_lengths().collect());
+        lengths.into_series().cast(dtype)
+    }
+
+    /// The sum of all values, weighted by the length of their runs.
+    ///
+    /// Returns `None` if this is not supported for the data type.
+    pub fn sum_reduce(&self) -> PolarsResult<Option<Scalar>> {
+        if !self.dtype().is_primitive_numeric() {
+            return Ok(None);
+        }
+        let dtype = self.values.clear().sum_reduce()?.dtype().clone();
+        let weighted =
+            (&self.values.cast(&dtype)? * &self.run_lengths_series(&dtype)?)?.cast(&dtype)?;
+        weighted.sum_reduce().map(Some)
+    }
+
+    /// The mean of all values, weighted by the length of their runs.
+    ///
+    /// Returns `None` if this is not supported for the data type.
+    pub fn mean_reduce(&self) -> PolarsResult<Option<Scalar>> {
+        if !self.dtype().is_primitive_numeric() {
+            return Ok(None);
+        }
+        let dtype = self.values.clear().mean_reduce()?.dtype().clone();
+        let values = self.values.cast(&DataType::Float64)?;
+        let values = values.f64()?;
+
+        let mut sum = 0.0;
+        let mut count = 0;
+        for (value, length) in values.iter().zip(self.run_lengths()) {
+            if let Some(value) = value {
+                sum += value * length as f64;
+                count += length as usize;
+            }
+        }
+        let mean = if count == 0 {
+            AnyValue::Null
+        } else {
+            AnyValue::Float64(sum / count as f64)
+        };
+        Scalar::new(DataType::Float64, mean)
+            .cast_with_options(&dtype, CastOptions::NonStrict)
+            .map(Some)
+    }
+}
+
+impl IntoColumn for RunEndColumn {
+    #[inline(always)]
+    fn into_column(self) -> Column {
+        self.into()
+    }
+}
+
+impl From<RunEndColumn> for Column {
+    #[inline]
+    fn from(value: RunEndColumn) -> Self {
+        Self::RunEnd(value)
+    }
+}
+
+#[cfg(feature = "dsl-schema")]
+impl schemars::JsonSchema for RunEndColumn {
+    fn schema_name() -> std::borrow::Cow<'static, str> {
+        "RunEndColumn".into()
+    }
+
+    fn schema_id() -> std::borrow::Cow<'static, str> {
+        std::borrow::Cow::Borrowed(concat!(module_path!(), "::", "RunEndColumn"))
+    }
+
+    fn json_schema(generator: &mut schemars::SchemaGenerator) -> schemars::Schema {
+        serde_impl::SerializeWrap::json_schema(generator)
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use polars_error::PolarsError;
+    use polars_utils::IdxSize;
+    use polars_utils::pl_str::PlSmallStr;
+
+    use super::RunEndColumn;
+    use crate::frame::Series;
+
+    #[derive(serde::Serialize, serde::Deserialize)]
+    #[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+    pub struct SerializeWrap {
+        name: PlSmallStr,
+        /// The value of every run
+        values: Series,
+        run_ends: Vec<IdxSize>,
+    }
+
+    impl From<&RunEndColumn> for SerializeWrap {
+        fn from(value: &RunEndColumn) -> Self {
+            Self {
+                name: value.name.clone(),
+                values: value.values.clone(),
+                run_ends: value.run_ends.as_slice().to_vec(),
+            }
+        }
+    }
+
+    impl TryFrom<SerializeWrap> for RunEndColumn {
+        type Error = PolarsError;
+
+        fn try_from(value: SerializeWrap) -> Result<Self, Self::Error> {
+            RunEndColumn::new(value.name, value.values, value.run_ends.into())
+        }
+    }
+
+    impl serde::ser::Serialize for RunEndColumn {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            SerializeWrap::from(self).serialize(serializer)
+        }
+    }
+
+    impl<'de> serde::de::Deserialize<'de> for RunEndColumn {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            use serde::de::Error;
+
+            SerializeWrap::deserialize(deserializer)
+                .and_then(|x| RunEndColumn::try_from(x).map_err(D::Error::custom))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn column() -> RunEndColumn {
+        let values = Series::new("a".into(), [Some(1i32), None, Some(3), Some(1)]);
+        RunEndColumn::new("a".into(), values, vec![3, 5, 6, 10].into()).unwrap()
+    }
+
+    #[test]
+    fn test_run_end_column() -> PolarsResult<()> {
+        let rc = column();
+        let dense = rc.to_series();
+        assert_eq!(rc.len(), 10);
+        assert_eq!(rc.null_count(), 2);
+        assert_eq!(
+            Vec::from(dense.i32()?),
+            &[
+                Some(1),
+                Some(1),
+                Some(1),
+                None,
+                None,
+                Some(3),
+                Some(1),
+                Some(1),
+                Some(1),
+                Some(1)
+            ]
+        );
+
+        let encoded = RunEndColumn::from_series(&dense)?;
+        assert_eq!(encoded.run_ends(), rc.run_ends());
+        assert!(encoded.values().equals_missing(rc.values()));
+
+        let sliced = rc.slice(2, 5);
+        assert_eq!(sliced.run_ends(), &[1, 3, 4, 5]);
+        assert!(sliced.to_series().equals_missing(&dense.slice(2, 5)));
+
+        let mask = BooleanChunked::from_iter_values(
+            "".into(),
+            (0..10).map(|i| i % 2 == 0 && i != 6 && i != 8),
+        );
+        let filtered = rc.filter(&mask)?;
+        assert_eq!(filtered.run_ends(), &[2, 3]);
+        assert!(filtered.to_series().equals_missing(&dense.filter(&mask)?));
+
+        let idxs = [9, 8, 0, 4, 3, 5];
+        let taken = unsafe { rc.take_iter_unchecked(idxs.iter().map(|&i| Some(i))) };
+        assert_eq!(taken.n_runs(), 4);
+        assert!(taken.to_series().equals_missing(&dense.take_slice(&idxs)?));
+
+        assert_eq!(rc.drop_nulls().run_ends(), &[3, 4, 8]);
+        assert!(rc.reverse().to_series().equals_missing(&dense.reverse()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_run_end_reduce() -> PolarsResult<()> {
+        let rc = column();
+        let dense = rc.to_series();
+        assert_eq!(rc.sum_reduce()?.unwrap(), dense.sum_reduce()?);
+        assert_eq!(rc.mean_reduce()?.unwrap(), dense.mean_reduce()?);
+        Ok(())
+    }
+}
@@ -199,6 +199,13 @@ impl AggList for ListChunked {
     }
 }
 
+#[cfg(feature = "dtype-decimal256")]
+impl AggList for Int256Chunked {
+    unsafe fn agg_list(&self, groups: &GroupsType) -> Series {
+        agg_list_by_gather_and_offsets(self, groups)
+    }
+}
+
 #[cfg(feature = "dtype-array")]
 impl AggList for ArrayChunked {
     unsafe fn agg_list(&self, groups: &GroupsType) -> Series {
@@ -74,12 +74,19 @@ impl DataFrame {
                 unreachable!()
             }
         } else {
-            // Skip null dtype.
+            // Skip null dtype, extension types are grouped by their comparison key.
             let by = by
                 .iter()
                 .filter(|s| !s.dtype().is_null())
-                .cloned()
-                .collect::<Vec<_>>();
+                .map(|s| {
+                    if s.dtype().is_extension() {
+                        let key = s.as_materialized_series().to_comparison_key()?;
+                        Ok(key.into_owned().into_column())
+                    } else {
+                        Ok(s.clone())
+                    }
+                })
+                .collect::<PolarsResult<Vec<_>>>()?;
             if by.is_empty() {
                 let groups = if self.height() == 0 {
                     vec![]
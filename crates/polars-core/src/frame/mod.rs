@@ -275,6 +275,34 @@ impl DataFrame {
         }
     }
 
+    /// Keep the integer columns bitpacked in memory where that takes less memory.
+    ///
+    /// The packed columns are decompressed when they are accessed. Filters, sums and comparisons
+    /// with a scalar run on the packed values. Boolean columns are already stored as bits and are
+    /// left as they are.
+    ///
+    /// Accessing a packed column by reference caches its decoded values, calling this again drops
+    /// those caches.
+    #[cfg(feature = "compress_in_memory")]
+    pub fn compress_in_memory(&mut self) -> PolarsResult<()> {
+        // SAFETY: We never adjust the length, names or data types of the columns.
+        let columns = unsafe { self.columns_mut_retain_schema() };
+        POOL.install(|| {
+            columns.par_iter_mut().try_for_each(|c| {
+                match c {
+                    Column::Series(s) => {
+                        if let Some(packed) = column::PackedColumn::compress(s)? {
+                            *c = packed.into_column();
+                        }
+                    },
+                    Column::Packed(packed) => packed.clear_materialized(),
+                    _ => {},
+                }
+                PolarsResult::Ok(())
+            })
+        })
+    }
+
     /// Aggregate all the chunks in the DataFrame to a single chunk in parallel.
     /// This may lead to more peak memory consumption.
     pub fn rechunk_mut_par(&mut self) -> &mut Self {
@@ -1548,6 +1576,10 @@ impl DataFrame {
             let (repr, materialized_at) = match col {
                 Column::Series(s) => ("series", s.materialized_at()),
                 Column::Scalar(_) => ("scalar", None),
+                Column::RunEnd(_) => ("run_end", None),
+                Column::Dictionary(_) => ("dictionary", None),
+                #[cfg(feature = "compress_in_memory")]
+                Column::Packed(_) => ("packed", None),
             };
             let sorted_asc = flags.contains(StatisticsFlags::IS_SORTED_ASC);
             let sorted_dsc = flags.contains(StatisticsFlags::IS_SORTED_DSC);
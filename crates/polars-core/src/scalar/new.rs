@@ -1,10 +1,14 @@
 use std::sync::Arc;
 
+#[cfg(feature = "dtype-interval")]
+use arrow::types::months_days_ns;
 use polars_error::PolarsResult;
 use polars_utils::pl_str::PlSmallStr;
 
 use super::Scalar;
 use crate::datatypes::time_unit::TimeUnit;
+#[cfg(feature = "dtype-decimal256")]
+use crate::prelude::i256;
 use crate::prelude::{AnyValue, DataType, TimeZone};
 use crate::series::Series;
 
@@ -35,6 +39,11 @@ impl Scalar {
         Scalar::new(DataType::Time, AnyValue::Time(value))
     }
 
+    #[cfg(feature = "dtype-interval")]
+    pub fn new_interval(value: months_days_ns) -> Self {
+        Scalar::new(DataType::Interval, AnyValue::Interval(value))
+    }
+
     pub fn new_list(values: Series) -> Self {
         Scalar::new(
             DataType::List(Box::new(values.dtype().clone())),
@@ -58,6 +67,14 @@ impl Scalar {
         )
     }
 
+    #[cfg(feature = "dtype-decimal256")]
+    pub fn new_decimal256(value: i256, precision: usize, scale: usize) -> Self {
+        Scalar::new(
+            DataType::Decimal256(precision, scale),
+            AnyValue::Decimal256(value, precision, scale),
+        )
+    }
+
     #[cfg(feature = "dtype-categorical")]
     pub fn new_enum(
         value: polars_dtype::categorical::CatSize,
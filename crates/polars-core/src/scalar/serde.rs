@@ -1,4 +1,6 @@
 use arrow::array::IntoBoxedArray;
+#[cfg(feature = "dtype-interval")]
+use arrow::types::months_days_ns;
 use polars_error::{PolarsError, PolarsResult, polars_bail};
 use polars_utils::float16::pf16;
 use polars_utils::pl_str::PlSmallStr;
@@ -6,6 +8,8 @@ use polars_utils::pl_str::PlSmallStr;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use super::Scalar;
+#[cfg(feature = "dtype-decimal256")]
+use crate::prelude::i256;
 use crate::prelude::{AnyValue, DataType, Field};
 use crate::series::Series;
 
@@ -119,6 +123,16 @@ pub enum SerializableScalar {
     #[cfg(feature = "dtype-decimal")]
     Decimal(i128, usize, usize),
 
+    /// A 256-bit fixed point decimal number with a scale, stored as its high and low 128-bit
+    /// words.
+    #[cfg(feature = "dtype-decimal256")]
+    Decimal256 {
+        hi: i128,
+        lo: i128,
+        precision: usize,
+        scale: usize,
+    },
+
     #[cfg(feature = "dtype-categorical")]
     Categorical {
         value: PlSmallStr,
@@ -134,6 +148,14 @@ pub enum SerializableScalar {
 
     #[cfg(feature = "dtype-struct")]
     Struct(Vec<(PlSmallStr, SerializableScalar)>),
+
+    /// A calendar interval of months, days and nanoseconds.
+    #[cfg(feature = "dtype-interval")]
+    Interval {
+        months: i32,
+        days: i32,
+        nanoseconds: i64,
+    },
 }
 
 impl TryFrom<Scalar> for SerializableScalar {
@@ -178,6 +200,13 @@ impl TryFrom<Scalar> for SerializableScalar {
             #[cfg(feature = "dtype-time")]
             AnyValue::Time(v) => Self::Time(v),
 
+            #[cfg(feature = "dtype-interval")]
+            AnyValue::Interval(v) => Self::Interval {
+                months: v.months(),
+                days: v.days(),
+                nanoseconds: v.ns(),
+            },
+
             #[cfg(feature = "dtype-categorical")]
             AnyValue::Categorical(cat, _) | AnyValue::CategoricalOwned(cat, _) => {
                 let DataType::Categorical(categories, mapping) = value.dtype() else {
@@ -262,6 +291,17 @@ impl TryFrom<Scalar> for SerializableScalar {
 
             #[cfg(feature = "dtype-decimal")]
             AnyValue::Decimal(v, prec, scale) => Self::Decimal(v, prec, scale),
+
+            #[cfg(feature = "dtype-decimal256")]
+            AnyValue::Decimal256(v, precision, scale) => {
+                let (hi, lo) = v.0.into_words();
+                Self::Decimal256 {
+                    hi,
+                    lo,
+                    precision,
+                    scale,
+                }
+            },
         };
         Ok(out)
     }
@@ -299,10 +339,23 @@ impl TryFrom<SerializableScalar> for Scalar {
             S::Duration(v, time_unit) => Self::new_duration(v, time_unit),
             #[cfg(feature = "dtype-time")]
             S::Time(v) => Self::new_time(v),
+            #[cfg(feature = "dtype-interval")]
+            S::Interval {
+                months,
+                days,
+                nanoseconds,
+            } => Self::new_interval(months_days_ns::new(months, days, nanoseconds)),
             #[cfg(feature = "dtype-array")]
             S::Array(v, width) => Self::new_array(v, width),
             #[cfg(feature = "dtype-decimal")]
             S::Decimal(v, prec, scale) => Self::new_decimal(v, prec, scale),
+            #[cfg(feature = "dtype-decimal256")]
+            S::Decimal256 {
+                hi,
+                lo,
+                precision,
+                scale,
+            } => Self::new_decimal256(i256::from_words(hi, lo), precision, scale),
 
             #[cfg(feature = "dtype-categorical")]
             S::Categorical {
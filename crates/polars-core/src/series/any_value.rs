@@ -77,6 +77,16 @@ impl Series {
                     }
                     DataType::Decimal(prec, scale)
                 },
+                #[cfg(feature = "dtype-decimal256")]
+                DataType::Decimal256(mut prec, mut scale) => {
+                    for v in values {
+                        if let DataType::Decimal256(p, s) = v.dtype() {
+                            prec = prec.max(p);
+                            scale = scale.max(s);
+                        }
+                    }
+                    DataType::Decimal256(prec, scale)
+                },
                 dt => dt,
             }
         } else {
@@ -138,6 +148,18 @@ impl Series {
             },
             #[cfg(feature = "dtype-duration")]
             DataType::Duration(tu) => any_values_to_duration(values, *tu, strict)?.into_series(),
+            #[cfg(feature = "dtype-interval")]
+            DataType::Interval => any_values_to_interval(values, strict)?.into_series(),
+            #[cfg(feature = "dtype-variant")]
+            DataType::Variant => {
+                // Variants are represented by their encoded binary, strings are parsed as JSON.
+                let s = Series::from_any_values(name.clone(), values, strict)?;
+                if strict {
+                    s.strict_cast(dtype)?
+                } else {
+                    s.cast(dtype)?
+                }
+            },
             #[cfg(feature = "dtype-categorical")]
             dt @ (DataType::Categorical(_, _) | DataType::Enum(_, _)) => {
                 any_values_to_categorical(values, dt, strict)?
@@ -146,12 +168,23 @@ impl Series {
             DataType::Decimal(precision, scale) => {
                 any_values_to_decimal(values, *precision, *scale, strict)?.into_series()
             },
+            #[cfg(feature = "dtype-decimal256")]
+            DataType::Decimal256(precision, scale) => {
+                any_values_to_decimal256(values, *precision, *scale, strict)?.into_series()
+            },
             #[cfg(feature = "dtype-extension")]
             DataType::Extension(typ, storage) => {
                 Series::from_any_values_and_dtype(name.clone(), values, storage, strict)?
                     .into_extension(typ.clone())
             },
             DataType::List(inner) => any_values_to_list(values, inner, strict)?.into_series(),
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(key, value) => {
+                let entries = DataType::map_entries_dtype(*key.clone(), *value.clone());
+                any_values_to_list(values, &entries, strict)?
+                    .into_map()?
+                    .into_series()
+            },
             #[cfg(feature = "dtype-array")]
             DataType::Array(inner, size) => any_values_to_array(values, inner, strict, *size)?
                 .into_series()
@@ -562,6 +595,28 @@ fn any_values_to_duration(
     Ok(builder.finish().into_duration(time_unit))
 }
 
+#[cfg(feature = "dtype-interval")]
+fn any_values_to_interval(values: &[AnyValue], strict: bool) -> PolarsResult<IntervalChunked> {
+    let mut builder = PrimitiveChunkedBuilder::<Int128Type>::new(PlSmallStr::EMPTY, values.len());
+    let target_dtype = DataType::Interval;
+    for av in values {
+        match av {
+            AnyValue::Interval(v) => builder.append_value(interval_to_i128(*v)),
+            AnyValue::Null => builder.append_null(),
+            av => {
+                if strict {
+                    return Err(invalid_value_error(&target_dtype, av));
+                }
+                match av.cast(&target_dtype) {
+                    AnyValue::Interval(v) => builder.append_value(interval_to_i128(v)),
+                    _ => builder.append_null(),
+                }
+            },
+        }
+    }
+    Ok(builder.finish().into_interval())
+}
+
 #[cfg(feature = "dtype-categorical")]
 fn any_values_to_categorical(
     values: &[AnyValue],
@@ -656,6 +711,45 @@ fn any_values_to_decimal(
     builder.finish().into_decimal(precision, scale)
 }
 
+#[cfg(feature = "dtype-decimal256")]
+fn any_values_to_decimal256(
+    values: &[AnyValue],
+    precision: usize,
+    scale: usize,
+    strict: bool,
+) -> PolarsResult<Decimal256Chunked> {
+    let target_dtype = DataType::Decimal256(precision, scale);
+
+    let mut out = Vec::with_capacity(values.len());
+    for av in values {
+        let v = match av {
+            AnyValue::Decimal256(v, p, s) if *p <= precision && *s == scale => Some(*v),
+            // Allow equal or less scale, as for `Decimal`.
+            AnyValue::Decimal256(_, _, s) | AnyValue::Decimal(_, _, s) if *s <= scale => {
+                match av.strict_cast(&target_dtype) {
+                    Some(AnyValue::Decimal256(i, _, _)) => Some(i),
+                    _ => None,
+                }
+            },
+            AnyValue::Null => None,
+            av => {
+                if strict {
+                    return Err(invalid_value_error(&target_dtype, av));
+                }
+                match av.strict_cast(&target_dtype) {
+                    Some(AnyValue::Decimal256(i, _, _)) => Some(i),
+                    _ => None,
+                }
+            },
+        };
+        out.push(v);
+    }
+
+    // Build the array and do a precision check if needed.
+    let arr = PrimitiveArray::<i256>::from(out);
+    Int256Chunked::with_chunk(PlSmallStr::EMPTY, arr).into_decimal256(precision, scale)
+}
+
 fn any_values_to_list(
     avs: &[AnyValue],
     inner_type: &DataType,
@@ -1,4 +1,6 @@
 use super::*;
+#[cfg(feature = "dtype-extension")]
+use crate::datatypes::extension::{ExtensionOperation, arithmetic_extension_type};
 use crate::utils::align_chunks_binary;
 
 pub trait NumOpsDispatchInner: PolarsDataType + Sized {
@@ -484,6 +486,24 @@ pub fn _struct_arithmetic<F: FnMut(&Series, &Series) -> PolarsResult<Series>>(
     }
 }
 
+/// Apply an arithmetic operation on the storage of extension types. The result keeps the
+/// extension type if the type preserves it for `op`.
+#[cfg(feature = "dtype-extension")]
+fn extension_arithmetic<F: FnOnce(&Series, &Series) -> PolarsResult<Series>>(
+    lhs: &Series,
+    rhs: &Series,
+    op: ExtensionOperation,
+    func: F,
+) -> PolarsResult<Series> {
+    let (typ, storage) = arithmetic_extension_type(lhs.dtype(), rhs.dtype())?;
+    let out = func(lhs.to_storage(), rhs.to_storage())?;
+    if typ.keeps_extension(op, storage, out.dtype()) {
+        Ok(out.into_extension(typ.clone()))
+    } else {
+        Ok(out)
+    }
+}
+
 fn check_lengths(a: &Series, b: &Series) -> PolarsResult<()> {
     match (a.len(), b.len()) {
         // broadcasting
@@ -507,6 +527,10 @@ impl Add for &Series {
             (DataType::Struct(_), DataType::Struct(_)) => {
                 _struct_arithmetic(self, rhs, |a, b| a.add(b))
             },
+            #[cfg(feature = "dtype-extension")]
+            (DataType::Extension(_, _), _) | (_, DataType::Extension(_, _)) => {
+                extension_arithmetic(self, rhs, ExtensionOperation::Add, |a, b| a.add(b))
+            },
             (DataType::List(_), _) | (_, DataType::List(_)) => {
                 list::NumericListOp::add().execute(self, rhs)
             },
@@ -532,6 +556,10 @@ impl Sub for &Series {
             (DataType::Struct(_), DataType::Struct(_)) => {
                 _struct_arithmetic(self, rhs, |a, b| a.sub(b))
             },
+            #[cfg(feature = "dtype-extension")]
+            (DataType::Extension(_, _), _) | (_, DataType::Extension(_, _)) => {
+                extension_arithmetic(self, rhs, ExtensionOperation::Sub, |a, b| a.sub(b))
+            },
             (DataType::List(_), _) | (_, DataType::List(_)) => {
                 list::NumericListOp::sub().execute(self, rhs)
             },
@@ -562,6 +590,10 @@ impl Mul for &Series {
         match (self.dtype(), rhs.dtype()) {
             #[cfg(feature = "dtype-struct")]
             (Struct(_), Struct(_)) => _struct_arithmetic(self, rhs, |a, b| a.mul(b)),
+            #[cfg(feature = "dtype-extension")]
+            (Extension(_, _), _) | (_, Extension(_, _)) => {
+                extension_arithmetic(self, rhs, ExtensionOperation::Mul, |a, b| a.mul(b))
+            },
             // temporal lh
             (Duration(_), _) | (Date, _) | (Datetime(_, _), _) | (Time, _) => self.multiply(rhs),
             // temporal rhs
@@ -602,6 +634,10 @@ impl Div for &Series {
         match (self.dtype(), rhs.dtype()) {
             #[cfg(feature = "dtype-struct")]
             (Struct(_), Struct(_)) => _struct_arithmetic(self, rhs, |a, b| a.div(b)),
+            #[cfg(feature = "dtype-extension")]
+            (Extension(_, _), _) | (_, Extension(_, _)) => {
+                extension_arithmetic(self, rhs, ExtensionOperation::Div, |a, b| a.div(b))
+            },
             (Duration(_), _) => self.divide(rhs),
             (Date, _)
             | (Datetime(_, _), _)
@@ -640,6 +676,10 @@ impl Rem for &Series {
             (DataType::Struct(_), DataType::Struct(_)) => {
                 _struct_arithmetic(self, rhs, |a, b| a.rem(b))
             },
+            #[cfg(feature = "dtype-extension")]
+            (DataType::Extension(_, _), _) | (_, DataType::Extension(_, _)) => {
+                extension_arithmetic(self, rhs, ExtensionOperation::Rem, |a, b| a.rem(b))
+            },
             (DataType::List(_), _) | (_, DataType::List(_)) => {
                 list::NumericListOp::rem().execute(self, rhs)
             },
@@ -91,6 +91,11 @@ impl CategoricalToArrowConverter {
                 }
             },
             List(inner) => self.initialize(inner),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => {
+                self.initialize(key);
+                self.initialize(value);
+            },
             #[cfg(feature = "dtype-array")]
             Array(inner, _width) => self.initialize(inner),
             #[cfg(feature = "dtype-struct")]
@@ -55,19 +55,19 @@ macro_rules! impl_eq_compare {
             (le @ Extension(_, _), re @ Extension(_, _)) if le == re => {
                 let lhs = lhs.ext().unwrap();
                 let rhs = rhs.ext().unwrap();
-                return lhs.storage().$method(rhs.storage());
+                return lhs.comparison_key()?.$method(rhs.comparison_key()?.as_ref());
             },
 
             #[cfg(feature = "dtype-extension")]
-            (Extension(_, storage), rdt) if **storage == *rdt => {
+            (Extension(typ, storage), rdt) if **storage == *rdt => {
                 let lhs = lhs.ext().unwrap();
-                return lhs.storage().$method(rhs);
+                return lhs.comparison_key()?.$method(typ.comparison_key(rhs)?.as_ref());
             },
 
             #[cfg(feature = "dtype-extension")]
-            (ldt, Extension(_, storage)) if *ldt == **storage => {
+            (ldt, Extension(typ, storage)) if *ldt == **storage => {
                 let rhs = rhs.ext().unwrap();
-                return lhs.$method(rhs.storage());
+                return typ.comparison_key(lhs)?.$method(rhs.comparison_key()?.as_ref());
             },
             _ => (),
         };
@@ -173,19 +173,19 @@ macro_rules! impl_ineq_compare {
             (le @ Extension(_, _), re @ Extension(_, _)) if le == re => {
                 let lhs = lhs.ext().unwrap();
                 let rhs = rhs.ext().unwrap();
-                return lhs.storage().$method(rhs.storage());
+                return lhs.comparison_key()?.$method(rhs.comparison_key()?.as_ref());
             },
 
             #[cfg(feature = "dtype-extension")]
-            (Extension(_, storage), rdt) if **storage == *rdt => {
+            (Extension(typ, storage), rdt) if **storage == *rdt => {
                 let lhs = lhs.ext().unwrap();
-                return lhs.storage().$method(rhs);
+                return lhs.comparison_key()?.$method(typ.comparison_key(rhs)?.as_ref());
             },
 
             #[cfg(feature = "dtype-extension")]
-            (ldt, Extension(_, storage)) if *ldt == **storage => {
+            (ldt, Extension(typ, storage)) if *ldt == **storage => {
                 let rhs = rhs.ext().unwrap();
-                return lhs.$method(rhs.storage());
+                return typ.comparison_key(lhs)?.$method(rhs.comparison_key()?.as_ref());
             },
             _ => (),
         };
@@ -232,6 +232,24 @@ macro_rules! impl_ineq_compare {
     }};
 }
 
+/// Compare an extension series with a string, respecting the comparison key of the extension type.
+#[cfg(feature = "dtype-extension")]
+fn ext_compare_str(
+    s: &Series,
+    rhs: &str,
+    cmp: impl Fn(&Series, &Series) -> PolarsResult<BooleanChunked>,
+    cmp_str: impl Fn(&Series, &str) -> PolarsResult<BooleanChunked>,
+) -> PolarsResult<BooleanChunked> {
+    let ext = s.ext().unwrap();
+    match ext.comparison_key()? {
+        std::borrow::Cow::Borrowed(storage) => cmp_str(storage, rhs),
+        std::borrow::Cow::Owned(key) => {
+            let rhs = Series::new(PlSmallStr::EMPTY, [rhs]).strict_cast(ext.storage().dtype())?;
+            cmp(&key, ext.extension_type().comparison_key(&rhs)?.as_ref())
+        },
+    }
+}
+
 fn validate_types(left: &DataType, right: &DataType) -> PolarsResult<()> {
     use DataType::*;
 
@@ -378,7 +396,9 @@ impl ChunkCompareEq<&str> for Series {
                 }),
             ),
             #[cfg(feature = "dtype-extension")]
-            DataType::Extension(_, _) => self.ext().unwrap().storage().equal(rhs),
+            DataType::Extension(_, _) => {
+                ext_compare_str(self, rhs, |l, r| l.equal(r), |l, r| l.equal(r))
+            },
             _ => Ok(BooleanChunked::full(self.name().clone(), false, self.len())),
         }
     }
@@ -394,7 +414,12 @@ impl ChunkCompareEq<&str> for Series {
                 }),
             ),
             #[cfg(feature = "dtype-extension")]
-            DataType::Extension(_, _) => self.ext().unwrap().storage().equal_missing(rhs),
+            DataType::Extension(_, _) => ext_compare_str(
+                self,
+                rhs,
+                |l, r| l.equal_missing(r),
+                |l, r| l.equal_missing(r),
+            ),
             _ => Ok(replace_non_null(
                 self.name().clone(),
                 self.0.chunks(),
@@ -414,7 +439,9 @@ impl ChunkCompareEq<&str> for Series {
                 }),
             ),
             #[cfg(feature = "dtype-extension")]
-            DataType::Extension(_, _) => self.ext().unwrap().storage().not_equal(rhs),
+            DataType::Extension(_, _) => {
+                ext_compare_str(self, rhs, |l, r| l.not_equal(r), |l, r| l.not_equal(r))
+            },
             _ => Ok(BooleanChunked::full(self.name().clone(), true, self.len())),
         }
     }
@@ -430,7 +457,12 @@ impl ChunkCompareEq<&str> for Series {
                 }),
             ),
             #[cfg(feature = "dtype-extension")]
-            DataType::Extension(_, _) => self.ext().unwrap().storage().not_equal_missing(rhs),
+            DataType::Extension(_, _) => ext_compare_str(
+                self,
+                rhs,
+                |l, r| l.not_equal_missing(r),
+                |l, r| l.not_equal_missing(r),
+            ),
             _ => Ok(replace_non_null(self.name().clone(), self.0.chunks(), true)),
         }
     }
@@ -450,7 +482,7 @@ impl ChunkCompareIneq<&str> for Series {
                 }),
             ),
             #[cfg(feature = "dtype-extension")]
-            DataType::Extension(_, _) => self.ext().unwrap().storage().gt(rhs),
+            DataType::Extension(_, _) => ext_compare_str(self, rhs, |l, r| l.gt(r), |l, r| l.gt(r)),
             _ => polars_bail!(
                 ComputeError: "cannot compare str value to series of type {}", self.dtype(),
             ),
@@ -468,7 +500,9 @@ impl ChunkCompareIneq<&str> for Series {
                 }),
             ),
             #[cfg(feature = "dtype-extension")]
-            DataType::Extension(_, _) => self.ext().unwrap().storage().gt_eq(rhs),
+            DataType::Extension(_, _) => {
+                ext_compare_str(self, rhs, |l, r| l.gt_eq(r), |l, r| l.gt_eq(r))
+            },
             _ => polars_bail!(
                 ComputeError: "cannot compare str value to series of type {}", self.dtype(),
             ),
@@ -486,7 +520,7 @@ impl ChunkCompareIneq<&str> for Series {
                 }),
             ),
             #[cfg(feature = "dtype-extension")]
-            DataType::Extension(_, _) => self.ext().unwrap().storage().lt(rhs),
+            DataType::Extension(_, _) => ext_compare_str(self, rhs, |l, r| l.lt(r), |l, r| l.lt(r)),
             _ => polars_bail!(
                 ComputeError: "cannot compare str value to series of type {}", self.dtype(),
             ),
@@ -504,7 +538,9 @@ impl ChunkCompareIneq<&str> for Series {
                 }),
             ),
             #[cfg(feature = "dtype-extension")]
-            DataType::Extension(_, _) => self.ext().unwrap().storage().lt_eq(rhs),
+            DataType::Extension(_, _) => {
+                ext_compare_str(self, rhs, |l, r| l.lt_eq(r), |l, r| l.lt_eq(r))
+            },
             _ => polars_bail!(
                 ComputeError: "cannot compare str value to series of type {}", self.dtype(),
             ),
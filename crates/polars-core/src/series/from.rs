@@ -12,6 +12,7 @@ use polars_compute::cast::cast_unchecked as cast;
 #[cfg(feature = "dtype-decimal")]
 use polars_compute::decimal::dec128_fits;
 use polars_error::feature_gated;
+#[cfg(not(feature = "dtype-interval"))]
 use polars_utils::check_allow_importing_interval_as_struct;
 use polars_utils::itertools::Itertools;
 
@@ -96,6 +97,14 @@ impl Series {
             Decimal(precision, scale) => Int128Chunked::from_chunks(name, chunks)
                 .into_decimal_unchecked(*precision, *scale)
                 .into_series(),
+            #[cfg(feature = "dtype-decimal256")]
+            Decimal256(precision, scale) => Int256Chunked::from_chunks(name, chunks)
+                .into_decimal256_unchecked(*precision, *scale)
+                .into_series(),
+            #[cfg(feature = "dtype-interval")]
+            Interval => Int128Chunked::from_chunks(name, chunks)
+                .into_interval()
+                .into_series(),
             #[cfg(feature = "dtype-array")]
             Array(_, _) => {
                 ArrayChunked::from_chunks_and_dtype_unchecked(name, chunks, dtype.clone())
@@ -103,6 +112,21 @@ impl Series {
             },
             List(_) => ListChunked::from_chunks_and_dtype_unchecked(name, chunks, dtype.clone())
                 .into_series(),
+            #[cfg(feature = "dtype-map")]
+            Map(key, value) => {
+                let entries = DataType::map_entries_dtype(*key.clone(), *value.clone());
+                let phys = ListChunked::from_chunks_and_dtype_unchecked(
+                    name,
+                    chunks,
+                    List(Box::new(entries)),
+                );
+                MapChunked::new_logical(phys, dtype.clone()).into_series()
+            },
+            #[cfg(feature = "dtype-variant")]
+            Variant => {
+                VariantChunked::new_logical(BinaryChunked::from_chunks(name, chunks), Variant)
+                    .into_series()
+            },
             String => StringChunked::from_chunks(name, chunks).into_series(),
             Binary => BinaryChunked::from_chunks(name, chunks).into_series(),
             #[cfg(feature = "dtype-categorical")]
@@ -118,6 +142,8 @@ impl Series {
             Float32 => Float32Chunked::from_chunks(name, chunks).into_series(),
             Float64 => Float64Chunked::from_chunks(name, chunks).into_series(),
             BinaryOffset => BinaryOffsetChunked::from_chunks(name, chunks).into_series(),
+            #[cfg(feature = "dtype-decimal256")]
+            Int256 => Int256Chunked::from_chunks(name, chunks).into_series(),
             #[cfg(feature = "dtype-extension")]
             Extension(typ, storage) => ExtensionChunked::from_storage(
                 typ.clone(),
@@ -388,6 +414,28 @@ impl Series {
                     Ok(s)
                 })
             },
+            #[cfg(feature = "dtype-decimal256")]
+            ArrowDataType::Decimal256(precision, scale) => {
+                polars_compute::decimal::dec256_verify_prec_scale(*precision, *scale)?;
+
+                let mut chunks = chunks;
+                for chunk in chunks.iter_mut() {
+                    *chunk = std::mem::take(
+                        chunk
+                            .as_any_mut()
+                            .downcast_mut::<PrimitiveArray<i256>>()
+                            .unwrap(),
+                    )
+                    .to(DataType::Int256.to_arrow(CompatLevel::newest()))
+                    .to_boxed();
+                }
+
+                let s = Int256Chunked::from_chunks(name, chunks)
+                    .into_decimal256_unchecked(*precision, *scale)
+                    .into_series();
+                Ok(s)
+            },
+            #[cfg(not(feature = "dtype-decimal256"))]
             ArrowDataType::Decimal256(precision, scale) => {
                 feature_gated!("dtype-decimal", {
                     use arrow::types::i256;
@@ -444,6 +492,20 @@ impl Series {
 
                 Ok(first)
             },
+            #[cfg(feature = "dtype-variant")]
+            ArrowDataType::Extension(ext)
+                if ext.name.as_str() == arrow::datatypes::PARQUET_VARIANT_EXTENSION_NAME =>
+            {
+                let chunks = chunks
+                    .iter()
+                    .map(|arr| {
+                        let arr = arr.as_any().downcast_ref::<StructArray>().unwrap();
+                        Ok(polars_compute::variant::struct_to_variant(arr)?.boxed())
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                let phys = BinaryChunked::from_chunks(name, chunks);
+                Ok(VariantChunked::new_logical(phys, DataType::Variant).into_series())
+            },
             #[cfg(feature = "object")]
             ArrowDataType::Extension(ext)
                 if ext.name == POLARS_OBJECT_EXTENSION_NAME && ext.metadata.is_some() =>
@@ -531,7 +593,7 @@ impl Series {
                         let arr = arr.as_any().downcast_ref::<MapArray>().unwrap();
                         let offsets: &OffsetsBuffer<i32> = arr.offsets();
 
-                        let validity = values.validity().cloned();
+                        let validity = arr.validity().cloned();
 
                         Box::from(ListArray::<i64>::new(
                             ListArray::<i64>::default_datatype(values.dtype().clone()),
@@ -542,16 +604,34 @@ impl Series {
                     })
                     .collect();
 
-                unsafe {
-                    let out = ListChunked::from_chunks_and_dtype_unchecked(
+                let out = unsafe {
+                    ListChunked::from_chunks_and_dtype_unchecked(
                         name,
                         chunks,
                         DataType::List(Box::new(dtype)),
-                    );
+                    )
+                };
 
+                #[cfg(feature = "dtype-map")]
+                {
+                    out.into_map().map(|ca| ca.into_series())
+                }
+                #[cfg(not(feature = "dtype-map"))]
+                {
                     Ok(out.into_series())
                 }
             },
+            #[cfg(feature = "dtype-interval")]
+            ArrowDataType::Interval(IntervalUnit::MonthDayNano) => {
+                let chunks = chunks
+                    .iter()
+                    .map(|arr| convert_month_day_nano_to_i128(arr.as_ref()))
+                    .collect();
+                Ok(Int128Chunked::from_chunks(name, chunks)
+                    .into_interval()
+                    .into_series())
+            },
+            #[cfg(not(feature = "dtype-interval"))]
             ArrowDataType::Interval(IntervalUnit::MonthDayNano) => {
                 check_allow_importing_interval_as_struct("month_day_nano_interval")?;
 
@@ -605,6 +685,17 @@ unsafe fn to_physical_and_dtype(
                 (s.chunks().clone(), s.dtype().clone())
             })
         },
+        #[cfg(feature = "dtype-variant")]
+        dt @ ArrowDataType::Extension(ext)
+            if ext.name.as_str() == arrow::datatypes::PARQUET_VARIANT_EXTENSION_NAME =>
+        {
+            let s = unsafe {
+                let dt = dt.clone();
+                Series::_try_from_arrow_unchecked_with_md(PlSmallStr::EMPTY, arrays, &dt, md)
+            }
+            .unwrap();
+            (s.chunks().clone(), s.dtype().clone())
+        },
         dt @ ArrowDataType::Extension(_) => {
             feature_gated!("dtype-extension", {
                 let s = unsafe {
@@ -738,6 +829,7 @@ unsafe fn to_physical_and_dtype(
         | ArrowDataType::Timestamp(_, _)
         | ArrowDataType::Date32
         | ArrowDataType::Decimal(_, _)
+        | ArrowDataType::Decimal256(_, _)
         | ArrowDataType::Date64
         | ArrowDataType::Map(_, _)) => {
             let dt = dt.clone();
@@ -745,6 +837,13 @@ unsafe fn to_physical_and_dtype(
             let dtype = s.dtype().clone();
             (std::mem::take(s.chunks_mut()), dtype)
         },
+        #[cfg(feature = "dtype-interval")]
+        dt @ ArrowDataType::Interval(IntervalUnit::MonthDayNano) => {
+            let dt = dt.clone();
+            let mut s = Series::_try_from_arrow_unchecked(PlSmallStr::EMPTY, arrays, &dt).unwrap();
+            let dtype = s.dtype().clone();
+            (std::mem::take(s.chunks_mut()), dtype)
+        },
         dt => {
             let dtype = DataType::from_arrow(dt, md);
             (arrays, dtype)
@@ -841,7 +940,23 @@ unsafe fn import_arrow_dictionary_array(
     }
 }
 
-#[cfg(feature = "dtype-struct")]
+#[cfg(feature = "dtype-interval")]
+fn convert_month_day_nano_to_i128(arr: &dyn Array) -> ArrayRef {
+    let arr: &PrimitiveArray<months_days_ns> = arr.as_any().downcast_ref().unwrap();
+    let values = arr
+        .values()
+        .iter()
+        .map(|v| interval_to_i128(*v))
+        .collect::<Vec<_>>();
+    PrimitiveArray::<i128>::new(
+        ArrowDataType::Int128,
+        values.into(),
+        arr.validity().cloned(),
+    )
+    .boxed()
+}
+
+#[cfg(all(feature = "dtype-struct", not(feature = "dtype-interval")))]
 fn convert_month_day_nano_to_struct(chunk: Box<dyn Array>) -> PolarsResult<Box<dyn Array>> {
     let arr: &PrimitiveArray<months_days_ns> = chunk.as_any().downcast_ref().unwrap();
 
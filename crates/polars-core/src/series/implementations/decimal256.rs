@@ -0,0 +1,500 @@
+use polars_compute::decimal::{DEC256_MAX_PREC, dec256_add, dec256_to_f64};
+
+use super::*;
+#[cfg(feature = "algorithm_group_by")]
+use crate::frame::group_by::*;
+use crate::prelude::*;
+
+unsafe impl IntoSeries for Decimal256Chunked {
+    fn into_series(self) -> Series {
+        Series(Arc::new(SeriesWrap(self)))
+    }
+}
+
+impl private::PrivateSeriesNumeric for SeriesWrap<Decimal256Chunked> {
+    fn bit_repr(&self) -> Option<BitRepr> {
+        None
+    }
+}
+
+impl SeriesWrap<Decimal256Chunked> {
+    fn physical_series(&self) -> Series {
+        self.0.physical().clone().into_series()
+    }
+
+    /// Relabels a physical result as a decimal with the precision and scale of `self`.
+    fn to_logical(&self, s: Series) -> Series {
+        s.i256()
+            .unwrap()
+            .clone()
+            .into_decimal256_unchecked(self.0.precision(), self.0.scale())
+            .into_series()
+    }
+
+    fn reduce(&self, f: impl Fn(i256, i256) -> i256) -> Scalar {
+        let v = self
+            .0
+            .physical()
+            .downcast_iter()
+            .flat_map(|arr| arr.iter().flatten().copied())
+            .reduce(f);
+        let av = v.map_or(AnyValue::Null, |v| {
+            AnyValue::Decimal256(v, self.0.precision(), self.0.scale())
+        });
+        Scalar::new(self.dtype().clone(), av)
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_reduce(&self, groups: &GroupsType, f: impl Fn(i256, i256) -> i256) -> Series {
+        self.try_agg_reduce(groups, self.0.precision(), false, |l, r| Some(f(l, r)))
+            .unwrap()
+    }
+
+    /// Reduces every group with `f`, which returns `None` on overflow. An overflowing group
+    /// raises an error if `strict`, otherwise it becomes null.
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn try_agg_reduce(
+        &self,
+        groups: &GroupsType,
+        precision: usize,
+        strict: bool,
+        f: impl Fn(i256, i256) -> Option<i256>,
+    ) -> PolarsResult<Series> {
+        let phys = self.0.physical().rechunk();
+        let arr = phys.downcast_iter().next().unwrap();
+        let mut overflow = false;
+        let mut reduce_group = |idx: &mut dyn Iterator<Item = usize>| {
+            let mut values = idx.filter_map(|i| arr.get_unchecked(i));
+            let first = values.next()?;
+            let out = values.try_fold(first, &f);
+            overflow |= out.is_none();
+            out
+        };
+        let out: PrimitiveArray<i256> = groups
+            .iter()
+            .map(|g| match g {
+                GroupsIndicator::Idx((_, idx)) => {
+                    reduce_group(&mut idx.iter().map(|i| *i as usize))
+                },
+                GroupsIndicator::Slice([first, len]) => {
+                    reduce_group(&mut (first as usize..(first + len) as usize))
+                },
+            })
+            .collect();
+        polars_ensure!(!(strict && overflow), ComputeError: "overflow in Decimal256 sum");
+        Ok(Int256Chunked::with_chunk(self.0.name().clone(), out)
+            .into_decimal256_unchecked(precision, self.0.scale())
+            .into_series())
+    }
+
+    fn sum(l: i256, r: i256) -> Option<i256> {
+        dec256_add(l, r, DEC256_MAX_PREC)
+    }
+}
+
+impl private::PrivateSeries for SeriesWrap<Decimal256Chunked> {
+    fn compute_len(&mut self) {
+        self.0.physical_mut().compute_len()
+    }
+
+    fn _field(&self) -> Cow<'_, Field> {
+        Cow::Owned(self.0.field())
+    }
+
+    fn _dtype(&self) -> &DataType {
+        self.0.dtype()
+    }
+    fn _get_flags(&self) -> StatisticsFlags {
+        self.0.physical().get_flags()
+    }
+    fn _set_flags(&mut self, flags: StatisticsFlags) {
+        self.0.physical_mut().set_flags(flags)
+    }
+
+    #[cfg(feature = "zip_with")]
+    fn zip_with_same_type(&self, mask: &BooleanChunked, other: &Series) -> PolarsResult<Series> {
+        let other = other.to_physical_repr();
+        let out = self
+            .physical_series()
+            .zip_with_same_type(mask, other.as_ref())?;
+        Ok(self.to_logical(out))
+    }
+
+    unsafe fn equal_element(&self, idx_self: usize, idx_other: usize, other: &Series) -> bool {
+        let other = other.to_physical_repr();
+        self.physical_series()
+            .equal_element(idx_self, idx_other, other.as_ref())
+    }
+
+    fn into_total_eq_inner<'a>(&'a self) -> Box<dyn TotalEqInner + 'a> {
+        self.0.physical().into_total_eq_inner()
+    }
+    fn into_total_ord_inner<'a>(&'a self) -> Box<dyn TotalOrdInner + 'a> {
+        self.0.physical().into_total_ord_inner()
+    }
+
+    fn vec_hash(
+        &self,
+        random_state: PlSeedableRandomStateQuality,
+        buf: &mut Vec<u64>,
+    ) -> PolarsResult<()> {
+        self.physical_series().vec_hash(random_state, buf)
+    }
+
+    fn vec_hash_combine(
+        &self,
+        build_hasher: PlSeedableRandomStateQuality,
+        hashes: &mut [u64],
+    ) -> PolarsResult<()> {
+        self.physical_series()
+            .vec_hash_combine(build_hasher, hashes)
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_sum(&self, groups: &GroupsType) -> Series {
+        self.try_agg_reduce(groups, DEC256_MAX_PREC, false, Self::sum)
+            .unwrap()
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn try_agg_sum(&self, groups: &GroupsType) -> PolarsResult<Series> {
+        self.try_agg_reduce(groups, DEC256_MAX_PREC, true, Self::sum)
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_min(&self, groups: &GroupsType) -> Series {
+        self.agg_reduce(groups, |l, r| l.min(r))
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_max(&self, groups: &GroupsType) -> Series {
+        self.agg_reduce(groups, |l, r| l.max(r))
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_list(&self, groups: &GroupsType) -> Series {
+        let list = self.physical_series().agg_list(groups);
+        let list = list.list().unwrap();
+        // The values stay physical, only the logical inner type changes.
+        ListChunked::from_chunks_and_dtype_unchecked(
+            list.name().clone(),
+            list.chunks().clone(),
+            DataType::List(Box::new(self.dtype().clone())),
+        )
+        .into_series()
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_var(&self, groups: &GroupsType, ddof: u8) -> Series {
+        self.0
+            .cast(&DataType::Float64)
+            .unwrap()
+            .agg_var(groups, ddof)
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_std(&self, groups: &GroupsType, ddof: u8) -> Series {
+        self.0
+            .cast(&DataType::Float64)
+            .unwrap()
+            .agg_std(groups, ddof)
+    }
+
+    fn subtract(&self, rhs: &Series) -> PolarsResult<Series> {
+        let rhs = rhs.decimal256()?;
+        ((&self.0) - rhs).map(|ca| ca.into_series())
+    }
+    fn add_to(&self, rhs: &Series) -> PolarsResult<Series> {
+        let rhs = rhs.decimal256()?;
+        ((&self.0) + rhs).map(|ca| ca.into_series())
+    }
+    fn multiply(&self, rhs: &Series) -> PolarsResult<Series> {
+        let rhs = rhs.decimal256()?;
+        ((&self.0) * rhs).map(|ca| ca.into_series())
+    }
+    fn divide(&self, rhs: &Series) -> PolarsResult<Series> {
+        let rhs = rhs.decimal256()?;
+        ((&self.0) / rhs).map(|ca| ca.into_series())
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn group_tuples(&self, multithreaded: bool, sorted: bool) -> PolarsResult<GroupsType> {
+        self.physical_series().group_tuples(multithreaded, sorted)
+    }
+
+    fn arg_sort_multiple(
+        &self,
+        by: &[Column],
+        options: &SortMultipleOptions,
+    ) -> PolarsResult<IdxCa> {
+        self.physical_series().arg_sort_multiple(by, options)
+    }
+}
+
+impl SeriesTrait for SeriesWrap<Decimal256Chunked> {
+    fn rename(&mut self, name: PlSmallStr) {
+        self.0.rename(name)
+    }
+
+    fn chunk_lengths(&self) -> ChunkLenIter<'_> {
+        self.0.physical().chunk_lengths()
+    }
+
+    fn name(&self) -> &PlSmallStr {
+        self.0.name()
+    }
+
+    fn chunks(&self) -> &Vec<ArrayRef> {
+        self.0.physical().chunks()
+    }
+    unsafe fn chunks_mut(&mut self) -> &mut Vec<ArrayRef> {
+        self.0.physical_mut().chunks_mut()
+    }
+
+    fn slice(&self, offset: i64, length: usize) -> Series {
+        self.0.slice(offset, length).into_series()
+    }
+
+    fn split_at(&self, offset: i64) -> (Series, Series) {
+        let (a, b) = self.0.split_at(offset);
+        (a.into_series(), b.into_series())
+    }
+
+    fn append(&mut self, other: &Series) -> PolarsResult<()> {
+        polars_ensure!(self.0.dtype() == other.dtype(), append);
+        let other = other.to_physical_repr();
+        self.0
+            .physical_mut()
+            .append(other.as_ref().as_ref().as_ref())
+    }
+    fn append_owned(&mut self, mut other: Series) -> PolarsResult<()> {
+        polars_ensure!(self.0.dtype() == other.dtype(), append);
+        self.0.physical_mut().append_owned(std::mem::take(
+            &mut other
+                ._get_inner_mut()
+                .as_any_mut()
+                .downcast_mut::<Decimal256Chunked>()
+                .unwrap()
+                .phys,
+        ))
+    }
+
+    fn extend(&mut self, other: &Series) -> PolarsResult<()> {
+        self.append(other)
+    }
+
+    fn filter(&self, filter: &BooleanChunked) -> PolarsResult<Series> {
+        Ok(self.to_logical(self.physical_series().filter(filter)?))
+    }
+
+    fn take(&self, indices: &IdxCa) -> PolarsResult<Series> {
+        Ok(self.to_logical(self.physical_series().take(indices)?))
+    }
+
+    unsafe fn take_unchecked(&self, indices: &IdxCa) -> Series {
+        self.to_logical(self.physical_series().take_unchecked(indices))
+    }
+
+    fn take_slice(&self, indices: &[IdxSize]) -> PolarsResult<Series> {
+        Ok(self.to_logical(self.physical_series().take_slice(indices)?))
+    }
+
+    unsafe fn take_slice_unchecked(&self, indices: &[IdxSize]) -> Series {
+        self.to_logical(self.physical_series().take_slice_unchecked(indices))
+    }
+
+    fn deposit(&self, validity: &Bitmap) -> Series {
+        self.to_logical(self.physical_series().deposit(validity))
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn rechunk(&self) -> Series {
+        self.to_logical(self.physical_series().rechunk())
+    }
+
+    fn new_from_index(&self, index: usize, length: usize) -> Series {
+        self.to_logical(self.physical_series().new_from_index(index, length))
+    }
+
+    fn cast(&self, dtype: &DataType, cast_options: CastOptions) -> PolarsResult<Series> {
+        self.0.cast_with_options(dtype, cast_options)
+    }
+
+    #[inline]
+    unsafe fn get_unchecked(&self, index: usize) -> AnyValue<'_> {
+        self.0.get_any_value_unchecked(index)
+    }
+
+    fn sort_with(&self, options: SortOptions) -> PolarsResult<Series> {
+        Ok(self.to_logical(self.physical_series().sort_with(options)?))
+    }
+
+    fn arg_sort(&self, options: SortOptions) -> IdxCa {
+        self.physical_series().arg_sort(options)
+    }
+
+    fn null_count(&self) -> usize {
+        self.0.null_count()
+    }
+
+    fn has_nulls(&self) -> bool {
+        self.0.has_nulls()
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn unique(&self) -> PolarsResult<Series> {
+        Ok(self.to_logical(self.physical_series().unique()?))
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn n_unique(&self) -> PolarsResult<usize> {
+        self.physical_series().n_unique()
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn arg_unique(&self) -> PolarsResult<IdxCa> {
+        self.physical_series().arg_unique()
+    }
+
+    fn unique_id(&self) -> PolarsResult<(IdxSize, Vec<IdxSize>)> {
+        self.physical_series().unique_id()
+    }
+
+    fn is_null(&self) -> BooleanChunked {
+        self.0.is_null()
+    }
+
+    fn is_not_null(&self) -> BooleanChunked {
+        self.0.is_not_null()
+    }
+
+    fn reverse(&self) -> Series {
+        self.to_logical(self.physical_series().reverse())
+    }
+
+    fn shift(&self, periods: i64) -> Series {
+        self.to_logical(self.physical_series().shift(periods))
+    }
+
+    fn clone_inner(&self) -> Arc<dyn SeriesTrait> {
+        Arc::new(SeriesWrap(Clone::clone(&self.0)))
+    }
+
+    fn sum_reduce(&self) -> PolarsResult<Scalar> {
+        let sum = self
+            .0
+            .physical()
+            .downcast_iter()
+            .flat_map(|arr| arr.iter().flatten().copied())
+            .try_fold(i256::default(), Self::sum)
+            .ok_or_else(|| polars_err!(ComputeError: "overflow in Decimal256 sum"))?;
+        let scale = self.0.scale();
+        Ok(Scalar::new(
+            DataType::Decimal256(DEC256_MAX_PREC, scale),
+            AnyValue::Decimal256(sum, DEC256_MAX_PREC, scale),
+        ))
+    }
+
+    fn min_reduce(&self) -> PolarsResult<Scalar> {
+        Ok(self.reduce(|l, r| l.min(r)))
+    }
+
+    fn max_reduce(&self) -> PolarsResult<Scalar> {
+        Ok(self.reduce(|l, r| l.max(r)))
+    }
+
+    fn _sum_as_f64(&self) -> f64 {
+        self.0
+            .physical()
+            .downcast_iter()
+            .flat_map(|arr| arr.iter().flatten())
+            .map(|v| dec256_to_f64(*v, self.0.scale()))
+            .sum()
+    }
+
+    fn mean(&self) -> Option<f64> {
+        let n = self.0.len() - self.0.null_count();
+        (n > 0).then(|| self._sum_as_f64() / n as f64)
+    }
+
+    fn mean_reduce(&self) -> PolarsResult<Scalar> {
+        Ok(Scalar::new(DataType::Float64, self.mean().into()))
+    }
+
+    fn median(&self) -> Option<f64> {
+        self.0.cast(&DataType::Float64).ok()?.median()
+    }
+
+    fn median_reduce(&self) -> PolarsResult<Scalar> {
+        self.0.cast(&DataType::Float64)?.median_reduce()
+    }
+
+    fn std(&self, ddof: u8) -> Option<f64> {
+        self.0.cast(&DataType::Float64).ok()?.std(ddof)
+    }
+
+    fn std_reduce(&self, ddof: u8) -> PolarsResult<Scalar> {
+        self.0.cast(&DataType::Float64)?.std_reduce(ddof)
+    }
+
+    fn var(&self, ddof: u8) -> Option<f64> {
+        self.0.cast(&DataType::Float64).ok()?.var(ddof)
+    }
+
+    fn var_reduce(&self, ddof: u8) -> PolarsResult<Scalar> {
+        self.0.cast(&DataType::Float64)?.var_reduce(ddof)
+    }
+
+    fn find_validity_mismatch(&self, other: &Series, idxs: &mut Vec<IdxSize>) {
+        self.0.physical().find_validity_mismatch(other, idxs)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        &self.0
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        &mut self.0
+    }
+
+    fn as_phys_any(&self) -> &dyn Any {
+        self.0.physical()
+    }
+
+    fn as_arc_any(self: Arc<Self>) -> Arc<dyn Any + Send + Sync> {
+        self as _
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_decimal256_sum_overflow() -> PolarsResult<()> {
+        let max = "9".repeat(DEC256_MAX_PREC);
+        let s =
+            Series::new("a".into(), ["1.5", "2.25"]).strict_cast(&DataType::Decimal256(10, 2))?;
+        let sum = s.sum_reduce()?;
+        assert_eq!(sum.dtype(), &DataType::Decimal256(DEC256_MAX_PREC, 2));
+        assert_eq!(sum.value().extract::<f64>(), Some(3.75));
+
+        let s = Series::new("a".into(), [max.as_str(), "1", "2"])
+            .strict_cast(&DataType::Decimal256(DEC256_MAX_PREC, 0))?;
+        assert!(s.sum_reduce().is_err());
+
+        #[cfg(feature = "algorithm_group_by")]
+        {
+            let groups = GroupsType::new_slice(vec![[0, 2], [1, 2]], true, true);
+            assert!(unsafe { s.try_agg_sum(&groups) }.is_err());
+            let out = unsafe { s.agg_sum(&groups) };
+            assert_eq!(out.dtype(), &DataType::Decimal256(DEC256_MAX_PREC, 0));
+            assert_eq!(out.null_count(), 1);
+            assert_eq!(out.str_value(1)?, "3");
+        }
+        Ok(())
+    }
+}
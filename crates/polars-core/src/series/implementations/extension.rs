@@ -57,7 +57,7 @@ impl private::PrivateSeries for SeriesWrap<ExtensionChunked> {
         build_hasher: PlSeedableRandomStateQuality,
         buf: &mut Vec<u64>,
     ) -> PolarsResult<()> {
-        self.0.storage().vec_hash(build_hasher, buf)
+        self.0.comparison_key()?.vec_hash(build_hasher, buf)
     }
 
     fn vec_hash_combine(
@@ -65,11 +65,13 @@ impl private::PrivateSeries for SeriesWrap<ExtensionChunked> {
         build_hasher: PlSeedableRandomStateQuality,
         hashes: &mut [u64],
     ) -> PolarsResult<()> {
-        self.0.storage().vec_hash_combine(build_hasher, hashes)
+        self.0
+            .comparison_key()?
+            .vec_hash_combine(build_hasher, hashes)
     }
 
     fn group_tuples(&self, multithreaded: bool, sorted: bool) -> PolarsResult<GroupsType> {
-        self.0.storage().group_tuples(multithreaded, sorted)
+        self.0.comparison_key()?.group_tuples(multithreaded, sorted)
     }
 
     fn zip_with_same_type(&self, mask: &BooleanChunked, other: &Series) -> PolarsResult<Series> {
@@ -90,7 +92,7 @@ impl private::PrivateSeries for SeriesWrap<ExtensionChunked> {
         by: &[Column],
         options: &SortMultipleOptions,
     ) -> PolarsResult<IdxCa> {
-        self.0.storage().arg_sort_multiple(by, options)
+        self.0.comparison_key()?.arg_sort_multiple(by, options)
     }
 }
 
@@ -198,10 +200,10 @@ impl SeriesTrait for SeriesWrap<ExtensionChunked> {
     }
 
     fn find_validity_mismatch(&self, other: &Series, idxs: &mut Vec<IdxSize>) {
-        assert!(self.0.dtype() == other.dtype());
+        // `other` can be the output of a cast to another extension type.
         self.0
             .storage()
-            .find_validity_mismatch(other.ext().unwrap().storage(), idxs)
+            .find_validity_mismatch(other.to_storage(), idxs)
     }
 
     fn cast(&self, dtype: &DataType, options: CastOptions) -> PolarsResult<Series> {
@@ -283,27 +285,40 @@ impl SeriesTrait for SeriesWrap<ExtensionChunked> {
     }
 
     fn sort_with(&self, options: SortOptions) -> PolarsResult<Series> {
-        self.try_apply_on_storage(|s| s.sort_with(options))
+        match self.0.comparison_key()? {
+            Cow::Borrowed(storage) => Ok(storage
+                .sort_with(options)?
+                .into_extension(self.0.extension_type().clone())),
+            Cow::Owned(key) => self.take(&key.arg_sort(options)),
+        }
     }
 
     fn arg_sort(&self, options: SortOptions) -> IdxCa {
-        self.0.storage().arg_sort(options)
+        // `arg_sort` can't fail, so an invalid comparison key falls back to the storage order.
+        // `sort_with` reports it as an error.
+        match self.0.comparison_key() {
+            Ok(key) => key.arg_sort(options),
+            Err(_) => self.0.storage().arg_sort(options),
+        }
     }
 
     fn unique(&self) -> PolarsResult<Series> {
-        self.try_apply_on_storage(|s| s.unique())
+        match self.0.comparison_key()? {
+            Cow::Borrowed(_) => self.try_apply_on_storage(|s| s.unique()),
+            Cow::Owned(key) => self.take(&key.arg_unique()?),
+        }
     }
 
     fn n_unique(&self) -> PolarsResult<usize> {
-        self.0.storage().n_unique()
+        self.0.comparison_key()?.n_unique()
     }
 
     fn arg_unique(&self) -> PolarsResult<IdxCa> {
-        self.0.storage().arg_unique()
+        self.0.comparison_key()?.arg_unique()
     }
 
     fn unique_id(&self) -> PolarsResult<(IdxSize, Vec<IdxSize>)> {
-        self.0.storage().unique_id()
+        self.0.comparison_key()?.unique_id()
     }
 
     fn as_single_ptr(&mut self) -> PolarsResult<usize> {
@@ -312,6 +327,6 @@ impl SeriesTrait for SeriesWrap<ExtensionChunked> {
 
     #[cfg(feature = "approx_unique")]
     fn approx_n_unique(&self) -> PolarsResult<IdxSize> {
-        self.0.storage().approx_n_unique()
+        self.0.comparison_key()?.approx_n_unique()
     }
 }
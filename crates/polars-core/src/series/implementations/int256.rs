@@ -0,0 +1,342 @@
+use arrow::array::BinaryArray;
+use arrow::types::NativeType;
+use polars_compute::decimal::DEC256_MAX_PREC;
+
+use super::*;
+#[cfg(feature = "algorithm_group_by")]
+use crate::frame::group_by::*;
+use crate::prelude::*;
+
+/// The physical array of [`DataType::Int256`] has no hashing, grouping or sorting kernels of its
+/// own. Instead we encode the values as big-endian bytes with the sign bit flipped, which sort
+/// and compare byte-wise exactly like the integers do, and dispatch to [`BinaryOffsetChunked`].
+fn to_ordered_binary(ca: &Int256Chunked) -> BinaryOffsetChunked {
+    let chunks = ca.downcast_iter().map(|arr| {
+        arr.iter()
+            .map(|opt_v| {
+                opt_v.map(|v| {
+                    let mut bytes = v.to_be_bytes();
+                    bytes[0] ^= 0x80;
+                    bytes
+                })
+            })
+            .collect::<BinaryArray<i64>>()
+    });
+    BinaryOffsetChunked::from_chunk_iter(ca.name().clone(), chunks)
+}
+
+impl private::PrivateSeries for SeriesWrap<Int256Chunked> {
+    fn compute_len(&mut self) {
+        self.0.compute_len()
+    }
+    fn _field(&self) -> Cow<'_, Field> {
+        Cow::Borrowed(self.0.ref_field())
+    }
+    fn _dtype(&self) -> &DataType {
+        self.0.ref_field().dtype()
+    }
+    fn _get_flags(&self) -> StatisticsFlags {
+        self.0.get_flags()
+    }
+    fn _set_flags(&mut self, flags: StatisticsFlags) {
+        self.0.set_flags(flags)
+    }
+
+    unsafe fn equal_element(&self, idx_self: usize, idx_other: usize, other: &Series) -> bool {
+        let other: &Int256Chunked = other.as_ref().as_ref();
+        self.0
+            .get_unchecked(idx_self)
+            .tot_eq(&other.get_unchecked(idx_other))
+    }
+
+    fn into_total_eq_inner<'a>(&'a self) -> Box<dyn TotalEqInner + 'a> {
+        (&self.0).into_total_eq_inner()
+    }
+    fn into_total_ord_inner<'a>(&'a self) -> Box<dyn TotalOrdInner + 'a> {
+        (&self.0).into_total_ord_inner()
+    }
+
+    fn vec_hash(
+        &self,
+        random_state: PlSeedableRandomStateQuality,
+        buf: &mut Vec<u64>,
+    ) -> PolarsResult<()> {
+        to_ordered_binary(&self.0).vec_hash(random_state, buf)?;
+        Ok(())
+    }
+
+    fn vec_hash_combine(
+        &self,
+        build_hasher: PlSeedableRandomStateQuality,
+        hashes: &mut [u64],
+    ) -> PolarsResult<()> {
+        to_ordered_binary(&self.0).vec_hash_combine(build_hasher, hashes)?;
+        Ok(())
+    }
+
+    #[cfg(feature = "zip_with")]
+    fn zip_with_same_type(&self, mask: &BooleanChunked, other: &Series) -> PolarsResult<Series> {
+        let other: &Int256Chunked = other.as_ref().as_ref();
+        let (self_len, other_len, mask_len) = (self.0.len(), other.len(), mask.len());
+        let len = self_len.max(other_len).max(mask_len);
+        polars_ensure!(
+            [self_len, other_len, mask_len].iter().all(|l| *l == len || *l == 1),
+            ShapeMismatch: "shapes of `self`, `mask` and `other` are not suitable for `zip_with` operation"
+        );
+
+        // Gather from `self` and `other` appended to each other, broadcasting unit lengths.
+        let broadcast = |n: usize, i: usize| if n == 1 { 0 } else { i as IdxSize };
+        let indices: Vec<IdxSize> = (0..len)
+            .map(|i| {
+                if mask.get(broadcast(mask_len, i) as usize) == Some(true) {
+                    broadcast(self_len, i)
+                } else {
+                    self_len as IdxSize + broadcast(other_len, i)
+                }
+            })
+            .collect();
+        let mut both = self.0.clone();
+        both.append(other)?;
+        Ok(both.take(indices.as_slice())?.into_series())
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    unsafe fn agg_list(&self, groups: &GroupsType) -> Series {
+        self.0.agg_list(groups)
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn group_tuples(&self, multithreaded: bool, sorted: bool) -> PolarsResult<GroupsType> {
+        IntoGroupsType::group_tuples(&to_ordered_binary(&self.0), multithreaded, sorted)
+    }
+
+    fn arg_sort_multiple(
+        &self,
+        by: &[Column],
+        options: &SortMultipleOptions,
+    ) -> PolarsResult<IdxCa> {
+        to_ordered_binary(&self.0).arg_sort_multiple(by, options)
+    }
+}
+
+impl SeriesTrait for SeriesWrap<Int256Chunked> {
+    fn rename(&mut self, name: PlSmallStr) {
+        self.0.rename(name);
+    }
+
+    fn chunk_lengths(&self) -> ChunkLenIter<'_> {
+        self.0.chunk_lengths()
+    }
+    fn name(&self) -> &PlSmallStr {
+        self.0.name()
+    }
+
+    fn chunks(&self) -> &Vec<ArrayRef> {
+        self.0.chunks()
+    }
+    unsafe fn chunks_mut(&mut self) -> &mut Vec<ArrayRef> {
+        self.0.chunks_mut()
+    }
+    fn shrink_to_fit(&mut self) {
+        self.0.shrink_to_fit()
+    }
+
+    fn slice(&self, offset: i64, length: usize) -> Series {
+        self.0.slice(offset, length).into_series()
+    }
+    fn split_at(&self, offset: i64) -> (Series, Series) {
+        let (a, b) = self.0.split_at(offset);
+        (a.into_series(), b.into_series())
+    }
+
+    fn append(&mut self, other: &Series) -> PolarsResult<()> {
+        polars_ensure!(self.0.dtype() == other.dtype(), append);
+        self.0.append(other.as_ref().as_ref())?;
+        Ok(())
+    }
+    fn append_owned(&mut self, other: Series) -> PolarsResult<()> {
+        polars_ensure!(self.0.dtype() == other.dtype(), append);
+        self.0.append_owned(other.take_inner())
+    }
+
+    fn extend(&mut self, other: &Series) -> PolarsResult<()> {
+        polars_ensure!(self.0.dtype() == other.dtype(), extend);
+        // There is no in-place extend kernel, appending the chunks is equivalent.
+        self.0.append(other.as_ref().as_ref())?;
+        Ok(())
+    }
+
+    fn filter(&self, filter: &BooleanChunked) -> PolarsResult<Series> {
+        ChunkFilter::filter(&self.0, filter).map(|ca| ca.into_series())
+    }
+
+    fn take(&self, indices: &IdxCa) -> PolarsResult<Series> {
+        Ok(self.0.take(indices)?.into_series())
+    }
+
+    unsafe fn take_unchecked(&self, indices: &IdxCa) -> Series {
+        self.0.take_unchecked(indices).into_series()
+    }
+
+    fn take_slice(&self, indices: &[IdxSize]) -> PolarsResult<Series> {
+        Ok(self.0.take(indices)?.into_series())
+    }
+
+    unsafe fn take_slice_unchecked(&self, indices: &[IdxSize]) -> Series {
+        self.0.take_unchecked(indices).into_series()
+    }
+
+    fn deposit(&self, validity: &Bitmap) -> Series {
+        self.0.deposit(validity).into_series()
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn rechunk(&self) -> Series {
+        self.0.rechunk().into_owned().into_series()
+    }
+
+    fn new_from_index(&self, index: usize, length: usize) -> Series {
+        let indices = vec![index as IdxSize; length];
+        self.0.take(indices.as_slice()).unwrap().into_series()
+    }
+
+    fn cast(&self, dtype: &DataType, options: CastOptions) -> PolarsResult<Series> {
+        match dtype {
+            DataType::Int256 => Ok(self.0.clone().into_series()),
+            _ => self
+                .0
+                .clone()
+                .into_decimal256_unchecked(DEC256_MAX_PREC, 0)
+                .cast_with_options(dtype, options),
+        }
+    }
+
+    #[inline]
+    unsafe fn get_unchecked(&self, index: usize) -> AnyValue<'_> {
+        self.0.get_any_value_unchecked(index)
+    }
+
+    fn sort_with(&self, options: SortOptions) -> PolarsResult<Series> {
+        let idx = self.arg_sort(options);
+        Ok(unsafe { self.0.take_unchecked(&idx) }.into_series())
+    }
+
+    fn arg_sort(&self, options: SortOptions) -> IdxCa {
+        ChunkSort::arg_sort(&to_ordered_binary(&self.0), options)
+    }
+
+    fn null_count(&self) -> usize {
+        self.0.null_count()
+    }
+
+    fn has_nulls(&self) -> bool {
+        self.0.has_nulls()
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn unique(&self) -> PolarsResult<Series> {
+        let idx = self.arg_unique()?;
+        Ok(unsafe { self.0.take_unchecked(&idx) }.into_series())
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn n_unique(&self) -> PolarsResult<usize> {
+        ChunkUnique::n_unique(&to_ordered_binary(&self.0))
+    }
+
+    #[cfg(feature = "algorithm_group_by")]
+    fn arg_unique(&self) -> PolarsResult<IdxCa> {
+        ChunkUnique::arg_unique(&to_ordered_binary(&self.0))
+    }
+
+    fn unique_id(&self) -> PolarsResult<(IdxSize, Vec<IdxSize>)> {
+        ChunkUnique::unique_id(&to_ordered_binary(&self.0))
+    }
+
+    fn is_null(&self) -> BooleanChunked {
+        self.0.is_null()
+    }
+
+    fn is_not_null(&self) -> BooleanChunked {
+        self.0.is_not_null()
+    }
+
+    fn reverse(&self) -> Series {
+        let indices: Vec<IdxSize> = (0..self.0.len() as IdxSize).rev().collect();
+        unsafe { self.0.take_unchecked(indices.as_slice()) }.into_series()
+    }
+
+    fn shift(&self, periods: i64) -> Series {
+        let len = self.0.len();
+        let fill_len = (periods.unsigned_abs() as usize).min(len);
+        let nulls = Int256Chunked::full_null(self.0.name().clone(), fill_len);
+        let mut out = if periods >= 0 {
+            let mut out = nulls;
+            out.append(&self.0.slice(0, len - fill_len)).unwrap();
+            out
+        } else {
+            let mut out = self.0.slice(fill_len as i64, len - fill_len);
+            out.append(&nulls).unwrap();
+            out
+        };
+        out.rename(self.0.name().clone());
+        out.into_series()
+    }
+
+    fn min_reduce(&self) -> PolarsResult<Scalar> {
+        let v = self
+            .0
+            .downcast_iter()
+            .flat_map(|arr| arr.iter().flatten().copied())
+            .min();
+        let av = v.map_or(AnyValue::Null, |v| {
+            AnyValue::Decimal256(v, DEC256_MAX_PREC, 0)
+        });
+        Ok(Scalar::new(DataType::Int256, av))
+    }
+
+    fn max_reduce(&self) -> PolarsResult<Scalar> {
+        let v = self
+            .0
+            .downcast_iter()
+            .flat_map(|arr| arr.iter().flatten().copied())
+            .max();
+        let av = v.map_or(AnyValue::Null, |v| {
+            AnyValue::Decimal256(v, DEC256_MAX_PREC, 0)
+        });
+        Ok(Scalar::new(DataType::Int256, av))
+    }
+
+    fn clone_inner(&self) -> Arc<dyn SeriesTrait> {
+        Arc::new(SeriesWrap(Clone::clone(&self.0)))
+    }
+
+    fn find_validity_mismatch(&self, other: &Series, idxs: &mut Vec<IdxSize>) {
+        self.0.find_validity_mismatch(other, idxs)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        &self.0
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        &mut self.0
+    }
+
+    fn as_phys_any(&self) -> &dyn Any {
+        &self.0
+    }
+
+    fn as_arc_any(self: Arc<Self>) -> Arc<dyn Any + Send + Sync> {
+        self as _
+    }
+}
+
+impl private::PrivateSeriesNumeric for SeriesWrap<Int256Chunked> {
+    fn bit_repr(&self) -> Option<BitRepr> {
+        None
+    }
+}
@@ -12,12 +12,20 @@ mod date;
 mod datetime;
 #[cfg(feature = "dtype-decimal")]
 mod decimal;
+#[cfg(feature = "dtype-decimal256")]
+mod decimal256;
 #[cfg(feature = "dtype-duration")]
 mod duration;
 #[cfg(feature = "dtype-extension")]
 mod extension;
 mod floats;
+#[cfg(feature = "dtype-decimal256")]
+mod int256;
+#[cfg(feature = "dtype-interval")]
+mod interval;
 mod list;
+#[cfg(feature = "dtype-map")]
+mod map;
 pub(crate) mod null;
 #[cfg(feature = "object")]
 mod object;
@@ -26,6 +34,8 @@ mod string;
 mod struct_;
 #[cfg(feature = "dtype-time")]
 mod time;
+#[cfg(feature = "dtype-variant")]
+mod variant;
 
 use std::any::Any;
 use std::borrow::Cow;
@@ -101,6 +101,51 @@ impl ToArrowConverter {
                 );
                 Box::new(arr)
             },
+            #[cfg(feature = "dtype-variant")]
+            DataType::Variant => {
+                let arr: &BinaryViewArray = array.as_any().downcast_ref().unwrap();
+                // The values were validated when the variants were created.
+                polars_compute::variant::variant_to_struct(arr)
+                    .unwrap()
+                    .boxed()
+            },
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(key, value) => {
+                use arrow::array::{MapArray, StructArray};
+                use arrow::offset::OffsetsBuffer;
+
+                let arr: &ListArray<i64> = array.as_any().downcast_ref().unwrap();
+                let entries = DataType::map_entries_dtype(*key.clone(), *value.clone());
+                let new_values = self.array_to_arrow(arr.values().as_ref(), &entries);
+                let new_values: &StructArray = new_values.as_any().downcast_ref().unwrap();
+
+                // Arrow requires the map keys to be non-nullable.
+                let ArrowDataType::Struct(mut fields) = new_values.dtype().clone() else {
+                    unreachable!()
+                };
+                fields[0].is_nullable = false;
+                let entries = StructArray::new(
+                    ArrowDataType::Struct(fields),
+                    new_values.len(),
+                    new_values.values().to_vec(),
+                    new_values.validity().cloned(),
+                );
+
+                MapArray::new(
+                    ArrowDataType::Map(
+                        Box::new(ArrowField::new(
+                            PlSmallStr::from_static("entries"),
+                            entries.dtype().clone(),
+                            false,
+                        )),
+                        false,
+                    ),
+                    OffsetsBuffer::<i32>::try_from(arr.offsets()).unwrap(),
+                    entries.boxed(),
+                    arr.validity().cloned(),
+                )
+                .boxed()
+            },
             #[cfg(feature = "dtype-array")]
             DataType::Array(inner, width) => {
                 use arrow::array::FixedSizeListArray;
@@ -144,6 +189,24 @@ impl ToArrowConverter {
             DataType::Time => {
                 cast_default(array, &DataType::Time.to_arrow(self.compat_level)).unwrap()
             },
+            #[cfg(feature = "dtype-interval")]
+            DataType::Interval => {
+                let arr = array
+                    .as_any()
+                    .downcast_ref::<arrow::array::PrimitiveArray<i128>>()
+                    .unwrap();
+                let values = arr
+                    .values()
+                    .iter()
+                    .map(|v| interval_from_i128(*v))
+                    .collect::<Vec<_>>();
+                arrow::array::PrimitiveArray::new(
+                    dtype.to_arrow(self.compat_level),
+                    values.into(),
+                    arr.validity().cloned(),
+                )
+                .boxed()
+            },
             #[cfg(feature = "dtype-decimal")]
             DataType::Decimal(_, _) => array
                 .as_any()
@@ -152,6 +215,14 @@ impl ToArrowConverter {
                 .clone()
                 .to(dtype.to_arrow(CompatLevel::newest()))
                 .to_boxed(),
+            #[cfg(feature = "dtype-decimal256")]
+            DataType::Decimal256(_, _) => array
+                .as_any()
+                .downcast_ref::<arrow::array::PrimitiveArray<i256>>()
+                .unwrap()
+                .clone()
+                .to(dtype.to_arrow(CompatLevel::newest()))
+                .to_boxed(),
             #[cfg(feature = "object")]
             DataType::Object(_) => {
                 use crate::chunked_array::object::builder::object_series_to_arrow_array;
@@ -181,7 +252,16 @@ impl ToArrowConverter {
             DataType::Extension(typ, storage_dtype) => {
                 use arrow::datatypes::ExtensionType;
 
-                let mut arr = self.array_to_arrow(array, storage_dtype);
+                let mut arr = match storage_dtype.as_ref() {
+                    #[cfg(feature = "dtype-uuid")]
+                    DataType::Binary if crate::datatypes::extension::is_uuid(dtype) => {
+                        crate::datatypes::extension::uuid_to_fixed_size_binary(
+                            array.as_any().downcast_ref().unwrap(),
+                        )
+                        .boxed()
+                    },
+                    _ => self.array_to_arrow(array, storage_dtype),
+                };
                 *arr.dtype_mut() = ArrowDataType::Extension(Box::new(ExtensionType {
                     name: typ.name().into(),
                     metadata: typ.serialize_metadata().map(|md| md.into()),
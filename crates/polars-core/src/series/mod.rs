@@ -462,6 +462,20 @@ impl Series {
             return Ok(Series::full_null(slf.name().clone(), len, dtype));
         }
 
+        #[cfg(feature = "dtype-extension")]
+        if let D::Extension(typ, storage) = dtype
+            && !slf.dtype().is_extension()
+            && let Some(out) = typ.0.cast_from(&slf, storage, options)
+        {
+            let out = out?;
+            polars_ensure!(
+                out.dtype() == storage.as_ref(),
+                ComputeError: "cast to extension type {} returned {:?}, expected {:?}",
+                dtype, out.dtype(), storage
+            );
+            return Ok(out.into_extension(typ.clone()));
+        }
+
         let new_options = match options {
             // Strictness is handled on this level to improve error messages.
             CastOptions::Strict => CastOptions::NonStrict,
@@ -518,6 +532,14 @@ impl Series {
                     .into_decimal_unchecked(*precision, *scale)
                     .into_series())
             },
+            #[cfg(feature = "dtype-decimal256")]
+            (D::Int256, D::Decimal256(precision, scale)) => {
+                let ca = self.i256().unwrap();
+                Ok(ca
+                    .clone()
+                    .into_decimal256_unchecked(*precision, *scale)
+                    .into_series())
+            },
 
             #[cfg(feature = "dtype-categorical")]
             (phys, D::Categorical(cats, _)) if &cats.physical().dtype() == phys => {
@@ -553,6 +575,15 @@ impl Series {
                 feature_gated!("dtype-duration", Ok(self.clone().into_duration(*tu)))
             },
             (D::Int64, D::Time) => feature_gated!("dtype-time", Ok(self.clone().into_time())),
+            #[cfg(feature = "dtype-interval")]
+            (D::Int128, D::Interval) => {
+                Ok(self.i128().unwrap().clone().into_interval().into_series())
+            },
+            #[cfg(feature = "dtype-variant")]
+            (D::Binary, D::Variant) => Ok(unsafe {
+                VariantChunked::new_logical(self.binary().unwrap().clone(), D::Variant)
+            }
+            .into_series()),
 
             (D::List(_), D::List(to)) => unsafe {
                 self.list()
@@ -560,6 +591,12 @@ impl Series {
                     .from_physical_unchecked(to.as_ref().clone())
                     .map(|ca| ca.into_series())
             },
+            #[cfg(feature = "dtype-map")]
+            (D::List(_), D::Map(key, value)) => unsafe {
+                let entries = DataType::map_entries_dtype(*key.clone(), *value.clone());
+                let ca = self.list().unwrap().from_physical_unchecked(entries)?;
+                Ok(MapChunked::new_logical(ca, dtype.clone()).into_series())
+            },
             #[cfg(feature = "dtype-array")]
             (D::Array(_, lw), D::Array(to, rw)) if lw == rw => unsafe {
                 self.array()
@@ -731,11 +768,13 @@ impl Series {
     /// * Datetime -> Int64
     /// * Duration -> Int64
     /// * Decimal -> Int128
+    /// * Decimal256 -> Int256
     /// * Time -> Int64
     /// * Categorical -> U8/U16/U32
     /// * List(inner) -> List(physical of inner)
     /// * Array(inner) -> Array(physical of inner)
     /// * Struct -> Struct with physical repr of each struct column
+    /// * Map(key, value) -> List(Struct{key, value}) with physical repr of key and value
     /// * Extension -> physical of storage type
     pub fn to_physical_repr(&self) -> Cow<'_, Series> {
         use DataType::*;
@@ -750,6 +789,18 @@ impl Series {
             Duration(_) => Cow::Owned(self.duration().unwrap().phys.clone().into_series()),
             #[cfg(feature = "dtype-time")]
             Time => Cow::Owned(self.time().unwrap().phys.clone().into_series()),
+            #[cfg(feature = "dtype-interval")]
+            Interval => Cow::Owned(self.interval().unwrap().phys.clone().into_series()),
+            #[cfg(feature = "dtype-variant")]
+            Variant => Cow::Owned(self.variant().unwrap().phys.clone().into_series()),
+            #[cfg(feature = "dtype-map")]
+            Map(_, _) => {
+                let phys = self.map().unwrap().phys.clone();
+                Cow::Owned(match phys.to_physical_repr() {
+                    Cow::Borrowed(_) => phys.into_series(),
+                    Cow::Owned(ca) => ca.into_series(),
+                })
+            },
             #[cfg(feature = "dtype-categorical")]
             dt @ (Categorical(_, _) | Enum(_, _)) => {
                 with_match_categorical_physical_type!(dt.cat_physical().unwrap(), |$C| {
@@ -759,6 +810,8 @@ impl Series {
             },
             #[cfg(feature = "dtype-decimal")]
             Decimal(_, _) => Cow::Owned(self.decimal().unwrap().phys.clone().into_series()),
+            #[cfg(feature = "dtype-decimal256")]
+            Decimal256(_, _) => Cow::Owned(self.decimal256().unwrap().phys.clone().into_series()),
             List(_) => match self.list().unwrap().to_physical_repr() {
                 Cow::Borrowed(_) => Cow::Borrowed(self),
                 Cow::Owned(ca) => Cow::Owned(ca.into_series()),
@@ -791,6 +844,18 @@ impl Series {
         self
     }
 
+    /// If the Series is an Extension type, return the key its values are compared, hashed and
+    /// grouped by. Otherwise, return itself.
+    pub fn to_comparison_key(&self) -> PolarsResult<Cow<'_, Series>> {
+        #[cfg(feature = "dtype-extension")]
+        {
+            if let DataType::Extension(_, _) = self.dtype() {
+                return self.ext().unwrap().comparison_key();
+            }
+        }
+        Ok(Cow::Borrowed(self))
+    }
+
     /// Traverse and collect every nth element in a new array.
     pub fn gather_every(&self, n: usize, offset: usize) -> PolarsResult<Series> {
         polars_ensure!(n > 0, ComputeError: "cannot perform gather every for `n=0`");
@@ -970,7 +1035,19 @@ impl Series {
 
     // used for formatting
     pub fn str_value(&self, index: usize) -> PolarsResult<Cow<'_, str>> {
-        Ok(self.0.get(index)?.str_value())
+        let av = self.0.get(index)?;
+        #[cfg(feature = "dtype-variant")]
+        if let (DataType::Variant, AnyValue::Binary(v)) = (self.dtype(), &av) {
+            let v = polars_compute::variant::VariantRef::try_new(v)?;
+            return Ok(Cow::Owned(v.to_json()?));
+        }
+        #[cfg(feature = "dtype-extension")]
+        if let DataType::Extension(typ, _) = self.dtype()
+            && let Some(s) = typ.format_value(&av)
+        {
+            return Ok(Cow::Owned(s));
+        }
+        Ok(av.str_value())
     }
     /// Get the head of the Series.
     pub fn head(&self, length: Option<usize>) -> Series {
@@ -1238,6 +1315,39 @@ mod test {
         }
     }
 
+    #[test]
+    #[cfg(feature = "dtype-decimal256")]
+    fn series_decimal256_cast_and_arithmetic() {
+        let s = Series::new(
+            "a".into(),
+            &["1.25", "123456789012345678901234567890123456789.5"],
+        )
+        .cast(&DataType::Decimal256(60, 2))
+        .unwrap();
+        assert_eq!(s.dtype(), &DataType::Decimal256(60, 2));
+        assert_eq!(
+            s.get(0).unwrap(),
+            AnyValue::Decimal256(i256::from_words(0, 125), 60, 2)
+        );
+
+        // The wide value doesn't fit in a 128-bit decimal.
+        let narrow = s.cast(&DataType::Decimal(38, 2)).unwrap();
+        assert_eq!(narrow.get(0).unwrap(), AnyValue::Decimal(125, 38, 2));
+        assert_eq!(narrow.get(1).unwrap(), AnyValue::Null);
+        assert!(s.strict_cast(&DataType::Decimal(38, 2)).is_err());
+
+        let sum = (&s + &s).unwrap();
+        assert_eq!(sum.dtype(), &DataType::Decimal256(76, 2));
+        assert_eq!(
+            sum.get(0).unwrap(),
+            AnyValue::Decimal256(i256::from_words(0, 250), 76, 2)
+        );
+        assert_eq!(
+            format!("{}", sum.get(1).unwrap()),
+            "246913578024691357802469135780246913579.00"
+        );
+    }
+
     #[test]
     fn series_slice_works() {
         let series = Series::new("a".into(), &[1i64, 2, 3, 4, 5]);
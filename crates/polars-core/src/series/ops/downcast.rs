@@ -68,6 +68,12 @@ impl Series {
         try_unpack_chunked!(self, DataType::Int128 => Int128Chunked)
     }
 
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Int256`]
+    #[cfg(feature = "dtype-decimal256")]
+    pub fn try_i256(&self) -> Option<&Int256Chunked> {
+        try_unpack_chunked!(self, DataType::Int256 => Int256Chunked)
+    }
+
     /// Unpack to [`ChunkedArray`] of dtype [`DataType::Float16`]
     #[cfg(feature = "dtype-f16")]
     pub fn try_f16(&self) -> Option<&Float16Chunked> {
@@ -154,12 +160,36 @@ impl Series {
         try_unpack_chunked!(self, DataType::Duration(_) => DurationChunked)
     }
 
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Interval`]
+    #[cfg(feature = "dtype-interval")]
+    pub fn try_interval(&self) -> Option<&IntervalChunked> {
+        try_unpack_chunked!(self, DataType::Interval => IntervalChunked)
+    }
+
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Variant`]
+    #[cfg(feature = "dtype-variant")]
+    pub fn try_variant(&self) -> Option<&VariantChunked> {
+        try_unpack_chunked!(self, DataType::Variant => VariantChunked)
+    }
+
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Map`]
+    #[cfg(feature = "dtype-map")]
+    pub fn try_map(&self) -> Option<&MapChunked> {
+        try_unpack_chunked!(self, DataType::Map(_, _) => MapChunked)
+    }
+
     /// Unpack to [`ChunkedArray`] of dtype [`DataType::Decimal`]
     #[cfg(feature = "dtype-decimal")]
     pub fn try_decimal(&self) -> Option<&DecimalChunked> {
         try_unpack_chunked!(self, DataType::Decimal(_, _) => DecimalChunked)
     }
 
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Decimal256`]
+    #[cfg(feature = "dtype-decimal256")]
+    pub fn try_decimal256(&self) -> Option<&Decimal256Chunked> {
+        try_unpack_chunked!(self, DataType::Decimal256(_, _) => Decimal256Chunked)
+    }
+
     /// Unpack to [`ChunkedArray`] of dtype list
     pub fn try_list(&self) -> Option<&ListChunked> {
         try_unpack_chunked!(self, DataType::List(_) => ListChunked)
@@ -260,6 +290,13 @@ impl Series {
             .ok_or_else(|| unpack_chunked_err!(self => "Int128"))
     }
 
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Int256`]
+    #[cfg(feature = "dtype-decimal256")]
+    pub fn i256(&self) -> PolarsResult<&Int256Chunked> {
+        self.try_i256()
+            .ok_or_else(|| unpack_chunked_err!(self => "Int256"))
+    }
+
     /// Unpack to [`ChunkedArray`] of dtype [`DataType::Float16`]
     #[cfg(feature = "dtype-f16")]
     pub fn f16(&self) -> PolarsResult<&Float16Chunked> {
@@ -362,6 +399,27 @@ impl Series {
             .ok_or_else(|| unpack_chunked_err!(self => "Duration"))
     }
 
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Interval`]
+    #[cfg(feature = "dtype-interval")]
+    pub fn interval(&self) -> PolarsResult<&IntervalChunked> {
+        self.try_interval()
+            .ok_or_else(|| unpack_chunked_err!(self => "Interval"))
+    }
+
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Variant`]
+    #[cfg(feature = "dtype-variant")]
+    pub fn variant(&self) -> PolarsResult<&VariantChunked> {
+        self.try_variant()
+            .ok_or_else(|| unpack_chunked_err!(self => "Variant"))
+    }
+
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Map`]
+    #[cfg(feature = "dtype-map")]
+    pub fn map(&self) -> PolarsResult<&MapChunked> {
+        self.try_map()
+            .ok_or_else(|| unpack_chunked_err!(self => "Map"))
+    }
+
     /// Unpack to [`ChunkedArray`] of dtype [`DataType::Decimal`]
     #[cfg(feature = "dtype-decimal")]
     pub fn decimal(&self) -> PolarsResult<&DecimalChunked> {
@@ -369,6 +427,13 @@ impl Series {
             .ok_or_else(|| unpack_chunked_err!(self => "Decimal"))
     }
 
+    /// Unpack to [`ChunkedArray`] of dtype [`DataType::Decimal256`]
+    #[cfg(feature = "dtype-decimal256")]
+    pub fn decimal256(&self) -> PolarsResult<&Decimal256Chunked> {
+        self.try_decimal256()
+            .ok_or_else(|| unpack_chunked_err!(self => "Decimal256"))
+    }
+
     /// Unpack to [`ChunkedArray`] of dtype list
     pub fn list(&self) -> PolarsResult<&ListChunked> {
         self.try_list()
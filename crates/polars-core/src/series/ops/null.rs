@@ -40,6 +40,23 @@ impl Series {
             DataType::Duration(tu) => Int64Chunked::full_null(name, size)
                 .into_duration(*tu)
                 .into_series(),
+            #[cfg(feature = "dtype-interval")]
+            DataType::Interval => Int128Chunked::full_null(name, size)
+                .into_interval()
+                .into_series(),
+            #[cfg(feature = "dtype-variant")]
+            DataType::Variant => {
+                let phys = BinaryChunked::full_null(name, size);
+                // SAFETY: a fully null array holds no invalid variants.
+                unsafe { VariantChunked::new_logical(phys, DataType::Variant) }.into_series()
+            },
+            #[cfg(feature = "dtype-map")]
+            DataType::Map(key, value) => {
+                let entries = DataType::map_entries_dtype(*key.clone(), *value.clone());
+                let phys = ListChunked::full_null_with_dtype(name, size, &entries);
+                // SAFETY: the entries have the `{key, value}` layout.
+                unsafe { MapChunked::new_logical(phys, dtype.clone()) }.into_series()
+            },
             #[cfg(feature = "dtype-time")]
             DataType::Time => Int64Chunked::full_null(name, size)
                 .into_time()
@@ -48,6 +65,12 @@ impl Series {
             DataType::Decimal(precision, scale) => Int128Chunked::full_null(name, size)
                 .into_decimal_unchecked(*precision, *scale)
                 .into_series(),
+            #[cfg(feature = "dtype-decimal256")]
+            DataType::Int256 => Int256Chunked::full_null(name, size).into_series(),
+            #[cfg(feature = "dtype-decimal256")]
+            DataType::Decimal256(precision, scale) => Int256Chunked::full_null(name, size)
+                .into_decimal256_unchecked(*precision, *scale)
+                .into_series(),
             #[cfg(feature = "dtype-struct")]
             DataType::Struct(fields) => {
                 let fields = fields
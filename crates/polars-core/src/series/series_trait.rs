@@ -137,6 +137,16 @@ pub(crate) mod private {
         unsafe fn agg_sum(&self, groups: &GroupsType) -> Series {
             Series::full_null(self._field().name().clone(), groups.len(), self._dtype())
         }
+        /// Like [`agg_sum`](Self::agg_sum), but raises an error where the sum overflows
+        /// instead of producing a null.
+        ///
+        /// # Safety
+        ///
+        /// Does no bounds checks, groups must be correct.
+        #[cfg(feature = "algorithm_group_by")]
+        unsafe fn try_agg_sum(&self, groups: &GroupsType) -> PolarsResult<Series> {
+            Ok(unsafe { self.agg_sum(groups) })
+        }
         /// # Safety
         ///
         /// Does no bounds checks, groups must be correct.
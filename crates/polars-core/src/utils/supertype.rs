@@ -2,6 +2,8 @@ use bitflags::bitflags;
 use num_traits::Signed;
 #[cfg(feature = "dtype-decimal")]
 use polars_compute::decimal::{DEC128_MAX_PREC, i128_to_dec128};
+#[cfg(feature = "dtype-decimal256")]
+use polars_compute::decimal::{DEC256_MAX_PREC, i128_to_dec256};
 
 use super::*;
 
@@ -398,6 +400,8 @@ pub fn get_supertype_with_options(
             (Duration(_), Date) | (Date, Duration(_)) => Some(Date),
             #[cfg(feature = "dtype-duration")]
             (Duration(lu), Duration(ru)) => Some(Duration(get_time_units(lu, ru))),
+            #[cfg(feature = "dtype-interval")]
+            (Duration(_), Interval) | (Interval, Duration(_)) => Some(Interval),
 
             // both None or both Some("<tz>") timezones
             // we cast from more precision to higher precision as that always fits with occasional loss of precision
@@ -414,6 +418,12 @@ pub fn get_supertype_with_options(
                 let st = get_supertype(inner_left, inner_right)?;
                 Some(List(Box::new(st)))
             }
+            #[cfg(feature = "dtype-map")]
+            (Map(key_left, value_left), Map(key_right, value_right)) => {
+                let key = get_supertype(key_left, key_right)?;
+                let value = get_supertype(value_left, value_right)?;
+                Some(Map(Box::new(key), Box::new(value)))
+            }
             #[cfg(feature = "dtype-array")]
             (List(inner_left), Array(inner_right, _)) | (Array(inner_left, _), List(inner_right)) => {
                 let st = get_supertype(inner_left, inner_right)?;
@@ -535,6 +545,40 @@ pub fn get_supertype_with_options(
                     Some(Decimal(DEC128_MAX_PREC, *scale))
                 }
             }
+            #[cfg(feature = "dtype-decimal256")]
+            (Decimal256(p1, s1), Decimal256(p2, s2)) => {
+                Some(Decimal256((*p1).max(*p2), (*s1).max(*s2)))
+            },
+            #[cfg(feature = "dtype-decimal256")]
+            (Decimal256(p1, s1), Decimal(p2, s2)) => {
+                Some(Decimal256((*p1).max(*p2), (*s1).max(*s2)))
+            },
+            #[cfg(all(feature = "dtype-decimal256", feature = "dtype-f16"))]
+            (Decimal256(_, _), Float16) => Some(Float64),
+            #[cfg(feature = "dtype-decimal256")]
+            (Decimal256(_, _), Float32 | Float64) => Some(Float64),
+            #[cfg(feature = "dtype-decimal256")]
+            (Decimal256(prec, scale), dt) if dt.is_signed_integer() || dt.is_unsigned_integer() => {
+                let fits = |v| { i128_to_dec256(v, *prec, *scale).is_some() };
+                let fits_orig_prec_scale = match dt {
+                    UInt8 => fits(u8::MAX as i128),
+                    UInt16 => fits(u16::MAX as i128),
+                    UInt32 => fits(u32::MAX as i128),
+                    UInt64 => fits(u64::MAX as i128),
+                    Int8 => fits(i8::MAX as i128),
+                    Int16 => fits(i16::MAX as i128),
+                    Int32 => fits(i32::MAX as i128),
+                    Int64 => fits(i64::MAX as i128),
+                    // Any 128-bit integer fits in 39 digits.
+                    UInt128 | Int128 => *prec - *scale >= 39,
+                    _ => unreachable!(),
+                };
+                if fits_orig_prec_scale {
+                    Some(Decimal256(*prec, *scale))
+                } else {
+                    Some(Decimal256(DEC256_MAX_PREC, *scale))
+                }
+            }
             _ => None,
         }
     }
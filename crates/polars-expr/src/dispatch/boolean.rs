@@ -125,6 +125,15 @@ fn is_between(s: &[Column], closed: polars_ops::series::ClosedInterval) -> Polar
 fn is_in(s: &mut [Column], nulls_equal: bool) -> PolarsResult<Column> {
     let left = &s[0];
     let other = &s[1];
+    if other.len() == 1 {
+        // This is elementwise in `left`, so e.g. dictionary columns only evaluate their values.
+        let other = other.as_materialized_series();
+        return left
+            .try_map_unary_elementwise_to_bool(|left| {
+                polars_ops::prelude::is_in(left, other, nulls_equal)
+            })
+            .map(IntoColumn::into_column);
+    }
     polars_ops::prelude::is_in(
         left.as_materialized_series(),
         other.as_materialized_series(),
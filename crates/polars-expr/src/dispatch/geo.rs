@@ -0,0 +1,33 @@
+use std::sync::Arc;
+
+use polars_core::error::PolarsResult;
+use polars_core::prelude::*;
+use polars_ops::series::{
+    geo_area, geo_bbox, geo_centroid, geo_contains, geo_distance, geo_from_wkt, geo_intersects,
+    geo_length, geo_to_wkt,
+};
+use polars_plan::dsl::{ColumnsUdf, SpecialEq};
+use polars_plan::plans::IRGeoFunction;
+
+pub fn function_expr_to_udf(func: IRGeoFunction) -> SpecialEq<Arc<dyn ColumnsUdf>> {
+    use IRGeoFunction::*;
+    match func {
+        Area => map!(unary, geo_area),
+        Length => map!(unary, geo_length),
+        Centroid => map!(unary, geo_centroid),
+        Bbox => map!(unary, geo_bbox),
+        Distance => map_as_slice!(binary, geo_distance),
+        Contains => map_as_slice!(binary, geo_contains),
+        Intersects => map_as_slice!(binary, geo_intersects),
+        ToWkt => map!(unary, geo_to_wkt),
+        FromWkt => map!(unary, geo_from_wkt),
+    }
+}
+
+fn unary(s: &Column, f: fn(&Series) -> PolarsResult<Series>) -> PolarsResult<Column> {
+    f(s.as_materialized_series()).map(Column::from)
+}
+
+fn binary(s: &[Column], f: fn(&Series, &Series) -> PolarsResult<Series>) -> PolarsResult<Column> {
+    f(s[0].as_materialized_series(), s[1].as_materialized_series()).map(Column::from)
+}
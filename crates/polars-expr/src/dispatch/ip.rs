@@ -0,0 +1,28 @@
+use std::sync::Arc;
+
+use polars_core::error::PolarsResult;
+use polars_core::prelude::*;
+use polars_ops::series::{ip_broadcast, ip_contains, ip_network};
+use polars_plan::dsl::{ColumnsUdf, SpecialEq};
+use polars_plan::plans::IRIpFunction;
+
+pub fn function_expr_to_udf(func: IRIpFunction) -> SpecialEq<Arc<dyn ColumnsUdf>> {
+    use IRIpFunction::*;
+    match func {
+        Contains(cidr) => map!(contains, &cidr),
+        Network(prefix_len) => map!(network, prefix_len),
+        Broadcast(prefix_len) => map!(broadcast, prefix_len),
+    }
+}
+
+fn contains(s: &Column, cidr: &str) -> PolarsResult<Column> {
+    ip_contains(s.as_materialized_series(), cidr).map(Column::from)
+}
+
+fn network(s: &Column, prefix_len: u8) -> PolarsResult<Column> {
+    ip_network(s.as_materialized_series(), prefix_len).map(Column::from)
+}
+
+fn broadcast(s: &Column, prefix_len: u8) -> PolarsResult<Column> {
+    ip_broadcast(s.as_materialized_series(), prefix_len).map(Column::from)
+}
@@ -0,0 +1,43 @@
+use std::sync::Arc;
+
+use polars_core::error::PolarsResult;
+use polars_core::prelude::*;
+use polars_ops::chunked_array::map::*;
+use polars_plan::dsl::{ColumnsUdf, SpecialEq};
+use polars_plan::plans::IRMapFunction;
+
+pub fn function_expr_to_udf(func: IRMapFunction) -> SpecialEq<Arc<dyn ColumnsUdf>> {
+    use IRMapFunction::*;
+    match func {
+        Get => map_as_slice!(get),
+        ContainsKey => map_as_slice!(contains_key),
+        Keys => map!(keys),
+        Values => map!(values),
+        FromLists => map_as_slice!(from_lists),
+    }
+}
+
+fn get(s: &[Column]) -> PolarsResult<Column> {
+    let ca = s[0].map()?;
+    ca.map_get(s[1].as_materialized_series()).map(Column::from)
+}
+
+fn contains_key(s: &[Column]) -> PolarsResult<Column> {
+    let ca = s[0].map()?;
+    ca.map_contains_key(s[1].as_materialized_series())
+        .map(IntoColumn::into_column)
+}
+
+fn keys(s: &Column) -> PolarsResult<Column> {
+    s.map()?.map_keys().map(IntoColumn::into_column)
+}
+
+fn values(s: &Column) -> PolarsResult<Column> {
+    s.map()?.map_values().map(IntoColumn::into_column)
+}
+
+fn from_lists(s: &[Column]) -> PolarsResult<Column> {
+    let keys = s[0].list()?;
+    let values = s[1].list()?;
+    map_from_lists(keys, values).map(|ca| ca.into_series().into_column())
+}
@@ -108,9 +108,15 @@ mod cum;
 mod datetime;
 #[cfg(feature = "dtype-extension")]
 mod extension;
+#[cfg(feature = "geo")]
+mod geo;
 mod groups_dispatch;
 mod horizontal;
+#[cfg(feature = "dtype-ip")]
+mod ip;
 mod list;
+#[cfg(feature = "dtype-map")]
+mod map_;
 mod misc;
 mod pow;
 #[cfg(feature = "random")]
@@ -132,6 +138,8 @@ pub(crate) mod struct_;
 mod temporal;
 #[cfg(feature = "trigonometry")]
 mod trigonometry;
+#[cfg(feature = "dtype-variant")]
+mod variant;
 
 pub use groups_dispatch::drop_items;
 
@@ -147,6 +155,14 @@ pub fn function_expr_to_udf(func: IRFunctionExpr) -> SpecialEq<Arc<dyn ColumnsUd
         #[cfg(feature = "dtype-extension")]
         F::Extension(func) => extension::function_expr_to_udf(func),
         F::ListExpr(func) => list::function_expr_to_udf(func),
+        #[cfg(feature = "dtype-map")]
+        F::MapExpr(func) => map_::function_expr_to_udf(func),
+        #[cfg(feature = "dtype-variant")]
+        F::VariantExpr(func) => variant::function_expr_to_udf(func),
+        #[cfg(feature = "geo")]
+        F::GeoExpr(func) => geo::function_expr_to_udf(func),
+        #[cfg(feature = "dtype-ip")]
+        F::IpExpr(func) => ip::function_expr_to_udf(func),
         #[cfg(feature = "strings")]
         F::StringExpr(func) => strings::function_expr_to_udf(func),
         #[cfg(feature = "dtype-struct")]
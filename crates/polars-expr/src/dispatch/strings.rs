@@ -244,8 +244,15 @@ pub(super) fn len_bytes(s: &Column) -> PolarsResult<Column> {
 #[cfg(feature = "regex")]
 pub(super) fn contains(s: &[Column], literal: bool, strict: bool) -> PolarsResult<Column> {
     _check_same_length(s, "contains")?;
-    let ca = s[0].str()?;
     let pat = s[1].str()?;
+    if pat.len() == 1 {
+        // This is elementwise in the strings, so e.g. dictionary columns only evaluate their
+        // values.
+        return s[0]
+            .try_map_unary_elementwise_to_bool(|s| s.str()?.contains_chunked(pat, literal, strict))
+            .map(|ok| ok.into_column());
+    }
+    let ca = s[0].str()?;
     ca.contains_chunked(pat, literal, strict)
         .map(|ok| ok.into_column())
 }
@@ -0,0 +1,31 @@
+use std::sync::Arc;
+
+use polars_core::error::PolarsResult;
+use polars_core::prelude::*;
+use polars_ops::chunked_array::variant::*;
+use polars_plan::dsl::{ColumnsUdf, SpecialEq};
+use polars_plan::plans::IRVariantFunction;
+
+pub fn function_expr_to_udf(func: IRVariantFunction) -> SpecialEq<Arc<dyn ColumnsUdf>> {
+    use IRVariantFunction::*;
+    match func {
+        Get { path, dtype } => map!(get, &path, &dtype),
+        TypeName => map!(type_name),
+        ToJson => map!(to_json),
+    }
+}
+
+fn get(s: &Column, path: &str, dtype: &DataType) -> PolarsResult<Column> {
+    let path = VariantPath::parse(path)?;
+    s.variant()?.variant_get(&path, dtype).map(Column::from)
+}
+
+fn type_name(s: &Column) -> PolarsResult<Column> {
+    s.variant()?
+        .variant_type_name()
+        .map(IntoColumn::into_column)
+}
+
+fn to_json(s: &Column) -> PolarsResult<Column> {
+    s.variant()?.variant_to_json().map(IntoColumn::into_column)
+}
@@ -246,7 +246,7 @@ impl PhysicalExpr for AggregationExpr {
                 },
                 GroupByMethod::Sum => {
                     let (c, groups) = ac.get_final_aggregation();
-                    let agg_c = c.agg_sum(&groups);
+                    let agg_c = c.try_agg_sum(&groups)?;
                     AggregatedScalar(agg_c.with_name(keep_name))
                 },
                 GroupByMethod::Count { include_nulls } => {
@@ -73,6 +73,8 @@ pub fn apply_operator(left: &Column, right: &Column, op: Operator) -> PolarsResu
         Operator::TrueDivide => match left.dtype() {
             #[cfg(feature = "dtype-decimal")]
             Decimal(_, _) => left / right,
+            #[cfg(feature = "dtype-decimal256")]
+            Decimal256(_, _) => left / right,
             #[cfg(feature = "dtype-f16")]
             Float16 => left / right,
             Duration(_) | Date | Datetime(_, _) | Float32 | Float64 => left / right,
@@ -315,8 +317,9 @@ impl PhysicalExpr for BinaryExpr {
         let mut aggregated = has_agg_list || has_agg_scalar;
 
         // Arithmetic on Decimal is fallible
+        let is_decimal = |dt: &DataType| dt.is_decimal() || dt.is_decimal256();
         let has_decimal_dtype =
-            ac_l.get_values().dtype().is_decimal() || ac_r.get_values().dtype().is_decimal();
+            is_decimal(ac_l.get_values().dtype()) || is_decimal(ac_r.get_values().dtype());
         let is_fallible = has_decimal_dtype && self.op.is_arithmetic();
 
         // Broadcast in NotAgg or AggList requires group_aware
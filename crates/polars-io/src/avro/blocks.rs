@@ -0,0 +1,199 @@
+//! Block-level access to Avro object container files.
+//!
+//! An Avro file consists of a header followed by data blocks that each carry their row count and
+//! byte size. This allows locating all blocks without decoding them, after which the blocks can be
+//! decoded independently of each other.
+
+use std::io::Cursor;
+use std::sync::Arc;
+
+use arrow::datatypes::ArrowSchemaRef;
+use arrow::io::avro::avro_schema::file::FileMetadata;
+use arrow::io::avro::avro_schema::read::fallible_streaming_iterator::FallibleStreamingIterator;
+use arrow::io::avro::avro_schema::read::{block_iterator, read_metadata};
+use arrow::io::avro::read::{deserialize, infer_schema};
+use arrow::record_batch::RecordBatch;
+use polars_core::error::to_compute_err;
+use polars_core::prelude::*;
+
+const SYNC_MARKER_LEN: usize = 16;
+
+/// Header of an Avro object container file.
+#[derive(Debug, Clone)]
+pub struct AvroFileMetadata {
+    pub metadata: FileMetadata,
+    pub schema: ArrowSchemaRef,
+    /// Byte offset of the first data block.
+    pub data_offset: usize,
+}
+
+impl AvroFileMetadata {
+    /// Reads the header from the start of `bytes`.
+    pub fn try_new(bytes: &[u8]) -> PolarsResult<Self> {
+        let mut reader = Cursor::new(bytes);
+        let metadata = read_metadata(&mut reader).map_err(to_compute_err)?;
+        let schema = Arc::new(infer_schema(&metadata.record)?);
+
+        Ok(Self {
+            metadata,
+            schema,
+            data_offset: usize::try_from(reader.position()).unwrap(),
+        })
+    }
+}
+
+/// Location of a single data block within an Avro file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AvroBlockSpan {
+    /// Byte offset of the start of the block header.
+    pub offset: usize,
+    /// Byte length of the block, including its header and trailing sync marker.
+    pub len: usize,
+    pub num_rows: usize,
+}
+
+/// Locates the data blocks of an Avro file without decoding them. Empty blocks are skipped.
+pub fn split_avro_blocks(
+    bytes: &[u8],
+    metadata: &AvroFileMetadata,
+) -> PolarsResult<Vec<AvroBlockSpan>> {
+    let mut blocks = vec![];
+    let mut offset = metadata.data_offset;
+
+    while offset < bytes.len() {
+        let (num_rows, num_rows_len) = read_zigzag_long(&bytes[offset..])?;
+        let (size, size_len) = read_zigzag_long(&bytes[offset + num_rows_len..])?;
+
+        let block_err =
+            || polars_err!(ComputeError: "invalid avro block at byte offset {}", offset);
+
+        let (Ok(num_rows), Ok(size)) = (usize::try_from(num_rows), usize::try_from(size)) else {
+            return Err(block_err());
+        };
+
+        let end = (offset + num_rows_len + size_len)
+            .checked_add(size)
+            .and_then(|x| x.checked_add(SYNC_MARKER_LEN))
+            .filter(|&end| end <= bytes.len())
+            .ok_or_else(block_err)?;
+
+        polars_ensure!(
+            bytes[end - SYNC_MARKER_LEN..end] == metadata.metadata.marker,
+            ComputeError: "avro sync marker mismatch for block at byte offset {}", offset
+        );
+
+        if num_rows > 0 {
+            blocks.push(AvroBlockSpan {
+                offset,
+                len: end - offset,
+                num_rows,
+            });
+        }
+
+        offset = end;
+    }
+
+    Ok(blocks)
+}
+
+/// Decodes a single data block of `bytes`.
+///
+/// `projection` must contain an entry for every field of the file schema. Only the columns set to
+/// `true` are decoded and returned.
+pub fn decode_avro_block(
+    bytes: &[u8],
+    span: AvroBlockSpan,
+    metadata: &AvroFileMetadata,
+    projection: &[bool],
+) -> PolarsResult<RecordBatch> {
+    let block_bytes = &bytes[span.offset..span.offset + span.len];
+
+    let mut blocks = block_iterator(
+        Cursor::new(block_bytes),
+        metadata.metadata.compression,
+        metadata.metadata.marker,
+    );
+
+    let block = blocks.next().map_err(to_compute_err)?.ok_or_else(
+        || polars_err!(ComputeError: "expected avro block at byte offset {}", span.offset),
+    )?;
+
+    deserialize(
+        block,
+        &metadata.schema,
+        &metadata.metadata.record.fields,
+        projection,
+    )
+}
+
+/// Decodes a zig-zag encoded variable-length `long`. Returns the value and the number of bytes
+/// it occupied.
+pub(crate) fn read_zigzag_long(bytes: &[u8]) -> PolarsResult<(i64, usize)> {
+    let mut value: u64 = 0;
+
+    for (i, &byte) in bytes.iter().take(10).enumerate() {
+        value |= u64::from(byte & 0x7F) << (i * 7);
+
+        if byte & 0x80 == 0 {
+            let value = ((value >> 1) as i64) ^ -((value & 1) as i64);
+            return Ok((value, i + 1));
+        }
+    }
+
+    polars_bail!(ComputeError: "invalid avro data: corrupt variable-length integer")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SerWriter;
+    use crate::avro::{AvroCompression, AvroWriter};
+
+    #[test]
+    fn test_split_and_decode_avro_blocks() -> PolarsResult<()> {
+        let a = Column::new("a".into(), (0..100i64).collect::<Vec<_>>());
+        let b = Column::new(
+            "b".into(),
+            (0..100).map(|i| format!("{i}")).collect::<Vec<_>>(),
+        );
+
+        let df = DataFrame::new_infer_height(vec![a, b])?;
+        // Three chunks result in three blocks.
+        let mut df = df
+            .slice(0, 10)
+            .vstack(&df.slice(10, 40))?
+            .vstack(&df.slice(50, 50))?;
+
+        for compression in [None, Some(AvroCompression::Deflate)] {
+            let mut bytes = vec![];
+            AvroWriter::new(&mut bytes)
+                .with_compression(compression)
+                .finish(&mut df)?;
+
+            let metadata = AvroFileMetadata::try_new(&bytes)?;
+            let blocks = split_avro_blocks(&bytes, &metadata)?;
+
+            assert_eq!(
+                blocks.iter().map(|x| x.num_rows).collect::<Vec<_>>(),
+                [10, 40, 50]
+            );
+
+            let batch = decode_avro_block(&bytes, blocks[1], &metadata, &[false, true])?;
+            let out = DataFrame::from(batch);
+
+            assert_eq!(out.width(), 1);
+            assert!(out.equals(&df.select(["b"])?.slice(10, 40)));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_zigzag_long() {
+        assert_eq!(read_zigzag_long(&[0x00]).unwrap(), (0, 1));
+        assert_eq!(read_zigzag_long(&[0x01]).unwrap(), (-1, 1));
+        assert_eq!(read_zigzag_long(&[0x02]).unwrap(), (1, 1));
+        assert_eq!(read_zigzag_long(&[0x80, 0x01]).unwrap(), (64, 2));
+        assert!(read_zigzag_long(&[0x80]).is_err());
+    }
+}
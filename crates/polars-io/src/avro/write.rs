@@ -2,13 +2,58 @@ use std::io::Write;
 
 pub use Compression as AvroCompression;
 pub use arrow::io::avro::avro_schema::file::Compression;
+use arrow::io::avro::avro_schema::file::{Block, CompressedBlock};
+use arrow::io::avro::avro_schema::schema::Record;
 use arrow::io::avro::avro_schema::{self};
 use arrow::io::avro::write;
+use arrow::record_batch::RecordBatch;
 use polars_core::error::to_compute_err;
 use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 use crate::shared::{SerWriter, schema_to_arrow_checked};
 
+/// Compression codec of the Avro data blocks in [`AvroWriterOptions`]. This mirrors
+/// [`AvroCompression`], which cannot be serialized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum AvroCodec {
+    Deflate,
+    Snappy,
+}
+
+impl From<AvroCodec> for AvroCompression {
+    fn from(value: AvroCodec) -> Self {
+        match value {
+            AvroCodec::Deflate => AvroCompression::Deflate,
+            AvroCodec::Snappy => AvroCompression::Snappy,
+        }
+    }
+}
+
+impl From<AvroCompression> for AvroCodec {
+    fn from(value: AvroCompression) -> Self {
+        match value {
+            AvroCompression::Deflate => AvroCodec::Deflate,
+            AvroCompression::Snappy => AvroCodec::Snappy,
+        }
+    }
+}
+
+/// Options of the streaming Avro sink. In Rust, it is used through `LazyFrame::sink` with
+/// `FileWriteFormat::Avro`, like the sinks of the other formats.
+#[derive(Clone, Debug, PartialEq, Eq, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct AvroWriterOptions {
+    /// Data block compression.
+    pub compression: Option<AvroCodec>,
+    /// Name of the top-level Avro record.
+    pub name: PlSmallStr,
+}
+
 /// Write a [`DataFrame`] to [Apache Avro] format
 ///
 /// [Apache Avro]: https://avro.apache.org
@@ -64,39 +109,80 @@ where
     }
 
     fn finish(&mut self, df: &mut DataFrame) -> PolarsResult<()> {
-        let schema = schema_to_arrow_checked(df.schema(), CompatLevel::oldest(), "avro")?;
-        let record = write::to_record(&schema, self.name.clone())?;
+        let record = avro_record(df.schema(), self.name.clone())?;
+
+        write_avro_header(&mut self.writer, &record, self.compression)?;
 
-        let mut data = vec![];
-        let mut compressed_block = avro_schema::file::CompressedBlock::default();
+        df.align_chunks_par();
+
+        let mut encoder = AvroBlockEncoder::default();
         for chunk in df.iter_chunks(CompatLevel::oldest(), true) {
-            let mut serializers = chunk
-                .iter()
-                .zip(record.fields.iter())
-                .map(|(array, field)| write::new_serializer(array.as_ref(), &field.schema))
-                .collect::<Vec<_>>();
-
-            let mut block =
-                avro_schema::file::Block::new(chunk.arrays()[0].len(), std::mem::take(&mut data));
-            write::serialize(&mut serializers, &mut block);
-            let _was_compressed =
-                avro_schema::write::compress(&mut block, &mut compressed_block, self.compression)
-                    .map_err(to_compute_err)?;
-
-            avro_schema::write::write_metadata(&mut self.writer, record.clone(), self.compression)
-                .map_err(to_compute_err)?;
+            encoder.write_block(&mut self.writer, &chunk, &record, self.compression)?;
+        }
 
-            avro_schema::write::write_block(&mut self.writer, &compressed_block)
-                .map_err(to_compute_err)?;
-            // reuse block for next iteration.
-            data = block.data;
-            data.clear();
+        Ok(())
+    }
+}
+
+/// Converts a Polars schema into the Avro record written in the file header.
+pub fn avro_record(schema: &Schema, name: String) -> PolarsResult<Record> {
+    let schema = schema_to_arrow_checked(schema, CompatLevel::oldest(), "avro")?;
+    write::to_record(&schema, name)
+}
+
+/// Writes the Avro file header. This must be written once, before any data blocks.
+pub fn write_avro_header<W: Write>(
+    writer: &mut W,
+    record: &Record,
+    compression: Option<AvroCompression>,
+) -> PolarsResult<()> {
+    avro_schema::write::write_metadata(writer, record.clone(), compression).map_err(to_compute_err)
+}
 
-            // reuse block for next iteration
-            compressed_block.data.clear();
-            compressed_block.number_of_rows = 0
+/// Serializes [`RecordBatch`]es into Avro data blocks, re-using its buffers across blocks.
+#[derive(Default)]
+pub struct AvroBlockEncoder {
+    block: Block,
+    compressed_block: CompressedBlock,
+}
+
+impl AvroBlockEncoder {
+    /// Serializes `chunk` as a single data block, including the block header and sync marker.
+    ///
+    /// The arrays of `chunk` must have been converted with [`CompatLevel::oldest`].
+    pub fn write_block<W: Write>(
+        &mut self,
+        writer: &mut W,
+        chunk: &RecordBatch,
+        record: &Record,
+        compression: Option<AvroCompression>,
+    ) -> PolarsResult<()> {
+        // A block with 0 rows marks the end of the file for some readers.
+        if chunk.is_empty() {
+            return Ok(());
         }
 
+        let mut serializers = chunk
+            .arrays()
+            .iter()
+            .zip(record.fields.iter())
+            .map(|(array, field)| write::new_serializer(array.as_ref(), &field.schema))
+            .collect::<Vec<_>>();
+
+        self.block.number_of_rows = chunk.len();
+        write::serialize(&mut serializers, &mut self.block);
+
+        let _was_compressed =
+            avro_schema::write::compress(&mut self.block, &mut self.compressed_block, compression)
+                .map_err(to_compute_err)?;
+
+        avro_schema::write::write_block(writer, &self.compressed_block).map_err(to_compute_err)?;
+
+        // Reuse the buffers for the next block.
+        self.block.data.clear();
+        self.compressed_block.data.clear();
+        self.compressed_block.number_of_rows = 0;
+
         Ok(())
     }
 }
@@ -359,3 +359,83 @@ impl_page_walk!(ListSchemas, NamespaceInfo, key_name = schemas);
 
 pub struct ListTables(pub(crate) PageWalker);
 impl_page_walk!(ListTables, TableInfo, key_name = tables);
+
+#[cfg(test)]
+mod tests {
+    use polars_core::prelude::{DataType, Field};
+
+    use super::*;
+    use crate::utils::mock_http::spawn_mock_server;
+
+    #[test]
+    fn test_catalog_client_write_operations() -> PolarsResult<()> {
+        let table_json = r#"{
+            "name": "t", "table_id": "id-1", "table_type": "EXTERNAL",
+            "storage_location": "/tmp/t", "data_source_format": "DELTA",
+            "columns": [{"name": "a", "type_text": "bigint", "type_name": "LONG",
+                "type_json": "{\"name\":\"a\",\"type\":\"long\",\"nullable\":true,\"metadata\":{}}",
+                "position": 0, "nullable": true}],
+            "created_at": null, "created_by": null, "updated_at": null, "updated_by": null
+        }"#;
+
+        let rt = crate::pl_async::get_runtime();
+
+        rt.block_on(async {
+            let (url, requests) = spawn_mock_server(vec![
+                r#"{"name": "ns", "comment": null, "created_at": null, "created_by": null,
+                    "updated_at": null, "updated_by": null}"#,
+                table_json,
+                "{}",
+            ]);
+
+            let client = CatalogClientBuilder::new()
+                .with_workspace_url(url)
+                .with_bearer_token("token")
+                .build()?;
+
+            let namespace = client.create_namespace("cat", "ns", None, None).await?;
+            assert_eq!(namespace.name, "ns");
+
+            let schema = Schema::from_iter([Field::new("a".into(), DataType::Int64)]);
+            let table_info = client
+                .create_table(
+                    "cat",
+                    "ns",
+                    "t",
+                    Some(&schema),
+                    &TableType::External,
+                    Some(&DataSourceFormat::Delta),
+                    None,
+                    Some("/tmp/t"),
+                    &mut std::iter::empty(),
+                )
+                .await?;
+
+            let (table_schema, _) = super::super::schema::table_info_to_schemas(&table_info)?;
+            assert_eq!(table_schema.unwrap().as_ref(), &schema);
+
+            client.delete_table("cat", "ns", "t").await?;
+
+            let requests = requests.lock().unwrap();
+            assert_eq!(
+                requests
+                    .iter()
+                    .map(|(method, path, _)| (method.as_str(), path.as_str()))
+                    .collect::<Vec<_>>(),
+                [
+                    ("POST", "/api/2.1/unity-catalog/schemas"),
+                    ("POST", "/api/2.1/unity-catalog/tables"),
+                    ("DELETE", "/api/2.1/unity-catalog/tables/cat.ns.t"),
+                ]
+            );
+
+            let create_table_body: serde_json::Value =
+                serde_json::from_str(&requests[1].2).unwrap();
+            assert_eq!(create_table_body["data_source_format"], "DELTA");
+            assert_eq!(create_table_body["columns"][0]["name"], "a");
+            assert_eq!(create_table_body["columns"][0]["type_name"], "LONG");
+
+            PolarsResult::Ok(())
+        })
+    }
+}
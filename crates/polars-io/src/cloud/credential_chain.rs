@@ -0,0 +1,793 @@
+//! Credential sources that can be composed into a [`CredentialProviderChain`].
+//!
+//! The built-in sources are the credential providers of `object_store` (environment variables,
+//! web identity, instance metadata, the Azure CLI, service account files, ...) and the AWS shared
+//! credentials / config INI files. The chain plugs into the existing [`PlCredentialProvider`]
+//! machinery, so fetched credentials are cached and only refreshed once they (nearly) expire.
+
+use std::fmt::Debug;
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use async_trait::async_trait;
+use polars_core::config;
+use polars_error::{PolarsResult, polars_bail, to_compute_err};
+
+use super::credential_provider::{ObjectStoreCredential, PlCredentialProvider};
+
+/// A single source of credentials, e.g. a local profile file.
+#[async_trait]
+pub trait CredentialSource: Debug + Send + Sync {
+    /// Short name used in log and error messages.
+    fn name(&self) -> &'static str;
+
+    /// Fetches credentials along with their expiry time as seconds since UNIX_EPOCH.
+    ///
+    /// Returns `None` if this source is not configured in the current environment (e.g. the
+    /// file it reads from does not exist), in which case the chain moves on to the next source.
+    async fn fetch_credential(&self) -> PolarsResult<Option<(ObjectStoreCredential, u64)>>;
+}
+
+/// Tries a list of [`CredentialSource`]s in order, using the first one that returns credentials.
+///
+/// The source that last succeeded is tried first on subsequent refreshes.
+#[derive(Debug, Clone)]
+pub struct CredentialProviderChain {
+    sources: Vec<Arc<dyn CredentialSource>>,
+    refresh_margin_secs: u64,
+    last_used: Arc<std::sync::atomic::AtomicUsize>,
+}
+
+impl Default for CredentialProviderChain {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CredentialProviderChain {
+    pub fn new() -> Self {
+        Self {
+            sources: vec![],
+            refresh_margin_secs: 60,
+            last_used: Default::default(),
+        }
+    }
+
+    /// Appends a source to the end of the chain.
+    pub fn with_source(mut self, source: impl CredentialSource + 'static) -> Self {
+        self.sources.push(Arc::new(source));
+        self
+    }
+
+    /// Refresh credentials this many seconds before they expire. Defaults to 60.
+    pub fn with_refresh_margin(mut self, secs: u64) -> Self {
+        self.refresh_margin_secs = secs;
+        self
+    }
+
+    /// Chain of the shared credentials / config profile files, followed by the `object_store`
+    /// providers configured from the environment.
+    #[cfg(feature = "aws")]
+    pub fn default_aws() -> Self {
+        Self::new()
+            .with_source(aws::AwsProfileCredentialSource::default())
+            .with_source(ObjectStoreCredentialSource::aws(
+                object_store::aws::AmazonS3Builder::from_env(),
+            ))
+    }
+
+    /// Chain of the `object_store` providers configured from the environment, followed by the
+    /// Azure CLI.
+    #[cfg(feature = "azure")]
+    pub fn default_azure() -> Self {
+        let builder = object_store::azure::MicrosoftAzureBuilder::from_env();
+
+        Self::new()
+            .with_source(ObjectStoreCredentialSource::azure(builder.clone()))
+            .with_source(ObjectStoreCredentialSource::azure(
+                builder.with_use_azure_cli(true),
+            ))
+    }
+
+    /// Chain of the `object_store` providers configured from the environment, which includes the
+    /// application default credentials file.
+    #[cfg(feature = "gcp")]
+    pub fn default_gcp() -> Self {
+        Self::new().with_source(ObjectStoreCredentialSource::gcp(
+            object_store::gcp::GoogleCloudStorageBuilder::from_env(),
+        ))
+    }
+
+    /// Fetches credentials from the first source that provides them.
+    pub async fn fetch_credential(&self) -> PolarsResult<(ObjectStoreCredential, u64)> {
+        use std::sync::atomic::Ordering;
+
+        let verbose = config::verbose();
+
+        if self.sources.is_empty() {
+            polars_bail!(ComputeError: "credential provider chain has no sources")
+        }
+
+        let start = self
+            .last_used
+            .load(Ordering::Relaxed)
+            .min(self.sources.len() - 1);
+        let mut errors = vec![];
+
+        for i in (start..self.sources.len()).chain(0..start) {
+            let source = &self.sources[i];
+
+            match source.fetch_credential().await {
+                Ok(Some((credential, expiry))) => {
+                    if verbose {
+                        eprintln!(
+                            "[CredentialProviderChain]: using credentials from {}",
+                            source.name()
+                        )
+                    }
+
+                    self.last_used.store(i, Ordering::Relaxed);
+                    return Ok((credential, self.apply_refresh_margin(expiry)));
+                },
+                Ok(None) => {
+                    if verbose {
+                        eprintln!(
+                            "[CredentialProviderChain]: {} is not configured, skipping",
+                            source.name()
+                        )
+                    }
+                },
+                Err(e) => {
+                    if verbose {
+                        eprintln!("[CredentialProviderChain]: {} failed: {}", source.name(), e)
+                    }
+
+                    errors.push(format!("{}: {}", source.name(), e));
+                },
+            }
+        }
+
+        if errors.is_empty() {
+            polars_bail!(
+                ComputeError:
+                "no credential source in the chain was configured (tried: {})",
+                self.sources.iter().map(|x| x.name()).collect::<Vec<_>>().join(", ")
+            )
+        }
+
+        polars_bail!(
+            ComputeError:
+            "failed to load credentials from any source in the chain: {}",
+            errors.join("; ")
+        )
+    }
+
+    /// Moves the expiry forward by the refresh margin so that the cache refreshes early, without
+    /// moving it into the past.
+    fn apply_refresh_margin(&self, expiry: u64) -> u64 {
+        if expiry == u64::MAX {
+            return expiry;
+        }
+
+        let now = now_secs();
+
+        if expiry < now {
+            return expiry;
+        }
+
+        expiry.saturating_sub(self.refresh_margin_secs).max(now)
+    }
+
+    pub fn into_credential_provider(self) -> PlCredentialProvider {
+        let chain = Arc::new(self);
+
+        PlCredentialProvider::from_func(move || {
+            let chain = chain.clone();
+            // The sources return futures that are only `Send`, so they are polled on a separate
+            // task whose handle is `Sync`.
+            let task =
+                crate::pl_async::get_runtime().spawn(async move { chain.fetch_credential().await });
+
+            Box::pin(async move { task.await.map_err(to_compute_err)? })
+        })
+    }
+}
+
+impl From<CredentialProviderChain> for PlCredentialProvider {
+    fn from(value: CredentialProviderChain) -> Self {
+        value.into_credential_provider()
+    }
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+}
+
+type CredentialFuture =
+    std::pin::Pin<Box<dyn Future<Output = PolarsResult<ObjectStoreCredential>> + Send>>;
+
+/// Fetches credentials from the credential provider of an `object_store` builder.
+///
+/// The provider is whatever `object_store` would use for the builder's configuration, e.g.
+/// static keys, web identity or instance metadata. `object_store` refreshes these credentials
+/// itself at least 5 minutes before they expire, so the chain fetches them again after that time.
+pub struct ObjectStoreCredentialSource {
+    name: &'static str,
+    fetch: Box<dyn Fn() -> CredentialFuture + Send + Sync>,
+}
+
+/// Bucket / container name for the builders, which require one but do not use it for the
+/// credentials.
+#[cfg(any(feature = "aws", feature = "azure", feature = "gcp"))]
+const PLACEHOLDER_BUCKET: &str = "polars-credentials";
+
+/// `object_store` refreshes credentials once they are valid for less than this many seconds.
+const OBJECT_STORE_MIN_TTL_SECS: u64 = 300;
+
+impl ObjectStoreCredentialSource {
+    #[cfg(feature = "aws")]
+    pub fn aws(builder: object_store::aws::AmazonS3Builder) -> Self {
+        let provider = std::sync::OnceLock::new();
+
+        Self {
+            name: "object-store-aws",
+            fetch: Box::new(move || {
+                let provider = get_or_build(&provider, || {
+                    let store = builder
+                        .clone()
+                        .with_bucket_name(PLACEHOLDER_BUCKET)
+                        .build()?;
+                    Ok(store.credentials().clone())
+                });
+
+                Box::pin(async move {
+                    let credential = provider?.get_credential().await.map_err(to_compute_err)?;
+                    Ok(ObjectStoreCredential::Aws(credential))
+                })
+            }),
+        }
+    }
+
+    #[cfg(feature = "azure")]
+    pub fn azure(builder: object_store::azure::MicrosoftAzureBuilder) -> Self {
+        let provider = std::sync::OnceLock::new();
+
+        Self {
+            name: "object-store-azure",
+            fetch: Box::new(move || {
+                let provider = get_or_build(&provider, || {
+                    let store = builder
+                        .clone()
+                        .with_container_name(PLACEHOLDER_BUCKET)
+                        .build()?;
+                    Ok(store.credentials().clone())
+                });
+
+                Box::pin(async move {
+                    let credential = provider?.get_credential().await.map_err(to_compute_err)?;
+                    Ok(ObjectStoreCredential::Azure(credential))
+                })
+            }),
+        }
+    }
+
+    #[cfg(feature = "gcp")]
+    pub fn gcp(builder: object_store::gcp::GoogleCloudStorageBuilder) -> Self {
+        let provider = std::sync::OnceLock::new();
+
+        Self {
+            name: "object-store-gcp",
+            fetch: Box::new(move || {
+                let provider = get_or_build(&provider, || {
+                    let store = builder
+                        .clone()
+                        .with_bucket_name(PLACEHOLDER_BUCKET)
+                        .build()?;
+                    Ok(store.credentials().clone())
+                });
+
+                Box::pin(async move {
+                    let credential = provider?.get_credential().await.map_err(to_compute_err)?;
+                    Ok(ObjectStoreCredential::Gcp(credential))
+                })
+            }),
+        }
+    }
+}
+
+/// Builds the provider on first use, so that constructing a chain does not fail for sources that
+/// are never reached.
+#[cfg(any(feature = "aws", feature = "azure", feature = "gcp"))]
+fn get_or_build<P: Clone>(
+    provider: &std::sync::OnceLock<P>,
+    build: impl FnOnce() -> object_store::Result<P>,
+) -> PolarsResult<P> {
+    if let Some(v) = provider.get() {
+        return Ok(v.clone());
+    }
+
+    let v = build().map_err(to_compute_err)?;
+    Ok(provider.get_or_init(|| v).clone())
+}
+
+impl Debug for ObjectStoreCredentialSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ObjectStoreCredentialSource")
+            .field("name", &self.name)
+            .finish()
+    }
+}
+
+#[async_trait]
+impl CredentialSource for ObjectStoreCredentialSource {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    async fn fetch_credential(&self) -> PolarsResult<Option<(ObjectStoreCredential, u64)>> {
+        let credential = (self.fetch)().await?;
+        Ok(Some((credential, now_secs() + OBJECT_STORE_MIN_TTL_SECS)))
+    }
+}
+
+/// Minimal INI parser for the AWS shared configuration files.
+///
+/// Returns `(section, key, value)` triples in file order. Indented lines (nested sub-sections)
+/// and comments are ignored. Keys are lowercased.
+#[cfg(feature = "aws")]
+pub(crate) fn parse_ini(text: &str) -> Vec<(String, String, String)> {
+    let mut out = vec![];
+    let mut section: Option<String> = None;
+
+    for line in text.lines() {
+        if line.starts_with([' ', '\t']) {
+            continue;
+        }
+
+        let line = line.trim();
+
+        if line.is_empty() || line.starts_with(['#', ';']) {
+            continue;
+        }
+
+        if let Some(name) = line.strip_prefix('[').and_then(|x| x.strip_suffix(']')) {
+            section = Some(name.trim().to_string());
+            continue;
+        }
+
+        let (Some(section), Some((key, value))) = (section.as_ref(), line.split_once('=')) else {
+            continue;
+        };
+
+        let value = value.trim();
+        // Strip trailing inline comments (` #` / ` ;`).
+        let value = value
+            .find(" #")
+            .or_else(|| value.find(" ;"))
+            .map_or(value, |i| value[..i].trim_end());
+
+        out.push((
+            section.clone(),
+            key.trim().to_ascii_lowercase(),
+            value.to_string(),
+        ))
+    }
+
+    out
+}
+
+#[cfg(feature = "aws")]
+pub mod aws {
+    use std::path::{Path, PathBuf};
+    use std::sync::Arc;
+
+    use async_trait::async_trait;
+    use object_store::aws::AwsCredential;
+    use polars_error::{PolarsResult, polars_bail, polars_err};
+    use polars_utils::aliases::PlHashMap;
+
+    use super::{CredentialSource, parse_ini};
+    use crate::cloud::credential_provider::ObjectStoreCredential;
+
+    /// Reads the static credentials of a profile from `~/.aws/credentials` and `~/.aws/config`.
+    ///
+    /// Profiles that assume a role (`role_arn`) are not supported, use the `object_store`
+    /// providers (e.g. web identity) for those.
+    #[derive(Debug, Clone, Default)]
+    pub struct AwsProfileCredentialSource {
+        profile: Option<String>,
+        credentials_path: Option<PathBuf>,
+        config_path: Option<PathBuf>,
+    }
+
+    impl AwsProfileCredentialSource {
+        /// Use the given profile instead of `AWS_PROFILE` / `default`.
+        pub fn with_profile(mut self, profile: impl Into<String>) -> Self {
+            self.profile = Some(profile.into());
+            self
+        }
+
+        /// Path to the shared credentials file. Defaults to `AWS_SHARED_CREDENTIALS_FILE` or
+        /// `~/.aws/credentials`.
+        pub fn with_credentials_path(mut self, path: impl Into<PathBuf>) -> Self {
+            self.credentials_path = Some(path.into());
+            self
+        }
+
+        /// Path to the shared config file. Defaults to `AWS_CONFIG_FILE` or `~/.aws/config`.
+        pub fn with_config_path(mut self, path: impl Into<PathBuf>) -> Self {
+            self.config_path = Some(path.into());
+            self
+        }
+
+        /// The explicitly requested profile, if any.
+        fn requested_profile(&self) -> Option<String> {
+            self.profile
+                .clone()
+                .or_else(|| std::env::var("AWS_PROFILE").ok().filter(|x| !x.is_empty()))
+        }
+
+        /// Loads the properties of all profiles, merging the config file with the credentials
+        /// file. Values from the credentials file take precedence. Returns `None` if neither file
+        /// exists.
+        fn load_profiles(
+            &self,
+        ) -> PolarsResult<Option<PlHashMap<String, PlHashMap<String, String>>>> {
+            let credentials_path = resolve_path(
+                self.credentials_path.as_deref(),
+                "AWS_SHARED_CREDENTIALS_FILE",
+                "~/.aws/credentials",
+            );
+            let config_path = resolve_path(
+                self.config_path.as_deref(),
+                "AWS_CONFIG_FILE",
+                "~/.aws/config",
+            );
+
+            let config = read_optional_file(&config_path)?;
+            let credentials = read_optional_file(&credentials_path)?;
+
+            if config.is_none() && credentials.is_none() {
+                return Ok(None);
+            }
+
+            let mut profiles = PlHashMap::<String, PlHashMap<String, String>>::default();
+
+            if let Some(config) = config {
+                for (section, key, value) in parse_ini(&config) {
+                    // The config file names profiles `[profile name]`, except for `[default]`.
+                    let name = match section.strip_prefix("profile ") {
+                        Some(v) => v.trim().to_string(),
+                        None if section == "default" => section,
+                        None => continue,
+                    };
+
+                    profiles.entry(name).or_default().insert(key, value);
+                }
+            }
+
+            if let Some(credentials) = credentials {
+                for (section, key, value) in parse_ini(&credentials) {
+                    profiles.entry(section).or_default().insert(key, value);
+                }
+            }
+
+            Ok(Some(profiles))
+        }
+    }
+
+    #[async_trait]
+    impl CredentialSource for AwsProfileCredentialSource {
+        fn name(&self) -> &'static str {
+            "aws-profile"
+        }
+
+        async fn fetch_credential(&self) -> PolarsResult<Option<(ObjectStoreCredential, u64)>> {
+            let requested = self.requested_profile();
+
+            // Credentials in the environment take precedence over the default profile.
+            if requested.is_none() && std::env::var_os("AWS_ACCESS_KEY_ID").is_some() {
+                return Ok(None);
+            }
+
+            let Some(profiles) = self.load_profiles()? else {
+                return Ok(None);
+            };
+
+            let name = requested.as_deref().unwrap_or("default");
+
+            let Some(profile) = profiles.get(name) else {
+                if requested.is_some() {
+                    polars_bail!(ComputeError: "aws profile '{}' not found", name)
+                }
+                return Ok(None);
+            };
+
+            let get = |key: &str| profile.get(key).map(|x| x.as_str());
+
+            if get("role_arn").is_some() {
+                polars_bail!(
+                    ComputeError:
+                    "aws profile '{}': assuming a role (role_arn) is not supported by the profile \
+                    source",
+                    name
+                )
+            }
+
+            let (Some(key_id), Some(secret_key)) =
+                (get("aws_access_key_id"), get("aws_secret_access_key"))
+            else {
+                polars_bail!(
+                    ComputeError:
+                    "aws profile '{}' does not contain aws_access_key_id and aws_secret_access_key",
+                    name
+                )
+            };
+
+            let credential = AwsCredential {
+                key_id: key_id.to_string(),
+                secret_key: secret_key.to_string(),
+                token: get("aws_session_token").map(|x| x.to_string()),
+            };
+
+            Ok(Some((
+                ObjectStoreCredential::Aws(Arc::new(credential)),
+                u64::MAX,
+            )))
+        }
+    }
+
+    /// Resolves a path from (in order) an explicit value, an environment variable, or a default
+    /// relative to the home directory.
+    fn resolve_path(explicit: Option<&Path>, env_var: &str, default: &str) -> PathBuf {
+        if let Some(v) = explicit {
+            return v.to_path_buf();
+        }
+
+        if let Some(v) = std::env::var_os(env_var).filter(|x| !x.is_empty()) {
+            return v.into();
+        }
+
+        crate::path_utils::resolve_homedir(default).into_owned()
+    }
+
+    /// Reads a file to a string, returning `None` if it does not exist.
+    fn read_optional_file(path: &Path) -> PolarsResult<Option<String>> {
+        match std::fs::read_to_string(path) {
+            Ok(v) => Ok(Some(v)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(polars_err!(ComputeError: "{}: {}", path.display(), e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug)]
+    struct TestSource(&'static str, Option<bool>);
+
+    #[async_trait]
+    impl CredentialSource for TestSource {
+        fn name(&self) -> &'static str {
+            self.0
+        }
+
+        async fn fetch_credential(&self) -> PolarsResult<Option<(ObjectStoreCredential, u64)>> {
+            match self.1 {
+                None => Ok(None),
+                Some(true) => Ok(Some((ObjectStoreCredential::None, u64::MAX))),
+                Some(false) => polars_bail!(ComputeError: "failed"),
+            }
+        }
+    }
+
+    #[cfg(feature = "aws")]
+    #[test]
+    fn test_parse_ini() {
+        let text = "\
+# comment
+[default]
+aws_access_key_id = AKID ; inline comment
+aws_secret_access_key=secret
+
+[profile dev]
+role_arn = arn:aws:iam::123:role/dev
+s3 =
+  max_concurrent_requests = 10
+source_profile = default
+";
+
+        assert_eq!(
+            parse_ini(text),
+            [
+                ("default", "aws_access_key_id", "AKID"),
+                ("default", "aws_secret_access_key", "secret"),
+                ("profile dev", "role_arn", "arn:aws:iam::123:role/dev"),
+                ("profile dev", "s3", ""),
+                ("profile dev", "source_profile", "default"),
+            ]
+            .map(|(a, b, c)| (a.to_string(), b.to_string(), c.to_string()))
+        );
+    }
+
+    #[test]
+    fn test_credential_provider_chain() {
+        let rt = crate::pl_async::get_runtime();
+
+        let chain = CredentialProviderChain::new()
+            .with_source(TestSource("unconfigured", None))
+            .with_source(TestSource("failing", Some(false)))
+            .with_source(TestSource("ok", Some(true)));
+
+        let (_, expiry) = rt.block_on(chain.fetch_credential()).unwrap();
+        assert_eq!(expiry, u64::MAX);
+        assert_eq!(
+            chain.last_used.load(std::sync::atomic::Ordering::Relaxed),
+            2
+        );
+
+        let chain = CredentialProviderChain::new()
+            .with_source(TestSource("unconfigured", None))
+            .with_source(TestSource("failing", Some(false)));
+
+        let err = rt.block_on(chain.fetch_credential()).err().unwrap();
+        assert!(err.to_string().contains("failing: failed"));
+
+        let chain = CredentialProviderChain::new().with_refresh_margin(60);
+        let now = now_secs();
+        assert_eq!(chain.apply_refresh_margin(now + 3600), now + 3600 - 60);
+        assert!(chain.apply_refresh_margin(now + 10) >= now);
+        assert_eq!(chain.apply_refresh_margin(u64::MAX), u64::MAX);
+    }
+
+    #[cfg(feature = "aws")]
+    #[test]
+    fn test_aws_profile_credentials() {
+        use std::io::Write;
+
+        let mut credentials = tempfile::NamedTempFile::new().unwrap();
+        writeln!(
+            credentials,
+            "[default]\naws_access_key_id = AKID\naws_secret_access_key = SECRET\n\
+            [other]\naws_access_key_id = AKID2\naws_secret_access_key = SECRET2\n\
+            aws_session_token = TOKEN2\n\
+            [dev]\nrole_arn = arn:aws:iam::123:role/dev\nsource_profile = default"
+        )
+        .unwrap();
+
+        let source = |profile: &str| {
+            aws::AwsProfileCredentialSource::default()
+                .with_credentials_path(credentials.path())
+                .with_config_path(credentials.path().with_extension("missing"))
+                .with_profile(profile)
+        };
+        let rt = crate::pl_async::get_runtime();
+
+        let (credential, expiry) = rt
+            .block_on(source("other").fetch_credential())
+            .unwrap()
+            .unwrap();
+
+        let ObjectStoreCredential::Aws(credential) = credential else {
+            panic!()
+        };
+
+        assert_eq!(credential.key_id, "AKID2");
+        assert_eq!(credential.secret_key, "SECRET2");
+        assert_eq!(credential.token.as_deref(), Some("TOKEN2"));
+        assert_eq!(expiry, u64::MAX);
+
+        let err = rt.block_on(source("dev").fetch_credential()).err().unwrap();
+        assert!(err.to_string().contains("role_arn"));
+
+        let err = rt
+            .block_on(source("missing").fetch_credential())
+            .err()
+            .unwrap();
+        assert!(err.to_string().contains("not found"));
+    }
+
+    #[cfg(feature = "aws")]
+    #[test]
+    fn test_object_store_aws_source() {
+        let source = ObjectStoreCredentialSource::aws(
+            object_store::aws::AmazonS3Builder::new()
+                .with_access_key_id("AKID")
+                .with_secret_access_key("SECRET"),
+        );
+
+        let (credential, expiry) = crate::pl_async::get_runtime()
+            .block_on(source.fetch_credential())
+            .unwrap()
+            .unwrap();
+
+        let ObjectStoreCredential::Aws(credential) = credential else {
+            panic!()
+        };
+
+        assert_eq!(credential.key_id, "AKID");
+        assert_eq!(credential.secret_key, "SECRET");
+        assert!(expiry > now_secs());
+    }
+
+    #[cfg(feature = "azure")]
+    #[test]
+    fn test_object_store_azure_source() {
+        use object_store::azure::AzureCredential;
+
+        let source = ObjectStoreCredentialSource::azure(
+            object_store::azure::MicrosoftAzureBuilder::new()
+                .with_account("account")
+                .with_access_key("a2V5"),
+        );
+
+        let (credential, _) = crate::pl_async::get_runtime()
+            .block_on(source.fetch_credential())
+            .unwrap()
+            .unwrap();
+
+        let ObjectStoreCredential::Azure(credential) = credential else {
+            panic!()
+        };
+
+        assert!(matches!(credential.as_ref(), AzureCredential::AccessKey(_)));
+    }
+
+    #[cfg(feature = "aws")]
+    #[test]
+    fn test_credential_provider_chain_refresh() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        use object_store::CredentialProvider;
+        use object_store::aws::AwsCredential;
+
+        use crate::cloud::credential_provider::IntoCredentialProvider;
+
+        /// Returns credentials that expire within the refresh margin on the first fetch.
+        #[derive(Debug)]
+        struct ExpiringSource(Arc<AtomicUsize>);
+
+        #[async_trait]
+        impl CredentialSource for ExpiringSource {
+            fn name(&self) -> &'static str {
+                "expiring"
+            }
+
+            async fn fetch_credential(&self) -> PolarsResult<Option<(ObjectStoreCredential, u64)>> {
+                let n = self.0.fetch_add(1, Ordering::Relaxed) + 1;
+                let expiry = now_secs() + if n == 1 { 30 } else { 3600 };
+                let credential = AwsCredential {
+                    key_id: format!("KEY{n}"),
+                    secret_key: String::new(),
+                    token: None,
+                };
+
+                Ok(Some((
+                    ObjectStoreCredential::Aws(Arc::new(credential)),
+                    expiry,
+                )))
+            }
+        }
+
+        let fetches = Arc::new(AtomicUsize::new(0));
+        let provider = CredentialProviderChain::new()
+            .with_source(ExpiringSource(fetches.clone()))
+            .with_refresh_margin(60)
+            .into_credential_provider()
+            .into_aws_provider();
+
+        crate::pl_async::get_runtime().block_on(async {
+            assert_eq!(provider.get_credential().await.unwrap().key_id, "KEY1");
+            assert_eq!(provider.get_credential().await.unwrap().key_id, "KEY2");
+            // Cached until the refreshed credentials expire.
+            assert_eq!(provider.get_credential().await.unwrap().key_id, "KEY2");
+        });
+
+        assert_eq!(fetches.load(Ordering::Relaxed), 2);
+    }
+}
@@ -20,5 +20,7 @@ pub use options::*;
 #[cfg(feature = "cloud")]
 pub use polars_object_store::*;
 
+#[cfg(feature = "cloud")]
+pub mod credential_chain;
 #[cfg(feature = "cloud")]
 pub mod credential_provider;
@@ -22,13 +22,18 @@ mod parser;
 mod read_impl;
 mod reader;
 pub mod schema_inference;
+mod sniffer;
 mod splitfields;
 pub mod streaming;
 mod utils;
 
-pub use options::{CommentPrefix, CsvEncoding, CsvParseOptions, CsvReadOptions, NullValues};
+pub use options::{
+    CommentPrefix, CsvEncoding, CsvParseOptions, CsvReadOptions, CsvSeparator, MultiSeparator,
+    NullValues,
+};
 pub use parser::{SplitLines, count_rows, count_rows_from_slice_par};
 pub use reader::CsvReader;
+pub use sniffer::{CsvDialect, sniff_csv_dialect};
 pub use streaming::read_until_start_and_infer_schema;
 
 pub mod _csv_read_internal {
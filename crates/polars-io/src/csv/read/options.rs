@@ -4,12 +4,13 @@ use std::sync::Arc;
 
 use polars_core::datatypes::{DataType, Field};
 use polars_core::schema::{Schema, SchemaRef};
-use polars_error::PolarsResult;
+use polars_error::{PolarsError, PolarsResult, polars_bail, polars_ensure};
 use polars_utils::pl_str::PlSmallStr;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 use crate::RowIndex;
+use crate::utils::text_encoding::TextEncoding;
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -42,13 +43,17 @@ pub struct CsvReadOptions {
     pub raise_if_empty: bool,
     pub ignore_errors: bool,
     pub fields_to_cast: Vec<Field>,
+    /// Detect the separator, quote character, header, comment prefix and decimal comma from a
+    /// sample of the first file, overriding the configured values.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub sniff_dialect: bool,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
 pub struct CsvParseOptions {
-    pub separator: u8,
+    pub separator: CsvSeparator,
     pub quote_char: Option<u8>,
     pub eol_char: u8,
     pub encoding: CsvEncoding,
@@ -88,6 +93,7 @@ impl Default for CsvReadOptions {
             raise_if_empty: true,
             ignore_errors: false,
             fields_to_cast: vec![],
+            sniff_dialect: false,
         }
     }
 }
@@ -96,7 +102,7 @@ impl Default for CsvReadOptions {
 impl Default for CsvParseOptions {
     fn default() -> Self {
         Self {
-            separator: b',',
+            separator: CsvSeparator::Single(b','),
             quote_char: Some(b'"'),
             eol_char: b'\n',
             encoding: Default::default(),
@@ -198,6 +204,14 @@ impl CsvReadOptions {
         self
     }
 
+    /// Detect the dialect of the file from a sample before reading it. See [`CsvDialect`].
+    ///
+    /// [`CsvDialect`]: super::CsvDialect
+    pub fn with_sniff_dialect(mut self, sniff_dialect: bool) -> Self {
+        self.sniff_dialect = sniff_dialect;
+        self
+    }
+
     /// Sets the chunk size used by the parser. This influences performance.
     pub fn with_chunk_size(mut self, chunk_size: usize) -> Self {
         self.chunk_size = chunk_size;
@@ -259,10 +273,10 @@ impl CsvReadOptions {
 }
 
 impl CsvParseOptions {
-    /// The character used to separate fields in the CSV file. This
+    /// The character or string used to separate fields in the CSV file. This
     /// is most often a comma ','.
-    pub fn with_separator(mut self, separator: u8) -> Self {
-        self.separator = separator;
+    pub fn with_separator<T: Into<CsvSeparator>>(mut self, separator: T) -> Self {
+        self.separator = separator.into();
         self
     }
 
@@ -273,12 +287,31 @@ impl CsvParseOptions {
         self
     }
 
-    /// Set the character used to indicate an end-of-line (eol).
+    /// Set the character used to indicate an end-of-line (eol). If this is `\r`, a `\n` directly
+    /// following it is part of the line terminator, so files mixing `\r` and `\r\n` line endings
+    /// can be read.
+    ///
+    /// See [`CsvParseOptions::with_line_terminator`] to set it from a string.
     pub fn with_eol_char(mut self, eol_char: u8) -> Self {
         self.eol_char = eol_char;
         self
     }
 
+    /// Set the line terminator from a string. This is either a single byte or `\r\n`, which
+    /// also accepts lines ending in just `\r`.
+    ///
+    /// Other line terminators of multiple bytes cannot be read and return an error.
+    pub fn with_line_terminator(self, line_terminator: &str) -> PolarsResult<Self> {
+        let eol_char = match line_terminator.as_bytes() {
+            [c] => *c,
+            b"\r\n" => b'\r',
+            _ => polars_bail!(
+                InvalidOperation: "line terminator {:?} is not supported when reading CSV, only single bytes and \"\\r\\n\" are", line_terminator
+            ),
+        };
+        Ok(self.with_eol_char(eol_char))
+    }
+
     /// Set the encoding used by the file.
     pub fn with_encoding(mut self, encoding: CsvEncoding) -> Self {
         self.encoding = encoding;
@@ -330,15 +363,74 @@ impl CsvParseOptions {
     }
 }
 
-#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+/// Character encoding of a CSV file. Input that is not UTF-8 is transcoded while it is read.
+pub type CsvEncoding = TextEncoding;
+
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
-pub enum CsvEncoding {
-    /// Utf8 encoding.
-    #[default]
-    Utf8,
-    /// Utf8 encoding and unknown bytes are replaced with �.
-    LossyUtf8,
+pub enum CsvSeparator {
+    /// A single byte separating the fields, which takes the fast path of the field splitter.
+    Single(u8),
+    /// A string of multiple bytes separating the fields, e.g. `||` or `~|~`.
+    Multi(MultiSeparator),
+}
+
+/// A non-empty separator without newlines, built with [`CsvSeparator::new_from_str`].
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "PlSmallStr"))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct MultiSeparator(PlSmallStr);
+
+impl MultiSeparator {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl TryFrom<PlSmallStr> for MultiSeparator {
+    type Error = PolarsError;
+
+    fn try_from(separator: PlSmallStr) -> PolarsResult<Self> {
+        polars_ensure!(!separator.is_empty(), InvalidOperation: "separator cannot be empty");
+        polars_ensure!(
+            !separator.contains('\n'),
+            InvalidOperation: "separator cannot contain a newline"
+        );
+        Ok(Self(separator))
+    }
+}
+
+impl CsvSeparator {
+    /// Creates a new `CsvSeparator` from a `&str`.
+    pub fn new_from_str(separator: &str) -> PolarsResult<Self> {
+        Ok(match separator.as_bytes() {
+            [c] if *c != b'\n' => CsvSeparator::Single(*c),
+            _ => CsvSeparator::Multi(PlSmallStr::from_str(separator).try_into()?),
+        })
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        match self {
+            CsvSeparator::Single(c) => std::slice::from_ref(c),
+            CsvSeparator::Multi(s) => s.as_str().as_bytes(),
+        }
+    }
+}
+
+impl From<u8> for CsvSeparator {
+    fn from(value: u8) -> Self {
+        Self::Single(value)
+    }
+}
+
+impl TryFrom<&str> for CsvSeparator {
+    type Error = PolarsError;
+
+    fn try_from(value: &str) -> PolarsResult<Self> {
+        Self::new_from_str(value)
+    }
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
@@ -12,7 +12,7 @@ use rayon::prelude::*;
 
 use super::CsvParseOptions;
 use super::builder::Builder;
-use super::options::{CommentPrefix, NullValuesCompiled};
+use super::options::{CommentPrefix, CsvEncoding, NullValuesCompiled};
 use super::splitfields::SplitFields;
 use crate::csv::read::read_until_start_and_infer_schema;
 use crate::prelude::CsvReadOptions;
@@ -26,6 +26,7 @@ pub fn count_rows(
     quote_char: Option<u8>,
     comment_prefix: Option<&CommentPrefix>,
     eol_char: u8,
+    encoding: CsvEncoding,
     has_header: bool,
     skip_lines: usize,
     skip_rows_before_header: usize,
@@ -50,6 +51,7 @@ pub fn count_rows(
         quote_char,
         comment_prefix,
         eol_char,
+        encoding,
         has_header,
         skip_lines,
         skip_rows_before_header,
@@ -65,18 +67,20 @@ pub fn count_rows_from_slice_par(
     quote_char: Option<u8>,
     comment_prefix: Option<&CommentPrefix>,
     eol_char: u8,
+    encoding: CsvEncoding,
     has_header: bool,
     skip_lines: usize,
     skip_rows_before_header: usize,
     skip_rows_after_header: usize,
 ) -> PolarsResult<usize> {
-    let mut reader = CompressedReader::try_new(buffer)?;
+    let mut reader = CompressedReader::try_new(buffer)?.with_encoding(encoding)?;
 
     let reader_options = CsvReadOptions {
         parse_options: Arc::new(CsvParseOptions {
             quote_char,
             comment_prefix: comment_prefix.cloned(),
             eol_char,
+            encoding,
             ..Default::default()
         }),
         has_header,
@@ -217,20 +221,20 @@ pub(super) fn next_line_position_naive(input: &[u8], eol_char: u8) -> Option<usi
 pub(super) fn next_line_position(
     mut input: &[u8],
     mut expected_fields: Option<usize>,
-    separator: u8,
+    separator: &[u8],
     quote_char: Option<u8>,
     eol_char: u8,
 ) -> Option<usize> {
     fn accept_line(
         line: &[u8],
         expected_fields: usize,
-        separator: u8,
+        separator: &[u8],
         eol_char: u8,
         quote_char: Option<u8>,
     ) -> bool {
         let mut count = 0usize;
         for (field, _) in SplitFields::new(line, separator, quote_char, eol_char) {
-            if memchr2_iter(separator, eol_char, field).count() >= expected_fields {
+            if memchr2_iter(separator[0], eol_char, field).count() >= expected_fields {
                 return false;
             }
             count += 1;
@@ -1035,6 +1039,7 @@ pub(super) fn parse_lines(
     let start = bytes.as_ptr() as usize;
     let original_bytes_len = bytes.len();
     let n_lines = n_lines as u32;
+    let separator_len = parse_options.separator.as_bytes().len();
 
     let mut line_count = 0u32;
     loop {
@@ -1043,6 +1048,11 @@ pub(super) fn parse_lines(
             return Ok(end - start);
         }
 
+        // A `\r\n` line terminator when the eol char is `\r`.
+        if parse_options.eol_char == b'\r' && bytes.first() == Some(&b'\n') {
+            bytes = unsafe { bytes.get_unchecked(1..) };
+        }
+
         if bytes.is_empty() {
             return Ok(original_bytes_len);
         } else if is_comment_line(bytes, parse_options.comment_prefix.as_ref()) {
@@ -1061,7 +1071,7 @@ pub(super) fn parse_lines(
 
         let mut iter = SplitFields::new(
             bytes,
-            parse_options.separator,
+            parse_options.separator.as_bytes(),
             parse_options.quote_char,
             parse_options.eol_char,
         );
@@ -1077,8 +1087,15 @@ pub(super) fn parse_lines(
                 Some((mut field, needs_escaping)) => {
                     let field_len = field.len();
 
-                    // +1 is the split character that is consumed by the iterator.
-                    read_sol += field_len + 1;
+                    // The separator or eol char that is consumed by the iterator.
+                    let split_len = if separator_len == 1
+                        || bytes.get(read_sol + field_len) == Some(&parse_options.eol_char)
+                    {
+                        1
+                    } else {
+                        separator_len
+                    };
+                    read_sol += field_len + split_len;
 
                     if idx == next_projected as u32 {
                         // the iterator is finished when it encounters a `\n`
@@ -154,8 +154,6 @@ impl<'a> CoreReader<'a> {
         row_index: Option<RowIndex>,
         raise_if_empty: bool,
     ) -> PolarsResult<CoreReader<'a>> {
-        let separator = parse_options.separator;
-
         #[cfg(feature = "decompress")]
         let mut reader_bytes = reader_bytes;
 
@@ -170,12 +168,14 @@ impl<'a> CoreReader<'a> {
         // again after decompression.
         #[cfg(feature = "decompress")]
         {
-            let total_n_rows =
-                n_rows.map(|n| skip_rows + (has_header as usize) + skip_rows_after_header + n);
+            // Rows can only be counted in the decompressed bytes if they don't need transcoding.
+            let total_n_rows = n_rows
+                .filter(|_| parse_options.encoding.is_utf8())
+                .map(|n| skip_rows + (has_header as usize) + skip_rows_after_header + n);
             if let Some(b) = decompress(
                 &reader_bytes,
                 total_n_rows,
-                separator,
+                parse_options.separator.as_bytes(),
                 parse_options.quote_char,
                 parse_options.eol_char,
             ) {
@@ -192,7 +192,8 @@ impl<'a> CoreReader<'a> {
             },
             ReaderBytes::Owned(slice) => slice.clone(),
         };
-        let mut compressed_reader = CompressedReader::try_new(reader_slice)?;
+        let mut compressed_reader =
+            CompressedReader::try_new(reader_slice)?.with_encoding(parse_options.encoding)?;
 
         let read_options = CsvReadOptions {
             parse_options: parse_options.clone(),
@@ -1,14 +1,17 @@
 use std::fs::File;
 use std::path::PathBuf;
 
+use polars_buffer::{Buffer, SharedStorage};
 use polars_core::prelude::*;
 
 use super::options::CsvReadOptions;
 use super::read_impl::CoreReader;
-use crate::mmap::MmapBytesReader;
+use super::sniffer::sniff_csv_dialect;
+use crate::mmap::{MmapBytesReader, ReaderBytes};
 use crate::path_utils::resolve_homedir;
 use crate::predicates::PhysicalIoExpr;
 use crate::shared::SerReader;
+use crate::utils::compression::CompressedReader;
 use crate::utils::get_reader_bytes;
 
 /// Create a new DataFrame by reading a csv file.
@@ -100,6 +103,20 @@ impl<R: MmapBytesReader> CsvReader<R> {
     fn core_reader(&mut self) -> PolarsResult<CoreReader<'_>> {
         let reader_bytes = get_reader_bytes(&mut self.reader)?;
 
+        if self.options.sniff_dialect {
+            let buffer = match &reader_bytes {
+                // SAFETY: The buffer and the reader do not outlive `reader_bytes`.
+                ReaderBytes::Borrowed(slice) => unsafe {
+                    Buffer::from_storage(SharedStorage::from_slice_unchecked(slice))
+                },
+                ReaderBytes::Owned(slice) => slice.clone(),
+            };
+            let mut reader = CompressedReader::try_new(buffer)?
+                .with_encoding(self.options.parse_options.encoding)?;
+            let dialect = sniff_csv_dialect(&self.options, &mut reader)?;
+            self.options = dialect.apply(std::mem::take(&mut self.options));
+        }
+
         let parse_options = self.options.get_parse_options();
 
         CoreReader::new(
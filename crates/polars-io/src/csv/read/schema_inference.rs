@@ -55,7 +55,7 @@ fn infer_headers(mut header_line: &[u8], parse_options: &CsvParseOptions) -> Vec
 
     let byterecord = SplitFields::new(
         header_line,
-        parse_options.separator,
+        parse_options.separator.as_bytes(),
         parse_options.quote_char,
         parse_options.eol_char,
     );
@@ -103,7 +103,7 @@ fn infer_types_from_line(
 
     let record = SplitFields::new(
         line,
-        parse_options.separator,
+        parse_options.separator.as_bytes(),
         parse_options.quote_char,
         parse_options.eol_char,
     );
@@ -0,0 +1,412 @@
+//! Detection of the CSV dialect from a sample of the file.
+use polars_buffer::Buffer;
+use polars_core::prelude::*;
+
+use super::options::{CommentPrefix, CsvReadOptions};
+use super::schema_inference::{finish_infer_field_schema, infer_field_schema};
+use crate::utils::compression::CompressedReader;
+
+const SEPARATOR_CANDIDATES: [u8; 4] = [b',', b';', b'\t', b'|'];
+const QUOTE_CANDIDATES: [u8; 2] = [b'"', b'\''];
+const COMMENT_CHAR: u8 = b'#';
+/// Upper bound on the number of bytes read for the sample.
+const MAX_SAMPLE_SIZE: usize = 1 << 22;
+
+/// CSV dialect detected by [`sniff_csv_dialect`].
+///
+/// There is no escape character, quotes inside quoted fields are expected to be escaped by
+/// doubling them as this is the only form the parser supports.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CsvDialect {
+    pub separator: u8,
+    pub quote_char: Option<u8>,
+    pub has_header: bool,
+    pub comment_prefix: Option<CommentPrefix>,
+    pub decimal_comma: bool,
+    /// Whether a column contains values in a supported date or datetime format.
+    pub try_parse_dates: bool,
+}
+
+impl CsvDialect {
+    /// Sets the detected dialect on `options`. Date parsing is only ever enabled, not disabled.
+    pub fn apply(&self, options: CsvReadOptions) -> CsvReadOptions {
+        options
+            .with_has_header(self.has_header)
+            .map_parse_options(|parse_options| {
+                let try_parse_dates = parse_options.try_parse_dates || self.try_parse_dates;
+
+                parse_options
+                    .with_separator(self.separator)
+                    .with_quote_char(self.quote_char)
+                    .with_comment_prefix(self.comment_prefix.clone())
+                    .with_decimal_comma(self.decimal_comma)
+                    .with_try_parse_dates(try_parse_dates)
+            })
+    }
+}
+
+/// Detects the dialect from the start of the file.
+///
+/// The sample is the same number of rows that is used for schema inference
+/// (`infer_schema_length`), after skipping `skip_lines` and `skip_rows`. The separator and quote character are
+/// chosen such that the number of fields per row is most consistent.
+pub fn sniff_csv_dialect(
+    options: &CsvReadOptions,
+    reader: &mut CompressedReader,
+) -> PolarsResult<CsvDialect> {
+    let n_rows = options
+        .infer_schema_length
+        .unwrap_or(usize::MAX)
+        .saturating_add(options.skip_lines + options.skip_rows + 1)
+        .max(2);
+    let eol_char = options.parse_options.eol_char;
+
+    let mut sample = Buffer::new();
+    let mut read_size = CompressedReader::initial_read_size();
+
+    let is_eof = loop {
+        let (slice, bytes_read) = reader.read_next_slice(&sample, read_size)?;
+        sample = slice;
+
+        if bytes_read == 0 {
+            break true;
+        }
+
+        if sample.len() >= MAX_SAMPLE_SIZE
+            || memchr::memchr_iter(eol_char, &sample).nth(n_rows).is_some()
+        {
+            break false;
+        }
+
+        read_size = read_size.saturating_mul(2);
+    };
+
+    let mut lines: Vec<&[u8]> = sample
+        .strip_prefix(b"\xef\xbb\xbf")
+        .unwrap_or(&sample)
+        .split(|&b| b == eol_char)
+        .collect();
+
+    // The last line may be incomplete.
+    if !is_eof && lines.len() > 1 {
+        lines.pop();
+    }
+
+    let lines: Vec<&[u8]> = lines
+        .into_iter()
+        .skip(options.skip_lines)
+        .map(|line| line.strip_suffix(b"\r").unwrap_or(line))
+        .filter(|line| !line.is_empty())
+        .skip(options.skip_rows)
+        .take(n_rows)
+        .collect();
+
+    Ok(sniff_dialect_from_lines(&lines))
+}
+
+fn sniff_dialect_from_lines(lines: &[&[u8]]) -> CsvDialect {
+    let n_comments = lines
+        .iter()
+        .filter(|line| line.first() == Some(&COMMENT_CHAR))
+        .count();
+
+    // A header may start with the comment character, e.g. `#id,name`.
+    let is_commented_header = n_comments == 1 && lines.first().map(|x| x[0]) == Some(COMMENT_CHAR);
+    let comment_prefix = (n_comments > 0 && n_comments < lines.len() && !is_commented_header)
+        .then_some(CommentPrefix::Single(COMMENT_CHAR));
+
+    let lines: Vec<&[u8]> = if comment_prefix.is_some() {
+        lines
+            .iter()
+            .copied()
+            .filter(|line| line.first() != Some(&COMMENT_CHAR))
+            .collect()
+    } else {
+        lines.to_vec()
+    };
+
+    let (separator, quote_char) = detect_separator_and_quote(&lines);
+
+    let rows: Vec<Vec<&[u8]>> = lines
+        .iter()
+        .map(|line| split_fields(line, separator, quote_char))
+        .collect();
+
+    let decimal_comma = separator != b',' && detect_decimal_comma(&rows, quote_char);
+
+    let values: Vec<Vec<String>> = rows
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|field| {
+                    let (value, _) = unquote(field, quote_char);
+                    String::from_utf8_lossy(value).trim().to_string()
+                })
+                .collect()
+        })
+        .collect();
+
+    CsvDialect {
+        separator,
+        quote_char,
+        has_header: detect_header(&values, decimal_comma),
+        comment_prefix,
+        decimal_comma,
+        try_parse_dates: detect_dates(&values, decimal_comma),
+    }
+}
+
+/// Splits a line into its raw fields, quotes included.
+fn split_fields(line: &[u8], separator: u8, quote_char: Option<u8>) -> Vec<&[u8]> {
+    let mut fields = vec![];
+    let mut in_quotes = false;
+    let mut start = 0;
+
+    for (i, &b) in line.iter().enumerate() {
+        if Some(b) == quote_char {
+            in_quotes = !in_quotes;
+        } else if b == separator && !in_quotes {
+            fields.push(&line[start..i]);
+            start = i + 1;
+        }
+    }
+
+    fields.push(&line[start..]);
+    fields
+}
+
+/// Returns the field with surrounding quotes removed, and the inner value if it was quoted.
+fn unquote(field: &[u8], quote_char: Option<u8>) -> (&[u8], Option<&[u8]>) {
+    let trimmed = field.trim_ascii();
+
+    match quote_char {
+        Some(q) if trimmed.len() >= 2 && trimmed[0] == q && trimmed[trimmed.len() - 1] == q => {
+            let inner = &trimmed[1..trimmed.len() - 1];
+            (inner, Some(inner))
+        },
+        _ => (field, None),
+    }
+}
+
+/// Chooses the separator and quote character for which the number of fields per line is most
+/// consistent. Ties are broken by the order of the candidates.
+fn detect_separator_and_quote(lines: &[&[u8]]) -> (u8, Option<u8>) {
+    let mut best = (b',', Some(b'"'));
+    let mut best_score = (0.0, 0);
+
+    for quote_char in QUOTE_CANDIDATES {
+        // A quote character that is not used to quote fields is not a candidate, apart from the
+        // default.
+        let n_quoted = lines
+            .iter()
+            .flat_map(|line| {
+                SEPARATOR_CANDIDATES
+                    .iter()
+                    .flat_map(move |&sep| split_fields(line, sep, None))
+            })
+            .filter(|field| unquote(field, Some(quote_char)).1.is_some())
+            .count();
+
+        if quote_char != b'"' && n_quoted == 0 {
+            continue;
+        }
+
+        for separator in SEPARATOR_CANDIDATES {
+            let counts: Vec<usize> = lines
+                .iter()
+                .map(|line| split_fields(line, separator, Some(quote_char)).len())
+                .collect();
+
+            let Some((mode, frequency)) = mode(&counts) else {
+                continue;
+            };
+
+            if mode < 2 {
+                continue;
+            }
+
+            let consistency = frequency as f64 / counts.len() as f64;
+            let score = (consistency, mode);
+
+            if score.0 > best_score.0 || (score.0 == best_score.0 && score.1 > best_score.1) {
+                best = (separator, Some(quote_char));
+                best_score = score;
+            }
+        }
+    }
+
+    best
+}
+
+/// Returns the most frequent value and its frequency.
+fn mode(values: &[usize]) -> Option<(usize, usize)> {
+    let mut counts = PlHashMap::<usize, usize>::new();
+
+    for &v in values {
+        *counts.entry(v).or_default() += 1;
+    }
+
+    counts
+        .into_iter()
+        .max_by_key(|&(value, frequency)| (frequency, std::cmp::Reverse(value)))
+}
+
+/// Detects numbers written with a decimal comma (e.g. `3,14`) that are not quoted.
+fn detect_decimal_comma(rows: &[Vec<&[u8]>], quote_char: Option<u8>) -> bool {
+    let is_number_with = |field: &[u8], decimal: u8| {
+        let field = field.trim_ascii();
+        let field = field
+            .strip_prefix(b"-")
+            .or_else(|| field.strip_prefix(b"+"))
+            .unwrap_or(field);
+
+        let mut parts = field.splitn(2, |&b| b == decimal);
+        let (Some(int), Some(frac)) = (parts.next(), parts.next()) else {
+            return false;
+        };
+
+        !int.is_empty()
+            && !frac.is_empty()
+            && int.iter().all(u8::is_ascii_digit)
+            && frac.iter().all(u8::is_ascii_digit)
+    };
+
+    let fields = rows
+        .iter()
+        .flatten()
+        .filter(|field| unquote(field, quote_char).1.is_none());
+
+    let mut n_comma = 0;
+
+    for field in fields {
+        if is_number_with(field, b'.') {
+            return false;
+        }
+
+        n_comma += is_number_with(field, b',') as usize;
+    }
+
+    n_comma > 0
+}
+
+/// A header is assumed if the first row does not have the types of the other rows.
+fn detect_header(rows: &[Vec<String>], decimal_comma: bool) -> bool {
+    let Some((header, body)) = rows.split_first() else {
+        return true;
+    };
+
+    if body.is_empty() {
+        return true;
+    }
+
+    let mut votes: i64 = 0;
+
+    for (i, header_value) in header.iter().enumerate() {
+        let column: Vec<&str> = body
+            .iter()
+            .filter_map(|row| row.get(i))
+            .map(|x| x.as_str())
+            .filter(|x| !x.is_empty())
+            .collect();
+
+        if column.is_empty() {
+            continue;
+        }
+
+        let possibilities: PlHashSet<DataType> = column
+            .iter()
+            .map(|x| infer_field_schema(x, false, decimal_comma))
+            .collect();
+        let dtype = finish_infer_field_schema(&possibilities);
+
+        if dtype != DataType::String {
+            if infer_field_schema(header_value, false, decimal_comma) == DataType::String {
+                votes += 1;
+            } else {
+                votes -= 1;
+            }
+        } else if column.iter().all(|x| x.len() == column[0].len()) {
+            // Fixed-length string columns, e.g. codes.
+            if header_value.len() == column[0].len() {
+                votes -= 1;
+            } else {
+                votes += 1;
+            }
+        }
+    }
+
+    match votes {
+        v if v > 0 => true,
+        v if v < 0 => false,
+        // Without evidence, assume a header if its values could be column names.
+        _ => {
+            let names: PlHashSet<&str> = header.iter().map(|x| x.as_str()).collect();
+            names.len() == header.len() && !names.contains("")
+        },
+    }
+}
+
+/// Detects columns that are dates or datetimes when parsing dates is enabled.
+fn detect_dates(rows: &[Vec<String>], decimal_comma: bool) -> bool {
+    if !cfg!(feature = "polars-time") {
+        return false;
+    }
+
+    let n_columns = rows.iter().map(|row| row.len()).max().unwrap_or(0);
+
+    // The first row may be the header, which is skipped when it is not a date.
+    (0..n_columns).any(|i| {
+        let possibilities: PlHashSet<DataType> = rows
+            .iter()
+            .skip(1)
+            .filter_map(|row| row.get(i))
+            .filter(|x| !x.is_empty())
+            .map(|x| infer_field_schema(x, true, decimal_comma))
+            .collect();
+
+        !possibilities.is_empty() && finish_infer_field_schema(&possibilities).is_temporal()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sniff(csv: &str) -> CsvDialect {
+        let mut reader =
+            CompressedReader::try_new(Buffer::from_vec(csv.as_bytes().to_vec())).unwrap();
+        sniff_csv_dialect(&CsvReadOptions::default(), &mut reader).unwrap()
+    }
+
+    #[test]
+    fn test_sniff_separator_and_header() {
+        let dialect = sniff("id;name;score\n1;\"a;b\";3,5\n2;c;4,25\n");
+        assert_eq!(dialect.separator, b';');
+        assert_eq!(dialect.quote_char, Some(b'"'));
+        assert!(dialect.has_header);
+        assert!(dialect.decimal_comma);
+        assert_eq!(dialect.comment_prefix, None);
+
+        let dialect = sniff("1\t2.5\tx\n2\t3.5\ty\n");
+        assert_eq!(dialect.separator, b'\t');
+        assert!(!dialect.has_header);
+        assert!(!dialect.decimal_comma);
+
+        let dialect = sniff("a|b\n'x|y'|1\n'z'|2\n");
+        assert_eq!(dialect.separator, b'|');
+        assert_eq!(dialect.quote_char, Some(b'\''));
+    }
+
+    #[test]
+    fn test_sniff_comment() {
+        let dialect = sniff("# exported\nname,text\n# note\nx,\"say \"\"hi\"\"\"\ny,z\n");
+        assert_eq!(dialect.separator, b',');
+        assert_eq!(dialect.comment_prefix, Some(CommentPrefix::Single(b'#')));
+        assert!(dialect.has_header);
+
+        // A header starting with the comment character is not a comment.
+        let dialect = sniff("#id,value\n1,2\n3,4\n");
+        assert_eq!(dialect.comment_prefix, None);
+        assert!(dialect.has_header);
+    }
+}
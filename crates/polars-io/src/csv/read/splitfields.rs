@@ -6,6 +6,7 @@ mod inner {
     pub(crate) struct SplitFields<'a> {
         v: &'a [u8],
         separator: u8,
+        multi_byte_separator: Option<&'a [u8]>,
         finished: bool,
         quote_char: u8,
         quoting: bool,
@@ -15,13 +16,18 @@ mod inner {
     impl<'a> SplitFields<'a> {
         pub(crate) fn new(
             slice: &'a [u8],
-            separator: u8,
+            separator: &'a [u8],
             quote_char: Option<u8>,
             eol_char: u8,
         ) -> Self {
+            let (separator, multi_byte_separator) = match separator {
+                [c] => (*c, None),
+                _ => (separator[0], Some(separator)),
+            };
             Self {
                 v: slice,
                 separator,
+                multi_byte_separator,
                 finished: false,
                 quote_char: quote_char.unwrap_or(b'"'),
                 quoting: quote_char.is_some(),
@@ -47,6 +53,28 @@ mod inner {
         fn eof_eol(&self, current_ch: u8) -> bool {
             current_ch == self.separator || current_ch == self.eol_char
         }
+
+        fn next_multi_byte(&mut self, separator: &[u8]) -> Option<(&'a [u8], bool)> {
+            if self.v.is_empty() {
+                return self.finish(false);
+            }
+
+            let needs_escaping = self.quoting && self.v[0] == self.quote_char;
+            let quote_char = needs_escaping.then_some(self.quote_char);
+
+            match super::find_field_end_multi_byte(self.v, separator, quote_char, self.eol_char) {
+                None => self.finish(needs_escaping),
+                // SAFETY: `pos` is in bounds.
+                Some(pos) if self.v[pos] == self.eol_char => unsafe {
+                    self.finish_eol(needs_escaping, pos)
+                },
+                Some(pos) => {
+                    let field = &self.v[..pos];
+                    self.v = &self.v[pos + separator.len()..];
+                    Some((field, needs_escaping))
+                },
+            }
+        }
     }
 
     impl<'a> Iterator for SplitFields<'a> {
@@ -57,7 +85,11 @@ mod inner {
         fn next(&mut self) -> Option<(&'a [u8], bool)> {
             if self.finished {
                 return None;
-            } else if self.v.is_empty() {
+            }
+            if let Some(separator) = self.multi_byte_separator {
+                return self.next_multi_byte(separator);
+            }
+            if self.v.is_empty() {
                 return self.finish(false);
             }
 
@@ -149,6 +181,7 @@ mod inner {
     pub(crate) struct SplitFields<'a> {
         pub v: &'a [u8],
         separator: u8,
+        multi_byte_separator: Option<&'a [u8]>,
         pub finished: bool,
         quote_char: u8,
         quoting: bool,
@@ -162,10 +195,14 @@ mod inner {
     impl<'a> SplitFields<'a> {
         pub(crate) fn new(
             slice: &'a [u8],
-            separator: u8,
+            separator: &'a [u8],
             quote_char: Option<u8>,
             eol_char: u8,
         ) -> Self {
+            let (separator, multi_byte_separator) = match separator {
+                [c] => (*c, None),
+                _ => (separator[0], Some(separator)),
+            };
             let simd_separator = SimdVec::splat(separator);
             let simd_eol_char = SimdVec::splat(eol_char);
             let quoting = quote_char.is_some();
@@ -175,6 +212,7 @@ mod inner {
             Self {
                 v: slice,
                 separator,
+                multi_byte_separator,
                 finished: false,
                 quote_char,
                 quoting,
@@ -205,6 +243,28 @@ mod inner {
         fn eof_eol(&self, current_ch: u8) -> bool {
             current_ch == self.separator || current_ch == self.eol_char
         }
+
+        fn next_multi_byte(&mut self, separator: &[u8]) -> Option<(&'a [u8], bool)> {
+            if self.v.is_empty() {
+                return self.finish(false);
+            }
+
+            let needs_escaping = self.quoting && self.v[0] == self.quote_char;
+            let quote_char = needs_escaping.then_some(self.quote_char);
+
+            match super::find_field_end_multi_byte(self.v, separator, quote_char, self.eol_char) {
+                None => self.finish(needs_escaping),
+                // SAFETY: `pos` is in bounds.
+                Some(pos) if self.v[pos] == self.eol_char => unsafe {
+                    self.finish_eol(needs_escaping, pos)
+                },
+                Some(pos) => {
+                    let field = &self.v[..pos];
+                    self.v = &self.v[pos + separator.len()..];
+                    Some((field, needs_escaping))
+                },
+            }
+        }
     }
 
     impl<'a> Iterator for SplitFields<'a> {
@@ -217,6 +277,10 @@ mod inner {
             if self.finished {
                 return None;
             }
+            // Separators of more than one byte don't use SIMD.
+            if let Some(separator) = self.multi_byte_separator {
+                return self.next_multi_byte(separator);
+            }
             // Then check cached value as this is hot.
             if self.previous_valid_ends != 0 {
                 let pos = self.previous_valid_ends.trailing_zeros() as usize;
@@ -405,6 +469,37 @@ mod inner {
 
 pub(crate) use inner::SplitFields;
 
+/// Returns the position of the first separator or `eol_char` ending a field, for separators of
+/// more than one byte. `quote_char` must only be given if the field starts with it.
+fn find_field_end_multi_byte(
+    v: &[u8],
+    separator: &[u8],
+    quote_char: Option<u8>,
+    eol_char: u8,
+) -> Option<usize> {
+    let Some(quote_char) = quote_char else {
+        let mut offset = 0;
+        while let Some(pos) = memchr::memchr2(separator[0], eol_char, &v[offset..]) {
+            let pos = offset + pos;
+            if v[pos] == eol_char || v[pos..].starts_with(separator) {
+                return Some(pos);
+            }
+            offset = pos + 1;
+        }
+        return None;
+    };
+
+    let mut in_field = false;
+    for (i, &c) in v.iter().enumerate() {
+        if c == quote_char {
+            in_field = !in_field;
+        } else if !in_field && (c == eol_char || v[i..].starts_with(separator)) {
+            return Some(i);
+        }
+    }
+    None
+}
+
 #[cfg(test)]
 mod test {
     use super::SplitFields;
@@ -412,18 +507,38 @@ mod test {
     #[test]
     fn test_splitfields() {
         let input = "\"foo\",\"bar\"";
-        let mut fields = SplitFields::new(input.as_bytes(), b',', Some(b'"'), b'\n');
+        let mut fields = SplitFields::new(input.as_bytes(), b",", Some(b'"'), b'\n');
 
         assert_eq!(fields.next(), Some(("\"foo\"".as_bytes(), true)));
         assert_eq!(fields.next(), Some(("\"bar\"".as_bytes(), true)));
         assert_eq!(fields.next(), None);
 
         let input2 = "\"foo\n bar\";\"baz\";12345";
-        let mut fields2 = SplitFields::new(input2.as_bytes(), b';', Some(b'"'), b'\n');
+        let mut fields2 = SplitFields::new(input2.as_bytes(), b";", Some(b'"'), b'\n');
 
         assert_eq!(fields2.next(), Some(("\"foo\n bar\"".as_bytes(), true)));
         assert_eq!(fields2.next(), Some(("\"baz\"".as_bytes(), true)));
         assert_eq!(fields2.next(), Some(("12345".as_bytes(), false)));
         assert_eq!(fields2.next(), None);
     }
+
+    #[test]
+    fn test_splitfields_multi_byte_separator() {
+        let input = "a~|~\"b~|~c\"~|~d|e~|~\nx";
+        let mut fields = SplitFields::new(input.as_bytes(), b"~|~", Some(b'"'), b'\n');
+
+        assert_eq!(fields.next(), Some(("a".as_bytes(), false)));
+        assert_eq!(fields.next(), Some(("\"b~|~c\"".as_bytes(), true)));
+        assert_eq!(fields.next(), Some(("d|e".as_bytes(), false)));
+        assert_eq!(fields.next(), Some(("".as_bytes(), false)));
+        assert_eq!(fields.next(), None);
+
+        let input2 = "1\x01\x022\x01\x01\x023";
+        let fields2 = SplitFields::new(input2.as_bytes(), b"\x01\x02", None, b'\n');
+        let fields2: Vec<_> = fields2.map(|(field, _)| field).collect();
+        assert_eq!(
+            fields2,
+            [b"1".as_slice(), b"2\x01".as_slice(), b"3".as_slice()]
+        );
+    }
 }
@@ -202,6 +202,11 @@ fn for_each_line_from_reader(
         }
 
         let line_to_sub_slice = |line: &[u8]| {
+            // A `\r\n` line terminator when the eol char is `\r`.
+            let line = match line {
+                [b'\n', rest @ ..] if parse_options.eol_char == b'\r' => rest,
+                _ => line,
+            };
             let start = line.as_ptr() as usize - slice.as_ptr() as usize;
             slice.clone().sliced(start..(start + line.len()))
         };
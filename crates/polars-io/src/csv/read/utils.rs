@@ -12,7 +12,7 @@ use super::splitfields::SplitFields;
 fn decompress_impl<R: Read>(
     decoder: &mut R,
     n_rows: Option<usize>,
-    separator: u8,
+    separator: &[u8],
     quote_char: Option<u8>,
     eol_char: u8,
 ) -> Option<Vec<u8>> {
@@ -88,7 +88,7 @@ fn decompress_impl<R: Read>(
 pub(crate) fn decompress(
     bytes: &[u8],
     n_rows: Option<usize>,
-    separator: u8,
+    separator: &[u8],
     quote_char: Option<u8>,
     eol_char: u8,
 ) -> Option<Vec<u8>> {
@@ -6,6 +6,7 @@ use polars_utils::pl_str::PlSmallStr;
 use serde::{Deserialize, Serialize};
 
 use crate::ExternalCompression;
+use crate::csv::read::CsvSeparator;
 
 /// Options for writing CSV files.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
@@ -55,7 +56,7 @@ pub struct SerializeOptions {
     /// Use comma as the decimal separator.
     pub decimal_comma: bool,
     /// Used as separator.
-    pub separator: u8,
+    pub separator: CsvSeparator,
     /// Quoting character.
     pub quote_char: u8,
     /// Null value representation.
@@ -75,7 +76,7 @@ impl Default for SerializeOptions {
             float_scientific: None,
             float_precision: None,
             decimal_comma: false,
-            separator: b',',
+            separator: CsvSeparator::Single(b','),
             quote_char: b'"',
             null: PlSmallStr::EMPTY,
             line_terminator: "\n".into(),
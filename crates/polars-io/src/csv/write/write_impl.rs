@@ -156,10 +156,12 @@ impl CsvSerializer {
         let mut serializers_vec = reuse_vec(std::mem::take(&mut self.serializers));
         let serializers = self.build_serializers(df.columns(), &mut serializers_vec)?;
 
+        let separator = options.separator.as_bytes();
+
         for _ in 0..df.height() {
             serializers[0].serialize(buffer, options);
             for serializer in &mut serializers[1..] {
-                buffer.push(options.separator);
+                buffer.extend_from_slice(separator);
                 serializer.serialize(buffer, options);
             }
 
@@ -270,7 +272,7 @@ pub fn csv_header(names: &[&str], options: &SerializeOptions) -> PolarsResult<Ve
     for i in 0..names.len() {
         names_serializer.serialize(&mut header, options);
         if i != names.len() - 1 {
-            header.push(options.separator);
+            header.extend_from_slice(options.separator.as_bytes());
         }
     }
     header.extend_from_slice(options.line_terminator.as_bytes());
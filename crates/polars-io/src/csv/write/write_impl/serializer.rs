@@ -38,7 +38,7 @@ use arrow::legacy::time_zone::Tz;
 use arrow::types::NativeType;
 #[cfg(feature = "timezones")]
 use chrono::TimeZone;
-use memchr::{memchr_iter, memchr3};
+use memchr::{memchr_iter, memchr2, memchr3, memmem};
 use num_traits::NumCast;
 use polars_core::prelude::*;
 use polars_utils::float16::pf16;
@@ -428,6 +428,19 @@ fn date_and_time_final_serializer<'a, T: NativeType>(
     }
 }
 
+/// Whether a field followed by the multi-byte `separator` would be split at a different position.
+///
+/// This is the case if the field contains the separator, or if it ends in a part of the
+/// separator that starts a match together with the separator that follows it.
+fn contains_multi_byte_separator(field: &[u8], separator: &[u8]) -> bool {
+    if memmem::find(field, separator).is_some() {
+        return true;
+    }
+    let n = separator.len();
+    (1..n.min(field.len() + 1))
+        .any(|k| field.ends_with(&separator[..k]) && separator[..n - k] == separator[k..])
+}
+
 pub(super) fn string_serializer<'a, Iter: Send + 'a>(
     mut f: impl FnMut(&mut Iter) -> Option<&str> + Send + 'a,
     options: &SerializeOptions,
@@ -526,7 +539,13 @@ pub(super) fn string_serializer<'a, Iter: Send + 'a>(
                         buf.extend_from_slice(&[quote_char, quote_char]);
                         return;
                     }
-                    let needs_quote = memchr3(options.separator, LF, CR, s.as_bytes()).is_some();
+                    let needs_quote = match options.separator.as_bytes() {
+                        [separator] => memchr3(*separator, LF, CR, s.as_bytes()).is_some(),
+                        separator => {
+                            memchr2(LF, CR, s.as_bytes()).is_some()
+                                || contains_multi_byte_separator(s.as_bytes(), separator)
+                        },
+                    };
                     if needs_quote {
                         buf.push(quote_char);
                     }
@@ -584,7 +603,8 @@ pub(super) fn serializer_for<'a>(
             // (e.g., in scientific notation when only the first digit is non-zero such as '1e12',
             // or null values in 'non_numeric' quote_style).
 
-            let mut should_quote = options.decimal_comma && options.separator == b',';
+            let mut should_quote =
+                options.decimal_comma && options.separator.as_bytes().contains(&b',');
             if let Some(precision) = options.float_precision {
                 should_quote &= precision > 0;
             }
@@ -598,7 +618,8 @@ pub(super) fn serializer_for<'a>(
         #[cfg(feature = "dtype-decimal")]
         DataType::Decimal(_, scale) => {
             // Similar to logic for float data-types, but need to consider scale rather than precision
-            let should_quote = options.decimal_comma && options.separator == b',' && *scale > 0;
+            let should_quote =
+                options.decimal_comma && options.separator.as_bytes().contains(&b',') && *scale > 0;
 
             match options.quote_style {
                 QuoteStyle::Always => true,
@@ -10,6 +10,7 @@ use polars_utils::pl_str::PlSmallStr;
 
 use super::write_impl::{UTF8_BOM, csv_header, write};
 use super::{QuoteStyle, SerializeOptions};
+use crate::csv::read::CsvSeparator;
 use crate::shared::SerWriter;
 
 /// Write a DataFrame to csv.
@@ -86,9 +87,9 @@ where
         self
     }
 
-    /// Set the CSV file's column separator as a byte character.
-    pub fn with_separator(mut self, separator: u8) -> Self {
-        self.options_mut().separator = separator;
+    /// Set the CSV file's column separator as a byte character or a string.
+    pub fn with_separator<T: Into<CsvSeparator>>(mut self, separator: T) -> Self {
+        self.options_mut().separator = separator.into();
         self
     }
 
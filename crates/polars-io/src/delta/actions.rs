@@ -0,0 +1,86 @@
+use polars_core::prelude::PlHashMap;
+
+use super::deletion_vector::DeletionVectorDescriptor;
+
+/// A single line of a Delta commit file. Exactly one of the fields is expected to be set.
+#[derive(Debug, Default, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct Action {
+    #[serde(default)]
+    pub add: Option<AddAction>,
+    #[serde(default)]
+    pub remove: Option<RemoveAction>,
+    #[serde(default)]
+    pub meta_data: Option<MetadataAction>,
+    #[serde(default)]
+    pub protocol: Option<ProtocolAction>,
+    #[serde(default)]
+    pub commit_info: Option<CommitInfoAction>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct AddAction {
+    pub path: String,
+    #[serde(default)]
+    pub partition_values: PlHashMap<String, Option<String>>,
+    pub size: i64,
+    /// JSON encoded file statistics.
+    #[serde(default)]
+    pub stats: Option<String>,
+    #[serde(default)]
+    pub deletion_vector: Option<DeletionVectorDescriptor>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct RemoveAction {
+    pub path: String,
+    #[serde(default)]
+    pub deletion_vector: Option<DeletionVectorDescriptor>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct MetadataAction {
+    pub schema_string: String,
+    #[serde(default)]
+    pub partition_columns: Vec<String>,
+    #[serde(default)]
+    pub configuration: PlHashMap<String, Option<String>>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct ProtocolAction {
+    pub min_reader_version: i32,
+    #[serde(default)]
+    pub reader_features: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct CommitInfoAction {
+    #[serde(default)]
+    pub timestamp: Option<i64>,
+    #[serde(default)]
+    pub in_commit_timestamp: Option<i64>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct FileStatistics {
+    #[serde(default)]
+    pub num_records: Option<u64>,
+}
+
+/// Key identifying a logical file: the data file path together with the deletion vector id.
+pub(super) fn logical_file_key(
+    path: &str,
+    deletion_vector: Option<&DeletionVectorDescriptor>,
+) -> (String, Option<String>) {
+    (
+        path.to_string(),
+        deletion_vector.map(DeletionVectorDescriptor::unique_id),
+    )
+}
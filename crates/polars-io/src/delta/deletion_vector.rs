@@ -0,0 +1,341 @@
+use polars_error::{PolarsResult, polars_bail, polars_ensure, polars_err};
+use polars_utils::pl_path::PlRefPath;
+
+use crate::cloud::CloudOptions;
+use crate::utils::byte_source::{ByteSource, DynByteSourceBuilder};
+
+/// Magic number at the start of a serialized deletion vector.
+const DELETION_VECTOR_MAGIC: u32 = 1681511377;
+
+const SERIAL_COOKIE_NO_RUNCONTAINER: u32 = 12346;
+const SERIAL_COOKIE: u32 = 12347;
+const NO_OFFSET_THRESHOLD: usize = 4;
+const ARRAY_CONTAINER_MAX_CARDINALITY: usize = 4096;
+
+const Z85_ALPHABET: &[u8; 85] =
+    b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ.-:+=^!/*?&<>()[]{}@%$#";
+
+/// Deletion vector descriptor of an `add` or `remove` action.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeletionVectorDescriptor {
+    /// One of `u` (relative path), `i` (inline) or `p` (absolute path).
+    pub storage_type: String,
+    pub path_or_inline_dv: String,
+    /// Byte offset of the deletion vector within the file. Absent for inline deletion vectors.
+    #[serde(default)]
+    pub offset: Option<i32>,
+    pub size_in_bytes: i32,
+    /// Number of deleted rows.
+    pub cardinality: i64,
+}
+
+impl DeletionVectorDescriptor {
+    /// Identifier that together with the data file path uniquely identifies a logical file.
+    pub fn unique_id(&self) -> String {
+        match self.offset {
+            Some(offset) => format!("{}{}@{}", self.storage_type, self.path_or_inline_dv, offset),
+            None => format!("{}{}", self.storage_type, self.path_or_inline_dv),
+        }
+    }
+
+    /// Returns the serialized bitmap if it is stored inline.
+    pub fn inline_bytes(&self) -> PolarsResult<Option<Vec<u8>>> {
+        if self.storage_type != "i" {
+            return Ok(None);
+        }
+
+        let mut bytes = z85_decode(&self.path_or_inline_dv)?;
+        bytes.truncate(usize::try_from(self.size_in_bytes).unwrap_or(0));
+
+        Ok(Some(bytes))
+    }
+
+    /// Returns the absolute path of the deletion vector file, or `None` if the deletion vector
+    /// is stored inline.
+    pub fn absolute_path(&self, table_root: &str) -> PolarsResult<Option<String>> {
+        let path = match self.storage_type.as_str() {
+            "i" => return Ok(None),
+            "p" => self.path_or_inline_dv.clone(),
+            "u" => {
+                let s = self.path_or_inline_dv.as_str();
+                polars_ensure!(
+                    s.len() >= 20,
+                    ComputeError: "invalid delta deletion vector path: {}", s
+                );
+
+                let (prefix, encoded_uuid) = s.split_at(s.len() - 20);
+                let uuid = format_uuid(&z85_decode(encoded_uuid)?);
+                let table_root = table_root.trim_end_matches('/');
+
+                if prefix.is_empty() {
+                    format!("{table_root}/deletion_vector_{uuid}.bin")
+                } else {
+                    format!("{table_root}/{prefix}/deletion_vector_{uuid}.bin")
+                }
+            },
+            v => polars_bail!(ComputeError: "unknown delta deletion vector storage type: {}", v),
+        };
+
+        Ok(Some(path))
+    }
+}
+
+/// Decodes a serialized deletion vector into the sorted positions of the deleted rows.
+///
+/// The serialization is a magic number followed by a "portable" `RoaringBitmapArray`, which
+/// stores one 32-bit roaring bitmap per distinct value of the upper 32 bits of the positions.
+pub fn decode_deletion_vector(bytes: &[u8]) -> PolarsResult<Vec<u64>> {
+    let mut cursor = Cursor { bytes, offset: 0 };
+
+    polars_ensure!(
+        cursor.read_u32()? == DELETION_VECTOR_MAGIC,
+        ComputeError: "invalid delta deletion vector: unexpected magic number"
+    );
+
+    let n_bitmaps = cursor.read_u64()?;
+    let mut out = vec![];
+
+    for _ in 0..n_bitmaps {
+        let high = u64::from(cursor.read_u32()?) << 32;
+        decode_roaring_bitmap(&mut cursor, |low| out.push(high | u64::from(low)))?;
+    }
+
+    Ok(out)
+}
+
+/// Loads the deletion vector stored at `offset` in the file at `path`.
+///
+/// The serialized bitmap is prefixed by its big-endian size and followed by a checksum.
+pub async fn load_deletion_vector_file(
+    path: &str,
+    offset: u64,
+    size_in_bytes: u64,
+    cloud_options: Option<&CloudOptions>,
+) -> PolarsResult<Vec<u64>> {
+    let source = DynByteSourceBuilder::ObjectStore
+        .try_build_from_path(PlRefPath::new(path), cloud_options)
+        .await?;
+
+    let start = usize::try_from(offset).unwrap();
+    let len = usize::try_from(size_in_bytes).unwrap();
+    let file_size = source.get_size().await?;
+
+    polars_ensure!(
+        start + 4 + len <= file_size,
+        ComputeError: "invalid delta deletion vector file: {}: out of bounds read", path
+    );
+
+    let bytes = source.get_range(start..start + 4 + len).await?;
+    let stored_len = u32::from_be_bytes(bytes[..4].try_into().unwrap()) as usize;
+
+    polars_ensure!(
+        stored_len == len,
+        ComputeError: "invalid delta deletion vector file: {}: size mismatch", path
+    );
+
+    decode_deletion_vector(&bytes[4..])
+}
+
+/// Decodes a roaring bitmap in the portable serialization format.
+fn decode_roaring_bitmap(cursor: &mut Cursor<'_>, mut push: impl FnMut(u32)) -> PolarsResult<()> {
+    let cookie = cursor.read_u32()?;
+
+    let (n_containers, run_flags) = if cookie & 0xFFFF == SERIAL_COOKIE {
+        let n_containers = (cookie >> 16) as usize + 1;
+        let run_flags = cursor.read_bytes(n_containers.div_ceil(8))?;
+        (n_containers, Some(run_flags))
+    } else if cookie == SERIAL_COOKIE_NO_RUNCONTAINER {
+        (cursor.read_u32()? as usize, None)
+    } else {
+        polars_bail!(ComputeError: "invalid delta deletion vector: unknown roaring cookie {}", cookie)
+    };
+
+    let is_run = |i: usize| run_flags.is_some_and(|flags| flags[i / 8] & (1 << (i % 8)) != 0);
+
+    let mut headers = Vec::with_capacity(n_containers);
+    for _ in 0..n_containers {
+        let key = cursor.read_u16()?;
+        let cardinality = cursor.read_u16()? as usize + 1;
+        headers.push((key, cardinality));
+    }
+
+    // Container offsets are only needed for random access.
+    if run_flags.is_none() || n_containers >= NO_OFFSET_THRESHOLD {
+        cursor.read_bytes(4 * n_containers)?;
+    }
+
+    for (i, &(key, cardinality)) in headers.iter().enumerate() {
+        let high = u32::from(key) << 16;
+
+        if is_run(i) {
+            let n_runs = cursor.read_u16()?;
+            for _ in 0..n_runs {
+                let start = u32::from(cursor.read_u16()?);
+                let len = u32::from(cursor.read_u16()?);
+                (start..=start + len).for_each(|low| push(high | low));
+            }
+        } else if cardinality <= ARRAY_CONTAINER_MAX_CARDINALITY {
+            for _ in 0..cardinality {
+                push(high | u32::from(cursor.read_u16()?));
+            }
+        } else {
+            for word_idx in 0..1024u32 {
+                let mut word = cursor.read_u64()?;
+                while word != 0 {
+                    let bit = word.trailing_zeros();
+                    push(high | (word_idx * 64 + bit));
+                    word &= word - 1;
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+struct Cursor<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn read_bytes(&mut self, n: usize) -> PolarsResult<&'a [u8]> {
+        let out = self
+            .bytes
+            .get(self.offset..self.offset + n)
+            .ok_or_else(|| polars_err!(ComputeError: "invalid delta deletion vector: truncated"))?;
+        self.offset += n;
+        Ok(out)
+    }
+
+    fn read_u16(&mut self) -> PolarsResult<u16> {
+        Ok(u16::from_le_bytes(self.read_bytes(2)?.try_into().unwrap()))
+    }
+
+    fn read_u32(&mut self) -> PolarsResult<u32> {
+        Ok(u32::from_le_bytes(self.read_bytes(4)?.try_into().unwrap()))
+    }
+
+    fn read_u64(&mut self) -> PolarsResult<u64> {
+        Ok(u64::from_le_bytes(self.read_bytes(8)?.try_into().unwrap()))
+    }
+}
+
+/// Decodes a Z85 encoded string. Every 5 characters decode to 4 big-endian bytes.
+fn z85_decode(s: &str) -> PolarsResult<Vec<u8>> {
+    polars_ensure!(
+        s.len().is_multiple_of(5),
+        ComputeError: "invalid z85 string length: {}", s.len()
+    );
+
+    let mut lookup = [u8::MAX; 256];
+    for (i, &c) in Z85_ALPHABET.iter().enumerate() {
+        lookup[c as usize] = i as u8;
+    }
+
+    let mut out = Vec::with_capacity(s.len() / 5 * 4);
+
+    for chunk in s.as_bytes().chunks_exact(5) {
+        let mut value: u64 = 0;
+        for &c in chunk {
+            let digit = lookup[c as usize];
+            polars_ensure!(
+                digit != u8::MAX,
+                ComputeError: "invalid z85 character: {:?}", c as char
+            );
+            value = value * 85 + u64::from(digit);
+        }
+
+        let value = u32::try_from(value)
+            .map_err(|_| polars_err!(ComputeError: "invalid z85 string: value overflow"))?;
+        out.extend_from_slice(&value.to_be_bytes());
+    }
+
+    Ok(out)
+}
+
+fn format_uuid(bytes: &[u8]) -> String {
+    use std::fmt::Write;
+
+    let mut out = String::with_capacity(36);
+    for (i, b) in bytes.iter().enumerate() {
+        if [4, 6, 8, 10].contains(&i) {
+            out.push('-');
+        }
+        write!(out, "{b:02x}").unwrap();
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_z85_decode() -> PolarsResult<()> {
+        assert_eq!(
+            z85_decode("HelloWorld")?,
+            [0x86, 0x4F, 0xD2, 0x6F, 0xB5, 0x59, 0xF7, 0x5B]
+        );
+        assert!(z85_decode("Hell").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_decode_deletion_vector() -> PolarsResult<()> {
+        let mut bytes = vec![];
+        bytes.extend(DELETION_VECTOR_MAGIC.to_le_bytes());
+        bytes.extend(2u64.to_le_bytes());
+
+        // Bitmap with high bits 0: array container {3, 7} and run container [65536, 65538].
+        bytes.extend(0u32.to_le_bytes());
+        bytes.extend((SERIAL_COOKIE | (1 << 16)).to_le_bytes());
+        bytes.push(0b10);
+        for (key, card) in [(0u16, 2u16), (1, 3)] {
+            bytes.extend(key.to_le_bytes());
+            bytes.extend((card - 1).to_le_bytes());
+        }
+        for v in [3u16, 7] {
+            bytes.extend(v.to_le_bytes());
+        }
+        for v in [1u16, 0, 2] {
+            bytes.extend(v.to_le_bytes());
+        }
+
+        // Bitmap with high bits 1: array container {5} without run containers.
+        bytes.extend(1u32.to_le_bytes());
+        bytes.extend(SERIAL_COOKIE_NO_RUNCONTAINER.to_le_bytes());
+        bytes.extend(1u32.to_le_bytes());
+        bytes.extend(0u16.to_le_bytes());
+        bytes.extend(0u16.to_le_bytes());
+        bytes.extend(0u32.to_le_bytes());
+        bytes.extend(5u16.to_le_bytes());
+
+        assert_eq!(
+            decode_deletion_vector(&bytes)?,
+            [3, 7, 65536, 65537, 65538, (1 << 32) | 5]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_deletion_vector_absolute_path() -> PolarsResult<()> {
+        let dv = DeletionVectorDescriptor {
+            storage_type: "u".into(),
+            path_or_inline_dv: "ab^-aqEH.-t@S}K{vb[*k^".into(),
+            offset: Some(4),
+            size_in_bytes: 40,
+            cardinality: 6,
+        };
+
+        assert_eq!(
+            dv.absolute_path("s3://bucket/table/")?.as_deref(),
+            Some("s3://bucket/table/ab/deletion_vector_d2c639aa-8816-431a-aaf6-d3fe2512ff61.bin")
+        );
+
+        Ok(())
+    }
+}
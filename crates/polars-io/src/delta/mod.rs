@@ -0,0 +1,18 @@
+//! Reading and appending to Delta Lake tables.
+//!
+//! The active file set of a table version is resolved by replaying the JSON commits and parquet
+//! checkpoints in the `_delta_log` directory.
+mod actions;
+mod deletion_vector;
+mod schema;
+mod snapshot;
+mod write;
+
+pub use deletion_vector::{
+    DeletionVectorDescriptor, decode_deletion_vector, load_deletion_vector_file,
+};
+pub use schema::{parse_delta_schema_string, schema_to_delta_schema_string};
+pub use snapshot::{DeltaDataFile, DeltaSnapshot, DeltaVersion};
+pub use write::{check_delta_append, commit_delta_append, write_delta_table};
+
+pub use crate::utils::file::delta_data_file_name;
@@ -0,0 +1,280 @@
+use polars_core::prelude::*;
+use polars_error::to_compute_err;
+
+/// A field of a Delta `struct` type.
+#[derive(Debug, serde::Deserialize)]
+struct DeltaStructField {
+    name: PlSmallStr,
+    #[serde(rename = "type")]
+    type_: DeltaType,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(untagged)]
+enum DeltaType {
+    /// Primitive types, e.g. `"long"` or `"decimal(10,2)"`.
+    Primitive(String),
+    Nested(Box<DeltaNestedType>),
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+enum DeltaNestedType {
+    Struct {
+        fields: Vec<DeltaStructField>,
+    },
+    Array {
+        #[serde(rename = "elementType")]
+        element_type: DeltaType,
+    },
+    Map {
+        #[serde(rename = "keyType")]
+        key_type: DeltaType,
+        #[serde(rename = "valueType")]
+        value_type: DeltaType,
+    },
+}
+
+/// Parses the `schemaString` of a Delta `metaData` action.
+pub fn parse_delta_schema_string(schema_string: &str) -> PolarsResult<Schema> {
+    let root: DeltaNestedType = serde_json::from_str(schema_string).map_err(to_compute_err)?;
+
+    let DeltaNestedType::Struct { fields } = root else {
+        polars_bail!(ComputeError: "delta table schema must be a struct type")
+    };
+
+    fields
+        .iter()
+        .map(|f| Ok(Field::new(f.name.clone(), delta_type_to_dtype(&f.type_)?)))
+        .collect()
+}
+
+/// Creates the `schemaString` of a Delta `metaData` action. All fields are marked as nullable.
+pub fn schema_to_delta_schema_string(schema: &Schema) -> PolarsResult<String> {
+    let fields = schema
+        .iter()
+        .map(|(name, dtype)| dtype_to_delta_field(name, dtype))
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    Ok(serde_json::json!({"type": "struct", "fields": fields}).to_string())
+}
+
+fn dtype_to_delta_field(name: &str, dtype: &DataType) -> PolarsResult<serde_json::Value> {
+    Ok(serde_json::json!({
+        "name": name,
+        "type": dtype_to_delta_type(dtype)?,
+        "nullable": true,
+        "metadata": {},
+    }))
+}
+
+fn dtype_to_delta_type(dtype: &DataType) -> PolarsResult<serde_json::Value> {
+    use DataType::*;
+    use serde_json::{Value, json};
+
+    Ok(match dtype {
+        Boolean => Value::from("boolean"),
+        Int8 => Value::from("byte"),
+        Int16 => Value::from("short"),
+        Int32 => Value::from("integer"),
+        Int64 => Value::from("long"),
+        Float32 => Value::from("float"),
+        Float64 => Value::from("double"),
+        String => Value::from("string"),
+        Binary => Value::from("binary"),
+        Date => Value::from("date"),
+        Datetime(TimeUnit::Microseconds, None) => Value::from("timestamp_ntz"),
+        Datetime(TimeUnit::Microseconds, Some(tz)) if tz == &TimeZone::UTC => {
+            Value::from("timestamp")
+        },
+        #[cfg(feature = "dtype-decimal")]
+        Decimal(precision, scale) => Value::from(format!("decimal({precision},{scale})")),
+        List(inner) => match inner.as_ref() {
+            // Lists of key/value structs are written as maps, mirroring the reader.
+            Struct(fields)
+                if fields.len() == 2 && fields[0].name == "key" && fields[1].name == "value" =>
+            {
+                json!({
+                    "type": "map",
+                    "keyType": dtype_to_delta_type(fields[0].dtype())?,
+                    "valueType": dtype_to_delta_type(fields[1].dtype())?,
+                    "valueContainsNull": true,
+                })
+            },
+            inner => json!({
+                "type": "array",
+                "elementType": dtype_to_delta_type(inner)?,
+                "containsNull": true,
+            }),
+        },
+        Struct(fields) => json!({
+            "type": "struct",
+            "fields": fields
+                .iter()
+                .map(|f| dtype_to_delta_field(f.name(), f.dtype()))
+                .collect::<PolarsResult<Vec<_>>>()?,
+        }),
+        v => polars_bail!(
+            ComputeError:
+            "unsupported data type for delta table: {} (cast to a supported type first)",
+            v
+        ),
+    })
+}
+
+fn delta_type_to_dtype(delta_type: &DeltaType) -> PolarsResult<DataType> {
+    let nested = match delta_type {
+        DeltaType::Primitive(name) => return delta_primitive_to_dtype(name),
+        DeltaType::Nested(nested) => nested,
+    };
+
+    Ok(match nested.as_ref() {
+        DeltaNestedType::Struct { fields } => DataType::Struct(
+            fields
+                .iter()
+                .map(|f| Ok(Field::new(f.name.clone(), delta_type_to_dtype(&f.type_)?)))
+                .collect::<PolarsResult<_>>()?,
+        ),
+        DeltaNestedType::Array { element_type } => {
+            DataType::List(Box::new(delta_type_to_dtype(element_type)?))
+        },
+        // Maps are read as a list of key/value structs.
+        DeltaNestedType::Map {
+            key_type,
+            value_type,
+        } => DataType::List(Box::new(DataType::Struct(vec![
+            Field::new(
+                PlSmallStr::from_static("key"),
+                delta_type_to_dtype(key_type)?,
+            ),
+            Field::new(
+                PlSmallStr::from_static("value"),
+                delta_type_to_dtype(value_type)?,
+            ),
+        ]))),
+    })
+}
+
+fn delta_primitive_to_dtype(name: &str) -> PolarsResult<DataType> {
+    use DataType::*;
+
+    Ok(match name {
+        "boolean" => Boolean,
+        "byte" => Int8,
+        "short" => Int16,
+        "integer" => Int32,
+        "long" => Int64,
+        "float" => Float32,
+        "double" => Float64,
+        "string" => String,
+        "binary" => Binary,
+        "date" => Date,
+        // `timestamp` is adjusted to UTC, `timestamp_ntz` has no timezone.
+        "timestamp" => Datetime(TimeUnit::Microseconds, Some(TimeZone::UTC)),
+        "timestamp_ntz" => Datetime(TimeUnit::Microseconds, None),
+        v if v.starts_with("decimal") => {
+            #[cfg(feature = "dtype-decimal")]
+            {
+                // e.g. decimal(38,18)
+                let parsed = (|| {
+                    let (precision, scale) = v
+                        .get(7..)?
+                        .strip_prefix('(')?
+                        .strip_suffix(')')?
+                        .split_once(',')?;
+                    Some(Decimal(
+                        precision.trim().parse().ok()?,
+                        scale.trim().parse().ok()?,
+                    ))
+                })();
+
+                parsed
+                    .ok_or_else(|| polars_err!(ComputeError: "invalid delta decimal type: {}", v))?
+            }
+            #[cfg(not(feature = "dtype-decimal"))]
+            {
+                polars_bail!(ComputeError: "reading delta decimal columns requires the 'dtype-decimal' feature")
+            }
+        },
+        v => polars_bail!(ComputeError: "unsupported delta data type: {}", v),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_delta_schema_string() -> PolarsResult<()> {
+        let schema_string = r#"{"type":"struct","fields":[
+            {"name":"id","type":"long","nullable":true,"metadata":{}},
+            {"name":"ts","type":"timestamp","nullable":true,"metadata":{}},
+            {"name":"tags","type":{"type":"array","elementType":"string","containsNull":true},"nullable":true,"metadata":{}},
+            {"name":"attrs","type":{"type":"map","keyType":"string","valueType":"integer","valueContainsNull":true},"nullable":true,"metadata":{}},
+            {"name":"point","type":{"type":"struct","fields":[{"name":"x","type":"double","nullable":true,"metadata":{}}]},"nullable":true,"metadata":{}}
+        ]}"#;
+
+        let schema = parse_delta_schema_string(schema_string)?;
+
+        assert_eq!(
+            schema,
+            Schema::from_iter([
+                Field::new("id".into(), DataType::Int64),
+                Field::new(
+                    "ts".into(),
+                    DataType::Datetime(TimeUnit::Microseconds, Some(TimeZone::UTC))
+                ),
+                Field::new("tags".into(), DataType::List(Box::new(DataType::String))),
+                Field::new(
+                    "attrs".into(),
+                    DataType::List(Box::new(DataType::Struct(vec![
+                        Field::new("key".into(), DataType::String),
+                        Field::new("value".into(), DataType::Int32),
+                    ])))
+                ),
+                Field::new(
+                    "point".into(),
+                    DataType::Struct(vec![Field::new("x".into(), DataType::Float64)])
+                ),
+            ])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_schema_to_delta_schema_string() -> PolarsResult<()> {
+        let schema = Schema::from_iter([
+            Field::new("id".into(), DataType::Int64),
+            Field::new(
+                "ts".into(),
+                DataType::Datetime(TimeUnit::Microseconds, Some(TimeZone::UTC)),
+            ),
+            Field::new("tags".into(), DataType::List(Box::new(DataType::String))),
+            Field::new(
+                "attrs".into(),
+                DataType::List(Box::new(DataType::Struct(vec![
+                    Field::new("key".into(), DataType::String),
+                    Field::new("value".into(), DataType::Int32),
+                ]))),
+            ),
+            Field::new(
+                "point".into(),
+                DataType::Struct(vec![Field::new("x".into(), DataType::Float64)]),
+            ),
+        ]);
+
+        let schema_string = schema_to_delta_schema_string(&schema)?;
+        assert_eq!(parse_delta_schema_string(&schema_string)?, schema);
+
+        assert!(
+            schema_to_delta_schema_string(&Schema::from_iter([Field::new(
+                "x".into(),
+                DataType::UInt64
+            )]))
+            .is_err()
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,591 @@
+use std::collections::BTreeMap;
+use std::io::Cursor;
+
+use polars_buffer::Buffer;
+use polars_core::prelude::*;
+use polars_utils::pl_path::PlRefPath;
+
+use super::actions::{
+    Action, AddAction, CommitInfoAction, FileStatistics, MetadataAction, ProtocolAction,
+    logical_file_key,
+};
+use super::deletion_vector::DeletionVectorDescriptor;
+use super::schema::parse_delta_schema_string;
+use crate::cloud::CloudOptions;
+use crate::parquet::read::ParquetReader;
+use crate::path_utils::expand_paths;
+use crate::prelude::SerReader;
+use crate::utils::byte_source::{ByteSource, DynByteSourceBuilder};
+use crate::utils::decode_json_response;
+
+const MAX_READER_VERSION: i32 = 3;
+
+/// Reader features for which no special handling is needed, or which are handled by the
+/// snapshot / deletion vector readers.
+const SUPPORTED_READER_FEATURES: &[&str] = &[
+    "columnMapping",
+    "deletionVectors",
+    "timestampNtz",
+    "typeWidening",
+    "typeWidening-preview",
+    "vacuumProtocolCheck",
+];
+
+/// Version of a Delta table to resolve.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum DeltaVersion {
+    #[default]
+    Latest,
+    Version(i64),
+    /// Latest version committed at or before this timestamp (milliseconds since the epoch).
+    Timestamp(i64),
+}
+
+/// A data file that is part of a Delta table snapshot.
+#[derive(Debug, Clone)]
+pub struct DeltaDataFile {
+    /// Absolute path of the data file.
+    pub path: String,
+    pub size: u64,
+    pub partition_values: PlHashMap<String, Option<String>>,
+    /// Number of physical rows in the file, if recorded in the file statistics.
+    pub num_records: Option<u64>,
+    pub deletion_vector: Option<DeletionVectorDescriptor>,
+}
+
+/// The active state of a Delta table at a specific version, resolved by replaying the
+/// transaction log.
+#[derive(Debug, Clone)]
+pub struct DeltaSnapshot {
+    pub table_root: String,
+    pub version: i64,
+    /// Logical schema of the table, including the partition columns.
+    pub schema: SchemaRef,
+    pub partition_columns: Vec<PlSmallStr>,
+    pub files: Vec<DeltaDataFile>,
+}
+
+impl DeltaSnapshot {
+    pub async fn try_new(
+        table_uri: &str,
+        version: DeltaVersion,
+        cloud_options: Option<&CloudOptions>,
+    ) -> PolarsResult<Self> {
+        let verbose = polars_core::config::verbose();
+        let table_root = table_uri.trim_end_matches('/').to_string();
+
+        let listing = LogListing::try_new(&table_root, cloud_options).await?;
+
+        let latest_version = listing.latest_version().ok_or_else(|| {
+                polars_err!(ComputeError: "not a delta table (no transaction log found): {}", table_uri)
+            })?;
+
+        let version = match version {
+            DeltaVersion::Latest => latest_version,
+            DeltaVersion::Version(v) => {
+                polars_ensure!(
+                    (0..=latest_version).contains(&v),
+                    ComputeError: "delta table version {} does not exist, latest version is {}",
+                    v, latest_version
+                );
+                v
+            },
+            DeltaVersion::Timestamp(ts) => {
+                listing
+                    .resolve_version_at_timestamp(ts, cloud_options)
+                    .await?
+            },
+        };
+
+        let checkpoint = listing.checkpoints.range(..=version).next_back();
+        let first_commit = checkpoint.map_or(0, |(v, _)| v + 1);
+
+        if verbose {
+            eprintln!(
+                "[DeltaSnapshot]: table: {}, version: {}, checkpoint: {:?}, commits to replay: {}",
+                table_root,
+                version,
+                checkpoint.map(|(v, _)| v),
+                (version + 1 - first_commit).max(0),
+            )
+        }
+
+        let mut state = ReplayState::default();
+
+        if let Some((_, parts)) = checkpoint {
+            for path in parts {
+                let bytes = read_file(path, cloud_options).await?;
+                let df = ParquetReader::new(Cursor::new(bytes.as_ref())).finish()?;
+                state.replay_checkpoint(&df)?;
+            }
+        }
+
+        for v in first_commit..=version {
+            let path = listing.commits.get(&v).ok_or_else(|| {
+                polars_err!(
+                    ComputeError:
+                    "cannot reconstruct delta table version {}: missing commit file for version {}",
+                    version, v
+                )
+            })?;
+
+            let bytes = read_file(path, cloud_options).await?;
+            for action in parse_commit(&bytes)? {
+                state.apply(action);
+            }
+        }
+
+        state.finish(table_root, version)
+    }
+
+    /// Schema of the partition columns, or `None` if the table is not partitioned.
+    pub fn hive_schema(&self) -> Option<SchemaRef> {
+        if self.partition_columns.is_empty() {
+            return None;
+        }
+
+        Some(Arc::new(
+            self.partition_columns
+                .iter()
+                .filter_map(|name| {
+                    self.schema
+                        .get(name)
+                        .map(|dtype| Field::new(name.clone(), dtype.clone()))
+                })
+                .collect(),
+        ))
+    }
+}
+
+/// Files in the `_delta_log` directory, keyed by version.
+pub(super) struct LogListing {
+    commits: BTreeMap<i64, PlRefPath>,
+    /// Only contains checkpoints for which all parts are present.
+    checkpoints: BTreeMap<i64, Vec<PlRefPath>>,
+}
+
+impl LogListing {
+    pub(super) async fn try_new(
+        table_root: &str,
+        cloud_options: Option<&CloudOptions>,
+    ) -> PolarsResult<Self> {
+        let log_dir = PlRefPath::new(format!("{table_root}/_delta_log/"));
+        let paths = expand_paths(&[log_dir], false, &[], &mut cloud_options.cloned()).await?;
+
+        let mut commits = BTreeMap::new();
+        let mut checkpoint_parts: BTreeMap<i64, (usize, Vec<(usize, PlRefPath)>)> = BTreeMap::new();
+
+        for path in paths.iter() {
+            let file_name = path.as_str().rsplit('/').next().unwrap();
+
+            let Some((version, rest)) = file_name
+                .split_once('.')
+                .filter(|(v, _)| v.len() == 20 && v.bytes().all(|b| b.is_ascii_digit()))
+                .and_then(|(v, rest)| Some((v.parse::<i64>().ok()?, rest)))
+            else {
+                continue;
+            };
+
+            if rest == "json" {
+                commits.insert(version, path.clone());
+            } else if rest == "checkpoint.parquet" {
+                checkpoint_parts.insert(version, (1, vec![(0, path.clone())]));
+            } else if let Some((part, n_parts)) = rest
+                .strip_prefix("checkpoint.")
+                .and_then(|x| x.strip_suffix(".parquet"))
+                .and_then(|x| x.split_once('.'))
+                .and_then(|(part, n_parts)| {
+                    Some((part.parse::<usize>().ok()?, n_parts.parse::<usize>().ok()?))
+                })
+            {
+                let entry = checkpoint_parts
+                    .entry(version)
+                    .or_insert_with(|| (n_parts, vec![]));
+                entry.1.push((part, path.clone()));
+            }
+        }
+
+        let checkpoints = checkpoint_parts
+            .into_iter()
+            .filter(|(_, (n_parts, parts))| parts.len() == *n_parts)
+            .map(|(version, (_, mut parts))| {
+                parts.sort_unstable_by_key(|(part, _)| *part);
+                (version, parts.into_iter().map(|(_, path)| path).collect())
+            })
+            .collect();
+
+        Ok(Self {
+            commits,
+            checkpoints,
+        })
+    }
+
+    pub(super) fn latest_version(&self) -> Option<i64> {
+        self.commits
+            .keys()
+            .chain(self.checkpoints.keys())
+            .max()
+            .copied()
+    }
+
+    /// Returns the latest version that was committed at or before `timestamp`.
+    async fn resolve_version_at_timestamp(
+        &self,
+        timestamp: i64,
+        cloud_options: Option<&CloudOptions>,
+    ) -> PolarsResult<i64> {
+        for (&version, path) in self.commits.iter().rev() {
+            let bytes = read_file(path, cloud_options).await?;
+
+            let commit_timestamp = parse_commit(&bytes)?
+                .into_iter()
+                .find_map(|action| action.commit_info)
+                .and_then(
+                    |CommitInfoAction {
+                         timestamp,
+                         in_commit_timestamp,
+                     }| in_commit_timestamp.or(timestamp),
+                )
+                .ok_or_else(|| {
+                    polars_err!(
+                        ComputeError:
+                        "delta commit for version {} does not contain a timestamp", version
+                    )
+                })?;
+
+            if commit_timestamp <= timestamp {
+                return Ok(version);
+            }
+        }
+
+        polars_bail!(
+            ComputeError:
+            "timestamp {} is before the earliest available delta table version", timestamp
+        )
+    }
+}
+
+#[derive(Default)]
+struct ReplayState {
+    files: PlIndexMap<(String, Option<String>), AddAction>,
+    metadata: Option<MetadataAction>,
+    protocol: Option<ProtocolAction>,
+}
+
+impl ReplayState {
+    fn apply(&mut self, action: Action) {
+        if let Some(add) = action.add {
+            let key = logical_file_key(&add.path, add.deletion_vector.as_ref());
+            self.files.insert(key, add);
+        }
+
+        if let Some(remove) = action.remove {
+            let key = logical_file_key(&remove.path, remove.deletion_vector.as_ref());
+            self.files.shift_remove(&key);
+        }
+
+        if let Some(metadata) = action.meta_data {
+            self.metadata = Some(metadata);
+        }
+
+        if let Some(protocol) = action.protocol {
+            self.protocol = Some(protocol);
+        }
+    }
+
+    fn replay_checkpoint(&mut self, df: &DataFrame) -> PolarsResult<()> {
+        if let Ok(add) = df.column("add") {
+            let add = add.as_materialized_series().struct_()?;
+            let valid = add.is_not_null();
+
+            let path = add.field_by_name("path")?;
+            let path = path.str()?;
+            let size = add.field_by_name("size")?.cast(&DataType::Int64)?;
+            let size = size.i64()?;
+            let stats = add.field_by_name("stats").ok();
+            let stats = stats.as_ref().map(|s| s.str()).transpose()?;
+            let partition_values = add.field_by_name("partitionValues")?;
+            let partition_values = partition_values.list()?;
+            let deletion_vectors = add
+                .field_by_name("deletionVector")
+                .ok()
+                .map(|s| DeletionVectorColumns::try_new(&s))
+                .transpose()?;
+
+            for i in valid
+                .iter()
+                .enumerate()
+                .filter_map(|(i, v)| (v == Some(true)).then_some(i))
+            {
+                let add = AddAction {
+                    path: path.get(i).unwrap_or_default().to_string(),
+                    partition_values: string_map_from_series(partition_values.get_as_series(i))?,
+                    size: size.get(i).unwrap_or(0),
+                    stats: stats.and_then(|s| s.get(i)).map(|s| s.to_string()),
+                    deletion_vector: deletion_vectors.as_ref().and_then(|x| x.get(i)),
+                };
+
+                let key = logical_file_key(&add.path, add.deletion_vector.as_ref());
+                self.files.insert(key, add);
+            }
+        }
+
+        if let Ok(metadata) = df.column("metaData") {
+            let metadata = metadata.as_materialized_series().struct_()?;
+            let schema_string = metadata.field_by_name("schemaString")?;
+            let partition_columns = metadata.field_by_name("partitionColumns")?;
+            let configuration = metadata.field_by_name("configuration").ok();
+
+            if let Some(i) = schema_string.str()?.iter().position(|x| x.is_some()) {
+                self.metadata = Some(MetadataAction {
+                    schema_string: schema_string.str()?.get(i).unwrap().to_string(),
+                    partition_columns: partition_columns
+                        .list()?
+                        .get_as_series(i)
+                        .map(|s| {
+                            PolarsResult::Ok(
+                                s.str()?.iter().flatten().map(|x| x.to_string()).collect(),
+                            )
+                        })
+                        .transpose()?
+                        .unwrap_or_default(),
+                    configuration: configuration
+                        .as_ref()
+                        .map(|c| string_map_from_series(c.list()?.get_as_series(i)))
+                        .transpose()?
+                        .unwrap_or_default(),
+                });
+            }
+        }
+
+        if let Ok(protocol) = df.column("protocol") {
+            let protocol = protocol.as_materialized_series().struct_()?;
+            let min_reader_version = protocol
+                .field_by_name("minReaderVersion")?
+                .cast(&DataType::Int32)?;
+            let reader_features = protocol.field_by_name("readerFeatures").ok();
+
+            if let Some(i) = min_reader_version.i32()?.iter().position(|x| x.is_some()) {
+                self.protocol = Some(ProtocolAction {
+                    min_reader_version: min_reader_version.i32()?.get(i).unwrap(),
+                    reader_features: reader_features
+                        .as_ref()
+                        .and_then(|s| s.list().ok()?.get_as_series(i))
+                        .map(|s| {
+                            PolarsResult::Ok(
+                                s.str()?.iter().flatten().map(|x| x.to_string()).collect(),
+                            )
+                        })
+                        .transpose()?,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    fn finish(self, table_root: String, version: i64) -> PolarsResult<DeltaSnapshot> {
+        let protocol = self.protocol.ok_or_else(
+            || polars_err!(ComputeError: "delta transaction log does not contain a protocol"),
+        )?;
+        let metadata = self.metadata.ok_or_else(
+            || polars_err!(ComputeError: "delta transaction log does not contain table metadata"),
+        )?;
+
+        polars_ensure!(
+            protocol.min_reader_version <= MAX_READER_VERSION,
+            ComputeError: "unsupported delta reader version: {}", protocol.min_reader_version
+        );
+
+        for feature in protocol.reader_features.iter().flatten() {
+            polars_ensure!(
+                SUPPORTED_READER_FEATURES.contains(&feature.as_str()),
+                ComputeError: "unsupported delta reader feature: {}", feature
+            );
+        }
+
+        if let Some(Some(mode)) = metadata.configuration.get("delta.columnMapping.mode")
+            && mode != "none"
+        {
+            polars_bail!(
+                nyi = "reading delta tables with column mapping mode '{}'",
+                mode
+            )
+        }
+
+        let schema = Arc::new(parse_delta_schema_string(&metadata.schema_string)?);
+
+        let files = self
+            .files
+            .into_values()
+            .map(|add| {
+                let num_records = add
+                    .stats
+                    .as_deref()
+                    .and_then(|s| decode_json_response::<FileStatistics>(s.as_bytes()).ok())
+                    .and_then(|s| s.num_records);
+
+                DeltaDataFile {
+                    path: resolve_data_file_path(&table_root, &add.path),
+                    size: u64::try_from(add.size).unwrap_or(0),
+                    partition_values: add.partition_values,
+                    num_records,
+                    deletion_vector: add.deletion_vector,
+                }
+            })
+            .collect();
+
+        Ok(DeltaSnapshot {
+            table_root,
+            version,
+            schema,
+            partition_columns: metadata
+                .partition_columns
+                .into_iter()
+                .map(PlSmallStr::from)
+                .collect(),
+            files,
+        })
+    }
+}
+
+/// Columns of the `deletionVector` struct of a checkpoint `add` column.
+struct DeletionVectorColumns {
+    valid: BooleanChunked,
+    storage_type: StringChunked,
+    path_or_inline_dv: StringChunked,
+    offset: Int32Chunked,
+    size_in_bytes: Int32Chunked,
+    cardinality: Int64Chunked,
+}
+
+impl DeletionVectorColumns {
+    fn try_new(s: &Series) -> PolarsResult<Self> {
+        let ca = s.struct_()?;
+        let get = |name: &str, dtype: &DataType| ca.field_by_name(name)?.cast(dtype);
+
+        Ok(Self {
+            valid: ca.is_not_null(),
+            storage_type: get("storageType", &DataType::String)?.str()?.clone(),
+            path_or_inline_dv: get("pathOrInlineDv", &DataType::String)?.str()?.clone(),
+            offset: get("offset", &DataType::Int32)?.i32()?.clone(),
+            size_in_bytes: get("sizeInBytes", &DataType::Int32)?.i32()?.clone(),
+            cardinality: get("cardinality", &DataType::Int64)?.i64()?.clone(),
+        })
+    }
+
+    fn get(&self, i: usize) -> Option<DeletionVectorDescriptor> {
+        if self.valid.get(i) != Some(true) {
+            return None;
+        }
+
+        Some(DeletionVectorDescriptor {
+            storage_type: self.storage_type.get(i)?.to_string(),
+            path_or_inline_dv: self.path_or_inline_dv.get(i)?.to_string(),
+            offset: self.offset.get(i),
+            size_in_bytes: self.size_in_bytes.get(i)?,
+            cardinality: self.cardinality.get(i)?,
+        })
+    }
+}
+
+/// Converts a list element of a map column (a struct of `key` / `value` fields) into a hashmap.
+fn string_map_from_series(s: Option<Series>) -> PolarsResult<PlHashMap<String, Option<String>>> {
+    let Some(s) = s else {
+        return Ok(PlHashMap::new());
+    };
+
+    let ca = s.struct_()?;
+    let keys = ca.field_by_name("key")?.cast(&DataType::String)?;
+    let values = ca.field_by_name("value")?.cast(&DataType::String)?;
+
+    Ok(keys
+        .str()?
+        .iter()
+        .zip(values.str()?.iter())
+        .filter_map(|(k, v)| Some((k?.to_string(), v.map(|v| v.to_string()))))
+        .collect())
+}
+
+/// Parses the newline-delimited actions of a commit file.
+fn parse_commit(bytes: &[u8]) -> PolarsResult<Vec<Action>> {
+    bytes
+        .split(|&b| b == b'\n')
+        .filter(|line| !line.trim_ascii().is_empty())
+        .map(decode_json_response::<Action>)
+        .collect()
+}
+
+/// Data file paths in the log are URI encoded and relative to the table root unless they are
+/// absolute URIs.
+fn resolve_data_file_path(table_root: &str, path: &str) -> String {
+    let path = percent_encoding::percent_decode_str(path).decode_utf8_lossy();
+
+    if path.contains("://") || path.starts_with('/') {
+        path.into_owned()
+    } else {
+        format!("{table_root}/{path}")
+    }
+}
+
+async fn read_file(
+    path: &PlRefPath,
+    cloud_options: Option<&CloudOptions>,
+) -> PolarsResult<Buffer<u8>> {
+    let source = DynByteSourceBuilder::ObjectStore
+        .try_build_from_path(path.clone(), cloud_options)
+        .await?;
+    let size = source.get_size().await?;
+
+    source.get_range(0..size).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_replay_commits() -> PolarsResult<()> {
+        let commit_0 = br#"{"commitInfo":{"timestamp":1700000000000}}
+{"protocol":{"minReaderVersion":3,"minWriterVersion":7,"readerFeatures":["deletionVectors"],"writerFeatures":["deletionVectors"]}}
+{"metaData":{"id":"a","format":{"provider":"parquet","options":{}},"schemaString":"{\"type\":\"struct\",\"fields\":[{\"name\":\"x\",\"type\":\"long\",\"nullable\":true,\"metadata\":{}},{\"name\":\"p\",\"type\":\"string\",\"nullable\":true,\"metadata\":{}}]}","partitionColumns":["p"],"configuration":{},"createdTime":1700000000000}}
+{"add":{"path":"p=a/0.parquet","partitionValues":{"p":"a"},"size":10,"modificationTime":0,"dataChange":true,"stats":"{\"numRecords\":3}"}}
+{"add":{"path":"p=b%20c/1.parquet","partitionValues":{"p":"b c"},"size":10,"modificationTime":0,"dataChange":true}}
+"#;
+        let commit_1 = br#"{"remove":{"path":"p=a/0.parquet","dataChange":true}}
+{"add":{"path":"p=a/0.parquet","partitionValues":{"p":"a"},"size":10,"modificationTime":0,"dataChange":true,"stats":"{\"numRecords\":3}","deletionVector":{"storageType":"i","pathOrInlineDv":"wi5b=000010000siXQKl0rr91000f55c8Xg0@@D72lkbi5=-{L","sizeInBytes":40,"cardinality":1}}}
+"#;
+
+        let mut state = ReplayState::default();
+        for action in parse_commit(commit_0)?
+            .into_iter()
+            .chain(parse_commit(commit_1)?)
+        {
+            state.apply(action);
+        }
+
+        let snapshot = state.finish("s3://bucket/table".into(), 1)?;
+
+        assert_eq!(
+            snapshot.hive_schema().as_deref(),
+            Some(&Schema::from_iter([Field::new(
+                "p".into(),
+                DataType::String
+            )]))
+        );
+        assert_eq!(snapshot.files.len(), 2);
+        assert_eq!(snapshot.files[0].path, "s3://bucket/table/p=b c/1.parquet");
+        assert_eq!(snapshot.files[0].num_records, None);
+        assert_eq!(snapshot.files[1].path, "s3://bucket/table/p=a/0.parquet");
+        assert_eq!(snapshot.files[1].num_records, Some(3));
+        assert_eq!(
+            snapshot.files[1]
+                .deletion_vector
+                .as_ref()
+                .map(|x| x.cardinality),
+            Some(1)
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,358 @@
+use std::io::Write;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use object_store::{PutMode, PutPayload};
+use polars_core::prelude::*;
+use polars_utils::pl_path::PlRefPath;
+
+use super::schema::schema_to_delta_schema_string;
+use super::snapshot::{DeltaSnapshot, DeltaVersion, LogListing};
+use crate::cloud::{CloudLocation, CloudOptions, build_object_store, object_path_from_str};
+use crate::parquet::write::ParquetWriter;
+use crate::pl_async::get_runtime;
+use crate::utils::file::{Writeable, delta_data_file_name};
+use crate::utils::sync_on_close::SyncOnCloseType;
+use crate::{get_upload_chunk_size, get_upload_concurrency};
+
+/// Number of versions a commit is attempted at before giving up, when other writers keep
+/// committing the version first.
+const MAX_COMMIT_ATTEMPTS: usize = 10;
+
+/// Appends `df` to the Delta table at `table_uri` as a single parquet data file, creating the
+/// table if it does not yet have a transaction log.
+///
+/// The schema of `df` must match the schema of an existing table, and partitioned tables are not
+/// supported. The commit file is created only if it does not exist yet (an exclusive create
+/// locally, a conditional put on object stores), so concurrent writers never overwrite each
+/// other's commits. If another writer committed the version first, the commit is retried at the
+/// next version. Object stores without conditional put support raise an error.
+///
+/// Returns the committed table version.
+pub fn write_delta_table(
+    table_uri: &str,
+    df: &mut DataFrame,
+    cloud_options: Option<&CloudOptions>,
+) -> PolarsResult<i64> {
+    let table_root = table_uri.trim_end_matches('/');
+    let schema = df.schema().clone();
+
+    // Check against the current table before writing the data file.
+    check_delta_append(table_root, &schema, cloud_options)?;
+
+    if !PlRefPath::new(table_root).has_scheme() {
+        std::fs::create_dir_all(format!("{table_root}/_delta_log"))?;
+    }
+
+    let file_name = delta_data_file_name();
+
+    let size = {
+        let mut writeable = Writeable::try_new(
+            PlRefPath::new(format!("{table_root}/{file_name}")),
+            cloud_options,
+            get_upload_chunk_size(),
+            get_upload_concurrency(),
+        )?;
+        let size = ParquetWriter::new(&mut *writeable).finish(df)?;
+        writeable.close(SyncOnCloseType::None)?;
+        size
+    };
+
+    commit_append(
+        table_root,
+        &schema,
+        &file_name,
+        size,
+        Some(df.height()),
+        cloud_options,
+    )
+}
+
+/// Checks that data with `schema` can be appended to the Delta table at `table_uri`, which may not
+/// exist yet.
+pub fn check_delta_append(
+    table_uri: &str,
+    schema: &SchemaRef,
+    cloud_options: Option<&CloudOptions>,
+) -> PolarsResult<()> {
+    latest_table_version(table_uri.trim_end_matches('/'), schema, cloud_options).map(|_| ())
+}
+
+/// Commits `file_name`, a parquet data file with `schema` that was already written to the root of
+/// the Delta table at `table_uri`, as an append to the table. This lets the data file be written
+/// by a streaming sink, see [`write_delta_table`] for the commit semantics.
+///
+/// Returns the committed table version.
+pub fn commit_delta_append(
+    table_uri: &str,
+    schema: &SchemaRef,
+    file_name: &str,
+    num_records: Option<usize>,
+    cloud_options: Option<&CloudOptions>,
+) -> PolarsResult<i64> {
+    let table_root = table_uri.trim_end_matches('/');
+    let path = format!("{table_root}/{file_name}");
+
+    let size = if PlRefPath::new(table_root).has_scheme() {
+        get_runtime().block_in_place_on(async {
+            let (CloudLocation { prefix, .. }, store) =
+                build_object_store(PlRefPath::new(path.as_str()), cloud_options, false).await?;
+            PolarsResult::Ok(store.head(&object_path_from_str(&prefix)?).await?.size)
+        })?
+    } else {
+        std::fs::create_dir_all(format!("{table_root}/_delta_log"))?;
+        std::fs::metadata(&path)?.len()
+    };
+
+    commit_append(
+        table_root,
+        schema,
+        file_name,
+        size,
+        num_records,
+        cloud_options,
+    )
+}
+
+fn commit_append(
+    table_root: &str,
+    schema: &SchemaRef,
+    file_name: &str,
+    size: u64,
+    num_records: Option<usize>,
+    cloud_options: Option<&CloudOptions>,
+) -> PolarsResult<i64> {
+    let verbose = polars_core::config::verbose();
+    let log_dir = format!("{table_root}/_delta_log");
+
+    for _ in 0..MAX_COMMIT_ATTEMPTS {
+        let version = latest_table_version(table_root, schema, cloud_options)?.map_or(0, |v| v + 1);
+        let now_ms = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_millis() as i64;
+
+        let mut actions = vec![serde_json::json!({
+            "commitInfo": {
+                "timestamp": now_ms,
+                "operation": "WRITE",
+                "operationParameters": {"mode": "Append"},
+            }
+        })];
+
+        if version == 0 {
+            actions.push(serde_json::json!({
+                "protocol": {"minReaderVersion": 1, "minWriterVersion": 2}
+            }));
+            actions.push(serde_json::json!({
+                "metaData": {
+                    "id": uuid::Uuid::new_v4().to_string(),
+                    "format": {"provider": "parquet", "options": {}},
+                    "schemaString": schema_to_delta_schema_string(schema)?,
+                    "partitionColumns": [],
+                    "configuration": {},
+                    "createdTime": now_ms,
+                }
+            }));
+        }
+
+        let mut add = serde_json::json!({
+            "path": file_name,
+            "partitionValues": {},
+            "size": size,
+            "modificationTime": now_ms,
+            "dataChange": true,
+        });
+        if let Some(num_records) = num_records {
+            add["stats"] = serde_json::json!({"numRecords": num_records})
+                .to_string()
+                .into();
+        }
+        actions.push(serde_json::json!({ "add": add }));
+
+        let mut commit = Vec::new();
+        for action in actions {
+            writeln!(&mut commit, "{action}")?;
+        }
+
+        let commit_path = format!("{log_dir}/{version:020}.json");
+
+        if put_if_absent(&commit_path, commit, cloud_options)? {
+            if verbose {
+                eprintln!(
+                    "[write_delta_table]: table: {}, version: {}, data file: {} ({} bytes)",
+                    table_root, version, file_name, size
+                )
+            }
+            return Ok(version);
+        }
+
+        if verbose {
+            eprintln!(
+                "[write_delta_table]: version {} of table {} was committed concurrently, retrying",
+                version, table_root
+            )
+        }
+    }
+
+    polars_bail!(
+        ComputeError:
+        "failed to commit to delta table {}: other writers committed concurrently {} times",
+        table_root, MAX_COMMIT_ATTEMPTS
+    )
+}
+
+/// Returns the latest version of the table at `table_root`, or `None` if it has no transaction
+/// log yet, after checking that data with `schema` can be appended to it.
+fn latest_table_version(
+    table_root: &str,
+    schema: &SchemaRef,
+    cloud_options: Option<&CloudOptions>,
+) -> PolarsResult<Option<i64>> {
+    let log_dir = format!("{table_root}/_delta_log");
+
+    // Listing a local directory that doesn't exist is an error, whereas an empty cloud prefix
+    // simply lists nothing.
+    if !PlRefPath::new(table_root).has_scheme() && !std::path::Path::new(&log_dir).is_dir() {
+        return Ok(None);
+    }
+
+    let latest_version = get_runtime()
+        .block_in_place_on(LogListing::try_new(table_root, cloud_options))?
+        .latest_version();
+
+    if latest_version.is_none() {
+        return Ok(None);
+    }
+
+    let snapshot = get_runtime().block_in_place_on(DeltaSnapshot::try_new(
+        table_root,
+        DeltaVersion::Latest,
+        cloud_options,
+    ))?;
+
+    polars_ensure!(
+        snapshot.partition_columns.is_empty(),
+        ComputeError: "writing to partitioned delta tables is not supported"
+    );
+    polars_ensure!(
+        snapshot.schema.as_ref() == schema.as_ref(),
+        SchemaMismatch: "schema of data does not match delta table schema: {:?} != {:?}",
+        schema, snapshot.schema
+    );
+
+    Ok(Some(snapshot.version))
+}
+
+/// Writes `bytes` to `path` unless a file already exists there. Returns whether it was written.
+fn put_if_absent(
+    path: &str,
+    bytes: Vec<u8>,
+    cloud_options: Option<&CloudOptions>,
+) -> PolarsResult<bool> {
+    if !PlRefPath::new(path).has_scheme() {
+        let mut file = match std::fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(path)
+        {
+            Ok(file) => file,
+            Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => return Ok(false),
+            Err(e) => return Err(e.into()),
+        };
+        file.write_all(&bytes)?;
+        file.sync_all()?;
+        return Ok(true);
+    }
+
+    get_runtime().block_in_place_on(async {
+        let (CloudLocation { prefix, .. }, store) =
+            build_object_store(PlRefPath::new(path), cloud_options, false).await?;
+        let store = store.to_dyn_object_store().await;
+
+        match store
+            .put_opts(
+                &object_path_from_str(&prefix)?,
+                PutPayload::from(bytes),
+                PutMode::Create.into(),
+            )
+            .await
+        {
+            Ok(_) => Ok(true),
+            Err(object_store::Error::AlreadyExists { .. }) => Ok(false),
+            Err(e) => Err(e.into()),
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_delta_table_roundtrip() -> PolarsResult<()> {
+        let dir = tempfile::tempdir()?;
+        let table_uri = dir.path().to_str().unwrap();
+
+        let mut df = df!("a" => [1i64, 2, 3], "b" => ["x", "y", "z"])?;
+
+        assert_eq!(write_delta_table(table_uri, &mut df, None)?, 0);
+        assert_eq!(write_delta_table(table_uri, &mut df, None)?, 1);
+
+        let snapshot = get_runtime().block_on(DeltaSnapshot::try_new(
+            table_uri,
+            DeltaVersion::Latest,
+            None,
+        ))?;
+
+        assert_eq!(snapshot.version, 1);
+        assert_eq!(snapshot.schema.as_ref(), df.schema().as_ref());
+        assert_eq!(snapshot.files.len(), 2);
+        assert!(snapshot.files.iter().all(|f| f.num_records == Some(3)));
+
+        let mut other = df!("a" => [1i32])?;
+        assert!(write_delta_table(table_uri, &mut other, None).is_err());
+
+        // Existing commits are never overwritten.
+        let commit_path = format!("{table_uri}/_delta_log/{:020}.json", 1);
+        assert!(!put_if_absent(&commit_path, b"{}\n".to_vec(), None)?);
+        let snapshot = get_runtime().block_on(DeltaSnapshot::try_new(
+            table_uri,
+            DeltaVersion::Latest,
+            None,
+        ))?;
+        assert_eq!(snapshot.files.len(), 2);
+
+        // Another writer committed the next version.
+        std::fs::write(
+            dir.path()
+                .join("_delta_log")
+                .join(format!("{:020}.json", 2)),
+            "{\"commitInfo\":{}}\n",
+        )?;
+        assert_eq!(write_delta_table(table_uri, &mut df, None)?, 3);
+
+        // A data file written by another writer, e.g. a streaming sink.
+        let file_name = delta_data_file_name();
+        let mut file = std::fs::File::create(dir.path().join(&file_name))?;
+        ParquetWriter::new(&mut file).finish(&mut df)?;
+        let schema = df.schema().clone();
+        assert_eq!(
+            commit_delta_append(table_uri, &schema, &file_name, None, None)?,
+            4
+        );
+        let snapshot = get_runtime().block_on(DeltaSnapshot::try_new(
+            table_uri,
+            DeltaVersion::Latest,
+            None,
+        ))?;
+        assert_eq!(snapshot.files.len(), 4);
+        assert!(
+            snapshot
+                .files
+                .iter()
+                .any(|f| f.path.ends_with(&file_name) && f.num_records.is_none())
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,381 @@
+//! Reader for Excel (xlsx) and OpenDocument (ods) spreadsheets, and writer for xlsx.
+//!
+//! Both formats are zip archives of XML parts. Cells are collected per sheet and converted into
+//! columns with the CSV type inference rules, with date-formatted cells becoming temporal columns.
+mod ods;
+mod read;
+mod sheet;
+mod write;
+mod xlsx;
+mod xml;
+mod zip;
+
+pub use read::{ExcelReader, ExcelSheet};
+pub use sheet::CellRange;
+pub use write::{ExcelSheetOptions, ExcelSheetWriter, ExcelWriter};
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use polars_core::prelude::*;
+
+    use super::zip::ZipArchive;
+    use super::zip::tests::build_zip;
+    use super::*;
+    use crate::SerReader;
+
+    const CONTENT_TYPES: &str = r#"<?xml version="1.0" encoding="UTF-8"?><Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types"/>"#;
+
+    fn xlsx_fixture() -> Vec<u8> {
+        let workbook = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<workbook xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+  <sheets>
+    <sheet name="Notes" sheetId="1" r:id="rId1"/>
+    <sheet name="Data" sheetId="2" r:id="rId2"/>
+  </sheets>
+</workbook>"#;
+        let rels = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">
+  <Relationship Id="rId1" Type="worksheet" Target="worksheets/sheet1.xml"/>
+  <Relationship Id="rId2" Type="worksheet" Target="/xl/worksheets/sheet2.xml"/>
+</Relationships>"#;
+        let shared_strings = r#"<sst><si><t>name</t></si><si><r><t>val</t></r><r><t>ue</t></r></si><si><t>day</t></si><si><t>a &amp; b</t><rPh><t>x</t></rPh></si></sst>"#;
+        let styles = r#"<styleSheet>
+  <numFmts count="1"><numFmt numFmtId="164" formatCode="yyyy\-mm\-dd\ hh:mm"/></numFmts>
+  <cellStyleXfs count="1"><xf numFmtId="14"/></cellStyleXfs>
+  <cellXfs count="3"><xf numFmtId="0"/><xf numFmtId="14"/><xf numFmtId="164"/></cellXfs>
+</styleSheet>"#;
+        let sheet1 = r#"<worksheet><sheetData><row r="1"><c r="A1" t="inlineStr"><is><t>notes</t></is></c></row></sheetData></worksheet>"#;
+        let sheet2 = r#"<worksheet><sheetData>
+  <row r="1"><c r="B1" t="str"><v>Report</v></c></row>
+  <row r="3"><c r="B3" t="s"><v>0</v></c><c r="C3" t="s"><v>1</v></c><c r="D3" t="s"><v>2</v></c><c r="E3" t="s"><v>2</v></c></row>
+  <row r="4"><c r="B4" t="s"><v>3</v></c><c r="C4"><f>1+1</f><v>2</v></c><c r="D4" s="1"><v>43831</v></c><c r="E4" s="2"><v>43831.5</v></c></row>
+  <row r="5"><c r="B5" t="b"><v>1</v></c><c r="C5"><v>2.5</v></c><c r="D5" t="e"><v>#N/A</v></c><c r="E5" s="2"><v>43832</v></c></row>
+  <row r="7"><c r="B7" t="str"><v>total</v></c><c r="C7"><v>4.5</v></c></row>
+</sheetData></worksheet>"#;
+
+        build_zip(&[
+            ("[Content_Types].xml", CONTENT_TYPES),
+            ("xl/workbook.xml", workbook),
+            ("xl/_rels/workbook.xml.rels", rels),
+            ("xl/sharedStrings.xml", shared_strings),
+            ("xl/styles.xml", styles),
+            ("xl/worksheets/sheet1.xml", sheet1),
+            ("xl/worksheets/sheet2.xml", sheet2),
+        ])
+    }
+
+    fn ods_fixture() -> Vec<u8> {
+        let content = r#"<?xml version="1.0" encoding="UTF-8"?>
+<office:document-content xmlns:office="urn:oasis:names:tc:opendocument:xmlns:office:1.0" xmlns:table="urn:oasis:names:tc:opendocument:xmlns:table:1.0" xmlns:text="urn:oasis:names:tc:opendocument:xmlns:text:1.0">
+<office:body><office:spreadsheet>
+<table:table table:name="First">
+  <table:table-column table:number-columns-repeated="3"/>
+  <table:table-row>
+    <table:table-cell office:value-type="string"><text:p>id</text:p></table:table-cell>
+    <table:table-cell office:value-type="string"><text:p>when</text:p></table:table-cell>
+    <table:table-cell office:value-type="string"><text:p>text</text:p></table:table-cell>
+  </table:table-row>
+  <table:table-row table:number-rows-repeated="2">
+    <table:table-cell office:value-type="float" office:value="7"><text:p>7</text:p></table:table-cell>
+    <table:table-cell office:value-type="date" office:date-value="2020-01-02"><text:p>02/01/20</text:p></table:table-cell>
+    <table:table-cell office:value-type="string"><text:p>a<text:s text:c="2"/>b</text:p><text:p>c</text:p><office:annotation><text:p>note</text:p></office:annotation></table:table-cell>
+  </table:table-row>
+  <table:table-row>
+    <table:table-cell table:number-columns-repeated="2"/>
+    <table:table-cell office:value-type="string"><text:p>z</text:p></table:table-cell>
+  </table:table-row>
+  <table:table-row table:number-rows-repeated="1048570"><table:table-cell table:number-columns-repeated="1024"/></table:table-row>
+</table:table>
+<table:table table:name="Second"><table:table-row><table:table-cell/></table:table-row></table:table>
+</office:spreadsheet></office:body></office:document-content>"#;
+
+        build_zip(&[
+            ("mimetype", "application/vnd.oasis.opendocument.spreadsheet"),
+            ("content.xml", content),
+        ])
+    }
+
+    #[test]
+    fn test_read_xlsx() -> PolarsResult<()> {
+        let mut reader = ExcelReader::new(Cursor::new(xlsx_fixture()));
+        assert_eq!(reader.sheet_names()?, ["Notes", "Data"]);
+
+        let df = reader
+            .with_sheet(ExcelSheet::Name("Data".into()))
+            .with_cell_range(Some("B3:E".parse()?))
+            .with_n_rows(Some(2))
+            .finish()?;
+
+        assert_eq!(
+            df.get_column_names(),
+            ["name", "value", "day", "day_duplicated_0"]
+        );
+        assert_eq!(
+            df.schema().iter_values().cloned().collect::<Vec<_>>(),
+            [
+                DataType::String,
+                DataType::Float64,
+                DataType::Date,
+                DataType::Datetime(TimeUnit::Microseconds, None),
+            ]
+        );
+        assert!(
+            df.column("name")?
+                .equals(&Column::new("name".into(), ["a & b", "true"]))
+        );
+        assert!(
+            df.column("value")?
+                .equals(&Column::new("value".into(), [2.0, 2.5]))
+        );
+
+        let day = df.column("day")?.cast(&DataType::Int32)?;
+        assert!(day.equals_missing(&Column::new("day".into(), [Some(18262i32), None])));
+        let time = df.column("day_duplicated_0")?.cast(&DataType::Int64)?;
+        assert!(time.equals(&Column::new(
+            "day_duplicated_0".into(),
+            [1577880000000000i64, 1577923200000000]
+        )));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_xlsx_options() -> PolarsResult<()> {
+        let df = ExcelReader::new(Cursor::new(xlsx_fixture()))
+            .with_sheet(ExcelSheet::Index(1))
+            .with_skip_rows(2)
+            .with_has_header(false)
+            .with_infer_schema_length(Some(0))
+            .finish()?;
+
+        // Leading empty columns are kept, trailing empty ones are not.
+        assert_eq!(df.shape(), (5, 5));
+        assert_eq!(df.get_column_names()[0], "column_1");
+        assert_eq!(df.column("column_1")?.null_count(), 5);
+        assert!(df.column("column_3")?.equals_missing(&Column::new(
+            "column_3".into(),
+            [Some("value"), Some("2"), Some("2.5"), None, Some("4.5")]
+        )));
+
+        let err = ExcelReader::new(Cursor::new(xlsx_fixture()))
+            .with_sheet(ExcelSheet::Name("Missing".into()))
+            .finish();
+        assert!(err.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_xlsx_1900_dates() -> PolarsResult<()> {
+        let workbook =
+            r#"<workbook><sheets><sheet name="Sheet1" r:id="rId1"/></sheets></workbook>"#;
+        let rels = r#"<Relationships><Relationship Id="rId1" Target="worksheets/sheet1.xml"/></Relationships>"#;
+        let styles = r#"<styleSheet><cellXfs count="2"><xf numFmtId="0"/><xf numFmtId="14"/></cellXfs></styleSheet>"#;
+        let sheet = r#"<worksheet><sheetData>
+  <row r="1"><c r="A1" t="str"><v>day</v></c></row>
+  <row r="2"><c r="A2" s="1"><v>1</v></c></row>
+  <row r="3"><c r="A3" s="1"><v>59</v></c></row>
+  <row r="4"><c r="A4" s="1"><v>61</v></c></row>
+  <row r="5"><c r="A5" s="1"><v>25569</v></c></row>
+</sheetData></worksheet>"#;
+        let data = build_zip(&[
+            ("[Content_Types].xml", CONTENT_TYPES),
+            ("xl/workbook.xml", workbook),
+            ("xl/_rels/workbook.xml.rels", rels),
+            ("xl/styles.xml", styles),
+            ("xl/worksheets/sheet1.xml", sheet),
+        ]);
+
+        // The 1900 date system has a 1900-02-29, so serials before it are shifted by a day.
+        let df = ExcelReader::new(Cursor::new(data)).finish()?;
+        let day = df.column("day")?.date()?.as_date_iter().collect::<Vec<_>>();
+        let ymd = |y, m, d| chrono::NaiveDate::from_ymd_opt(y, m, d);
+        assert_eq!(
+            day,
+            [
+                ymd(1900, 1, 1),
+                ymd(1900, 2, 28),
+                ymd(1900, 3, 1),
+                ymd(1970, 1, 1)
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_ods() -> PolarsResult<()> {
+        let mut reader = ExcelReader::new(Cursor::new(ods_fixture()));
+        assert_eq!(reader.sheet_names()?, ["First", "Second"]);
+
+        let df = reader.finish()?;
+        assert_eq!(df.shape(), (3, 3));
+        assert!(
+            df.column("id")?
+                .equals_missing(&Column::new("id".into(), [Some(7i64), Some(7), None]))
+        );
+        assert_eq!(df.column("when")?.dtype(), &DataType::Date);
+        assert_eq!(df.column("when")?.null_count(), 1);
+        assert!(
+            df.column("text")?
+                .equals(&Column::new("text".into(), ["a  b\nc", "a  b\nc", "z"]))
+        );
+
+        let df = ExcelReader::new(Cursor::new(ods_fixture()))
+            .with_cell_range(Some("B2:B2".parse()?))
+            .with_has_header(false)
+            .finish()?;
+        assert_eq!(df.shape(), (1, 1));
+        assert_eq!(
+            df.column("column_1")?.cast(&DataType::Int32)?.get(0)?,
+            AnyValue::Int32(18263)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_xlsx_roundtrip() -> PolarsResult<()> {
+        let mut df = df!(
+            "int" => [Some(1i32), None, Some(-3)],
+            "float" => [0.5, f64::NAN, 1e-10],
+            "bool" => [true, false, true],
+            "text" => [Some("a & <b>"), Some(" padded "), None],
+            "date" => [18262i32, 0, -1],
+            "datetime" => [1577880000000i64, 0, 86_399_000],
+        )?;
+        df.apply("date", |c| c.cast(&DataType::Date).unwrap())?;
+        df.apply("datetime", |c| {
+            c.cast(&DataType::Datetime(TimeUnit::Milliseconds, None))
+                .unwrap()
+        })?;
+
+        let options = ExcelSheetOptions {
+            freeze_header: true,
+            autofilter: true,
+            column_formats: [("float".into(), "0.00".to_string())].into_iter().collect(),
+            column_widths: [("text".into(), 20.0)].into_iter().collect(),
+            ..Default::default()
+        };
+        let mut writer = ExcelWriter::new(vec![]);
+        writer.write_sheet("First", &df, &options)?;
+
+        // Sheets can also be written in batches.
+        let mut sheet = writer.start_sheet("Second", df.schema(), &ExcelSheetOptions::default())?;
+        sheet.write_batch(&df.slice(0, 1))?;
+        sheet.write_batch(&df.slice(1, 2))?;
+        sheet.finish()?;
+
+        assert!(
+            writer
+                .write_sheet("first", &df, &ExcelSheetOptions::default())
+                .is_err()
+        );
+        let out = writer.finish()?;
+
+        let mut archive = ZipArchive::try_new(out.clone())?;
+        let sheet = archive.read_string("xl/worksheets/sheet1.xml")?.unwrap();
+        assert!(sheet.contains(r#"state="frozen""#));
+        assert!(sheet.contains(r#"<autoFilter ref="A1:F4"/>"#));
+        assert!(sheet.contains(r#"<col min="4" max="4" width="20" customWidth="1"/>"#));
+        assert!(sheet.contains(r#"<c r="B3" s="1" t="e"><v>#NUM!</v></c>"#));
+
+        for sheet in ["First", "Second"] {
+            let read = ExcelReader::new(Cursor::new(out.clone()))
+                .with_sheet(ExcelSheet::Name(sheet.into()))
+                .finish()?;
+
+            assert_eq!(
+                read.schema().iter_values().cloned().collect::<Vec<_>>(),
+                [
+                    DataType::Int64,
+                    DataType::Float64,
+                    DataType::Boolean,
+                    DataType::String,
+                    DataType::Date,
+                    DataType::Datetime(TimeUnit::Microseconds, None),
+                ]
+            );
+            assert!(
+                read.column("int")?
+                    .equals_missing(&df.column("int")?.cast(&DataType::Int64)?)
+            );
+            assert!(read.column("bool")?.equals(df.column("bool")?));
+            assert!(read.column("text")?.equals_missing(df.column("text")?));
+            assert!(read.column("date")?.equals(df.column("date")?));
+            let datetime = df
+                .column("datetime")?
+                .cast(&DataType::Datetime(TimeUnit::Microseconds, None))?;
+            assert!(read.column("datetime")?.equals(&datetime));
+
+            let float = read.column("float")?.f64()?;
+            assert_eq!(float.get(0), Some(0.5));
+            assert_eq!(float.get(1), None);
+            assert_eq!(float.get(2), Some(1e-10));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_xlsx_file() -> PolarsResult<()> {
+        let mut df = df!(
+            "day" => [-25567i32, -25509, -25508, 0],
+            "amount" => [1.25, -2.5, 1e6, 0.0],
+            "text" => ["a\u{1}b", "'quoted'", "x & y", "line\nbreak"],
+        )?;
+        df.apply("day", |c| c.cast(&DataType::Date).unwrap())?;
+
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("out.xlsx");
+        let options = ExcelSheetOptions {
+            column_formats: [("amount".into(), "#,##0.00".to_string())]
+                .into_iter()
+                .collect(),
+            ..Default::default()
+        };
+        let mut writer = ExcelWriter::new(std::fs::File::create(&path)?);
+        writer.write_sheet("Data", &df, &options)?;
+        writer.finish()?;
+
+        // Dates before 1900-03-01 are one serial lower, as the 1900 date system counts a
+        // 1900-02-29.
+        let mut archive = ZipArchive::try_new(std::fs::read(&path)?)?;
+        let sheet = archive.read_string("xl/worksheets/sheet1.xml")?.unwrap();
+        assert!(sheet.contains("<v>1.0</v>"));
+        assert!(sheet.contains("<v>59.0</v>"));
+        assert!(sheet.contains("<v>61.0</v>"));
+
+        let read = ExcelReader::new(std::fs::File::open(&path)?).finish()?;
+        assert_eq!(read.column("day")?.dtype(), &DataType::Date);
+        assert!(read.column("day")?.equals(df.column("day")?));
+        assert!(read.column("amount")?.equals(df.column("amount")?));
+        assert!(read.column("text")?.equals(&Column::new(
+            "text".into(),
+            ["a\u{1}b", "'quoted'", "x & y", "line\nbreak"]
+        )));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_xlsx_invalid() {
+        let df = df!("a" => [1]).unwrap();
+        let options = ExcelSheetOptions::default();
+
+        let mut writer = ExcelWriter::new(vec![]);
+        assert!(writer.write_sheet("a/b", &df, &options).is_err());
+        assert!(writer.write_sheet(&"x".repeat(32), &df, &options).is_err());
+
+        let options = ExcelSheetOptions {
+            column_widths: [("missing".into(), 10.0)].into_iter().collect(),
+            ..Default::default()
+        };
+        assert!(writer.write_sheet("Sheet1", &df, &options).is_err());
+
+        // A workbook needs at least one sheet.
+        assert!(writer.finish().is_err());
+    }
+}
@@ -0,0 +1,210 @@
+//! OpenDocument spreadsheets (ods).
+use polars_core::prelude::*;
+
+use super::sheet::{Cell, SheetBuilder, parse_iso_datetime};
+use super::xml::{XmlEvent, XmlReader, XmlTag};
+use super::zip::ZipArchive;
+
+pub(super) const CONTENT_PATH: &str = "content.xml";
+
+pub(super) struct OdsWorkbook {
+    content: String,
+    sheet_names: Vec<String>,
+}
+
+impl OdsWorkbook {
+    pub fn try_new(archive: &mut ZipArchive) -> PolarsResult<Self> {
+        let content = archive.read_string(CONTENT_PATH)?.ok_or_else(
+            || polars_err!(ComputeError: "invalid ods workbook: missing '{}'", CONTENT_PATH),
+        )?;
+
+        let mut sheet_names = vec![];
+        let mut reader = XmlReader::new(&content);
+        while let Some(event) = reader.next_event()? {
+            if let XmlEvent::Start(tag) = event {
+                if tag.name() == "table" {
+                    sheet_names.push(tag.attr("name").unwrap_or_default().into_owned());
+                    reader.skip_element(&tag)?;
+                }
+            }
+        }
+
+        Ok(Self {
+            content,
+            sheet_names,
+        })
+    }
+
+    pub fn sheet_names(&self) -> &[String] {
+        &self.sheet_names
+    }
+
+    pub fn read_sheet(&self, index: usize, builder: &mut SheetBuilder) -> PolarsResult<()> {
+        let mut reader = XmlReader::new(&self.content);
+        let mut table_idx = 0;
+        while let Some(event) = reader.next_event()? {
+            let XmlEvent::Start(tag) = event else {
+                continue;
+            };
+            if tag.name() != "table" {
+                continue;
+            }
+            if table_idx == index {
+                return if tag.empty {
+                    Ok(())
+                } else {
+                    read_table(&mut reader, builder)
+                };
+            }
+            reader.skip_element(&tag)?;
+            table_idx += 1;
+        }
+        polars_bail!(ComputeError: "invalid ods workbook: sheet {} not found", index)
+    }
+}
+
+fn repeat_count(tag: &XmlTag, attr: &str) -> usize {
+    tag.attr(attr)
+        .and_then(|n| n.parse::<usize>().ok())
+        .unwrap_or(1)
+        .max(1)
+}
+
+fn read_table(reader: &mut XmlReader, builder: &mut SheetBuilder) -> PolarsResult<()> {
+    let mut row = 0;
+    while let Some(event) = reader.next_event()? {
+        match event {
+            XmlEvent::Start(tag) if tag.name() == "table-row" => {
+                if builder.is_past_end(row) {
+                    return Ok(());
+                }
+                // Runs of identical rows, typically empty ones up to the end of the sheet, are
+                // written once with a repeat count.
+                let repeat = repeat_count(&tag, "number-rows-repeated");
+                if !tag.empty {
+                    let cells = read_row(reader)?;
+                    for r in row..row + repeat {
+                        if cells.is_empty() || builder.is_past_end(r) {
+                            break;
+                        }
+                        for (col, cell) in &cells {
+                            builder.push(r, *col, cell.clone());
+                        }
+                    }
+                }
+                row += repeat;
+            },
+            // Skip shapes, named ranges and the like.
+            XmlEvent::Start(tag)
+                if !matches!(
+                    tag.name(),
+                    "table-row-group" | "table-rows" | "table-header-rows"
+                ) =>
+            {
+                reader.skip_element(&tag)?
+            },
+            XmlEvent::End(end) if end.name() == "table" => return Ok(()),
+            _ => {},
+        }
+    }
+    polars_bail!(ComputeError: "invalid ods workbook: unexpected end of XML")
+}
+
+/// Read the non-empty cells of a row with their column index.
+fn read_row(reader: &mut XmlReader) -> PolarsResult<Vec<(usize, Cell)>> {
+    let mut cells = vec![];
+    let mut col = 0;
+    while let Some(event) = reader.next_event()? {
+        match event {
+            XmlEvent::Start(tag) => {
+                let repeat = repeat_count(&tag, "number-columns-repeated");
+                let cell = match tag.name() {
+                    "table-cell" => read_cell(reader, &tag)?,
+                    // Cells hidden by a merged cell.
+                    "covered-table-cell" => {
+                        reader.skip_element(&tag)?;
+                        Cell::Empty
+                    },
+                    _ => {
+                        reader.skip_element(&tag)?;
+                        continue;
+                    },
+                };
+                if cell != Cell::Empty {
+                    cells.extend((col..col + repeat).map(|c| (c, cell.clone())));
+                }
+                col += repeat;
+            },
+            XmlEvent::End(_) => return Ok(cells),
+            XmlEvent::Text(_) => {},
+        }
+    }
+    polars_bail!(ComputeError: "invalid ods workbook: unexpected end of XML")
+}
+
+fn read_cell(reader: &mut XmlReader, tag: &XmlTag) -> PolarsResult<Cell> {
+    let text = if tag.empty {
+        String::new()
+    } else {
+        read_cell_text(reader)?
+    };
+
+    let number = |attr: &str| {
+        tag.attr(attr)
+            .and_then(|v| v.trim().parse::<f64>().ok())
+            .map_or(Cell::Empty, Cell::Number)
+    };
+    let cell = match tag.attr("value-type").as_deref() {
+        Some("float" | "percentage" | "currency") => number("value"),
+        Some("date") => tag
+            .attr("date-value")
+            .and_then(|v| parse_iso_datetime(&v))
+            .unwrap_or(Cell::Empty),
+        Some("boolean") => tag
+            .attr("boolean-value")
+            .map_or(Cell::Empty, |v| Cell::Bool(v == "true")),
+        // Untyped cells are empty, even if they hold text.
+        None => Cell::Empty,
+        Some(_) => Cell::String(text),
+    };
+    Ok(cell)
+}
+
+/// Collect the paragraphs of a cell, up to and including its end tag.
+fn read_cell_text(reader: &mut XmlReader) -> PolarsResult<String> {
+    let mut out = String::new();
+    let mut paragraphs = 0;
+    let mut depth = 0usize;
+    while let Some(event) = reader.next_event()? {
+        match event {
+            XmlEvent::Start(tag) => {
+                match tag.name() {
+                    // Comments are not part of the value.
+                    "annotation" => {
+                        reader.skip_element(&tag)?;
+                        continue;
+                    },
+                    "p" | "h" => {
+                        if paragraphs > 0 {
+                            out.push('\n');
+                        }
+                        paragraphs += 1;
+                    },
+                    "s" => out.extend(std::iter::repeat_n(' ', repeat_count(&tag, "c"))),
+                    "tab" => out.push('\t'),
+                    "line-break" => out.push('\n'),
+                    _ => {},
+                }
+                if !tag.empty {
+                    depth += 1;
+                }
+            },
+            XmlEvent::End(_) if depth == 0 => return Ok(out),
+            XmlEvent::End(_) => depth -= 1,
+            // Whitespace between the paragraphs is formatting.
+            XmlEvent::Text(text) if depth > 0 => out.push_str(&text),
+            XmlEvent::Text(_) => {},
+        }
+    }
+    polars_bail!(ComputeError: "invalid ods workbook: unexpected end of XML")
+}
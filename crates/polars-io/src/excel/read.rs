@@ -0,0 +1,209 @@
+use std::io::Read;
+
+use polars_core::prelude::*;
+
+use super::ods::{self, OdsWorkbook};
+use super::sheet::{CellRange, SheetBuilder, SheetReadOptions, sheet_to_dataframe};
+use super::xlsx::{self, XlsxWorkbook};
+use super::zip::ZipArchive;
+use crate::prelude::*;
+
+/// The sheet of a workbook to read.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ExcelSheet {
+    /// Zero-based position of the sheet in the workbook.
+    Index(usize),
+    Name(String),
+}
+
+impl Default for ExcelSheet {
+    fn default() -> Self {
+        Self::Index(0)
+    }
+}
+
+enum Workbook {
+    Xlsx(XlsxWorkbook),
+    Ods(OdsWorkbook),
+}
+
+impl Workbook {
+    fn try_new(data: Vec<u8>) -> PolarsResult<Self> {
+        let mut archive = ZipArchive::try_new(data)?;
+        if archive.contains(xlsx::WORKBOOK_PATH) {
+            XlsxWorkbook::try_new(archive).map(Self::Xlsx)
+        } else if archive.contains(ods::CONTENT_PATH) {
+            OdsWorkbook::try_new(&mut archive).map(Self::Ods)
+        } else {
+            polars_bail!(ComputeError: "file is neither an xlsx nor an ods workbook")
+        }
+    }
+
+    fn sheet_names(&self) -> &[String] {
+        match self {
+            Self::Xlsx(workbook) => workbook.sheet_names(),
+            Self::Ods(workbook) => workbook.sheet_names(),
+        }
+    }
+
+    fn sheet_index(&self, sheet: &ExcelSheet) -> PolarsResult<usize> {
+        let names = self.sheet_names();
+        match sheet {
+            ExcelSheet::Index(i) => {
+                polars_ensure!(
+                    *i < names.len(),
+                    OutOfBounds: "sheet index {} is out of bounds for a workbook with {} sheets", i, names.len()
+                );
+                Ok(*i)
+            },
+            ExcelSheet::Name(name) => names.iter().position(|n| n == name).ok_or_else(
+                || polars_err!(ComputeError: "sheet '{}' not found, available sheets: {:?}", name, names),
+            ),
+        }
+    }
+
+    fn read_sheet(&mut self, index: usize, builder: &mut SheetBuilder) -> PolarsResult<()> {
+        match self {
+            Self::Xlsx(workbook) => workbook.read_sheet(index, builder),
+            Self::Ods(workbook) => workbook.read_sheet(index, builder),
+        }
+    }
+}
+
+/// Read a sheet of an Excel (xlsx) or OpenDocument (ods) workbook into a [`DataFrame`].
+///
+/// Column types are inferred with the same rules as the CSV reader. Cells formatted as dates
+/// are read as `Date`, or as `Datetime` if they have a time of day.
+///
+/// # Example
+/// ```
+/// use std::fs::File;
+/// use polars_core::prelude::*;
+/// use polars_io::excel::{ExcelReader, ExcelSheet};
+/// use polars_io::SerReader;
+///
+/// fn example() -> PolarsResult<DataFrame> {
+///     let file = File::open("report.xlsx").expect("file not found");
+///
+///     ExcelReader::new(file)
+///         .with_sheet(ExcelSheet::Name("Summary".into()))
+///         .with_cell_range(Some("B3:F".parse()?))
+///         .finish()
+/// }
+/// ```
+#[must_use]
+pub struct ExcelReader<R> {
+    reader: R,
+    workbook: Option<Workbook>,
+    sheet: ExcelSheet,
+    has_header: bool,
+    skip_rows: usize,
+    n_rows: Option<usize>,
+    cell_range: Option<CellRange>,
+    infer_schema_length: Option<usize>,
+    schema_overwrite: Option<SchemaRef>,
+    try_parse_dates: bool,
+}
+
+impl<R: Read> ExcelReader<R> {
+    /// Select the sheet to read. Defaults to the first sheet.
+    pub fn with_sheet(mut self, sheet: ExcelSheet) -> Self {
+        self.sheet = sheet;
+        self
+    }
+
+    /// Whether the first row (after skipping rows) holds the column names. Defaults to `true`.
+    pub fn with_has_header(mut self, has_header: bool) -> Self {
+        self.has_header = has_header;
+        self
+    }
+
+    /// Skip the first `n` rows of the selected range.
+    pub fn with_skip_rows(mut self, n: usize) -> Self {
+        self.skip_rows = n;
+        self
+    }
+
+    /// Stop reading after `n` data rows.
+    pub fn with_n_rows(mut self, n_rows: Option<usize>) -> Self {
+        self.n_rows = n_rows;
+        self
+    }
+
+    /// Only read the cells in this range, e.g. `"B2:F100"`.
+    pub fn with_cell_range(mut self, cell_range: Option<CellRange>) -> Self {
+        self.cell_range = cell_range;
+        self
+    }
+
+    /// Set the number of rows used to infer the column types. `None` uses all rows and `Some(0)`
+    /// reads all columns as `String`. Defaults to 100.
+    pub fn with_infer_schema_length(mut self, infer_schema_length: Option<usize>) -> Self {
+        self.infer_schema_length = infer_schema_length;
+        self
+    }
+
+    /// Overwrite the inferred type of the columns in this schema.
+    pub fn with_schema_overwrite(mut self, schema: Option<SchemaRef>) -> Self {
+        self.schema_overwrite = schema;
+        self
+    }
+
+    /// Try to parse dates and date-times from text cells.
+    pub fn with_try_parse_dates(mut self, try_parse_dates: bool) -> Self {
+        self.try_parse_dates = try_parse_dates;
+        self
+    }
+
+    fn workbook(&mut self) -> PolarsResult<&mut Workbook> {
+        if self.workbook.is_none() {
+            let mut data = vec![];
+            self.reader.read_to_end(&mut data)?;
+            self.workbook = Some(Workbook::try_new(data)?);
+        }
+        Ok(self.workbook.as_mut().unwrap())
+    }
+
+    /// Get the names of the sheets in the workbook, in order.
+    pub fn sheet_names(&mut self) -> PolarsResult<Vec<String>> {
+        Ok(self.workbook()?.sheet_names().to_vec())
+    }
+}
+
+impl<R: Read> SerReader<R> for ExcelReader<R> {
+    fn new(reader: R) -> Self {
+        ExcelReader {
+            reader,
+            workbook: None,
+            sheet: ExcelSheet::default(),
+            has_header: true,
+            skip_rows: 0,
+            n_rows: None,
+            cell_range: None,
+            infer_schema_length: Some(100),
+            schema_overwrite: None,
+            try_parse_dates: false,
+        }
+    }
+
+    fn finish(mut self) -> PolarsResult<DataFrame> {
+        let sheet = std::mem::take(&mut self.sheet);
+        let cell_range = self.cell_range;
+        let workbook = self.workbook()?;
+
+        let index = workbook.sheet_index(&sheet)?;
+        let mut builder = SheetBuilder::new(cell_range);
+        workbook.read_sheet(index, &mut builder)?;
+        let (rows, width) = builder.finish();
+
+        let options = SheetReadOptions {
+            has_header: self.has_header,
+            skip_rows: self.skip_rows,
+            n_rows: self.n_rows,
+            infer_schema_length: self.infer_schema_length,
+            schema_overwrite: self.schema_overwrite.as_deref(),
+            try_parse_dates: self.try_parse_dates,
+        };
+        sheet_to_dataframe(rows, width, &options)
+    }
+}
@@ -0,0 +1,560 @@
+use std::str::FromStr;
+
+use chrono::{Duration, NaiveDate, NaiveDateTime};
+use polars_core::prelude::*;
+use polars_utils::format_pl_smallstr;
+
+use crate::csv::read::schema_inference::{finish_infer_field_schema, infer_field_schema};
+
+/// Serial number of 1970-01-01 in the 1900 date system.
+pub(super) const UNIX_EPOCH_SERIAL: f64 = 25569.0;
+/// Difference between serial numbers in the 1900 and 1904 date systems.
+pub(super) const DATE_1904_OFFSET: f64 = 1462.0;
+const MICROSECONDS_PER_DAY: f64 = 86_400_000_000.0;
+
+/// A cell value as stored in the workbook.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(super) enum Cell {
+    #[default]
+    Empty,
+    String(String),
+    Number(f64),
+    Bool(bool),
+    /// Days since 1899-12-30.
+    Date(f64),
+    /// Days since 1899-12-30, with a time-of-day fraction.
+    DateTime(f64),
+}
+
+/// Convert a serial number of the 1900 date system to days since 1899-12-30.
+///
+/// The 1900 date system counts the non-existent 1900-02-29 as serial 60, so serials before 61
+/// are one day behind. Serial 60 itself is read as 1900-03-01.
+pub(super) fn serial_1900_to_days(serial: f64) -> f64 {
+    if serial < 61.0 { serial + 1.0 } else { serial }
+}
+
+/// Convert days since 1899-12-30 to a serial number of the 1900 date system.
+pub(super) fn days_to_serial_1900(days: f64) -> f64 {
+    if days < 61.0 { days - 1.0 } else { days }
+}
+
+fn serial_epoch() -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(1899, 12, 30)
+        .unwrap()
+        .and_hms_opt(0, 0, 0)
+        .unwrap()
+}
+
+fn serial_to_naive(serial: f64) -> NaiveDateTime {
+    serial_epoch() + Duration::milliseconds((serial * 86_400_000.0).round() as i64)
+}
+
+/// Parse an ISO 8601 date or date-time as written in the workbook XML into a cell.
+pub(super) fn parse_iso_datetime(s: &str) -> Option<Cell> {
+    let s = s.trim();
+    if let Ok(date) = NaiveDate::parse_from_str(s, "%Y-%m-%d") {
+        let days = (date - serial_epoch().date()).num_days();
+        return Some(Cell::Date(days as f64));
+    }
+    let datetime = parse_datetime_str(s)?;
+    let ms = (datetime - serial_epoch()).num_milliseconds();
+    Some(Cell::DateTime(ms as f64 / 86_400_000.0))
+}
+
+fn parse_datetime_str(s: &str) -> Option<NaiveDateTime> {
+    // Drop a trailing UTC designator, as written for date cells by some applications.
+    let s = s.trim().trim_end_matches('Z');
+    [
+        "%Y-%m-%dT%H:%M:%S%.f",
+        "%Y-%m-%d %H:%M:%S%.f",
+        "%Y-%m-%dT%H:%M",
+        "%Y-%m-%d %H:%M",
+        "%d-%m-%Y %H:%M:%S%.f",
+        "%d/%m/%Y %H:%M:%S%.f",
+    ]
+    .iter()
+    .find_map(|fmt| NaiveDateTime::parse_from_str(s, fmt).ok())
+    .or_else(|| parse_date_str(s).map(|date| date.and_hms_opt(0, 0, 0).unwrap()))
+}
+
+fn parse_date_str(s: &str) -> Option<NaiveDate> {
+    let s = s.trim();
+    ["%Y-%m-%d", "%Y/%m/%d", "%d-%m-%Y", "%d/%m/%Y", "%d.%m.%Y"]
+        .iter()
+        .find_map(|fmt| NaiveDate::parse_from_str(s, fmt).ok())
+}
+
+/// Column index of the letters of an A1 reference, e.g. `AB` is 27.
+fn column_index(letters: &str) -> Option<usize> {
+    letters
+        .bytes()
+        .try_fold(0usize, |acc, b| {
+            b.is_ascii_alphabetic()
+                .then(|| acc * 26 + (b.to_ascii_uppercase() - b'A') as usize + 1)
+        })
+        .and_then(|col| col.checked_sub(1))
+}
+
+/// Letters of the zero-based column index `col` in an A1 reference, e.g. 27 is `AB`.
+pub(super) fn column_letters(col: usize) -> String {
+    let mut letters = vec![];
+    let mut n = col + 1;
+    while n > 0 {
+        let rem = (n - 1) % 26;
+        letters.push(b'A' + rem as u8);
+        n = (n - 1) / 26;
+    }
+    letters.reverse();
+    String::from_utf8(letters).unwrap()
+}
+
+/// Parse an A1 reference into its zero-based column and row, either of which may be omitted.
+pub(super) fn parse_cell_ref(s: &str) -> Option<(Option<usize>, Option<usize>)> {
+    let s = s.trim().replace('$', "");
+    let split = s.find(|c: char| c.is_ascii_digit()).unwrap_or(s.len());
+    let (letters, digits) = s.split_at(split);
+    if letters.is_empty() && digits.is_empty() {
+        return None;
+    }
+
+    let col = if letters.is_empty() {
+        None
+    } else {
+        Some(column_index(letters)?)
+    };
+    let row = if digits.is_empty() {
+        None
+    } else {
+        Some(digits.parse::<usize>().ok()?.checked_sub(1)?)
+    };
+    Some((col, row))
+}
+
+/// A rectangular range of cells in A1 notation, e.g. `B2:D100`.
+///
+/// The end of the range may leave out the row or column to select up to the last row or column
+/// with data, as in `B2:D` or `A3:`. A single reference like `B2` selects everything below and
+/// to the right of it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CellRange {
+    pub start_row: usize,
+    pub start_col: usize,
+    /// Inclusive end row.
+    pub end_row: Option<usize>,
+    /// Inclusive end column.
+    pub end_col: Option<usize>,
+}
+
+impl CellRange {
+    pub fn contains(&self, row: usize, col: usize) -> bool {
+        row >= self.start_row
+            && col >= self.start_col
+            && self.end_row.is_none_or(|end| row <= end)
+            && self.end_col.is_none_or(|end| col <= end)
+    }
+}
+
+impl FromStr for CellRange {
+    type Err = PolarsError;
+
+    fn from_str(s: &str) -> PolarsResult<Self> {
+        let invalid = || polars_err!(InvalidOperation: "invalid cell range: '{}'", s);
+        let (start, end) = s.split_once(':').unwrap_or((s, ""));
+
+        let (start_col, start_row) = parse_cell_ref(start).ok_or_else(invalid)?;
+        let (end_col, end_row) = if end.trim().is_empty() {
+            (None, None)
+        } else {
+            parse_cell_ref(end).ok_or_else(invalid)?
+        };
+
+        let range = CellRange {
+            start_row: start_row.unwrap_or(0),
+            start_col: start_col.unwrap_or(0),
+            end_row,
+            end_col,
+        };
+        polars_ensure!(
+            range.end_row.is_none_or(|end| end >= range.start_row)
+                && range.end_col.is_none_or(|end| end >= range.start_col),
+            InvalidOperation: "cell range '{}' ends before it starts", s
+        );
+        Ok(range)
+    }
+}
+
+/// Collects the non-empty cells of a sheet that fall in the selected range.
+pub(super) struct SheetBuilder {
+    range: CellRange,
+    rows: Vec<Vec<Cell>>,
+}
+
+impl SheetBuilder {
+    pub fn new(range: Option<CellRange>) -> Self {
+        Self {
+            range: range.unwrap_or_default(),
+            rows: vec![],
+        }
+    }
+
+    /// Whether `row` lies below the selected range, so that reading can stop.
+    pub fn is_past_end(&self, row: usize) -> bool {
+        self.range.end_row.is_some_and(|end| row > end)
+    }
+
+    pub fn push(&mut self, row: usize, col: usize, cell: Cell) {
+        if cell == Cell::Empty || !self.range.contains(row, col) {
+            return;
+        }
+        let (row, col) = (row - self.range.start_row, col - self.range.start_col);
+
+        if self.rows.len() <= row {
+            self.rows.resize_with(row + 1, Vec::new);
+        }
+        let row = &mut self.rows[row];
+        if row.len() <= col {
+            row.resize(col + 1, Cell::Empty);
+        }
+        row[col] = cell;
+    }
+
+    /// Returns the rows of the selected range and its width.
+    pub fn finish(self) -> (Vec<Vec<Cell>>, usize) {
+        let width = self.rows.iter().map(|row| row.len()).max().unwrap_or(0);
+        let width = match self.range.end_col {
+            Some(end) => width.max(end - self.range.start_col + 1),
+            None => width,
+        };
+        (self.rows, width)
+    }
+}
+
+pub(super) struct SheetReadOptions<'a> {
+    pub has_header: bool,
+    pub skip_rows: usize,
+    pub n_rows: Option<usize>,
+    pub infer_schema_length: Option<usize>,
+    pub schema_overwrite: Option<&'a Schema>,
+    pub try_parse_dates: bool,
+}
+
+fn cell_to_string(cell: &Cell) -> Option<String> {
+    match cell {
+        Cell::Empty => None,
+        Cell::String(s) => Some(s.clone()),
+        Cell::Number(v) if v.fract() == 0.0 && v.abs() < 1e15 => Some(format!("{}", *v as i64)),
+        Cell::Number(v) => Some(format!("{v}")),
+        Cell::Bool(v) => Some(v.to_string()),
+        Cell::Date(v) => Some(serial_to_naive(*v).date().to_string()),
+        Cell::DateTime(v) => Some(serial_to_naive(*v).to_string()),
+    }
+}
+
+fn infer_cell_dtype(cell: &Cell, try_parse_dates: bool) -> Option<DataType> {
+    let dtype = match cell {
+        Cell::Empty => return None,
+        Cell::String(s) => match infer_field_schema(s, try_parse_dates, false) {
+            // Only naive dates and date-times are parsed from text.
+            DataType::Date => DataType::Date,
+            DataType::Datetime(_, None) => DataType::Datetime(TimeUnit::Microseconds, None),
+            DataType::Time | DataType::Datetime(_, Some(_)) => DataType::String,
+            dtype => dtype,
+        },
+        Cell::Number(v) if v.fract() == 0.0 && v.abs() < 2f64.powi(53) => DataType::Int64,
+        Cell::Number(_) => DataType::Float64,
+        Cell::Bool(_) => DataType::Boolean,
+        Cell::Date(v) if v.fract() == 0.0 => DataType::Date,
+        Cell::Date(_) | Cell::DateTime(_) => DataType::Datetime(TimeUnit::Microseconds, None),
+    };
+    Some(dtype)
+}
+
+fn infer_column_dtype<'a>(
+    cells: impl Iterator<Item = &'a Cell>,
+    try_parse_dates: bool,
+) -> DataType {
+    let mut possibilities = cells
+        .filter_map(|cell| infer_cell_dtype(cell, try_parse_dates))
+        .collect::<PlHashSet<_>>();
+
+    if possibilities.is_empty() {
+        return DataType::String;
+    }
+    // Dates are widened to date-times.
+    if possibilities.len() > 1 && possibilities.remove(&DataType::Date) {
+        possibilities.insert(DataType::Datetime(TimeUnit::Microseconds, None));
+    }
+    finish_infer_field_schema(&possibilities)
+}
+
+fn build_column(name: PlSmallStr, cells: &[&Cell], dtype: &DataType) -> PolarsResult<Column> {
+    let series = if dtype.is_integer() {
+        Int64Chunked::from_iter_options(
+            name,
+            cells.iter().map(|cell| match cell {
+                Cell::Number(v) | Cell::Date(v) | Cell::DateTime(v) if v.fract() == 0.0 => {
+                    Some(*v as i64)
+                },
+                Cell::Bool(v) => Some(*v as i64),
+                Cell::String(s) => s.trim().parse().ok(),
+                _ => None,
+            }),
+        )
+        .into_series()
+    } else if dtype.is_float() {
+        Float64Chunked::from_iter_options(
+            name,
+            cells.iter().map(|cell| match cell {
+                Cell::Number(v) | Cell::Date(v) | Cell::DateTime(v) => Some(*v),
+                Cell::Bool(v) => Some(*v as u8 as f64),
+                Cell::String(s) => s.trim().parse().ok(),
+                Cell::Empty => None,
+            }),
+        )
+        .into_series()
+    } else {
+        match dtype {
+            DataType::Boolean => BooleanChunked::from_iter_options(
+                name,
+                cells.iter().map(|cell| match cell {
+                    Cell::Bool(v) => Some(*v),
+                    Cell::Number(v) => Some(*v != 0.0),
+                    Cell::String(s) => match s.trim().to_ascii_lowercase().as_str() {
+                        "true" => Some(true),
+                        "false" => Some(false),
+                        _ => None,
+                    },
+                    _ => None,
+                }),
+            )
+            .into_series(),
+            DataType::Date => Int32Chunked::from_iter_options(
+                name,
+                cells.iter().map(|cell| match cell {
+                    Cell::Number(v) | Cell::Date(v) | Cell::DateTime(v) => {
+                        Some((v.floor() - UNIX_EPOCH_SERIAL) as i32)
+                    },
+                    Cell::String(s) => parse_date_str(s)
+                        .or_else(|| parse_datetime_str(s).map(|dt| dt.date()))
+                        .map(|date| {
+                            (date - NaiveDate::from_ymd_opt(1970, 1, 1).unwrap()).num_days() as i32
+                        }),
+                    _ => None,
+                }),
+            )
+            .into_date()
+            .into_series(),
+            DataType::Datetime(_, _) => Int64Chunked::from_iter_options(
+                name,
+                cells.iter().map(|cell| match cell {
+                    Cell::Number(v) | Cell::Date(v) | Cell::DateTime(v) => {
+                        Some(((v - UNIX_EPOCH_SERIAL) * MICROSECONDS_PER_DAY).round() as i64)
+                    },
+                    Cell::String(s) => {
+                        parse_datetime_str(s).map(|dt| dt.and_utc().timestamp_micros())
+                    },
+                    _ => None,
+                }),
+            )
+            .into_datetime(TimeUnit::Microseconds, None)
+            .into_series(),
+            _ => StringChunked::from_iter_options(
+                name,
+                cells.iter().map(|cell| cell_to_string(cell)),
+            )
+            .into_series(),
+        }
+    };
+
+    let series = if series.dtype() != dtype {
+        series.strict_cast(dtype)?
+    } else {
+        series
+    };
+    Ok(series.into_column())
+}
+
+/// Convert the rows of a sheet into a [`DataFrame`], inferring the column types.
+pub(super) fn sheet_to_dataframe(
+    rows: Vec<Vec<Cell>>,
+    width: usize,
+    options: &SheetReadOptions,
+) -> PolarsResult<DataFrame> {
+    let mut rows = rows.into_iter().skip(options.skip_rows);
+    let header = if options.has_header {
+        rows.next()
+    } else {
+        None
+    };
+    let rows = match options.n_rows {
+        Some(n) => rows.take(n).collect::<Vec<_>>(),
+        None => rows.collect::<Vec<_>>(),
+    };
+
+    let width = rows
+        .iter()
+        .map(|row| row.len())
+        .chain(header.as_ref().map(|header| header.len()))
+        .fold(width, usize::max);
+
+    let mut names = Vec::with_capacity(width);
+    let mut name_counts = PlHashMap::with_capacity(width);
+    for i in 0..width {
+        let name = header
+            .as_ref()
+            .and_then(|header| header.get(i))
+            .and_then(cell_to_string)
+            .filter(|name| !name.is_empty())
+            .map(PlSmallStr::from_string)
+            .unwrap_or_else(|| format_pl_smallstr!("column_{}", i + 1));
+
+        let count = name_counts.entry(name.clone()).or_insert(0usize);
+        names.push(if *count == 0 {
+            name
+        } else {
+            format_pl_smallstr!("{}_duplicated_{}", name, *count - 1)
+        });
+        *count += 1;
+    }
+
+    let empty = Cell::Empty;
+    let infer_len = options.infer_schema_length.unwrap_or(usize::MAX);
+    let columns = names
+        .into_iter()
+        .enumerate()
+        .map(|(i, name)| {
+            let cells = rows
+                .iter()
+                .map(|row| row.get(i).unwrap_or(&empty))
+                .collect::<Vec<_>>();
+
+            let dtype = match options
+                .schema_overwrite
+                .and_then(|schema| schema.get(&name))
+            {
+                Some(dtype) => dtype.clone(),
+                None if infer_len == 0 => DataType::String,
+                None => infer_column_dtype(
+                    cells.iter().copied().take(infer_len),
+                    options.try_parse_dates,
+                ),
+            };
+            build_column(name, &cells, &dtype)
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    DataFrame::new(rows.len(), columns)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_cell_range() -> PolarsResult<()> {
+        assert_eq!(parse_cell_ref("AB12"), Some((Some(27), Some(11))));
+        assert_eq!(parse_cell_ref("$C$3"), Some((Some(2), Some(2))));
+        assert_eq!(parse_cell_ref("C"), Some((Some(2), None)));
+        for col in [0, 25, 26, 27, 701, 702, 16383] {
+            assert_eq!(column_index(&column_letters(col)), Some(col));
+        }
+        assert_eq!(column_letters(16383), "XFD");
+
+        assert_eq!(
+            "B2:D10".parse::<CellRange>()?,
+            CellRange {
+                start_row: 1,
+                start_col: 1,
+                end_row: Some(9),
+                end_col: Some(3),
+            }
+        );
+        assert_eq!(
+            "A:C".parse::<CellRange>()?,
+            CellRange {
+                start_row: 0,
+                start_col: 0,
+                end_row: None,
+                end_col: Some(2),
+            }
+        );
+        assert!("D1:A1".parse::<CellRange>().is_err());
+        assert!("1A".parse::<CellRange>().is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_sheet_to_dataframe() -> PolarsResult<()> {
+        let mut builder = SheetBuilder::new(None);
+        let rows = [
+            vec![Cell::String("title".into())],
+            vec![
+                Cell::String("int".into()),
+                Cell::String("float".into()),
+                Cell::String("date".into()),
+                Cell::String("mixed".into()),
+                Cell::String("int".into()),
+            ],
+            vec![
+                Cell::Number(1.0),
+                Cell::Number(1.5),
+                Cell::Date(43831.0),
+                Cell::Number(1.0),
+                Cell::String("2".into()),
+            ],
+            vec![
+                Cell::Empty,
+                Cell::Number(2.0),
+                Cell::DateTime(43831.5),
+                Cell::Bool(true),
+                Cell::Number(3.0),
+            ],
+        ];
+        for (i, row) in rows.into_iter().enumerate() {
+            for (j, cell) in row.into_iter().enumerate() {
+                builder.push(i, j, cell);
+            }
+        }
+        let (rows, width) = builder.finish();
+
+        let options = SheetReadOptions {
+            has_header: true,
+            skip_rows: 1,
+            n_rows: None,
+            infer_schema_length: Some(100),
+            schema_overwrite: None,
+            try_parse_dates: false,
+        };
+        let df = sheet_to_dataframe(rows, width, &options)?;
+
+        assert_eq!(
+            df.schema().iter_values().cloned().collect::<Vec<_>>(),
+            [
+                DataType::Int64,
+                DataType::Float64,
+                DataType::Datetime(TimeUnit::Microseconds, None),
+                DataType::String,
+                DataType::Int64,
+            ]
+        );
+        assert_eq!(
+            df.get_column_names(),
+            ["int", "float", "date", "mixed", "int_duplicated_0"]
+        );
+
+        let expected = df!(
+            "int" => [Some(1i64), None],
+            "float" => [1.5, 2.0],
+            "date" => [1577836800000000i64, 1577880000000000],
+            "mixed" => ["1", "true"],
+            "int_duplicated_0" => [2i64, 3],
+        )?;
+        let date = df.column("date")?.cast(&DataType::Int64)?;
+        assert!(date.equals(expected.column("date")?));
+        assert!(df.drop("date")?.equals_missing(&expected.drop("date")?));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,626 @@
+use std::fmt::Write as _;
+use std::io::Write;
+
+use polars_core::prelude::*;
+
+use super::sheet::{UNIX_EPOCH_SERIAL, column_letters, days_to_serial_1900};
+use super::xml::escape;
+use super::zip::{ZipEntryWriter, ZipWriter};
+
+const MAX_ROWS: usize = 1 << 20;
+const MAX_COLUMNS: usize = 1 << 14;
+const MAX_SHEET_NAME_LEN: usize = 31;
+const MAX_STRING_LEN: usize = 32767;
+/// Number format ids below this one are built in.
+const FIRST_CUSTOM_FORMAT_ID: u32 = 164;
+
+const XML_DECLARATION: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>"#;
+const SPREADSHEET_NS: &str = "http://schemas.openxmlformats.org/spreadsheetml/2006/main";
+const RELATIONSHIPS_NS: &str =
+    "http://schemas.openxmlformats.org/officeDocument/2006/relationships";
+const PACKAGE_RELATIONSHIPS_NS: &str =
+    "http://schemas.openxmlformats.org/package/2006/relationships";
+
+/// Options for writing a sheet with the [`ExcelWriter`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExcelSheetOptions {
+    /// Write the column names as a bold first row.
+    pub include_header: bool,
+    /// Keep the header row in view when scrolling.
+    pub freeze_header: bool,
+    /// Add filter buttons to the header row.
+    pub autofilter: bool,
+    /// Number format codes of columns, e.g. `"#,##0.00"` or `"0.0%"`.
+    pub column_formats: PlHashMap<PlSmallStr, String>,
+    /// Widths of columns, in characters.
+    pub column_widths: PlHashMap<PlSmallStr, f64>,
+    pub date_format: String,
+    pub datetime_format: String,
+    pub time_format: String,
+    pub duration_format: String,
+}
+
+impl Default for ExcelSheetOptions {
+    fn default() -> Self {
+        Self {
+            include_header: true,
+            freeze_header: false,
+            autofilter: false,
+            column_formats: PlHashMap::new(),
+            column_widths: PlHashMap::new(),
+            date_format: "yyyy-mm-dd".to_string(),
+            datetime_format: "yyyy-mm-dd hh:mm:ss".to_string(),
+            time_format: "hh:mm:ss".to_string(),
+            duration_format: "[h]:mm:ss".to_string(),
+        }
+    }
+}
+
+impl ExcelSheetOptions {
+    fn default_format(&self, dtype: &DataType) -> Option<&str> {
+        match dtype {
+            DataType::Date => Some(&self.date_format),
+            DataType::Datetime(_, _) => Some(&self.datetime_format),
+            DataType::Time => Some(&self.time_format),
+            DataType::Duration(_) => Some(&self.duration_format),
+            _ => None,
+        }
+    }
+}
+
+/// The number formats and cell styles of a workbook.
+struct Styles {
+    num_formats: Vec<String>,
+    /// Number format id and whether the font is bold, indexed by cell style.
+    cell_styles: Vec<(u32, bool)>,
+}
+
+impl Default for Styles {
+    fn default() -> Self {
+        Self {
+            num_formats: vec![],
+            cell_styles: vec![(0, false)],
+        }
+    }
+}
+
+impl Styles {
+    fn cell_style(&mut self, format: Option<&str>, bold: bool) -> u32 {
+        let num_format_id = match format {
+            None | Some("General") => 0,
+            Some(format) => {
+                let idx = match self.num_formats.iter().position(|f| f == format) {
+                    Some(idx) => idx,
+                    None => {
+                        self.num_formats.push(format.to_string());
+                        self.num_formats.len() - 1
+                    },
+                };
+                FIRST_CUSTOM_FORMAT_ID + idx as u32
+            },
+        };
+
+        let style = (num_format_id, bold);
+        let idx = match self.cell_styles.iter().position(|s| *s == style) {
+            Some(idx) => idx,
+            None => {
+                self.cell_styles.push(style);
+                self.cell_styles.len() - 1
+            },
+        };
+        idx as u32
+    }
+
+    fn to_xml(&self) -> String {
+        let mut xml = format!(r#"{XML_DECLARATION}<styleSheet xmlns="{SPREADSHEET_NS}">"#);
+        if !self.num_formats.is_empty() {
+            write!(xml, r#"<numFmts count="{}">"#, self.num_formats.len()).unwrap();
+            for (i, format) in self.num_formats.iter().enumerate() {
+                write!(
+                    xml,
+                    r#"<numFmt numFmtId="{}" formatCode="{}"/>"#,
+                    FIRST_CUSTOM_FORMAT_ID + i as u32,
+                    escape(format)
+                )
+                .unwrap();
+            }
+            xml.push_str("</numFmts>");
+        }
+        xml.push_str(concat!(
+            r#"<fonts count="2">"#,
+            r#"<font><sz val="11"/><name val="Calibri"/><family val="2"/></font>"#,
+            r#"<font><b/><sz val="11"/><name val="Calibri"/><family val="2"/></font>"#,
+            "</fonts>",
+            r#"<fills count="2">"#,
+            r#"<fill><patternFill patternType="none"/></fill>"#,
+            r#"<fill><patternFill patternType="gray125"/></fill>"#,
+            "</fills>",
+            r#"<borders count="1"><border><left/><right/><top/><bottom/><diagonal/></border></borders>"#,
+            r#"<cellStyleXfs count="1"><xf numFmtId="0" fontId="0" fillId="0" borderId="0"/></cellStyleXfs>"#,
+        ));
+        write!(xml, r#"<cellXfs count="{}">"#, self.cell_styles.len()).unwrap();
+        for (num_format_id, bold) in &self.cell_styles {
+            write!(
+                xml,
+                r#"<xf numFmtId="{num_format_id}" fontId="{}" fillId="0" borderId="0" xfId="0""#,
+                *bold as u8
+            )
+            .unwrap();
+            if *num_format_id != 0 {
+                xml.push_str(r#" applyNumberFormat="1""#);
+            }
+            if *bold {
+                xml.push_str(r#" applyFont="1""#);
+            }
+            xml.push_str("/>");
+        }
+        xml.push_str(concat!(
+            "</cellXfs>",
+            r#"<cellStyles count="1"><cellStyle name="Normal" xfId="0" builtinId="0"/></cellStyles>"#,
+            "</styleSheet>"
+        ));
+        xml
+    }
+}
+
+struct SheetInfo {
+    name: String,
+    /// Absolute reference of the autofilter range.
+    filter: Option<String>,
+}
+
+fn validate_sheet_name(name: &str, sheets: &[SheetInfo]) -> PolarsResult<()> {
+    polars_ensure!(
+        !name.is_empty() && name.chars().count() <= MAX_SHEET_NAME_LEN,
+        InvalidOperation: "sheet names must have 1 to {} characters, got '{}'", MAX_SHEET_NAME_LEN, name
+    );
+    polars_ensure!(
+        !name.contains(['[', ']', ':', '*', '?', '/', '\\'])
+            && !name.starts_with('\'')
+            && !name.ends_with('\''),
+        InvalidOperation: "sheet name '{}' contains characters that are not allowed", name
+    );
+    polars_ensure!(
+        !sheets.iter().any(|s| s.name.to_lowercase() == name.to_lowercase()),
+        Duplicate: "workbook already has a sheet named '{}'", name
+    );
+    Ok(())
+}
+
+/// Write [`DataFrame`]s to the sheets of an Excel (xlsx) workbook.
+///
+/// Numbers, booleans and temporal values are written as native cells, with temporal values
+/// formatted by the number formats in the [`ExcelSheetOptions`]. Time zone-aware date-times are
+/// written in UTC. Rows are compressed into the output as they are written, so that large sheets
+/// can be written in batches with [`ExcelWriter::start_sheet`].
+///
+/// # Example
+/// ```
+/// use std::fs::File;
+/// use polars_core::prelude::*;
+/// use polars_io::excel::{ExcelSheetOptions, ExcelWriter};
+///
+/// fn example(df: &DataFrame) -> PolarsResult<()> {
+///     let file = File::create("report.xlsx").expect("could not create file");
+///
+///     let options = ExcelSheetOptions {
+///         freeze_header: true,
+///         autofilter: true,
+///         ..Default::default()
+///     };
+///     let mut writer = ExcelWriter::new(file);
+///     writer.write_sheet("Report", df, &options)?;
+///     writer.finish()?;
+///     Ok(())
+/// }
+/// ```
+pub struct ExcelWriter<W: Write> {
+    zip: ZipWriter<W>,
+    sheets: Vec<SheetInfo>,
+    styles: Styles,
+}
+
+impl<W: Write> ExcelWriter<W> {
+    pub fn new(writer: W) -> Self {
+        Self {
+            zip: ZipWriter::new(writer),
+            sheets: vec![],
+            styles: Styles::default(),
+        }
+    }
+
+    /// Write `df` to a new sheet.
+    pub fn write_sheet(
+        &mut self,
+        name: &str,
+        df: &DataFrame,
+        options: &ExcelSheetOptions,
+    ) -> PolarsResult<()> {
+        let mut sheet = self.start_sheet(name, df.schema(), options)?;
+        sheet.write_batch(df)?;
+        sheet.finish()
+    }
+
+    /// Start a new sheet with the given schema, whose rows are written in batches with
+    /// [`ExcelSheetWriter::write_batch`]. The sheet must be finished before the next one is
+    /// started.
+    pub fn start_sheet(
+        &mut self,
+        name: &str,
+        schema: &Schema,
+        options: &ExcelSheetOptions,
+    ) -> PolarsResult<ExcelSheetWriter<'_, W>> {
+        validate_sheet_name(name, &self.sheets)?;
+        polars_ensure!(
+            schema.len() <= MAX_COLUMNS,
+            InvalidOperation: "Excel sheets have at most {} columns, got {}", MAX_COLUMNS, schema.len()
+        );
+        for column in options
+            .column_formats
+            .keys()
+            .chain(options.column_widths.keys())
+        {
+            polars_ensure!(schema.contains(column), ColumnNotFound: "{}", column);
+        }
+        for (column, dtype) in schema.iter() {
+            polars_ensure!(
+                !(dtype.is_nested() || dtype.is_binary() || dtype.is_object()),
+                InvalidOperation: "cannot write column '{}' of type {} to Excel", column, dtype
+            );
+        }
+
+        let styles = schema
+            .iter()
+            .map(|(column, dtype)| {
+                let format = match options.column_formats.get(column) {
+                    Some(format) => Some(format.as_str()),
+                    None => options.default_format(dtype),
+                };
+                self.styles.cell_style(format, false)
+            })
+            .collect::<Vec<_>>();
+        let header_style = self.styles.cell_style(None, true);
+        let columns = (0..schema.len()).map(column_letters).collect::<Vec<_>>();
+
+        let mut xml = format!(
+            r#"{XML_DECLARATION}<worksheet xmlns="{SPREADSHEET_NS}" xmlns:r="{RELATIONSHIPS_NS}">"#
+        );
+        if options.include_header && options.freeze_header {
+            xml.push_str(concat!(
+                r#"<sheetViews><sheetView workbookViewId="0">"#,
+                r#"<pane ySplit="1" topLeftCell="A2" activePane="bottomLeft" state="frozen"/>"#,
+                r#"<selection pane="bottomLeft"/>"#,
+                "</sheetView></sheetViews>",
+            ));
+        }
+        if !options.column_widths.is_empty() {
+            xml.push_str("<cols>");
+            for (i, column) in schema.iter_names().enumerate() {
+                if let Some(width) = options.column_widths.get(column) {
+                    write!(
+                        xml,
+                        r#"<col min="{0}" max="{0}" width="{width}" customWidth="1"/>"#,
+                        i + 1
+                    )
+                    .unwrap();
+                }
+            }
+            xml.push_str("</cols>");
+        }
+        xml.push_str("<sheetData>");
+
+        let path = format!("xl/worksheets/sheet{}.xml", self.sheets.len() + 1);
+        let mut entry = self.zip.start_entry(&path)?;
+        entry.write_all(xml.as_bytes())?;
+
+        self.sheets.push(SheetInfo {
+            name: name.to_string(),
+            filter: None,
+        });
+        let mut sheet = ExcelSheetWriter {
+            entry,
+            info: self.sheets.last_mut().unwrap(),
+            schema: schema.clone(),
+            styles,
+            columns,
+            autofilter: options.include_header && options.autofilter,
+            next_row: 0,
+            buf: String::new(),
+        };
+        if options.include_header {
+            sheet.write_header(header_style)?;
+        }
+        Ok(sheet)
+    }
+
+    /// Write the workbook parts and return the underlying writer.
+    pub fn finish(mut self) -> PolarsResult<W> {
+        polars_ensure!(
+            !self.sheets.is_empty(),
+            InvalidOperation: "an Excel workbook must have at least one sheet"
+        );
+
+        let mut content_types = format!(
+            concat!(
+                "{}",
+                r#"<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types">"#,
+                r#"<Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/>"#,
+                r#"<Default Extension="xml" ContentType="application/xml"/>"#,
+                r#"<Override PartName="/xl/workbook.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.sheet.main+xml"/>"#,
+                r#"<Override PartName="/xl/styles.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.styles+xml"/>"#,
+            ),
+            XML_DECLARATION
+        );
+        let mut workbook = format!(
+            r#"{XML_DECLARATION}<workbook xmlns="{SPREADSHEET_NS}" xmlns:r="{RELATIONSHIPS_NS}"><bookViews><workbookView/></bookViews><sheets>"#
+        );
+        let mut workbook_rels =
+            format!(r#"{XML_DECLARATION}<Relationships xmlns="{PACKAGE_RELATIONSHIPS_NS}">"#);
+        let mut defined_names = String::new();
+
+        for (i, sheet) in self.sheets.iter().enumerate() {
+            let n = i + 1;
+            write!(
+                content_types,
+                r#"<Override PartName="/xl/worksheets/sheet{n}.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.worksheet+xml"/>"#
+            )
+            .unwrap();
+            write!(
+                workbook,
+                r#"<sheet name="{}" sheetId="{n}" r:id="rId{n}"/>"#,
+                escape(&sheet.name)
+            )
+            .unwrap();
+            write!(
+                workbook_rels,
+                r#"<Relationship Id="rId{n}" Type="{RELATIONSHIPS_NS}/worksheet" Target="worksheets/sheet{n}.xml"/>"#
+            )
+            .unwrap();
+            // Spreadsheet applications expect a hidden name for the range of an autofilter.
+            if let Some(filter) = &sheet.filter {
+                let reference = format!("'{}'!{}", sheet.name.replace('\'', "''"), filter);
+                write!(
+                    defined_names,
+                    r#"<definedName name="_xlnm._FilterDatabase" localSheetId="{i}" hidden="1">{}</definedName>"#,
+                    escape(&reference)
+                )
+                .unwrap();
+            }
+        }
+
+        content_types.push_str("</Types>");
+        workbook.push_str("</sheets>");
+        if !defined_names.is_empty() {
+            write!(workbook, "<definedNames>{defined_names}</definedNames>").unwrap();
+        }
+        workbook.push_str("</workbook>");
+        write!(
+            workbook_rels,
+            r#"<Relationship Id="rId{}" Type="{RELATIONSHIPS_NS}/styles" Target="styles.xml"/></Relationships>"#,
+            self.sheets.len() + 1
+        )
+        .unwrap();
+        let root_rels = format!(
+            r#"{XML_DECLARATION}<Relationships xmlns="{PACKAGE_RELATIONSHIPS_NS}"><Relationship Id="rId1" Type="{RELATIONSHIPS_NS}/officeDocument" Target="xl/workbook.xml"/></Relationships>"#
+        );
+
+        self.zip
+            .write_entry("[Content_Types].xml", content_types.as_bytes())?;
+        self.zip.write_entry("_rels/.rels", root_rels.as_bytes())?;
+        self.zip
+            .write_entry("xl/workbook.xml", workbook.as_bytes())?;
+        self.zip
+            .write_entry("xl/_rels/workbook.xml.rels", workbook_rels.as_bytes())?;
+        self.zip
+            .write_entry("xl/styles.xml", self.styles.to_xml().as_bytes())?;
+        self.zip.finish()
+    }
+}
+
+/// Cell values of a column, with temporal values converted to serial numbers.
+enum ColumnCells {
+    Bool(BooleanChunked),
+    Int(Int64Chunked),
+    Float(Float64Chunked),
+    String(StringChunked),
+}
+
+fn column_cells(column: &Column) -> PolarsResult<ColumnCells> {
+    let s = column.as_materialized_series().rechunk();
+
+    let to_days = |s: &Series, units_per_day: f64| -> PolarsResult<Float64Chunked> {
+        let s = s.to_physical_repr().cast(&DataType::Float64)?;
+        Ok(s.f64()?.apply_values(|v| v / units_per_day))
+    };
+    let to_serial = |s: &Series, units_per_day: f64| -> PolarsResult<ColumnCells> {
+        let ca = to_days(s, units_per_day)?;
+        Ok(ColumnCells::Float(ca.apply_values(|v| {
+            days_to_serial_1900(v + UNIX_EPOCH_SERIAL)
+        })))
+    };
+    let units_per_day = |tu: &TimeUnit| match tu {
+        TimeUnit::Nanoseconds => 86_400_000_000_000.0,
+        TimeUnit::Microseconds => 86_400_000_000.0,
+        TimeUnit::Milliseconds => 86_400_000.0,
+    };
+
+    let cells = match s.dtype() {
+        DataType::Boolean => ColumnCells::Bool(s.bool()?.clone()),
+        DataType::String => ColumnCells::String(s.str()?.clone()),
+        DataType::Date => to_serial(&s, 1.0)?,
+        DataType::Datetime(tu, _) => to_serial(&s, units_per_day(tu))?,
+        DataType::Duration(tu) => ColumnCells::Float(to_days(&s, units_per_day(tu))?),
+        DataType::Time => ColumnCells::Float(to_days(&s, 86_400_000_000_000.0)?),
+        // Spreadsheets store numbers as doubles, so only integers that fit in one exactly are
+        // written as integers.
+        DataType::UInt64 | DataType::Int128 | DataType::UInt128 => {
+            ColumnCells::Float(s.cast(&DataType::Float64)?.f64()?.clone())
+        },
+        dt if dt.is_integer() => ColumnCells::Int(s.cast(&DataType::Int64)?.i64()?.clone()),
+        dt if dt.is_float() || dt.is_decimal() => {
+            ColumnCells::Float(s.cast(&DataType::Float64)?.f64()?.clone())
+        },
+        _ => ColumnCells::String(s.cast(&DataType::String)?.str()?.clone()),
+    };
+    Ok(cells)
+}
+
+/// Writes the rows of a sheet started with [`ExcelWriter::start_sheet`].
+pub struct ExcelSheetWriter<'a, W: Write> {
+    entry: ZipEntryWriter<'a, W>,
+    info: &'a mut SheetInfo,
+    schema: Schema,
+    /// Cell style of every column.
+    styles: Vec<u32>,
+    /// Column letters of every column.
+    columns: Vec<String>,
+    autofilter: bool,
+    next_row: usize,
+    buf: String,
+}
+
+impl<W: Write> ExcelSheetWriter<'_, W> {
+    fn start_row(&mut self, n_rows: usize) -> PolarsResult<()> {
+        polars_ensure!(
+            self.next_row + n_rows <= MAX_ROWS,
+            InvalidOperation: "Excel sheets have at most {} rows", MAX_ROWS
+        );
+        Ok(())
+    }
+
+    fn write_header(&mut self, style: u32) -> PolarsResult<()> {
+        self.start_row(1)?;
+        self.buf.clear();
+        self.buf.push_str(r#"<row r="1">"#);
+        for (name, column) in self.schema.iter_names().zip(&self.columns) {
+            write_string_cell(&mut self.buf, column, 1, style, name)?;
+        }
+        self.buf.push_str("</row>");
+        self.entry.write_all(self.buf.as_bytes())?;
+        self.next_row += 1;
+        Ok(())
+    }
+
+    /// Write the rows of `df`, which must match the schema of the sheet.
+    pub fn write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
+        polars_ensure!(
+            df.schema().as_ref() == &self.schema,
+            SchemaMismatch: "cannot write frame with schema {:?} to sheet with schema {:?}",
+            df.schema(), self.schema
+        );
+        self.start_row(df.height())?;
+
+        let cells = df
+            .columns()
+            .iter()
+            .map(column_cells)
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let mut int_buf = itoa::Buffer::new();
+        let mut float_buf = zmij::Buffer::new();
+
+        for i in 0..df.height() {
+            let row = self.next_row + 1;
+            self.buf.clear();
+            write!(self.buf, r#"<row r="{row}">"#).unwrap();
+
+            for ((cells, column), style) in cells.iter().zip(&self.columns).zip(&self.styles) {
+                let buf = &mut self.buf;
+                match cells {
+                    ColumnCells::Bool(ca) => {
+                        if let Some(v) = ca.get(i) {
+                            write_cell_start(buf, column, row, *style, Some("b"));
+                            write!(buf, "<v>{}</v></c>", v as u8).unwrap();
+                        }
+                    },
+                    ColumnCells::Int(ca) => {
+                        if let Some(v) = ca.get(i) {
+                            write_cell_start(buf, column, row, *style, None);
+                            write!(buf, "<v>{}</v></c>", int_buf.format(v)).unwrap();
+                        }
+                    },
+                    ColumnCells::Float(ca) => match ca.get(i) {
+                        Some(v) if v.is_finite() => {
+                            write_cell_start(buf, column, row, *style, None);
+                            write!(buf, "<v>{}</v></c>", float_buf.format(v)).unwrap();
+                        },
+                        // Spreadsheets have no representation for NaN and infinity.
+                        Some(_) => {
+                            write_cell_start(buf, column, row, *style, Some("e"));
+                            buf.push_str("<v>#NUM!</v></c>");
+                        },
+                        None => {},
+                    },
+                    ColumnCells::String(ca) => {
+                        if let Some(v) = ca.get(i) {
+                            write_string_cell(buf, column, row, *style, v)?;
+                        }
+                    },
+                }
+            }
+
+            self.buf.push_str("</row>");
+            self.entry.write_all(self.buf.as_bytes())?;
+            self.next_row += 1;
+        }
+        Ok(())
+    }
+
+    /// Write the end of the sheet.
+    pub fn finish(mut self) -> PolarsResult<()> {
+        self.buf.clear();
+        self.buf.push_str("</sheetData>");
+        if self.autofilter && !self.columns.is_empty() {
+            let last_column = self.columns.last().unwrap();
+            let last_row = self.next_row.max(1);
+            write!(
+                self.buf,
+                r#"<autoFilter ref="A1:{last_column}{last_row}"/>"#
+            )
+            .unwrap();
+            self.info.filter = Some(format!("$A$1:${last_column}${last_row}"));
+        }
+        self.buf.push_str("</worksheet>");
+        self.entry.write_all(self.buf.as_bytes())?;
+        Ok(())
+    }
+}
+
+fn write_cell_start(
+    buf: &mut String,
+    column: &str,
+    row: usize,
+    style: u32,
+    cell_type: Option<&str>,
+) {
+    write!(buf, r#"<c r="{column}{row}""#).unwrap();
+    if style != 0 {
+        write!(buf, r#" s="{style}""#).unwrap();
+    }
+    if let Some(cell_type) = cell_type {
+        write!(buf, r#" t="{cell_type}""#).unwrap();
+    }
+    buf.push('>');
+}
+
+fn write_string_cell(
+    buf: &mut String,
+    column: &str,
+    row: usize,
+    style: u32,
+    value: &str,
+) -> PolarsResult<()> {
+    polars_ensure!(
+        value.len() <= MAX_STRING_LEN || value.chars().count() <= MAX_STRING_LEN,
+        ComputeError: "cell {}{} exceeds the Excel limit of {} characters", column, row, MAX_STRING_LEN
+    );
+    write_cell_start(buf, column, row, style, Some("inlineStr"));
+    // Keep leading and trailing whitespace.
+    if value.starts_with(char::is_whitespace) || value.ends_with(char::is_whitespace) {
+        buf.push_str(r#"<is><t xml:space="preserve">"#);
+    } else {
+        buf.push_str("<is><t>");
+    }
+    buf.push_str(&escape(value));
+    buf.push_str("</t></is></c>");
+    Ok(())
+}
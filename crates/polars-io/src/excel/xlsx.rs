@@ -0,0 +1,345 @@
+//! Office Open XML (xlsx) workbooks.
+use polars_core::prelude::*;
+
+use super::sheet::{
+    Cell, DATE_1904_OFFSET, SheetBuilder, parse_cell_ref, parse_iso_datetime, serial_1900_to_days,
+};
+use super::xml::{XmlEvent, XmlReader, decode_char_notation};
+use super::zip::ZipArchive;
+
+pub(super) const WORKBOOK_PATH: &str = "xl/workbook.xml";
+const WORKBOOK_RELS_PATH: &str = "xl/_rels/workbook.xml.rels";
+const SHARED_STRINGS_PATH: &str = "xl/sharedStrings.xml";
+const STYLES_PATH: &str = "xl/styles.xml";
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum DateKind {
+    Date,
+    DateTime,
+}
+
+pub(super) struct XlsxWorkbook {
+    archive: ZipArchive,
+    sheet_names: Vec<String>,
+    sheet_paths: Vec<String>,
+    shared_strings: Vec<String>,
+    /// Date kind of every cell format, indexed by the `s` attribute of a cell.
+    cell_formats: Vec<Option<DateKind>>,
+    date1904: bool,
+}
+
+/// Date kind of the built-in number format `id`.
+fn builtin_format_kind(id: u32) -> Option<DateKind> {
+    match id {
+        14..=17 | 27..=36 | 50..=58 => Some(DateKind::Date),
+        22 => Some(DateKind::DateTime),
+        _ => None,
+    }
+}
+
+/// Date kind of a custom number format code such as `yyyy-mm-dd hh:mm`.
+fn format_code_kind(code: &str) -> Option<DateKind> {
+    // Only look at the format of positive numbers, without literals and modifiers.
+    let section = code.split(';').next().unwrap_or_default();
+    let mut stripped = String::with_capacity(section.len());
+    let mut chars = section.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => chars.by_ref().take_while(|&c| c != '"').for_each(drop),
+            '[' => chars.by_ref().take_while(|&c| c != ']').for_each(drop),
+            '\\' | '_' | '*' => {
+                chars.next();
+            },
+            c => stripped.push(c.to_ascii_lowercase()),
+        }
+    }
+
+    let has_date = stripped.contains(['y', 'd']);
+    let has_time = stripped.contains(['h', 's']);
+    match (has_date, has_time) {
+        (true, false) => Some(DateKind::Date),
+        (true, true) => Some(DateKind::DateTime),
+        _ => None,
+    }
+}
+
+fn read_part(archive: &mut ZipArchive, path: &str) -> PolarsResult<String> {
+    archive
+        .read_string(path)?
+        .ok_or_else(|| polars_err!(ComputeError: "invalid xlsx workbook: missing '{}'", path))
+}
+
+impl XlsxWorkbook {
+    pub fn try_new(mut archive: ZipArchive) -> PolarsResult<Self> {
+        let mut sheet_names = vec![];
+        let mut sheet_ids = vec![];
+        let mut date1904 = false;
+
+        let xml = read_part(&mut archive, WORKBOOK_PATH)?;
+        let mut reader = XmlReader::new(&xml);
+        while let Some(event) = reader.next_event()? {
+            let XmlEvent::Start(tag) = event else {
+                continue;
+            };
+            match tag.name() {
+                "sheet" => {
+                    let (Some(name), Some(id)) = (tag.attr("name"), tag.attr("id")) else {
+                        polars_bail!(ComputeError: "invalid xlsx workbook: sheet without a name or relationship")
+                    };
+                    sheet_names.push(name.into_owned());
+                    sheet_ids.push(id.into_owned());
+                },
+                "workbookPr" => {
+                    date1904 = tag
+                        .attr("date1904")
+                        .is_some_and(|v| v == "1" || v == "true");
+                },
+                _ => {},
+            }
+        }
+
+        let mut targets = PlHashMap::new();
+        let xml = read_part(&mut archive, WORKBOOK_RELS_PATH)?;
+        let mut reader = XmlReader::new(&xml);
+        while let Some(event) = reader.next_event()? {
+            if let XmlEvent::Start(tag) = event {
+                if let ("Relationship", Some(id), Some(target)) =
+                    (tag.name(), tag.attr("Id"), tag.attr("Target"))
+                {
+                    // Targets are relative to the workbook part unless they are absolute.
+                    let path = match target.strip_prefix('/') {
+                        Some(path) => path.to_string(),
+                        None => format!("xl/{target}"),
+                    };
+                    targets.insert(id.into_owned(), path);
+                }
+            }
+        }
+        let sheet_paths = sheet_ids
+            .iter()
+            .map(|id| {
+                targets.remove(id).ok_or_else(
+                    || polars_err!(ComputeError: "invalid xlsx workbook: unknown sheet relationship '{}'", id),
+                )
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        let shared_strings = match archive.read_string(SHARED_STRINGS_PATH)? {
+            Some(xml) => read_shared_strings(&xml)?,
+            None => vec![],
+        };
+        let cell_formats = match archive.read_string(STYLES_PATH)? {
+            Some(xml) => read_cell_formats(&xml)?,
+            None => vec![],
+        };
+
+        Ok(Self {
+            archive,
+            sheet_names,
+            sheet_paths,
+            shared_strings,
+            cell_formats,
+            date1904,
+        })
+    }
+
+    pub fn sheet_names(&self) -> &[String] {
+        &self.sheet_names
+    }
+
+    pub fn read_sheet(&mut self, index: usize, builder: &mut SheetBuilder) -> PolarsResult<()> {
+        let xml = read_part(&mut self.archive, &self.sheet_paths[index])?;
+        let mut reader = XmlReader::new(&xml);
+        let mut row = 0;
+        let mut next_row = 0;
+        let mut next_col = 0;
+
+        while let Some(event) = reader.next_event()? {
+            let XmlEvent::Start(tag) = event else {
+                continue;
+            };
+            match tag.name() {
+                "row" => {
+                    // Row and cell references are optional, in which case they follow the
+                    // previous one.
+                    row = tag
+                        .attr("r")
+                        .and_then(|r| r.parse::<usize>().ok())
+                        .map_or(next_row, |r| r.saturating_sub(1));
+                    next_row = row + 1;
+                    next_col = 0;
+                    if builder.is_past_end(row) {
+                        break;
+                    }
+                },
+                "c" => {
+                    let col = tag
+                        .attr("r")
+                        .and_then(|r| parse_cell_ref(&r))
+                        .and_then(|(col, _)| col)
+                        .unwrap_or(next_col);
+                    next_col = col + 1;
+
+                    let cell_type = tag.attr("t");
+                    let format = tag
+                        .attr("s")
+                        .and_then(|s| s.parse::<usize>().ok())
+                        .and_then(|s| self.cell_formats.get(s).copied().flatten());
+
+                    let mut value = None;
+                    if !tag.empty {
+                        while let Some(event) = reader.next_event()? {
+                            match event {
+                                XmlEvent::Start(inner) if matches!(inner.name(), "v" | "is") => {
+                                    value = Some(reader.read_text(&inner)?);
+                                },
+                                XmlEvent::Start(inner) => reader.skip_element(&inner)?,
+                                XmlEvent::End(_) => break,
+                                XmlEvent::Text(_) => {},
+                            }
+                        }
+                    }
+                    let Some(value) = value else {
+                        continue;
+                    };
+
+                    let cell = self.decode_cell(cell_type.as_deref(), format, value)?;
+                    builder.push(row, col, cell);
+                },
+                _ => {},
+            }
+        }
+        Ok(())
+    }
+
+    fn decode_cell(
+        &self,
+        cell_type: Option<&str>,
+        format: Option<DateKind>,
+        value: String,
+    ) -> PolarsResult<Cell> {
+        let cell = match cell_type {
+            Some("s") => {
+                let string = value
+                    .trim()
+                    .parse::<usize>()
+                    .ok()
+                    .and_then(|i| self.shared_strings.get(i));
+                match string {
+                    Some(s) => Cell::String(s.clone()),
+                    None => {
+                        polars_bail!(ComputeError: "invalid xlsx workbook: unknown shared string '{}'", value)
+                    },
+                }
+            },
+            Some("str" | "inlineStr") => Cell::String(decode_char_notation(&value).into_owned()),
+            Some("b") => Cell::Bool(matches!(value.trim(), "1" | "true")),
+            // Error values such as `#DIV/0!` are read as missing.
+            Some("e") => Cell::Empty,
+            Some("d") => parse_iso_datetime(&value).unwrap_or(Cell::String(value)),
+            _ => {
+                if value.trim().is_empty() {
+                    return Ok(Cell::Empty);
+                }
+                let number = value.trim().parse::<f64>().map_err(
+                    |_| polars_err!(ComputeError: "invalid xlsx workbook: invalid number '{}'", value),
+                )?;
+                let days = if self.date1904 {
+                    number + DATE_1904_OFFSET
+                } else {
+                    serial_1900_to_days(number)
+                };
+                match format {
+                    Some(DateKind::Date) => Cell::Date(days),
+                    Some(DateKind::DateTime) => Cell::DateTime(days),
+                    None => Cell::Number(number),
+                }
+            },
+        };
+        Ok(cell)
+    }
+}
+
+fn read_shared_strings(xml: &str) -> PolarsResult<Vec<String>> {
+    let mut strings = vec![];
+    let mut current = None;
+
+    let mut reader = XmlReader::new(xml);
+    while let Some(event) = reader.next_event()? {
+        match event {
+            XmlEvent::Start(tag) => match tag.name() {
+                "si" if tag.empty => strings.push(String::new()),
+                "si" => current = Some(String::new()),
+                "t" => {
+                    let text = reader.read_text(&tag)?;
+                    if let Some(current) = current.as_mut() {
+                        current.push_str(&text);
+                    }
+                },
+                // Phonetic hints are not part of the value.
+                "rPh" => reader.skip_element(&tag)?,
+                _ => {},
+            },
+            XmlEvent::End(end) if end.name() == "si" => strings.extend(
+                current
+                    .take()
+                    .map(|s| decode_char_notation(&s).into_owned()),
+            ),
+            _ => {},
+        }
+    }
+    Ok(strings)
+}
+
+fn read_cell_formats(xml: &str) -> PolarsResult<Vec<Option<DateKind>>> {
+    let mut custom_formats = PlHashMap::new();
+    let mut format_ids = vec![];
+    let mut in_cell_formats = false;
+
+    let mut reader = XmlReader::new(xml);
+    while let Some(event) = reader.next_event()? {
+        match event {
+            XmlEvent::Start(tag) => match tag.name() {
+                "numFmt" => {
+                    if let (Some(id), Some(code)) = (
+                        tag.attr("numFmtId").and_then(|id| id.parse::<u32>().ok()),
+                        tag.attr("formatCode"),
+                    ) {
+                        custom_formats.insert(id, format_code_kind(&code));
+                    }
+                },
+                "cellXfs" => in_cell_formats = !tag.empty,
+                "xf" if in_cell_formats => format_ids.push(
+                    tag.attr("numFmtId")
+                        .and_then(|id| id.parse::<u32>().ok())
+                        .unwrap_or(0),
+                ),
+                _ => {},
+            },
+            XmlEvent::End(end) if end.name() == "cellXfs" => in_cell_formats = false,
+            _ => {},
+        }
+    }
+
+    Ok(format_ids
+        .into_iter()
+        .map(|id| match custom_formats.get(&id) {
+            Some(kind) => *kind,
+            None => builtin_format_kind(id),
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_code_kind() {
+        assert!(format_code_kind("yyyy-mm-dd") == Some(DateKind::Date));
+        assert!(format_code_kind("[$-409]d/m/yy\\ h:mm;@") == Some(DateKind::DateTime));
+        assert!(format_code_kind("0.00\"days\"").is_none());
+        assert!(format_code_kind("[Red]#,##0").is_none());
+        assert!(format_code_kind("hh:mm:ss").is_none());
+    }
+}
@@ -0,0 +1,221 @@
+//! Pull parser for the machine-written XML parts of a workbook, and escaping for writing them.
+//!
+//! Element and attribute names are reported without their namespace prefix. Processing
+//! instructions, comments and doctype declarations are skipped.
+use std::borrow::Cow;
+
+use polars_core::prelude::*;
+use quick_xml::Reader;
+use quick_xml::escape::resolve_predefined_entity;
+use quick_xml::events::{BytesEnd, BytesRef, BytesStart, Event};
+
+pub(super) enum XmlEvent<'a> {
+    Start(XmlTag<'a>),
+    /// Not emitted for self-closing elements.
+    End(XmlEnd<'a>),
+    Text(Cow<'a, str>),
+}
+
+pub(super) struct XmlTag<'a> {
+    start: BytesStart<'a>,
+    /// Whether this is a self-closing element.
+    pub empty: bool,
+}
+
+pub(super) struct XmlEnd<'a>(BytesEnd<'a>);
+
+fn xml_err(err: impl std::fmt::Display) -> PolarsError {
+    polars_err!(ComputeError: "invalid workbook: {}", err)
+}
+
+fn local_name(name: &[u8]) -> &str {
+    std::str::from_utf8(name).unwrap_or_default()
+}
+
+impl XmlTag<'_> {
+    pub fn name(&self) -> &str {
+        local_name(self.start.local_name().into_inner())
+    }
+
+    /// Get the unescaped value of the attribute with local name `name`.
+    pub fn attr(&self, name: &str) -> Option<Cow<'_, str>> {
+        self.start
+            .attributes()
+            .with_checks(false)
+            .flatten()
+            .find(|attr| attr.key.local_name().as_ref() == name.as_bytes())
+            .and_then(|attr| attr.unescape_value().ok())
+    }
+}
+
+impl XmlEnd<'_> {
+    pub fn name(&self) -> &str {
+        local_name(self.0.local_name().into_inner())
+    }
+}
+
+/// Resolve a character or predefined entity reference.
+fn resolve_reference(reference: &BytesRef<'_>) -> PolarsResult<Cow<'static, str>> {
+    if let Some(c) = reference.resolve_char_ref().map_err(xml_err)? {
+        return Ok(Cow::Owned(c.to_string()));
+    }
+    let name = reference.decode().map_err(xml_err)?;
+    let resolved = match resolve_predefined_entity(&name) {
+        Some(s) => Cow::Borrowed(s),
+        // Not a reference we know, keep it as is.
+        None => Cow::Owned(format!("&{name};")),
+    };
+    Ok(resolved)
+}
+
+pub(super) struct XmlReader<'a> {
+    reader: Reader<&'a [u8]>,
+}
+
+impl<'a> XmlReader<'a> {
+    pub fn new(src: &'a str) -> Self {
+        Self {
+            reader: Reader::from_str(src),
+        }
+    }
+
+    pub fn next_event(&mut self) -> PolarsResult<Option<XmlEvent<'a>>> {
+        loop {
+            let event = match self.reader.read_event().map_err(xml_err)? {
+                Event::Start(start) => XmlEvent::Start(XmlTag {
+                    start,
+                    empty: false,
+                }),
+                Event::Empty(start) => XmlEvent::Start(XmlTag { start, empty: true }),
+                Event::End(end) => XmlEvent::End(XmlEnd(end)),
+                Event::Text(text) => XmlEvent::Text(text.xml_content().map_err(xml_err)?),
+                Event::CData(data) => XmlEvent::Text(data.xml_content().map_err(xml_err)?),
+                Event::GeneralRef(reference) => XmlEvent::Text(resolve_reference(&reference)?),
+                Event::Decl(_) | Event::PI(_) | Event::Comment(_) | Event::DocType(_) => continue,
+                Event::Eof => return Ok(None),
+            };
+            return Ok(Some(event));
+        }
+    }
+
+    /// Collect the text content of the element whose start tag was just read, up to and
+    /// including its end tag.
+    pub fn read_text(&mut self, tag: &XmlTag) -> PolarsResult<String> {
+        let mut out = String::new();
+        if tag.empty {
+            return Ok(out);
+        }
+        let mut depth = 0usize;
+        while let Some(event) = self.next_event()? {
+            match event {
+                XmlEvent::Start(inner) if !inner.empty => depth += 1,
+                XmlEvent::Start(_) => {},
+                XmlEvent::End(_) if depth == 0 => return Ok(out),
+                XmlEvent::End(_) => depth -= 1,
+                XmlEvent::Text(text) => out.push_str(&text),
+            }
+        }
+        polars_bail!(ComputeError: "invalid workbook: unexpected end of XML")
+    }
+
+    /// Skip the rest of the element whose start tag was just read.
+    pub fn skip_element(&mut self, tag: &XmlTag) -> PolarsResult<()> {
+        self.read_text(tag).map(|_| ())
+    }
+}
+
+/// Parse the `_xHHHH_` notation for a character at the start of `s`.
+fn parse_char_notation(s: &str) -> Option<char> {
+    let hex = s.strip_prefix("_x")?.get(..5)?.strip_suffix('_')?;
+    if !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    char::from_u32(u32::from_str_radix(hex, 16).ok()?)
+}
+
+/// Escape `s` for use in text content and attribute values.
+///
+/// Control characters that XML 1.0 cannot represent are written in the `_xHHHH_` notation that
+/// spreadsheet applications decode, and text that looks like this notation has its underscore
+/// written as `_x005F_`.
+pub(super) fn escape(s: &str) -> Cow<'_, str> {
+    let is_invalid = |c: char| c.is_control() && !matches!(c, '\t' | '\n' | '\r');
+    if !s.contains(is_invalid) && !s.contains("_x") {
+        return quick_xml::escape::escape(s);
+    }
+
+    let mut out = String::with_capacity(s.len() + 16);
+    for (i, c) in s.char_indices() {
+        if is_invalid(c) {
+            use std::fmt::Write;
+            write!(out, "_x{:04X}_", c as u32).unwrap();
+        } else if c == '_' && parse_char_notation(&s[i..]).is_some() {
+            out.push_str("_x005F_");
+        } else {
+            out.push(c);
+        }
+    }
+    Cow::Owned(quick_xml::escape::escape(out).into_owned())
+}
+
+/// Decode the `_xHHHH_` notation of characters in shared and inline strings.
+pub(super) fn decode_char_notation(s: &str) -> Cow<'_, str> {
+    if !s.contains("_x") {
+        return Cow::Borrowed(s);
+    }
+
+    let mut out = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some(start) = rest.find("_x") {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+        match parse_char_notation(rest) {
+            Some(c) => {
+                out.push(c);
+                rest = &rest["_xHHHH_".len()..];
+            },
+            None => {
+                out.push('_');
+                rest = &rest[1..];
+            },
+        }
+    }
+    out.push_str(rest);
+    Cow::Owned(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_xml_reader() -> PolarsResult<()> {
+        let src = r#"<?xml version="1.0"?><!-- c --><x:a k="1 &amp; 2" x:j='>'><b/>t&lt;&#65;&#x42;<![CDATA[<c>]]></x:a>"#;
+        let mut reader = XmlReader::new(src);
+
+        let Some(XmlEvent::Start(a)) = reader.next_event()? else {
+            panic!()
+        };
+        assert_eq!(a.name(), "a");
+        assert!(!a.empty);
+        assert_eq!(a.attr("k").as_deref(), Some("1 & 2"));
+        assert_eq!(a.attr("j").as_deref(), Some(">"));
+        assert_eq!(a.attr("missing"), None);
+
+        assert_eq!(reader.read_text(&a)?, "t<AB<c>");
+        assert!(reader.next_event()?.is_none());
+
+        assert_eq!(escape("plain"), "plain");
+        assert_eq!(
+            escape("a<b & \"c\"\u{1}"),
+            "a&lt;b &amp; &quot;c&quot;_x0001_"
+        );
+        assert_eq!(escape("_x0041_ _x"), "_x005F_x0041_ _x");
+        assert_eq!(
+            decode_char_notation("_x005F_x0041_ _x0001_ _xZZ"),
+            "_x0041_ \u{1} _xZZ"
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,164 @@
+//! Reader and writer for the zip container of xlsx and ods workbooks.
+use std::io::{Cursor, Read, Write};
+
+use ::zip::CompressionMethod;
+use ::zip::result::ZipError;
+use ::zip::write::{SimpleFileOptions, StreamWriter};
+use polars_core::prelude::*;
+use polars_error::to_compute_err;
+
+/// Upper bound of the ratio between the inflated and deflated size of an entry.
+const MAX_DEFLATE_RATIO: u64 = 1032;
+/// Upper bound of the buffer reserved up front for an entry. Larger entries grow it as they are
+/// inflated.
+const MAX_PREALLOCATION: u64 = 1 << 26;
+
+pub(super) struct ZipArchive {
+    archive: ::zip::ZipArchive<Cursor<Vec<u8>>>,
+}
+
+fn zip_err(err: ZipError) -> PolarsError {
+    polars_err!(ComputeError: "invalid workbook: {}", err)
+}
+
+impl ZipArchive {
+    pub fn try_new(data: Vec<u8>) -> PolarsResult<Self> {
+        let archive = ::zip::ZipArchive::new(Cursor::new(data)).map_err(zip_err)?;
+        Ok(Self { archive })
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.archive.index_for_name(name).is_some()
+    }
+
+    /// Decompress the entry `name`, or return `None` if the archive has no such entry.
+    pub fn read(&mut self, name: &str) -> PolarsResult<Option<Vec<u8>>> {
+        let entry = match self.archive.by_name(name) {
+            Ok(entry) => entry,
+            Err(ZipError::FileNotFound) => return Ok(None),
+            Err(err) => return Err(zip_err(err)),
+        };
+
+        // The sizes in the central directory are not trusted for the allocation, the reserved
+        // buffer is bounded by what the compressed data can inflate to.
+        let size = entry.size();
+        let capacity = size
+            .min(entry.compressed_size().saturating_mul(MAX_DEFLATE_RATIO))
+            .min(MAX_PREALLOCATION);
+        let mut out = Vec::with_capacity(capacity as usize);
+        entry.take(size.saturating_add(1)).read_to_end(&mut out)?;
+        polars_ensure!(
+            out.len() as u64 == size,
+            ComputeError: "invalid workbook: zip entry '{}' inflates to a different size than the {} bytes it declares", name, size
+        );
+
+        Ok(Some(out))
+    }
+
+    /// Decompress the UTF-8 entry `name`, or return `None` if the archive has no such entry.
+    pub fn read_string(&mut self, name: &str) -> PolarsResult<Option<String>> {
+        let Some(bytes) = self.read(name)? else {
+            return Ok(None);
+        };
+        let mut s = String::from_utf8(bytes).map_err(
+            |_| polars_err!(ComputeError: "workbook entry '{}' is not valid UTF-8", name),
+        )?;
+        if s.starts_with('\u{feff}') {
+            s.remove(0);
+        }
+        Ok(Some(s))
+    }
+}
+
+/// Writes a zip archive of deflated entries to a non-seekable writer.
+///
+/// Entries are compressed as they are written, and their sizes and checksums are recorded in
+/// data descriptors after the entry data.
+pub(super) struct ZipWriter<W: Write> {
+    zip: ::zip::ZipWriter<StreamWriter<W>>,
+}
+
+impl<W: Write> ZipWriter<W> {
+    pub fn new(writer: W) -> Self {
+        Self {
+            zip: ::zip::ZipWriter::new_stream(writer),
+        }
+    }
+
+    /// Start a new entry. It is finished when the next entry is started or the archive is
+    /// finished.
+    pub fn start_entry(&mut self, name: &str) -> PolarsResult<ZipEntryWriter<'_, W>> {
+        let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+        self.zip.start_file(name, options).map_err(to_compute_err)?;
+        Ok(ZipEntryWriter { zip: &mut self.zip })
+    }
+
+    /// Write an entry with the given content.
+    pub fn write_entry(&mut self, name: &str, data: &[u8]) -> PolarsResult<()> {
+        self.start_entry(name)?.write_all(data)?;
+        Ok(())
+    }
+
+    /// Write the central directory and return the underlying writer.
+    pub fn finish(self) -> PolarsResult<W> {
+        let mut writer = self.zip.finish().map_err(to_compute_err)?.into_inner();
+        writer.flush()?;
+        Ok(writer)
+    }
+}
+
+/// Compresses the data of a single entry into the archive.
+pub(super) struct ZipEntryWriter<'a, W: Write> {
+    zip: &'a mut ::zip::ZipWriter<StreamWriter<W>>,
+}
+
+impl<W: Write> Write for ZipEntryWriter<'_, W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.zip.write(buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.zip.flush()
+    }
+}
+
+#[cfg(test)]
+pub(super) mod tests {
+    use super::*;
+
+    /// Build a zip archive of deflated entries.
+    pub(in crate::excel) fn build_zip(files: &[(&str, &str)]) -> Vec<u8> {
+        let mut zip = ZipWriter::new(vec![]);
+        for (name, content) in files {
+            zip.write_entry(name, content.as_bytes()).unwrap();
+        }
+        zip.finish().unwrap()
+    }
+
+    #[test]
+    fn test_zip_roundtrip() -> PolarsResult<()> {
+        let mut archive = ZipArchive::try_new(build_zip(&[("a.xml", "<a/>"), ("dir/b.txt", "b")]))?;
+
+        assert!(archive.contains("dir/b.txt"));
+        assert_eq!(archive.read_string("a.xml")?.as_deref(), Some("<a/>"));
+        assert_eq!(archive.read_string("dir/b.txt")?.as_deref(), Some("b"));
+        assert!(archive.read("missing")?.is_none());
+        assert!(ZipArchive::try_new(b"not a zip".to_vec()).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_zip_declared_size_mismatch() -> PolarsResult<()> {
+        let mut data = build_zip(&[("a.xml", "<a/>")]);
+
+        // Claim an uncompressed size of almost 4 GiB in the central directory.
+        let central = data.windows(4).position(|w| w == b"PK\x01\x02").unwrap();
+        data[central + 24..central + 28].copy_from_slice(&0xFFFF_FFF0u32.to_le_bytes());
+
+        let mut archive = ZipArchive::try_new(data)?;
+        assert!(archive.read("a.xml").is_err());
+
+        Ok(())
+    }
+}
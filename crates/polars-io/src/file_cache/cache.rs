@@ -1,14 +1,19 @@
-use std::sync::atomic::AtomicU64;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, LazyLock, RwLock};
+use std::time::{Duration, SystemTime};
 
+use fs4::fs_std::FileExt;
 use polars_core::config;
-use polars_error::PolarsResult;
+use polars_error::{PolarsError, PolarsResult, polars_bail};
 use polars_utils::aliases::PlHashMap;
 use polars_utils::pl_path::PlRefPath;
 
-use super::entry::{DATA_PREFIX, FileCacheEntry, METADATA_PREFIX};
+use super::cache_lock::{GLOBAL_FILE_CACHE_LOCK, GlobalFileCacheGuardExclusive};
+use super::entry::{DATA_PREFIX, FileCacheEntry, METADATA_PREFIX, get_data_file_path};
 use super::eviction::EvictionManager;
 use super::file_fetcher::FileFetcher;
+use super::file_lock::FileLock;
+use super::metadata::{EntryMetadata, FileVersion};
 use super::utils::FILE_CACHE_PREFIX;
 use crate::path_utils::ensure_directory_init;
 
@@ -20,6 +25,8 @@ pub static FILE_CACHE: LazyLock<FileCache> = LazyLock::new(|| {
     }
 
     let min_ttl = Arc::new(AtomicU64::from(get_env_file_cache_ttl()));
+    let max_size = Arc::new(AtomicU64::from(get_env_file_cache_max_size()));
+    let counters = Arc::new(FileCacheCounters::default());
     let notify_ttl_updated = Arc::new(tokio::sync::Notify::new());
 
     let metadata_dir = prefix.join(std::str::from_utf8(&[METADATA_PREFIX]).unwrap());
@@ -44,21 +51,55 @@ pub static FILE_CACHE: LazyLock<FileCache> = LazyLock::new(|| {
         metadata_dir,
         files_to_remove: None,
         min_ttl: min_ttl.clone(),
+        max_size: max_size.clone(),
+        counters: counters.clone(),
         notify_ttl_updated: notify_ttl_updated.clone(),
     }
     .run_in_background();
 
     // Safety: We have created the data and metadata directories.
-    unsafe { FileCache::new_unchecked(prefix, min_ttl, notify_ttl_updated) }
+    unsafe { FileCache::new_unchecked(prefix, min_ttl, max_size, counters, notify_ttl_updated) }
 });
 
 pub struct FileCache {
     prefix: PlRefPath,
     entries: Arc<RwLock<PlHashMap<PlRefPath, Arc<FileCacheEntry>>>>,
     min_ttl: Arc<AtomicU64>,
+    /// Maximum total size of the data files in bytes, 0 if unbounded.
+    max_size: Arc<AtomicU64>,
+    counters: Arc<FileCacheCounters>,
     notify_ttl_updated: Arc<tokio::sync::Notify>,
 }
 
+#[derive(Debug, Default)]
+pub(crate) struct FileCacheCounters {
+    pub(super) hits: AtomicU64,
+    pub(super) misses: AtomicU64,
+    pub(super) evictions: AtomicU64,
+}
+
+/// Statistics of the file cache, counted since the start of this process.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FileCacheStats {
+    /// Number of files opened from the cache.
+    pub hits: u64,
+    /// Number of files downloaded into the cache.
+    pub misses: u64,
+    /// Number of data files removed by the eviction task.
+    pub evictions: u64,
+}
+
+/// A file stored in the cache.
+#[derive(Debug, Clone)]
+pub struct FileCacheEntryInfo {
+    pub uri: PlRefPath,
+    /// Size of the data file in bytes.
+    pub size: u64,
+    pub last_accessed: SystemTime,
+    /// TTL since last access, in seconds.
+    pub ttl: u64,
+}
+
 impl FileCache {
     /// # Safety
     /// The following directories exist:
@@ -67,12 +108,16 @@ impl FileCache {
     unsafe fn new_unchecked(
         prefix: PlRefPath,
         min_ttl: Arc<AtomicU64>,
+        max_size: Arc<AtomicU64>,
+        counters: Arc<FileCacheCounters>,
         notify_ttl_updated: Arc<tokio::sync::Notify>,
     ) -> Self {
         Self {
             prefix,
             entries: Default::default(),
             min_ttl,
+            max_size,
+            counters,
             notify_ttl_updated,
         }
     }
@@ -150,6 +195,7 @@ impl FileCache {
                 self.prefix.clone(),
                 get_file_fetcher()?,
                 ttl,
+                self.counters.clone(),
             ));
             entries.insert(uri.clone(), entry.clone());
             Ok(entry)
@@ -166,6 +212,173 @@ impl FileCache {
             self.entries.read().unwrap().get(&p).cloned()
         }
     }
+
+    /// Sets the maximum total size in bytes of the cached files. Once exceeded, the least
+    /// recently used files are evicted, even if their TTL has not expired. `None` removes the
+    /// limit.
+    pub fn set_max_size(&self, max_size: Option<u64>) {
+        self.max_size
+            .store(max_size.unwrap_or(0), Ordering::Relaxed);
+        self.notify_ttl_updated.notify_one();
+    }
+
+    pub fn max_size(&self) -> Option<u64> {
+        Some(self.max_size.load(Ordering::Relaxed)).filter(|&x| x > 0)
+    }
+
+    pub fn stats(&self) -> FileCacheStats {
+        FileCacheStats {
+            hits: self.counters.hits.load(Ordering::Relaxed),
+            misses: self.counters.misses.load(Ordering::Relaxed),
+            evictions: self.counters.evictions.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Lists the files that are currently stored in the cache, including those stored by other
+    /// processes.
+    pub fn list_entries(&self) -> PolarsResult<Vec<FileCacheEntryInfo>> {
+        let _cache_guard = GLOBAL_FILE_CACHE_LOCK.lock_shared();
+        let mut out = vec![];
+
+        for (uri_hash, metadata_path) in self.metadata_files()? {
+            let Ok(metadata_file) = &mut FileLock::from(&metadata_path).acquire_shared() else {
+                continue;
+            };
+            let Ok(metadata) = EntryMetadata::try_from_reader(&mut **metadata_file) else {
+                continue;
+            };
+
+            if metadata.remote_version == FileVersion::Uninitialized {
+                continue;
+            }
+
+            let data_file_path = get_data_file_path(
+                self.prefix.as_bytes(),
+                uri_hash.as_bytes(),
+                &metadata.remote_version,
+            );
+            let Ok(data_file_metadata) = std::fs::metadata(data_file_path) else {
+                continue;
+            };
+
+            out.push(FileCacheEntryInfo {
+                uri: metadata.uri,
+                size: data_file_metadata.len(),
+                last_accessed: data_file_metadata
+                    .accessed()
+                    .or_else(|_| data_file_metadata.modified())?,
+                ttl: metadata.ttl,
+            })
+        }
+
+        Ok(out)
+    }
+
+    /// Removes the cached files of all URIs starting with `prefix`. Files that are currently
+    /// opened are skipped. Returns the number of removed files.
+    pub fn clear(&self, prefix: &str) -> PolarsResult<usize> {
+        let guard = self.lock_eviction()?;
+        let mut data_files = self.data_files()?;
+        let mut n_removed = 0;
+
+        for (uri_hash, metadata_path) in self.metadata_files()? {
+            // Held until the files are removed so that no entry is updated in between.
+            let Ok(metadata_file) = &mut FileLock::from(&metadata_path).acquire_exclusive() else {
+                continue;
+            };
+            let Ok(metadata) = EntryMetadata::try_from_reader(&mut **metadata_file) else {
+                continue;
+            };
+
+            if !metadata.uri.as_str().starts_with(prefix) {
+                continue;
+            }
+
+            let paths = data_files.remove(&uri_hash).unwrap_or_default();
+            let n_paths = paths.len();
+            let n = remove_data_files(paths, &guard)?;
+            n_removed += n;
+
+            // The metadata is kept if a data file could not be removed.
+            if n == n_paths {
+                std::fs::remove_file(&metadata_path)?;
+            }
+        }
+
+        Ok(n_removed)
+    }
+
+    fn lock_eviction(&self) -> PolarsResult<GlobalFileCacheGuardExclusive<'_>> {
+        for _ in 0..100 {
+            if let Some(guard) = GLOBAL_FILE_CACHE_LOCK.try_lock_eviction() {
+                return Ok(guard);
+            }
+            std::thread::sleep(Duration::from_millis(100));
+        }
+
+        polars_bail!(ComputeError: "timed out waiting for exclusive access to the file cache")
+    }
+
+    /// Returns the URI hashes and paths of the metadata files.
+    fn metadata_files(&self) -> PolarsResult<Vec<(String, PlRefPath)>> {
+        let metadata_dir = self
+            .prefix
+            .join(std::str::from_utf8(&[METADATA_PREFIX]).unwrap());
+
+        std::fs::read_dir(metadata_dir.as_std_path())?
+            .map(|file| {
+                let path = PlRefPath::try_from_pathbuf(file?.path())?;
+                let uri_hash = path.file_name().unwrap().to_str().unwrap().to_string();
+                PolarsResult::Ok((uri_hash, path))
+            })
+            .collect()
+    }
+
+    /// Returns the paths of the data files of all versions of a URI, keyed by the URI hash.
+    fn data_files(&self) -> PolarsResult<PlHashMap<String, Vec<PlRefPath>>> {
+        let data_dir = self
+            .prefix
+            .join(std::str::from_utf8(&[DATA_PREFIX]).unwrap());
+        let mut out = PlHashMap::<String, Vec<PlRefPath>>::default();
+
+        for file in std::fs::read_dir(data_dir.as_std_path())? {
+            let path = PlRefPath::try_from_pathbuf(file?.path())?;
+            let Some(uri_hash) = path.file_name().and_then(|x| x.to_str()?.get(..32)) else {
+                continue;
+            };
+
+            out.entry(uri_hash.to_string()).or_default().push(path);
+        }
+
+        Ok(out)
+    }
+}
+
+/// Removes the data files that are not opened. Returns the number of removed files.
+fn remove_data_files(
+    paths: Vec<PlRefPath>,
+    _guard: &GlobalFileCacheGuardExclusive,
+) -> PolarsResult<usize> {
+    let mut n_removed = 0;
+
+    for path in paths {
+        {
+            let file = std::fs::File::open(&path)?;
+
+            // Opened data files hold a shared lock.
+            if !matches!(FileExt::try_lock_exclusive(&file), Ok(true)) {
+                continue;
+            }
+        }
+
+        match std::fs::remove_file(&path) {
+            Ok(()) => n_removed += 1,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => n_removed += 1,
+            Err(e) => return Err(PolarsError::from(e)),
+        }
+    }
+
+    Ok(n_removed)
 }
 
 pub fn get_env_file_cache_ttl() -> u64 {
@@ -173,3 +386,143 @@ pub fn get_env_file_cache_ttl() -> u64 {
         .map(|x| x.parse::<u64>().expect("integer"))
         .unwrap_or(60 * 60)
 }
+
+/// Maximum total size of the file cache in bytes, 0 if unbounded.
+pub fn get_env_file_cache_max_size() -> u64 {
+    std::env::var("POLARS_FILE_CACHE_MAX_SIZE")
+        .map(|x| x.parse::<u64>().expect("integer"))
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+pub(super) mod tests {
+    use std::fs::FileTimes;
+    use std::path::Path;
+
+    use super::*;
+
+    pub(in crate::file_cache) fn uri_hash(idx: u64) -> String {
+        format!("{idx:032x}")
+    }
+
+    /// Writes the metadata and data file of a cached `uri` into the cache at `prefix`.
+    pub(in crate::file_cache) fn write_entry(
+        prefix: &Path,
+        idx: u64,
+        uri: &str,
+        data: &[u8],
+        ttl: u64,
+        last_accessed: SystemTime,
+    ) {
+        let prefix = prefix.to_str().unwrap();
+        let uri_hash = uri_hash(idx);
+        let metadata = EntryMetadata {
+            uri: PlRefPath::new(uri),
+            local_last_modified: 0,
+            local_size: data.len() as u64,
+            remote_version: FileVersion::Timestamp(1),
+            ttl,
+        };
+
+        let metadata_path = Path::new(prefix).join("m").join(&uri_hash);
+        metadata
+            .try_write(&mut std::fs::File::create(metadata_path).unwrap())
+            .unwrap();
+
+        let data_file_path = get_data_file_path(
+            prefix.as_bytes(),
+            uri_hash.as_bytes(),
+            &metadata.remote_version,
+        );
+        std::fs::write(&data_file_path, data).unwrap();
+        std::fs::OpenOptions::new()
+            .write(true)
+            .open(&data_file_path)
+            .unwrap()
+            .set_times(
+                FileTimes::new()
+                    .set_accessed(last_accessed)
+                    .set_modified(last_accessed),
+            )
+            .unwrap();
+    }
+
+    fn new_test_cache(prefix: &Path) -> FileCache {
+        std::fs::create_dir_all(prefix.join("m")).unwrap();
+        std::fs::create_dir_all(prefix.join("d")).unwrap();
+
+        // Safety: We have created the data and metadata directories.
+        unsafe {
+            FileCache::new_unchecked(
+                PlRefPath::try_from_path(prefix).unwrap(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+            )
+        }
+    }
+
+    fn listed_uris(file_cache: &FileCache) -> Vec<String> {
+        let mut uris = file_cache
+            .list_entries()
+            .unwrap()
+            .into_iter()
+            .map(|x| x.uri.as_str().to_string())
+            .collect::<Vec<_>>();
+        uris.sort();
+        uris
+    }
+
+    #[test]
+    fn test_file_cache_list_and_clear() {
+        let dir = tempfile::tempdir().unwrap();
+        let file_cache = new_test_cache(dir.path());
+        let now = SystemTime::now();
+
+        write_entry(dir.path(), 1, "s3://bucket/a.parquet", b"a", 60, now);
+        write_entry(dir.path(), 2, "s3://bucket/b.parquet", b"bb", 60, now);
+        write_entry(dir.path(), 3, "gs://other/c.parquet", b"ccc", 120, now);
+
+        let mut entries = file_cache.list_entries().unwrap();
+        entries.sort_by(|a, b| a.uri.as_str().cmp(b.uri.as_str()));
+        assert_eq!(
+            entries
+                .iter()
+                .map(|x| (x.uri.as_str(), x.size, x.ttl))
+                .collect::<Vec<_>>(),
+            [
+                ("gs://other/c.parquet", 3, 120),
+                ("s3://bucket/a.parquet", 1, 60),
+                ("s3://bucket/b.parquet", 2, 60),
+            ]
+        );
+
+        // Opened data files hold a shared lock and are not removed.
+        let opened = std::fs::File::open(get_data_file_path(
+            dir.path().to_str().unwrap().as_bytes(),
+            uri_hash(1).as_bytes(),
+            &FileVersion::Timestamp(1),
+        ))
+        .unwrap();
+        FileExt::lock_shared(&opened).unwrap();
+
+        assert_eq!(file_cache.clear("s3://bucket/").unwrap(), 1);
+        assert_eq!(
+            listed_uris(&file_cache),
+            ["gs://other/c.parquet", "s3://bucket/a.parquet"]
+        );
+        assert!(dir.path().join("m").join(uri_hash(1)).exists());
+        assert!(!dir.path().join("m").join(uri_hash(2)).exists());
+
+        drop(opened);
+
+        assert_eq!(file_cache.clear("s3://").unwrap(), 1);
+        assert_eq!(listed_uris(&file_cache), ["gs://other/c.parquet"]);
+
+        assert_eq!(file_cache.clear("").unwrap(), 1);
+        assert!(listed_uris(&file_cache).is_empty());
+        assert_eq!(std::fs::read_dir(dir.path().join("m")).unwrap().count(), 0);
+        assert_eq!(std::fs::read_dir(dir.path().join("d")).unwrap().count(), 0);
+    }
+}
@@ -1,6 +1,6 @@
 use std::io::{Seek, SeekFrom};
 use std::path::{Path, PathBuf};
-use std::sync::atomic::AtomicU64;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, LazyLock, Mutex};
 
 use fs4::fs_std::FileExt;
@@ -8,6 +8,7 @@ use polars_core::config;
 use polars_error::{PolarsError, PolarsResult, polars_bail, to_compute_err};
 use polars_utils::pl_path::PlRefPath;
 
+use super::cache::FileCacheCounters;
 use super::cache_lock::{self, GLOBAL_FILE_CACHE_LOCK};
 use super::file_fetcher::{FileFetcher, RemoteMetadata};
 use super::file_lock::{FileLock, FileLockAnyGuard};
@@ -31,6 +32,7 @@ struct Inner {
     cached_data: Option<CachedData>,
     ttl: Arc<AtomicU64>,
     file_fetcher: Arc<dyn FileFetcher>,
+    counters: Arc<FileCacheCounters>,
 }
 
 struct EntryData {
@@ -68,6 +70,7 @@ impl Inner {
                             self.uri.clone()
                         );
                     }
+                    self.counters.hits.fetch_add(1, Ordering::Relaxed);
                     return Ok(finish_open(data_file_path, metadata_file));
                 }
             }
@@ -103,6 +106,7 @@ impl Inner {
                                 self.uri.clone()
                             );
                         }
+                        self.counters.hits.fetch_add(1, Ordering::Relaxed);
                         return Ok(finish_open(data_file_path, metadata_file));
                     }
                 }
@@ -130,6 +134,7 @@ impl Inner {
                         self.uri.clone()
                     );
                 }
+                self.counters.hits.fetch_add(1, Ordering::Relaxed);
                 return Ok(finish_open(data_file_path, metadata_file));
             }
         }
@@ -197,6 +202,7 @@ impl Inner {
             }
         }
         self.file_fetcher.fetch(data_file_path)?;
+        self.counters.misses.fetch_add(1, Ordering::Relaxed);
 
         // Don't do this on windows as it will break setting last accessed times.
         #[cfg(target_family = "unix")]
@@ -319,6 +325,7 @@ impl FileCacheEntry {
         path_prefix: PlRefPath,
         file_fetcher: Arc<dyn FileFetcher>,
         file_cache_ttl: u64,
+        counters: Arc<FileCacheCounters>,
     ) -> Self {
         let metadata = FileLock::from(get_metadata_file_path(
             path_prefix.as_bytes(),
@@ -342,6 +349,7 @@ impl FileCacheEntry {
                 cached_data: None,
                 ttl: ttl.clone(),
                 file_fetcher,
+                counters,
             }),
             ttl,
         })
@@ -399,7 +407,7 @@ fn finish_open<F: FileLockAnyGuard>(data_file_path: &Path, _metadata_guard: &F)
 }
 
 /// `[prefix]/d/[uri hash][last modified]`
-fn get_data_file_path(
+pub(super) fn get_data_file_path(
     path_prefix: &[u8],
     uri_hash: &[u8],
     remote_version: &FileVersion,
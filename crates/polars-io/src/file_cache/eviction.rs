@@ -6,6 +6,7 @@ use fs4::fs_std::FileExt;
 use polars_error::{PolarsError, PolarsResult};
 use polars_utils::pl_path::PlRefPath;
 
+use super::cache::FileCacheCounters;
 use super::cache_lock::{GLOBAL_FILE_CACHE_LOCK, GlobalFileCacheGuardExclusive};
 use super::metadata::EntryMetadata;
 use crate::pl_async;
@@ -16,6 +17,9 @@ pub(super) struct EvictionCandidate {
     metadata_path: PlRefPath,
     metadata_last_modified: SystemTime,
     ttl: u64,
+    /// Set if the file is evicted to bound the cache size rather than because its TTL expired.
+    /// The file is kept if it was accessed after this time.
+    lru_last_accessed: Option<SystemTime>,
 }
 
 pub(super) struct EvictionManager {
@@ -23,6 +27,8 @@ pub(super) struct EvictionManager {
     pub(super) metadata_dir: PlRefPath,
     pub(super) files_to_remove: Option<Vec<EvictionCandidate>>,
     pub(super) min_ttl: Arc<AtomicU64>,
+    pub(super) max_size: Arc<AtomicU64>,
+    pub(super) counters: Arc<FileCacheCounters>,
     pub(super) notify_ttl_updated: Arc<tokio::sync::Notify>,
 }
 
@@ -71,12 +77,13 @@ impl EvictionCandidate {
         }
     }
 
+    /// Returns whether the file was removed.
     fn try_evict(
         &mut self,
         now: &SystemTime,
         verbose: bool,
         _guard: &GlobalFileCacheGuardExclusive,
-    ) {
+    ) -> bool {
         self.update_ttl();
         let path = &self.path;
 
@@ -87,36 +94,29 @@ impl EvictionCandidate {
                     path
                 );
             }
-            return;
+            return false;
         }
 
         let metadata = std::fs::metadata(path).unwrap();
-
-        let since_last_accessed = match now.duration_since(
-            metadata
-                .accessed()
-                .unwrap_or_else(|_| metadata.modified().unwrap()),
-        ) {
-            Ok(v) => v.as_secs(),
-            Err(_) => {
-                if verbose {
-                    eprintln!(
-                        "[EvictionManager] evict_files: skipping {} (last accessed time was updated)",
-                        path
-                    );
-                }
-                return;
-            },
+        let last_accessed = metadata
+            .accessed()
+            .unwrap_or_else(|_| metadata.modified().unwrap());
+
+        let was_accessed = match self.lru_last_accessed {
+            Some(lru_last_accessed) => last_accessed > lru_last_accessed,
+            None => !now
+                .duration_since(last_accessed)
+                .is_ok_and(|v| v.as_secs() >= self.ttl),
         };
 
-        if since_last_accessed < self.ttl {
+        if was_accessed {
             if verbose {
                 eprintln!(
                     "[EvictionManager] evict_files: skipping {} (last accessed time was updated)",
                     path
                 );
             }
-            return;
+            return false;
         }
 
         {
@@ -129,7 +129,7 @@ impl EvictionCandidate {
                         self.path
                     );
                 }
-                return;
+                return false;
             }
         }
 
@@ -140,8 +140,12 @@ impl EvictionCandidate {
                     path, err
                 );
             }
-        } else if verbose {
-            eprintln!("[EvictionManager] evict_files: removed file at {}", path);
+            false
+        } else {
+            if verbose {
+                eprintln!("[EvictionManager] evict_files: removed file at {}", path);
+            }
+            true
         }
     }
 }
@@ -200,7 +204,7 @@ impl EvictionManager {
 
                 loop {
                     let min_ttl = self.min_ttl.load(std::sync::atomic::Ordering::Relaxed);
-                    let sleep_interval = std::cmp::max(min_ttl / 4, {
+                    let min_sleep_interval = {
                         #[cfg(debug_assertions)]
                         {
                             3
@@ -209,7 +213,13 @@ impl EvictionManager {
                         {
                             60
                         }
-                    });
+                    };
+                    let mut sleep_interval = std::cmp::max(min_ttl / 4, min_sleep_interval);
+
+                    // The size limit can be exceeded at any time by new downloads.
+                    if self.max_size.load(std::sync::atomic::Ordering::Relaxed) > 0 {
+                        sleep_interval = min_sleep_interval;
+                    }
 
                     let since_last_eviction =
                         Instant::now().duration_since(last_eviction_time).as_secs();
@@ -266,6 +276,8 @@ impl EvictionManager {
         );
 
         let now = SystemTime::now();
+        // Data files that are kept by the TTL, with their size and last access time.
+        let mut data_files = vec![];
 
         for file in data_files_iter {
             let file = file?;
@@ -285,11 +297,35 @@ impl EvictionManager {
                 metadata_path,
                 metadata_last_modified: UNIX_EPOCH,
                 ttl: 0,
+                lru_last_accessed: None,
             };
             eviction_candidate.update_ttl();
 
             if eviction_candidate.should_remove(&now) {
                 files_to_remove.push(eviction_candidate);
+            } else if let Ok(metadata) = std::fs::metadata(&eviction_candidate.path) {
+                let last_accessed = metadata
+                    .accessed()
+                    .unwrap_or_else(|_| metadata.modified().unwrap());
+                data_files.push((eviction_candidate, metadata.len(), last_accessed));
+            }
+        }
+
+        let max_size = self.max_size.load(std::sync::atomic::Ordering::Relaxed);
+        let mut total_size: u64 = data_files.iter().map(|(_, size, _)| size).sum();
+
+        if max_size > 0 && total_size > max_size {
+            // Evict the least recently used files until the cache fits.
+            data_files.sort_unstable_by_key(|(_, _, last_accessed)| *last_accessed);
+
+            for (mut eviction_candidate, size, last_accessed) in data_files {
+                if total_size <= max_size {
+                    break;
+                }
+
+                eviction_candidate.lru_last_accessed = Some(last_accessed);
+                files_to_remove.push(eviction_candidate);
+                total_size -= size;
             }
         }
 
@@ -303,6 +339,7 @@ impl EvictionManager {
                 metadata_path,
                 metadata_last_modified: UNIX_EPOCH,
                 ttl: 0,
+                lru_last_accessed: None,
             };
 
             eviction_candidate.update_ttl();
@@ -325,7 +362,98 @@ impl EvictionManager {
         let now = &SystemTime::now();
 
         for eviction_candidate in files_to_remove.iter_mut() {
-            eviction_candidate.try_evict(now, verbose, _guard);
+            let is_data_file = eviction_candidate.path != eviction_candidate.metadata_path;
+
+            if eviction_candidate.try_evict(now, verbose, _guard) && is_data_file {
+                self.counters
+                    .evictions
+                    .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+            }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::Ordering;
+
+    use super::super::cache::tests::{uri_hash, write_entry};
+    use super::*;
+
+    #[test]
+    fn test_eviction_max_size_lru() {
+        let dir = tempfile::tempdir().unwrap();
+        let data_dir = dir.path().join("d");
+        let metadata_dir = dir.path().join("m");
+        std::fs::create_dir_all(&data_dir).unwrap();
+        std::fs::create_dir_all(&metadata_dir).unwrap();
+
+        let now = SystemTime::now();
+        let data = [0u8; 100];
+
+        // The TTL of all files has not expired, only the size limit applies.
+        write_entry(
+            dir.path(),
+            1,
+            "s3://bucket/1",
+            &data,
+            3600,
+            now - Duration::from_secs(100),
+        );
+        write_entry(
+            dir.path(),
+            2,
+            "s3://bucket/2",
+            &data,
+            3600,
+            now - Duration::from_secs(300),
+        );
+        write_entry(
+            dir.path(),
+            3,
+            "s3://bucket/3",
+            &data,
+            3600,
+            now - Duration::from_secs(200),
+        );
+
+        let mut eviction_manager = EvictionManager {
+            data_dir: PlRefPath::try_from_pathbuf(data_dir.clone()).unwrap(),
+            metadata_dir: PlRefPath::try_from_pathbuf(metadata_dir).unwrap(),
+            files_to_remove: None,
+            min_ttl: Default::default(),
+            max_size: Arc::new(AtomicU64::new(250)),
+            counters: Default::default(),
+            notify_ttl_updated: Default::default(),
+        };
+
+        eviction_manager.update_file_list().unwrap();
+        assert_eq!(eviction_manager.files_to_remove.as_ref().unwrap().len(), 1);
+
+        let guard = loop {
+            if let Some(guard) = GLOBAL_FILE_CACHE_LOCK.try_lock_eviction() {
+                break guard;
+            }
+            std::thread::sleep(Duration::from_millis(100));
+        };
+        eviction_manager.evict_files(&guard);
+        drop(guard);
+
+        let mut remaining = std::fs::read_dir(&data_dir)
+            .unwrap()
+            .map(|x| x.unwrap().file_name().to_str().unwrap()[..32].to_string())
+            .collect::<Vec<_>>();
+        remaining.sort();
+
+        // The least recently accessed file is evicted.
+        assert_eq!(remaining, [uri_hash(1), uri_hash(3)]);
+        assert_eq!(
+            eviction_manager.counters.evictions.load(Ordering::Relaxed),
+            1
+        );
+
+        // The cache fits after the eviction.
+        eviction_manager.update_file_list().unwrap();
+        assert!(eviction_manager.files_to_remove.unwrap().is_empty());
+    }
+}
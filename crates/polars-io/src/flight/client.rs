@@ -0,0 +1,235 @@
+use arrow::io::ipc::read::FlightConsumer;
+use arrow::io::ipc::write::EncodedData;
+use bytes::Bytes;
+use http::{Method, Request, StatusCode, header};
+use polars_core::prelude::*;
+use polars_error::to_compute_err;
+
+use super::grpc::{self, MessageDecoder};
+use super::{FlightData, FlightDescriptor, FlightInfo, Ticket};
+
+/// Client for the `DoGet` and `GetFlightInfo` calls of an Arrow Flight service.
+///
+/// Only plaintext connections (`grpc://` or `grpc+tcp://` locations) are supported, TLS is not
+/// implemented yet.
+#[derive(Clone)]
+pub struct FlightClient {
+    sender: h2::client::SendRequest<Bytes>,
+    authority: String,
+    max_message_size: usize,
+}
+
+impl FlightClient {
+    /// Connect to the Flight service at `location`, e.g. `grpc://localhost:8815`.
+    pub async fn connect(location: &str) -> PolarsResult<Self> {
+        let authority = parse_location(location)?;
+
+        let stream = tokio::net::TcpStream::connect(&authority).await?;
+        stream.set_nodelay(true)?;
+        let (sender, connection) = h2::client::handshake(stream)
+            .await
+            .map_err(to_compute_err)?;
+
+        // The connection future drives the socket and resolves when it is closed.
+        tokio::spawn(async move {
+            if let Err(err) = connection.await {
+                if polars_core::config::verbose() {
+                    eprintln!("[FlightClient]: connection closed with error: {err}");
+                }
+            }
+        });
+
+        Ok(Self {
+            sender,
+            authority,
+            max_message_size: grpc::DEFAULT_MAX_MESSAGE_SIZE,
+        })
+    }
+
+    /// Set the maximum size in bytes of a received message. Defaults to
+    /// [`DEFAULT_MAX_MESSAGE_SIZE`](super::DEFAULT_MAX_MESSAGE_SIZE).
+    pub fn with_max_message_size(mut self, max_message_size: usize) -> Self {
+        self.max_message_size = max_message_size;
+        self
+    }
+
+    /// Issue a `GetFlightInfo` for `descriptor`.
+    pub async fn get_flight_info(&self, descriptor: FlightDescriptor) -> PolarsResult<FlightInfo> {
+        let mut body = self
+            .call("GetFlightInfo", grpc::encode_message(&descriptor))
+            .await?;
+        let (info, trailers) =
+            grpc::recv_unary::<FlightInfo>(&mut body, self.max_message_size).await?;
+        if let Some(trailers) = trailers {
+            grpc::check_status(&trailers)?;
+        }
+        Ok(info)
+    }
+
+    /// Issue a `DoGet` for `ticket` and return a stream over the resulting record batches.
+    pub async fn do_get(
+        &self,
+        ticket: impl Into<Vec<u8>>,
+    ) -> PolarsResult<FlightRecordBatchStream> {
+        let ticket = Ticket {
+            ticket: ticket.into(),
+        };
+        let body = self.call("DoGet", grpc::encode_message(&ticket)).await?;
+        FlightRecordBatchStream::try_new(body, self.max_message_size).await
+    }
+
+    async fn call(&self, method: &str, message: Bytes) -> PolarsResult<h2::RecvStream> {
+        let request = Request::builder()
+            .method(Method::POST)
+            .uri(format!(
+                "http://{}{}/{}",
+                self.authority,
+                grpc::SERVICE_PATH,
+                method
+            ))
+            .header(header::CONTENT_TYPE, grpc::CONTENT_TYPE)
+            .header(header::TE, "trailers")
+            .body(())
+            .map_err(to_compute_err)?;
+
+        let mut sender = self.sender.clone().ready().await.map_err(to_compute_err)?;
+        let (response, mut send_stream) = sender
+            .send_request(request, false)
+            .map_err(to_compute_err)?;
+        grpc::send_data(&mut send_stream, message).await?;
+        send_stream
+            .send_data(Bytes::new(), true)
+            .map_err(to_compute_err)?;
+
+        let (parts, body) = response.await.map_err(to_compute_err)?.into_parts();
+        polars_ensure!(
+            parts.status == StatusCode::OK,
+            ComputeError: "flight call {} failed with HTTP status {}", method, parts.status
+        );
+        // Failed calls are usually answered with the status in the headers and no body.
+        if parts.headers.contains_key("grpc-status") {
+            grpc::check_status(&parts.headers)?;
+        }
+
+        Ok(body)
+    }
+}
+
+/// Record batches received from a `DoGet`, decoded into [`DataFrame`]s.
+pub struct FlightRecordBatchStream {
+    body: h2::RecvStream,
+    decoder: MessageDecoder,
+    consumer: FlightConsumer,
+    schema: SchemaRef,
+    finished: bool,
+}
+
+impl FlightRecordBatchStream {
+    async fn try_new(mut body: h2::RecvStream, max_message_size: usize) -> PolarsResult<Self> {
+        let mut decoder = MessageDecoder::new(max_message_size);
+        let mut finished = false;
+
+        let Some(first) = next_flight_data(&mut body, &mut decoder, &mut finished).await? else {
+            polars_bail!(ComputeError: "flight stream ended before the schema was received")
+        };
+        let consumer = FlightConsumer::new(EncodedData {
+            ipc_message: first.data_header,
+            arrow_data: first.data_body,
+        })?;
+        let schema = Arc::new(Schema::from_arrow_schema(consumer.schema()));
+
+        Ok(Self {
+            body,
+            decoder,
+            consumer,
+            schema,
+            finished,
+        })
+    }
+
+    /// Schema of the record batches in this stream.
+    pub fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    /// Receive the next record batch, or `None` once the stream has ended.
+    pub async fn next_batch(&mut self) -> PolarsResult<Option<DataFrame>> {
+        while let Some(data) =
+            next_flight_data(&mut self.body, &mut self.decoder, &mut self.finished).await?
+        {
+            let msg = EncodedData {
+                ipc_message: data.data_header,
+                arrow_data: data.data_body,
+            };
+            // Dictionary batches only update the consumer state.
+            if let Some(batch) = self.consumer.consume(msg)? {
+                return Ok(Some(DataFrame::from(batch)));
+            }
+        }
+        Ok(None)
+    }
+}
+
+async fn next_flight_data(
+    body: &mut h2::RecvStream,
+    decoder: &mut MessageDecoder,
+    finished: &mut bool,
+) -> PolarsResult<Option<FlightData>> {
+    loop {
+        if let Some(data) = decoder.next_message::<FlightData>()? {
+            // Messages that only carry application metadata have no IPC payload.
+            if data.data_header.is_empty() {
+                continue;
+            }
+            return Ok(Some(data));
+        }
+        if *finished {
+            return Ok(None);
+        }
+        if !grpc::recv_data(body, decoder).await? {
+            polars_ensure!(
+                decoder.is_empty(),
+                ComputeError: "flight stream ended in the middle of a message"
+            );
+            if let Some(trailers) = body.trailers().await.map_err(to_compute_err)? {
+                grpc::check_status(&trailers)?;
+            }
+            *finished = true;
+        }
+    }
+}
+
+/// Decodes [`FlightInfo::schema`], the schema of a flight as an encapsulated IPC message.
+pub fn flight_info_schema(info: &FlightInfo) -> PolarsResult<SchemaRef> {
+    let mut message = info.schema.as_slice();
+    // The continuation marker is missing in the legacy format.
+    if let Some(rest) = message.strip_prefix(&[0xff; 4]) {
+        message = rest;
+    }
+    let message = message
+        .split_first_chunk::<4>()
+        .and_then(|(len, rest)| rest.get(..usize::try_from(i32::from_le_bytes(*len)).ok()?))
+        .ok_or_else(|| polars_err!(ComputeError: "flight info does not contain a valid schema"))?;
+
+    let consumer = FlightConsumer::new(EncodedData {
+        ipc_message: message.to_vec(),
+        arrow_data: vec![],
+    })?;
+    Ok(Arc::new(Schema::from_arrow_schema(consumer.schema())))
+}
+
+/// Returns the `host:port` of a plaintext Flight location.
+fn parse_location(location: &str) -> PolarsResult<String> {
+    let authority = ["grpc+tcp://", "grpc://"]
+        .iter()
+        .find_map(|scheme| location.strip_prefix(scheme));
+
+    let Some(authority) = authority else {
+        if location.starts_with("grpc+tls://") {
+            polars_bail!(ComputeError: "TLS flight locations are not supported: {}", location)
+        }
+        polars_bail!(ComputeError: "unsupported flight location: {}", location)
+    };
+
+    Ok(authority.trim_end_matches('/').to_string())
+}
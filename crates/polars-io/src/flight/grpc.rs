@@ -0,0 +1,209 @@
+//! Just enough of the gRPC wire protocol over HTTP/2 to carry the unary and server-streaming
+//! Flight calls. Messages are uncompressed and length-prefixed, and the call status is sent in
+//! the `grpc-status`/`grpc-message` trailers.
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use http::HeaderMap;
+use percent_encoding::{AsciiSet, CONTROLS, percent_decode_str, utf8_percent_encode};
+use polars_error::{
+    PolarsError, PolarsResult, polars_bail, polars_ensure, polars_err, to_compute_err,
+};
+
+pub(super) const SERVICE_PATH: &str = "/arrow.flight.protocol.FlightService";
+pub(super) const CONTENT_TYPE: &str = "application/grpc";
+
+pub(super) const STATUS_OK: u32 = 0;
+pub(super) const STATUS_INVALID_ARGUMENT: u32 = 3;
+pub(super) const STATUS_UNIMPLEMENTED: u32 = 12;
+pub(super) const STATUS_INTERNAL: u32 = 13;
+
+/// Default upper bound on the size of a message, the same as the default receive limit of gRPC.
+/// Without a limit, a corrupt or malicious length prefix could make the decoder buffer up to
+/// 4 GiB per message.
+pub const DEFAULT_MAX_MESSAGE_SIZE: usize = 4 << 20;
+
+/// Characters that must be percent-encoded in a `grpc-message`.
+const GRPC_MESSAGE: &AsciiSet = &CONTROLS.add(b'%');
+
+/// Status of a failed call, sent to the peer in the trailers.
+pub(super) struct Status {
+    pub code: u32,
+    pub message: String,
+}
+
+impl Status {
+    pub fn new(code: u32, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+
+    pub fn to_headers(&self) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert("grpc-status", self.code.into());
+        let message = utf8_percent_encode(&self.message, GRPC_MESSAGE).to_string();
+        if let Ok(message) = message.try_into() {
+            headers.insert("grpc-message", message);
+        }
+        headers
+    }
+}
+
+impl From<PolarsError> for Status {
+    fn from(err: PolarsError) -> Self {
+        let code = match &err {
+            PolarsError::InvalidOperation(_)
+            | PolarsError::SQLInterface(_)
+            | PolarsError::SQLSyntax(_) => STATUS_INVALID_ARGUMENT,
+            _ => STATUS_INTERNAL,
+        };
+        Self::new(code, err.to_string())
+    }
+}
+
+/// Raises an error if the headers or trailers carry a non-OK `grpc-status`.
+pub(super) fn check_status(headers: &HeaderMap) -> PolarsResult<()> {
+    let Some(code) = headers.get("grpc-status") else {
+        polars_bail!(ComputeError: "flight response is missing the grpc-status")
+    };
+    let code = code
+        .to_str()
+        .ok()
+        .and_then(|code| code.parse::<u32>().ok())
+        .ok_or_else(|| polars_err!(ComputeError: "invalid grpc-status: {:?}", code))?;
+
+    if code != STATUS_OK {
+        let message = headers
+            .get("grpc-message")
+            .and_then(|v| v.to_str().ok())
+            .map(|v| percent_decode_str(v).decode_utf8_lossy().into_owned())
+            .unwrap_or_default();
+        polars_bail!(ComputeError: "flight call failed with status {}: {}", code, message)
+    }
+
+    Ok(())
+}
+
+/// Encodes `msg` as a single length-prefixed gRPC message.
+pub(super) fn encode_message<M: prost::Message>(msg: &M) -> Bytes {
+    let len = msg.encoded_len();
+    let mut buf = BytesMut::with_capacity(5 + len);
+    buf.put_u8(0);
+    buf.put_u32(len as u32);
+    // Cannot fail as the buffer has enough capacity.
+    msg.encode(&mut buf).unwrap();
+    buf.freeze()
+}
+
+/// Splits the length-prefixed gRPC messages out of the received HTTP/2 data frames.
+pub(super) struct MessageDecoder {
+    buf: BytesMut,
+    max_message_size: usize,
+}
+
+impl MessageDecoder {
+    pub fn new(max_message_size: usize) -> Self {
+        Self {
+            buf: BytesMut::new(),
+            max_message_size,
+        }
+    }
+
+    pub fn push(&mut self, data: &[u8]) {
+        self.buf.extend_from_slice(data);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.buf.is_empty()
+    }
+
+    /// Returns the next complete message, or `None` if more data is needed.
+    pub fn next_message<M: prost::Message + Default>(&mut self) -> PolarsResult<Option<M>> {
+        if self.buf.len() < 5 {
+            return Ok(None);
+        }
+        polars_ensure!(
+            self.buf[0] == 0,
+            ComputeError: "compressed gRPC messages are not supported"
+        );
+        let len = u32::from_be_bytes(self.buf[1..5].try_into().unwrap()) as usize;
+        polars_ensure!(
+            len <= self.max_message_size,
+            ComputeError: "gRPC message of {} bytes exceeds the limit of {} bytes", len, self.max_message_size
+        );
+        if self.buf.len() < 5 + len {
+            return Ok(None);
+        }
+
+        self.buf.advance(5);
+        let msg = self.buf.split_to(len).freeze();
+        M::decode(msg)
+            .map(Some)
+            .map_err(|err| polars_err!(ComputeError: "failed to decode flight message: {}", err))
+    }
+}
+
+/// Receives the next data frame of `body` into `decoder`. Returns `false` once the body has ended.
+pub(super) async fn recv_data(
+    body: &mut h2::RecvStream,
+    decoder: &mut MessageDecoder,
+) -> PolarsResult<bool> {
+    match body.data().await {
+        Some(data) => {
+            let data = data.map_err(to_compute_err)?;
+            // Hand the window back to the peer so that it can keep sending.
+            let _ = body.flow_control().release_capacity(data.len());
+            decoder.push(&data);
+            Ok(true)
+        },
+        None => Ok(false),
+    }
+}
+
+/// Reads the single message of a unary request or response body.
+pub(super) async fn recv_unary<M: prost::Message + Default>(
+    body: &mut h2::RecvStream,
+    max_message_size: usize,
+) -> PolarsResult<(M, Option<HeaderMap>)> {
+    let mut decoder = MessageDecoder::new(max_message_size);
+    let mut msg = None;
+    while recv_data(body, &mut decoder).await? {
+        // Decode as the data arrives, so that the length prefix is checked before the body has
+        // been buffered.
+        if msg.is_none() {
+            msg = decoder.next_message()?;
+        }
+        polars_ensure!(
+            msg.is_none() || decoder.is_empty(),
+            ComputeError: "flight body contained more than one message"
+        );
+    }
+
+    let Some(msg) = msg else {
+        polars_bail!(ComputeError: "flight body did not contain a complete message")
+    };
+    let trailers = body.trailers().await.map_err(to_compute_err)?;
+
+    Ok((msg, trailers))
+}
+
+/// Sends `data` on `stream`, waiting for the peer to grant flow-control capacity so that a slow
+/// reader applies back-pressure instead of the data piling up in the send buffer.
+pub(super) async fn send_data(
+    stream: &mut h2::SendStream<Bytes>,
+    mut data: Bytes,
+) -> PolarsResult<()> {
+    while !data.is_empty() {
+        stream.reserve_capacity(data.len());
+        let capacity = std::future::poll_fn(|cx| stream.poll_capacity(cx))
+            .await
+            .ok_or_else(|| polars_err!(ComputeError: "flight stream was closed by the peer"))?
+            .map_err(to_compute_err)?;
+        if capacity == 0 {
+            continue;
+        }
+        let chunk = data.split_to(capacity.min(data.len()));
+        stream.send_data(chunk, false).map_err(to_compute_err)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,202 @@
+//! Arrow Flight client and server.
+//!
+//! Record batches are exchanged as Arrow IPC messages over a minimal gRPC transport that covers
+//! the `DoGet` and `GetFlightInfo` calls. Connections are plaintext; TLS and the Flight SQL
+//! commands are not implemented.
+mod client;
+mod grpc;
+mod server;
+
+pub use arrow::io::ipc::format::flight::data::{
+    FlightData, FlightDescriptor, FlightEndpoint, FlightInfo, Location, Ticket, flight_descriptor,
+};
+pub use client::{FlightClient, FlightRecordBatchStream, flight_info_schema};
+pub use grpc::DEFAULT_MAX_MESSAGE_SIZE;
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+pub use server::{FlightServer, FlightServerHandle, FlightTicketResolver};
+
+use crate::pl_async::get_runtime;
+
+/// Options for scanning the record batches of a `DoGet`. The location of the Flight service is
+/// the scan source.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct FlightScanOptions {
+    pub ticket: Vec<u8>,
+    /// Maximum size in bytes of a received message.
+    pub max_message_size: usize,
+}
+
+impl FlightScanOptions {
+    pub fn new(ticket: impl Into<Vec<u8>>) -> Self {
+        Self {
+            ticket: ticket.into(),
+            max_message_size: DEFAULT_MAX_MESSAGE_SIZE,
+        }
+    }
+
+    pub fn with_max_message_size(mut self, max_message_size: usize) -> Self {
+        self.max_message_size = max_message_size;
+        self
+    }
+
+    /// Connect to the Flight service at `location` with these options.
+    pub async fn connect(&self, location: &str) -> PolarsResult<FlightClient> {
+        Ok(FlightClient::connect(location)
+            .await?
+            .with_max_message_size(self.max_message_size))
+    }
+}
+
+/// Get the schema of the data behind the ticket of `options` from the Flight service at
+/// `location`.
+///
+/// This issues a `GetFlightInfo` with the ticket as command descriptor, so no data is sent.
+pub fn read_flight_schema(location: &str, options: &FlightScanOptions) -> PolarsResult<SchemaRef> {
+    get_runtime().block_in_place_on(async {
+        let client = options.connect(location).await?;
+        let info = client
+            .get_flight_info(FlightDescriptor {
+                r#type: flight_descriptor::DescriptorType::Cmd as i32,
+                cmd: options.ticket.clone(),
+                ..Default::default()
+            })
+            .await?;
+        flight_info_schema(&info)
+    })
+}
+
+/// Read the data behind the ticket of `options` from the Flight service at `location` with a
+/// `DoGet`.
+///
+/// Every received record batch becomes a chunk of the returned [`DataFrame`]. If `n_rows` is
+/// given, the call is cancelled once enough rows have been received.
+pub fn read_flight(
+    location: &str,
+    options: &FlightScanOptions,
+    n_rows: Option<usize>,
+) -> PolarsResult<DataFrame> {
+    get_runtime().block_in_place_on(async {
+        let client = options.connect(location).await?;
+        let mut stream = client.do_get(options.ticket.clone()).await?;
+
+        let mut num_rows = 0;
+        let mut dfs = vec![];
+
+        while let Some(mut df) = stream.next_batch().await? {
+            if let Some(n) = n_rows {
+                if num_rows + df.height() >= n {
+                    df = df.slice(0, n - num_rows);
+                    dfs.push(df);
+                    break;
+                }
+            }
+            num_rows += df.height();
+            dfs.push(df);
+        }
+
+        Ok(if dfs.is_empty() {
+            DataFrame::empty_with_schema(stream.schema())
+        } else {
+            accumulate_dataframes_vertical_unchecked(dfs)
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::flight_descriptor::DescriptorType;
+    use super::*;
+
+    struct TestResolver(DataFrame);
+
+    impl TestResolver {
+        fn get(&self, ticket: &[u8]) -> PolarsResult<&DataFrame> {
+            polars_ensure!(ticket == b"df", ComputeError: "unknown ticket");
+            Ok(&self.0)
+        }
+    }
+
+    impl FlightTicketResolver for TestResolver {
+        fn schema(&self, ticket: &[u8]) -> PolarsResult<SchemaRef> {
+            Ok(self.get(ticket)?.schema().clone())
+        }
+
+        fn fetch(&self, ticket: &[u8]) -> PolarsResult<DataFrame> {
+            self.get(ticket).cloned()
+        }
+    }
+
+    #[test]
+    fn test_flight_loopback() -> PolarsResult<()> {
+        let df = df!(
+            "a" => [1i64, 2, 3],
+            "b" => [Some("x"), None, Some("z")],
+        )?;
+        let server = FlightServer::new(Arc::new(TestResolver(df.clone())))
+            .serve("127.0.0.1:0".parse().unwrap())?;
+        let location = server.location();
+        let options = FlightScanOptions::new("df");
+
+        assert_eq!(read_flight_schema(&location, &options)?, *df.schema());
+        assert!(read_flight(&location, &options, None)?.equals_missing(&df));
+        assert!(read_flight(&location, &options, Some(2))?.equals_missing(&df.head(Some(2))));
+
+        let missing = FlightScanOptions::new("missing");
+        let err = read_flight(&location, &missing, None).unwrap_err();
+        assert!(err.to_string().contains("unknown ticket"));
+
+        let info = get_runtime().block_on(async {
+            FlightClient::connect(&location)
+                .await?
+                .get_flight_info(FlightDescriptor {
+                    r#type: DescriptorType::Cmd as i32,
+                    cmd: b"df".to_vec(),
+                    ..Default::default()
+                })
+                .await
+        })?;
+        assert_eq!(info.endpoint[0].ticket.as_ref().unwrap().ticket, b"df");
+        assert_eq!(flight_info_schema(&info)?, *df.schema());
+        assert!(read_flight_schema(&location, &missing).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_flight_max_message_size() -> PolarsResult<()> {
+        let df = df!(
+            "a" => (0..100_000i64).collect::<Vec<_>>(),
+            "b" => (0..100_000).map(|i| format!("value {i}")).collect::<Vec<_>>(),
+        )?;
+        let server = FlightServer::new(Arc::new(TestResolver(df.clone())))
+            .with_max_message_size(64 * 1024)
+            .serve("127.0.0.1:0".parse().unwrap())?;
+        let location = server.location();
+
+        // The server splits the frame into batches that fit in the limit.
+        let options = FlightScanOptions::new("df").with_max_message_size(64 * 1024);
+        let out = read_flight(&location, &options, None)?;
+        assert!(out.first_col_n_chunks() > 1);
+        assert!(out.equals(&df));
+
+        // The client rejects messages above its limit.
+        let options = options.with_max_message_size(1024);
+        let err = read_flight(&location, &options, None).unwrap_err();
+        assert!(err.to_string().contains("exceeds the limit"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_message_decoder_length_limit() {
+        let mut decoder = grpc::MessageDecoder::new(DEFAULT_MAX_MESSAGE_SIZE);
+        decoder.push(&[0, 0x00, 0x40, 0x00, 0x01]);
+        let err = decoder.next_message::<Ticket>().unwrap_err();
+        assert!(err.to_string().contains("exceeds the limit"));
+    }
+}
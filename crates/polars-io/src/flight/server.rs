@@ -0,0 +1,377 @@
+use std::net::SocketAddr;
+
+use arrow::io::ipc::write::{
+    DictionaryTracker, WriteOptions, default_ipc_fields, encode_chunk, schema_to_bytes,
+};
+use bytes::Bytes;
+use http::{Request, Response, StatusCode, header};
+use polars_core::frame::chunk_df_for_writing;
+use polars_core::prelude::*;
+use polars_error::to_compute_err;
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::mpsc;
+use tokio::task::{JoinHandle, JoinSet};
+
+use super::flight_descriptor::DescriptorType;
+use super::grpc::{self, Status};
+use super::{FlightData, FlightDescriptor, FlightEndpoint, FlightInfo, Ticket};
+use crate::pl_async::get_runtime;
+
+/// Resolves the tickets of [`FlightServer`] requests to data.
+///
+/// A ticket is the opaque byte string a client passes to `DoGet`. The same bytes are accepted as
+/// the command of a `GetFlightInfo` descriptor, and path descriptors are joined with `/`.
+pub trait FlightTicketResolver: Send + Sync {
+    /// Schema of the data `ticket` resolves to.
+    fn schema(&self, ticket: &[u8]) -> PolarsResult<SchemaRef>;
+
+    /// Materialize the data `ticket` resolves to.
+    fn fetch(&self, ticket: &[u8]) -> PolarsResult<DataFrame>;
+}
+
+/// Minimal Arrow Flight server that answers `DoGet` and `GetFlightInfo` from a
+/// [`FlightTicketResolver`]. Other calls are answered with `UNIMPLEMENTED`.
+pub struct FlightServer {
+    resolver: Arc<dyn FlightTicketResolver>,
+    compat_level: CompatLevel,
+    max_message_size: usize,
+}
+
+struct ServerState {
+    resolver: Arc<dyn FlightTicketResolver>,
+    compat_level: CompatLevel,
+    max_message_size: usize,
+}
+
+impl FlightServer {
+    pub fn new(resolver: Arc<dyn FlightTicketResolver>) -> Self {
+        Self {
+            resolver,
+            compat_level: CompatLevel::newest(),
+            max_message_size: grpc::DEFAULT_MAX_MESSAGE_SIZE,
+        }
+    }
+
+    /// Set the compatibility level of the Arrow data that is sent to clients.
+    pub fn with_compat_level(mut self, compat_level: CompatLevel) -> Self {
+        self.compat_level = compat_level;
+        self
+    }
+
+    /// Set the maximum size in bytes of a message. Requests above it are rejected, and record
+    /// batches are split so that the messages sent to clients stay below it. Defaults to
+    /// [`DEFAULT_MAX_MESSAGE_SIZE`](super::DEFAULT_MAX_MESSAGE_SIZE).
+    pub fn with_max_message_size(mut self, max_message_size: usize) -> Self {
+        self.max_message_size = max_message_size;
+        self
+    }
+
+    /// Bind to `addr` and serve requests in the background until the returned handle is dropped.
+    ///
+    /// Bind to port 0 to let the OS pick a free port.
+    pub fn serve(self, addr: SocketAddr) -> PolarsResult<FlightServerHandle> {
+        let listener = get_runtime().block_in_place_on(TcpListener::bind(addr))?;
+        let local_addr = listener.local_addr()?;
+
+        let state = Arc::new(ServerState {
+            resolver: self.resolver,
+            compat_level: self.compat_level,
+            max_message_size: self.max_message_size,
+        });
+        let task = get_runtime().spawn(accept_connections(listener, state));
+
+        Ok(FlightServerHandle { local_addr, task })
+    }
+}
+
+/// Handle to a running [`FlightServer`]. Dropping it shuts the server down.
+pub struct FlightServerHandle {
+    local_addr: SocketAddr,
+    task: JoinHandle<()>,
+}
+
+impl FlightServerHandle {
+    pub fn local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
+
+    /// Location that clients can connect to, e.g. `grpc://127.0.0.1:8815`.
+    pub fn location(&self) -> String {
+        format!("grpc://{}", self.local_addr)
+    }
+}
+
+impl Drop for FlightServerHandle {
+    fn drop(&mut self) {
+        // Connections are owned by the accept task, so this closes them as well.
+        self.task.abort();
+    }
+}
+
+async fn accept_connections(listener: TcpListener, state: Arc<ServerState>) {
+    let verbose = polars_core::config::verbose();
+    let mut connections = JoinSet::new();
+
+    loop {
+        match listener.accept().await {
+            Ok((socket, _)) => {
+                connections.spawn(serve_connection(socket, state.clone()));
+            },
+            Err(err) => {
+                if verbose {
+                    eprintln!("[FlightServer]: failed to accept connection: {err}");
+                }
+            },
+        }
+
+        // Reap the connections that have closed in the meantime.
+        while let Some(result) = connections.try_join_next() {
+            if let Ok(Err(err)) = result {
+                if verbose {
+                    eprintln!("[FlightServer]: connection closed with error: {err}");
+                }
+            }
+        }
+    }
+}
+
+async fn serve_connection(socket: TcpStream, state: Arc<ServerState>) -> PolarsResult<()> {
+    socket.set_nodelay(true)?;
+    let mut connection = h2::server::handshake(socket)
+        .await
+        .map_err(to_compute_err)?;
+    let mut calls = JoinSet::new();
+
+    while let Some(request) = connection.accept().await {
+        let (request, respond) = request.map_err(to_compute_err)?;
+        calls.spawn(serve_call(request, respond, state.clone()));
+    }
+    while calls.join_next().await.is_some() {}
+
+    Ok(())
+}
+
+async fn serve_call(
+    request: Request<h2::RecvStream>,
+    mut respond: h2::server::SendResponse<Bytes>,
+    state: Arc<ServerState>,
+) {
+    let method = request
+        .uri()
+        .path()
+        .strip_prefix(grpc::SERVICE_PATH)
+        .and_then(|path| path.strip_prefix('/'))
+        .map(|method| method.to_string());
+    let mut body = request.into_body();
+
+    let result = match method.as_deref() {
+        Some("DoGet") => do_get(&mut body, &mut respond, &state).await,
+        Some("GetFlightInfo") => get_flight_info(&mut body, &mut respond, &state).await,
+        _ => Err(Status::new(
+            grpc::STATUS_UNIMPLEMENTED,
+            format!(
+                "unsupported flight call: {}",
+                method.as_deref().unwrap_or("")
+            ),
+        )),
+    };
+
+    if let Err(status) = result {
+        // Nothing has been sent yet, so answer with a trailers-only response.
+        let mut response = response_builder().body(()).unwrap();
+        response.headers_mut().extend(status.to_headers());
+        let _ = respond.send_response(response, true);
+    }
+}
+
+fn response_builder() -> http::response::Builder {
+    Response::builder()
+        .status(StatusCode::OK)
+        .header(header::CONTENT_TYPE, grpc::CONTENT_TYPE)
+}
+
+/// Runs a blocking resolver call off the async worker threads.
+async fn spawn_resolver<F, O>(func: F) -> Result<O, Status>
+where
+    F: FnOnce() -> PolarsResult<O> + Send + 'static,
+    O: Send + 'static,
+{
+    get_runtime()
+        .spawn_blocking(func)
+        .await
+        .map_err(|err| Status::new(grpc::STATUS_INTERNAL, err.to_string()))?
+        .map_err(Status::from)
+}
+
+async fn get_flight_info(
+    body: &mut h2::RecvStream,
+    respond: &mut h2::server::SendResponse<Bytes>,
+    state: &ServerState,
+) -> Result<(), Status> {
+    let (descriptor, _) =
+        grpc::recv_unary::<FlightDescriptor>(body, state.max_message_size).await?;
+
+    let ticket = match descriptor.r#type() {
+        DescriptorType::Cmd => descriptor.cmd.clone(),
+        DescriptorType::Path => descriptor.path.join("/").into_bytes(),
+        DescriptorType::Unknown => {
+            return Err(Status::new(
+                grpc::STATUS_INVALID_ARGUMENT,
+                "flight descriptor must be a command or a path",
+            ));
+        },
+    };
+
+    let resolver = state.resolver.clone();
+    let compat_level = state.compat_level;
+    let schema = {
+        let ticket = ticket.clone();
+        spawn_resolver(move || resolver.schema(&ticket)).await?
+    };
+
+    let info = FlightInfo {
+        schema: encapsulate_schema(&schema, compat_level),
+        flight_descriptor: Some(descriptor),
+        endpoint: vec![FlightEndpoint {
+            ticket: Some(Ticket { ticket }),
+            ..Default::default()
+        }],
+        total_records: -1,
+        total_bytes: -1,
+    };
+
+    let mut send = respond
+        .send_response(response_builder().body(()).unwrap(), false)
+        .map_err(to_compute_err)?;
+    grpc::send_data(&mut send, grpc::encode_message(&info)).await?;
+    send.send_trailers(Status::new(grpc::STATUS_OK, "").to_headers())
+        .map_err(to_compute_err)?;
+
+    Ok(())
+}
+
+async fn do_get(
+    body: &mut h2::RecvStream,
+    respond: &mut h2::server::SendResponse<Bytes>,
+    state: &ServerState,
+) -> Result<(), Status> {
+    let (ticket, _) = grpc::recv_unary::<Ticket>(body, state.max_message_size).await?;
+
+    let resolver = state.resolver.clone();
+    let mut df = spawn_resolver(move || resolver.fetch(&ticket.ticket)).await?;
+
+    // Encode on a blocking thread and stream the messages out as they are produced. The bounded
+    // channel stops the encoder from running ahead of a slow client.
+    let (tx, mut rx) = mpsc::channel(2);
+    let compat_level = state.compat_level;
+    let max_message_size = state.max_message_size;
+    let encoder = get_runtime()
+        .spawn_blocking(move || encode_dataframe(&mut df, compat_level, max_message_size, &tx));
+
+    let mut send = respond
+        .send_response(response_builder().body(()).unwrap(), false)
+        .map_err(to_compute_err)?;
+
+    let mut result = Ok(());
+    while let Some(message) = rx.recv().await {
+        result = grpc::send_data(&mut send, message).await;
+        if result.is_err() {
+            break;
+        }
+    }
+    // Dropping the receiver stops the encoder if sending failed.
+    drop(rx);
+
+    let status = match (result, encoder.await) {
+        (Err(err), _) | (_, Ok(Err(err))) => Status::from(err),
+        (_, Err(err)) => Status::new(grpc::STATUS_INTERNAL, err.to_string()),
+        (Ok(()), Ok(Ok(()))) => Status::new(grpc::STATUS_OK, ""),
+    };
+    // Headers have been sent, so errors from here on are reported in the trailers.
+    let _ = send.send_trailers(status.to_headers());
+
+    Ok(())
+}
+
+/// Encodes `df` as a schema message followed by its dictionary and record batches.
+///
+/// Chunks are sliced into record batches with an estimated size of at most half of
+/// `max_message_size`, which leaves room for the IPC metadata and padding.
+fn encode_dataframe(
+    df: &mut DataFrame,
+    compat_level: CompatLevel,
+    max_message_size: usize,
+    tx: &mpsc::Sender<Bytes>,
+) -> PolarsResult<()> {
+    let arrow_schema = df.schema().to_arrow(compat_level);
+    let ipc_fields = default_ipc_fields(arrow_schema.iter_values());
+
+    let schema = FlightData {
+        data_header: schema_to_bytes(&arrow_schema, &ipc_fields, None),
+        ..Default::default()
+    };
+    if tx.blocking_send(grpc::encode_message(&schema)).is_err() {
+        return Ok(());
+    }
+
+    let mut dictionary_tracker = DictionaryTracker {
+        dictionaries: Default::default(),
+        cannot_replace: false,
+    };
+    let options = WriteOptions::default();
+    let mut df = chunk_df_for_writing(df, 512 * 512)?.into_owned();
+    let target_size = (max_message_size / 2).max(1);
+
+    for chunk in df.split_chunks() {
+        let n_batches = chunk.estimated_size().div_ceil(target_size).max(1);
+        let batch_size = chunk.height().div_ceil(n_batches).max(1);
+
+        let mut offset = 0;
+        loop {
+            let sliced = chunk.slice(offset as i64, batch_size);
+            for batch in sliced.iter_chunks(compat_level, true) {
+                let (dictionaries, record_batch) =
+                    encode_chunk(&batch, &ipc_fields, &mut dictionary_tracker, &options)?;
+
+                for encoded in dictionaries.into_iter().chain([record_batch]) {
+                    let data = FlightData {
+                        data_header: encoded.ipc_message,
+                        data_body: encoded.arrow_data,
+                        ..Default::default()
+                    };
+                    let message = grpc::encode_message(&data);
+                    polars_ensure!(
+                        message.len() - 5 <= max_message_size,
+                        ComputeError: "flight message of {} bytes exceeds the limit of {} bytes",
+                        message.len() - 5, max_message_size
+                    );
+                    // The client went away.
+                    if tx.blocking_send(message).is_err() {
+                        return Ok(());
+                    }
+                }
+            }
+
+            offset += batch_size;
+            if offset >= chunk.height() {
+                break;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Encodes `schema` as an encapsulated IPC message, as expected in [`FlightInfo::schema`].
+fn encapsulate_schema(schema: &Schema, compat_level: CompatLevel) -> Vec<u8> {
+    let arrow_schema = schema.to_arrow(compat_level);
+    let ipc_fields = default_ipc_fields(arrow_schema.iter_values());
+    let mut message = schema_to_bytes(&arrow_schema, &ipc_fields, None);
+    message.resize(message.len().next_multiple_of(8), 0);
+
+    let mut out = Vec::with_capacity(8 + message.len());
+    out.extend_from_slice(&[0xff; 4]);
+    out.extend_from_slice(&(message.len() as i32).to_le_bytes());
+    out.extend_from_slice(&message);
+    out
+}
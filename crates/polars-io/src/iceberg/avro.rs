@@ -0,0 +1,287 @@
+//! Decoding of the Avro manifest (list) files of an Iceberg table into generic values.
+//!
+//! Manifests are small and their schemas contain constructs (e.g. empty partition records) that
+//! are not supported by the columnar Avro reader, so they are decoded row by row.
+use std::io::Cursor;
+
+use arrow::io::avro::avro_schema::read::fallible_streaming_iterator::FallibleStreamingIterator;
+use arrow::io::avro::avro_schema::read::{block_iterator, read_metadata};
+use arrow::io::avro::avro_schema::schema::Schema as AvroSchema;
+use polars_core::error::to_compute_err;
+use polars_core::prelude::*;
+
+use crate::avro::read_zigzag_long;
+
+#[derive(Debug, Clone, PartialEq)]
+pub(super) enum AvroValue {
+    Null,
+    Boolean(bool),
+    Int(i32),
+    Long(i64),
+    Float(f32),
+    Double(f64),
+    /// Also used for `fixed` values.
+    Bytes(Vec<u8>),
+    String(String),
+    Array(Vec<AvroValue>),
+    Map(Vec<(String, AvroValue)>),
+    Record(Vec<(String, AvroValue)>),
+}
+
+impl AvroValue {
+    /// Returns the value of a record field, or `None` if the field does not exist or is null.
+    pub(super) fn field(&self, name: &str) -> Option<&AvroValue> {
+        let AvroValue::Record(fields) = self else {
+            return None;
+        };
+
+        fields
+            .iter()
+            .find(|(k, _)| k == name)
+            .map(|(_, v)| v)
+            .filter(|v| !matches!(v, AvroValue::Null))
+    }
+
+    pub(super) fn as_i64(&self) -> Option<i64> {
+        match self {
+            AvroValue::Int(v) => Some(i64::from(*v)),
+            AvroValue::Long(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    pub(super) fn as_f64(&self) -> Option<f64> {
+        match self {
+            AvroValue::Float(v) => Some(f64::from(*v)),
+            AvroValue::Double(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    pub(super) fn as_bool(&self) -> Option<bool> {
+        match self {
+            AvroValue::Boolean(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    pub(super) fn as_str(&self) -> Option<&str> {
+        match self {
+            AvroValue::String(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    pub(super) fn as_bytes(&self) -> Option<&[u8]> {
+        match self {
+            AvroValue::Bytes(v) => Some(v),
+            AvroValue::String(v) => Some(v.as_bytes()),
+            _ => None,
+        }
+    }
+
+    pub(super) fn as_array(&self) -> &[AvroValue] {
+        match self {
+            AvroValue::Array(v) => v,
+            _ => &[],
+        }
+    }
+}
+
+/// Decodes all rows of an Avro object container file.
+pub(super) fn read_avro_rows(bytes: &[u8]) -> PolarsResult<Vec<AvroValue>> {
+    let mut reader = Cursor::new(bytes);
+    let metadata = read_metadata(&mut reader).map_err(to_compute_err)?;
+    let schema = AvroSchema::Record(metadata.record);
+
+    let mut blocks = block_iterator(reader, metadata.compression, metadata.marker);
+    let mut rows = vec![];
+
+    while let Some(block) = blocks.next().map_err(to_compute_err)? {
+        let mut decoder = Decoder {
+            bytes: &block.data,
+            offset: 0,
+        };
+
+        rows.reserve(block.number_of_rows);
+
+        for _ in 0..block.number_of_rows {
+            rows.push(decoder.decode(&schema)?);
+        }
+    }
+
+    Ok(rows)
+}
+
+struct Decoder<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Decoder<'a> {
+    fn read_exact(&mut self, len: usize) -> PolarsResult<&'a [u8]> {
+        let out = self
+            .offset
+            .checked_add(len)
+            .and_then(|end| self.bytes.get(self.offset..end))
+            .ok_or_else(|| polars_err!(ComputeError: "unexpected end of avro block"))?;
+        self.offset += len;
+        Ok(out)
+    }
+
+    /// Decodes a zig-zag encoded variable-length `long`.
+    fn read_long(&mut self) -> PolarsResult<i64> {
+        let (value, len) = read_zigzag_long(&self.bytes[self.offset..])?;
+        self.offset += len;
+        Ok(value)
+    }
+
+    fn read_len(&mut self) -> PolarsResult<usize> {
+        let len = self.read_long()?;
+        usize::try_from(len)
+            .map_err(|_| polars_err!(ComputeError: "invalid avro data: negative length {}", len))
+    }
+
+    /// Reads the item count of the next block of an array or map. Returns 0 at the end.
+    fn read_block_count(&mut self) -> PolarsResult<usize> {
+        let count = self.read_long()?;
+
+        if count < 0 {
+            // The block byte size follows a negative count.
+            self.read_long()?;
+        }
+
+        Ok(count.unsigned_abs() as usize)
+    }
+
+    fn decode(&mut self, schema: &AvroSchema) -> PolarsResult<AvroValue> {
+        Ok(match schema {
+            AvroSchema::Null => AvroValue::Null,
+            AvroSchema::Boolean => AvroValue::Boolean(self.read_exact(1)?[0] != 0),
+            AvroSchema::Int(_) => AvroValue::Int(i32::try_from(self.read_long()?).map_err(
+                |_| polars_err!(ComputeError: "invalid avro data: int value out of range"),
+            )?),
+            AvroSchema::Long(_) => AvroValue::Long(self.read_long()?),
+            AvroSchema::Float => {
+                AvroValue::Float(f32::from_le_bytes(self.read_exact(4)?.try_into().unwrap()))
+            },
+            AvroSchema::Double => {
+                AvroValue::Double(f64::from_le_bytes(self.read_exact(8)?.try_into().unwrap()))
+            },
+            AvroSchema::Bytes(_) => {
+                let len = self.read_len()?;
+                AvroValue::Bytes(self.read_exact(len)?.to_vec())
+            },
+            AvroSchema::String(_) => {
+                let len = self.read_len()?;
+                AvroValue::String(
+                    std::str::from_utf8(self.read_exact(len)?)
+                        .map_err(to_compute_err)?
+                        .to_string(),
+                )
+            },
+            AvroSchema::Fixed(fixed) => AvroValue::Bytes(self.read_exact(fixed.size)?.to_vec()),
+            AvroSchema::Enum(_) => AvroValue::Int(i32::try_from(self.read_long()?).unwrap_or(-1)),
+            AvroSchema::Record(record) => AvroValue::Record(
+                record
+                    .fields
+                    .iter()
+                    .map(|f| Ok((f.name.clone(), self.decode(&f.schema)?)))
+                    .collect::<PolarsResult<_>>()?,
+            ),
+            AvroSchema::Union(variants) => {
+                let idx = self.read_long()?;
+                let variant = usize::try_from(idx)
+                    .ok()
+                    .and_then(|i| variants.get(i))
+                    .ok_or_else(
+                        || polars_err!(ComputeError: "invalid avro data: union index {}", idx),
+                    )?;
+                self.decode(variant)?
+            },
+            AvroSchema::Array(item) => {
+                let mut values = vec![];
+
+                loop {
+                    let count = self.read_block_count()?;
+                    if count == 0 {
+                        break;
+                    }
+
+                    for _ in 0..count {
+                        values.push(self.decode(item)?);
+                    }
+                }
+
+                AvroValue::Array(values)
+            },
+            AvroSchema::Map(value) => {
+                let mut entries = vec![];
+
+                loop {
+                    let count = self.read_block_count()?;
+                    if count == 0 {
+                        break;
+                    }
+
+                    for _ in 0..count {
+                        let len = self.read_len()?;
+                        let key = std::str::from_utf8(self.read_exact(len)?)
+                            .map_err(to_compute_err)?
+                            .to_string();
+                        entries.push((key, self.decode(value)?));
+                    }
+                }
+
+                AvroValue::Map(entries)
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_avro_values() -> PolarsResult<()> {
+        use arrow::io::avro::avro_schema::schema::{Field, Record};
+
+        let schema = AvroSchema::Record(Record::new(
+            "r",
+            vec![
+                Field::new("a", AvroSchema::Long(None)),
+                Field::new(
+                    "b",
+                    AvroSchema::Union(vec![AvroSchema::Null, AvroSchema::String(None)]),
+                ),
+                Field::new("c", AvroSchema::Array(Box::new(AvroSchema::Int(None)))),
+                Field::new("d", AvroSchema::Record(Record::new("empty", vec![]))),
+            ],
+        ));
+
+        // a = -2, b = "hi", c = [1, 2] (single block), d = {}
+        let bytes = [
+            0x03, // -2
+            0x02, 0x04, b'h', b'i', // union branch 1, string of length 2
+            0x04, 0x02, 0x04, 0x00, // block of 2 items: 1, 2; end
+        ];
+
+        let mut decoder = Decoder {
+            bytes: &bytes,
+            offset: 0,
+        };
+        let value = decoder.decode(&schema)?;
+
+        assert_eq!(decoder.offset, bytes.len());
+        assert_eq!(value.field("a").and_then(|x| x.as_i64()), Some(-2));
+        assert_eq!(value.field("b").and_then(|x| x.as_str()), Some("hi"));
+        assert_eq!(
+            value.field("c").map(|x| x.as_array().to_vec()),
+            Some(vec![AvroValue::Int(1), AvroValue::Int(2)])
+        );
+        assert_eq!(value.field("d"), Some(&AvroValue::Record(vec![])));
+
+        Ok(())
+    }
+}
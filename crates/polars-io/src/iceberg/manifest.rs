@@ -0,0 +1,189 @@
+use polars_core::prelude::*;
+
+use super::avro::{AvroValue, read_avro_rows};
+
+/// Field ID of the `file_path` column of position delete files.
+pub(super) const POSITION_DELETE_FILE_PATH_ID: u32 = 2147483546;
+
+/// An entry of a snapshot's manifest list.
+#[derive(Debug, Clone)]
+pub(super) struct ManifestFile {
+    pub path: String,
+    pub partition_spec_id: i32,
+    pub sequence_number: i64,
+    /// Number of rows in the live (added or existing) files of the manifest, if recorded.
+    pub num_live_rows: Option<u64>,
+    pub partitions: Vec<PartitionFieldSummary>,
+}
+
+#[derive(Debug, Clone)]
+pub(super) struct PartitionFieldSummary {
+    pub contains_null: bool,
+    pub lower_bound: Option<Vec<u8>>,
+    pub upper_bound: Option<Vec<u8>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum DataFileContent {
+    Data,
+    PositionDeletes,
+    EqualityDeletes,
+}
+
+/// A live (added or existing) file of a manifest.
+#[derive(Debug, Clone)]
+pub(super) struct ManifestEntry {
+    /// Data sequence number, with inheritance from the manifest applied.
+    pub sequence_number: i64,
+    pub content: DataFileContent,
+    pub file_path: String,
+    pub file_format: String,
+    /// The partition tuple, a record with a field for every field of the partition spec.
+    pub partition: AvroValue,
+    pub record_count: u64,
+    pub null_value_counts: PlHashMap<u32, u64>,
+    pub lower_bounds: PlHashMap<u32, Vec<u8>>,
+    pub upper_bounds: PlHashMap<u32, Vec<u8>>,
+    pub equality_ids: Vec<u32>,
+}
+
+pub(super) fn parse_manifest_list(bytes: &[u8]) -> PolarsResult<Vec<ManifestFile>> {
+    read_avro_rows(bytes)?
+        .iter()
+        .map(|row| {
+            let path = row
+                .field("manifest_path")
+                .and_then(|x| x.as_str())
+                .ok_or_else(
+                    || polars_err!(ComputeError: "iceberg manifest list entry has no manifest_path"),
+                )?
+                .to_string();
+
+            let count = |name: &str| row.field(name).and_then(|x| x.as_i64());
+            let num_live_rows = count("added_rows_count")
+                .zip(count("existing_rows_count"))
+                .and_then(|(a, b)| u64::try_from(a + b).ok());
+
+            let partitions = row
+                .field("partitions")
+                .map_or(&[][..], |x| x.as_array())
+                .iter()
+                .map(|x| PartitionFieldSummary {
+                    contains_null: x
+                        .field("contains_null")
+                        .and_then(|x| x.as_bool())
+                        .unwrap_or(true),
+                    lower_bound: x
+                        .field("lower_bound")
+                        .and_then(|x| x.as_bytes())
+                        .map(|x| x.to_vec()),
+                    upper_bound: x
+                        .field("upper_bound")
+                        .and_then(|x| x.as_bytes())
+                        .map(|x| x.to_vec()),
+                })
+                .collect();
+
+            Ok(ManifestFile {
+                path,
+                partition_spec_id: count("partition_spec_id").unwrap_or(0) as i32,
+                // Format version 1 has no sequence numbers, which is equivalent to 0.
+                sequence_number: count("sequence_number").unwrap_or(0),
+                num_live_rows,
+                partitions,
+            })
+        })
+        .collect()
+}
+
+/// Parses the entries of a manifest, skipping entries of deleted files.
+pub(super) fn parse_manifest(
+    bytes: &[u8],
+    manifest: &ManifestFile,
+) -> PolarsResult<Vec<ManifestEntry>> {
+    const STATUS_DELETED: i64 = 2;
+
+    let mut out = vec![];
+
+    for row in read_avro_rows(bytes)?.iter() {
+        let status = row.field("status").and_then(|x| x.as_i64());
+
+        if status == Some(STATUS_DELETED) {
+            continue;
+        }
+
+        let data_file = row.field("data_file").ok_or_else(
+            || polars_err!(ComputeError: "iceberg manifest entry has no data_file: {}", manifest.path),
+        )?;
+
+        let file_path = data_file
+            .field("file_path")
+            .and_then(|x| x.as_str())
+            .ok_or_else(
+                || polars_err!(ComputeError: "iceberg data file has no file_path: {}", manifest.path),
+            )?
+            .to_string();
+
+        let content = match data_file.field("content").and_then(|x| x.as_i64()) {
+            None | Some(0) => DataFileContent::Data,
+            Some(1) => DataFileContent::PositionDeletes,
+            Some(2) => DataFileContent::EqualityDeletes,
+            Some(v) => {
+                polars_bail!(ComputeError: "unknown iceberg data file content type {} for file {}", v, file_path)
+            },
+        };
+
+        out.push(ManifestEntry {
+            // Entries without a sequence number inherit the sequence number of the manifest.
+            sequence_number: row
+                .field("sequence_number")
+                .and_then(|x| x.as_i64())
+                .unwrap_or(manifest.sequence_number),
+            content,
+            file_format: data_file
+                .field("file_format")
+                .and_then(|x| x.as_str())
+                .unwrap_or("PARQUET")
+                .to_string(),
+            partition: data_file
+                .field("partition")
+                .cloned()
+                .unwrap_or(AvroValue::Record(vec![])),
+            record_count: data_file
+                .field("record_count")
+                .and_then(|x| x.as_i64())
+                .and_then(|x| u64::try_from(x).ok())
+                .unwrap_or(0),
+            null_value_counts: int_map(data_file.field("null_value_counts"), |v| {
+                u64::try_from(v.as_i64()?).ok()
+            }),
+            lower_bounds: int_map(data_file.field("lower_bounds"), |v| {
+                v.as_bytes().map(|x| x.to_vec())
+            }),
+            upper_bounds: int_map(data_file.field("upper_bounds"), |v| {
+                v.as_bytes().map(|x| x.to_vec())
+            }),
+            equality_ids: data_file
+                .field("equality_ids")
+                .map_or(&[][..], |x| x.as_array())
+                .iter()
+                .filter_map(|x| u32::try_from(x.as_i64()?).ok())
+                .collect(),
+            file_path,
+        });
+    }
+
+    Ok(out)
+}
+
+/// Maps keyed by field ID are stored as arrays of `key` / `value` records.
+fn int_map<T>(value: Option<&AvroValue>, f: impl Fn(&AvroValue) -> Option<T>) -> PlHashMap<u32, T> {
+    value
+        .map_or(&[][..], |x| x.as_array())
+        .iter()
+        .filter_map(|kv| {
+            let key = u32::try_from(kv.field("key")?.as_i64()?).ok()?;
+            Some((key, f(kv.field("value")?)?))
+        })
+        .collect()
+}
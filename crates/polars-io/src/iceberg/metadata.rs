@@ -0,0 +1,213 @@
+use polars_core::prelude::*;
+
+use super::schema::IcebergTableSchema;
+
+/// Maximum supported Iceberg table format version.
+const MAX_FORMAT_VERSION: u8 = 2;
+
+/// Snapshot of an Iceberg table to resolve.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum IcebergVersion {
+    #[default]
+    Latest,
+    SnapshotId(i64),
+    /// Latest snapshot that was current at or before this timestamp (milliseconds since the
+    /// epoch).
+    Timestamp(i64),
+}
+
+/// Contents of an Iceberg table metadata file (`*.metadata.json`).
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct IcebergTableMetadata {
+    pub format_version: u8,
+    pub location: String,
+    #[serde(default)]
+    pub current_schema_id: Option<i32>,
+    #[serde(default)]
+    pub schemas: Vec<IcebergTableSchema>,
+    /// Format version 1 only.
+    #[serde(default)]
+    pub schema: Option<IcebergTableSchema>,
+    #[serde(default)]
+    pub partition_specs: Vec<IcebergPartitionSpec>,
+    /// Format version 1 only.
+    #[serde(default)]
+    pub partition_spec: Option<Vec<IcebergPartitionField>>,
+    #[serde(default)]
+    pub current_snapshot_id: Option<i64>,
+    #[serde(default)]
+    pub snapshots: Vec<IcebergSnapshot>,
+    #[serde(default)]
+    pub snapshot_log: Vec<IcebergSnapshotLogEntry>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct IcebergSnapshot {
+    pub snapshot_id: i64,
+    /// Absent for format version 1.
+    #[serde(default)]
+    pub sequence_number: i64,
+    pub timestamp_ms: i64,
+    #[serde(default)]
+    pub manifest_list: Option<String>,
+    #[serde(default)]
+    pub schema_id: Option<i32>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct IcebergSnapshotLogEntry {
+    pub snapshot_id: i64,
+    pub timestamp_ms: i64,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct IcebergPartitionSpec {
+    pub spec_id: i32,
+    pub fields: Vec<IcebergPartitionField>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct IcebergPartitionField {
+    pub source_id: u32,
+    pub name: String,
+    pub transform: String,
+}
+
+impl IcebergTableMetadata {
+    pub fn try_from_json(bytes: &[u8]) -> PolarsResult<Self> {
+        let metadata: Self = crate::utils::decode_json_response(bytes)?;
+
+        polars_ensure!(
+            metadata.format_version <= MAX_FORMAT_VERSION,
+            ComputeError: "unsupported iceberg table format version: {}", metadata.format_version
+        );
+
+        Ok(metadata)
+    }
+
+    /// Resolves the snapshot to read. Returns `None` if the table has no snapshots.
+    pub fn snapshot(&self, version: IcebergVersion) -> PolarsResult<Option<&IcebergSnapshot>> {
+        let snapshot_id = match version {
+            // Format version 1 uses -1 to indicate that there is no current snapshot.
+            IcebergVersion::Latest => match self.current_snapshot_id {
+                Some(id) if id != -1 => id,
+                _ => return Ok(None),
+            },
+            IcebergVersion::SnapshotId(id) => id,
+            IcebergVersion::Timestamp(ts) => {
+                let from_log = self
+                    .snapshot_log
+                    .iter()
+                    .filter(|x| x.timestamp_ms <= ts)
+                    .max_by_key(|x| x.timestamp_ms)
+                    .map(|x| x.snapshot_id);
+
+                let from_snapshots = || {
+                    self.snapshots
+                        .iter()
+                        .filter(|x| x.timestamp_ms <= ts)
+                        .max_by_key(|x| x.timestamp_ms)
+                        .map(|x| x.snapshot_id)
+                };
+
+                from_log.or_else(from_snapshots).ok_or_else(|| {
+                    polars_err!(ComputeError: "iceberg table has no snapshot at or before timestamp {}", ts)
+                })?
+            },
+        };
+
+        self.snapshots
+            .iter()
+            .find(|x| x.snapshot_id == snapshot_id)
+            .map(Some)
+            .ok_or_else(
+                || polars_err!(ComputeError: "iceberg snapshot {} does not exist", snapshot_id),
+            )
+    }
+
+    /// Schema used to read the given snapshot. This is the current table schema unless a specific
+    /// snapshot was requested.
+    pub fn schema_for(&self, version: IcebergVersion) -> PolarsResult<&IcebergTableSchema> {
+        let schema_id = match version {
+            IcebergVersion::Latest => self.current_schema_id,
+            _ => self
+                .snapshot(version)?
+                .and_then(|x| x.schema_id)
+                .or(self.current_schema_id),
+        };
+
+        let schema = match schema_id {
+            Some(id) => self.schemas.iter().find(|x| x.schema_id == id),
+            None => self.schema.as_ref().or(self.schemas.last()),
+        };
+
+        schema.ok_or_else(
+            || polars_err!(ComputeError: "iceberg table metadata does not contain schema {:?}", schema_id),
+        )
+    }
+
+    pub fn partition_spec(&self, spec_id: i32) -> Option<&[IcebergPartitionField]> {
+        self.partition_specs
+            .iter()
+            .find(|x| x.spec_id == spec_id)
+            .map(|x| x.fields.as_slice())
+            .or_else(|| self.partition_spec.as_deref().filter(|_| spec_id == 0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_iceberg_snapshot() -> PolarsResult<()> {
+        let metadata = IcebergTableMetadata::try_from_json(
+            br#"{
+                "format-version": 2,
+                "table-uuid": "9c12d441-03fe-4693-9a96-a0705ddf69c1",
+                "location": "s3://bucket/table",
+                "last-sequence-number": 2,
+                "current-schema-id": 1,
+                "schemas": [
+                    {"type": "struct", "schema-id": 0, "fields": [{"id": 1, "name": "x", "required": true, "type": "long"}]},
+                    {"type": "struct", "schema-id": 1, "fields": [{"id": 1, "name": "y", "required": true, "type": "long"}]}
+                ],
+                "default-spec-id": 0,
+                "partition-specs": [{"spec-id": 0, "fields": []}],
+                "current-snapshot-id": 20,
+                "snapshots": [
+                    {"snapshot-id": 10, "sequence-number": 1, "timestamp-ms": 1000, "manifest-list": "s3://bucket/table/metadata/snap-10.avro", "schema-id": 0},
+                    {"snapshot-id": 20, "sequence-number": 2, "timestamp-ms": 2000, "manifest-list": "s3://bucket/table/metadata/snap-20.avro", "schema-id": 1}
+                ],
+                "snapshot-log": [
+                    {"snapshot-id": 10, "timestamp-ms": 1000},
+                    {"snapshot-id": 20, "timestamp-ms": 2000}
+                ]
+            }"#,
+        )?;
+
+        let snapshot_id = |v| PolarsResult::Ok(metadata.snapshot(v)?.map(|x| x.snapshot_id));
+
+        assert_eq!(snapshot_id(IcebergVersion::Latest)?, Some(20));
+        assert_eq!(snapshot_id(IcebergVersion::SnapshotId(10))?, Some(10));
+        assert_eq!(snapshot_id(IcebergVersion::Timestamp(1500))?, Some(10));
+        assert!(snapshot_id(IcebergVersion::Timestamp(500)).is_err());
+        assert!(snapshot_id(IcebergVersion::SnapshotId(30)).is_err());
+
+        assert_eq!(
+            metadata.schema_for(IcebergVersion::SnapshotId(10))?.fields[0].name,
+            "x"
+        );
+        assert_eq!(
+            metadata.schema_for(IcebergVersion::Latest)?.fields[0].name,
+            "y"
+        );
+
+        Ok(())
+    }
+}
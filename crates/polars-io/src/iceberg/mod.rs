@@ -0,0 +1,18 @@
+//! Reading of Apache Iceberg tables.
+//!
+//! The data and delete files of a table snapshot are resolved from the table metadata file, the
+//! snapshot's manifest list and the Avro manifests it references.
+mod avro;
+mod manifest;
+mod metadata;
+mod schema;
+mod table;
+
+pub use metadata::{
+    IcebergPartitionField, IcebergPartitionSpec, IcebergSnapshot, IcebergSnapshotLogEntry,
+    IcebergTableMetadata, IcebergVersion,
+};
+pub use schema::{
+    IcebergNestedField, IcebergNestedType, IcebergPrimitiveType, IcebergTableSchema, IcebergType,
+};
+pub use table::{IcebergDataFile, IcebergEqualityDeleteFile, IcebergScanFiles, IcebergTable};
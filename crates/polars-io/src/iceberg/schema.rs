@@ -0,0 +1,402 @@
+use polars_core::prelude::*;
+use polars_core::schema::iceberg::{
+    IcebergColumn, IcebergColumnType, IcebergSchema, LIST_ELEMENT_DEFAULT_ID,
+};
+
+use super::avro::AvroValue;
+
+/// A schema from the Iceberg table metadata.
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct IcebergTableSchema {
+    #[serde(default)]
+    pub schema_id: i32,
+    pub fields: Vec<IcebergNestedField>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct IcebergNestedField {
+    pub id: u32,
+    pub name: PlSmallStr,
+    #[serde(rename = "type")]
+    pub type_: IcebergType,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(untagged)]
+pub enum IcebergType {
+    /// Primitive types, e.g. `"long"` or `"decimal(10,2)"`.
+    Primitive(String),
+    Nested(Box<IcebergNestedType>),
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(tag = "type", rename_all = "kebab-case")]
+pub enum IcebergNestedType {
+    Struct {
+        fields: Vec<IcebergNestedField>,
+    },
+    List {
+        #[serde(rename = "element-id")]
+        element_id: u32,
+        element: IcebergType,
+    },
+    Map {
+        #[serde(rename = "key-id")]
+        key_id: u32,
+        key: IcebergType,
+        #[serde(rename = "value-id")]
+        value_id: u32,
+        value: IcebergType,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IcebergPrimitiveType {
+    Boolean,
+    Int,
+    Long,
+    Float,
+    Double,
+    Decimal { precision: usize, scale: usize },
+    Date,
+    Time,
+    Timestamp,
+    Timestamptz,
+    TimestampNs,
+    TimestamptzNs,
+    String,
+    Uuid,
+    Fixed(usize),
+    Binary,
+}
+
+impl IcebergTableSchema {
+    /// Schema with the physical field IDs, used to map the columns of the data files.
+    pub fn to_iceberg_schema(&self) -> PolarsResult<IcebergSchema> {
+        self.fields
+            .iter()
+            .map(|f| Ok((f.id, to_iceberg_column(f.name.clone(), f.id, &f.type_)?)))
+            .collect()
+    }
+
+    pub fn to_polars_schema(&self) -> PolarsResult<Schema> {
+        Ok(self
+            .to_iceberg_schema()?
+            .values()
+            .map(|col| Field::new(col.name.clone(), col.type_.to_polars_dtype()))
+            .collect())
+    }
+
+    /// Returns the top-level field with the given ID.
+    pub fn field_by_id(&self, id: u32) -> Option<&IcebergNestedField> {
+        self.fields.iter().find(|f| f.id == id)
+    }
+}
+
+impl IcebergType {
+    pub fn as_primitive(&self) -> PolarsResult<Option<IcebergPrimitiveType>> {
+        match self {
+            IcebergType::Primitive(name) => IcebergPrimitiveType::parse(name).map(Some),
+            IcebergType::Nested(_) => Ok(None),
+        }
+    }
+}
+
+fn to_iceberg_column(
+    name: PlSmallStr,
+    physical_id: u32,
+    type_: &IcebergType,
+) -> PolarsResult<IcebergColumn> {
+    let nested = match type_ {
+        IcebergType::Primitive(v) => {
+            return Ok(IcebergColumn {
+                name,
+                physical_id,
+                type_: IcebergColumnType::Primitive {
+                    dtype: IcebergPrimitiveType::parse(v)?.to_dtype()?,
+                },
+            });
+        },
+        IcebergType::Nested(nested) => nested,
+    };
+
+    let type_ = match nested.as_ref() {
+        IcebergNestedType::Struct { fields } => IcebergColumnType::Struct(
+            fields
+                .iter()
+                .map(|f| Ok((f.id, to_iceberg_column(f.name.clone(), f.id, &f.type_)?)))
+                .collect::<PolarsResult<_>>()?,
+        ),
+        IcebergNestedType::List {
+            element_id,
+            element,
+        } => IcebergColumnType::List(Box::new(to_iceberg_column(
+            PlSmallStr::from_static("element"),
+            *element_id,
+            element,
+        )?)),
+        // Maps are read as a list of key/value structs.
+        IcebergNestedType::Map {
+            key_id,
+            key,
+            value_id,
+            value,
+        } => IcebergColumnType::List(Box::new(IcebergColumn {
+            name: PlSmallStr::from_static("key_value"),
+            physical_id: LIST_ELEMENT_DEFAULT_ID,
+            type_: IcebergColumnType::Struct(IcebergSchema::from_iter([
+                (
+                    *key_id,
+                    to_iceberg_column(PlSmallStr::from_static("key"), *key_id, key)?,
+                ),
+                (
+                    *value_id,
+                    to_iceberg_column(PlSmallStr::from_static("value"), *value_id, value)?,
+                ),
+            ])),
+        })),
+    };
+
+    Ok(IcebergColumn {
+        name,
+        physical_id,
+        type_,
+    })
+}
+
+impl IcebergPrimitiveType {
+    pub fn parse(name: &str) -> PolarsResult<Self> {
+        use IcebergPrimitiveType::*;
+
+        Ok(match name {
+            "boolean" => Boolean,
+            "int" => Int,
+            "long" => Long,
+            "float" => Float,
+            "double" => Double,
+            "date" => Date,
+            "time" => Time,
+            "timestamp" => Timestamp,
+            "timestamptz" => Timestamptz,
+            "timestamp_ns" => TimestampNs,
+            "timestamptz_ns" => TimestamptzNs,
+            "string" => String,
+            "uuid" => Uuid,
+            "binary" => Binary,
+            v => {
+                // e.g. decimal(38, 18) or fixed[16]
+                let parsed = if let Some(args) =
+                    v.strip_prefix("decimal(").and_then(|x| x.strip_suffix(')'))
+                {
+                    args.split_once(',').and_then(|(p, s)| {
+                        Some(Decimal {
+                            precision: p.trim().parse().ok()?,
+                            scale: s.trim().parse().ok()?,
+                        })
+                    })
+                } else {
+                    v.strip_prefix("fixed[")
+                        .and_then(|x| x.strip_suffix(']'))
+                        .and_then(|x| Some(Fixed(x.trim().parse().ok()?)))
+                };
+
+                parsed.ok_or_else(
+                    || polars_err!(ComputeError: "unsupported iceberg data type: {}", v),
+                )?
+            },
+        })
+    }
+
+    pub fn to_dtype(&self) -> PolarsResult<DataType> {
+        use IcebergPrimitiveType as I;
+
+        Ok(match self {
+            I::Boolean => DataType::Boolean,
+            I::Int => DataType::Int32,
+            I::Long => DataType::Int64,
+            I::Float => DataType::Float32,
+            I::Double => DataType::Float64,
+            I::Decimal { precision, scale } => {
+                #[cfg(feature = "dtype-decimal")]
+                {
+                    DataType::Decimal(*precision, *scale)
+                }
+                #[cfg(not(feature = "dtype-decimal"))]
+                {
+                    let _ = (precision, scale);
+                    polars_bail!(ComputeError: "reading iceberg decimal columns requires the 'dtype-decimal' feature")
+                }
+            },
+            I::Date => DataType::Date,
+            I::Time => DataType::Time,
+            I::Timestamp => DataType::Datetime(TimeUnit::Microseconds, None),
+            I::Timestamptz => DataType::Datetime(TimeUnit::Microseconds, Some(TimeZone::UTC)),
+            I::TimestampNs => DataType::Datetime(TimeUnit::Nanoseconds, None),
+            I::TimestamptzNs => DataType::Datetime(TimeUnit::Nanoseconds, Some(TimeZone::UTC)),
+            I::String => DataType::String,
+            I::Uuid | I::Fixed(_) | I::Binary => DataType::Binary,
+        })
+    }
+
+    /// Whether the column bounds of this type can be used to skip files.
+    ///
+    /// Float bounds are not used as they do not account for NaN values.
+    pub fn supports_statistics(&self) -> bool {
+        !matches!(self, Self::Float | Self::Double)
+    }
+
+    /// Decodes a value stored using the Iceberg single-value binary serialization, as used for
+    /// column bounds and partition field summaries.
+    pub fn decode_bound(&self, bytes: &[u8]) -> Option<AnyValue<'static>> {
+        use IcebergPrimitiveType as I;
+
+        // Values of promoted types (int -> long, float -> double) are stored using the width of
+        // the type at the time of writing.
+        let int = || -> Option<i64> {
+            Some(match bytes.len() {
+                4 => i64::from(i32::from_le_bytes(bytes.try_into().ok()?)),
+                8 => i64::from_le_bytes(bytes.try_into().ok()?),
+                _ => return None,
+            })
+        };
+
+        Some(match self {
+            I::Boolean => AnyValue::Boolean(*bytes.first()? != 0),
+            I::Int => AnyValue::Int32(i32::from_le_bytes(bytes.try_into().ok()?)),
+            I::Long => AnyValue::Int64(int()?),
+            I::Float => AnyValue::Float32(f32::from_le_bytes(bytes.try_into().ok()?)),
+            I::Double => AnyValue::Float64(match bytes.len() {
+                4 => f64::from(f32::from_le_bytes(bytes.try_into().ok()?)),
+                _ => f64::from_le_bytes(bytes.try_into().ok()?),
+            }),
+            I::Decimal { precision, scale } => {
+                #[cfg(feature = "dtype-decimal")]
+                {
+                    // Big-endian two's complement of the unscaled value.
+                    if bytes.is_empty() || bytes.len() > 16 {
+                        return None;
+                    }
+                    let fill = if bytes[0] & 0x80 != 0 { 0xFF } else { 0 };
+                    let mut buf = [fill; 16];
+                    buf[16 - bytes.len()..].copy_from_slice(bytes);
+                    AnyValue::Decimal(i128::from_be_bytes(buf), *precision, *scale)
+                }
+                #[cfg(not(feature = "dtype-decimal"))]
+                {
+                    let _ = (precision, scale);
+                    return None;
+                }
+            },
+            I::Date => AnyValue::Date(i32::from_le_bytes(bytes.try_into().ok()?)),
+            I::Time => AnyValue::Time(int()? * 1000),
+            I::Timestamp | I::Timestamptz | I::TimestampNs | I::TimestamptzNs => {
+                self.datetime_value(int()?)
+            },
+            I::String => AnyValue::StringOwned(std::str::from_utf8(bytes).ok()?.into()),
+            I::Uuid | I::Fixed(_) | I::Binary => AnyValue::BinaryOwned(bytes.to_vec()),
+        })
+    }
+
+    /// Converts a partition value read from a manifest.
+    pub(super) fn decode_avro(&self, value: &AvroValue) -> Option<AnyValue<'static>> {
+        use IcebergPrimitiveType as I;
+
+        Some(match self {
+            I::Boolean => AnyValue::Boolean(value.as_bool()?),
+            I::Int => AnyValue::Int32(i32::try_from(value.as_i64()?).ok()?),
+            I::Long => AnyValue::Int64(value.as_i64()?),
+            I::Float => AnyValue::Float32(value.as_f64()? as f32),
+            I::Double => AnyValue::Float64(value.as_f64()?),
+            I::Decimal { .. } => return self.decode_bound(value.as_bytes()?),
+            I::Date => AnyValue::Date(i32::try_from(value.as_i64()?).ok()?),
+            I::Time => AnyValue::Time(value.as_i64()? * 1000),
+            I::Timestamp | I::Timestamptz | I::TimestampNs | I::TimestamptzNs => {
+                self.datetime_value(value.as_i64()?)
+            },
+            I::String => AnyValue::StringOwned(value.as_str()?.into()),
+            I::Uuid | I::Fixed(_) | I::Binary => AnyValue::BinaryOwned(value.as_bytes()?.to_vec()),
+        })
+    }
+
+    fn datetime_value(&self, v: i64) -> AnyValue<'static> {
+        use IcebergPrimitiveType as I;
+
+        let (tu, tz) = match self {
+            I::Timestamp => (TimeUnit::Microseconds, None),
+            I::Timestamptz => (TimeUnit::Microseconds, Some(Arc::new(TimeZone::UTC))),
+            I::TimestampNs => (TimeUnit::Nanoseconds, None),
+            I::TimestamptzNs => (TimeUnit::Nanoseconds, Some(Arc::new(TimeZone::UTC))),
+            _ => unreachable!(),
+        };
+
+        AnyValue::DatetimeOwned(v, tu, tz)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_iceberg_schema() -> PolarsResult<()> {
+        let schema: IcebergTableSchema = serde_json::from_str(
+            r#"{"type":"struct","schema-id":0,"fields":[
+                {"id":1,"name":"id","required":true,"type":"long"},
+                {"id":2,"name":"ts","required":false,"type":"timestamptz"},
+                {"id":3,"name":"tags","required":false,"type":{"type":"list","element-id":5,"element":"string","element-required":false}},
+                {"id":4,"name":"attrs","required":false,"type":{"type":"map","key-id":6,"key":"string","value-id":7,"value":"int","value-required":false}}
+            ]}"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            schema.to_polars_schema()?,
+            Schema::from_iter([
+                Field::new("id".into(), DataType::Int64),
+                Field::new(
+                    "ts".into(),
+                    DataType::Datetime(TimeUnit::Microseconds, Some(TimeZone::UTC))
+                ),
+                Field::new("tags".into(), DataType::List(Box::new(DataType::String))),
+                Field::new(
+                    "attrs".into(),
+                    DataType::List(Box::new(DataType::Struct(vec![
+                        Field::new("key".into(), DataType::String),
+                        Field::new("value".into(), DataType::Int32),
+                    ])))
+                ),
+            ])
+        );
+
+        let iceberg_schema = schema.to_iceberg_schema()?;
+        assert_eq!(
+            iceberg_schema.keys().copied().collect::<Vec<_>>(),
+            [1, 2, 3, 4]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_decode_bound() {
+        assert_eq!(
+            IcebergPrimitiveType::Long.decode_bound(&5i32.to_le_bytes()),
+            Some(AnyValue::Int64(5))
+        );
+        assert_eq!(
+            IcebergPrimitiveType::String.decode_bound(b"abc"),
+            Some(AnyValue::StringOwned("abc".into()))
+        );
+        #[cfg(feature = "dtype-decimal")]
+        assert_eq!(
+            IcebergPrimitiveType::Decimal {
+                precision: 10,
+                scale: 2
+            }
+            .decode_bound(&[0xFF, 0x38]),
+            Some(AnyValue::Decimal(-200, 10, 2))
+        );
+        assert_eq!(IcebergPrimitiveType::Int.decode_bound(&[1, 2]), None);
+    }
+}
@@ -0,0 +1,689 @@
+use polars_buffer::Buffer;
+use polars_core::prelude::*;
+use polars_error::to_compute_err;
+use polars_utils::pl_path::PlRefPath;
+use polars_utils::{IdxSize, format_pl_smallstr};
+
+use super::avro::AvroValue;
+use super::manifest::{
+    DataFileContent, ManifestEntry, ManifestFile, POSITION_DELETE_FILE_PATH_ID, parse_manifest,
+    parse_manifest_list,
+};
+use super::metadata::{IcebergPartitionField, IcebergTableMetadata, IcebergVersion};
+use super::schema::{IcebergPrimitiveType, IcebergTableSchema};
+use crate::cloud::CloudOptions;
+use crate::path_utils::expand_paths;
+use crate::predicates::{ColumnStatistics, ScanIOPredicate};
+use crate::utils::byte_source::{ByteSource, DynByteSourceBuilder};
+
+/// An Iceberg table, loaded from a table metadata file.
+#[derive(Debug, Clone)]
+pub struct IcebergTable {
+    /// Path of the metadata file that was read.
+    pub metadata_location: String,
+    pub metadata: IcebergTableMetadata,
+}
+
+/// The files to scan for a snapshot of an Iceberg table.
+#[derive(Debug, Clone)]
+pub struct IcebergScanFiles {
+    pub schema: IcebergTableSchema,
+    pub data_files: Vec<IcebergDataFile>,
+    pub equality_deletes: Vec<IcebergEqualityDeleteFile>,
+}
+
+#[derive(Debug, Clone)]
+pub struct IcebergDataFile {
+    /// Absolute path of the data file.
+    pub path: String,
+    pub record_count: u64,
+    /// Paths of the position delete files that apply to this file.
+    pub position_deletes: Vec<String>,
+    /// Indices into [`IcebergScanFiles::equality_deletes`] of the equality delete files that apply
+    /// to this file.
+    pub equality_deletes: Vec<usize>,
+    /// Values of the identity-transformed partition fields, keyed by source field ID.
+    pub partition_values: PlHashMap<u32, AnyValue<'static>>,
+    /// Statistics of top-level columns, keyed by field ID.
+    pub statistics: PlHashMap<u32, ColumnStatistics>,
+}
+
+#[derive(Debug, Clone)]
+pub struct IcebergEqualityDeleteFile {
+    /// Absolute path of the delete file.
+    pub path: String,
+    /// Field IDs of the columns used to match deleted rows.
+    pub equality_ids: Vec<u32>,
+}
+
+/// A top-level column of the table schema.
+struct TableColumn {
+    id: u32,
+    name: PlSmallStr,
+    dtype: DataType,
+    /// `None` for nested columns.
+    primitive: Option<IcebergPrimitiveType>,
+}
+
+impl IcebergTable {
+    /// Loads the table metadata. `table_uri` is either the path of a metadata file, or the table
+    /// root, in which case the latest metadata file in its `metadata` directory is used.
+    pub async fn try_new(
+        table_uri: &str,
+        cloud_options: Option<&CloudOptions>,
+    ) -> PolarsResult<Self> {
+        let metadata_location = resolve_metadata_location(table_uri, cloud_options).await?;
+
+        if polars_core::config::verbose() {
+            eprintln!("[IcebergTable]: table: {table_uri}, metadata file: {metadata_location}")
+        }
+
+        let bytes = read_file(&metadata_location, cloud_options).await?;
+        let metadata = IcebergTableMetadata::try_from_json(&bytes)?;
+
+        Ok(Self {
+            metadata_location,
+            metadata,
+        })
+    }
+
+    /// Resolves the data and delete files of a snapshot.
+    ///
+    /// If a `predicate` is given, manifests and data files that cannot contain matching rows are
+    /// pruned using the partition summaries and column bounds of the table. The predicate must
+    /// still be applied to the scanned rows.
+    pub async fn scan_files(
+        &self,
+        version: IcebergVersion,
+        predicate: Option<&ScanIOPredicate>,
+        cloud_options: Option<&CloudOptions>,
+    ) -> PolarsResult<IcebergScanFiles> {
+        let verbose = polars_core::config::verbose();
+        let schema = self.metadata.schema_for(version)?.clone();
+        let columns = table_columns(&schema)?;
+
+        let mut out = IcebergScanFiles {
+            schema,
+            data_files: vec![],
+            equality_deletes: vec![],
+        };
+
+        let Some(snapshot) = self.metadata.snapshot(version)? else {
+            return Ok(out);
+        };
+
+        let Some(manifest_list) = snapshot.manifest_list.as_deref() else {
+            polars_bail!(
+                nyi = "reading iceberg snapshots without a manifest list (snapshot {})",
+                snapshot.snapshot_id
+            )
+        };
+
+        let manifests = parse_manifest_list(&read_file(manifest_list, cloud_options).await?)?;
+        let num_manifests = manifests.len();
+
+        let mut selected_manifests = Vec::with_capacity(num_manifests);
+
+        for manifest in manifests {
+            let spec = self.partition_spec(manifest.partition_spec_id)?;
+
+            if let Some(predicate) = predicate
+                && can_skip_manifest(&manifest, spec, &columns, predicate)?
+            {
+                continue;
+            }
+
+            selected_manifests.push(manifest);
+        }
+
+        let manifest_entries =
+            futures::future::try_join_all(selected_manifests.iter().map(|manifest| async move {
+                let bytes = read_file(&manifest.path, cloud_options).await?;
+                parse_manifest(&bytes, manifest)
+            }))
+            .await?;
+
+        let mut data_files: Vec<(IcebergDataFile, PartitionKey, i64, String)> = vec![];
+        let mut position_deletes: PlHashMap<PartitionKey, Vec<ManifestEntry>> = PlHashMap::new();
+        let mut equality_deletes: Vec<(Option<PartitionKey>, i64)> = vec![];
+        let mut num_pruned_files: usize = 0;
+
+        for (manifest, entries) in selected_manifests.iter().zip(manifest_entries) {
+            let spec = self.partition_spec(manifest.partition_spec_id)?;
+
+            for entry in entries {
+                polars_ensure!(
+                    entry.file_format.eq_ignore_ascii_case("parquet"),
+                    ComputeError: "unsupported iceberg file format '{}' for file {}",
+                    entry.file_format, entry.file_path
+                );
+
+                let partition_key = PartitionKey::new(manifest.partition_spec_id, &entry.partition);
+
+                match entry.content {
+                    DataFileContent::Data => {
+                        let data_file = to_data_file(&entry, spec, &columns);
+
+                        if let Some(predicate) = predicate
+                            && can_skip_data_file(&data_file, &columns, predicate)?
+                        {
+                            num_pruned_files += 1;
+                            continue;
+                        }
+
+                        data_files.push((
+                            data_file,
+                            partition_key,
+                            entry.sequence_number,
+                            entry.file_path,
+                        ));
+                    },
+                    DataFileContent::PositionDeletes => position_deletes
+                        .entry(partition_key)
+                        .or_default()
+                        .push(entry),
+                    DataFileContent::EqualityDeletes => {
+                        polars_ensure!(
+                            !entry.equality_ids.is_empty(),
+                            ComputeError: "iceberg equality delete file has no equality_ids: {}",
+                            entry.file_path
+                        );
+
+                        // Equality deletes of an unpartitioned spec apply to all partitions.
+                        equality_deletes.push((
+                            (!spec.is_empty()).then_some(partition_key),
+                            entry.sequence_number,
+                        ));
+                        out.equality_deletes.push(IcebergEqualityDeleteFile {
+                            path: resolve_file_path(&entry.file_path),
+                            equality_ids: entry.equality_ids,
+                        });
+                    },
+                }
+            }
+        }
+
+        for (mut data_file, partition_key, sequence_number, raw_path) in data_files {
+            // A position delete applies to data files with a sequence number less than or equal
+            // to its own.
+            if let Some(deletes) = position_deletes.get(&partition_key) {
+                data_file.position_deletes = deletes
+                    .iter()
+                    .filter(|d| {
+                        d.sequence_number >= sequence_number
+                            && may_reference_data_file(d, &raw_path)
+                    })
+                    .map(|d| resolve_file_path(&d.file_path))
+                    .collect();
+            }
+
+            // An equality delete applies to data files with a strictly smaller sequence number.
+            data_file.equality_deletes = equality_deletes
+                .iter()
+                .enumerate()
+                .filter(|(_, (key, delete_sequence_number))| {
+                    *delete_sequence_number > sequence_number
+                        && key.as_ref().is_none_or(|k| k == &partition_key)
+                })
+                .map(|(i, _)| i)
+                .collect();
+
+            out.data_files.push(data_file);
+        }
+
+        if verbose {
+            eprintln!(
+                "[IcebergTable]: snapshot: {}, manifests: {} / {}, data files: {} (pruned: {}), \
+                files with position deletes: {}, equality delete files: {}",
+                snapshot.snapshot_id,
+                selected_manifests.len(),
+                num_manifests,
+                out.data_files.len(),
+                num_pruned_files,
+                out.data_files
+                    .iter()
+                    .filter(|x| !x.position_deletes.is_empty())
+                    .count(),
+                out.equality_deletes.len(),
+            )
+        }
+
+        Ok(out)
+    }
+
+    fn partition_spec(&self, spec_id: i32) -> PolarsResult<&[IcebergPartitionField]> {
+        self.metadata.partition_spec(spec_id).ok_or_else(
+            || polars_err!(ComputeError: "iceberg partition spec {} does not exist", spec_id),
+        )
+    }
+}
+
+impl IcebergScanFiles {
+    /// Per-file column statistics in the layout used for skipping files with a predicate: a `len`
+    /// column, followed by `{col}_nc`, `{col}_min` and `{col}_max` columns for every top-level
+    /// column.
+    pub fn table_statistics(&self, file_indices: &[usize]) -> PolarsResult<DataFrame> {
+        let files: Vec<&IcebergDataFile> =
+            file_indices.iter().map(|&i| &self.data_files[i]).collect();
+        let height = files.len();
+
+        let mut out = Vec::with_capacity(1 + 3 * self.schema.fields.len());
+
+        out.push(Column::new(
+            PlSmallStr::from_static("len"),
+            files
+                .iter()
+                .map(|f| IdxSize::try_from(f.record_count).unwrap_or(IdxSize::MAX))
+                .collect::<Vec<_>>(),
+        ));
+
+        for column in table_columns(&self.schema)? {
+            let stats: Vec<Option<&ColumnStatistics>> =
+                files.iter().map(|f| f.statistics.get(&column.id)).collect();
+
+            let name_nc = format_pl_smallstr!("{}_nc", column.name);
+            let name_min = format_pl_smallstr!("{}_min", column.name);
+            let name_max = format_pl_smallstr!("{}_max", column.name);
+
+            if stats.iter().all(|x| x.is_none()) {
+                out.extend([
+                    Column::full_null(name_nc, height, &IDX_DTYPE),
+                    Column::full_null(name_min, height, &column.dtype),
+                    Column::full_null(name_max, height, &column.dtype),
+                ]);
+                continue;
+            }
+
+            let values = |f: fn(&ColumnStatistics) -> &AnyValue<'static>| {
+                stats
+                    .iter()
+                    .map(|x| x.map_or(AnyValue::Null, |x| f(x).clone()))
+                    .collect::<Vec<_>>()
+            };
+
+            out.extend([
+                Column::new(
+                    name_nc,
+                    stats
+                        .iter()
+                        .map(|x| x.and_then(|x| x.null_count))
+                        .collect::<Vec<_>>(),
+                ),
+                Series::from_any_values_and_dtype(
+                    name_min,
+                    &values(|x| &x.min),
+                    &column.dtype,
+                    false,
+                )?
+                .into_column(),
+                Series::from_any_values_and_dtype(
+                    name_max,
+                    &values(|x| &x.max),
+                    &column.dtype,
+                    false,
+                )?
+                .into_column(),
+            ]);
+        }
+
+        DataFrame::new(height, out)
+    }
+
+    /// Values of the identity-transformed partition fields, keyed by source field ID. These are
+    /// used for columns that are not present in the data files.
+    pub fn identity_partition_values(
+        &self,
+        file_indices: &[usize],
+    ) -> PolarsResult<PlIndexMap<u32, Column>> {
+        let mut out = PlIndexMap::new();
+
+        for column in table_columns(&self.schema)? {
+            if !file_indices
+                .iter()
+                .any(|&i| self.data_files[i].partition_values.contains_key(&column.id))
+            {
+                continue;
+            }
+
+            let values: Vec<AnyValue<'static>> = file_indices
+                .iter()
+                .map(|&i| {
+                    self.data_files[i]
+                        .partition_values
+                        .get(&column.id)
+                        .cloned()
+                        .unwrap_or(AnyValue::Null)
+                })
+                .collect();
+
+            out.insert(
+                column.id,
+                Series::from_any_values_and_dtype(column.name, &values, &column.dtype, false)?
+                    .into_column(),
+            );
+        }
+
+        Ok(out)
+    }
+}
+
+fn table_columns(schema: &IcebergTableSchema) -> PolarsResult<Vec<TableColumn>> {
+    let polars_schema = schema.to_polars_schema()?;
+
+    schema
+        .fields
+        .iter()
+        .zip(polars_schema.iter())
+        .map(|(field, (name, dtype))| {
+            Ok(TableColumn {
+                id: field.id,
+                name: name.clone(),
+                dtype: dtype.clone(),
+                primitive: field.type_.as_primitive()?,
+            })
+        })
+        .collect()
+}
+
+/// Identifies the partition of a file: the partition spec ID together with the partition tuple.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct PartitionKey(i32, String);
+
+impl PartitionKey {
+    fn new(spec_id: i32, partition: &AvroValue) -> Self {
+        Self(spec_id, format!("{partition:?}"))
+    }
+}
+
+fn to_data_file(
+    entry: &ManifestEntry,
+    spec: &[IcebergPartitionField],
+    columns: &[TableColumn],
+) -> IcebergDataFile {
+    let mut partition_values = PlHashMap::new();
+    let mut statistics = PlHashMap::new();
+
+    for column in columns {
+        let Some(primitive) = column.primitive.filter(|x| x.supports_statistics()) else {
+            continue;
+        };
+
+        let bound = |bounds: &PlHashMap<u32, Vec<u8>>| {
+            bounds
+                .get(&column.id)
+                .and_then(|x| primitive.decode_bound(x))
+                .unwrap_or(AnyValue::Null)
+        };
+
+        statistics.insert(
+            column.id,
+            ColumnStatistics {
+                dtype: column.dtype.clone(),
+                min: bound(&entry.lower_bounds),
+                max: bound(&entry.upper_bounds),
+                null_count: entry
+                    .null_value_counts
+                    .get(&column.id)
+                    .and_then(|&x| IdxSize::try_from(x).ok()),
+            },
+        );
+    }
+
+    for (i, partition_field) in spec.iter().enumerate() {
+        if partition_field.transform != "identity" {
+            continue;
+        }
+
+        let Some(column) = columns.iter().find(|c| c.id == partition_field.source_id) else {
+            continue;
+        };
+
+        let Some(primitive) = column.primitive else {
+            continue;
+        };
+
+        let AvroValue::Record(values) = &entry.partition else {
+            continue;
+        };
+
+        let value = values
+            .get(i)
+            .filter(|(name, _)| name == &partition_field.name)
+            .or_else(|| {
+                values
+                    .iter()
+                    .find(|(name, _)| name == &partition_field.name)
+            })
+            .and_then(|(_, v)| primitive.decode_avro(v))
+            .unwrap_or(AnyValue::Null);
+
+        // The partition value is exact for all rows of the file.
+        let null_count = if value.is_null() {
+            IdxSize::try_from(entry.record_count).ok()
+        } else {
+            Some(0)
+        };
+
+        if primitive.supports_statistics() {
+            statistics.insert(
+                column.id,
+                ColumnStatistics {
+                    dtype: column.dtype.clone(),
+                    min: value.clone(),
+                    max: value.clone(),
+                    null_count,
+                },
+            );
+        }
+
+        partition_values.insert(column.id, value);
+    }
+
+    IcebergDataFile {
+        path: resolve_file_path(&entry.file_path),
+        record_count: entry.record_count,
+        position_deletes: vec![],
+        equality_deletes: vec![],
+        partition_values,
+        statistics,
+    }
+}
+
+/// Checks the partition summaries of the identity-transformed partition fields of a manifest.
+fn can_skip_manifest(
+    manifest: &ManifestFile,
+    spec: &[IcebergPartitionField],
+    columns: &[TableColumn],
+    predicate: &ScanIOPredicate,
+) -> PolarsResult<bool> {
+    let Some(skip_batch_predicate) = &predicate.skip_batch_predicate else {
+        return Ok(false);
+    };
+
+    let mut statistics = PlIndexMap::new();
+
+    for (partition_field, summary) in spec.iter().zip(&manifest.partitions) {
+        if partition_field.transform != "identity" {
+            continue;
+        }
+
+        let Some(column) = columns.iter().find(|c| c.id == partition_field.source_id) else {
+            continue;
+        };
+
+        let Some(primitive) = column.primitive.filter(|x| x.supports_statistics()) else {
+            continue;
+        };
+
+        if !predicate.live_columns.contains(&column.name) {
+            continue;
+        }
+
+        let bound = |bound: &Option<Vec<u8>>| {
+            bound
+                .as_deref()
+                .and_then(|x| primitive.decode_bound(x))
+                .unwrap_or(AnyValue::Null)
+        };
+
+        statistics.insert(
+            column.name.clone(),
+            ColumnStatistics {
+                dtype: column.dtype.clone(),
+                min: bound(&summary.lower_bound),
+                max: bound(&summary.upper_bound),
+                null_count: (!summary.contains_null).then_some(0),
+            },
+        );
+    }
+
+    if statistics.is_empty() {
+        return Ok(false);
+    }
+
+    let num_rows = manifest
+        .num_live_rows
+        .and_then(|x| IdxSize::try_from(x).ok())
+        .unwrap_or(IdxSize::MAX);
+
+    skip_batch_predicate.can_skip_batch(num_rows, &predicate.live_columns, statistics)
+}
+
+fn can_skip_data_file(
+    data_file: &IcebergDataFile,
+    columns: &[TableColumn],
+    predicate: &ScanIOPredicate,
+) -> PolarsResult<bool> {
+    let Some(skip_batch_predicate) = &predicate.skip_batch_predicate else {
+        return Ok(false);
+    };
+
+    let statistics = columns
+        .iter()
+        .filter(|column| predicate.live_columns.contains(&column.name))
+        .filter_map(|column| {
+            Some((
+                column.name.clone(),
+                data_file.statistics.get(&column.id)?.clone(),
+            ))
+        })
+        .collect::<PlIndexMap<_, _>>();
+
+    if statistics.is_empty() {
+        return Ok(false);
+    }
+
+    skip_batch_predicate.can_skip_batch(
+        IdxSize::try_from(data_file.record_count).unwrap_or(IdxSize::MAX),
+        &predicate.live_columns,
+        statistics,
+    )
+}
+
+/// Position delete files record the `file_path` bounds, which allows skipping delete files that
+/// only reference other data files.
+fn may_reference_data_file(delete: &ManifestEntry, data_file_path: &str) -> bool {
+    fn bound(bounds: &PlHashMap<u32, Vec<u8>>) -> Option<&str> {
+        bounds
+            .get(&POSITION_DELETE_FILE_PATH_ID)
+            .and_then(|x| std::str::from_utf8(x).ok())
+    }
+
+    bound(&delete.lower_bounds).is_none_or(|lower| lower <= data_file_path)
+        && bound(&delete.upper_bounds).is_none_or(|upper| data_file_path <= upper)
+}
+
+async fn resolve_metadata_location(
+    table_uri: &str,
+    cloud_options: Option<&CloudOptions>,
+) -> PolarsResult<String> {
+    if table_uri.ends_with(".metadata.json") {
+        return Ok(table_uri.to_string());
+    }
+
+    let metadata_dir = format!("{}/metadata", table_uri.trim_end_matches('/'));
+    let paths = expand_paths(
+        &[PlRefPath::new(format!("{metadata_dir}/"))],
+        false,
+        &[],
+        &mut cloud_options.cloned(),
+    )
+    .await?;
+
+    if let Some(hint_path) = paths
+        .iter()
+        .find(|x| x.as_str().ends_with("/version-hint.text"))
+    {
+        let bytes = read_file(hint_path.as_str(), cloud_options).await?;
+        let hint = std::str::from_utf8(&bytes).map_err(to_compute_err)?.trim();
+
+        return Ok(if hint.bytes().all(|b| b.is_ascii_digit()) {
+            format!("{metadata_dir}/v{hint}.metadata.json")
+        } else {
+            format!("{metadata_dir}/{hint}")
+        });
+    }
+
+    // Metadata files are named either `v{version}.metadata.json` or
+    // `{version}-{uuid}.metadata.json`.
+    paths
+        .iter()
+        .filter_map(|path| {
+            let file_name = path.as_str().rsplit('/').next()?;
+            let stem = file_name.strip_suffix(".metadata.json")?;
+            let version = stem
+                .strip_prefix('v')
+                .unwrap_or(stem)
+                .split('-')
+                .next()?
+                .parse::<u64>()
+                .ok()?;
+            Some((version, path))
+        })
+        .max_by_key(|(version, _)| *version)
+        .map(|(_, path)| path.as_str().to_string())
+        .ok_or_else(|| {
+            polars_err!(ComputeError: "not an iceberg table (no metadata files found): {}", table_uri)
+        })
+}
+
+/// Paths in the table metadata are absolute URIs, local paths may use the `file:` scheme.
+fn resolve_file_path(path: &str) -> String {
+    if let Some(rest) = path.strip_prefix("file://") {
+        rest.to_string()
+    } else if let Some(rest) = path.strip_prefix("file:") {
+        rest.to_string()
+    } else {
+        path.to_string()
+    }
+}
+
+async fn read_file(path: &str, cloud_options: Option<&CloudOptions>) -> PolarsResult<Buffer<u8>> {
+    let source = DynByteSourceBuilder::ObjectStore
+        .try_build_from_path(PlRefPath::new(resolve_file_path(path)), cloud_options)
+        .await?;
+    let size = source.get_size().await?;
+
+    source.get_range(0..size).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_file_path() {
+        assert_eq!(
+            resolve_file_path("file:///tmp/t/a.parquet"),
+            "/tmp/t/a.parquet"
+        );
+        assert_eq!(
+            resolve_file_path("file:/tmp/t/a.parquet"),
+            "/tmp/t/a.parquet"
+        );
+        assert_eq!(
+            resolve_file_path("s3://bucket/t/a.parquet"),
+            "s3://bucket/t/a.parquet"
+        );
+    }
+}
@@ -14,8 +14,16 @@ pub mod catalog;
 pub mod cloud;
 #[cfg(any(feature = "csv", feature = "json"))]
 pub mod csv;
+#[cfg(feature = "delta")]
+pub mod delta;
+#[cfg(feature = "excel")]
+pub mod excel;
 #[cfg(feature = "file_cache")]
 pub mod file_cache;
+#[cfg(feature = "flight")]
+pub mod flight;
+#[cfg(feature = "iceberg")]
+pub mod iceberg;
 #[cfg(any(feature = "ipc", feature = "ipc_streaming"))]
 pub mod ipc;
 #[cfg(feature = "json")]
@@ -24,6 +32,8 @@ pub mod mmap;
 #[cfg(feature = "json")]
 pub mod ndjson;
 mod options;
+#[cfg(feature = "orc")]
+pub mod orc;
 #[cfg(feature = "parquet")]
 pub mod parquet;
 pub mod path_utils;
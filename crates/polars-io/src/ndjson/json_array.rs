@@ -0,0 +1,515 @@
+//! Streaming access to the elements of a JSON array document.
+//!
+//! The elements of the array are rewritten as newline-delimited JSON, which allows JSON array
+//! documents to be read by the NDJSON readers without loading the full document in memory.
+
+use std::io::Read;
+
+use polars_buffer::Buffer;
+use polars_core::prelude::*;
+
+use crate::utils::compression::CompressedReader;
+
+#[derive(Debug)]
+enum Frame {
+    Object {
+        /// Whether the path to this object is a prefix of the pointer.
+        matches: bool,
+        expect_key: bool,
+        key: Option<String>,
+    },
+    Array {
+        matches: bool,
+        index: usize,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StringKind {
+    /// Object key that is part of the pointer path, its contents are captured.
+    CapturedKey,
+    /// Object key that is not relevant to the pointer.
+    Key,
+    Value,
+}
+
+/// Incrementally rewrites the elements of the JSON array at a [JSON pointer] into NDJSON.
+///
+/// Every element is written as a single line. Line breaks within an element are replaced by
+/// spaces, which is lossless as JSON does not allow raw line breaks inside strings.
+///
+/// [JSON pointer]: https://datatracker.ietf.org/doc/html/rfc6901
+#[derive(Debug)]
+pub struct JsonArrayTranscoder {
+    pointer: Vec<String>,
+    pointer_str: PlSmallStr,
+    /// Containers enclosing the current position while navigating to the target array.
+    path: Vec<Frame>,
+    in_target: bool,
+    /// Nesting depth within the current element of the target array.
+    element_depth: usize,
+    element_len: usize,
+    string: Option<StringKind>,
+    escape: bool,
+    key_buf: Vec<u8>,
+    done: bool,
+}
+
+impl JsonArrayTranscoder {
+    /// `pointer` is a JSON pointer to the array, the empty string refers to a top-level array.
+    pub fn try_new(pointer: &str) -> PolarsResult<Self> {
+        let pointer_tokens = match pointer.strip_prefix('/') {
+            _ if pointer.is_empty() => vec![],
+            Some(v) => v
+                .split('/')
+                .map(|token| token.replace("~1", "/").replace("~0", "~"))
+                .collect(),
+            None => polars_bail!(
+                InvalidOperation: "invalid JSON pointer '{}': must be empty or start with '/'", pointer
+            ),
+        };
+
+        Ok(Self {
+            pointer: pointer_tokens,
+            pointer_str: pointer.into(),
+            path: vec![],
+            in_target: false,
+            element_depth: 0,
+            element_len: 0,
+            string: None,
+            escape: false,
+            key_buf: vec![],
+            done: false,
+        })
+    }
+
+    /// Returns `true` once the end of the target array has been reached. Any further input is
+    /// ignored.
+    pub fn is_done(&self) -> bool {
+        self.done
+    }
+
+    /// Transcodes the next bytes of the document, appending complete and partial elements to
+    /// `out`. A line break is written after each completed element.
+    pub fn transcode(&mut self, input: &[u8], out: &mut Vec<u8>) -> PolarsResult<()> {
+        for &b in input {
+            if self.done {
+                break;
+            }
+
+            if let Some(kind) = self.string {
+                self.push_string_byte(kind, b, out)?;
+                continue;
+            }
+
+            if self.in_target {
+                self.push_target_byte(b, out);
+                continue;
+            }
+
+            self.navigate(b)?;
+        }
+
+        Ok(())
+    }
+
+    /// Checks that the target array was fully read. Must be called at the end of the input.
+    pub fn finish(&self) -> PolarsResult<()> {
+        if self.done {
+            return Ok(());
+        }
+
+        if self.in_target {
+            polars_bail!(ComputeError: "unexpected end of JSON input within array at '{}'", self.pointer_str)
+        }
+
+        polars_bail!(ComputeError: "JSON array at pointer '{}' was not found", self.pointer_str)
+    }
+
+    fn push_string_byte(&mut self, kind: StringKind, b: u8, out: &mut Vec<u8>) -> PolarsResult<()> {
+        let closes = !self.escape && b == b'"';
+        self.escape = !self.escape && b == b'\\';
+
+        if self.in_target {
+            out.push(replace_line_break(b));
+        }
+
+        if !closes {
+            if kind == StringKind::CapturedKey {
+                self.key_buf.push(b);
+            }
+            return Ok(());
+        }
+
+        self.string = None;
+
+        if kind != StringKind::Value {
+            let key = if kind == StringKind::CapturedKey {
+                let key = unescape_json_string(&self.key_buf).ok_or_else(
+                    || polars_err!(ComputeError: "invalid JSON string escape in object key"),
+                )?;
+                self.key_buf.clear();
+                Some(key)
+            } else {
+                None
+            };
+
+            if let Some(Frame::Object {
+                expect_key,
+                key: frame_key,
+                ..
+            }) = self.path.last_mut()
+            {
+                *expect_key = false;
+                *frame_key = key;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn push_target_byte(&mut self, b: u8, out: &mut Vec<u8>) {
+        if self.element_depth > 0 {
+            out.push(replace_line_break(b));
+
+            match b {
+                b'{' | b'[' => self.element_depth += 1,
+                b'}' | b']' => self.element_depth -= 1,
+                b'"' => self.string = Some(StringKind::Value),
+                _ => {},
+            }
+
+            return;
+        }
+
+        match b {
+            b' ' | b'\t' | b'\n' | b'\r' => {},
+            b',' | b']' => {
+                if self.element_len > 0 {
+                    out.push(b'\n');
+                    self.element_len = 0;
+                }
+
+                self.done = b == b']';
+            },
+            _ => {
+                out.push(b);
+                self.element_len += 1;
+
+                match b {
+                    b'{' | b'[' => self.element_depth = 1,
+                    b'"' => self.string = Some(StringKind::Value),
+                    _ => {},
+                }
+            },
+        }
+    }
+
+    fn navigate(&mut self, b: u8) -> PolarsResult<()> {
+        match b {
+            b' ' | b'\t' | b'\n' | b'\r' | b':' => {},
+            b'"' => {
+                self.string = Some(match self.path.last() {
+                    Some(Frame::Object {
+                        expect_key: true,
+                        matches,
+                        ..
+                    }) => {
+                        if *matches && self.path.len() <= self.pointer.len() {
+                            StringKind::CapturedKey
+                        } else {
+                            StringKind::Key
+                        }
+                    },
+                    _ => {
+                        self.check_not_target("string")?;
+                        StringKind::Value
+                    },
+                });
+            },
+            b',' => match self.path.last_mut() {
+                Some(Frame::Object {
+                    expect_key, key, ..
+                }) => {
+                    *expect_key = true;
+                    *key = None;
+                },
+                Some(Frame::Array { index, .. }) => *index += 1,
+                None => {},
+            },
+            b'{' | b'[' => {
+                let matches = self.value_matches();
+
+                if matches && self.path.len() == self.pointer.len() {
+                    if b == b'[' {
+                        self.in_target = true;
+                        // The enclosing containers are not needed anymore.
+                        self.path.clear();
+                        return Ok(());
+                    }
+
+                    self.check_not_target("object")?;
+                }
+
+                self.path.push(if b == b'{' {
+                    Frame::Object {
+                        matches,
+                        expect_key: true,
+                        key: None,
+                    }
+                } else {
+                    Frame::Array { matches, index: 0 }
+                });
+            },
+            b'}' | b']' => {
+                self.path.pop();
+            },
+            _ => self.check_not_target("scalar")?,
+        }
+
+        Ok(())
+    }
+
+    /// Whether the value starting at the current position is on the pointer path.
+    fn value_matches(&self) -> bool {
+        let depth = self.path.len();
+
+        let Some(frame) = self.path.last() else {
+            return true;
+        };
+
+        if depth > self.pointer.len() {
+            return false;
+        }
+
+        let token = &self.pointer[depth - 1];
+
+        match frame {
+            Frame::Object { matches, key, .. } => *matches && key.as_deref() == Some(token),
+            Frame::Array { matches, index } => {
+                *matches && token.parse::<usize>().is_ok_and(|x| x == *index)
+            },
+        }
+    }
+
+    fn check_not_target(&self, found: &str) -> PolarsResult<()> {
+        polars_ensure!(
+            !(self.value_matches() && self.path.len() == self.pointer.len()),
+            ComputeError: "expected JSON array at pointer '{}', found {}", self.pointer_str, found
+        );
+        Ok(())
+    }
+}
+
+#[inline(always)]
+fn replace_line_break(b: u8) -> u8 {
+    if b == b'\n' || b == b'\r' { b' ' } else { b }
+}
+
+/// Decodes the contents of a JSON string (without the surrounding quotes).
+fn unescape_json_string(raw: &[u8]) -> Option<String> {
+    let raw = std::str::from_utf8(raw).ok()?;
+
+    if !raw.contains('\\') {
+        return Some(raw.to_string());
+    }
+
+    let mut out = String::with_capacity(raw.len());
+    let mut chars = raw.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+
+        let c = match chars.next()? {
+            '"' => '"',
+            '\\' => '\\',
+            '/' => '/',
+            'b' => '\u{8}',
+            'f' => '\u{c}',
+            'n' => '\n',
+            'r' => '\r',
+            't' => '\t',
+            'u' => {
+                let hi = read_hex4(&mut chars)?;
+
+                if (0xD800..0xDC00).contains(&hi) {
+                    // Surrogate pair.
+                    (chars.next()? == '\\' && chars.next()? == 'u').then_some(())?;
+                    let lo = read_hex4(&mut chars)?;
+                    char::from_u32(0x10000 + ((hi - 0xD800) << 10) + (lo.checked_sub(0xDC00)?))?
+                } else {
+                    char::from_u32(hi)?
+                }
+            },
+            _ => return None,
+        };
+
+        out.push(c);
+    }
+
+    Some(out)
+}
+
+fn read_hex4(chars: &mut std::str::Chars<'_>) -> Option<u32> {
+    let hex: String = chars.take(4).collect();
+    (hex.len() == 4).then_some(())?;
+    u32::from_str_radix(&hex, 16).ok()
+}
+
+/// Reads the elements of a JSON array document as NDJSON.
+pub struct JsonArrayReader {
+    reader: CompressedReader,
+    transcoder: JsonArrayTranscoder,
+    eof: bool,
+    /// Transcoded output that has not been consumed through [`Read`].
+    pending: Vec<u8>,
+    pending_offset: usize,
+}
+
+impl JsonArrayReader {
+    pub fn try_new(reader: CompressedReader, pointer: &str) -> PolarsResult<Self> {
+        Ok(Self {
+            reader,
+            transcoder: JsonArrayTranscoder::try_new(pointer)?,
+            eof: false,
+            pending: vec![],
+            pending_offset: 0,
+        })
+    }
+
+    /// Equivalent of [`CompressedReader::read_next_slice`] for the transcoded output.
+    ///
+    /// `read_size` refers to the number of bytes read from the document. The returned slice always
+    /// contains at least one new byte unless the end of the array was reached, in which case the
+    /// returned byte count is 0.
+    pub fn read_next_slice(
+        &mut self,
+        prev_leftover: &Buffer<u8>,
+        read_size: usize,
+    ) -> std::io::Result<(Buffer<u8>, usize)> {
+        let mut buf = Vec::with_capacity(prev_leftover.len().saturating_add(read_size));
+        buf.extend_from_slice(prev_leftover);
+        let bytes_read = self.fill(&mut buf, read_size)?;
+        Ok((Buffer::from_vec(buf), bytes_read))
+    }
+
+    /// Transcodes input until `out` grows or the end of the array is reached. Returns the number of
+    /// bytes read from the document.
+    fn fill(&mut self, out: &mut Vec<u8>, read_size: usize) -> std::io::Result<usize> {
+        let prev_len = out.len();
+        let mut bytes_read = 0;
+
+        while !self.eof && out.len() == prev_len {
+            let (slice, n) = self.reader.read_next_slice(&Buffer::new(), read_size)?;
+            bytes_read += n;
+
+            self.transcoder
+                .transcode(&slice, out)
+                .map_err(std::io::Error::other)?;
+
+            if n == 0 || self.transcoder.is_done() {
+                self.transcoder.finish().map_err(std::io::Error::other)?;
+                self.eof = true;
+            }
+        }
+
+        Ok(bytes_read)
+    }
+}
+
+/// This implementation is meant for compatibility. Use [`Self::read_next_slice`] for best
+/// performance.
+impl Read for JsonArrayReader {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        if self.pending_offset == self.pending.len() {
+            let mut pending = std::mem::take(&mut self.pending);
+            pending.clear();
+            self.pending_offset = 0;
+            self.fill(&mut pending, CompressedReader::initial_read_size())?;
+            self.pending = pending;
+        }
+
+        let n = buf.len().min(self.pending.len() - self.pending_offset);
+        buf[..n].copy_from_slice(&self.pending[self.pending_offset..self.pending_offset + n]);
+        self.pending_offset += n;
+
+        Ok(n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn transcode_chunked(input: &str, pointer: &str, chunk_size: usize) -> PolarsResult<String> {
+        let mut transcoder = JsonArrayTranscoder::try_new(pointer)?;
+        let mut out = vec![];
+
+        for chunk in input.as_bytes().chunks(chunk_size) {
+            transcoder.transcode(chunk, &mut out)?;
+        }
+
+        transcoder.finish()?;
+        Ok(String::from_utf8(out).unwrap())
+    }
+
+    #[test]
+    fn test_transcode_top_level_array() -> PolarsResult<()> {
+        let input = "[\n  {\"a\": 1, \"b\": \"x,]}\"},\n  {\"a\": [2,\n 3]}\n]";
+
+        for chunk_size in [1, 3, 1024] {
+            assert_eq!(
+                transcode_chunked(input, "", chunk_size)?,
+                "{\"a\": 1, \"b\": \"x,]}\"}\n{\"a\": [2,  3]}\n"
+            );
+        }
+
+        assert_eq!(transcode_chunked("[]", "", 1)?, "");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_transcode_json_pointer() -> PolarsResult<()> {
+        let input = r#"{"meta": {"items": [0]}, "data": {"n": 2, "it\"ems": [], "items": [{"a": 1}, {"a": 2}]}, "tail": [3]}"#;
+
+        for chunk_size in [1, 5, 1024] {
+            assert_eq!(
+                transcode_chunked(input, "/data/items", chunk_size)?,
+                "{\"a\": 1}\n{\"a\": 2}\n"
+            );
+        }
+
+        assert_eq!(
+            transcode_chunked(r#"{"x": [[1], [{"b": 1}]]}"#, "/x/1", 1)?,
+            "{\"b\": 1}\n"
+        );
+        assert_eq!(
+            transcode_chunked(r#"{"a/b": [{"c": 1}]}"#, "/a~1b", 1)?,
+            "{\"c\": 1}\n"
+        );
+
+        assert!(transcode_chunked(r#"{"data": {}}"#, "/data", 1).is_err());
+        assert!(transcode_chunked(r#"{"data": [{}"#, "/data", 1).is_err());
+        assert!(transcode_chunked(r#"{"other": []}"#, "/data", 1).is_err());
+        assert!(JsonArrayTranscoder::try_new("data").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_json_array_reader() -> PolarsResult<()> {
+        let input = Buffer::from_vec(br#"{"data": [{"a": 1}, {"a": 2}]} trailing"#.to_vec());
+        let mut reader = JsonArrayReader::try_new(CompressedReader::try_new(input)?, "/data")?;
+
+        let mut out = String::new();
+        reader.read_to_string(&mut out)?;
+
+        assert_eq!(out, "{\"a\": 1}\n{\"a\": 2}\n");
+
+        Ok(())
+    }
+}
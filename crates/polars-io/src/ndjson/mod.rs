@@ -10,6 +10,7 @@ use crate::ExternalCompression;
 
 pub(crate) mod buffer;
 pub mod core;
+pub mod json_array;
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Default, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -0,0 +1,151 @@
+use std::borrow::Cow;
+use std::io::Read;
+
+use polars_core::prelude::*;
+
+/// Compression codec of an ORC file. The codec applies to all streams and to the footer and
+/// metadata sections of the file tail.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OrcCompression {
+    None,
+    Zlib,
+    Snappy,
+    Lzo,
+    Lz4,
+    Zstd,
+}
+
+impl OrcCompression {
+    pub(super) fn try_from_proto(v: u64) -> PolarsResult<Self> {
+        Ok(match v {
+            0 => Self::None,
+            1 => Self::Zlib,
+            2 => Self::Snappy,
+            3 => Self::Lzo,
+            4 => Self::Lz4,
+            5 => Self::Zstd,
+            v => polars_bail!(ComputeError: "unknown orc compression kind: {}", v),
+        })
+    }
+}
+
+/// Decompresses a single ORC stream.
+///
+/// Compressed streams consist of chunks that are each prefixed by a 3 byte little-endian header
+/// holding the chunk length and whether the chunk is stored uncompressed.
+pub fn decompress_orc_stream(
+    compression: OrcCompression,
+    block_size: usize,
+    bytes: &[u8],
+) -> PolarsResult<Cow<'_, [u8]>> {
+    if compression == OrcCompression::None {
+        return Ok(Cow::Borrowed(bytes));
+    }
+
+    let mut out = Vec::with_capacity(bytes.len() * 2);
+    let mut offset = 0;
+
+    while offset < bytes.len() {
+        polars_ensure!(
+            offset + 3 <= bytes.len(),
+            ComputeError: "invalid orc stream: truncated compression chunk header"
+        );
+
+        let header = u32::from(bytes[offset])
+            | (u32::from(bytes[offset + 1]) << 8)
+            | (u32::from(bytes[offset + 2]) << 16);
+        let is_original = header & 1 == 1;
+        let chunk_len = (header >> 1) as usize;
+        offset += 3;
+
+        let chunk = bytes.get(offset..offset + chunk_len).ok_or_else(
+            || polars_err!(ComputeError: "invalid orc stream: truncated compression chunk"),
+        )?;
+        offset += chunk_len;
+
+        if is_original {
+            out.extend_from_slice(chunk);
+            continue;
+        }
+
+        match compression {
+            OrcCompression::None => unreachable!(),
+            OrcCompression::Zlib => {
+                // ORC uses raw deflate streams without the zlib header.
+                flate2::read::DeflateDecoder::new(chunk)
+                    .read_to_end(&mut out)
+                    .map_err(
+                        |e| polars_err!(ComputeError: "orc zlib decompression failed: {}", e),
+                    )?;
+            },
+            OrcCompression::Snappy => {
+                let len = snap::raw::decompress_len(chunk).map_err(
+                    |e| polars_err!(ComputeError: "orc snappy decompression failed: {}", e),
+                )?;
+                let start = out.len();
+                out.resize(start + len, 0);
+                snap::raw::Decoder::new()
+                    .decompress(chunk, &mut out[start..])
+                    .map_err(
+                        |e| polars_err!(ComputeError: "orc snappy decompression failed: {}", e),
+                    )?;
+            },
+            OrcCompression::Lz4 => {
+                let start = out.len();
+                out.resize(start + block_size, 0);
+                let len = lz4_flex::block::decompress_into(chunk, &mut out[start..]).map_err(
+                    |e| polars_err!(ComputeError: "orc lz4 decompression failed: {}", e),
+                )?;
+                out.truncate(start + len);
+            },
+            OrcCompression::Zstd => {
+                zstd::stream::read::Decoder::new(chunk)
+                    .and_then(|mut decoder| decoder.read_to_end(&mut out))
+                    .map_err(
+                        |e| polars_err!(ComputeError: "orc zstd decompression failed: {}", e),
+                    )?;
+            },
+            OrcCompression::Lzo => {
+                polars_bail!(ComputeError: "reading LZO compressed orc files is not supported")
+            },
+        }
+    }
+
+    Ok(Cow::Owned(out))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use super::*;
+
+    fn chunk_header(len: usize, is_original: bool) -> [u8; 3] {
+        let header = ((len as u32) << 1) | u32::from(is_original);
+        [header as u8, (header >> 8) as u8, (header >> 16) as u8]
+    }
+
+    #[test]
+    fn test_decompress_orc_stream() -> PolarsResult<()> {
+        let data = b"hello hello hello hello orc";
+
+        let mut encoder =
+            flate2::write::DeflateEncoder::new(vec![], flate2::Compression::default());
+        encoder.write_all(data).unwrap();
+        let deflated = encoder.finish().unwrap();
+
+        let mut bytes = vec![];
+        bytes.extend(chunk_header(deflated.len(), false));
+        bytes.extend(&deflated);
+        bytes.extend(chunk_header(3, true));
+        bytes.extend(b"!!!");
+
+        let out = decompress_orc_stream(OrcCompression::Zlib, 1024, &bytes)?;
+        assert_eq!(&*out, b"hello hello hello hello orc!!!");
+
+        let out = decompress_orc_stream(OrcCompression::None, 1024, data)?;
+        assert!(matches!(out, Cow::Borrowed(_)));
+
+        Ok(())
+    }
+}
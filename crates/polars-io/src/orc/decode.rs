@@ -0,0 +1,522 @@
+use std::borrow::Cow;
+use std::ops::Range;
+
+use arrow::array::ListArray;
+use arrow::bitmap::Bitmap;
+use arrow::offset::Offsets;
+use polars_core::prelude::*;
+use polars_utils::pl_str::PlSmallStr;
+
+use super::OrcFileMetadata;
+use super::compression::decompress_orc_stream;
+use super::proto::{ColumnEncoding, OrcTypeKind, StreamKind, StripeFooter};
+#[cfg(feature = "dtype-decimal")]
+use super::rle::decode_varint_i128;
+use super::rle::{RleVersion, decode_bool_rle, decode_byte_rle, decode_int_rle};
+
+/// Seconds between the unix epoch and the ORC timestamp epoch (2015-01-01 00:00:00).
+const ORC_TIMESTAMP_BASE_SECONDS: i64 = 1_420_070_400;
+
+/// Decodes a single stripe of `bytes`.
+///
+/// `projection` holds the indices of the top-level columns to decode, the output columns are in
+/// the order of `projection`.
+///
+/// Values of the `TIMESTAMP` type are read relative to a UTC epoch, which matches the written
+/// values if the writer timezone is UTC.
+pub fn decode_orc_stripe(
+    bytes: &[u8],
+    metadata: &OrcFileMetadata,
+    stripe_idx: usize,
+    projection: &[usize],
+) -> PolarsResult<DataFrame> {
+    let stripe = &metadata.footer.stripes[stripe_idx];
+    let num_rows = usize::try_from(stripe.number_of_rows).unwrap();
+
+    let offset = usize::try_from(stripe.offset).unwrap();
+    let footer_offset = offset + usize::try_from(stripe.index_length + stripe.data_length).unwrap();
+    let footer_end = footer_offset + usize::try_from(stripe.footer_length).unwrap();
+
+    let stripe_err =
+        || polars_err!(ComputeError: "invalid orc file: stripe {} out of bounds", stripe_idx);
+
+    let stripe_footer = StripeFooter::decode(&decompress_orc_stream(
+        metadata.compression,
+        metadata.compression_block_size,
+        bytes
+            .get(footer_offset..footer_end)
+            .ok_or_else(stripe_err)?,
+    )?)?;
+
+    let mut streams = PlHashMap::with_capacity(stripe_footer.streams.len());
+    let mut stream_offset = offset;
+
+    for stream in stripe_footer.streams.iter() {
+        let end = stream_offset + usize::try_from(stream.length).unwrap();
+        if end > footer_offset {
+            return Err(stripe_err());
+        }
+        streams.insert((stream.column, stream.kind), stream_offset..end);
+        stream_offset = end;
+    }
+
+    let decoder = StripeDecoder {
+        bytes,
+        metadata,
+        streams,
+        encodings: stripe_footer.columns,
+    };
+
+    let columns = projection
+        .iter()
+        .map(|&i| {
+            let (name, dtype) = metadata.schema.get_at_index(i).unwrap();
+            decoder
+                .decode_column(name.clone(), metadata.column_type_ids[i], dtype, num_rows)
+                .map(Column::from)
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    DataFrame::new(num_rows, columns)
+}
+
+struct StripeDecoder<'a> {
+    bytes: &'a [u8],
+    metadata: &'a OrcFileMetadata,
+    streams: PlHashMap<(u32, StreamKind), Range<usize>>,
+    encodings: Vec<ColumnEncoding>,
+}
+
+impl<'a> StripeDecoder<'a> {
+    /// Returns the decompressed stream. Writers may omit empty streams, these are returned as
+    /// empty slices.
+    fn stream(&self, column: u32, kind: StreamKind) -> PolarsResult<Cow<'a, [u8]>> {
+        match self.streams.get(&(column, kind)) {
+            Some(range) => decompress_orc_stream(
+                self.metadata.compression,
+                self.metadata.compression_block_size,
+                &self.bytes[range.clone()],
+            ),
+            None => Ok(Cow::Borrowed(&[])),
+        }
+    }
+
+    fn encoding(&self, column: u32) -> ColumnEncoding {
+        self.encodings
+            .get(column as usize)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    fn rle_version(&self, column: u32) -> RleVersion {
+        if self.encoding(column).kind.is_v2() {
+            RleVersion::V2
+        } else {
+            RleVersion::V1
+        }
+    }
+
+    fn decode_column(
+        &self,
+        name: PlSmallStr,
+        type_id: u32,
+        dtype: &DataType,
+        n: usize,
+    ) -> PolarsResult<Series> {
+        let validity = if self.streams.contains_key(&(type_id, StreamKind::Present)) {
+            Some(decode_bool_rle(
+                &self.stream(type_id, StreamKind::Present)?,
+                n,
+            )?)
+        } else {
+            None
+        };
+
+        let validity = validity.filter(|v| v.unset_bits() > 0);
+        let n_values = validity.as_ref().map_or(n, |v| v.set_bits());
+        let values = self.decode_values(name, type_id, dtype, n_values)?;
+
+        match validity {
+            None => Ok(values),
+            Some(validity) => scatter_by_validity(values, &validity),
+        }
+    }
+
+    /// Decodes `n` non-null values.
+    fn decode_values(
+        &self,
+        name: PlSmallStr,
+        type_id: u32,
+        dtype: &DataType,
+        n: usize,
+    ) -> PolarsResult<Series> {
+        let orc_type = self.metadata.orc_type(type_id)?;
+        let version = self.rle_version(type_id);
+        let ints = |kind: StreamKind, n: usize, signed: bool| -> PolarsResult<Vec<i64>> {
+            decode_int_rle(&self.stream(type_id, kind)?, n, version, signed)
+        };
+        let dtype_mismatch = || {
+            polars_err!(
+                ComputeError: "invalid orc file: type id {} of kind {:?} cannot be read as {}",
+                type_id, orc_type.kind, dtype
+            )
+        };
+        let subtypes = |expected: usize| -> PolarsResult<&[u32]> {
+            polars_ensure!(
+                orc_type.subtypes.len() == expected,
+                ComputeError: "invalid orc file: type id {} has {} child types, expected {}",
+                type_id, orc_type.subtypes.len(), expected
+            );
+            Ok(&orc_type.subtypes)
+        };
+
+        let out = match orc_type.kind {
+            OrcTypeKind::Boolean => BooleanChunked::from_bitmap(
+                name,
+                decode_bool_rle(&self.stream(type_id, StreamKind::Data)?, n)?,
+            )
+            .into_series(),
+            OrcTypeKind::Byte => {
+                let values = decode_byte_rle(&self.stream(type_id, StreamKind::Data)?, n)?;
+                Int8Chunked::from_vec(name, values.into_iter().map(|v| v as i8).collect())
+                    .into_series()
+            },
+            OrcTypeKind::Short => {
+                let values = ints(StreamKind::Data, n, true)?;
+                Int16Chunked::from_vec(name, values.into_iter().map(|v| v as i16).collect())
+                    .into_series()
+            },
+            OrcTypeKind::Int => {
+                let values = ints(StreamKind::Data, n, true)?;
+                Int32Chunked::from_vec(name, values.into_iter().map(|v| v as i32).collect())
+                    .into_series()
+            },
+            OrcTypeKind::Long => {
+                Int64Chunked::from_vec(name, ints(StreamKind::Data, n, true)?).into_series()
+            },
+            OrcTypeKind::Float => {
+                let data = self.stream(type_id, StreamKind::Data)?;
+                Float32Chunked::from_vec(
+                    name,
+                    decode_fixed_le::<4>(&data, n)?
+                        .map(f32::from_le_bytes)
+                        .collect(),
+                )
+                .into_series()
+            },
+            OrcTypeKind::Double => {
+                let data = self.stream(type_id, StreamKind::Data)?;
+                Float64Chunked::from_vec(
+                    name,
+                    decode_fixed_le::<8>(&data, n)?
+                        .map(f64::from_le_bytes)
+                        .collect(),
+                )
+                .into_series()
+            },
+            OrcTypeKind::String | OrcTypeKind::Varchar | OrcTypeKind::Char => {
+                let encoding = self.encoding(type_id);
+
+                if encoding.kind.is_dictionary() {
+                    let dict_size = encoding.dictionary_size as usize;
+                    let dict_lengths = ints(StreamKind::Length, dict_size, false)?;
+                    let dictionary = build_binary_values(
+                        name,
+                        &self.stream(type_id, StreamKind::DictionaryData)?,
+                        &dict_lengths,
+                        true,
+                    )?;
+                    let indices = ints(StreamKind::Data, n, false)?;
+                    let indices = IdxCa::from_vec(
+                        PlSmallStr::EMPTY,
+                        indices.into_iter().map(|i| i as IdxSize).collect(),
+                    );
+                    dictionary.take(&indices)?
+                } else {
+                    build_binary_values(
+                        name,
+                        &self.stream(type_id, StreamKind::Data)?,
+                        &ints(StreamKind::Length, n, false)?,
+                        true,
+                    )?
+                }
+            },
+            OrcTypeKind::Binary => build_binary_values(
+                name,
+                &self.stream(type_id, StreamKind::Data)?,
+                &ints(StreamKind::Length, n, false)?,
+                false,
+            )?,
+            OrcTypeKind::Date => {
+                let values = ints(StreamKind::Data, n, true)?;
+                Int32Chunked::from_vec(name, values.into_iter().map(|v| v as i32).collect())
+                    .into_date()
+                    .into_series()
+            },
+            OrcTypeKind::Timestamp | OrcTypeKind::TimestampInstant => {
+                let seconds = ints(StreamKind::Data, n, true)?;
+                let nanos = ints(StreamKind::Secondary, n, false)?;
+
+                let values = seconds
+                    .into_iter()
+                    .zip(nanos)
+                    .map(|(seconds, nanos)| {
+                        let nanos = decode_timestamp_nanos(nanos);
+                        let mut seconds = seconds + ORC_TIMESTAMP_BASE_SECONDS;
+                        // Writers truncate negative timestamps towards zero.
+                        if seconds < 0 && nanos > 999_999 {
+                            seconds -= 1;
+                        }
+                        seconds.wrapping_mul(1_000_000_000).wrapping_add(nanos)
+                    })
+                    .collect();
+
+                let DataType::Datetime(tu, tz) = dtype else {
+                    return Err(dtype_mismatch());
+                };
+                Int64Chunked::from_vec(name, values)
+                    .into_datetime(*tu, tz.clone())
+                    .into_series()
+            },
+            #[cfg(feature = "dtype-decimal")]
+            OrcTypeKind::Decimal => {
+                let DataType::Decimal(precision, scale) = dtype else {
+                    return Err(dtype_mismatch());
+                };
+                let values = decode_varint_i128(&self.stream(type_id, StreamKind::Data)?, n)?;
+                let scales = ints(StreamKind::Secondary, n, true)?;
+
+                let values = values
+                    .into_iter()
+                    .zip(scales)
+                    .map(|(v, s)| rescale_decimal(v, s, *scale as i64))
+                    .collect::<PolarsResult<Vec<_>>>()?;
+
+                Int128Chunked::from_vec(name, values)
+                    .into_decimal_unchecked(*precision, *scale)
+                    .into_series()
+            },
+            OrcTypeKind::List => {
+                let DataType::List(inner) = dtype else {
+                    return Err(dtype_mismatch());
+                };
+                let lengths = ints(StreamKind::Length, n, false)?;
+                let values = self.decode_column(
+                    PlSmallStr::from_static("item"),
+                    subtypes(1)?[0],
+                    inner,
+                    total_length(&lengths)?,
+                )?;
+                build_list(name, &lengths, values)?
+            },
+            OrcTypeKind::Map => {
+                let DataType::List(inner) = dtype else {
+                    return Err(dtype_mismatch());
+                };
+                let DataType::Struct(fields) = inner.as_ref() else {
+                    return Err(dtype_mismatch());
+                };
+                let lengths = ints(StreamKind::Length, n, false)?;
+                let total = total_length(&lengths)?;
+
+                let entries = fields
+                    .iter()
+                    .zip(subtypes(fields.len())?)
+                    .map(|(field, &child_id)| {
+                        self.decode_column(field.name.clone(), child_id, &field.dtype, total)
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                let entries = StructChunked::from_series(
+                    PlSmallStr::from_static("item"),
+                    total,
+                    entries.iter(),
+                )?
+                .into_series();
+
+                build_list(name, &lengths, entries)?
+            },
+            OrcTypeKind::Struct => {
+                let DataType::Struct(fields) = dtype else {
+                    return Err(dtype_mismatch());
+                };
+
+                let children = fields
+                    .iter()
+                    .zip(subtypes(fields.len())?)
+                    .map(|(field, &child_id)| {
+                        self.decode_column(field.name.clone(), child_id, &field.dtype, n)
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()?;
+
+                StructChunked::from_series(name, n, children.iter())?.into_series()
+            },
+            kind => polars_bail!(
+                ComputeError: "reading orc columns of type {:?} is not supported", kind
+            ),
+        };
+
+        polars_ensure!(
+            out.len() == n,
+            ComputeError: "invalid orc file: decoded {} values for column {} but expected {}",
+            out.len(), out.name(), n
+        );
+
+        Ok(out)
+    }
+}
+
+/// Spreads `values` over the valid positions of `validity`, inserting nulls at the invalid
+/// positions.
+fn scatter_by_validity(values: Series, validity: &Bitmap) -> PolarsResult<Series> {
+    let mut next: IdxSize = 0;
+    let indices = IdxCa::from_iter_options(
+        PlSmallStr::EMPTY,
+        validity.iter().map(|is_valid| {
+            is_valid.then(|| {
+                next += 1;
+                next - 1
+            })
+        }),
+    );
+
+    values.take(&indices)
+}
+
+fn decode_fixed_le<const N: usize>(
+    bytes: &[u8],
+    n: usize,
+) -> PolarsResult<impl Iterator<Item = [u8; N]> + '_> {
+    polars_ensure!(
+        bytes.len() >= n * N,
+        ComputeError: "invalid orc stream: unexpected end of floating point data"
+    );
+
+    Ok(bytes[..n * N]
+        .chunks_exact(N)
+        .map(|chunk| chunk.try_into().unwrap()))
+}
+
+fn build_binary_values(
+    name: PlSmallStr,
+    data: &[u8],
+    lengths: &[i64],
+    utf8: bool,
+) -> PolarsResult<Series> {
+    let mut values = Vec::with_capacity(lengths.len());
+    let mut offset = 0usize;
+
+    for &len in lengths {
+        let end = usize::try_from(len)
+            .ok()
+            .and_then(|len| offset.checked_add(len))
+            .filter(|&end| end <= data.len())
+            .ok_or_else(
+                || polars_err!(ComputeError: "invalid orc stream: string length out of bounds"),
+            )?;
+        values.push(&data[offset..end]);
+        offset = end;
+    }
+
+    if utf8 {
+        let values = values
+            .into_iter()
+            .map(|v| {
+                std::str::from_utf8(v).map_err(
+                    |_| polars_err!(ComputeError: "invalid utf-8 in orc string column {}", name),
+                )
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        Ok(StringChunked::from_slice(name, &values).into_series())
+    } else {
+        Ok(BinaryChunked::from_slice(name, &values).into_series())
+    }
+}
+
+fn total_length(lengths: &[i64]) -> PolarsResult<usize> {
+    lengths
+        .iter()
+        .try_fold(0usize, |acc, &len| {
+            usize::try_from(len)
+                .ok()
+                .and_then(|len| acc.checked_add(len))
+        })
+        .ok_or_else(|| polars_err!(ComputeError: "invalid orc stream: corrupt list lengths"))
+}
+
+fn build_list(name: PlSmallStr, lengths: &[i64], values: Series) -> PolarsResult<Series> {
+    let offsets = Offsets::<i64>::try_from_lengths(lengths.iter().map(|&len| len as usize))?;
+    let values = values.rechunk();
+    let inner_dtype = values.dtype().clone();
+
+    let arrow_dtype = ListArray::<i64>::default_datatype(
+        inner_dtype.to_physical().to_arrow(CompatLevel::newest()),
+    );
+    let arr = ListArray::<i64>::new(
+        arrow_dtype,
+        offsets.into(),
+        values.chunks()[0].clone(),
+        None,
+    );
+
+    let mut out = ListChunked::with_chunk(name, arr);
+    out.set_inner_dtype(inner_dtype);
+    Ok(out.into_series())
+}
+
+/// Nanoseconds are stored with their trailing decimal zeros removed. The lowest 3 bits hold the
+/// number of removed zeros minus one.
+fn decode_timestamp_nanos(v: i64) -> i64 {
+    let zeros = v & 0x7;
+    let mut nanos = v >> 3;
+
+    if zeros != 0 {
+        for _ in 0..=zeros {
+            nanos *= 10;
+        }
+    }
+
+    nanos
+}
+
+#[cfg(feature = "dtype-decimal")]
+fn rescale_decimal(v: i128, from_scale: i64, to_scale: i64) -> PolarsResult<i128> {
+    let diff = to_scale - from_scale;
+    let factor = 10i128.checked_pow(diff.unsigned_abs() as u32).ok_or_else(
+        || polars_err!(ComputeError: "invalid orc stream: decimal scale out of range"),
+    )?;
+
+    if diff >= 0 {
+        v.checked_mul(factor).ok_or_else(
+            || polars_err!(ComputeError: "orc decimal value does not fit in its precision"),
+        )
+    } else {
+        Ok(v / factor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_timestamp_nanos() {
+        assert_eq!(decode_timestamp_nanos(0), 0);
+        assert_eq!(decode_timestamp_nanos(5 << 3), 5);
+        // 1000 is stored as 1 with 3 trailing zeros.
+        assert_eq!(decode_timestamp_nanos((1 << 3) | 2), 1000);
+    }
+
+    #[test]
+    fn test_scatter_by_validity() -> PolarsResult<()> {
+        let values = Series::new("a".into(), [1i32, 2, 3]);
+        let validity = Bitmap::from([true, false, true, false, true]);
+        let out = scatter_by_validity(values, &validity)?;
+
+        assert_eq!(
+            out.i32()?.into_iter().collect::<Vec<_>>(),
+            [Some(1), None, Some(2), None, Some(3)]
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,206 @@
+use polars_core::chunked_array::cast::CastOptions;
+use polars_core::prelude::*;
+use polars_utils::pl_str::PlSmallStr;
+
+use super::compression::{OrcCompression, decompress_orc_stream};
+use super::proto::{
+    ColumnStatistics, Footer, OrcStatisticsRange, OrcType, OrcTypeKind, PostScript,
+    decode_stripe_statistics,
+};
+
+const MAGIC: &[u8] = b"ORC";
+
+/// File tail of an ORC file.
+#[derive(Debug, Clone)]
+pub struct OrcFileMetadata {
+    pub compression: OrcCompression,
+    pub compression_block_size: usize,
+    pub footer: Footer,
+    /// Column statistics per stripe, indexed by column (type) id. Empty if the file does not
+    /// contain stripe statistics.
+    pub stripe_statistics: Vec<Vec<ColumnStatistics>>,
+    /// Schema of the top-level columns.
+    pub schema: SchemaRef,
+    /// Type id of every top-level column.
+    pub column_type_ids: Vec<u32>,
+}
+
+impl OrcFileMetadata {
+    /// Reads the file tail from the end of `bytes`.
+    pub fn try_new(bytes: &[u8]) -> PolarsResult<Self> {
+        polars_ensure!(
+            bytes.len() > MAGIC.len() && bytes.starts_with(MAGIC),
+            ComputeError: "invalid orc file: missing magic bytes"
+        );
+
+        let ps_len = usize::from(*bytes.last().unwrap());
+        let tail_err = || polars_err!(ComputeError: "invalid orc file: corrupt file tail");
+
+        let ps_start = (bytes.len() - 1).checked_sub(ps_len).ok_or_else(tail_err)?;
+        let postscript = PostScript::decode(&bytes[ps_start..bytes.len() - 1])?;
+
+        let footer_start = ps_start
+            .checked_sub(usize::try_from(postscript.footer_length).unwrap())
+            .ok_or_else(tail_err)?;
+        let metadata_start = footer_start
+            .checked_sub(usize::try_from(postscript.metadata_length).unwrap())
+            .ok_or_else(tail_err)?;
+
+        let compression = postscript.compression;
+        let compression_block_size = usize::try_from(postscript.compression_block_size).unwrap();
+
+        let footer = Footer::decode(&decompress_orc_stream(
+            compression,
+            compression_block_size,
+            &bytes[footer_start..ps_start],
+        )?)?;
+
+        let stripe_statistics = if metadata_start < footer_start {
+            decode_stripe_statistics(&decompress_orc_stream(
+                compression,
+                compression_block_size,
+                &bytes[metadata_start..footer_start],
+            )?)?
+        } else {
+            vec![]
+        };
+
+        let root = footer
+            .types
+            .first()
+            .filter(|t| t.kind == OrcTypeKind::Struct)
+            .ok_or_else(
+                || polars_err!(ComputeError: "invalid orc file: root type must be a struct"),
+            )?;
+
+        let mut schema = Schema::with_capacity(root.subtypes.len());
+        for (name, &type_id) in root.field_names.iter().zip(&root.subtypes) {
+            schema.insert(name.clone(), orc_type_to_dtype(&footer.types, type_id)?);
+        }
+
+        Ok(Self {
+            compression,
+            compression_block_size,
+            column_type_ids: root.subtypes.clone(),
+            footer,
+            stripe_statistics,
+            schema: Arc::new(schema),
+        })
+    }
+
+    pub fn num_rows(&self) -> usize {
+        usize::try_from(self.footer.number_of_rows).unwrap()
+    }
+
+    pub fn num_stripes(&self) -> usize {
+        self.footer.stripes.len()
+    }
+
+    pub(super) fn orc_type(&self, type_id: u32) -> PolarsResult<&OrcType> {
+        self.footer.types.get(type_id as usize).ok_or_else(
+            || polars_err!(ComputeError: "invalid orc file: unknown type id {}", type_id),
+        )
+    }
+
+    /// Returns the minimum, maximum and null count of the top-level column at `column_idx` within
+    /// the stripe at `stripe_idx`, if the file contains statistics for it.
+    ///
+    /// The values are returned with the dtype of the column in [`OrcFileMetadata::schema`].
+    pub fn stripe_column_statistics(
+        &self,
+        stripe_idx: usize,
+        column_idx: usize,
+    ) -> PolarsResult<Option<(Scalar, Scalar, IdxSize)>> {
+        let type_id = self.column_type_ids[column_idx] as usize;
+        let Some(stats) = self
+            .stripe_statistics
+            .get(stripe_idx)
+            .and_then(|s| s.get(type_id))
+        else {
+            return Ok(None);
+        };
+        let (Some(range), Some(number_of_values)) = (&stats.range, stats.number_of_values) else {
+            return Ok(None);
+        };
+
+        let num_rows = self.footer.stripes[stripe_idx].number_of_rows;
+        let null_count = if stats.has_null == Some(false) {
+            0
+        } else {
+            num_rows.saturating_sub(number_of_values)
+        };
+        let dtype = self.schema.get_at_index(column_idx).unwrap().1;
+
+        let (min, max) = match (range, dtype) {
+            (OrcStatisticsRange::Int { min, max }, dt) if dt.is_integer() => (
+                Scalar::from(*min).cast_with_options(dt, CastOptions::Strict)?,
+                Scalar::from(*max).cast_with_options(dt, CastOptions::Strict)?,
+            ),
+            (OrcStatisticsRange::Double { min, max }, dt) if dt.is_float() => (
+                Scalar::from(*min).cast_with_options(dt, CastOptions::Strict)?,
+                Scalar::from(*max).cast_with_options(dt, CastOptions::Strict)?,
+            ),
+            (OrcStatisticsRange::String { min, max }, DataType::String) => {
+                (Scalar::from(min.clone()), Scalar::from(max.clone()))
+            },
+            (OrcStatisticsRange::Date { min, max }, DataType::Date) => (
+                Scalar::new(DataType::Date, AnyValue::Date(*min)),
+                Scalar::new(DataType::Date, AnyValue::Date(*max)),
+            ),
+            _ => return Ok(None),
+        };
+
+        Ok(Some((min, max, null_count as IdxSize)))
+    }
+}
+
+fn orc_type_to_dtype(types: &[OrcType], type_id: u32) -> PolarsResult<DataType> {
+    let t = types.get(type_id as usize).ok_or_else(
+        || polars_err!(ComputeError: "invalid orc file: unknown type id {}", type_id),
+    )?;
+
+    let child = |i: usize| -> PolarsResult<DataType> {
+        let child_id = *t.subtypes.get(i).ok_or_else(
+            || polars_err!(ComputeError: "invalid orc file: missing child type of type id {}", type_id),
+        )?;
+        orc_type_to_dtype(types, child_id)
+    };
+
+    Ok(match t.kind {
+        OrcTypeKind::Boolean => DataType::Boolean,
+        OrcTypeKind::Byte => DataType::Int8,
+        OrcTypeKind::Short => DataType::Int16,
+        OrcTypeKind::Int => DataType::Int32,
+        OrcTypeKind::Long => DataType::Int64,
+        OrcTypeKind::Float => DataType::Float32,
+        OrcTypeKind::Double => DataType::Float64,
+        OrcTypeKind::String | OrcTypeKind::Varchar | OrcTypeKind::Char => DataType::String,
+        OrcTypeKind::Binary => DataType::Binary,
+        OrcTypeKind::Date => DataType::Date,
+        OrcTypeKind::Timestamp => DataType::Datetime(TimeUnit::Nanoseconds, None),
+        OrcTypeKind::TimestampInstant => {
+            DataType::Datetime(TimeUnit::Nanoseconds, Some(TimeZone::UTC))
+        },
+        #[cfg(feature = "dtype-decimal")]
+        OrcTypeKind::Decimal => DataType::Decimal(
+            t.precision.unwrap_or(38) as usize,
+            t.scale.unwrap_or(10) as usize,
+        ),
+        OrcTypeKind::List => DataType::List(Box::new(child(0)?)),
+        // Maps are read as a list of key/value structs.
+        OrcTypeKind::Map => DataType::List(Box::new(DataType::Struct(vec![
+            Field::new(PlSmallStr::from_static("key"), child(0)?),
+            Field::new(PlSmallStr::from_static("value"), child(1)?),
+        ]))),
+        OrcTypeKind::Struct => DataType::Struct(
+            t.field_names
+                .iter()
+                .enumerate()
+                .map(|(i, name)| Ok(Field::new(name.clone(), child(i)?)))
+                .collect::<PolarsResult<_>>()?,
+        ),
+        kind => polars_bail!(
+            ComputeError: "reading orc columns of type {:?} is not supported", kind
+        ),
+    })
+}
@@ -0,0 +1,16 @@
+//! Native reader for Apache ORC files.
+//!
+//! An ORC file consists of independently decodable stripes, followed by a file tail holding the
+//! schema, the stripe locations and per-stripe column statistics.
+mod compression;
+mod decode;
+mod metadata;
+mod proto;
+mod rle;
+
+pub use compression::OrcCompression;
+pub use decode::decode_orc_stripe;
+pub use metadata::OrcFileMetadata;
+pub use proto::{
+    ColumnStatistics, Footer, OrcStatisticsRange, OrcType, OrcTypeKind, StripeInformation,
+};
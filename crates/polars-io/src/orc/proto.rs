@@ -0,0 +1,643 @@
+//! Decoding of the protobuf messages stored in the ORC file tail and stripe footers.
+//!
+//! Only the fields that are needed for reading are decoded, all other fields are skipped.
+
+use polars_core::prelude::*;
+use polars_utils::pl_str::PlSmallStr;
+
+use super::compression::OrcCompression;
+
+/// Minimal protobuf wire-format reader.
+pub(super) struct ProtoReader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum WireType {
+    Varint,
+    Fixed64,
+    LengthDelimited,
+    Fixed32,
+}
+
+impl<'a> ProtoReader<'a> {
+    pub(super) fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, pos: 0 }
+    }
+
+    /// Returns the field number and wire type of the next field, or `None` at the end of the
+    /// message.
+    pub(super) fn next_field(&mut self) -> PolarsResult<Option<(u32, WireType)>> {
+        if self.pos >= self.bytes.len() {
+            return Ok(None);
+        }
+
+        let key = self.read_varint()?;
+        let wire_type = match key & 0x7 {
+            0 => WireType::Varint,
+            1 => WireType::Fixed64,
+            2 => WireType::LengthDelimited,
+            5 => WireType::Fixed32,
+            v => polars_bail!(ComputeError: "unsupported protobuf wire type {} in orc metadata", v),
+        };
+
+        Ok(Some(((key >> 3) as u32, wire_type)))
+    }
+
+    pub(super) fn read_varint(&mut self) -> PolarsResult<u64> {
+        let mut value: u64 = 0;
+
+        for i in 0..10 {
+            let Some(&byte) = self.bytes.get(self.pos) else {
+                break;
+            };
+            self.pos += 1;
+            value |= u64::from(byte & 0x7F) << (i * 7);
+
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+
+        polars_bail!(ComputeError: "invalid orc metadata: corrupt protobuf varint")
+    }
+
+    pub(super) fn read_sint64(&mut self) -> PolarsResult<i64> {
+        let v = self.read_varint()?;
+        Ok(((v >> 1) as i64) ^ -((v & 1) as i64))
+    }
+
+    pub(super) fn read_bytes(&mut self) -> PolarsResult<&'a [u8]> {
+        let len = usize::try_from(self.read_varint()?).unwrap();
+        let end = self
+            .pos
+            .checked_add(len)
+            .filter(|&end| end <= self.bytes.len())
+            .ok_or_else(
+                || polars_err!(ComputeError: "invalid orc metadata: protobuf field out of bounds"),
+            )?;
+
+        let out = &self.bytes[self.pos..end];
+        self.pos = end;
+        Ok(out)
+    }
+
+    pub(super) fn read_string(&mut self) -> PolarsResult<PlSmallStr> {
+        let bytes = self.read_bytes()?;
+        let s = std::str::from_utf8(bytes)
+            .map_err(|_| polars_err!(ComputeError: "invalid orc metadata: non-utf8 string"))?;
+        Ok(PlSmallStr::from_str(s))
+    }
+
+    pub(super) fn read_double(&mut self) -> PolarsResult<f64> {
+        let bytes = self.read_fixed::<8>()?;
+        Ok(f64::from_le_bytes(bytes))
+    }
+
+    fn read_fixed<const N: usize>(&mut self) -> PolarsResult<[u8; N]> {
+        let out = self
+            .bytes
+            .get(self.pos..self.pos + N)
+            .ok_or_else(
+                || polars_err!(ComputeError: "invalid orc metadata: protobuf field out of bounds"),
+            )?
+            .try_into()
+            .unwrap();
+        self.pos += N;
+        Ok(out)
+    }
+
+    /// Reads a repeated `uint32`/`uint64` field, which may either be packed or not.
+    pub(super) fn read_repeated_varint(
+        &mut self,
+        wire_type: WireType,
+        out: &mut Vec<u64>,
+    ) -> PolarsResult<()> {
+        if wire_type == WireType::LengthDelimited {
+            let mut packed = ProtoReader::new(self.read_bytes()?);
+            while packed.pos < packed.bytes.len() {
+                out.push(packed.read_varint()?);
+            }
+        } else {
+            out.push(self.read_varint()?);
+        }
+        Ok(())
+    }
+
+    pub(super) fn skip(&mut self, wire_type: WireType) -> PolarsResult<()> {
+        match wire_type {
+            WireType::Varint => {
+                self.read_varint()?;
+            },
+            WireType::Fixed64 => {
+                self.read_fixed::<8>()?;
+            },
+            WireType::LengthDelimited => {
+                self.read_bytes()?;
+            },
+            WireType::Fixed32 => {
+                self.read_fixed::<4>()?;
+            },
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PostScript {
+    pub footer_length: u64,
+    pub compression: OrcCompression,
+    pub compression_block_size: u64,
+    pub metadata_length: u64,
+}
+
+impl PostScript {
+    pub(super) fn decode(bytes: &[u8]) -> PolarsResult<Self> {
+        let mut r = ProtoReader::new(bytes);
+        let mut out = PostScript {
+            footer_length: 0,
+            compression: OrcCompression::None,
+            // Default of the ORC specification.
+            compression_block_size: 256 * 1024,
+            metadata_length: 0,
+        };
+        let mut magic = None;
+
+        while let Some((field, wire_type)) = r.next_field()? {
+            match field {
+                1 => out.footer_length = r.read_varint()?,
+                2 => out.compression = OrcCompression::try_from_proto(r.read_varint()?)?,
+                3 => out.compression_block_size = r.read_varint()?,
+                5 => out.metadata_length = r.read_varint()?,
+                8000 => magic = Some(r.read_bytes()?),
+                _ => r.skip(wire_type)?,
+            }
+        }
+
+        polars_ensure!(
+            magic.is_none_or(|m| m == b"ORC"),
+            ComputeError: "invalid orc file: postscript magic mismatch"
+        );
+
+        Ok(out)
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct StripeInformation {
+    pub offset: u64,
+    pub index_length: u64,
+    pub data_length: u64,
+    pub footer_length: u64,
+    pub number_of_rows: u64,
+}
+
+impl StripeInformation {
+    fn decode(bytes: &[u8]) -> PolarsResult<Self> {
+        let mut r = ProtoReader::new(bytes);
+        let mut out = Self::default();
+
+        while let Some((field, wire_type)) = r.next_field()? {
+            match field {
+                1 => out.offset = r.read_varint()?,
+                2 => out.index_length = r.read_varint()?,
+                3 => out.data_length = r.read_varint()?,
+                4 => out.footer_length = r.read_varint()?,
+                5 => out.number_of_rows = r.read_varint()?,
+                _ => r.skip(wire_type)?,
+            }
+        }
+
+        Ok(out)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OrcTypeKind {
+    Boolean,
+    Byte,
+    Short,
+    Int,
+    Long,
+    Float,
+    Double,
+    String,
+    Binary,
+    Timestamp,
+    List,
+    Map,
+    Struct,
+    Union,
+    Decimal,
+    Date,
+    Varchar,
+    Char,
+    TimestampInstant,
+}
+
+impl OrcTypeKind {
+    fn try_from_proto(v: u64) -> PolarsResult<Self> {
+        use OrcTypeKind::*;
+        Ok(match v {
+            0 => Boolean,
+            1 => Byte,
+            2 => Short,
+            3 => Int,
+            4 => Long,
+            5 => Float,
+            6 => Double,
+            7 => String,
+            8 => Binary,
+            9 => Timestamp,
+            10 => List,
+            11 => Map,
+            12 => Struct,
+            13 => Union,
+            14 => Decimal,
+            15 => Date,
+            16 => Varchar,
+            17 => Char,
+            18 => TimestampInstant,
+            v => polars_bail!(ComputeError: "unknown orc type kind: {}", v),
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct OrcType {
+    pub kind: OrcTypeKind,
+    pub subtypes: Vec<u32>,
+    pub field_names: Vec<PlSmallStr>,
+    pub precision: Option<u32>,
+    pub scale: Option<u32>,
+}
+
+impl OrcType {
+    fn decode(bytes: &[u8]) -> PolarsResult<Self> {
+        let mut r = ProtoReader::new(bytes);
+        let mut kind = None;
+        let mut subtypes = vec![];
+        let mut field_names = vec![];
+        let mut precision = None;
+        let mut scale = None;
+
+        while let Some((field, wire_type)) = r.next_field()? {
+            match field {
+                1 => kind = Some(OrcTypeKind::try_from_proto(r.read_varint()?)?),
+                2 => r.read_repeated_varint(wire_type, &mut subtypes)?,
+                3 => field_names.push(r.read_string()?),
+                5 => precision = Some(r.read_varint()? as u32),
+                6 => scale = Some(r.read_varint()? as u32),
+                _ => r.skip(wire_type)?,
+            }
+        }
+
+        Ok(Self {
+            kind: kind.ok_or_else(
+                || polars_err!(ComputeError: "invalid orc metadata: type without kind"),
+            )?,
+            subtypes: subtypes.into_iter().map(|x| x as u32).collect(),
+            field_names,
+            precision,
+            scale,
+        })
+    }
+}
+
+/// Minimum and maximum of a column, as stored in the ORC column statistics.
+#[derive(Debug, Clone, PartialEq)]
+pub enum OrcStatisticsRange {
+    Int { min: i64, max: i64 },
+    Double { min: f64, max: f64 },
+    String { min: PlSmallStr, max: PlSmallStr },
+    Date { min: i32, max: i32 },
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ColumnStatistics {
+    /// Number of non-null values.
+    pub number_of_values: Option<u64>,
+    pub has_null: Option<bool>,
+    pub range: Option<OrcStatisticsRange>,
+}
+
+impl ColumnStatistics {
+    fn decode(bytes: &[u8]) -> PolarsResult<Self> {
+        let mut r = ProtoReader::new(bytes);
+        let mut out = Self::default();
+
+        while let Some((field, wire_type)) = r.next_field()? {
+            match field {
+                1 => out.number_of_values = Some(r.read_varint()?),
+                2 => out.range = decode_int_statistics(r.read_bytes()?)?,
+                3 => out.range = decode_double_statistics(r.read_bytes()?)?,
+                4 => out.range = decode_string_statistics(r.read_bytes()?)?,
+                7 => out.range = decode_date_statistics(r.read_bytes()?)?,
+                10 => out.has_null = Some(r.read_varint()? != 0),
+                _ => r.skip(wire_type)?,
+            }
+        }
+
+        Ok(out)
+    }
+}
+
+fn decode_int_statistics(bytes: &[u8]) -> PolarsResult<Option<OrcStatisticsRange>> {
+    let mut r = ProtoReader::new(bytes);
+    let (mut min, mut max) = (None, None);
+
+    while let Some((field, wire_type)) = r.next_field()? {
+        match field {
+            1 => min = Some(r.read_sint64()?),
+            2 => max = Some(r.read_sint64()?),
+            _ => r.skip(wire_type)?,
+        }
+    }
+
+    Ok(min
+        .zip(max)
+        .map(|(min, max)| OrcStatisticsRange::Int { min, max }))
+}
+
+fn decode_double_statistics(bytes: &[u8]) -> PolarsResult<Option<OrcStatisticsRange>> {
+    let mut r = ProtoReader::new(bytes);
+    let (mut min, mut max) = (None, None);
+
+    while let Some((field, wire_type)) = r.next_field()? {
+        match (field, wire_type) {
+            (1, WireType::Fixed64) => min = Some(r.read_double()?),
+            (2, WireType::Fixed64) => max = Some(r.read_double()?),
+            _ => r.skip(wire_type)?,
+        }
+    }
+
+    Ok(min
+        .zip(max)
+        .map(|(min, max)| OrcStatisticsRange::Double { min, max }))
+}
+
+fn decode_string_statistics(bytes: &[u8]) -> PolarsResult<Option<OrcStatisticsRange>> {
+    let mut r = ProtoReader::new(bytes);
+    let (mut min, mut max) = (None, None);
+    let (mut lower, mut upper) = (None, None);
+
+    while let Some((field, wire_type)) = r.next_field()? {
+        match field {
+            1 => min = Some(r.read_string()?),
+            2 => max = Some(r.read_string()?),
+            // Writers store truncated bounds instead of the min/max for long strings.
+            4 => lower = Some(r.read_string()?),
+            5 => upper = Some(r.read_string()?),
+            _ => r.skip(wire_type)?,
+        }
+    }
+
+    Ok(min
+        .or(lower)
+        .zip(max.or(upper))
+        .map(|(min, max)| OrcStatisticsRange::String { min, max }))
+}
+
+fn decode_date_statistics(bytes: &[u8]) -> PolarsResult<Option<OrcStatisticsRange>> {
+    let mut r = ProtoReader::new(bytes);
+    let (mut min, mut max) = (None, None);
+
+    while let Some((field, wire_type)) = r.next_field()? {
+        match field {
+            1 => min = Some(r.read_sint64()? as i32),
+            2 => max = Some(r.read_sint64()? as i32),
+            _ => r.skip(wire_type)?,
+        }
+    }
+
+    Ok(min
+        .zip(max)
+        .map(|(min, max)| OrcStatisticsRange::Date { min, max }))
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Footer {
+    pub stripes: Vec<StripeInformation>,
+    pub types: Vec<OrcType>,
+    pub number_of_rows: u64,
+    /// File-level statistics, indexed by column (type) id.
+    pub statistics: Vec<ColumnStatistics>,
+}
+
+impl Footer {
+    pub(super) fn decode(bytes: &[u8]) -> PolarsResult<Self> {
+        let mut r = ProtoReader::new(bytes);
+        let mut out = Self::default();
+
+        while let Some((field, wire_type)) = r.next_field()? {
+            match field {
+                3 => out
+                    .stripes
+                    .push(StripeInformation::decode(r.read_bytes()?)?),
+                4 => out.types.push(OrcType::decode(r.read_bytes()?)?),
+                6 => out.number_of_rows = r.read_varint()?,
+                7 => out
+                    .statistics
+                    .push(ColumnStatistics::decode(r.read_bytes()?)?),
+                _ => r.skip(wire_type)?,
+            }
+        }
+
+        Ok(out)
+    }
+}
+
+/// Decodes the metadata section of the file tail, returning the column statistics per stripe.
+pub(super) fn decode_stripe_statistics(bytes: &[u8]) -> PolarsResult<Vec<Vec<ColumnStatistics>>> {
+    let mut r = ProtoReader::new(bytes);
+    let mut out = vec![];
+
+    while let Some((field, wire_type)) = r.next_field()? {
+        if field != 1 {
+            r.skip(wire_type)?;
+            continue;
+        }
+
+        let mut stripe_reader = ProtoReader::new(r.read_bytes()?);
+        let mut stripe_stats = vec![];
+
+        while let Some((field, wire_type)) = stripe_reader.next_field()? {
+            if field == 1 {
+                stripe_stats.push(ColumnStatistics::decode(stripe_reader.read_bytes()?)?);
+            } else {
+                stripe_reader.skip(wire_type)?;
+            }
+        }
+
+        out.push(stripe_stats);
+    }
+
+    Ok(out)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StreamKind {
+    Present,
+    Data,
+    Length,
+    DictionaryData,
+    Secondary,
+    /// Streams that are not needed for decoding values (indexes, bloom filters, ...).
+    Other(u32),
+}
+
+impl StreamKind {
+    fn from_proto(v: u64) -> Self {
+        match v {
+            0 => Self::Present,
+            1 => Self::Data,
+            2 => Self::Length,
+            3 => Self::DictionaryData,
+            5 => Self::Secondary,
+            v => Self::Other(v as u32),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct StreamInformation {
+    pub kind: StreamKind,
+    pub column: u32,
+    pub length: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColumnEncodingKind {
+    #[default]
+    Direct,
+    Dictionary,
+    DirectV2,
+    DictionaryV2,
+}
+
+impl ColumnEncodingKind {
+    pub fn is_dictionary(self) -> bool {
+        matches!(self, Self::Dictionary | Self::DictionaryV2)
+    }
+
+    pub fn is_v2(self) -> bool {
+        matches!(self, Self::DirectV2 | Self::DictionaryV2)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ColumnEncoding {
+    pub kind: ColumnEncodingKind,
+    pub dictionary_size: u32,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct StripeFooter {
+    pub streams: Vec<StreamInformation>,
+    /// Encodings, indexed by column (type) id.
+    pub columns: Vec<ColumnEncoding>,
+}
+
+impl StripeFooter {
+    pub(super) fn decode(bytes: &[u8]) -> PolarsResult<Self> {
+        let mut r = ProtoReader::new(bytes);
+        let mut out = Self::default();
+
+        while let Some((field, wire_type)) = r.next_field()? {
+            match field {
+                1 => {
+                    let mut s = ProtoReader::new(r.read_bytes()?);
+                    let mut stream = StreamInformation {
+                        kind: StreamKind::Other(u32::MAX),
+                        column: 0,
+                        length: 0,
+                    };
+
+                    while let Some((field, wire_type)) = s.next_field()? {
+                        match field {
+                            1 => stream.kind = StreamKind::from_proto(s.read_varint()?),
+                            2 => stream.column = s.read_varint()? as u32,
+                            3 => stream.length = s.read_varint()?,
+                            _ => s.skip(wire_type)?,
+                        }
+                    }
+
+                    out.streams.push(stream);
+                },
+                2 => {
+                    let mut e = ProtoReader::new(r.read_bytes()?);
+                    let mut encoding = ColumnEncoding::default();
+
+                    while let Some((field, wire_type)) = e.next_field()? {
+                        match field {
+                            1 => {
+                                encoding.kind = match e.read_varint()? {
+                                    0 => ColumnEncodingKind::Direct,
+                                    1 => ColumnEncodingKind::Dictionary,
+                                    2 => ColumnEncodingKind::DirectV2,
+                                    3 => ColumnEncodingKind::DictionaryV2,
+                                    v => polars_bail!(
+                                        ComputeError: "unknown orc column encoding: {}", v
+                                    ),
+                                }
+                            },
+                            2 => encoding.dictionary_size = e.read_varint()? as u32,
+                            _ => e.skip(wire_type)?,
+                        }
+                    }
+
+                    out.columns.push(encoding);
+                },
+                _ => r.skip(wire_type)?,
+            }
+        }
+
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_proto_reader() -> PolarsResult<()> {
+        // field 1 varint 300, field 2 string "ab", field 3 packed [1, 2], field 4 sint64 -2
+        let bytes = [
+            0x08, 0xAC, 0x02, 0x12, 0x02, b'a', b'b', 0x1A, 0x02, 0x01, 0x02, 0x20, 0x03,
+        ];
+        let mut r = ProtoReader::new(&bytes);
+
+        assert_eq!(r.next_field()?, Some((1, WireType::Varint)));
+        assert_eq!(r.read_varint()?, 300);
+        assert_eq!(r.next_field()?, Some((2, WireType::LengthDelimited)));
+        assert_eq!(r.read_string()?, "ab");
+        let (field, wire_type) = r.next_field()?.unwrap();
+        assert_eq!(field, 3);
+        let mut values = vec![];
+        r.read_repeated_varint(wire_type, &mut values)?;
+        assert_eq!(values, [1, 2]);
+        assert_eq!(r.next_field()?, Some((4, WireType::Varint)));
+        assert_eq!(r.read_sint64()?, -2);
+        assert_eq!(r.next_field()?, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_decode_postscript() -> PolarsResult<()> {
+        // footerLength = 5, compression = ZLIB, compressionBlockSize = 1024, magic = "ORC"
+        let bytes = [
+            0x08, 0x05, 0x10, 0x01, 0x18, 0x80, 0x08, 0x82, 0xF4, 0x03, 0x03, b'O', b'R', b'C',
+        ];
+        let ps = PostScript::decode(&bytes)?;
+
+        assert_eq!(ps.footer_length, 5);
+        assert_eq!(ps.compression, OrcCompression::Zlib);
+        assert_eq!(ps.compression_block_size, 1024);
+        assert_eq!(ps.metadata_length, 0);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,486 @@
+//! Run-length decoders for the ORC stream encodings.
+
+use arrow::bitmap::{Bitmap, MutableBitmap};
+use polars_core::prelude::*;
+
+fn truncated() -> PolarsError {
+    polars_err!(ComputeError: "invalid orc stream: unexpected end of run-length encoded data")
+}
+
+#[inline]
+fn zigzag_decode(v: u64) -> i64 {
+    ((v >> 1) as i64) ^ -((v & 1) as i64)
+}
+
+struct Cursor<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, pos: 0 }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.pos >= self.bytes.len()
+    }
+
+    fn read_u8(&mut self) -> PolarsResult<u8> {
+        let v = *self.bytes.get(self.pos).ok_or_else(truncated)?;
+        self.pos += 1;
+        Ok(v)
+    }
+
+    fn read_varint(&mut self) -> PolarsResult<u64> {
+        let mut value: u64 = 0;
+        for i in 0..10 {
+            let byte = self.read_u8()?;
+            value |= u64::from(byte & 0x7F) << (i * 7);
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        polars_bail!(ComputeError: "invalid orc stream: corrupt variable-length integer")
+    }
+
+    fn read_int(&mut self, signed: bool) -> PolarsResult<i64> {
+        let v = self.read_varint()?;
+        Ok(if signed { zigzag_decode(v) } else { v as i64 })
+    }
+
+    /// Reads an unsigned big-endian integer of `n_bytes` bytes.
+    fn read_be(&mut self, n_bytes: usize) -> PolarsResult<u64> {
+        let bytes = self
+            .bytes
+            .get(self.pos..self.pos + n_bytes)
+            .ok_or_else(truncated)?;
+        self.pos += n_bytes;
+        Ok(bytes.iter().fold(0u64, |acc, &b| (acc << 8) | u64::from(b)))
+    }
+
+    /// Reads `n` big-endian bit-packed values of `width` bits. The cursor is left at the start of
+    /// the next byte.
+    fn read_bit_packed(&mut self, width: u32, n: usize, out: &mut Vec<u64>) -> PolarsResult<()> {
+        polars_ensure!(
+            (n * width as usize).div_ceil(8) <= self.bytes.len() - self.pos,
+            ComputeError: "invalid orc stream: unexpected end of bit-packed data"
+        );
+
+        let mut current = 0u8;
+        let mut bits_left = 0u32;
+
+        for _ in 0..n {
+            let mut value = 0u64;
+            let mut bits_needed = width;
+
+            while bits_needed > 0 {
+                if bits_left == 0 {
+                    current = self.bytes[self.pos];
+                    self.pos += 1;
+                    bits_left = 8;
+                }
+
+                let take = bits_left.min(bits_needed);
+                let bits = (u32::from(current) >> (bits_left - take)) & ((1u32 << take) - 1);
+                value = (value << take) | u64::from(bits);
+                bits_left -= take;
+                bits_needed -= take;
+            }
+
+            out.push(value);
+        }
+
+        Ok(())
+    }
+}
+
+/// Decodes `n` bytes of a byte run-length encoded stream.
+pub fn decode_byte_rle(bytes: &[u8], n: usize) -> PolarsResult<Vec<u8>> {
+    let mut cursor = Cursor::new(bytes);
+    let mut out = Vec::with_capacity(n);
+
+    while out.len() < n {
+        let header = cursor.read_u8()? as i8;
+
+        if header >= 0 {
+            let run_len = header as usize + 3;
+            let value = cursor.read_u8()?;
+            out.extend(std::iter::repeat_n(value, run_len));
+        } else {
+            let n_literals = -(header as isize) as usize;
+            let literals = cursor
+                .bytes
+                .get(cursor.pos..cursor.pos + n_literals)
+                .ok_or_else(truncated)?;
+            cursor.pos += n_literals;
+            out.extend_from_slice(literals);
+        }
+    }
+
+    out.truncate(n);
+    Ok(out)
+}
+
+/// Decodes `n` booleans of a boolean stream, which is a byte run-length encoded stream of
+/// MSB-first bit-packed values.
+pub fn decode_bool_rle(bytes: &[u8], n: usize) -> PolarsResult<Bitmap> {
+    let packed = decode_byte_rle(bytes, n.div_ceil(8))?;
+    let mut out = MutableBitmap::with_capacity(n);
+
+    for i in 0..n {
+        out.push(packed[i / 8] & (0x80 >> (i % 8)) != 0);
+    }
+
+    Ok(out.freeze())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RleVersion {
+    V1,
+    V2,
+}
+
+/// Decodes `n` integers of an integer run-length encoded stream. Unsigned values are returned
+/// reinterpreted as `i64`.
+pub fn decode_int_rle(
+    bytes: &[u8],
+    n: usize,
+    version: RleVersion,
+    signed: bool,
+) -> PolarsResult<Vec<i64>> {
+    let mut cursor = Cursor::new(bytes);
+    let mut out = Vec::with_capacity(n);
+
+    while out.len() < n {
+        if cursor.is_empty() {
+            return Err(truncated());
+        }
+
+        match version {
+            RleVersion::V1 => decode_int_rle_v1_run(&mut cursor, signed, &mut out)?,
+            RleVersion::V2 => decode_int_rle_v2_run(&mut cursor, signed, &mut out)?,
+        }
+    }
+
+    out.truncate(n);
+    Ok(out)
+}
+
+fn decode_int_rle_v1_run(
+    cursor: &mut Cursor<'_>,
+    signed: bool,
+    out: &mut Vec<i64>,
+) -> PolarsResult<()> {
+    let header = cursor.read_u8()? as i8;
+
+    if header >= 0 {
+        let run_len = header as usize + 3;
+        let delta = i64::from(cursor.read_u8()? as i8);
+        let base = cursor.read_int(signed)?;
+        out.extend((0..run_len as i64).map(|i| base.wrapping_add(i.wrapping_mul(delta))));
+    } else {
+        for _ in 0..-(header as isize) {
+            out.push(cursor.read_int(signed)?);
+        }
+    }
+
+    Ok(())
+}
+
+/// Decodes the 5 bit width encoding used by integer RLE v2.
+fn decode_bit_width(code: u8) -> u32 {
+    match code {
+        0..=23 => u32::from(code) + 1,
+        24 => 26,
+        25 => 28,
+        26 => 30,
+        27 => 32,
+        28 => 40,
+        29 => 48,
+        30 => 56,
+        _ => 64,
+    }
+}
+
+fn closest_fixed_bits(n: u32) -> u32 {
+    match n {
+        0 => 1,
+        1..=24 => n,
+        25..=26 => 26,
+        27..=28 => 28,
+        29..=30 => 30,
+        31..=32 => 32,
+        33..=40 => 40,
+        41..=48 => 48,
+        49..=56 => 56,
+        _ => 64,
+    }
+}
+
+fn decode_int_rle_v2_run(
+    cursor: &mut Cursor<'_>,
+    signed: bool,
+    out: &mut Vec<i64>,
+) -> PolarsResult<()> {
+    let first = cursor.read_u8()?;
+    let decode = |v: u64| if signed { zigzag_decode(v) } else { v as i64 };
+
+    match first >> 6 {
+        // Short repeat
+        0 => {
+            let width = ((first >> 3) & 0x7) as usize + 1;
+            let count = (first & 0x7) as usize + 3;
+            let value = decode(cursor.read_be(width)?);
+            out.extend(std::iter::repeat_n(value, count));
+        },
+        // Direct
+        1 => {
+            let width = decode_bit_width((first >> 1) & 0x1F);
+            let len = ((usize::from(first & 1) << 8) | usize::from(cursor.read_u8()?)) + 1;
+            let mut values = Vec::with_capacity(len);
+            cursor.read_bit_packed(width, len, &mut values)?;
+            out.extend(values.into_iter().map(decode));
+        },
+        // Patched base
+        2 => {
+            let width = decode_bit_width((first >> 1) & 0x1F);
+            let len = ((usize::from(first & 1) << 8) | usize::from(cursor.read_u8()?)) + 1;
+
+            let third = cursor.read_u8()?;
+            let base_width = usize::from(third >> 5) + 1;
+            let patch_width = decode_bit_width(third & 0x1F);
+
+            let fourth = cursor.read_u8()?;
+            let patch_gap_width = u32::from(fourth >> 5) + 1;
+            let patch_list_len = usize::from(fourth & 0x1F);
+
+            // The base is stored in sign-magnitude representation.
+            let base = cursor.read_be(base_width)?;
+            let sign_mask = 1u64 << (base_width * 8 - 1);
+            let base = if base & sign_mask != 0 {
+                -((base & !sign_mask) as i64)
+            } else {
+                base as i64
+            };
+
+            let mut values = Vec::with_capacity(len);
+            cursor.read_bit_packed(width, len, &mut values)?;
+
+            let mut patches = Vec::with_capacity(patch_list_len);
+            cursor.read_bit_packed(
+                closest_fixed_bits(patch_width + patch_gap_width),
+                patch_list_len,
+                &mut patches,
+            )?;
+
+            let patch_mask = if patch_width >= 64 {
+                u64::MAX
+            } else {
+                (1u64 << patch_width) - 1
+            };
+            let mut idx = 0usize;
+
+            for patch in patches {
+                let gap = (patch >> patch_width) as usize;
+                let patch = patch & patch_mask;
+
+                idx += gap;
+                // A gap of 255 without a patch value only advances the position.
+                if gap == 255 && patch == 0 {
+                    continue;
+                }
+
+                let value = values.get_mut(idx).ok_or_else(
+                    || polars_err!(ComputeError: "invalid orc stream: patch out of bounds"),
+                )?;
+                *value |= patch.checked_shl(width).unwrap_or(0);
+            }
+
+            out.extend(values.into_iter().map(|v| base.wrapping_add(v as i64)));
+        },
+        // Delta
+        _ => {
+            let width_code = (first >> 1) & 0x1F;
+            let len = ((usize::from(first & 1) << 8) | usize::from(cursor.read_u8()?)) + 1;
+
+            let base = cursor.read_int(signed)?;
+            let delta_base = cursor.read_int(true)?;
+
+            out.push(base);
+            if len == 1 {
+                return Ok(());
+            }
+
+            if width_code == 0 {
+                // Fixed delta.
+                let mut v = base;
+                for _ in 1..len {
+                    v = v.wrapping_add(delta_base);
+                    out.push(v);
+                }
+            } else {
+                let mut v = base.wrapping_add(delta_base);
+                out.push(v);
+
+                let mut deltas = Vec::with_capacity(len - 2);
+                cursor.read_bit_packed(decode_bit_width(width_code), len - 2, &mut deltas)?;
+
+                for d in deltas {
+                    v = if delta_base < 0 {
+                        v.wrapping_sub(d as i64)
+                    } else {
+                        v.wrapping_add(d as i64)
+                    };
+                    out.push(v);
+                }
+            }
+        },
+    }
+
+    Ok(())
+}
+
+#[cfg(feature = "dtype-decimal")]
+/// Decodes `n` unbounded zig-zag encoded base-128 varints, as used by the decimal data stream.
+pub fn decode_varint_i128(bytes: &[u8], n: usize) -> PolarsResult<Vec<i128>> {
+    let mut out = Vec::with_capacity(n);
+    let mut pos = 0;
+
+    for _ in 0..n {
+        let mut value: u128 = 0;
+        let mut shift = 0;
+
+        loop {
+            let byte = *bytes.get(pos).ok_or_else(truncated)?;
+            pos += 1;
+
+            polars_ensure!(
+                shift < 128,
+                ComputeError: "invalid orc stream: decimal value does not fit in 128 bits"
+            );
+            value |= u128::from(byte & 0x7F) << shift;
+            shift += 7;
+
+            if byte & 0x80 == 0 {
+                break;
+            }
+        }
+
+        out.push(((value >> 1) as i128) ^ -((value & 1) as i128));
+    }
+
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Examples are taken from the ORC specification.
+
+    #[test]
+    fn test_byte_rle() -> PolarsResult<()> {
+        assert_eq!(decode_byte_rle(&[0x61, 0x00], 100)?, vec![0; 100]);
+        assert_eq!(decode_byte_rle(&[0xfe, 0x44, 0x45], 2)?, vec![0x44, 0x45]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_bool_rle() -> PolarsResult<()> {
+        let out = decode_bool_rle(&[0xff, 0x80], 8)?;
+        assert_eq!(
+            out.iter().collect::<Vec<_>>(),
+            [true, false, false, false, false, false, false, false]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_int_rle_v1() -> PolarsResult<()> {
+        assert_eq!(
+            decode_int_rle(&[0x61, 0x00, 0x07], 100, RleVersion::V1, false)?,
+            vec![7; 100]
+        );
+        assert_eq!(
+            decode_int_rle(&[0x61, 0xff, 0x64], 100, RleVersion::V1, false)?,
+            (1..=100).rev().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            decode_int_rle(
+                &[0xfb, 0x02, 0x03, 0x06, 0x07, 0x0b],
+                5,
+                RleVersion::V1,
+                false
+            )?,
+            vec![2, 3, 6, 7, 11]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_int_rle_v2_short_repeat() -> PolarsResult<()> {
+        assert_eq!(
+            decode_int_rle(&[0x0a, 0x27, 0x10], 5, RleVersion::V2, false)?,
+            vec![10000; 5]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_int_rle_v2_direct() -> PolarsResult<()> {
+        assert_eq!(
+            decode_int_rle(
+                &[0x5e, 0x03, 0x5c, 0xa1, 0xab, 0x1e, 0xde, 0xad, 0xbe, 0xef],
+                4,
+                RleVersion::V2,
+                false
+            )?,
+            vec![23713, 43806, 57005, 48879]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_int_rle_v2_patched_base() -> PolarsResult<()> {
+        let bytes = [
+            0x8e, 0x13, 0x2b, 0x21, 0x07, 0xd0, 0x1e, 0x00, 0x14, 0x70, 0x28, 0x32, 0x3c, 0x46,
+            0x50, 0x5a, 0x64, 0x6e, 0x78, 0x82, 0x8c, 0x96, 0xa0, 0xaa, 0xb4, 0xbe, 0xfc, 0xe8,
+        ];
+        let expected = [
+            2030, 2000, 2020, 1000000, 2040, 2050, 2060, 2070, 2080, 2090, 2100, 2110, 2120, 2130,
+            2140, 2150, 2160, 2170, 2180, 2190,
+        ];
+        assert_eq!(decode_int_rle(&bytes, 20, RleVersion::V2, false)?, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_int_rle_v2_delta() -> PolarsResult<()> {
+        assert_eq!(
+            decode_int_rle(
+                &[0xc6, 0x09, 0x02, 0x02, 0x22, 0x42, 0x42, 0x46],
+                10,
+                RleVersion::V2,
+                false
+            )?,
+            vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29]
+        );
+        // Fixed delta
+        assert_eq!(
+            decode_int_rle(&[0xc0, 0x04, 0x00, 0x03], 5, RleVersion::V2, true)?,
+            vec![0, -2, -4, -6, -8]
+        );
+        Ok(())
+    }
+
+    #[cfg(feature = "dtype-decimal")]
+    #[test]
+    fn test_decode_varint_i128() -> PolarsResult<()> {
+        assert_eq!(
+            decode_varint_i128(&[0x00, 0x01, 0x02, 0xac, 0x02], 4)?,
+            vec![0, -1, 1, 150]
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,159 @@
+//! GeoParquet metadata for WKB geometry columns.
+//!
+//! Geometry columns are described by a JSON document under the `geo` file metadata key, see
+//! <https://geoparquet.org/releases/v1.1.0/>.
+use arrow::datatypes::{ArrowDataType, ArrowSchema, ExtensionType};
+use polars_core::datatypes::extension::GEOARROW_WKB_EXTENSION_NAME;
+use polars_error::polars_warn;
+use polars_parquet::write::KeyValue;
+use polars_utils::pl_str::PlSmallStr;
+use serde_json::{Map, Value, json};
+
+const GEOPARQUET_METADATA_KEY: &str = "geo";
+const GEOPARQUET_VERSION: &str = "1.1.0";
+
+/// Build the `geo` file metadata for the `geoarrow.wkb` columns of `schema`, if there are any.
+pub(super) fn geoparquet_metadata(schema: &ArrowSchema) -> Option<KeyValue> {
+    let mut primary_column = None;
+    let mut columns = Map::new();
+    for field in schema.iter_values() {
+        let ArrowDataType::Extension(ext) = &field.dtype else {
+            continue;
+        };
+        if ext.name != GEOARROW_WKB_EXTENSION_NAME {
+            continue;
+        }
+
+        let mut column = json!({ "encoding": "WKB", "geometry_types": [] });
+        // The GeoArrow metadata holds the same `crs` and `edges` members as GeoParquet.
+        if let Some(Value::Object(md)) = ext
+            .metadata
+            .as_deref()
+            .and_then(|md| serde_json::from_str(md).ok())
+        {
+            for key in ["crs", "edges"] {
+                if let Some(v) = md.get(key) {
+                    column[key] = v.clone();
+                }
+            }
+        }
+        primary_column.get_or_insert_with(|| field.name.to_string());
+        columns.insert(field.name.to_string(), column);
+    }
+
+    let primary_column = primary_column?;
+    let value = json!({
+        "version": GEOPARQUET_VERSION,
+        "primary_column": primary_column,
+        "columns": columns,
+    });
+    Some(KeyValue {
+        key: GEOPARQUET_METADATA_KEY.to_string(),
+        value: Some(value.to_string()),
+    })
+}
+
+/// Mark the WKB columns listed in the `geo` file metadata as `geoarrow.wkb` in `schema`.
+///
+/// Malformed metadata is ignored with a warning, the columns are then read as plain binary.
+pub(super) fn apply_geoparquet_metadata(
+    schema: &mut ArrowSchema,
+    key_value_metadata: &Option<Vec<KeyValue>>,
+) {
+    let Some(geo) = key_value_metadata
+        .iter()
+        .flatten()
+        .find(|kv| kv.key == GEOPARQUET_METADATA_KEY)
+        .and_then(|kv| kv.value.as_deref())
+    else {
+        return;
+    };
+    let geo: Value = match serde_json::from_str(geo) {
+        Ok(geo) => geo,
+        Err(e) => {
+            polars_warn!("ignoring invalid GeoParquet metadata: {e}");
+            return;
+        },
+    };
+    let Some(columns) = geo.get("columns").and_then(Value::as_object) else {
+        return;
+    };
+
+    for (name, column) in columns {
+        let is_wkb = column
+            .get("encoding")
+            .and_then(Value::as_str)
+            .is_some_and(|e| e.eq_ignore_ascii_case("WKB"));
+        let Some(field) = schema.get_mut(name.as_str()) else {
+            continue;
+        };
+        if !is_wkb
+            || !matches!(
+                field.dtype,
+                ArrowDataType::Binary | ArrowDataType::LargeBinary | ArrowDataType::BinaryView
+            )
+        {
+            continue;
+        }
+
+        let mut md = Map::new();
+        for key in ["crs", "edges"] {
+            if let Some(v) = column.get(key) {
+                md.insert(key.to_string(), v.clone());
+            }
+        }
+        let inner = std::mem::take(&mut field.dtype);
+        field.dtype = ArrowDataType::Extension(Box::new(ExtensionType {
+            name: PlSmallStr::from_static(GEOARROW_WKB_EXTENSION_NAME),
+            inner,
+            metadata: (!md.is_empty()).then(|| Value::Object(md).to_string().into()),
+        }));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow::datatypes::Field;
+
+    use super::*;
+
+    #[test]
+    fn test_geoparquet_metadata_roundtrip() {
+        let geometry = ArrowDataType::Extension(Box::new(ExtensionType {
+            name: PlSmallStr::from_static(GEOARROW_WKB_EXTENSION_NAME),
+            inner: ArrowDataType::BinaryView,
+            metadata: Some(r#"{"crs":"OGC:CRS84"}"#.into()),
+        }));
+        let schema = ArrowSchema::from_iter([
+            Field::new("id".into(), ArrowDataType::Int64, true),
+            Field::new("geom".into(), geometry.clone(), true),
+        ]);
+
+        let kv = geoparquet_metadata(&schema).unwrap();
+        let geo: Value = serde_json::from_str(kv.value.as_deref().unwrap()).unwrap();
+        assert_eq!(geo["primary_column"], "geom");
+        assert_eq!(geo["columns"]["geom"]["encoding"], "WKB");
+        assert_eq!(geo["columns"]["geom"]["crs"], "OGC:CRS84");
+
+        // Files written by other tools store the geometries as plain binary.
+        let mut read_schema = ArrowSchema::from_iter([
+            Field::new("id".into(), ArrowDataType::Int64, true),
+            Field::new("geom".into(), ArrowDataType::BinaryView, true),
+        ]);
+        apply_geoparquet_metadata(&mut read_schema, &Some(vec![kv]));
+        assert_eq!(read_schema, schema);
+    }
+
+    #[test]
+    fn test_geoparquet_metadata_invalid() {
+        let schema =
+            ArrowSchema::from_iter([Field::new("geom".into(), ArrowDataType::BinaryView, true)]);
+        let mut read_schema = schema.clone();
+        let kv = KeyValue {
+            key: GEOPARQUET_METADATA_KEY.to_string(),
+            value: Some("{not json".to_string()),
+        };
+        apply_geoparquet_metadata(&mut read_schema, &Some(vec![kv]));
+        assert_eq!(read_schema, schema);
+    }
+}
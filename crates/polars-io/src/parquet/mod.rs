@@ -1,5 +1,7 @@
 //! Functionality for reading and writing Apache Parquet files.
 
+#[cfg(feature = "geo")]
+mod geo;
 pub mod metadata;
 pub mod read;
 pub mod write;
@@ -70,7 +70,7 @@ impl ParquetObjectStore {
             Some(schema) => Arc::clone(schema),
             None => {
                 let metadata = self.get_metadata().await?;
-                let arrow_schema = polars_parquet::arrow::read::infer_schema(metadata)?;
+                let arrow_schema = super::infer_schema(metadata)?;
                 Arc::new(arrow_schema)
             },
         });
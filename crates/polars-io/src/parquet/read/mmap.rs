@@ -4,9 +4,11 @@ use arrow::array::Array;
 use arrow::bitmap::Bitmap;
 use arrow::datatypes::Field;
 use polars_buffer::Buffer;
-use polars_error::PolarsResult;
+use polars_core::frame::column::{DictionaryColumn, RunEndColumn};
+use polars_core::prelude::*;
 use polars_parquet::read::{
     BasicDecompressor, ColumnChunkMetadata, Filter, PageReader, column_iter_to_arrays,
+    page_iter_to_dictionary_keys, page_iter_to_runs,
 };
 use polars_utils::mem::prefetch::prefetch_l2;
 
@@ -73,3 +75,105 @@ pub fn to_deserializer(
 
     column_iter_to_arrays(columns, types, field, filter)
 }
+
+/// The minimum average length of the runs for a column chunk to be decoded as a
+/// [`RunEndColumn`].
+const MIN_AVG_RUN_LENGTH: usize = 16;
+
+/// Try to decode a flat, dictionary encoded column chunk into a run-end encoded [`Column`].
+///
+/// Returns `None` if the column chunk should be decoded densely with [`to_deserializer`] instead.
+/// Whether the column chunk has long enough runs is only known after decoding it, so a column
+/// chunk with too many runs is not decoded again but returned as a materialized [`Column`].
+pub fn to_run_end_column(
+    columns: &[(&ColumnChunkMetadata, Buffer<u8>)],
+    field: &Field,
+    filter: Option<&Filter>,
+) -> PolarsResult<Option<Column>> {
+    let [(column_meta, chunk)] = columns else {
+        return Ok(None);
+    };
+    let range = match filter {
+        None => None,
+        Some(Filter::Range(range)) => Some(range.clone()),
+        Some(_) => return Ok(None),
+    };
+
+    let max_runs = column_meta.num_values() as usize / MIN_AVG_RUN_LENGTH;
+    if max_runs == 0 {
+        return Ok(None);
+    }
+
+    let pages = PageReader::new(Cursor::new(chunk.clone()), column_meta, vec![], usize::MAX);
+    let pages = BasicDecompressor::new(pages, vec![]);
+    let type_ = &column_meta.descriptor().descriptor.primitive_type;
+    let Some(runs) = page_iter_to_runs(pages, type_, field)? else {
+        return Ok(None);
+    };
+
+    let dict = Series::try_from((field, vec![runs.dict]))?;
+    let keys = IdxCa::from_iter_options(
+        PlSmallStr::EMPTY,
+        runs.keys.iter().map(|key| key.map(|key| key as IdxSize)),
+    );
+    let values = dict.take(&keys)?;
+    let run_ends = runs
+        .run_ends
+        .iter()
+        .map(|&end| end as IdxSize)
+        .collect::<Vec<_>>();
+    // SAFETY: The run ends are strictly increasing and there is a value for every run.
+    let column =
+        unsafe { RunEndColumn::new_unchecked(field.name.clone(), values, run_ends.into()) };
+    let is_long_runs = column.n_runs() <= max_runs;
+
+    let column = match range {
+        None => column,
+        Some(range) => column.slice(range.start as i64, range.len()),
+    };
+
+    if !is_long_runs {
+        return Ok(Some(column.take_materialized_series().into_column()));
+    }
+    Ok(Some(column.into()))
+}
+
+/// Try to decode a flat, dictionary encoded String or Binary column chunk into a
+/// [`DictionaryColumn`].
+///
+/// Returns `None` if the column chunk should be decoded densely with [`to_deserializer`] instead.
+pub fn to_dictionary_column(
+    columns: &[(&ColumnChunkMetadata, Buffer<u8>)],
+    field: &Field,
+    filter: Option<&Filter>,
+) -> PolarsResult<Option<Column>> {
+    let [(column_meta, chunk)] = columns else {
+        return Ok(None);
+    };
+    let range = match filter {
+        None => None,
+        Some(Filter::Range(range)) => Some(range.clone()),
+        Some(_) => return Ok(None),
+    };
+
+    let pages = PageReader::new(Cursor::new(chunk.clone()), column_meta, vec![], usize::MAX);
+    let pages = BasicDecompressor::new(pages, vec![]);
+    let type_ = &column_meta.descriptor().descriptor.primitive_type;
+    let Some(dict_keys) = page_iter_to_dictionary_keys(pages, type_, field)? else {
+        return Ok(None);
+    };
+
+    let values = Series::try_from((field, vec![dict_keys.dict]))?;
+    let keys = UInt32Chunked::with_chunk(PlSmallStr::EMPTY, dict_keys.keys)
+        .into_series()
+        .cast(&IDX_DTYPE)?;
+    // SAFETY: The keys were bounds checked while decoding.
+    let column =
+        unsafe { DictionaryColumn::new_unchecked(field.name.clone(), values, keys.idx()?.clone()) };
+
+    let column = match range {
+        None => column,
+        Some(range) => column.slice(range.start as i64, range.len()),
+    };
+    Ok(Some(column.into()))
+}
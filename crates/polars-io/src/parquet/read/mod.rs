@@ -29,18 +29,30 @@ consider compiling with polars-bigidx feature (pip install polars[rt64]), \
 or set 'streaming'",
 ));
 
+use arrow::datatypes::ArrowSchema;
 #[cfg(feature = "cloud")]
 pub use async_impl::ParquetObjectStore;
 pub use options::{ParallelStrategy, ParquetOptions};
-use polars_error::{ErrString, PolarsError};
-pub use polars_parquet::arrow::read::infer_schema;
+use polars_error::{ErrString, PolarsError, PolarsResult};
 pub use polars_parquet::read::FileMetadata;
-pub use read_impl::{create_sorting_map, try_set_sorted_flag};
+pub use read_impl::{create_sorting_map, try_set_sorted_flag, try_set_sorted_flag_column};
 pub use reader::ParquetReader;
 pub use utils::materialize_empty_df;
 
 pub mod _internal {
-    pub use super::mmap::to_deserializer;
+    pub use super::mmap::{to_deserializer, to_dictionary_column, to_run_end_column};
     pub use super::read_impl::{PrefilterMaskSetting, calc_prefilter_cost};
     pub use super::utils::ensure_matching_dtypes_if_found;
 }
+
+/// Infers the [`ArrowSchema`] of a parquet file from its [`FileMetadata`].
+///
+/// Unlike [`polars_parquet::arrow::read::infer_schema`], this also marks the geometry columns
+/// described by GeoParquet metadata.
+pub fn infer_schema(file_metadata: &FileMetadata) -> PolarsResult<ArrowSchema> {
+    #[allow(unused_mut)]
+    let mut schema = polars_parquet::arrow::read::infer_schema(file_metadata)?;
+    #[cfg(feature = "geo")]
+    super::geo::apply_geoparquet_metadata(&mut schema, file_metadata.key_value_metadata());
+    Ok(schema)
+}
@@ -10,6 +10,11 @@ pub struct ParquetOptions {
     pub parallel: ParallelStrategy,
     pub low_memory: bool,
     pub use_statistics: bool,
+    /// Keep dictionary encoded String and Binary columns as dictionary columns.
+    pub keep_dictionaries: bool,
+    /// Keep dictionary encoded columns that consist of a few long runs, e.g. mostly null or
+    /// constant columns, as run-end encoded columns.
+    pub keep_runs: bool,
 }
 
 impl Default for ParquetOptions {
@@ -19,6 +24,8 @@ impl Default for ParquetOptions {
             parallel: ParallelStrategy::default(),
             low_memory: false,
             use_statistics: true,
+            keep_dictionaries: false,
+            keep_runs: false,
         }
     }
 }
@@ -51,31 +51,108 @@ fn assert_dtypes(dtype: &ArrowDataType) {
     }
 }
 
+/// Whether the Parquet sort order of the physical type matches the Polars sort order.
 fn should_copy_sortedness(dtype: &DataType) -> bool {
-    // @NOTE: For now, we are a bit conservative with this.
     use DataType as D;
 
     matches!(
         dtype,
-        D::Int8 | D::Int16 | D::Int32 | D::Int64 | D::UInt8 | D::UInt16 | D::UInt32 | D::UInt64
+        D::Int8
+            | D::Int16
+            | D::Int32
+            | D::Int64
+            | D::UInt8
+            | D::UInt16
+            | D::UInt32
+            | D::UInt64
+            | D::Boolean
+            | D::String
+            | D::Binary
+            | D::Date
+            | D::Datetime(..)
+            | D::Duration(_)
+            | D::Time
     )
 }
 
 pub fn try_set_sorted_flag(series: &mut Series, col_idx: usize, sorting_map: &[(usize, IsSorted)]) {
-    let Some((sorted_col, is_sorted)) = sorting_map.first() else {
+    let Some(position) = sorting_map.iter().position(|(i, _)| *i == col_idx) else {
         return;
     };
-    if *sorted_col != col_idx || !should_copy_sortedness(series.dtype()) {
+
+    // The sorting columns describe a lexicographic order, so only the first column is sorted over
+    // the whole row group. The others are only sorted within runs of equal preceding values and
+    // have to be checked.
+    if position > 0 && !is_sorted(series, sorting_map[position].1) {
         return;
     }
+
+    if let Some(is_sorted) = sorted_flag(
+        series.name(),
+        series.dtype(),
+        col_idx,
+        &sorting_map[position..],
+    ) {
+        series.set_sorted_flag(is_sorted);
+    }
+}
+
+/// Checks that `series` is sorted in `order` with the nulls at either end.
+fn is_sorted(series: &Series, order: IsSorted) -> bool {
+    let null_count = series.null_count();
+
+    if null_count > 0
+        && series.head(Some(null_count)).null_count() != null_count
+        && series.tail(Some(null_count)).null_count() != null_count
+    {
+        return false;
+    }
+
+    let values = series.drop_nulls();
+    if values.len() <= 1 {
+        return true;
+    }
+
+    let (lhs, rhs) = (
+        values.slice(0, values.len() - 1),
+        values.slice(1, values.len() - 1),
+    );
+    let in_order = match order {
+        IsSorted::Ascending => lhs.lt_eq(&rhs),
+        IsSorted::Descending => lhs.gt_eq(&rhs),
+        IsSorted::Not => return false,
+    };
+
+    in_order.is_ok_and(|x| x.all())
+}
+
+/// [`try_set_sorted_flag`] for columns that were not decoded into a [`Series`], e.g. run-end
+/// encoded columns.
+pub fn try_set_sorted_flag_column(
+    column: &mut Column,
+    col_idx: usize,
+    sorting_map: &[(usize, IsSorted)],
+) {
+    if let Some(is_sorted) = sorted_flag(column.name(), column.dtype(), col_idx, sorting_map) {
+        column.set_sorted_flag(is_sorted);
+    }
+}
+
+fn sorted_flag(
+    name: &PlSmallStr,
+    dtype: &DataType,
+    col_idx: usize,
+    sorting_map: &[(usize, IsSorted)],
+) -> Option<IsSorted> {
+    let (sorted_col, is_sorted) = sorting_map.first()?;
+    if *sorted_col != col_idx || !should_copy_sortedness(dtype) {
+        return None;
+    }
     if config::verbose() {
-        eprintln!(
-            "Parquet conserved SortingColumn for column chunk of '{}' to {is_sorted:?}",
-            series.name()
-        );
+        eprintln!("Parquet conserved SortingColumn for column chunk of '{name}' to {is_sorted:?}");
     }
 
-    series.set_sorted_flag(*is_sorted);
+    Some(*is_sorted)
 }
 
 pub fn create_sorting_map(md: &RowGroupMetadata) -> Vec<(usize, IsSorted)> {
@@ -119,6 +196,33 @@ fn column_idx_to_series(
     Ok((series, pred_true_mask))
 }
 
+/// Decode a column. If `keep_runs` is set, columns that consist of a few long runs are kept run-end
+/// encoded.
+#[allow(clippy::too_many_arguments)]
+fn column_idx_to_column(
+    column_i: usize,
+    field_md: &[&ColumnChunkMetadata],
+    filter: Filter,
+    file_schema: &ArrowSchema,
+    store: &mmap::ColumnStore,
+    sorting_map: &[(usize, IsSorted)],
+    keep_runs: bool,
+) -> PolarsResult<Column> {
+    if keep_runs {
+        let field = file_schema.get_at_index(column_i).unwrap().1;
+        let columns = mmap_columns(store, field_md);
+        if let Some(mut column) = mmap::to_run_end_column(&columns, field, Some(&filter))? {
+            try_set_sorted_flag_column(&mut column, column_i, sorting_map);
+            return Ok(column);
+        }
+    }
+
+    let (mut series, _) =
+        column_idx_to_series(column_i, field_md, Some(filter), file_schema, store)?;
+    try_set_sorted_flag(&mut series, column_i, sorting_map);
+    Ok(series.into_column())
+}
+
 #[allow(clippy::too_many_arguments)]
 fn rg_to_dfs(
     store: &mmap::ColumnStore,
@@ -132,6 +236,7 @@ fn rg_to_dfs(
     parallel: ParallelStrategy,
     projection: &[usize],
     hive_partition_columns: Option<&[Series]>,
+    keep_runs: bool,
 ) -> PolarsResult<Vec<DataFrame>> {
     if config::verbose() {
         eprintln!("parquet scan with parallel = {parallel:?}");
@@ -168,6 +273,7 @@ fn rg_to_dfs(
             parallel,
             projection,
             hive_partition_columns,
+            keep_runs,
         ),
         _ => rg_to_dfs_par_over_rg(
             store,
@@ -180,6 +286,7 @@ fn rg_to_dfs(
             row_index,
             projection,
             hive_partition_columns,
+            keep_runs,
         ),
     }
 }
@@ -198,6 +305,7 @@ fn rg_to_dfs_optionally_par_over_columns(
     parallel: ParallelStrategy,
     projection: &[usize],
     hive_partition_columns: Option<&[Series]>,
+    keep_runs: bool,
 ) -> PolarsResult<Vec<DataFrame>> {
     let mut dfs = Vec::with_capacity(row_group_end - row_group_start);
 
@@ -228,16 +336,15 @@ fn rg_to_dfs_optionally_par_over_columns(
 
             let part = iter.collect::<Vec<_>>();
 
-            let (mut series, _) = column_idx_to_series(
+            column_idx_to_column(
                 *column_i,
                 part.as_slice(),
-                Some(Filter::new_ranged(rg_slice.0, rg_slice.0 + rg_slice.1)),
+                Filter::new_ranged(rg_slice.0, rg_slice.0 + rg_slice.1),
                 schema,
                 store,
-            )?;
-
-            try_set_sorted_flag(&mut series, *column_i, &sorting_map);
-            Ok(series.into_column())
+                &sorting_map,
+                keep_runs,
+            )
         };
 
         let columns = if let ParallelStrategy::Columns = parallel {
@@ -295,6 +402,7 @@ fn rg_to_dfs_par_over_rg(
     row_index: Option<RowIndex>,
     projection: &[usize],
     hive_partition_columns: Option<&[Series]>,
+    keep_runs: bool,
 ) -> PolarsResult<Vec<DataFrame>> {
     // compute the limits per row group and the row count offsets
     let mut row_groups = Vec::with_capacity(row_group_end - row_group_start);
@@ -371,16 +479,15 @@ fn rg_to_dfs_par_over_rg(
 
                         let part = iter.collect::<Vec<_>>();
 
-                        let (mut series, _) = column_idx_to_series(
+                        column_idx_to_column(
                             *column_i,
                             part.as_slice(),
-                            Some(Filter::new_ranged(slice.0, slice.0 + slice.1)),
+                            Filter::new_ranged(slice.0, slice.0 + slice.1),
                             schema,
                             store,
-                        )?;
-
-                        try_set_sorted_flag(&mut series, *column_i, &sorting_map);
-                        Ok(series.into_column())
+                            &sorting_map,
+                            keep_runs,
+                        )
                     })
                     .collect::<PolarsResult<Vec<_>>>()?;
 
@@ -414,6 +521,7 @@ pub fn read_parquet<R: MmapBytesReader>(
     mut parallel: ParallelStrategy,
     row_index: Option<RowIndex>,
     hive_partition_columns: Option<&[Series]>,
+    keep_runs: bool,
 ) -> PolarsResult<DataFrame> {
     // Fast path.
     if pre_slice.1 == 0 {
@@ -462,6 +570,7 @@ pub fn read_parquet<R: MmapBytesReader>(
             parallel,
             &materialized_projection,
             hive_partition_columns,
+            keep_runs,
         )?;
 
         if dfs.is_empty() {
@@ -28,6 +28,7 @@ pub struct ParquetReader<R: Read + Seek> {
     metadata: Option<FileMetadataRef>,
     hive_partition_columns: Option<Vec<Series>>,
     include_file_path: Option<(PlSmallStr, PlRefStr)>,
+    keep_runs: bool,
 }
 
 impl<R: MmapBytesReader> ParquetReader<R> {
@@ -49,6 +50,13 @@ impl<R: MmapBytesReader> ParquetReader<R> {
         self
     }
 
+    /// Keep dictionary encoded columns that consist of a few long runs, e.g. mostly null or
+    /// constant columns, as run-end encoded columns instead of materializing them.
+    pub fn with_keep_runs(mut self, keep_runs: bool) -> Self {
+        self.keep_runs = keep_runs;
+        self
+    }
+
     /// Columns to select/ project
     pub fn with_columns(mut self, columns: Option<Vec<String>>) -> Self {
         self.columns = columns;
@@ -138,7 +146,7 @@ impl<R: MmapBytesReader> ParquetReader<R> {
             Some(schema) => schema.clone(),
             None => {
                 let metadata = self.get_metadata()?;
-                Arc::new(read::infer_schema(metadata)?)
+                Arc::new(super::infer_schema(metadata)?)
             },
         });
 
@@ -192,6 +200,7 @@ impl<R: MmapBytesReader> SerReader<R> for ParquetReader<R> {
             schema: None,
             hive_partition_columns: None,
             include_file_path: None,
+            keep_runs: false,
         }
     }
 
@@ -218,6 +227,7 @@ impl<R: MmapBytesReader> SerReader<R> for ParquetReader<R> {
             self.parallel,
             self.row_index,
             self.hive_partition_columns.as_deref(),
+            self.keep_runs,
         )?;
 
         if self.rechunk {
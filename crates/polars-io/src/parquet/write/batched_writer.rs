@@ -4,14 +4,17 @@ use std::sync::Mutex;
 use arrow::record_batch::RecordBatch;
 use polars_core::POOL;
 use polars_core::prelude::*;
+use polars_core::series::IsSorted;
 use polars_parquet::read::{ParquetError, fallible_streaming_iterator};
 use polars_parquet::write::{
     ColumnWriteOptions, CompressedPage, Compressor, DynIter, DynStreamingIterator,
     FallibleStreamingIterator, FileWriter, Page, ParquetType, RowGroupIterColumns,
-    SchemaDescriptor, WriteOptions, array_to_columns, schema_to_metadata_key,
+    SchemaDescriptor, SortingColumn, WriteOptions, array_to_columns, schema_to_metadata_key,
 };
 use rayon::prelude::*;
 
+#[cfg(feature = "dtype-variant")]
+use super::VariantShredding;
 use super::{KeyValueMetadata, ParquetMetadataContext};
 
 pub struct BatchedWriter<W: Write> {
@@ -26,6 +29,8 @@ pub struct BatchedWriter<W: Write> {
     pub(super) options: WriteOptions,
     pub(super) parallel: bool,
     pub(super) key_value_metadata: Option<KeyValueMetadata>,
+    #[cfg(feature = "dtype-variant")]
+    pub(super) variant_shredding: VariantShredding,
 }
 
 impl<W: Write> BatchedWriter<W> {
@@ -43,6 +48,19 @@ impl<W: Write> BatchedWriter<W> {
             options,
             parallel,
             key_value_metadata,
+            #[cfg(feature = "dtype-variant")]
+            variant_shredding: VariantShredding::default(),
+        }
+    }
+
+    fn shred_variants(&self, batch: RecordBatch) -> PolarsResult<RecordBatch> {
+        #[cfg(feature = "dtype-variant")]
+        {
+            self.variant_shredding.shred(batch)
+        }
+        #[cfg(not(feature = "dtype-variant"))]
+        {
+            Ok(batch)
         }
     }
 
@@ -54,12 +72,14 @@ impl<W: Write> BatchedWriter<W> {
         rb_iter.filter_map(move |batch| match batch.len() {
             0 => None,
             _ => {
-                let row_group = create_eager_serializer(
-                    batch,
-                    self.parquet_schema.fields(),
-                    self.column_options.as_ref(),
-                    self.options,
-                );
+                let row_group = self.shred_variants(batch).and_then(|batch| {
+                    create_eager_serializer(
+                        batch,
+                        self.parquet_schema.fields(),
+                        self.column_options.as_ref(),
+                        self.options,
+                    )
+                });
 
                 Some(row_group)
             },
@@ -73,6 +93,7 @@ impl<W: Write> BatchedWriter<W> {
     pub fn write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
         let row_group_iter = prepare_rg_iter(
             df,
+            |batch| self.shred_variants(batch),
             &self.parquet_schema,
             &self.column_options,
             self.options,
@@ -80,8 +101,10 @@ impl<W: Write> BatchedWriter<W> {
         );
         // Lock before looping so that order is maintained under contention.
         let mut writer = self.writer.lock().unwrap();
+        // Every row group is a slice of `df`, so they are all sorted if `df` is.
+        let sorting_columns = get_sorting_columns(df.columns(), writer.parquet_schema());
         for (num_rows, group) in row_group_iter {
-            writer.write(num_rows as u64, group?)?;
+            writer.write_with_sorting_columns(num_rows as u64, group?, sorting_columns.clone())?;
         }
         Ok(())
     }
@@ -96,6 +119,7 @@ impl<W: Write> BatchedWriter<W> {
         &mut self,
         num_rows: u64,
         rg: &[Vec<CompressedPage>],
+        sorting_columns: Option<Vec<SortingColumn>>,
     ) -> PolarsResult<()> {
         let writer = self.writer.get_mut().unwrap();
         let rg = DynIter::new(rg.iter().map(|col_pages| {
@@ -103,7 +127,7 @@ impl<W: Write> BatchedWriter<W> {
                 fallible_streaming_iterator::convert(col_pages.iter().map(PolarsResult::Ok)),
             ))
         }));
-        writer.write(num_rows, rg)?;
+        writer.write_with_sorting_columns(num_rows, rg, sorting_columns)?;
         Ok(())
     }
 
@@ -127,7 +151,8 @@ impl<W: Write> BatchedWriter<W> {
     pub fn finish(&self) -> PolarsResult<u64> {
         let mut writer = self.writer.lock().unwrap();
 
-        let key_value_metadata = self
+        #[allow(unused_mut)]
+        let mut key_value_metadata = self
             .key_value_metadata
             .as_ref()
             .map(|meta| {
@@ -143,14 +168,94 @@ impl<W: Write> BatchedWriter<W> {
             })
             .transpose()?;
 
+        #[cfg(feature = "geo")]
+        if let Some(geo) = super::super::geo::geoparquet_metadata(writer.schema()) {
+            let out = key_value_metadata.get_or_insert_with(|| {
+                vec![schema_to_metadata_key(
+                    writer.schema(),
+                    &self.column_options,
+                )]
+            });
+            if !out.iter().any(|kv| kv.key == geo.key) {
+                out.push(geo);
+            }
+        }
+
         let size = writer.end(key_value_metadata, &self.column_options)?;
         Ok(size)
     }
 }
 
+/// Returns the `sorting_columns` metadata for a row group made up of `columns`, derived from their
+/// sorted flags.
+///
+/// Only columns that map to a single parquet leaf and whose polars sort order matches the parquet
+/// sort order of their physical type are considered.
+pub fn get_sorting_columns(
+    columns: &[Column],
+    parquet_schema: &SchemaDescriptor,
+) -> Option<Vec<SortingColumn>> {
+    fn num_leaves(parquet_type: &ParquetType) -> usize {
+        match parquet_type {
+            ParquetType::PrimitiveType(_) => 1,
+            ParquetType::GroupType { fields, .. } => fields.iter().map(num_leaves).sum(),
+        }
+    }
+
+    fn has_matching_sort_order(dtype: &DataType) -> bool {
+        use DataType as D;
+
+        matches!(
+            dtype,
+            D::Int8
+                | D::Int16
+                | D::Int32
+                | D::Int64
+                | D::UInt8
+                | D::UInt16
+                | D::UInt32
+                | D::UInt64
+                | D::Boolean
+                | D::String
+                | D::Binary
+                | D::Date
+                | D::Datetime(..)
+                | D::Duration(_)
+                | D::Time
+        )
+    }
+
+    let mut leaf_idx = 0;
+    let mut out = vec![];
+
+    for (column, parquet_type) in columns.iter().zip(parquet_schema.fields()) {
+        let descending = match column.is_sorted_flag() {
+            IsSorted::Ascending => Some(false),
+            IsSorted::Descending => Some(true),
+            IsSorted::Not => None,
+        };
+
+        if let Some(descending) = descending
+            && matches!(parquet_type, ParquetType::PrimitiveType(_))
+            && has_matching_sort_order(column.dtype())
+        {
+            out.push(SortingColumn {
+                column_idx: leaf_idx as i32,
+                descending,
+                nulls_first: column.null_count() > 0 && matches!(column.get(0), Ok(AnyValue::Null)),
+            });
+        }
+
+        leaf_idx += num_leaves(parquet_type);
+    }
+
+    (!out.is_empty()).then_some(out)
+}
+
 // Note that the df should be rechunked
 fn prepare_rg_iter<'a>(
     df: &'a DataFrame,
+    shred_variants: impl Fn(RecordBatch) -> PolarsResult<RecordBatch> + 'a,
     parquet_schema: &'a SchemaDescriptor,
     column_options: &'a [ColumnWriteOptions],
     options: WriteOptions,
@@ -165,13 +270,15 @@ fn prepare_rg_iter<'a>(
     rb_iter.filter_map(move |batch| match batch.len() {
         0 => None,
         num_rows => {
-            let row_group = create_serializer(
-                batch,
-                parquet_schema.fields(),
-                column_options,
-                options,
-                parallel,
-            );
+            let row_group = shred_variants(batch).and_then(|batch| {
+                create_serializer(
+                    batch,
+                    parquet_schema.fields(),
+                    column_options,
+                    options,
+                    parallel,
+                )
+            });
 
             Some((num_rows, row_group))
         },
@@ -3,13 +3,17 @@
 mod batched_writer;
 mod key_value_metadata;
 mod options;
+#[cfg(feature = "dtype-variant")]
+mod variant;
 mod writer;
 
-pub use batched_writer::BatchedWriter;
+pub use batched_writer::{BatchedWriter, get_sorting_columns};
 pub use key_value_metadata::{KeyValueMetadata, ParquetMetadataContext};
 pub use options::{
     ChildFieldOverwrites, MetadataKeyValue, ParquetCompression, ParquetFieldOverwrites,
     ParquetWriteOptions,
 };
 pub use polars_parquet::write::{RowGroupIterColumns, StatisticsOptions};
+#[cfg(feature = "dtype-variant")]
+pub use variant::VariantShredding;
 pub use writer::{ParquetWriter, get_column_write_options};
@@ -1,3 +1,4 @@
+use polars_core::prelude::DataType;
 use polars_parquet::write::{
     BrotliLevel, CompressionOptions, GzipLevel, StatisticsOptions, ZstdLevel,
 };
@@ -55,6 +56,8 @@ pub struct ParquetFieldOverwrites {
     pub required: Option<bool>,
     pub field_id: Option<i32>,
     pub metadata: Option<Vec<MetadataKeyValue>>,
+    /// Paths of a variant column to shred into typed columns, with the type to shred them to.
+    pub variant_shredding: Option<Vec<(PlSmallStr, DataType)>>,
 }
 
 /// The compression strategy to use for writing Parquet files.
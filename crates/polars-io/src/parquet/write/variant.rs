@@ -0,0 +1,76 @@
+use std::sync::Arc;
+
+use arrow::array::{Array, ArrayRef, StructArray};
+use arrow::datatypes::{ArrowDataType, ArrowSchema, PARQUET_VARIANT_EXTENSION_NAME};
+use arrow::record_batch::RecordBatch;
+use polars_compute::variant::{
+    VariantPath, shred_variant, shredded_variant_arrow_dtype, struct_to_variant,
+};
+use polars_core::prelude::*;
+
+use super::ParquetFieldOverwrites;
+
+/// The typed columns to shred a variant column into, by their path in the variant.
+type ShreddedPaths = Arc<[(VariantPath, ArrowDataType)]>;
+
+/// The variant columns to shred into typed columns when writing, by column index.
+#[derive(Clone, Debug, Default)]
+pub struct VariantShredding(Vec<Option<ShreddedPaths>>);
+
+impl VariantShredding {
+    /// Collects the requested shredding of top-level variant columns and sets their shredded type
+    /// in `schema`.
+    pub fn try_new(
+        schema: &mut ArrowSchema,
+        field_overwrites: &[ParquetFieldOverwrites],
+    ) -> PolarsResult<Self> {
+        let mut columns = vec![None; schema.len()];
+        for o in field_overwrites {
+            let (Some(name), Some(shredding)) = (&o.name, &o.variant_shredding) else {
+                continue;
+            };
+            let i = schema.try_index_of(name)?;
+            let (_, field) = schema.get_at_index_mut(i).unwrap();
+            polars_ensure!(
+                matches!(&field.dtype, ArrowDataType::Extension(ext) if ext.name == PARQUET_VARIANT_EXTENSION_NAME),
+                InvalidOperation: "cannot give variant shredding for non-variant column `{}`", name
+            );
+            let paths = shredding
+                .iter()
+                .map(|(path, dtype)| {
+                    Ok((
+                        VariantPath::parse(path)?,
+                        dtype.to_arrow(CompatLevel::newest()),
+                    ))
+                })
+                .collect::<PolarsResult<Vec<_>>>()?;
+            field.dtype = shredded_variant_arrow_dtype(&paths)?;
+            columns[i] = Some(paths.into());
+        }
+        Ok(Self(columns))
+    }
+
+    /// Shreds `array` if it is a variant column to shred.
+    pub fn shred_column(&self, i: usize, array: ArrayRef) -> PolarsResult<ArrayRef> {
+        let Some(paths) = self.0.get(i).and_then(Option::as_ref) else {
+            return Ok(array);
+        };
+        let arr = array.as_any().downcast_ref::<StructArray>().unwrap();
+        Ok(shred_variant(&struct_to_variant(arr)?, paths)?.boxed())
+    }
+
+    /// Shreds the variant columns of `batch`.
+    pub fn shred(&self, batch: RecordBatch) -> PolarsResult<RecordBatch> {
+        if self.0.iter().all(Option::is_none) {
+            return Ok(batch);
+        }
+        let height = batch.height();
+        let (schema, arrays) = batch.into_schema_and_arrays();
+        let arrays = arrays
+            .into_iter()
+            .enumerate()
+            .map(|(i, array)| self.shred_column(i, array))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        RecordBatch::try_new(height, schema, arrays)
+    }
+}
@@ -1,7 +1,7 @@
 use std::io::Write;
 use std::sync::Mutex;
 
-use arrow::datatypes::PhysicalType;
+use arrow::datatypes::{PhysicalType, PrimitiveType};
 use polars_core::frame::chunk_df_for_writing;
 use polars_core::prelude::*;
 use polars_parquet::write::{
@@ -12,6 +12,8 @@ use polars_parquet::write::{
 
 use super::batched_writer::BatchedWriter;
 use super::options::ParquetCompression;
+#[cfg(feature = "dtype-variant")]
+use super::variant::VariantShredding;
 use super::{KeyValueMetadata, MetadataKeyValue, ParquetFieldOverwrites, ParquetWriteOptions};
 use crate::prelude::ChildFieldOverwrites;
 use crate::shared::schema_to_arrow_checked;
@@ -27,6 +29,7 @@ impl ParquetWriteOptions {
             .with_row_group_size(self.row_group_size)
             .with_data_page_size(self.data_page_size)
             .with_key_value_metadata(self.key_value_metadata.clone())
+            .with_field_overwrites(self.field_overwrites.clone())
     }
 }
 
@@ -101,6 +104,12 @@ where
         self
     }
 
+    /// Set the overwrites of the written Parquet fields, e.g. field ids or variant shredding.
+    pub fn with_field_overwrites(mut self, field_overwrites: Vec<ParquetFieldOverwrites>) -> Self {
+        self.field_overwrites = field_overwrites;
+        self
+    }
+
     /// Serialize columns in parallel
     pub fn set_parallel(mut self, parallel: bool) -> Self {
         self.parallel = parallel;
@@ -120,7 +129,10 @@ where
     }
 
     pub fn batched(self, schema: &Schema) -> PolarsResult<BatchedWriter<W>> {
-        let schema = schema_to_arrow_checked(schema, CompatLevel::newest(), "parquet")?;
+        #[allow(unused_mut)]
+        let mut schema = schema_to_arrow_checked(schema, CompatLevel::newest(), "parquet")?;
+        #[cfg(feature = "dtype-variant")]
+        let variant_shredding = VariantShredding::try_new(&mut schema, &self.field_overwrites)?;
         let column_options = get_column_write_options(&schema, &self.field_overwrites);
         let parquet_schema = to_parquet_schema(&schema, &column_options)?;
         let options = self.materialize_options();
@@ -138,6 +150,8 @@ where
             options,
             parallel: self.parallel,
             key_value_metadata: self.key_value_metadata,
+            #[cfg(feature = "dtype-variant")]
+            variant_shredding,
         })
     }
 
@@ -203,7 +217,7 @@ fn to_column_write_options_rec(
                 encoding: encoding_map(field.dtype()),
             });
         },
-        List | FixedSizeList | LargeList => {
+        List | FixedSizeList | LargeList | Map => {
             let child_overwrites = overwrites.and_then(|o| match &o.children {
                 ChildFieldOverwrites::None => None,
                 ChildFieldOverwrites::ListLike(child_overwrites) => Some(child_overwrites.as_ref()),
@@ -217,6 +231,8 @@ fn to_column_write_options_rec(
                 to_column_write_options_rec(inner, child_overwrites)
             } else if let ArrowDataType::FixedSizeList(inner, _) = a {
                 to_column_write_options_rec(inner, child_overwrites)
+            } else if let ArrowDataType::Map(inner, _) = a {
+                to_column_write_options_rec(inner, child_overwrites)
             } else {
                 unreachable!()
             };
@@ -262,7 +278,7 @@ fn to_column_write_options_rec(
             }
         },
 
-        Map | Union => unreachable!(),
+        Union => unreachable!(),
     }
 
     column_options
@@ -287,6 +303,8 @@ pub fn get_column_write_options(
 /// Declare encodings
 fn encoding_map(dtype: &ArrowDataType) -> Encoding {
     match dtype.to_physical_type() {
+        // Intervals are written as fixed-size binary.
+        PhysicalType::Primitive(PrimitiveType::MonthDayNano) => Encoding::Plain,
         PhysicalType::Dictionary(_)
         | PhysicalType::LargeBinary
         | PhysicalType::LargeUtf8
@@ -341,6 +341,7 @@ fn use_min_max(dtype: &DataType) -> bool {
         )
 }
 
+#[derive(Debug, Clone)]
 pub struct ColumnStatistics {
     pub dtype: DataType,
     pub min: AnyValue<'static>,
@@ -7,6 +7,7 @@ use polars_error::{feature_gated, to_compute_err};
 
 use crate::utils::file::{Writeable, WriteableTrait};
 use crate::utils::sync_on_close::SyncOnCloseType;
+use crate::utils::text_encoding::{TextDecodingReader, TextEncoding};
 
 /// Represents the compression algorithms that we have decoders for
 #[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
@@ -85,6 +86,8 @@ pub enum CompressedReader {
     Zlib(flate2::bufread::ZlibDecoder<Cursor<Buffer<u8>>>),
     #[cfg(feature = "decompress")]
     Zstd(zstd::Decoder<'static, Cursor<Buffer<u8>>>),
+    /// Text in another encoding than UTF-8, transcoded to UTF-8.
+    Transcoded(Box<TextDecodingReader>),
 }
 
 impl CompressedReader {
@@ -110,6 +113,16 @@ impl CompressedReader {
         })
     }
 
+    /// Transcodes the output to UTF-8 if `encoding` is not UTF-8.
+    pub fn with_encoding(self, encoding: TextEncoding) -> PolarsResult<Self> {
+        Ok(match encoding.decoder()? {
+            None => self,
+            Some(decoder) => {
+                CompressedReader::Transcoded(Box::new(TextDecodingReader::new(self, decoder)))
+            },
+        })
+    }
+
     pub fn is_compressed(&self) -> bool {
         !matches!(&self, CompressedReader::Uncompressed { .. })
     }
@@ -151,6 +164,7 @@ impl CompressedReader {
             CompressedReader::Zstd(reader) => {
                 reader.get_ref().get_ref().len() * ESTIMATED_ZSTD_RATIO
             },
+            CompressedReader::Transcoded(reader) => reader.total_len_estimate(),
         }
     }
 
@@ -215,6 +229,11 @@ impl CompressedReader {
             CompressedReader::Zstd(decoder) => {
                 new_slice_from_read(decoder.take(read_size as u64).read_to_end(&mut buf)?, buf)
             },
+            CompressedReader::Transcoded(reader) => {
+                // The number of bytes read refers to the input, so the output is not truncated.
+                let bytes_read = reader.fill(&mut buf, read_size)?;
+                Ok((Buffer::from_vec(buf), bytes_read))
+            },
         }
     }
 }
@@ -236,6 +255,7 @@ impl Read for CompressedReader {
             CompressedReader::Zlib(decoder) => decoder.read(buf),
             #[cfg(feature = "decompress")]
             CompressedReader::Zstd(decoder) => decoder.read(buf),
+            CompressedReader::Transcoded(reader) => reader.read(buf),
         }
     }
 }
@@ -14,6 +14,13 @@ use super::sync_on_close::SyncOnCloseType;
 use crate::cloud::CloudOptions;
 use crate::resolve_homedir;
 
+/// A new unique name for a data file in a table root, in the `part-00000-{uuid}-c000.parquet`
+/// format of Delta Lake writers.
+#[cfg(any(feature = "catalog", feature = "delta"))]
+pub fn delta_data_file_name() -> String {
+    format!("part-00000-{}-c000.parquet", uuid::Uuid::new_v4())
+}
+
 // TODO document precise contract.
 pub trait WriteableTrait: std::io::Write {
     fn close(&mut self) -> std::io::Result<()>;
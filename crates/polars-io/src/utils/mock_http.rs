@@ -0,0 +1,69 @@
+//! Minimal HTTP server for tests of clients that talk to web services.
+
+use std::io::{Read, Write};
+use std::net::TcpListener;
+use std::sync::{Arc, Mutex};
+
+/// `(method, path, body)` of a request received by the mock server.
+pub type RecordedRequest = (String, String, String);
+
+/// Minimal HTTP/1.1 server that replies to each request with the next canned response body.
+///
+/// Returns the base URL of the server and the requests it received.
+pub fn spawn_mock_server(
+    responses: Vec<impl Into<String>>,
+) -> (String, Arc<Mutex<Vec<RecordedRequest>>>) {
+    let responses = responses
+        .into_iter()
+        .map(Into::into)
+        .collect::<Vec<String>>();
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let requests = Arc::new(Mutex::new(vec![]));
+    let recorded = requests.clone();
+
+    std::thread::spawn(move || {
+        for response in responses {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut buf = vec![];
+            let mut chunk = [0u8; 4096];
+
+            let (head_len, content_length) = loop {
+                let n = stream.read(&mut chunk).unwrap();
+                buf.extend_from_slice(&chunk[..n]);
+
+                if let Some(i) = buf.windows(4).position(|w| w == b"\r\n\r\n") {
+                    let head = std::str::from_utf8(&buf[..i]).unwrap().to_ascii_lowercase();
+                    let content_length = head
+                        .lines()
+                        .find_map(|l| l.strip_prefix("content-length:"))
+                        .map_or(0, |v| v.trim().parse::<usize>().unwrap());
+                    break (i + 4, content_length);
+                }
+            };
+
+            while buf.len() < head_len + content_length {
+                let n = stream.read(&mut chunk).unwrap();
+                buf.extend_from_slice(&chunk[..n]);
+            }
+
+            let head = std::str::from_utf8(&buf[..head_len]).unwrap();
+            let mut request_line = head.lines().next().unwrap().split(' ');
+            let method = request_line.next().unwrap().to_string();
+            let path = request_line.next().unwrap().to_string();
+            let body = String::from_utf8_lossy(&buf[head_len..]).into_owned();
+            recorded.lock().unwrap().push((method, path, body));
+
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\n\
+                content-length: {}\r\nconnection: close\r\n\r\n{}",
+                response.len(),
+                response
+            )
+            .unwrap();
+        }
+    });
+
+    (url, requests)
+}
@@ -6,8 +6,13 @@ pub use other::*;
 pub mod byte_source;
 pub mod file;
 pub mod mkdir;
+// Public so that the catalog tests of `polars-lazy` can use it as well.
+#[cfg(any(feature = "catalog", feature = "aws", feature = "gcp"))]
+#[doc(hidden)]
+pub mod mock_http;
 pub mod slice;
 pub mod sync_on_close;
+pub mod text_encoding;
 
 /// Excludes only the unreserved URI characters in RFC-3986:
 ///
@@ -0,0 +1,407 @@
+//! Transcoding of text files in other character encodings to UTF-8.
+//!
+//! Readers of text formats parse UTF-8. Input in another encoding is transcoded while it is being
+//! read, so that chunking and parallel parsing operate on the UTF-8 output.
+use std::io::Read;
+
+use polars_buffer::Buffer;
+use polars_error::PolarsResult;
+#[cfg(not(feature = "encoding"))]
+use polars_error::polars_bail;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::utils::compression::CompressedReader;
+
+/// Character encoding of a text file.
+///
+/// Windows-1252, UTF-16 and Shift-JIS require the `encoding` feature.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum TextEncoding {
+    /// Utf8 encoding.
+    #[default]
+    Utf8,
+    /// Utf8 encoding and unknown bytes are replaced with �.
+    LossyUtf8,
+    /// ISO-8859-1, every byte is the code point of the same value.
+    Latin1,
+    /// Windows-1252, which differs from Latin-1 in the range `0x80..0xA0`.
+    Windows1252,
+    /// UTF-16 little endian. A byte order mark takes precedence over the endianness.
+    Utf16Le,
+    /// UTF-16 big endian. A byte order mark takes precedence over the endianness.
+    Utf16Be,
+    /// UTF-32 little endian. A byte order mark takes precedence over the endianness.
+    Utf32Le,
+    /// UTF-32 big endian. A byte order mark takes precedence over the endianness.
+    Utf32Be,
+    /// Shift-JIS.
+    ShiftJis,
+}
+
+impl TextEncoding {
+    /// Whether the input is parsed as is, without transcoding.
+    pub fn is_utf8(self) -> bool {
+        matches!(self, Self::Utf8 | Self::LossyUtf8)
+    }
+
+    /// Returns a decoder to UTF-8, or `None` if the input does not need transcoding.
+    ///
+    /// Errors if decoding the encoding requires the `encoding` feature and it is not enabled.
+    pub fn decoder(self) -> PolarsResult<Option<TextDecoder>> {
+        use DecoderState as S;
+
+        let state = match self {
+            Self::Utf8 | Self::LossyUtf8 => return Ok(None),
+            Self::Latin1 => S::Latin1,
+            #[cfg(feature = "encoding")]
+            Self::Windows1252 => S::EncodingRs(encoding_rs::WINDOWS_1252.new_decoder()),
+            #[cfg(feature = "encoding")]
+            Self::ShiftJis => S::EncodingRs(encoding_rs::SHIFT_JIS.new_decoder()),
+            #[cfg(not(feature = "encoding"))]
+            Self::Windows1252 | Self::ShiftJis | Self::Utf16Le | Self::Utf16Be => {
+                polars_bail!(ComputeError: "reading {:?} encoded text requires the 'encoding' feature", self)
+            },
+            #[cfg(feature = "encoding")]
+            Self::Utf16Le => S::DetectBom {
+                width: 2,
+                big_endian: false,
+            },
+            #[cfg(feature = "encoding")]
+            Self::Utf16Be => S::DetectBom {
+                width: 2,
+                big_endian: true,
+            },
+            Self::Utf32Le => S::DetectBom {
+                width: 4,
+                big_endian: false,
+            },
+            Self::Utf32Be => S::DetectBom {
+                width: 4,
+                big_endian: true,
+            },
+        };
+
+        Ok(Some(TextDecoder {
+            state,
+            pending: vec![],
+        }))
+    }
+}
+
+/// Streaming decoder to UTF-8. Invalid sequences are replaced with �.
+pub struct TextDecoder {
+    state: DecoderState,
+    /// Input that was not decoded yet because it ends in an incomplete sequence.
+    pending: Vec<u8>,
+}
+
+enum DecoderState {
+    /// Waiting for enough input to check for a byte order mark of a UTF-16 or UTF-32 encoding.
+    DetectBom {
+        width: usize,
+        big_endian: bool,
+    },
+    #[cfg(feature = "encoding")]
+    EncodingRs(encoding_rs::Decoder),
+    Latin1,
+    Utf32 {
+        big_endian: bool,
+    },
+}
+
+impl TextDecoder {
+    /// Decodes `input` and appends the UTF-8 output to `out`. `last` must be set on the final call.
+    pub fn decode(&mut self, input: &[u8], out: &mut Vec<u8>, last: bool) {
+        match &mut self.state {
+            DecoderState::DetectBom { width, big_endian } => {
+                let (width, big_endian) = (*width, *big_endian);
+                self.pending.extend_from_slice(input);
+
+                if self.pending.len() < width && !last {
+                    return;
+                }
+
+                let (big_endian, bom_len) =
+                    detect_bom(&self.pending, width).unwrap_or((big_endian, 0));
+
+                self.state = match (width, big_endian) {
+                    #[cfg(feature = "encoding")]
+                    (2, false) => DecoderState::EncodingRs(
+                        encoding_rs::UTF_16LE.new_decoder_without_bom_handling(),
+                    ),
+                    #[cfg(feature = "encoding")]
+                    (2, true) => DecoderState::EncodingRs(
+                        encoding_rs::UTF_16BE.new_decoder_without_bom_handling(),
+                    ),
+                    _ => DecoderState::Utf32 { big_endian },
+                };
+
+                let pending = std::mem::take(&mut self.pending);
+                self.decode(&pending[bom_len..], out, last);
+            },
+            #[cfg(feature = "encoding")]
+            DecoderState::EncodingRs(decoder) => {
+                let offset = out.len();
+                let max_len = decoder
+                    .max_utf8_buffer_length(input.len())
+                    .expect("text decoder output length overflow");
+                out.resize(offset + max_len, 0);
+
+                let (_, bytes_read, bytes_written, _) =
+                    decoder.decode_to_utf8(input, &mut out[offset..], last);
+
+                debug_assert_eq!(bytes_read, input.len());
+                out.truncate(offset + bytes_written);
+            },
+            DecoderState::Latin1 => {
+                out.reserve(input.len());
+
+                for &b in input {
+                    if b.is_ascii() {
+                        out.push(b);
+                    } else {
+                        out.extend_from_slice(char::from(b).encode_utf8(&mut [0; 4]).as_bytes());
+                    }
+                }
+            },
+            DecoderState::Utf32 { big_endian } => {
+                let big_endian = *big_endian;
+                let mut input = input;
+
+                if !self.pending.is_empty() {
+                    let n = (4 - self.pending.len()).min(input.len());
+                    self.pending.extend_from_slice(&input[..n]);
+                    input = &input[n..];
+
+                    if self.pending.len() == 4 {
+                        push_utf32(self.pending[..].try_into().unwrap(), big_endian, out);
+                        self.pending.clear();
+                    }
+                }
+
+                let chunks = input.chunks_exact(4);
+                self.pending.extend_from_slice(chunks.remainder());
+                out.reserve(input.len() / 4);
+
+                for chunk in chunks {
+                    push_utf32(chunk.try_into().unwrap(), big_endian, out);
+                }
+
+                if last && !self.pending.is_empty() {
+                    self.pending.clear();
+                    out.extend_from_slice(
+                        char::REPLACEMENT_CHARACTER
+                            .encode_utf8(&mut [0; 4])
+                            .as_bytes(),
+                    );
+                }
+            },
+        }
+    }
+}
+
+/// Returns the endianness and length of the byte order mark of the given code unit width.
+fn detect_bom(bytes: &[u8], width: usize) -> Option<(bool, usize)> {
+    match width {
+        2 if bytes.starts_with(&[0xFF, 0xFE]) => Some((false, 2)),
+        2 if bytes.starts_with(&[0xFE, 0xFF]) => Some((true, 2)),
+        4 if bytes.starts_with(&[0xFF, 0xFE, 0x00, 0x00]) => Some((false, 4)),
+        4 if bytes.starts_with(&[0x00, 0x00, 0xFE, 0xFF]) => Some((true, 4)),
+        _ => None,
+    }
+}
+
+fn push_utf32(bytes: [u8; 4], big_endian: bool, out: &mut Vec<u8>) {
+    let code_point = if big_endian {
+        u32::from_be_bytes(bytes)
+    } else {
+        u32::from_le_bytes(bytes)
+    };
+
+    let c = char::from_u32(code_point).unwrap_or(char::REPLACEMENT_CHARACTER);
+    out.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+}
+
+/// Reader that transcodes the output of a [`CompressedReader`] to UTF-8.
+pub struct TextDecodingReader {
+    reader: CompressedReader,
+    decoder: TextDecoder,
+    eof: bool,
+    /// Transcoded output that has not been consumed through [`Read`].
+    pending: Vec<u8>,
+    pending_offset: usize,
+}
+
+impl TextDecodingReader {
+    pub fn new(reader: CompressedReader, decoder: TextDecoder) -> Self {
+        Self {
+            reader,
+            decoder,
+            eof: false,
+            pending: vec![],
+            pending_offset: 0,
+        }
+    }
+
+    pub fn total_len_estimate(&self) -> usize {
+        self.reader.total_len_estimate()
+    }
+
+    /// Transcodes input until `out` grows or the end of the input is reached. Returns the number of
+    /// bytes read from the input.
+    pub fn fill(&mut self, out: &mut Vec<u8>, read_size: usize) -> std::io::Result<usize> {
+        let prev_len = out.len();
+        let mut bytes_read = 0;
+
+        while !self.eof && out.len() == prev_len {
+            let (slice, n) = self.reader.read_next_slice(&Buffer::new(), read_size)?;
+            bytes_read += n;
+
+            // The inner reader only returns fewer bytes than requested at the end of the input.
+            let last = n < read_size;
+            self.decoder.decode(&slice, out, last);
+            self.eof = last;
+        }
+
+        Ok(bytes_read)
+    }
+}
+
+impl Read for TextDecodingReader {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        if self.pending_offset == self.pending.len() {
+            let mut pending = std::mem::take(&mut self.pending);
+            pending.clear();
+            self.pending_offset = 0;
+            self.fill(&mut pending, CompressedReader::initial_read_size())?;
+            self.pending = pending;
+        }
+
+        let n = buf.len().min(self.pending.len() - self.pending_offset);
+        buf[..n].copy_from_slice(&self.pending[self.pending_offset..self.pending_offset + n]);
+        self.pending_offset += n;
+
+        Ok(n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decode_chunked(encoding: TextEncoding, input: &[u8], chunk_size: usize) -> String {
+        let mut decoder = encoding.decoder().unwrap().unwrap();
+        let mut out = vec![];
+
+        for chunk in input.chunks(chunk_size) {
+            decoder.decode(chunk, &mut out, false);
+        }
+
+        decoder.decode(&[], &mut out, true);
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    #[cfg(not(feature = "encoding"))]
+    fn test_encoding_feature_required() {
+        let err = TextEncoding::ShiftJis.decoder().err().unwrap();
+        assert!(err.to_string().contains("'encoding' feature"));
+        assert!(TextEncoding::Latin1.decoder().unwrap().is_some());
+    }
+
+    #[test]
+    #[cfg(feature = "encoding")]
+    fn test_decode_text_encodings() {
+        let expected = "a,b\nü,€\n";
+
+        let utf16le: Vec<u8> = expected.encode_utf16().flat_map(u16::to_le_bytes).collect();
+        let utf16be: Vec<u8> = expected.encode_utf16().flat_map(u16::to_be_bytes).collect();
+        let utf32be: Vec<u8> = expected
+            .chars()
+            .flat_map(|c| u32::from(c).to_be_bytes())
+            .collect();
+
+        for chunk_size in [1, 3, 1024] {
+            assert_eq!(
+                decode_chunked(TextEncoding::Utf16Le, &utf16le, chunk_size),
+                expected
+            );
+            assert_eq!(
+                decode_chunked(TextEncoding::Utf16Be, &utf16be, chunk_size),
+                expected
+            );
+            assert_eq!(
+                decode_chunked(TextEncoding::Utf32Be, &utf32be, chunk_size),
+                expected
+            );
+            assert_eq!(
+                decode_chunked(TextEncoding::Windows1252, b"a,b\n\xFC,\x80\n", chunk_size),
+                expected
+            );
+            assert_eq!(
+                decode_chunked(TextEncoding::ShiftJis, b"\x93\xfa\x96\x7b,\xb1", chunk_size),
+                "日本,ｱ"
+            );
+        }
+
+        assert_eq!(
+            decode_chunked(TextEncoding::Latin1, b"\xFC\x80", 1),
+            "ü\u{80}"
+        );
+    }
+
+    #[test]
+    fn test_decode_byte_order_mark() {
+        // The byte order mark takes precedence over the configured endianness.
+        #[cfg(feature = "encoding")]
+        {
+            let mut utf16be = vec![0xFE, 0xFF];
+            utf16be.extend("ab".encode_utf16().flat_map(u16::to_be_bytes));
+            assert_eq!(decode_chunked(TextEncoding::Utf16Le, &utf16be, 1), "ab");
+        }
+
+        let mut utf32le = vec![0xFF, 0xFE, 0x00, 0x00];
+        utf32le.extend("ab".chars().flat_map(|c| u32::from(c).to_le_bytes()));
+        assert_eq!(decode_chunked(TextEncoding::Utf32Le, &utf32le, 3), "ab");
+
+        // Incomplete trailing code units are replaced.
+        assert_eq!(
+            decode_chunked(TextEncoding::Utf32Le, b"a\0\0\0b", 2),
+            "a\u{FFFD}"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "encoding")]
+    fn test_text_decoding_reader() -> std::io::Result<()> {
+        let input: Vec<u8> = "x\n1\n2\n"
+            .encode_utf16()
+            .flat_map(u16::to_le_bytes)
+            .collect();
+        let mut bom = vec![0xFF, 0xFE];
+        bom.extend(input);
+
+        let mut reader = CompressedReader::try_new(Buffer::from_vec(bom))
+            .unwrap()
+            .with_encoding(TextEncoding::Utf16Le)
+            .unwrap();
+
+        let mut slice = Buffer::new();
+
+        loop {
+            let (new_slice, bytes_read) = reader.read_next_slice(&slice, 3)?;
+            slice = new_slice;
+
+            if bytes_read == 0 {
+                break;
+            }
+        }
+
+        assert_eq!(&slice[..], b"x\n1\n2\n");
+
+        Ok(())
+    }
+}
@@ -14,9 +14,15 @@ use std::sync::{Arc, Mutex};
 pub use anonymous_scan::*;
 #[cfg(feature = "csv")]
 pub use csv::*;
+#[cfg(feature = "delta")]
+pub use delta::*;
 #[cfg(not(target_arch = "wasm32"))]
 pub use exitable::*;
 pub use file_list_reader::*;
+#[cfg(feature = "flight")]
+pub use flight::*;
+#[cfg(feature = "iceberg")]
+pub use iceberg::*;
 #[cfg(feature = "json")]
 pub use ndjson::*;
 #[cfg(feature = "parquet")]
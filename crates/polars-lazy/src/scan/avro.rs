@@ -0,0 +1,26 @@
+use polars_buffer::Buffer;
+use polars_core::prelude::*;
+use polars_utils::pl_path::PlRefPath;
+
+use crate::prelude::*;
+
+impl LazyFrame {
+    /// Create a LazyFrame directly from an avro scan.
+    pub fn scan_avro(path: PlRefPath, unified_scan_args: UnifiedScanArgs) -> PolarsResult<Self> {
+        Self::scan_avro_sources(
+            ScanSources::Paths(Buffer::from_iter([path])),
+            unified_scan_args,
+        )
+    }
+
+    pub fn scan_avro_sources(
+        sources: ScanSources,
+        unified_scan_args: UnifiedScanArgs,
+    ) -> PolarsResult<Self> {
+        let lf = DslBuilder::scan_avro(sources, unified_scan_args)?
+            .build()
+            .into();
+
+        Ok(lf)
+    }
+}
@@ -1,4 +1,4 @@
-use polars_core::error::{PolarsResult, feature_gated, polars_bail};
+use polars_core::error::{PolarsResult, feature_gated, polars_bail, polars_ensure};
 use polars_io::catalog::unity::models::{DataSourceFormat, TableInfo};
 use polars_io::catalog::unity::schema::table_info_to_schemas;
 use polars_io::cloud::CloudOptions;
@@ -39,6 +39,16 @@ impl LazyFrame {
 
                 Self::scan_parquet(storage_location, args)
             }),
+            DataSourceFormat::Delta => feature_gated!("delta", {
+                use crate::frame::ScanArgsDelta;
+
+                let args = ScanArgsDelta {
+                    cloud_options,
+                    ..Default::default()
+                };
+
+                Self::scan_delta(storage_location, args)
+            }),
             DataSourceFormat::Csv => feature_gated!("csv", {
                 use crate::frame::{LazyCsvReader, LazyFileListReader};
                 let (schema, _) = table_info_to_schemas(table_info)?;
@@ -54,4 +64,123 @@ impl LazyFrame {
             ),
         }
     }
+
+    /// Writes the result of this query to the storage location of a catalog table, e.g. one
+    /// created with `CatalogClient::create_table`. This executes the query.
+    ///
+    /// For `PARQUET` tables a new data file is added to the storage location. For `DELTA` tables
+    /// a new data file is written with the streaming engine and appended to the table in a new
+    /// commit, creating the transaction log if needed.
+    pub fn sink_catalog_table(
+        mut self,
+        table_info: &TableInfo,
+        cloud_options: Option<CloudOptions>,
+    ) -> PolarsResult<()> {
+        let Some(data_source_format) = &table_info.data_source_format else {
+            polars_bail!(ComputeError: "sink_catalog_table requires Some(_) for data_source_format")
+        };
+
+        let Some(storage_location) = table_info.storage_location.as_deref() else {
+            polars_bail!(ComputeError: "sink_catalog_table requires Some(_) for storage_location")
+        };
+
+        let (schema, hive_schema) = table_info_to_schemas(table_info)?;
+
+        polars_ensure!(
+            hive_schema.is_none(),
+            ComputeError: "sink_catalog_table does not support partitioned tables"
+        );
+
+        if let Some(schema) = schema {
+            let input_schema = self.collect_schema()?;
+
+            polars_ensure!(
+                input_schema.as_ref() == schema.as_ref(),
+                SchemaMismatch:
+                "schema of data does not match catalog table schema: {:?} != {:?}",
+                input_schema, schema
+            );
+        }
+
+        let storage_location = storage_location.trim_end_matches('/');
+
+        match data_source_format {
+            DataSourceFormat::Parquet => feature_gated!("parquet", {
+                use std::sync::Arc;
+
+                use polars_io::parquet::write::ParquetWriteOptions;
+                use polars_io::utils::file::delta_data_file_name;
+                use polars_plan::dsl::{
+                    FileWriteFormat, SinkDestination, SinkTarget, UnifiedSinkArgs,
+                };
+
+                let file_name = delta_data_file_name();
+
+                self.sink(
+                    SinkDestination::File {
+                        target: SinkTarget::Path(PlRefPath::new(format!(
+                            "{storage_location}/{file_name}"
+                        ))),
+                    },
+                    FileWriteFormat::Parquet(Arc::new(ParquetWriteOptions::default())),
+                    UnifiedSinkArgs {
+                        mkdir: true,
+                        cloud_options: cloud_options.map(Arc::new),
+                        ..Default::default()
+                    },
+                )?
+                .collect()?;
+
+                Ok(())
+            }),
+            DataSourceFormat::Delta => feature_gated!("delta", {
+                use std::sync::Arc;
+
+                use polars_io::delta::{
+                    check_delta_append, commit_delta_append, delta_data_file_name,
+                };
+                use polars_io::parquet::write::ParquetWriteOptions;
+                use polars_plan::dsl::{
+                    FileWriteFormat, SinkDestination, SinkTarget, UnifiedSinkArgs,
+                };
+
+                let schema = self.collect_schema()?;
+                check_delta_append(storage_location, &schema, cloud_options.as_ref())?;
+
+                // The data file is written by the streaming sink, it only becomes part of the
+                // table once the commit below succeeds.
+                let file_name = delta_data_file_name();
+
+                self.sink(
+                    SinkDestination::File {
+                        target: SinkTarget::Path(PlRefPath::new(format!(
+                            "{storage_location}/{file_name}"
+                        ))),
+                    },
+                    FileWriteFormat::Parquet(Arc::new(ParquetWriteOptions::default())),
+                    UnifiedSinkArgs {
+                        mkdir: true,
+                        cloud_options: cloud_options.clone().map(Arc::new),
+                        ..Default::default()
+                    },
+                )?
+                .collect()?;
+
+                commit_delta_append(
+                    storage_location,
+                    &schema,
+                    &file_name,
+                    None,
+                    cloud_options.as_ref(),
+                )?;
+
+                Ok(())
+            }),
+            v => polars_bail!(
+                ComputeError:
+                "not yet supported data_source_format for sink_catalog_table: {:?}",
+                v
+            ),
+        }
+    }
 }
@@ -3,8 +3,8 @@ use polars_buffer::Buffer;
 use polars_core::prelude::*;
 use polars_io::cloud::CloudOptions;
 use polars_io::csv::read::{
-    CommentPrefix, CsvEncoding, CsvParseOptions, CsvReadOptions, NullValues,
-    read_until_start_and_infer_schema,
+    CommentPrefix, CsvEncoding, CsvParseOptions, CsvReadOptions, CsvSeparator, NullValues,
+    read_until_start_and_infer_schema, sniff_csv_dialect,
 };
 use polars_io::path_utils::expand_paths;
 use polars_io::utils::compression::CompressedReader;
@@ -146,10 +146,11 @@ impl LazyCsvReader {
         self
     }
 
-    /// Set the CSV file's column separator as a byte character
+    /// Set the CSV file's column separator as a byte character or a string
     #[must_use]
-    pub fn with_separator(self, separator: u8) -> Self {
-        self.map_parse_options(|opts| opts.with_separator(separator))
+    pub fn with_separator<T: Into<CsvSeparator>>(self, separator: T) -> Self {
+        let separator = separator.into();
+        self.map_parse_options(|opts| opts.with_separator(separator.clone()))
     }
 
     /// Set the comment prefix for this instance. Lines starting with this prefix will be ignored.
@@ -234,6 +235,14 @@ impl LazyCsvReader {
         self.map_parse_options(|opts| opts.with_decimal_comma(decimal_comma))
     }
 
+    /// Detect the separator, quote character, header, comment prefix and decimal comma from
+    /// the first file.
+    #[must_use]
+    pub fn with_sniff_dialect(mut self, sniff_dialect: bool) -> Self {
+        self.read_options.sniff_dialect = sniff_dialect;
+        self
+    }
+
     #[must_use]
     /// Expand path given via globbing rules.
     pub fn with_glob(mut self, toggle: bool) -> Self {
@@ -256,15 +265,26 @@ impl LazyCsvReader {
         let n_threads = self.read_options.n_threads;
 
         let infer_schema = |bytes: Buffer<u8>| {
-            let mut reader = CompressedReader::try_new(bytes)?;
+            let mut read_options = self.read_options.clone();
+
+            if read_options.sniff_dialect {
+                let mut reader = CompressedReader::try_new(bytes.clone())?
+                    .with_encoding(read_options.parse_options.encoding)?;
+                let dialect = sniff_csv_dialect(&read_options, &mut reader)?;
+                read_options = dialect.apply(read_options);
+                read_options.sniff_dialect = false;
+            }
+
+            let mut reader = CompressedReader::try_new(bytes)?
+                .with_encoding(read_options.parse_options.encoding)?;
 
             let (inferred_schema, _) =
-                read_until_start_and_infer_schema(&self.read_options, None, None, &mut reader)?;
+                read_until_start_and_infer_schema(&read_options, None, None, &mut reader)?;
 
-            PolarsResult::Ok(inferred_schema)
+            PolarsResult::Ok((inferred_schema, read_options))
         };
 
-        let schema = match self.sources.clone() {
+        let (schema, read_options) = match self.sources.clone() {
             ScanSources::Paths(paths) => {
                 // TODO: Path expansion should happen when converting to the IR
                 // https://github.com/pola-rs/polars/issues/17634
@@ -303,6 +323,7 @@ impl LazyCsvReader {
             },
         };
 
+        self.read_options = read_options;
         self.read_options.n_threads = n_threads;
         let mut schema = f(schema)?;
 
@@ -0,0 +1,227 @@
+use polars_buffer::Buffer;
+use polars_core::prelude::*;
+use polars_io::cloud::CloudOptions;
+use polars_io::delta::DeltaSnapshot;
+pub use polars_io::delta::DeltaVersion;
+use polars_io::prelude::ParquetOptions;
+use polars_io::{HiveOptions, RowIndex};
+use polars_plan::dsl::deletion::{
+    DeletionFilesList, DeltaDeletionVector, DeltaDeletionVectorLocation,
+};
+use polars_utils::pl_path::PlRefPath;
+use polars_utils::slice_enum::Slice;
+
+use crate::prelude::*;
+
+#[derive(Clone, Default)]
+pub struct ScanArgsDelta {
+    /// Version of the table to read. Defaults to the latest version.
+    pub version: DeltaVersion,
+    pub n_rows: Option<usize>,
+    pub row_index: Option<RowIndex>,
+    pub cloud_options: Option<CloudOptions>,
+    pub rechunk: bool,
+    pub cache: bool,
+    pub include_file_paths: Option<PlSmallStr>,
+}
+
+impl LazyFrame {
+    /// Create a LazyFrame from a Delta Lake table.
+    ///
+    /// The transaction log is replayed eagerly to resolve the data files of the requested table
+    /// version, which are then scanned as parquet files. Partition values are taken from the
+    /// `add` actions of the data files and deletion vectors are applied during the scan.
+    pub fn scan_delta(table_uri: PlRefPath, args: ScanArgsDelta) -> PolarsResult<Self> {
+        let snapshot =
+            polars_io::pl_async::get_runtime().block_in_place_on(DeltaSnapshot::try_new(
+                table_uri.as_str(),
+                args.version,
+                args.cloud_options.as_ref(),
+            ))?;
+
+        Self::scan_delta_snapshot(&snapshot, args)
+    }
+
+    /// Create a LazyFrame from an already resolved Delta Lake table snapshot.
+    pub fn scan_delta_snapshot(
+        snapshot: &DeltaSnapshot,
+        args: ScanArgsDelta,
+    ) -> PolarsResult<Self> {
+        let file_schema: SchemaRef = Arc::new(
+            snapshot
+                .schema
+                .iter()
+                .filter(|(name, _)| !snapshot.partition_columns.contains(name))
+                .map(|(name, dtype)| Field::new(name.clone(), dtype.clone()))
+                .collect(),
+        );
+
+        let mut lf = if snapshot.partition_columns.is_empty() {
+            let file_indices: Vec<usize> = (0..snapshot.files.len()).collect();
+            scan_delta_files(snapshot, &file_indices, &file_schema, &args, args.n_rows)?
+        } else {
+            // The data files of a partition are scanned together, with the partition columns
+            // added as literals. An empty string is a null partition value.
+            let mut partitions: PlIndexMap<Vec<Option<&str>>, Vec<usize>> = PlIndexMap::new();
+
+            for (i, file) in snapshot.files.iter().enumerate() {
+                let values = snapshot
+                    .partition_columns
+                    .iter()
+                    .map(|name| {
+                        file.partition_values
+                            .get(name.as_str())
+                            .and_then(|v| v.as_deref())
+                            .filter(|v| !v.is_empty())
+                    })
+                    .collect();
+                partitions.entry(values).or_default().push(i);
+            }
+
+            if partitions.is_empty() {
+                partitions.insert(vec![None; snapshot.partition_columns.len()], vec![]);
+            }
+
+            let scans = partitions
+                .into_iter()
+                .map(|(values, file_indices)| {
+                    let partition_columns = snapshot
+                        .partition_columns
+                        .iter()
+                        .zip(values)
+                        .map(|(name, value)| {
+                            let dtype = snapshot.schema.try_get(name)?;
+                            let value = value.map_or(lit(NULL), lit);
+                            Ok(value.strict_cast(dtype.clone()).alias(name.clone()))
+                        })
+                        .collect::<PolarsResult<Vec<_>>>()?;
+
+                    Ok(
+                        scan_delta_files(snapshot, &file_indices, &file_schema, &args, None)?
+                            .with_columns(partition_columns),
+                    )
+                })
+                .collect::<PolarsResult<Vec<_>>>()?;
+
+            let lf = concat(scans, UnionArgs::default())?.select(
+                snapshot
+                    .schema
+                    .iter_names()
+                    .map(|name| col(name.clone()))
+                    .collect::<Vec<_>>(),
+            );
+
+            match args.n_rows {
+                Some(n_rows) => lf.limit(IdxSize::try_from(n_rows).unwrap_or(IdxSize::MAX)),
+                None => lf,
+            }
+        };
+
+        // It's a bit hacky, but this row_index function updates the schema.
+        if let Some(row_index) = args.row_index {
+            lf = lf.with_row_index(row_index.name, Some(row_index.offset))
+        }
+
+        Ok(lf)
+    }
+}
+
+/// Scans the data files at `file_indices` of `snapshot` as parquet files, applying their deletion
+/// vectors.
+fn scan_delta_files(
+    snapshot: &DeltaSnapshot,
+    file_indices: &[usize],
+    file_schema: &SchemaRef,
+    args: &ScanArgsDelta,
+    n_rows: Option<usize>,
+) -> PolarsResult<LazyFrame> {
+    let mut deletion_vectors = PlIndexMap::new();
+    let mut num_physical_rows: Option<u64> = Some(0);
+    let mut num_deleted_rows: u64 = 0;
+
+    for (source_idx, file) in file_indices.iter().map(|&i| &snapshot.files[i]).enumerate() {
+        num_physical_rows = num_physical_rows.zip(file.num_records).map(|(a, b)| a + b);
+
+        let Some(dv) = &file.deletion_vector else {
+            continue;
+        };
+
+        num_deleted_rows += u64::try_from(dv.cardinality).unwrap_or(0);
+
+        let location = if let Some(bytes) = dv.inline_bytes()? {
+            DeltaDeletionVectorLocation::Inline(bytes.into())
+        } else {
+            DeltaDeletionVectorLocation::File {
+                path: dv.absolute_path(&snapshot.table_root)?.unwrap(),
+                offset: dv.offset.map_or(0, |x| u64::try_from(x).unwrap_or(0)),
+                size_in_bytes: u64::try_from(dv.size_in_bytes).unwrap_or(0),
+            }
+        };
+
+        deletion_vectors.insert(
+            source_idx,
+            DeltaDeletionVector {
+                location,
+                num_rows: file.num_records,
+            },
+        );
+    }
+
+    let sources = ScanSources::Paths(Buffer::from_iter(
+        file_indices
+            .iter()
+            .map(|&i| PlRefPath::new(&snapshot.files[i].path)),
+    ));
+
+    let parquet_options = ParquetOptions {
+        schema: Some(file_schema.clone()),
+        parallel: Default::default(),
+        low_memory: false,
+        use_statistics: true,
+        keep_dictionaries: false,
+        keep_runs: false,
+    };
+
+    let unified_scan_args = UnifiedScanArgs {
+        schema: None,
+        cloud_options: args.cloud_options.clone(),
+        // Partition values come from the transaction log, not from the file paths.
+        hive_options: HiveOptions::new_disabled(),
+        rechunk: args.rechunk,
+        cache: args.cache,
+        glob: false,
+        hidden_file_prefix: None,
+        projection: None,
+        column_mapping: None,
+        default_values: None,
+        // Note: We call `with_row_index()` on the LazyFrame in `scan_delta_snapshot`
+        row_index: None,
+        pre_slice: n_rows.map(|len| Slice::Positive { offset: 0, len }),
+        cast_columns_policy: CastColumnsPolicy {
+            integer_upcast: true,
+            float_upcast: true,
+            float_downcast: true,
+            datetime_nanoseconds_downcast: true,
+            datetime_microseconds_downcast: false,
+            datetime_convert_timezone: true,
+            null_upcast: true,
+            categorical_to_string: true,
+            missing_struct_fields: MissingColumnsPolicy::Insert,
+            extra_struct_fields: ExtraColumnsPolicy::Ignore,
+        },
+        missing_columns_policy: MissingColumnsPolicy::Insert,
+        extra_columns_policy: ExtraColumnsPolicy::Ignore,
+        include_file_paths: args.include_file_paths.clone(),
+        deletion_files: DeletionFilesList::filter_empty(Some(
+            DeletionFilesList::DeltaDeletionVector(Arc::new(deletion_vectors)),
+        )),
+        table_statistics: None,
+        row_count: num_physical_rows.map(|n| (n, num_deleted_rows)),
+    };
+
+    Ok(
+        DslBuilder::scan_parquet(sources, parquet_options, unified_scan_args)?
+            .build()
+            .into(),
+    )
+}
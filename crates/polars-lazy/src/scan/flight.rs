@@ -0,0 +1,114 @@
+use std::sync::RwLock;
+
+use polars_buffer::Buffer;
+use polars_core::prelude::*;
+use polars_io::HiveOptions;
+use polars_io::flight::{FlightScanOptions, FlightTicketResolver, read_flight_schema};
+use polars_utils::pl_path::PlRefPath;
+
+use crate::prelude::*;
+
+impl LazyFrame {
+    /// Create a LazyFrame from the record batches of an Arrow Flight `DoGet` for `ticket` on the
+    /// service at `location`, e.g. `grpc://localhost:8815`. The record batches are streamed as
+    /// they are received.
+    ///
+    /// If no `schema` is given, it is fetched from the service when the LazyFrame is created.
+    pub fn scan_flight(
+        location: &str,
+        ticket: impl Into<Vec<u8>>,
+        schema: Option<SchemaRef>,
+    ) -> PolarsResult<Self> {
+        Self::scan_flight_with_options(location, FlightScanOptions::new(ticket), schema)
+    }
+
+    /// Like [`LazyFrame::scan_flight`], with the ticket and message size limit given in `options`.
+    pub fn scan_flight_with_options(
+        location: &str,
+        options: FlightScanOptions,
+        schema: Option<SchemaRef>,
+    ) -> PolarsResult<Self> {
+        let schema = match schema {
+            Some(schema) => schema,
+            None => read_flight_schema(location, &options)?,
+        };
+
+        let lf = DslBuilder::scan_flight(
+            ScanSources::Paths(Buffer::from_iter([PlRefPath::new(location)])),
+            options,
+            UnifiedScanArgs {
+                schema: Some(schema),
+                hive_options: HiveOptions::new_disabled(),
+                glob: false,
+                ..Default::default()
+            },
+        )?
+        .build()
+        .into();
+
+        Ok(lf)
+    }
+}
+
+type QueryHandler = dyn Fn(&str) -> PolarsResult<LazyFrame> + Send + Sync;
+
+/// Exposes [`LazyFrame`]s to a [`FlightServer`](polars_io::flight::FlightServer).
+///
+/// A ticket is the name of a registered frame, which is collected on every `DoGet`. Tickets that
+/// don't name a registered frame are passed to the query handler, if one is set.
+#[derive(Default)]
+pub struct LazyFrameFlightResolver {
+    frames: RwLock<PlHashMap<String, LazyFrame>>,
+    query_handler: Option<Box<QueryHandler>>,
+}
+
+impl LazyFrameFlightResolver {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set a function that turns tickets that don't name a registered frame into a LazyFrame,
+    /// e.g. by running them as a SQL query.
+    pub fn with_query_handler(
+        mut self,
+        query_handler: impl Fn(&str) -> PolarsResult<LazyFrame> + Send + Sync + 'static,
+    ) -> Self {
+        self.query_handler = Some(Box::new(query_handler));
+        self
+    }
+
+    /// Register `lf` under `name`, replacing any frame that was registered under that name.
+    ///
+    /// This can be called while the resolver is being served.
+    pub fn register(&self, name: impl Into<String>, lf: LazyFrame) {
+        self.frames.write().unwrap().insert(name.into(), lf);
+    }
+
+    pub fn unregister(&self, name: &str) -> Option<LazyFrame> {
+        self.frames.write().unwrap().remove(name)
+    }
+
+    fn resolve(&self, ticket: &[u8]) -> PolarsResult<LazyFrame> {
+        let ticket = std::str::from_utf8(ticket)
+            .map_err(|_| polars_err!(InvalidOperation: "flight ticket is not valid UTF-8"))?;
+
+        if let Some(lf) = self.frames.read().unwrap().get(ticket) {
+            return Ok(lf.clone());
+        }
+
+        match &self.query_handler {
+            Some(query_handler) => query_handler(ticket),
+            None => polars_bail!(InvalidOperation: "no frame is registered as '{}'", ticket),
+        }
+    }
+}
+
+impl FlightTicketResolver for LazyFrameFlightResolver {
+    fn schema(&self, ticket: &[u8]) -> PolarsResult<SchemaRef> {
+        self.resolve(ticket)?.collect_schema()
+    }
+
+    fn fetch(&self, ticket: &[u8]) -> PolarsResult<DataFrame> {
+        self.resolve(ticket)?.collect()
+    }
+}
@@ -0,0 +1,375 @@
+use polars_buffer::Buffer;
+use polars_core::prelude::*;
+use polars_core::schema::iceberg::{IcebergSchema, IcebergSchemaRef};
+use polars_expr::ExpressionConversionState;
+use polars_io::cloud::CloudOptions;
+pub use polars_io::iceberg::IcebergVersion;
+use polars_io::iceberg::{IcebergScanFiles, IcebergTable};
+use polars_io::predicates::ScanIOPredicate;
+use polars_io::prelude::ParquetOptions;
+use polars_io::{HiveOptions, RowIndex};
+use polars_mem_engine::scan_predicate::create_scan_predicate;
+use polars_ops::frame::MaintainOrderJoin;
+use polars_plan::dsl::default_values::{
+    DefaultFieldValues, IcebergIdentityTransformedPartitionFields,
+};
+use polars_plan::dsl::deletion::DeletionFilesList;
+use polars_plan::plans::{ExprToIRContext, to_expr_ir};
+use polars_utils::pl_path::PlRefPath;
+use polars_utils::slice_enum::Slice;
+
+use crate::prelude::*;
+
+#[derive(Clone, Default)]
+pub struct ScanArgsIceberg {
+    /// Snapshot of the table to read. Defaults to the current snapshot.
+    pub version: IcebergVersion,
+    /// Filter applied to the rows of the table. It is also used to prune manifests and data
+    /// files using the partition summaries and column bounds of the table.
+    pub filter: Option<Expr>,
+    /// Maximum number of rows to read, counted after `filter` is applied.
+    pub n_rows: Option<usize>,
+    pub row_index: Option<RowIndex>,
+    pub cloud_options: Option<CloudOptions>,
+    pub rechunk: bool,
+    pub cache: bool,
+    pub include_file_paths: Option<PlSmallStr>,
+}
+
+impl LazyFrame {
+    /// Create a LazyFrame from an Apache Iceberg table.
+    ///
+    /// `table_uri` is either the path of a table metadata file or the root directory of the table,
+    /// in which case its latest metadata file is used. The manifests of the requested snapshot are
+    /// read eagerly to resolve the data files, which are then scanned as parquet files. Position
+    /// deletes are applied during the scan and equality deletes are applied with an anti-join.
+    pub fn scan_iceberg(table_uri: PlRefPath, args: ScanArgsIceberg) -> PolarsResult<Self> {
+        let table = polars_io::pl_async::get_runtime().block_in_place_on(IcebergTable::try_new(
+            table_uri.as_str(),
+            args.cloud_options.as_ref(),
+        ))?;
+
+        Self::scan_iceberg_table(&table, args)
+    }
+
+    /// Create a LazyFrame from an already loaded Iceberg table.
+    pub fn scan_iceberg_table(table: &IcebergTable, args: ScanArgsIceberg) -> PolarsResult<Self> {
+        let schema = Arc::new(
+            table
+                .metadata
+                .schema_for(args.version)?
+                .to_polars_schema()?,
+        );
+
+        let predicate = args
+            .filter
+            .as_ref()
+            .and_then(|filter| iceberg_scan_predicate(filter, &schema));
+
+        let files = polars_io::pl_async::get_runtime().block_in_place_on(table.scan_files(
+            args.version,
+            predicate.as_ref(),
+            args.cloud_options.as_ref(),
+        ))?;
+
+        let iceberg_schema: IcebergSchemaRef = Arc::new(files.schema.to_iceberg_schema()?);
+
+        // Data files are grouped by the equality delete files that apply to them.
+        let mut groups: PlIndexMap<&[usize], Vec<usize>> = PlIndexMap::new();
+
+        for (i, data_file) in files.data_files.iter().enumerate() {
+            groups
+                .entry(data_file.equality_deletes.as_slice())
+                .or_default()
+                .push(i);
+        }
+
+        let has_equality_deletes = groups.keys().any(|x| !x.is_empty());
+        let push_down_slice = args.filter.is_none() && !has_equality_deletes;
+
+        let mut lf = if groups.is_empty() {
+            DataFrame::empty_with_schema(&schema).lazy()
+        } else {
+            let lfs = groups
+                .iter()
+                .map(|(equality_deletes, file_indices)| {
+                    let mut lf: LazyFrame = scan_iceberg_data_files(
+                        &files,
+                        file_indices,
+                        &schema,
+                        &iceberg_schema,
+                        &args,
+                        push_down_slice,
+                    )?;
+
+                    for (equality_ids, delete_indices) in
+                        group_by_equality_ids(&files, equality_deletes)
+                    {
+                        let (deletes, names) = scan_iceberg_equality_deletes(
+                            &files,
+                            &delete_indices,
+                            &equality_ids,
+                            &iceberg_schema,
+                            &args,
+                        )?;
+
+                        let on: Vec<Expr> = names.into_iter().map(col).collect();
+
+                        lf = lf
+                            .join_builder()
+                            .with(deletes)
+                            .left_on(&on)
+                            .right_on(&on)
+                            .how(JoinType::Anti)
+                            .join_nulls(true)
+                            .maintain_order(MaintainOrderJoin::Left)
+                            .finish();
+                    }
+
+                    Ok(lf)
+                })
+                .collect::<PolarsResult<Vec<_>>>()?;
+
+            if lfs.len() == 1 {
+                lfs.into_iter().next().unwrap()
+            } else {
+                concat(
+                    lfs,
+                    UnionArgs {
+                        rechunk: args.rechunk,
+                        maintain_order: true,
+                        ..Default::default()
+                    },
+                )?
+            }
+        };
+
+        if let Some(filter) = args.filter {
+            lf = lf.filter(filter);
+        }
+
+        if !push_down_slice && let Some(n_rows) = args.n_rows {
+            lf = lf.slice(0, IdxSize::try_from(n_rows).unwrap_or(IdxSize::MAX));
+        }
+
+        // It's a bit hacky, but this row_index function updates the schema.
+        if let Some(row_index) = args.row_index {
+            lf = lf.with_row_index(row_index.name, Some(row_index.offset))
+        }
+
+        Ok(lf)
+    }
+}
+
+/// Converts the filter into a predicate that can be evaluated against file statistics. Returns
+/// `None` if this is not possible, in which case no files are pruned.
+fn iceberg_scan_predicate(filter: &Expr, schema: &SchemaRef) -> Option<ScanIOPredicate> {
+    let mut expr_arena = Arena::with_capacity(16);
+
+    let predicate = (|| {
+        let expr_ir = to_expr_ir(
+            filter.clone(),
+            &mut ExprToIRContext::new(&mut expr_arena, schema),
+        )?;
+
+        create_scan_predicate(
+            &expr_ir,
+            &mut expr_arena,
+            schema,
+            None,
+            &mut ExpressionConversionState::new(true),
+            true,
+            false,
+        )
+    })();
+
+    match predicate {
+        Ok(predicate) => Some(predicate.to_io(None, schema.clone())),
+        Err(e) => {
+            if polars_core::config::verbose() {
+                eprintln!("scan_iceberg: filter cannot be used to prune files: {e}")
+            }
+            None
+        },
+    }
+}
+
+/// Groups the equality delete files by their equality field IDs.
+fn group_by_equality_ids(
+    files: &IcebergScanFiles,
+    delete_indices: &[usize],
+) -> PlIndexMap<Vec<u32>, Vec<usize>> {
+    let mut out: PlIndexMap<Vec<u32>, Vec<usize>> = PlIndexMap::new();
+
+    for &i in delete_indices {
+        let mut equality_ids = files.equality_deletes[i].equality_ids.clone();
+        equality_ids.sort_unstable();
+        out.entry(equality_ids).or_default().push(i);
+    }
+
+    out
+}
+
+fn scan_iceberg_data_files(
+    files: &IcebergScanFiles,
+    file_indices: &[usize],
+    schema: &SchemaRef,
+    iceberg_schema: &IcebergSchemaRef,
+    args: &ScanArgsIceberg,
+    push_down_slice: bool,
+) -> PolarsResult<LazyFrame> {
+    let data_files = file_indices.iter().map(|&i| &files.data_files[i]);
+
+    let sources = ScanSources::Paths(Buffer::from_iter(
+        data_files.clone().map(|f| PlRefPath::new(&f.path)),
+    ));
+
+    let position_deletes: PlIndexMap<usize, Arc<[String]>> = data_files
+        .clone()
+        .enumerate()
+        .filter(|(_, f)| !f.position_deletes.is_empty())
+        .map(|(i, f)| (i, Arc::from(f.position_deletes.as_slice())))
+        .collect();
+
+    // The number of deleted rows is unknown if there are position deletes.
+    let row_count = position_deletes
+        .is_empty()
+        .then(|| (data_files.clone().map(|f| f.record_count).sum::<u64>(), 0));
+
+    let default_values = files
+        .identity_partition_values(file_indices)?
+        .into_iter()
+        .map(|(id, column)| (id, Ok(column)))
+        .collect::<PlIndexMap<_, _>>();
+
+    let mut unified_scan_args = iceberg_unified_scan_args(iceberg_schema.clone(), args);
+
+    unified_scan_args.pre_slice = args
+        .n_rows
+        .filter(|_| push_down_slice)
+        .map(|len| Slice::Positive { offset: 0, len });
+    unified_scan_args.include_file_paths = args.include_file_paths.clone();
+    unified_scan_args.default_values = (!default_values.is_empty()).then(|| {
+        DefaultFieldValues::Iceberg(Arc::new(IcebergIdentityTransformedPartitionFields(
+            default_values,
+        )))
+    });
+    unified_scan_args.deletion_files = DeletionFilesList::filter_empty(Some(
+        DeletionFilesList::IcebergPositionDelete(Arc::new(position_deletes)),
+    ));
+    unified_scan_args.table_statistics = Some(TableStatistics(Arc::new(
+        files.table_statistics(file_indices)?,
+    )));
+    unified_scan_args.row_count = row_count;
+
+    Ok(DslBuilder::scan_parquet(
+        sources,
+        iceberg_parquet_options(schema.clone()),
+        unified_scan_args,
+    )?
+    .build()
+    .into())
+}
+
+/// Scans the equality columns of a set of equality delete files. Returns the scan together with
+/// the names of the equality columns.
+fn scan_iceberg_equality_deletes(
+    files: &IcebergScanFiles,
+    delete_indices: &[usize],
+    equality_ids: &[u32],
+    iceberg_schema: &IcebergSchemaRef,
+    args: &ScanArgsIceberg,
+) -> PolarsResult<(LazyFrame, Vec<PlSmallStr>)> {
+    let columns = equality_ids
+        .iter()
+        .map(|id| {
+            let column = iceberg_schema.get(id).ok_or_else(|| {
+                polars_err!(
+                    nyi = "iceberg equality deletes on field {} that is not a top-level column of the table schema",
+                    id
+                )
+            })?;
+
+            Ok((*id, column.clone()))
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    let names: Vec<PlSmallStr> = columns.iter().map(|(_, c)| c.name.clone()).collect();
+
+    let file_schema: Schema = columns
+        .iter()
+        .map(|(_, c)| Field::new(c.name.clone(), c.type_.to_polars_dtype()))
+        .collect();
+
+    let sources = ScanSources::Paths(Buffer::from_iter(
+        delete_indices
+            .iter()
+            .map(|&i| PlRefPath::new(&files.equality_deletes[i].path)),
+    ));
+
+    let unified_scan_args =
+        iceberg_unified_scan_args(Arc::new(IcebergSchema::from_iter(columns)), args);
+
+    let lf: LazyFrame = DslBuilder::scan_parquet(
+        sources,
+        iceberg_parquet_options(Arc::new(file_schema)),
+        unified_scan_args,
+    )?
+    .build()
+    .into();
+
+    Ok((lf, names))
+}
+
+fn iceberg_parquet_options(schema: SchemaRef) -> ParquetOptions {
+    ParquetOptions {
+        schema: Some(schema),
+        parallel: Default::default(),
+        low_memory: false,
+        use_statistics: true,
+        keep_dictionaries: false,
+        keep_runs: false,
+    }
+}
+
+fn iceberg_unified_scan_args(
+    iceberg_schema: IcebergSchemaRef,
+    args: &ScanArgsIceberg,
+) -> UnifiedScanArgs {
+    UnifiedScanArgs {
+        schema: None,
+        cloud_options: args.cloud_options.clone(),
+        hive_options: HiveOptions {
+            enabled: Some(false),
+            ..Default::default()
+        },
+        rechunk: args.rechunk,
+        cache: args.cache,
+        glob: false,
+        hidden_file_prefix: None,
+        projection: None,
+        column_mapping: Some(ColumnMapping::Iceberg(iceberg_schema)),
+        default_values: None,
+        // Note: We call `with_row_index()` on the LazyFrame instead.
+        row_index: None,
+        pre_slice: None,
+        cast_columns_policy: CastColumnsPolicy {
+            integer_upcast: true,
+            float_upcast: true,
+            float_downcast: true,
+            datetime_nanoseconds_downcast: true,
+            datetime_microseconds_downcast: false,
+            datetime_convert_timezone: true,
+            null_upcast: true,
+            categorical_to_string: true,
+            missing_struct_fields: MissingColumnsPolicy::Insert,
+            extra_struct_fields: ExtraColumnsPolicy::Ignore,
+        },
+        missing_columns_policy: MissingColumnsPolicy::Insert,
+        extra_columns_policy: ExtraColumnsPolicy::Ignore,
+        include_file_paths: None,
+        deletion_files: None,
+        table_statistics: None,
+        row_count: None,
+    }
+}
@@ -3,6 +3,7 @@ use std::num::NonZeroUsize;
 use polars_buffer::Buffer;
 use polars_core::prelude::*;
 use polars_io::cloud::CloudOptions;
+use polars_io::utils::text_encoding::TextEncoding;
 use polars_io::{HiveOptions, RowIndex};
 use polars_plan::dsl::{
     CastColumnsPolicy, DslPlan, ExtraColumnsPolicy, FileScanDsl, MissingColumnsPolicy, ScanSources,
@@ -28,6 +29,8 @@ pub struct LazyJsonLineReader {
     pub(crate) ignore_errors: bool,
     pub(crate) include_file_paths: Option<PlSmallStr>,
     pub(crate) cloud_options: Option<CloudOptions>,
+    pub(crate) json_array_pointer: Option<PlSmallStr>,
+    pub(crate) encoding: TextEncoding,
 }
 
 impl LazyJsonLineReader {
@@ -49,6 +52,8 @@ impl LazyJsonLineReader {
             n_rows: None,
             include_file_paths: None,
             cloud_options: None,
+            json_array_pointer: None,
+            encoding: TextEncoding::Utf8,
         }
     }
 
@@ -121,6 +126,23 @@ impl LazyJsonLineReader {
         self.include_file_paths = include_file_paths;
         self
     }
+
+    /// Read the files as JSON documents, taking the rows from the array at this JSON pointer
+    /// (e.g. `/data/items`). Use the empty string for documents that are a top-level array.
+    ///
+    /// The documents are streamed and do not need to fit in memory.
+    #[must_use]
+    pub fn with_json_array_pointer(mut self, json_array_pointer: Option<PlSmallStr>) -> Self {
+        self.json_array_pointer = json_array_pointer;
+        self
+    }
+
+    /// Set the encoding of the files. Files that are not UTF-8 are transcoded while they are read.
+    #[must_use]
+    pub fn with_encoding(mut self, encoding: TextEncoding) -> Self {
+        self.encoding = encoding;
+        self
+    }
 }
 
 impl LazyFileListReader for LazyJsonLineReader {
@@ -155,6 +177,8 @@ impl LazyFileListReader for LazyJsonLineReader {
             ignore_errors: self.ignore_errors,
             schema: self.schema,
             schema_overwrite: self.schema_overwrite,
+            json_array_pointer: self.json_array_pointer,
+            encoding: self.encoding,
         };
 
         let scan_type = Box::new(FileScanDsl::NDJson { options });
@@ -25,6 +25,11 @@ pub struct ScanArgsParquet {
     pub glob: bool,
     pub include_file_paths: Option<PlSmallStr>,
     pub allow_missing_columns: bool,
+    /// Keep dictionary encoded String and Binary columns as dictionary columns.
+    pub keep_dictionaries: bool,
+    /// Keep dictionary encoded columns that consist of a few long runs as run-end encoded
+    /// columns.
+    pub keep_runs: bool,
 }
 
 impl Default for ScanArgsParquet {
@@ -43,6 +48,8 @@ impl Default for ScanArgsParquet {
             glob: true,
             include_file_paths: None,
             allow_missing_columns: false,
+            keep_dictionaries: false,
+            keep_runs: false,
         }
     }
 }
@@ -70,6 +77,8 @@ impl LazyFileListReader for LazyParquetReader {
             parallel: self.args.parallel,
             low_memory: self.args.low_memory,
             use_statistics: self.args.use_statistics,
+            keep_dictionaries: self.args.keep_dictionaries,
+            keep_runs: self.args.keep_runs,
         };
 
         let unified_scan_args = UnifiedScanArgs {
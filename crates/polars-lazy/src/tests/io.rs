@@ -39,6 +39,34 @@ fn test_parquet_exec() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "parquet")]
+fn test_parquet_keep_dictionaries() -> PolarsResult<()> {
+    let _guard = SINGLE_LOCK.lock().unwrap();
+    init_files();
+
+    let scan = |keep_dictionaries| {
+        let args = ScanArgsParquet {
+            keep_dictionaries,
+            ..Default::default()
+        };
+        LazyFrame::scan_parquet(PlRefPath::new(FOODS_PARQUET), args)
+    };
+    for predicate in [
+        col("category").eq(lit("seafood")),
+        col("category").neq(lit("seafood")),
+    ] {
+        let expected = scan(false)?.filter(predicate.clone()).collect()?;
+        let out = scan(true)?.filter(predicate).collect()?;
+        assert!(out.equals_missing(&expected));
+    }
+
+    let expected = scan(false)?.collect()?;
+    let out = scan(true)?.collect()?;
+    assert!(out.equals_missing(&expected));
+    Ok(())
+}
+
 #[test]
 #[cfg(all(feature = "parquet", feature = "is_between"))]
 fn test_parquet_statistics_no_skip() {
@@ -810,3 +838,111 @@ fn scan_small_dtypes() -> PolarsResult<()> {
     }
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "delta")]
+fn test_scan_delta_partition_values() -> PolarsResult<()> {
+    use polars_io::parquet::write::ParquetWriter;
+
+    let dir = std::env::temp_dir().join(format!("polars-delta-partitions-{}", std::process::id()));
+    std::fs::create_dir_all(dir.join("_delta_log"))?;
+
+    // The data files are not in hive directories, the partition values are only in the log.
+    for (name, x) in [("0.parquet", [1i64, 2]), ("1.parquet", [3, 4])] {
+        let mut df = df!("x" => x)?;
+        ParquetWriter::new(std::fs::File::create(dir.join(name))?).finish(&mut df)?;
+    }
+    std::fs::write(
+        dir.join("_delta_log").join(format!("{:020}.json", 0)),
+        r#"{"protocol":{"minReaderVersion":1,"minWriterVersion":2}}
+{"metaData":{"id":"a","format":{"provider":"parquet","options":{}},"schemaString":"{\"type\":\"struct\",\"fields\":[{\"name\":\"p\",\"type\":\"long\",\"nullable\":true,\"metadata\":{}},{\"name\":\"x\",\"type\":\"long\",\"nullable\":true,\"metadata\":{}}]}","partitionColumns":["p"],"configuration":{},"createdTime":0}}
+{"add":{"path":"0.parquet","partitionValues":{"p":"7"},"size":1,"modificationTime":0,"dataChange":true,"stats":"{\"numRecords\":2}"}}
+{"add":{"path":"1.parquet","partitionValues":{"p":null},"size":1,"modificationTime":0,"dataChange":true,"stats":"{\"numRecords\":2}"}}
+"#,
+    )?;
+
+    let out = LazyFrame::scan_delta(
+        PlRefPath::new(dir.to_str().unwrap()),
+        ScanArgsDelta::default(),
+    )?
+    .sort(["x"], Default::default())
+    .collect()?;
+    std::fs::remove_dir_all(&dir)?;
+
+    assert_eq!(out.get_column_names(), ["p", "x"]);
+    assert_eq!(
+        Vec::from(out.column("p")?.i64()?),
+        [Some(7), Some(7), None, None]
+    );
+    assert_eq!(
+        Vec::from(out.column("x")?.i64()?),
+        [Some(1), Some(2), Some(3), Some(4)]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "catalog")]
+fn test_sink_catalog_table_parquet() -> PolarsResult<()> {
+    use polars_io::catalog::unity::client::CatalogClientBuilder;
+    use polars_io::utils::mock_http::spawn_mock_server;
+
+    let dir = std::env::temp_dir().join(format!("polars-catalog-sink-{}", std::process::id()));
+    let table_json = format!(
+        r#"{{
+            "name": "t", "table_id": "id-1", "table_type": "EXTERNAL",
+            "storage_location": "{}", "data_source_format": "PARQUET",
+            "columns": [{{"name": "a", "type_text": "bigint", "type_name": "LONG",
+                "type_json": "{{\"name\":\"a\",\"type\":\"long\",\"nullable\":true,\"metadata\":{{}}}}",
+                "position": 0, "nullable": true}}],
+            "created_at": null, "created_by": null, "updated_at": null, "updated_by": null
+        }}"#,
+        dir.to_str().unwrap()
+    );
+    let (url, requests) = spawn_mock_server(vec![table_json]);
+
+    let client = CatalogClientBuilder::new()
+        .with_workspace_url(url)
+        .with_bearer_token("token")
+        .build()?;
+    let table_info =
+        polars_io::pl_async::get_runtime().block_on(client.get_table_info("cat", "ns", "t"))?;
+    assert!(
+        requests.lock().unwrap()[0]
+            .1
+            .starts_with("/api/2.1/unity-catalog/tables/cat.ns.t")
+    );
+
+    // Every sink adds a data file with a unique name.
+    for a in [[1i64, 2], [3, 4]] {
+        df!("a" => a)?
+            .lazy()
+            .sink_catalog_table(&table_info, None)?;
+    }
+    let err = df!("a" => ["x"])?
+        .lazy()
+        .sink_catalog_table(&table_info, None)
+        .unwrap_err();
+    assert!(matches!(err, PolarsError::SchemaMismatch(_)));
+
+    let file_names = std::fs::read_dir(&dir)?
+        .map(|entry| Ok(entry?.file_name().into_string().unwrap()))
+        .collect::<std::io::Result<Vec<_>>>()?;
+    let out = LazyFrame::scan_catalog_table(&table_info, None)?
+        .sort(["a"], Default::default())
+        .collect()?;
+    std::fs::remove_dir_all(&dir)?;
+
+    assert_eq!(file_names.len(), 2);
+    assert_ne!(file_names[0], file_names[1]);
+    assert!(
+        file_names
+            .iter()
+            .all(|name| name.starts_with("part-00000-") && name.ends_with("-c000.parquet"))
+    );
+    assert_eq!(
+        Vec::from(out.column("a")?.i64()?),
+        [Some(1), Some(2), Some(3), Some(4)]
+    );
+    Ok(())
+}
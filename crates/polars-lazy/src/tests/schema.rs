@@ -46,3 +46,137 @@ fn test_schema_update_after_projection_pd() -> PolarsResult<()> {
 
     Ok(())
 }
+
+/// `money` stores cents as `Int64`, `ci_str` is a case-insensitive `String`.
+#[cfg(feature = "dtype-extension")]
+#[derive(Clone)]
+struct TestExtension(&'static str);
+
+#[cfg(feature = "dtype-extension")]
+impl polars_core::datatypes::extension::ExtensionTypeImpl for TestExtension {
+    fn name(&self) -> std::borrow::Cow<'_, str> {
+        self.0.into()
+    }
+
+    fn serialize_metadata(&self) -> Option<std::borrow::Cow<'_, str>> {
+        None
+    }
+
+    fn dyn_clone(&self) -> Box<dyn polars_core::datatypes::extension::ExtensionTypeImpl> {
+        Box::new(self.clone())
+    }
+
+    fn dyn_eq(&self, other: &dyn polars_core::datatypes::extension::ExtensionTypeImpl) -> bool {
+        (other as &dyn std::any::Any)
+            .downcast_ref::<TestExtension>()
+            .is_some_and(|other| self.0 == other.0)
+    }
+
+    fn dyn_hash(&self) -> u64 {
+        self.0.len() as u64
+    }
+
+    fn dyn_display(&self) -> std::borrow::Cow<'_, str> {
+        self.0.into()
+    }
+
+    fn dyn_debug(&self) -> std::borrow::Cow<'_, str> {
+        self.0.into()
+    }
+
+    fn comparison_key(&self, storage: &Series) -> Option<Series> {
+        (self.0 == "ci_str").then(|| {
+            let ca = storage.str().unwrap();
+            let lower = ca.iter().map(|s| s.map(str::to_lowercase));
+            StringChunked::from_iter_options(ca.name().clone(), lower).into_series()
+        })
+    }
+
+    fn preserves_extension(
+        &self,
+        op: polars_core::datatypes::extension::ExtensionOperation,
+    ) -> bool {
+        use polars_core::datatypes::extension::ExtensionOperation;
+
+        self.0 == "money" && matches!(op, ExtensionOperation::Add | ExtensionOperation::Sub)
+    }
+}
+
+#[cfg(feature = "dtype-extension")]
+fn test_extension(name: &'static str, storage: Series) -> Column {
+    use polars_core::datatypes::extension::ExtensionTypeInstance;
+
+    storage
+        .into_extension(ExtensionTypeInstance(Box::new(TestExtension(name))))
+        .into_column()
+}
+
+#[test]
+#[cfg(feature = "dtype-extension")]
+fn test_extension_arithmetic_schema() -> PolarsResult<()> {
+    let money = test_extension("money", Series::new("m".into(), [150i64, 2005]));
+    let money_dtype = money.dtype().clone();
+    let df = DataFrame::new_infer_height(vec![money])?;
+
+    let lf = df.lazy().select([
+        (col("m") + col("m")).alias("sum"),
+        (col("m") - lit(5i64)).alias("diff"),
+        (col("m") * col("m")).alias("product"),
+        (col("m") % lit(7i64)).alias("rem"),
+    ]);
+
+    let schema = lf.clone().collect_schema()?;
+    assert_eq!(schema.get("sum"), Some(&money_dtype));
+    assert_eq!(schema.get("diff"), Some(&money_dtype));
+    assert_eq!(schema.get("product"), Some(&DataType::Int64));
+    assert_eq!(schema.get("rem"), Some(&DataType::Int64));
+
+    let out = lf.collect()?;
+    assert_eq!(out.schema(), &schema);
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-extension")]
+fn test_extension_comparison_key() -> PolarsResult<()> {
+    let df = DataFrame::new_infer_height(vec![
+        test_extension("ci_str", Series::new("s".into(), ["a", "A", "b", "B", "a"])),
+        Column::new("v".into(), [1i32, 2, 3, 4, 5]),
+    ])?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .group_by_stable([col("s")])
+        .agg([col("v").sum()])
+        .collect()?;
+    assert_eq!(out.height(), 2);
+    assert_eq!(out.column("s")?.dtype(), df.column("s")?.dtype());
+    assert_eq!(Vec::from(out.column("v")?.i32()?), &[Some(8), Some(7)]);
+
+    let out = df
+        .clone()
+        .lazy()
+        .unique_stable(Some(cols(["s"])), UniqueKeepStrategy::First)
+        .collect()?;
+    assert_eq!(Vec::from(out.column("v")?.i32()?), &[Some(1), Some(3)]);
+
+    let other = DataFrame::new_infer_height(vec![
+        test_extension("ci_str", Series::new("s".into(), ["B"])),
+        Column::new("w".into(), [10i32]),
+    ])?;
+    let out = df
+        .lazy()
+        .join(
+            other.lazy(),
+            [col("s")],
+            [col("s")],
+            JoinArgs::new(JoinType::Inner),
+        )
+        .sort(["v"], Default::default())
+        .collect()?;
+    assert_eq!(Vec::from(out.column("v")?.i32()?), &[Some(3), Some(4)]);
+
+    Ok(())
+}
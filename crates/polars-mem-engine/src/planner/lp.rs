@@ -389,7 +389,10 @@ fn create_physical_plan_impl(
                         feature = "ipc",
                         feature = "csv",
                         feature = "json",
-                        feature = "scan_lines"
+                        feature = "scan_lines",
+                        feature = "avro",
+                        feature = "orc",
+                        feature = "flight"
                     )),
                     expect(unreachable_patterns)
                 )]
@@ -480,6 +480,15 @@ where
             #[cfg(feature = "json")]
             FileScanIR::NDJson { options: _ } => {},
 
+            #[cfg(feature = "avro")]
+            FileScanIR::Avro => {},
+
+            #[cfg(feature = "orc")]
+            FileScanIR::Orc => {},
+
+            #[cfg(feature = "flight")]
+            FileScanIR::Flight { .. } => {},
+
             #[cfg(feature = "python")]
             FileScanIR::PythonDataset {
                 dataset_object: _,
@@ -503,20 +512,31 @@ where
             .collect::<Vec<_>>()
     });
 
-    *deletion_files = deletion_files.as_ref().and_then(|x| match x {
-        DeletionFilesList::IcebergPositionDelete(deletions) => {
-            let mut out = None;
+    fn select_deletions<T: Clone>(
+        deletions: &PlIndexMap<usize, T>,
+        selected_path_indices: impl Iterator<Item = usize>,
+    ) -> Option<Arc<PlIndexMap<usize, T>>> {
+        let mut out = None;
+        let len = selected_path_indices.size_hint().0;
 
-            for (out_idx, source_idx) in selected_path_indices.clone().enumerate() {
-                if let Some(v) = deletions.get(&source_idx) {
-                    out.get_or_insert_with(|| {
-                        PlIndexMap::with_capacity(selected_path_indices.size_hint().0 - out_idx)
-                    })
+        for (out_idx, source_idx) in selected_path_indices.enumerate() {
+            if let Some(v) = deletions.get(&source_idx) {
+                out.get_or_insert_with(|| PlIndexMap::with_capacity(len.saturating_sub(out_idx)))
                     .insert(out_idx, v.clone());
-                }
             }
+        }
+
+        out.map(Arc::new)
+    }
 
-            out.map(|x| DeletionFilesList::IcebergPositionDelete(Arc::new(x)))
+    *deletion_files = deletion_files.as_ref().and_then(|x| match x {
+        DeletionFilesList::IcebergPositionDelete(deletions) => {
+            select_deletions(deletions, selected_path_indices.clone())
+                .map(DeletionFilesList::IcebergPositionDelete)
+        },
+        DeletionFilesList::DeltaDeletionVector(deletions) => {
+            select_deletions(deletions, selected_path_indices.clone())
+                .map(DeletionFilesList::DeltaDeletionVector)
         },
     });
 
@@ -0,0 +1,240 @@
+use arrow::array::Array;
+use arrow::offset::OffsetsBuffer;
+
+use super::*;
+
+fn entries_field(ca: &ListChunked, name: &str) -> PolarsResult<ListChunked> {
+    ca.apply_to_inner(&|entries| entries.struct_()?.field_by_name(name))
+}
+
+/// For every map, find the index of the first entry whose key equals `key`. The index points into
+/// the entries of the rechunked physical list, which are returned as well.
+fn find_keys(ca: &MapChunked, key: &Series) -> PolarsResult<(Series, Vec<Option<IdxSize>>)> {
+    polars_ensure!(
+        key.len() == 1 || key.len() == ca.len(),
+        length_mismatch = "map.get",
+        ca.len(),
+        key.len()
+    );
+    let key = key.strict_cast(ca.key_dtype())?;
+
+    let phys = ca.physical().rechunk();
+    let arr = phys.downcast_as_array();
+    let entries = phys.get_inner();
+    let keys = entries.struct_()?.field_by_name("key")?;
+
+    let mask = if key.len() == 1 {
+        keys.equal(&key)?
+    } else {
+        // Broadcast every key over the entries of its map.
+        let mut row_idx = vec![0 as IdxSize; keys.len()];
+        for i in 0..arr.len() {
+            let (start, end) = arr.offsets().start_end(i);
+            row_idx[start..end].fill(i as IdxSize);
+        }
+        keys.equal(&key.take_slice(&row_idx)?)?
+    };
+    let mask = mask.rechunk();
+    let mask = mask.downcast_as_array();
+
+    let idx = (0..arr.len())
+        .map(|i| {
+            if !arr.is_valid(i) {
+                return None;
+            }
+            let (start, end) = arr.offsets().start_end(i);
+            (start..end)
+                .find(|&j| mask.is_valid(j) && mask.value(j))
+                .map(|j| j as IdxSize)
+        })
+        .collect();
+    Ok((entries, idx))
+}
+
+pub trait MapNameSpace: AsMap {
+    /// Get the value of the first entry with the given key, or null if the key is missing.
+    fn map_get(&self, key: &Series) -> PolarsResult<Series> {
+        let ca = self.as_map();
+        let (entries, idx) = find_keys(ca, key)?;
+        let values = entries.struct_()?.field_by_name("value")?;
+        let idx = IdxCa::from_iter_options(PlSmallStr::EMPTY, idx.into_iter());
+        let mut out = values.take(&idx)?;
+        out.rename(ca.name().clone());
+        Ok(out)
+    }
+
+    fn map_contains_key(&self, key: &Series) -> PolarsResult<BooleanChunked> {
+        let ca = self.as_map();
+        let (_, idx) = find_keys(ca, key)?;
+        let validity = ca.physical().rechunk_validity();
+        Ok(BooleanChunked::from_iter_options(
+            ca.name().clone(),
+            idx.into_iter().enumerate().map(|(i, idx)| {
+                if validity.as_ref().is_some_and(|v| !v.get_bit(i)) {
+                    None
+                } else {
+                    Some(idx.is_some())
+                }
+            }),
+        ))
+    }
+
+    fn map_keys(&self) -> PolarsResult<ListChunked> {
+        let ca = self.as_map();
+        entries_field(ca.physical(), "key")
+    }
+
+    fn map_values(&self) -> PolarsResult<ListChunked> {
+        let ca = self.as_map();
+        entries_field(ca.physical(), "value")
+    }
+}
+
+impl MapNameSpace for MapChunked {}
+
+/// Create a map from a list of keys and a list of values of the same lengths.
+pub fn map_from_lists(keys: &ListChunked, values: &ListChunked) -> PolarsResult<MapChunked> {
+    polars_ensure!(
+        keys.len() == values.len(),
+        length_mismatch = "map.from_lists",
+        keys.len(),
+        values.len()
+    );
+    let keys = keys.rechunk();
+    let values = values.rechunk();
+    let k_arr = keys.downcast_as_array();
+    let v_arr = values.downcast_as_array();
+
+    let mut k_idx = Vec::with_capacity(k_arr.values().len());
+    let mut v_idx = Vec::with_capacity(v_arr.values().len());
+    let mut offsets = Vec::with_capacity(keys.len() + 1);
+    offsets.push(0i64);
+    let mut validity = Vec::with_capacity(keys.len());
+    for i in 0..keys.len() {
+        let valid = k_arr.is_valid(i) && v_arr.is_valid(i);
+        if valid {
+            let (k_start, k_end) = k_arr.offsets().start_end(i);
+            let (v_start, v_end) = v_arr.offsets().start_end(i);
+            polars_ensure!(
+                k_end - k_start == v_end - v_start,
+                ShapeMismatch: "keys and values of a map must have the same length, got {} and {} in row {}",
+                k_end - k_start, v_end - v_start, i
+            );
+            k_idx.extend(k_start as IdxSize..k_end as IdxSize);
+            v_idx.extend(v_start as IdxSize..v_end as IdxSize);
+        }
+        offsets.push(k_idx.len() as i64);
+        validity.push(valid);
+    }
+
+    let key = keys
+        .get_inner()
+        .take_slice(&k_idx)?
+        .with_name(PlSmallStr::from_static("key"));
+    polars_ensure!(
+        !key.has_nulls(),
+        ComputeError: "map keys must not be null, found {} null keys", key.null_count()
+    );
+    let value = values
+        .get_inner()
+        .take_slice(&v_idx)?
+        .with_name(PlSmallStr::from_static("value"));
+    let entries = StructChunked::from_series(
+        PlSmallStr::from_static("entries"),
+        key.len(),
+        [key, value].iter(),
+    )?
+    .into_series()
+    .rechunk();
+    let entries_arr = entries.chunks()[0].clone();
+
+    let arr = LargeListArray::new(
+        LargeListArray::default_datatype(entries_arr.dtype().clone()),
+        // SAFETY: the offsets are monotonically increasing.
+        unsafe { OffsetsBuffer::new_unchecked(offsets.into()) },
+        entries_arr,
+        Some(validity.into_iter().collect()),
+    );
+    // SAFETY: the dtype matches the entries array.
+    let ca = unsafe {
+        ListChunked::from_chunks_and_dtype_unchecked(
+            keys.name().clone(),
+            vec![arr.boxed()],
+            DataType::List(Box::new(entries.dtype().clone())),
+        )
+    };
+    ca.into_map()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn list<T: AsRef<[Option<&'static str>]>>(rows: &[Option<T>]) -> ListChunked {
+        rows.iter()
+            .map(|row| {
+                row.as_ref()
+                    .map(|v| Series::new(PlSmallStr::EMPTY, v.as_ref()))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_map_from_lists() {
+        let keys = list(&[
+            Some([Some("a"), Some("b")]),
+            None,
+            Some([Some("b"), Some("b")]),
+        ]);
+        let values = list(&[
+            Some([Some("1"), None]),
+            Some([Some("2"), Some("3")]),
+            Some([Some("4"), Some("5")]),
+        ]);
+        let ca = map_from_lists(&keys, &values).unwrap();
+        assert_eq!(ca.null_count(), 1);
+
+        let key = Series::new(PlSmallStr::EMPTY, ["b"]);
+        let out = ca.map_get(&key).unwrap();
+        // The first entry wins for duplicate keys.
+        assert_eq!(
+            out.str().unwrap().iter().collect::<Vec<_>>(),
+            [None, None, Some("4")]
+        );
+        let out = ca.map_contains_key(&key).unwrap();
+        assert_eq!(
+            out.iter().collect::<Vec<_>>(),
+            [Some(true), None, Some(true)]
+        );
+
+        let key = Series::new(PlSmallStr::EMPTY, ["a", "a", "c"]);
+        let out = ca.map_contains_key(&key).unwrap();
+        assert_eq!(
+            out.iter().collect::<Vec<_>>(),
+            [Some(true), None, Some(false)]
+        );
+
+        let out = ca.map_values().unwrap().get_as_series(0).unwrap();
+        assert_eq!(
+            out.str().unwrap().iter().collect::<Vec<_>>(),
+            [Some("1"), None]
+        );
+    }
+
+    #[test]
+    fn test_map_from_lists_invalid() {
+        let keys = list(&[Some([Some("a"), None])]);
+        let values = list(&[Some([Some("1"), Some("2")])]);
+        let err = map_from_lists(&keys, &values).unwrap_err();
+        assert!(err.to_string().contains("map keys must not be null"));
+
+        let keys = list(&[Some(vec![Some("a")])]);
+        let values = list(&[Some(vec![Some("1"), Some("2")])]);
+        assert!(map_from_lists(&keys, &values).is_err());
+
+        // Null keys in rows that are null themselves are not part of the map.
+        let keys = list(&[Some(vec![None]), Some(vec![Some("a")])]);
+        let values = list(&[None, Some(vec![Some("1")])]);
+        assert_eq!(map_from_lists(&keys, &values).unwrap().null_count(), 1);
+    }
+}
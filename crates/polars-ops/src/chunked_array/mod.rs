@@ -4,6 +4,8 @@ mod binary;
 #[cfg(feature = "timezones")]
 pub mod datetime;
 pub mod list;
+#[cfg(feature = "dtype-map")]
+pub mod map;
 #[cfg(feature = "propagate_nans")]
 pub mod nan_propagating_aggregate;
 #[cfg(feature = "peaks")]
@@ -13,6 +15,8 @@ pub mod strings;
 mod sum;
 #[cfg(feature = "top_k")]
 mod top_k;
+#[cfg(feature = "dtype-variant")]
+pub mod variant;
 
 #[cfg(feature = "mode")]
 pub mod mode;
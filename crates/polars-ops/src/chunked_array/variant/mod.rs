@@ -0,0 +1,14 @@
+mod namespace;
+
+pub use namespace::*;
+use polars_core::prelude::*;
+
+pub trait AsVariant {
+    fn as_variant(&self) -> &VariantChunked;
+}
+
+impl AsVariant for VariantChunked {
+    fn as_variant(&self) -> &VariantChunked {
+        self
+    }
+}
@@ -0,0 +1,137 @@
+pub use polars_compute::variant::VariantPath;
+use polars_compute::variant::{VariantPrimitive, VariantRef, VariantView};
+
+use super::*;
+
+/// Convert a variant to an [`AnyValue`]. Objects and arrays are converted to their JSON text.
+fn variant_to_any_value(v: VariantRef<'_>) -> PolarsResult<AnyValue<'static>> {
+    use VariantPrimitive as P;
+    let p = match v.view()? {
+        VariantView::Primitive(p) => p,
+        VariantView::Object(_) | VariantView::Array(_) => {
+            return Ok(AnyValue::StringOwned(v.to_json()?.into()));
+        },
+    };
+    Ok(match p {
+        P::Null => AnyValue::Null,
+        P::Boolean(v) => AnyValue::Boolean(v),
+        P::Int8(v) => AnyValue::Int64(v as i64),
+        P::Int16(v) => AnyValue::Int64(v as i64),
+        P::Int32(v) => AnyValue::Int64(v as i64),
+        P::Int64(v) => AnyValue::Int64(v),
+        P::Float(v) => AnyValue::Float32(v),
+        P::Double(v) => AnyValue::Float64(v),
+        #[cfg(feature = "dtype-decimal")]
+        P::Decimal4 { value, scale } => AnyValue::Decimal(value as i128, 38, scale as usize),
+        #[cfg(feature = "dtype-decimal")]
+        P::Decimal8 { value, scale } => AnyValue::Decimal(value as i128, 38, scale as usize),
+        #[cfg(feature = "dtype-decimal")]
+        P::Decimal16 { value, scale } => AnyValue::Decimal(value, 38, scale as usize),
+        #[cfg(feature = "dtype-date")]
+        P::Date(v) => AnyValue::Date(v),
+        // Timestamps adjusted to UTC are returned as naive UTC datetimes.
+        #[cfg(feature = "dtype-datetime")]
+        P::Timestamp(v) | P::TimestampNtz(v) => AnyValue::Datetime(v, TimeUnit::Microseconds, None),
+        #[cfg(feature = "dtype-datetime")]
+        P::TimestampNanos(v) | P::TimestampNtzNanos(v) => {
+            AnyValue::Datetime(v, TimeUnit::Nanoseconds, None)
+        },
+        #[cfg(feature = "dtype-time")]
+        P::TimeNtz(v) => AnyValue::Time(v * 1000),
+        P::Binary(v) => AnyValue::BinaryOwned(v.to_vec()),
+        P::String(v) => AnyValue::StringOwned(v.into()),
+        P::Uuid(v) => AnyValue::BinaryOwned(v.to_vec()),
+        #[allow(unreachable_patterns)]
+        _ => AnyValue::Null,
+    })
+}
+
+pub trait VariantNameSpace: AsVariant {
+    /// Extract the value at `path` of every variant.
+    ///
+    /// If `dtype` is [`DataType::Variant`] the sub-variants are returned as is. Otherwise values
+    /// are converted to `dtype`, and missing or non-convertible values become null.
+    fn variant_get(&self, path: &VariantPath, dtype: &DataType) -> PolarsResult<Series> {
+        let ca = self.as_variant();
+        let name = ca.name().clone();
+        if dtype.is_variant() {
+            let mut phys: BinaryChunked = ca
+                .physical()
+                .iter()
+                .map(|opt| {
+                    let Some(bytes) = opt else { return Ok(None) };
+                    let v = VariantRef::try_new(bytes)?.get_path(path)?;
+                    Ok(v.map(|v| v.to_bytes()))
+                })
+                .collect::<PolarsResult<_>>()?;
+            phys.rename(name);
+            return Ok(phys.into_variant(true)?.into_series());
+        }
+
+        let values = ca
+            .physical()
+            .iter()
+            .map(|opt| {
+                let Some(bytes) = opt else {
+                    return Ok(AnyValue::Null);
+                };
+                match VariantRef::try_new(bytes)?.get_path(path)? {
+                    Some(v) => variant_to_any_value(v),
+                    None => Ok(AnyValue::Null),
+                }
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        Series::from_any_values_and_dtype(name, &values, dtype, false)
+    }
+
+    /// The type of every variant value, e.g. `"object"` or `"int64"`.
+    fn variant_type_name(&self) -> PolarsResult<StringChunked> {
+        let ca = self.as_variant();
+        let mut out: StringChunked = ca
+            .physical()
+            .iter()
+            .map(|opt| {
+                opt.map(|bytes| VariantRef::try_new(bytes)?.type_name())
+                    .transpose()
+            })
+            .collect::<PolarsResult<_>>()?;
+        out.rename(ca.name().clone());
+        Ok(out)
+    }
+
+    fn variant_to_json(&self) -> PolarsResult<StringChunked> {
+        self.as_variant().to_json()
+    }
+}
+
+impl VariantNameSpace for VariantChunked {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_variant_get() {
+        let ca = StringChunked::new(
+            PlSmallStr::from_static("v"),
+            &[Some(r#"{"a": {"b": [1, "x"]}}"#), Some(r#"{"a": 2}"#), None],
+        )
+        .json_to_variant(true)
+        .unwrap();
+
+        let path = VariantPath::parse("a.b[0]").unwrap();
+        let out = ca.variant_get(&path, &DataType::Int32).unwrap();
+        assert_eq!(
+            out.i32().unwrap().iter().collect::<Vec<_>>(),
+            [Some(1), None, None]
+        );
+
+        let path = VariantPath::parse("$.a").unwrap();
+        let out = ca.variant_get(&path, &DataType::Variant).unwrap();
+        let types = out.variant().unwrap().variant_type_name().unwrap();
+        assert_eq!(
+            types.iter().collect::<Vec<_>>(),
+            [Some("object"), Some("int8"), None]
+        );
+    }
+}
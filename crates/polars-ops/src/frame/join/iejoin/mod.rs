@@ -357,7 +357,7 @@ pub(super) fn iejoin(
     unsafe { materialize_join(left, right, &left_row_idx, &right_row_idx, suffix) }
 }
 
-unsafe fn materialize_join(
+pub(super) unsafe fn materialize_join(
     left: &DataFrame,
     right: &DataFrame,
     left_row_idx: &IdxCa,
@@ -379,7 +379,7 @@ unsafe fn materialize_join(
 /// (one of [<, <=, >, >=]).
 /// Based on Khayyat et al. 2015, "Lightning Fast and Space Efficient Inequality Joins"
 /// and extended to work with duplicate values.
-fn iejoin_tuples(
+pub(super) fn iejoin_tuples(
     selected_left: Vec<Series>,
     selected_right: Vec<Series>,
     options: &IEJoinOptions,
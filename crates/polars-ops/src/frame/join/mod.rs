@@ -10,6 +10,8 @@ mod iejoin;
 pub mod merge_join;
 #[cfg(feature = "merge_sorted")]
 mod merge_sorted;
+#[cfg(feature = "geo")]
+mod spatial;
 
 use std::borrow::Cow;
 use std::fmt::{Debug, Display, Formatter};
@@ -44,6 +46,8 @@ use polars_core::utils::slice_offsets;
 use polars_core::utils::slice_slice;
 use polars_utils::hashing::BytesHash;
 use rayon::prelude::*;
+#[cfg(feature = "geo")]
+pub use spatial::{SpatialJoin, SpatialPredicate};
 
 use self::cross_join::fused_cross_filter;
 use super::IntoDf;
@@ -226,6 +230,13 @@ pub trait DataFrameJoinOps: IntoDf {
             );
         };
 
+        // Extension types are joined on their comparison key.
+        #[cfg(feature = "dtype-extension")]
+        let (selected_left, selected_right) = (
+            to_comparison_keys(selected_left)?,
+            to_comparison_keys(selected_right)?,
+        );
+
         #[cfg(feature = "iejoin")]
         if let JoinType::IEJoin = args.how {
             let Some(JoinTypeOptions::IEJoin(options)) = options else {
@@ -630,6 +641,13 @@ trait DataFrameJoinOpsPrivate: IntoDf {
 impl DataFrameJoinOps for DataFrame {}
 impl DataFrameJoinOpsPrivate for DataFrame {}
 
+#[cfg(feature = "dtype-extension")]
+fn to_comparison_keys(keys: Vec<Series>) -> PolarsResult<Vec<Series>> {
+    keys.iter()
+        .map(|s| Ok(s.to_comparison_key()?.into_owned()))
+        .collect()
+}
+
 fn prepare_keys_multiple(s: &[Series], nulls_equal: bool) -> PolarsResult<BinaryOffsetChunked> {
     let keys = s
         .iter()
@@ -0,0 +1,160 @@
+use polars_compute::geo::{self, Geometry, Rect};
+use polars_core::prelude::*;
+use polars_utils::IdxSize;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::iejoin::{IEJoinOptions, InequalityOperator, iejoin_tuples, materialize_join};
+use crate::frame::IntoDf;
+use crate::series::parse_geometries;
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum SpatialPredicate {
+    /// The geometries have at least one point in common.
+    #[default]
+    Intersects,
+    /// The left geometry contains the right geometry.
+    Contains,
+    /// The left geometry lies within the right geometry.
+    Within,
+}
+
+impl SpatialPredicate {
+    fn matches(&self, left: &Geometry, right: &Geometry) -> bool {
+        match self {
+            Self::Intersects => geo::intersects(left, right),
+            Self::Contains => geo::contains(left, right),
+            Self::Within => geo::contains(right, left),
+        }
+    }
+}
+
+fn bboxes(geoms: &[Option<Geometry>]) -> Vec<Option<Rect>> {
+    geoms
+        .iter()
+        .map(|g| g.as_ref().and_then(Geometry::bbox))
+        .collect()
+}
+
+fn bbox_column(bboxes: &[Option<Rect>], f: fn(&Rect) -> f64) -> Series {
+    Float64Chunked::from_iter_options(PlSmallStr::EMPTY, bboxes.iter().map(|r| r.as_ref().map(f)))
+        .into_series()
+}
+
+pub trait SpatialJoin: IntoDf {
+    /// Inner join of the rows whose geometries in `left_on` and `right_on` satisfy `predicate`.
+    ///
+    /// Candidate pairs are found with an inequality join on the overlap of the bounding boxes in
+    /// the x direction, after which the y overlap and the exact predicate are checked. Rows with
+    /// null or empty geometries never match.
+    ///
+    /// This is only available on `DataFrame`, there is no `LazyFrame` or streaming variant. Lazy
+    /// queries have to collect both inputs first.
+    fn spatial_join(
+        &self,
+        other: &DataFrame,
+        left_on: &str,
+        right_on: &str,
+        predicate: SpatialPredicate,
+        suffix: Option<PlSmallStr>,
+    ) -> PolarsResult<DataFrame> {
+        let left = self.to_df();
+        let left_geoms = parse_geometries(left.column(left_on)?.as_materialized_series())?;
+        let right_geoms = parse_geometries(other.column(right_on)?.as_materialized_series())?;
+        let left_bboxes = bboxes(&left_geoms);
+        let right_bboxes = bboxes(&right_geoms);
+
+        // Bounding boxes overlap in x if `left.min_x <= right.max_x` and
+        // `left.max_x >= right.min_x`.
+        let options = IEJoinOptions {
+            operator1: InequalityOperator::LtEq,
+            operator2: Some(InequalityOperator::GtEq),
+        };
+        let (left_idx, right_idx) = iejoin_tuples(
+            vec![
+                bbox_column(&left_bboxes, |r| r.min_x),
+                bbox_column(&left_bboxes, |r| r.max_x),
+            ],
+            vec![
+                bbox_column(&right_bboxes, |r| r.max_x),
+                bbox_column(&right_bboxes, |r| r.min_x),
+            ],
+            &options,
+            None,
+        )?;
+
+        let mut pairs: Vec<(IdxSize, IdxSize)> = left_idx
+            .into_no_null_iter()
+            .zip(right_idx.into_no_null_iter())
+            .filter(|&(l, r)| {
+                let (l, r) = (l as usize, r as usize);
+                let (Some(lb), Some(rb)) = (&left_bboxes[l], &right_bboxes[r]) else {
+                    return false;
+                };
+                lb.intersects(rb)
+                    && predicate.matches(
+                        left_geoms[l].as_ref().unwrap(),
+                        right_geoms[r].as_ref().unwrap(),
+                    )
+            })
+            .collect();
+        pairs.sort_unstable();
+
+        let (left_idx, right_idx): (Vec<_>, Vec<_>) = pairs.into_iter().unzip();
+        let left_idx = IdxCa::from_vec(PlSmallStr::EMPTY, left_idx);
+        let right_idx = IdxCa::from_vec(PlSmallStr::EMPTY, right_idx);
+        // SAFETY: the indices come from the join of the bounding boxes of both frames.
+        unsafe { materialize_join(left, other, &left_idx, &right_idx, suffix) }
+    }
+}
+
+impl SpatialJoin for DataFrame {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::series::geo_from_wkt;
+
+    #[test]
+    fn test_spatial_join() -> PolarsResult<()> {
+        let points = geo_from_wkt(&Series::new(
+            "point".into(),
+            [
+                "POINT (1 1)",
+                "POINT (5 5)",
+                "POINT (11 1)",
+                "POINT (20 20)",
+            ],
+        ))?;
+        let zones = geo_from_wkt(&Series::new(
+            "zone".into(),
+            [
+                "POLYGON ((0 0, 6 0, 6 6, 0 6, 0 0))",
+                "POLYGON ((4 0, 12 0, 12 2, 4 2, 4 0))",
+            ],
+        ))?;
+        let points = DataFrame::new(
+            4,
+            vec![Series::new("id".into(), [0, 1, 2, 3]).into(), points.into()],
+        )?;
+        let zones = DataFrame::new(
+            2,
+            vec![
+                Series::new("zone_id".into(), ["a", "b"]).into(),
+                zones.into(),
+            ],
+        )?;
+
+        let out = points.spatial_join(&zones, "point", "zone", SpatialPredicate::Within, None)?;
+        let ids = out.column("id")?.i32()?;
+        let zone_ids = out.column("zone_id")?.str()?;
+        assert_eq!(Vec::from(ids), &[Some(0), Some(1), Some(2)]);
+        assert_eq!(Vec::from(zone_ids), &[Some("a"), Some("a"), Some("b")]);
+
+        let out = zones.spatial_join(&points, "zone", "point", SpatialPredicate::Contains, None)?;
+        assert_eq!(out.height(), 3);
+        Ok(())
+    }
+}
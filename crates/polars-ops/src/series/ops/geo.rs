@@ -0,0 +1,181 @@
+use arrow::bitmap::Bitmap;
+use polars_compute::geo::{self, Geometry};
+use polars_core::datatypes::extension::{geometry_dtype, is_geometry, wkb_to_wkt, wkt_to_wkb};
+use polars_core::prelude::*;
+
+/// The WKB storage of a geometry series.
+fn wkb(s: &Series) -> PolarsResult<&BinaryChunked> {
+    polars_ensure!(
+        is_geometry(s.dtype()),
+        InvalidOperation: "expected a geometry column, got {}", s.dtype()
+    );
+    s.to_storage().binary()
+}
+
+/// Parse all WKB values of a geometry series.
+pub fn parse_geometries(s: &Series) -> PolarsResult<Vec<Option<Geometry>>> {
+    wkb(s)?
+        .iter()
+        .map(|opt_b| opt_b.map(Geometry::from_wkb).transpose())
+        .collect()
+}
+
+fn geo_unary_f64(s: &Series, f: impl Fn(&Geometry) -> Option<f64>) -> PolarsResult<Series> {
+    let out: Float64Chunked = parse_geometries(s)?
+        .iter()
+        .map(|g| g.as_ref().and_then(&f))
+        .collect();
+    Ok(out.with_name(s.name().clone()).into_series())
+}
+
+/// Apply `f` to every pair of geometries, broadcasting unit-length inputs.
+fn geo_binary<K>(
+    a: &Series,
+    b: &Series,
+    f: impl Fn(&Geometry, &Geometry) -> Option<K>,
+) -> PolarsResult<Vec<Option<K>>> {
+    let lhs = parse_geometries(a)?;
+    let rhs = parse_geometries(b)?;
+    let apply = |l: &Option<Geometry>, r: &Option<Geometry>| match (l, r) {
+        (Some(l), Some(r)) => f(l, r),
+        _ => None,
+    };
+    Ok(match (lhs.len(), rhs.len()) {
+        (1, _) => rhs.iter().map(|r| apply(&lhs[0], r)).collect(),
+        (_, 1) => lhs.iter().map(|l| apply(l, &rhs[0])).collect(),
+        (l, r) => {
+            polars_ensure!(
+                l == r,
+                ShapeMismatch: "geometry columns have different lengths: {l} and {r}"
+            );
+            lhs.iter().zip(&rhs).map(|(l, r)| apply(l, r)).collect()
+        },
+    })
+}
+
+/// The area of every geometry, zero for points and lines.
+pub fn geo_area(s: &Series) -> PolarsResult<Series> {
+    geo_unary_f64(s, |g| Some(g.area()))
+}
+
+/// The length of every geometry, the perimeter for polygons.
+pub fn geo_length(s: &Series) -> PolarsResult<Series> {
+    geo_unary_f64(s, |g| Some(g.length()))
+}
+
+/// The centroid of every geometry as a point geometry, null for empty geometries.
+pub fn geo_centroid(s: &Series) -> PolarsResult<Series> {
+    let mut buf = Vec::new();
+    let mut out = BinaryChunkedBuilder::new(s.name().clone(), s.len());
+    for g in parse_geometries(s)? {
+        match g.and_then(|g| g.centroid()) {
+            Some(c) => {
+                buf.clear();
+                Geometry::Point(Some(c)).write_wkb(&mut buf);
+                out.append_value(&buf);
+            },
+            None => out.append_null(),
+        }
+    }
+    Ok(out.finish().into_series().into_extension(geometry_ext()))
+}
+
+/// The bounding box of every geometry as a struct of `min_x`, `min_y`, `max_x` and `max_y`.
+pub fn geo_bbox(s: &Series) -> PolarsResult<Series> {
+    let geoms = parse_geometries(s)?;
+    let bboxes: Vec<_> = geoms
+        .iter()
+        .map(|g| g.as_ref().and_then(Geometry::bbox))
+        .collect();
+    let field = |name: &'static str, f: fn(&geo::Rect) -> f64| {
+        let ca: Float64Chunked = bboxes.iter().map(|r| r.as_ref().map(f)).collect();
+        ca.with_name(PlSmallStr::from_static(name)).into_series()
+    };
+    let fields = [
+        field("min_x", |r| r.min_x),
+        field("min_y", |r| r.min_y),
+        field("max_x", |r| r.max_x),
+        field("max_y", |r| r.max_y),
+    ];
+    let validity = Bitmap::from_iter(bboxes.iter().map(Option::is_some));
+    let out = StructChunked::from_series(s.name().clone(), s.len(), fields.iter())?
+        .with_outer_validity(Some(validity));
+    Ok(out.into_series())
+}
+
+/// The minimum distance between the geometries of `a` and `b`.
+pub fn geo_distance(a: &Series, b: &Series) -> PolarsResult<Series> {
+    let out = geo_binary(a, b, geo::distance)?;
+    Ok(Float64Chunked::from_iter_options(a.name().clone(), out.into_iter()).into_series())
+}
+
+/// Whether the geometries of `a` contain the geometries of `b`.
+pub fn geo_contains(a: &Series, b: &Series) -> PolarsResult<Series> {
+    let out = geo_binary(a, b, |a, b| Some(geo::contains(a, b)))?;
+    Ok(BooleanChunked::from_iter_options(a.name().clone(), out.into_iter()).into_series())
+}
+
+/// Whether the geometries of `a` and `b` intersect.
+pub fn geo_intersects(a: &Series, b: &Series) -> PolarsResult<Series> {
+    let out = geo_binary(a, b, |a, b| Some(geo::intersects(a, b)))?;
+    Ok(BooleanChunked::from_iter_options(a.name().clone(), out.into_iter()).into_series())
+}
+
+/// Format every geometry as well-known text.
+pub fn geo_to_wkt(s: &Series) -> PolarsResult<Series> {
+    Ok(wkb_to_wkt(wkb(s)?, true)?.into_series())
+}
+
+/// Parse well-known text into geometries.
+pub fn geo_from_wkt(s: &Series) -> PolarsResult<Series> {
+    let out = wkt_to_wkb(s.str()?, true)?;
+    Ok(out.into_series().into_extension(geometry_ext()))
+}
+
+fn geometry_ext() -> ExtensionTypeInstance {
+    match geometry_dtype() {
+        DataType::Extension(typ, _) => typ,
+        _ => unreachable!(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn geoms(wkt: &[&str]) -> Series {
+        geo_from_wkt(&Series::new("g".into(), wkt)).unwrap()
+    }
+
+    #[test]
+    fn test_geo_functions() -> PolarsResult<()> {
+        let square = "POLYGON ((0 0, 4 0, 4 4, 0 4, 0 0))";
+        let s = geoms(&[square, "LINESTRING (3 0, 6 4)", "POINT (1 1)"]);
+
+        let area = geo_area(&s)?;
+        assert_eq!(Vec::from(area.f64()?), &[Some(16.0), Some(0.0), Some(0.0)]);
+        let length = geo_length(&s)?;
+        assert_eq!(
+            Vec::from(length.f64()?),
+            &[Some(16.0), Some(5.0), Some(0.0)]
+        );
+        let centroid = geo_to_wkt(&geo_centroid(&s)?)?;
+        assert_eq!(centroid.str()?.get(0), Some("POINT (2 2)"));
+
+        let bbox = geo_bbox(&s)?;
+        let max_x = bbox.struct_()?.field_by_name("max_x")?;
+        assert_eq!(Vec::from(max_x.f64()?), &[Some(4.0), Some(6.0), Some(1.0)]);
+
+        let polygon = geoms(&[square]);
+        let contains = geo_contains(&polygon, &s)?;
+        assert_eq!(
+            Vec::from(contains.bool()?),
+            &[Some(true), Some(false), Some(true)]
+        );
+        let intersects = geo_intersects(&s, &polygon)?;
+        assert!(intersects.bool()?.all());
+        let distance = geo_distance(&geoms(&["POINT (7 8)"]), &polygon)?;
+        assert_eq!(distance.f64()?.get(0), Some(5.0));
+        Ok(())
+    }
+}
@@ -0,0 +1,117 @@
+use std::net::IpAddr;
+
+use polars_core::datatypes::extension::{IpVersion, ip_bits_iter, ip_bits_to_storage, ip_version};
+use polars_core::prelude::*;
+
+/// The version and storage of an IP address series.
+fn ip_storage(s: &Series) -> PolarsResult<(IpVersion, &Series)> {
+    let version = ip_version(s.dtype()).ok_or_else(
+        || polars_err!(InvalidOperation: "expected an IP address column, got {}", s.dtype()),
+    )?;
+    Ok((version, s.to_storage()))
+}
+
+/// The mask of the host bits of a network with `prefix_len` leading network bits.
+fn host_mask(version: IpVersion, prefix_len: u8) -> PolarsResult<u128> {
+    let num_bits = version.num_bits();
+    polars_ensure!(
+        prefix_len <= num_bits,
+        InvalidOperation: "prefix length {} is too large for {:?} addresses", prefix_len, version
+    );
+    let all = u128::MAX >> (128 - num_bits as u32);
+    Ok(all.checked_shr(prefix_len as u32).unwrap_or(0))
+}
+
+/// Parse a CIDR block such as `10.0.0.0/8` into its network address and prefix length. An
+/// address without prefix length is a network of a single address.
+fn parse_cidr(cidr: &str, version: IpVersion) -> PolarsResult<(u128, u8)> {
+    let invalid = || polars_err!(InvalidOperation: "invalid CIDR block: '{}'", cidr);
+    let (addr, prefix_len) = match cidr.split_once('/') {
+        Some((addr, len)) => (addr, Some(len.parse::<u8>().map_err(|_| invalid())?)),
+        None => (cidr, None),
+    };
+    let addr: IpAddr = addr.parse().map_err(|_| invalid())?;
+    let bits = version.addr_to_bits(addr).ok_or_else(invalid)?;
+    let prefix_len = match (addr, version) {
+        // IPv4 networks in an IPv6 column are IPv4-mapped.
+        (IpAddr::V4(_), IpVersion::V6) => prefix_len.map(|len| len.saturating_add(96)),
+        _ => prefix_len,
+    }
+    .unwrap_or(version.num_bits());
+    let mask = host_mask(version, prefix_len)?;
+    Ok((bits & !mask, prefix_len))
+}
+
+fn map_ip(s: &Series, f: impl Fn(u128) -> u128) -> PolarsResult<Series> {
+    let (version, storage) = ip_storage(s)?;
+    let out = ip_bits_to_storage(
+        s.name().clone(),
+        ip_bits_iter(storage, version)?.map(|v| v.map(&f)),
+        version,
+    );
+    let DataType::Extension(typ, _) = s.dtype() else {
+        unreachable!()
+    };
+    Ok(out.into_extension(typ.clone()))
+}
+
+/// Check if every address lies in the network of the `cidr` block, e.g. `192.168.0.0/16`.
+pub fn ip_contains(s: &Series, cidr: &str) -> PolarsResult<Series> {
+    let (version, storage) = ip_storage(s)?;
+    let (network, prefix_len) = parse_cidr(cidr, version)?;
+    let mask = host_mask(version, prefix_len)?;
+    let out: BooleanChunked = ip_bits_iter(storage, version)?
+        .map(|v| v.map(|v| v & !mask == network))
+        .collect();
+    Ok(out.with_name(s.name().clone()).into_series())
+}
+
+/// The network address of every address in a network with `prefix_len` leading network bits.
+pub fn ip_network(s: &Series, prefix_len: u8) -> PolarsResult<Series> {
+    let (version, _) = ip_storage(s)?;
+    let mask = host_mask(version, prefix_len)?;
+    map_ip(s, |v| v & !mask)
+}
+
+/// The broadcast address, the last address, of the network of every address with `prefix_len`
+/// leading network bits.
+pub fn ip_broadcast(s: &Series, prefix_len: u8) -> PolarsResult<Series> {
+    let (version, _) = ip_storage(s)?;
+    let mask = host_mask(version, prefix_len)?;
+    map_ip(s, |v| v | mask)
+}
+
+#[cfg(test)]
+mod test {
+    use polars_core::datatypes::extension::ip_dtype;
+
+    use super::*;
+
+    #[test]
+    fn test_ip_network() -> PolarsResult<()> {
+        let s = Series::new("ip".into(), [Some("192.168.1.10"), Some("10.1.2.3"), None])
+            .cast(&ip_dtype(IpVersion::V4))?;
+
+        let contains = ip_contains(&s, "192.168.0.0/16")?;
+        assert_eq!(Vec::from(contains.bool()?), [Some(true), Some(false), None]);
+        assert!(ip_contains(&s, "10.1.2.3")?.bool()?.get(1).unwrap());
+        assert!(ip_contains(&s, "10.0.0.0/33").is_err());
+
+        let network = ip_network(&s, 24)?;
+        assert_eq!(network.dtype(), s.dtype());
+        assert_eq!(network.str_value(0)?, "192.168.1.0");
+        assert_eq!(ip_broadcast(&s, 8)?.str_value(1)?, "10.255.255.255");
+        assert_eq!(ip_network(&s, 0)?.str_value(0)?, "0.0.0.0");
+
+        let s = Series::new("ip".into(), ["2001:db8::1", "::ffff:10.0.0.1"])
+            .cast(&ip_dtype(IpVersion::V6))?;
+        let contains = ip_contains(&s, "10.0.0.0/8")?;
+        assert_eq!(Vec::from(contains.bool()?), [Some(false), Some(true)]);
+        assert_eq!(ip_network(&s, 32)?.str_value(0)?, "2001:db8::");
+        assert_eq!(
+            ip_broadcast(&s, 64)?.str_value(0)?,
+            "2001:db8::ffff:ffff:ffff:ffff"
+        );
+        Ok(())
+    }
+}
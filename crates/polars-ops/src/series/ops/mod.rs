@@ -20,6 +20,8 @@ mod ewm_by;
 mod floor_divide;
 #[cfg(feature = "fused")]
 mod fused;
+#[cfg(feature = "geo")]
+mod geo;
 mod horizontal;
 mod index;
 #[cfg(feature = "index_of")]
@@ -27,6 +29,8 @@ mod index_of;
 mod int_range;
 #[cfg(any(feature = "interpolate_by", feature = "interpolate"))]
 mod interpolation;
+#[cfg(feature = "dtype-ip")]
+mod ip;
 #[cfg(feature = "is_between")]
 mod is_between;
 #[cfg(feature = "is_close")]
@@ -90,6 +94,8 @@ pub use ewm_by::*;
 pub use floor_divide::*;
 #[cfg(feature = "fused")]
 pub use fused::*;
+#[cfg(feature = "geo")]
+pub use geo::*;
 pub use horizontal::*;
 pub use index::*;
 #[cfg(feature = "index_of")]
@@ -101,6 +107,8 @@ pub use interpolation::interpolate::*;
 pub use interpolation::interpolate_by::*;
 #[cfg(any(feature = "interpolate", feature = "interpolate_by"))]
 pub use interpolation::*;
+#[cfg(feature = "dtype-ip")]
+pub use ip::*;
 #[cfg(feature = "is_between")]
 pub use is_between::*;
 #[cfg(feature = "is_close")]
@@ -22,6 +22,17 @@ pub fn negate(s: &Series) -> PolarsResult<Series> {
             let out = ca.physical().wrapping_neg();
             out.into_decimal_unchecked(precision, scale).into_series()
         },
+        #[cfg(feature = "dtype-decimal256")]
+        Decimal256(_, _) => {
+            let ca = s.decimal256().unwrap();
+            let precision = ca.precision();
+            let scale = ca.scale();
+
+            // Negating never widens a decimal of at most 76 digits.
+            let out: Int256Chunked = arity::unary_elementwise_values(ca.physical(), |v| -v);
+            out.into_decimal256_unchecked(precision, scale)
+                .into_series()
+        },
         #[cfg(feature = "dtype-duration")]
         Duration(_) => {
             let physical = s.to_physical_repr();
@@ -29,6 +40,19 @@ pub fn negate(s: &Series) -> PolarsResult<Series> {
             let out = ca.wrapping_neg().into_series();
             out.cast(s.dtype())?
         },
+        #[cfg(feature = "dtype-interval")]
+        Interval => {
+            let ca = s.interval().unwrap();
+            let out = ca.physical().apply_values(|v| {
+                let v = interval_from_i128(v);
+                interval_to_i128(months_days_ns::new(
+                    v.months().wrapping_neg(),
+                    v.days().wrapping_neg(),
+                    v.ns().wrapping_neg(),
+                ))
+            });
+            out.into_interval().into_series()
+        },
         dt => polars_bail!(opq = neg, dt),
     };
     Ok(out)
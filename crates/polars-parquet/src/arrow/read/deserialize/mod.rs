@@ -10,6 +10,7 @@ mod nested;
 mod nested_utils;
 mod null;
 mod primitive;
+mod run_end;
 mod simple;
 mod utils;
 
@@ -23,6 +24,9 @@ use polars_buffer::Buffer;
 use simple::page_iter_to_array;
 
 pub use self::nested_utils::{InitNested, NestedState, init_nested};
+pub use self::run_end::{
+    DictionaryKeys, DictionaryRuns, page_iter_to_dictionary_keys, page_iter_to_runs,
+};
 pub use self::utils::filter::{Filter, PredicateFilter};
 use self::utils::freeze_validity;
 use super::*;
@@ -0,0 +1,285 @@
+//! Decoding of dictionary encoded column chunks into runs instead of dense arrays.
+//!
+//! The keys of dictionary encoded pages are stored with the RLE / bit-packing hybrid encoding.
+//! Columns that are mostly null or constant consist of a few long RLE runs, which are kept here
+//! as `(key, length)` pairs instead of being expanded. Alternatively, the runs are expanded into
+//! the dictionary keys without gathering the dictionary values.
+use arrow::array::{Array, PrimitiveArray};
+use arrow::bitmap::MutableBitmap;
+use arrow::bitmap::utils::SlicesIterator;
+use arrow::datatypes::{ArrowDataType, Field};
+
+use super::utils::{Decoder, decode_page_validity, dict_indices_decoder, page_validity_decoder};
+use super::{BasicDecompressor, binview, primitive};
+use crate::parquet::encoding::Encoding;
+use crate::parquet::encoding::hybrid_rle::HybridRleChunk;
+use crate::parquet::error::{ParquetError, ParquetResult};
+use crate::parquet::page::DataPage;
+use crate::parquet::schema::Repetition;
+use crate::parquet::schema::types::{PhysicalType, PrimitiveType};
+
+/// A column chunk as runs of dictionary values.
+#[derive(Debug)]
+pub struct DictionaryRuns {
+    /// The values of the dictionary page.
+    pub dict: Box<dyn Array>,
+    /// The dictionary key of every run, `None` for a run of nulls.
+    pub keys: Vec<Option<u32>>,
+    /// The exclusive end of every run. The last end is the number of rows.
+    pub run_ends: Vec<usize>,
+}
+
+/// A column chunk as the dictionary and the dictionary key of every row.
+#[derive(Debug)]
+pub struct DictionaryKeys {
+    /// The values of the dictionary page.
+    pub dict: Box<dyn Array>,
+    /// The dictionary key of every row, null for a null row.
+    pub keys: PrimitiveArray<u32>,
+}
+
+#[derive(Default)]
+struct Runs {
+    keys: Vec<Option<u32>>,
+    run_ends: Vec<usize>,
+}
+
+impl Runs {
+    fn push(&mut self, key: Option<u32>, length: usize) {
+        if length == 0 {
+            return;
+        }
+        let end = self.run_ends.last().copied().unwrap_or(0) + length;
+        if self.keys.last() == Some(&key) {
+            *self.run_ends.last_mut().unwrap() = end;
+        } else {
+            self.keys.push(key);
+            self.run_ends.push(end);
+        }
+    }
+}
+
+/// Decode the pages of a flat, dictionary encoded column chunk into runs.
+///
+/// Returns `None` if the column chunk cannot be decoded this way, i.e. if the type is not
+/// supported or some page is not dictionary encoded. The pages are consumed in that case and the
+/// caller has to start over with a fresh iterator.
+pub fn page_iter_to_runs(
+    pages: BasicDecompressor,
+    type_: &PrimitiveType,
+    field: &Field,
+) -> ParquetResult<Option<DictionaryRuns>> {
+    use ArrowDataType as D;
+
+    if type_.field_info.repetition == Repetition::Repeated {
+        return Ok(None);
+    }
+
+    let dtype = &field.dtype;
+    match (&type_.physical_type, dtype) {
+        (PhysicalType::Int32, D::Int32 | D::Date32) => {
+            collect_runs(pages, primitive::IntDecoder::<i32, _, _>::unit(), |dict| {
+                dict.to(dtype.clone()).boxed()
+            })
+        },
+        (PhysicalType::Int64, D::Int64) => {
+            collect_runs(pages, primitive::IntDecoder::<i64, _, _>::unit(), |dict| {
+                dict.boxed()
+            })
+        },
+        (PhysicalType::Float, D::Float32) => collect_runs(
+            pages,
+            primitive::FloatDecoder::<f32, _, _>::unit(),
+            |dict| dict.boxed(),
+        ),
+        (PhysicalType::Double, D::Float64) => collect_runs(
+            pages,
+            primitive::FloatDecoder::<f64, _, _>::unit(),
+            |dict| dict.boxed(),
+        ),
+        (PhysicalType::ByteArray, D::BinaryView) => {
+            collect_runs(pages, binview::BinViewDecoder::new(false), |dict| {
+                dict.boxed()
+            })
+        },
+        (PhysicalType::ByteArray, D::Utf8View) => collect_runs(
+            pages,
+            binview::BinViewDecoder::new(true),
+            // SAFETY: the dictionary was validated as UTF-8 while decoding.
+            |dict| unsafe { dict.to_utf8view_unchecked() }.boxed(),
+        ),
+        _ => Ok(None),
+    }
+}
+
+/// Decode the pages of a flat, dictionary encoded String or Binary column chunk into the
+/// dictionary and its keys.
+///
+/// Returns `None` if the column chunk cannot be decoded this way, i.e. if the type is not
+/// supported or some page is not dictionary encoded. The pages are consumed in that case and the
+/// caller has to start over with a fresh iterator.
+pub fn page_iter_to_dictionary_keys(
+    pages: BasicDecompressor,
+    type_: &PrimitiveType,
+    field: &Field,
+) -> ParquetResult<Option<DictionaryKeys>> {
+    use ArrowDataType as D;
+
+    if type_.field_info.repetition == Repetition::Repeated {
+        return Ok(None);
+    }
+
+    let (decoder, is_utf8) = match (&type_.physical_type, &field.dtype) {
+        (PhysicalType::ByteArray, D::BinaryView) => (binview::BinViewDecoder::new(false), false),
+        (PhysicalType::ByteArray, D::Utf8View) => (binview::BinViewDecoder::new(true), true),
+        _ => return Ok(None),
+    };
+
+    let mut keys = Vec::new();
+    let mut validity = MutableBitmap::new();
+    let mut page_runs = Runs::default();
+    let dict = decode_dict_pages(pages, decoder, |page, dict_len, key_runs| {
+        page_runs.keys.clear();
+        page_runs.run_ends.clear();
+        page_to_runs(page, dict_len, key_runs, &mut page_runs)?;
+
+        let mut start = 0;
+        for (key, &end) in page_runs.keys.iter().zip(&page_runs.run_ends) {
+            keys.extend(std::iter::repeat_n(key.unwrap_or_default(), end - start));
+            validity.extend_constant(end - start, key.is_some());
+            start = end;
+        }
+        Ok(true)
+    })?;
+    let Some(dict) = dict else {
+        return Ok(None);
+    };
+
+    let dict = if is_utf8 {
+        // SAFETY: the dictionary was validated as UTF-8 while decoding.
+        unsafe { dict.to_utf8view_unchecked() }.boxed()
+    } else {
+        dict.boxed()
+    };
+    let keys = PrimitiveArray::new(ArrowDataType::UInt32, keys.into(), validity.into());
+    Ok(Some(DictionaryKeys { dict, keys }))
+}
+
+fn collect_runs<D: Decoder>(
+    pages: BasicDecompressor,
+    decoder: D,
+    into_array: impl FnOnce(D::Dict) -> Box<dyn Array>,
+) -> ParquetResult<Option<DictionaryRuns>> {
+    let mut runs = Runs::default();
+    let dict = decode_dict_pages(pages, decoder, |page, dict_len, key_runs| {
+        page_to_runs(page, dict_len, key_runs, &mut runs)?;
+        Ok(true)
+    })?;
+
+    Ok(dict.map(|dict| DictionaryRuns {
+        dict: into_array(dict),
+        keys: runs.keys,
+        run_ends: runs.run_ends,
+    }))
+}
+
+/// Decode the dictionary page and call `on_page` for every data page.
+///
+/// Returns `None` if there is no dictionary, if some page is not dictionary encoded or if
+/// `on_page` returns `false`.
+fn decode_dict_pages<D: Decoder>(
+    mut pages: BasicDecompressor,
+    mut decoder: D,
+    mut on_page: impl FnMut(&DataPage, usize, &mut Vec<(u32, usize)>) -> ParquetResult<bool>,
+) -> ParquetResult<Option<D::Dict>> {
+    let Some(dict_page) = pages.read_dict_page()? else {
+        return Ok(None);
+    };
+    let dict = decoder.deserialize_dict(dict_page)?;
+
+    let mut key_runs = Vec::new();
+    while let Some(page) = pages.next() {
+        let page = page?.decompress(&mut pages)?;
+        if !matches!(
+            page.encoding(),
+            Encoding::PlainDictionary | Encoding::RleDictionary
+        ) {
+            return Ok(None);
+        }
+        let keep_going = on_page(&page, dict.len(), &mut key_runs)?;
+        pages.reuse_page_buffer(page);
+
+        if !keep_going {
+            return Ok(None);
+        }
+    }
+
+    Ok(Some(dict))
+}
+
+fn page_to_runs(
+    page: &DataPage,
+    dict_len: usize,
+    key_runs: &mut Vec<(u32, usize)>,
+    runs: &mut Runs,
+) -> ParquetResult<()> {
+    let is_optional = page.descriptor.primitive_type.field_info.repetition == Repetition::Optional;
+    let validity = if is_optional && page.null_count().is_none_or(|nc| nc != 0) {
+        decode_page_validity(page_validity_decoder(page)?, None)?
+    } else {
+        None
+    };
+    let null_count = validity.as_ref().map_or(0, |v| v.unset_bits());
+
+    key_runs.clear();
+    let mut keys = dict_indices_decoder(page, null_count)?;
+    let mut buf = Vec::new();
+    while let Some(chunk) = keys.next_chunk()? {
+        match chunk {
+            HybridRleChunk::Rle(key, length) => key_runs.push((key, length)),
+            HybridRleChunk::Bitpacked(decoder) => {
+                buf.clear();
+                decoder.collect_into(&mut buf);
+                key_runs.extend(buf.iter().map(|&key| (key, 1)));
+            },
+        }
+    }
+    if let Some((key, _)) = key_runs.iter().find(|(key, _)| *key as usize >= dict_len) {
+        return Err(ParquetError::oos(format!(
+            "dictionary key {key} is out of bounds for a dictionary of length {dict_len}"
+        )));
+    }
+
+    let Some(validity) = validity else {
+        for &(key, length) in key_runs.iter() {
+            runs.push(Some(key), length);
+        }
+        return Ok(());
+    };
+
+    // Interleave the key runs with null runs for the gaps between the valid slices.
+    let mut key_runs = key_runs.iter().copied();
+    let mut current = None;
+    let mut end_of_last_slice = 0;
+    for (start, length) in SlicesIterator::new(&validity) {
+        runs.push(None, start - end_of_last_slice);
+        end_of_last_slice = start + length;
+
+        let mut remaining = length;
+        while remaining > 0 {
+            let Some((key, available)) = current.take().or_else(|| key_runs.next()) else {
+                return Err(ParquetError::oos(
+                    "page has fewer dictionary keys than values",
+                ));
+            };
+            let n = available.min(remaining);
+            runs.push(Some(key), n);
+            remaining -= n;
+            if available > n {
+                current = Some((key, available - n));
+            }
+        }
+    }
+    runs.push(None, validity.len() - end_of_last_slice);
+    Ok(())
+}
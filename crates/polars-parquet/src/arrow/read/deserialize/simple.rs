@@ -4,11 +4,10 @@ use arrow::datatypes::{
     ArrowDataType, DTYPE_CATEGORICAL_LEGACY, DTYPE_CATEGORICAL_NEW, DTYPE_ENUM_VALUES_LEGACY,
     DTYPE_ENUM_VALUES_NEW, Field, IntegerType, IntervalUnit, TimeUnit,
 };
-use arrow::types::{days_ms, i256};
+use arrow::types::{days_ms, i256, months_days_ns};
 use ethnum::I256;
 use polars_compute::cast::CastOptionsImpl;
 use polars_utils::float16::pf16;
-use polars_utils::pl_str::PlSmallStr;
 
 use super::utils::filter::Filter;
 use super::{
@@ -221,25 +220,38 @@ pub fn page_iter_to_array(
 
             (nested, array, ptm)
         },
-        (PhysicalType::FixedLenByteArray(12), Interval(IntervalUnit::MonthDayMillis)) => {
+        (PhysicalType::FixedLenByteArray(12), Interval(IntervalUnit::MonthDayNano)) => {
             // @TODO: Make a separate decoder for this
 
-            const N_BYTES: usize = 12;
+            let n = 12;
             let (nested, array, ptm) = PageDecoder::new(
                 &field.name,
                 pages,
-                ArrowDataType::FixedSizeBinary(N_BYTES),
-                fixed_size_binary::BinaryDecoder { size: N_BYTES },
+                ArrowDataType::FixedSizeBinary(n),
+                fixed_size_binary::BinaryDecoder { size: n },
                 init_nested,
             )?
             .collect(filter)?;
 
-            let out = array
+            let array = array
                 .into_iter()
-                .map(|arr| convert_interval_bytes_to_month_day_nano_struct(arr).boxed())
-                .collect();
+                .map(|array| {
+                    let values = array
+                        .values()
+                        .chunks_exact(n)
+                        .map(super::super::convert_months_days_ns)
+                        .collect::<Vec<_>>();
+                    let validity = array.validity().cloned();
+                    Ok(PrimitiveArray::<months_days_ns>::try_new(
+                        dtype.clone(),
+                        values.into(),
+                        validity,
+                    )?
+                    .to_boxed())
+                })
+                .collect::<ParquetResult<Vec<Box<dyn Array>>>>()?;
 
-            (nested, out, ptm)
+            (nested, array, ptm)
         },
         (PhysicalType::FixedLenByteArray(16), UInt128) => {
             let n = 16;
@@ -788,66 +800,3 @@ fn timestamp(
         .collect_boxed(filter),
     }
 }
-
-/// Converts directly from Parquet INTERVAL to Struct.
-fn convert_interval_bytes_to_month_day_nano_struct(
-    month_day_millis_bytes: FixedSizeBinaryArray,
-) -> StructArray {
-    const ROW_WIDTH: usize = 12;
-
-    let bytes: &[u8] = month_day_millis_bytes.values();
-    let output_length = bytes.len() / ROW_WIDTH;
-
-    assert_eq!(bytes.len(), output_length * ROW_WIDTH);
-
-    let (months_out, days_out, nanoseconds_out): (Vec<i32>, Vec<i32>, Vec<i64>) = (0
-        ..output_length)
-        .map(|i| {
-            let bytes: [u8; ROW_WIDTH] =
-                unsafe { bytes.get_unchecked(i * ROW_WIDTH..(i + 1) * ROW_WIDTH) }
-                    .try_into()
-                    .unwrap();
-
-            let months: i32 = i32::from_le_bytes(bytes[..4].try_into().unwrap());
-            let days: i32 = i32::from_le_bytes(bytes[4..8].try_into().unwrap());
-            let nanoseconds: i64 = (i32::from_le_bytes(bytes[8..12].try_into().unwrap()) as i64)
-                .checked_mul(1_000_000i64) // Convert milliseconds to nanoseconds.
-                .unwrap();
-
-            (months, days, nanoseconds)
-        })
-        .collect();
-
-    let struct_fields = vec![
-        Field::new(
-            PlSmallStr::from_static("months"),
-            ArrowDataType::Int32,
-            true,
-        ),
-        Field::new(PlSmallStr::from_static("days"), ArrowDataType::Int32, true),
-        Field::new(
-            PlSmallStr::from_static("nanoseconds"),
-            ArrowDataType::Duration(TimeUnit::Nanosecond),
-            true,
-        ),
-    ];
-
-    let struct_value_arrays = vec![
-        PrimitiveArray::<i32>::from_vec(months_out).boxed(),
-        PrimitiveArray::<i32>::from_vec(days_out).boxed(),
-        PrimitiveArray::<i64>::try_new(
-            ArrowDataType::Duration(TimeUnit::Nanosecond),
-            nanoseconds_out.into(),
-            None,
-        )
-        .unwrap()
-        .boxed(),
-    ];
-
-    StructArray::new(
-        ArrowDataType::Struct(struct_fields),
-        output_length,
-        struct_value_arrays,
-        month_day_millis_bytes.validity().cloned(),
-    )
-}
@@ -10,8 +10,9 @@ use std::io::{Read, Seek};
 
 use arrow::types::{NativeType, i256};
 pub use deserialize::{
-    Filter, InitNested, NestedState, PredicateFilter, column_iter_to_arrays, create_list,
-    create_map, get_page_iterator, init_nested, n_columns,
+    DictionaryKeys, DictionaryRuns, Filter, InitNested, NestedState, PredicateFilter,
+    column_iter_to_arrays, create_list, create_map, get_page_iterator, init_nested, n_columns,
+    page_iter_to_dictionary_keys, page_iter_to_runs,
 };
 #[cfg(feature = "async")]
 use futures::{AsyncRead, AsyncSeek};
@@ -77,6 +78,15 @@ fn convert_days_ms(value: &[u8]) -> arrow::types::days_ms {
     )
 }
 
+/// Parquet INTERVAL stores months, days and milliseconds.
+fn convert_months_days_ns(value: &[u8]) -> arrow::types::months_days_ns {
+    arrow::types::months_days_ns::new(
+        i32::from_le_bytes(value[..4].try_into().unwrap()),
+        i32::from_le_bytes(value[4..8].try_into().unwrap()),
+        i32::from_le_bytes(value[8..12].try_into().unwrap()) as i64 * 1_000_000,
+    )
+}
+
 fn convert_i128(value: &[u8], n: usize) -> i128 {
     // Copy the fixed-size byte value to the start of a 16 byte stack
     // allocated buffer, then use an arithmetic right shift to fill in
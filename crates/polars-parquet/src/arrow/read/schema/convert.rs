@@ -1,7 +1,10 @@
 //! This module has entry points, [`parquet_to_arrow_schema`] and the more configurable [`parquet_to_arrow_schema_with_options`].
 use std::sync::Arc;
 
-use arrow::datatypes::{ArrowDataType, ArrowSchema, Field, IntervalUnit, Metadata, TimeUnit};
+use arrow::datatypes::{
+    ARROW_UUID_EXTENSION_NAME, ArrowDataType, ArrowSchema, ExtensionType, Field, IntervalUnit,
+    Metadata, TimeUnit,
+};
 use polars_utils::format_pl_smallstr;
 use polars_utils::pl_str::PlSmallStr;
 
@@ -179,6 +182,13 @@ fn from_fixed_len_byte_array(
     converted_type: Option<PrimitiveConvertedType>,
 ) -> ArrowDataType {
     match (logical_type, converted_type) {
+        // Decimals wider than 16 bytes can't be represented by a 128-bit integer.
+        (Some(PrimitiveLogicalType::Decimal(precision, scale)), _) if length > 16 => {
+            ArrowDataType::Decimal256(precision, scale)
+        },
+        (None, Some(PrimitiveConvertedType::Decimal(precision, scale))) if length > 16 => {
+            ArrowDataType::Decimal256(precision, scale)
+        },
         (Some(PrimitiveLogicalType::Decimal(precision, scale)), _) => {
             ArrowDataType::Decimal(precision, scale)
         },
@@ -186,7 +196,14 @@ fn from_fixed_len_byte_array(
             ArrowDataType::Decimal(precision, scale)
         },
         (None, Some(PrimitiveConvertedType::Interval)) => {
-            ArrowDataType::Interval(IntervalUnit::MonthDayMillis)
+            ArrowDataType::Interval(IntervalUnit::MonthDayNano)
+        },
+        (Some(PrimitiveLogicalType::Uuid), _) if length == 16 => {
+            ArrowDataType::Extension(Box::new(ExtensionType {
+                name: PlSmallStr::from_static(ARROW_UUID_EXTENSION_NAME),
+                inner: ArrowDataType::FixedSizeBinary(16),
+                metadata: None,
+            }))
         },
         _ => ArrowDataType::FixedSizeBinary(length),
     }
@@ -250,10 +267,12 @@ fn non_repeated_group(
     match (logical_type, converted_type) {
         (Some(GroupLogicalType::List), _) => to_list(fields, parent_name, options),
         (None, Some(GroupConvertedType::List)) => to_list(fields, parent_name, options),
-        (Some(GroupLogicalType::Map), _) => to_list(fields, parent_name, options),
-        (None, Some(GroupConvertedType::Map) | Some(GroupConvertedType::MapKeyValue)) => {
-            to_map(fields, options)
+        (Some(GroupLogicalType::Map), _)
+        | (None, Some(GroupConvertedType::Map) | Some(GroupConvertedType::MapKeyValue)) => {
+            to_map(fields, parent_name, options)
         },
+        // Variant groups are not recognized by their layout, as that can't be told apart from a
+        // struct. The Arrow schema in the metadata marks them with the variant extension type.
         _ => to_struct(fields, options),
     }
 }
@@ -272,11 +291,31 @@ fn to_struct(fields: &[ParquetType], options: &SchemaInferenceOptions) -> Option
     }
 }
 
-/// Converts a parquet group type to an arrow [`ArrowDataType::Struct`].
-/// Returns [`None`] if all its fields are empty
-fn to_map(fields: &[ParquetType], options: &SchemaInferenceOptions) -> Option<ArrowDataType> {
-    let inner = to_field(&fields[0], options)?;
-    Some(ArrowDataType::Map(Box::new(inner), false))
+/// Converts a parquet map to an arrow [`ArrowDataType::Map`].
+///
+/// A map holds a single repeated group with the key and value fields. Maps that don't follow this
+/// layout (e.g. because a field was projected away) are read as a list.
+fn to_map(
+    fields: &[ParquetType],
+    parent_name: &str,
+    options: &SchemaInferenceOptions,
+) -> Option<ArrowDataType> {
+    if let ParquetType::GroupType {
+        fields: kv_fields, ..
+    } = &fields[0]
+    {
+        if let Some(ArrowDataType::Struct(kv_fields)) = to_struct(kv_fields, options) {
+            if kv_fields.len() == 2 {
+                let entries = Field::new(
+                    PlSmallStr::from_static("entries"),
+                    ArrowDataType::Struct(kv_fields),
+                    false,
+                );
+                return Some(ArrowDataType::Map(Box::new(entries), false));
+            }
+        }
+    }
+    to_list(fields, parent_name, options)
 }
 
 /// Entry point for converting parquet group type.
@@ -806,6 +845,96 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_parquet_map() -> PolarsResult<()> {
+        let message_type = "
+            message test_schema {
+              OPTIONAL group attributes (MAP) {
+                REPEATED group key_value {
+                  REQUIRED BYTE_ARRAY key (UTF8);
+                  OPTIONAL INT64 value;
+                }
+              }
+            }
+        ";
+
+        let entries = Field::new(
+            "entries".into(),
+            ArrowDataType::Struct(vec![
+                Field::new("key".into(), ArrowDataType::Utf8View, false),
+                Field::new("value".into(), ArrowDataType::Int64, true),
+            ]),
+            false,
+        );
+        let arrow_fields = vec![Field::new(
+            "attributes".into(),
+            ArrowDataType::Map(Box::new(entries), false),
+            true,
+        )];
+
+        let parquet_schema = SchemaDescriptor::try_from_message(message_type)?;
+        let fields = parquet_to_arrow_schema(parquet_schema.fields());
+        let fields = fields.iter_values().cloned().collect::<Vec<_>>();
+
+        assert_eq!(arrow_fields, fields);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parquet_uuid() -> PolarsResult<()> {
+        let message_type = "
+            message test_schema {
+              OPTIONAL FIXED_LEN_BYTE_ARRAY (16) id (UUID);
+            }
+        ";
+
+        let arrow_fields = vec![Field::new(
+            "id".into(),
+            ArrowDataType::Extension(Box::new(ExtensionType {
+                name: ARROW_UUID_EXTENSION_NAME.into(),
+                inner: ArrowDataType::FixedSizeBinary(16),
+                metadata: None,
+            })),
+            true,
+        )];
+
+        let parquet_schema = SchemaDescriptor::try_from_message(message_type)?;
+        let fields = parquet_to_arrow_schema(parquet_schema.fields());
+        let fields = fields.iter_values().cloned().collect::<Vec<_>>();
+
+        assert_eq!(arrow_fields, fields);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parquet_variant_layout() -> PolarsResult<()> {
+        // Without the Arrow schema, a group with the variant layout is read as a struct.
+        let message_type = "
+            message test_schema {
+              OPTIONAL group v {
+                REQUIRED BYTE_ARRAY metadata;
+                REQUIRED BYTE_ARRAY value;
+              }
+            }
+        ";
+
+        let arrow_fields = vec![Field::new(
+            "v".into(),
+            ArrowDataType::Struct(vec![
+                Field::new("metadata".into(), ArrowDataType::BinaryView, false),
+                Field::new("value".into(), ArrowDataType::BinaryView, false),
+            ]),
+            true,
+        )];
+
+        let parquet_schema = SchemaDescriptor::try_from_message(message_type)?;
+        let fields = parquet_to_arrow_schema(parquet_schema.fields());
+        let fields = fields.iter_values().cloned().collect::<Vec<_>>();
+
+        assert_eq!(arrow_fields, fields);
+        Ok(())
+    }
+
     #[test]
     fn test_parquet_list_nullable() -> PolarsResult<()> {
         let mut arrow_fields = Vec::new();
@@ -199,6 +199,8 @@ impl ColumnStatistics {
                 @prim Vec<u8>,
                 |x| convert_days_ms(&x)
             ),
+            // The sort order of INTERVAL is undefined.
+            (D::Interval(IntervalUnit::MonthDayNano), _) => (None, None),
 
             (D::UInt8, _) => rmap!(expect_int32, @prim i32 as u8),
             (D::UInt16, _) => rmap!(expect_int32, @prim i32 as u16),
@@ -307,6 +309,8 @@ pub fn deserialize_all(
         D::Dictionary(..) => Ok(None),
         D::FixedSizeList(..) => Ok(None),
         D::Struct(..) => Ok(None),
+        // The sort order of INTERVAL is undefined.
+        D::Interval(IntervalUnit::MonthDayNano) => Ok(None),
 
         _ => {
             let mut null_count = MutablePrimitiveArray::<IdxSize>::with_capacity(row_groups.len());
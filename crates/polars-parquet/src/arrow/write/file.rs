@@ -6,7 +6,7 @@ use polars_error::{PolarsError, PolarsResult};
 use super::schema::schema_to_metadata_key;
 use super::{ColumnWriteOptions, ThriftFileMetadata, WriteOptions, to_parquet_schema};
 use crate::parquet::metadata::{KeyValue, SchemaDescriptor};
-use crate::parquet::write::{RowGroupIterColumns, WriteOptions as FileWriteOptions};
+use crate::parquet::write::{RowGroupIterColumns, SortingColumn, WriteOptions as FileWriteOptions};
 
 /// An interface to write a parquet to a [`Write`]
 pub struct FileWriter<W: Write> {
@@ -87,6 +87,18 @@ impl<W: Write> FileWriter<W> {
         Ok(self.writer.write(num_rows, row_group)?)
     }
 
+    /// Writes a row group to the file, recording `sorting_columns` in its metadata.
+    pub fn write_with_sorting_columns(
+        &mut self,
+        num_rows: u64,
+        row_group: RowGroupIterColumns<'_, PolarsError>,
+        sorting_columns: Option<Vec<SortingColumn>>,
+    ) -> PolarsResult<()> {
+        Ok(self
+            .writer
+            .write_with_sorting_columns(num_rows, row_group, sorting_columns)?)
+    }
+
     /// Writes the footer of the parquet file. Returns the total size of the file.
     /// If `key_value_metadata` is provided, the value is taken as-is. If it is not provided,
     /// the Arrow schema is added to the metadata.
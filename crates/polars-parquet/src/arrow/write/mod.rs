@@ -28,7 +28,7 @@ mod utils;
 use arrow::array::*;
 use arrow::bitmap::Bitmap;
 use arrow::datatypes::*;
-use arrow::types::{NativeType, days_ms, i256};
+use arrow::types::{NativeType, days_ms, i256, months_days_ns};
 pub use nested::{num_values, write_rep_and_def};
 pub use pages::{to_leaves, to_nested, to_parquet_leaves};
 use polars_utils::float16::pf16;
@@ -47,8 +47,8 @@ pub use crate::parquet::schema::types::{
     FieldInfo, ParquetType, PhysicalType as ParquetPhysicalType,
 };
 pub use crate::parquet::write::{
-    Compressor, DynIter, DynStreamingIterator, RowGroupIterColumns, Version, compress,
-    write_metadata_sidecar,
+    Compressor, DynIter, DynStreamingIterator, RowGroupIterColumns, SortingColumn, Version,
+    compress, write_metadata_sidecar,
 };
 pub use crate::parquet::{FallibleStreamingIterator, fallible_streaming_iterator};
 use crate::write::fixed_size_binary::build_statistics_float16;
@@ -164,7 +164,7 @@ use arrow::compute::aggregate::estimated_bytes_size;
 use arrow::match_integer_type;
 pub use file::FileWriter;
 pub use pages::{Nested, array_to_columns, arrays_to_columns};
-use polars_error::{PolarsResult, polars_bail};
+use polars_error::{PolarsResult, polars_bail, polars_ensure};
 pub use row_group::{RowGroupIterator, row_group_iter};
 pub use schema::{schema_to_metadata_key, to_parquet_type};
 
@@ -349,6 +349,50 @@ pub fn get_max_length(nested: &[Nested]) -> usize {
     length
 }
 
+/// Converts the binary values of a UUID extension array to 16-byte fixed-length values.
+fn uuid_to_fixed_size_binary(array: &dyn Array) -> PolarsResult<Box<dyn Array>> {
+    let ArrowDataType::Extension(ext) = array.dtype() else {
+        unreachable!()
+    };
+    let iter: Box<dyn Iterator<Item = Option<&[u8]>>> = match &ext.inner {
+        ArrowDataType::BinaryView => Box::new(
+            array
+                .as_any()
+                .downcast_ref::<BinaryViewArray>()
+                .unwrap()
+                .iter(),
+        ),
+        ArrowDataType::LargeBinary => Box::new(
+            array
+                .as_any()
+                .downcast_ref::<BinaryArray<i64>>()
+                .unwrap()
+                .iter(),
+        ),
+        dt => polars_bail!(nyi = "Writing UUIDs stored as {dt:?} to parquet"),
+    };
+
+    let mut values = Vec::with_capacity(array.len() * 16);
+    for opt_v in iter {
+        match opt_v {
+            Some(v) => {
+                polars_ensure!(
+                    v.len() == 16,
+                    ComputeError: "UUID value has {} bytes, expected 16", v.len()
+                );
+                values.extend_from_slice(v);
+            },
+            None => values.extend_from_slice(&[0; 16]),
+        }
+    }
+
+    let dtype = ArrowDataType::Extension(Box::new(ExtensionType {
+        inner: ArrowDataType::FixedSizeBinary(16),
+        ..(**ext).clone()
+    }));
+    Ok(FixedSizeBinaryArray::new(dtype, values.into(), array.validity().cloned()).boxed())
+}
+
 /// Returns an iterator of [`Page`].
 pub fn array_to_pages(
     primitive_array: &dyn Array,
@@ -357,6 +401,18 @@ pub fn array_to_pages(
     options: WriteOptions,
     field_options: &FieldWriteOptions,
 ) -> PolarsResult<DynIter<'static, PolarsResult<Page>>> {
+    if let ArrowDataType::Extension(ext) = primitive_array.dtype()
+        && ext.name == ARROW_UUID_EXTENSION_NAME
+        && ext.inner != ArrowDataType::FixedSizeBinary(16)
+    {
+        // Random UUIDs don't benefit from dictionary encoding.
+        let array = uuid_to_fixed_size_binary(primitive_array)?;
+        let field_options = FieldWriteOptions {
+            encoding: Encoding::Plain,
+        };
+        return array_to_pages(array.as_ref(), type_, nested, options, &field_options);
+    }
+
     let mut encoding = field_options.encoding;
     if let ArrowDataType::Dictionary(key_type, _, _) = primitive_array.dtype().to_storage() {
         return match_integer_type!(key_type, |$T| {
@@ -664,6 +720,33 @@ pub fn array_to_page_simple(
             };
             fixed_size_binary::array_to_page(&array, options, type_, statistics)
         },
+        ArrowDataType::Interval(IntervalUnit::MonthDayNano) => {
+            let array = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<months_days_ns>>()
+                .unwrap();
+            let mut values = Vec::<u8>::with_capacity(12 * array.len());
+            for (i, x) in array.values().iter().enumerate() {
+                // Parquet INTERVAL only has millisecond precision.
+                let millis = i32::try_from(x.ns() / 1_000_000).ok();
+                let is_exact = millis.is_some() && x.ns() % 1_000_000 == 0;
+                polars_ensure!(
+                    is_exact || !array.is_valid(i),
+                    InvalidOperation: "cannot write interval with {} nanoseconds to parquet \
+                    INTERVAL, which stores whole milliseconds", x.ns()
+                );
+                values.extend_from_slice(&x.months().to_le_bytes());
+                values.extend_from_slice(&x.days().to_le_bytes());
+                values.extend_from_slice(&millis.unwrap_or_default().to_le_bytes());
+            }
+            let array = FixedSizeBinaryArray::new(
+                ArrowDataType::FixedSizeBinary(12),
+                values.into(),
+                array.validity().cloned(),
+            );
+            // The sort order of INTERVAL is undefined, so no statistics are written.
+            fixed_size_binary::array_to_page(&array, options, type_, None)
+        },
         ArrowDataType::FixedSizeBinary(_) => {
             let array = array.as_any().downcast_ref().unwrap();
             let statistics = if options.has_statistics() {
@@ -252,9 +252,9 @@ fn to_nested_recursive(
         },
         Map => {
             let array = array.as_any().downcast_ref::<MapArray>().unwrap();
-            let type_ = if let ParquetType::GroupType { fields, .. } = type_ {
+            let kv_fields = if let ParquetType::GroupType { fields, .. } = type_ {
                 if let ParquetType::GroupType { fields, .. } = &fields[0] {
-                    &fields[0]
+                    fields
                 } else {
                     polars_bail!(InvalidOperation:
                         "Parquet type must be a group for a map array",
@@ -271,7 +271,28 @@ fn to_nested_recursive(
                 array.validity().cloned(),
                 is_optional,
             )));
-            to_nested_recursive(array.field().as_ref(), type_, nested, parents)?;
+            match kv_fields.as_slice() {
+                // The entries are wrapped in a separate group inside the repeated group.
+                [kv @ ParquetType::GroupType { .. }] => {
+                    to_nested_recursive(array.field().as_ref(), kv, nested, parents)?
+                },
+                // The repeated `key_value` group holds the key and value fields directly.
+                _ => {
+                    let entries = array
+                        .field()
+                        .as_any()
+                        .downcast_ref::<StructArray>()
+                        .unwrap();
+                    parents.push(Nested::structure(
+                        entries.validity().cloned(),
+                        false,
+                        entries.len(),
+                    ));
+                    for (type_, array) in kv_fields.iter().zip(entries.values()) {
+                        to_nested_recursive(array.as_ref(), type_, nested, parents.clone())?;
+                    }
+                },
+            }
         },
         _ => {
             parents.push(Nested::Primitive(PrimitiveNested {
@@ -985,4 +1006,87 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn test_map_key_value() {
+        let kv_type = ArrowDataType::Struct(vec![
+            Field::new("key".into(), ArrowDataType::Utf8, false),
+            Field::new("value".into(), ArrowDataType::Int32, true),
+        ]);
+        let kv_field = Field::new("entries".into(), kv_type.clone(), false);
+        let map_type = ArrowDataType::Map(Box::new(kv_field), false);
+
+        let key_array = Utf8Array::<i32>::from_slice(["k1", "k2", "k3"]).boxed();
+        let val_array = Int32Array::from([Some(1), None, Some(3)]).boxed();
+        let kv_array = StructArray::try_new(kv_type, 3, vec![key_array, val_array], None)
+            .unwrap()
+            .boxed();
+        let offsets = OffsetsBuffer::try_from(vec![0, 2, 2, 3]).unwrap();
+
+        let array = MapArray::try_new(map_type, offsets, kv_array, None).unwrap();
+
+        let type_ = ParquetType::GroupType {
+            field_info: FieldInfo {
+                name: "m".into(),
+                repetition: Repetition::Optional,
+                id: None,
+            },
+            logical_type: Some(GroupLogicalType::Map),
+            converted_type: None,
+            fields: vec![ParquetType::GroupType {
+                field_info: FieldInfo {
+                    name: "key_value".into(),
+                    repetition: Repetition::Repeated,
+                    id: None,
+                },
+                logical_type: None,
+                converted_type: None,
+                fields: vec![
+                    ParquetType::PrimitiveType(ParquetPrimitiveType {
+                        field_info: FieldInfo {
+                            name: "key".into(),
+                            repetition: Repetition::Required,
+                            id: None,
+                        },
+                        logical_type: Some(PrimitiveLogicalType::String),
+                        converted_type: Some(PrimitiveConvertedType::Utf8),
+                        physical_type: ParquetPhysicalType::ByteArray,
+                    }),
+                    ParquetType::PrimitiveType(ParquetPrimitiveType {
+                        field_info: FieldInfo {
+                            name: "value".into(),
+                            repetition: Repetition::Optional,
+                            id: None,
+                        },
+                        logical_type: None,
+                        converted_type: None,
+                        physical_type: ParquetPhysicalType::Int32,
+                    }),
+                ],
+            }],
+        };
+
+        let a = to_nested(&array, &type_).unwrap();
+
+        let list = Nested::List(ListNested::<i32> {
+            is_optional: true,
+            offsets: vec![0, 2, 2, 3].try_into().unwrap(),
+            validity: None,
+        });
+        assert_eq!(
+            a,
+            vec![
+                vec![
+                    list.clone(),
+                    Nested::structure(None, false, 3),
+                    Nested::primitive(None, false, 3),
+                ],
+                vec![
+                    list,
+                    Nested::structure(None, false, 3),
+                    Nested::primitive(Some(Bitmap::from([true, false, true])), true, 3),
+                ],
+            ]
+        );
+    }
 }
@@ -2,12 +2,13 @@ use std::borrow::Cow;
 use std::sync::{Arc, LazyLock};
 
 use arrow::datatypes::{
-    ArrowDataType, ArrowSchema, ExtensionType, Field, PARQUET_EMPTY_STRUCT, TimeUnit,
+    ARROW_UUID_EXTENSION_NAME, ArrowDataType, ArrowSchema, ExtensionType, Field,
+    PARQUET_EMPTY_STRUCT, TimeUnit,
 };
 use arrow::io::ipc::write::{default_ipc_fields, schema_to_bytes};
 use base64::Engine as _;
 use base64::engine::general_purpose;
-use polars_error::{PolarsResult, polars_bail};
+use polars_error::{PolarsResult, polars_bail, polars_ensure};
 use polars_utils::pl_str::PlSmallStr;
 
 use super::super::ARROW_SCHEMA_META_KEY;
@@ -34,6 +35,7 @@ fn convert_dtype(dtype: ArrowDataType) -> ArrowDataType {
     use ArrowDataType as D;
     match dtype {
         D::LargeList(field) => D::LargeList(Box::new(convert_field(*field))),
+        D::Map(field, ordered) => D::Map(Box::new(convert_field(*field)), ordered),
         D::Struct(mut fields) => {
             for field in &mut fields {
                 *field = convert_field(std::mem::take(field))
@@ -46,6 +48,13 @@ fn convert_dtype(dtype: ArrowDataType) -> ArrowDataType {
             let dtype = convert_dtype(*dtype);
             D::Dictionary(it, Box::new(dtype), sorted)
         },
+        // UUIDs are written as 16-byte fixed-length values, see `to_parquet_type`.
+        D::Extension(ext) if ext.name == ARROW_UUID_EXTENSION_NAME => {
+            D::Extension(Box::new(ExtensionType {
+                inner: D::FixedSizeBinary(16),
+                ..*ext
+            }))
+        },
         D::Extension(ext) => {
             let dtype = convert_dtype(ext.inner);
             D::Extension(Box::new(ExtensionType {
@@ -204,6 +213,19 @@ pub fn to_parquet_type(field: &Field, options: &ColumnWriteOptions) -> PolarsRes
 
     let field_id = options.field_id;
 
+    if let ArrowDataType::Extension(ext) = field.dtype()
+        && ext.name == ARROW_UUID_EXTENSION_NAME
+    {
+        return Ok(ParquetType::try_from_primitive(
+            name,
+            PhysicalType::FixedLenByteArray(16),
+            repetition,
+            None,
+            Some(PrimitiveLogicalType::Uuid),
+            field_id,
+        )?);
+    }
+
     // create type from field
     let (physical_type, primitive_converted_type, primitive_logical_type) = match field
         .dtype()
@@ -441,6 +463,46 @@ pub fn to_parquet_type(field: &Field, options: &ColumnWriteOptions) -> PolarsRes
                 field_id,
             ));
         },
+        ArrowDataType::Map(f, _) => {
+            let ChildWriteOptions::ListLike(map_write_options) = &options.children else {
+                unreachable!();
+            };
+            let (ArrowDataType::Struct(kv_fields), ChildWriteOptions::Struct(kv_write_options)) =
+                (f.dtype().to_storage(), &map_write_options.child.children)
+            else {
+                polars_bail!(InvalidOperation: "map entries must be a struct, got {:?}", f.dtype());
+            };
+            polars_ensure!(
+                kv_fields.len() == 2,
+                InvalidOperation: "map entries must have a key and a value field, got {} fields",
+                kv_fields.len()
+            );
+
+            let mut key = kv_fields[0].clone();
+            key.name = PlSmallStr::from_static("key");
+            key.is_nullable = false;
+            let mut value = kv_fields[1].clone();
+            value.name = PlSmallStr::from_static("value");
+
+            return Ok(ParquetType::from_group(
+                name,
+                repetition,
+                Some(GroupConvertedType::Map),
+                Some(GroupLogicalType::Map),
+                vec![ParquetType::from_group(
+                    PlSmallStr::from_static("key_value"),
+                    Repetition::Repeated,
+                    None,
+                    None,
+                    vec![
+                        to_parquet_type(&key, &kv_write_options.children[0])?,
+                        to_parquet_type(&value, &kv_write_options.children[1])?,
+                    ],
+                    None,
+                )],
+                field_id,
+            ));
+        },
         other => polars_bail!(nyi = "Writing the data type {other:?} is not yet implemented"),
     };
 
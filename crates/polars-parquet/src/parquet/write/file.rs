@@ -1,7 +1,7 @@
 use std::io::Write;
 
-use polars_parquet_format::RowGroup;
 use polars_parquet_format::thrift::protocol::TCompactOutputProtocol;
+use polars_parquet_format::{RowGroup, SortingColumn};
 
 use super::indexes::{write_column_index, write_offset_index};
 use super::page::PageWriteSpec;
@@ -156,6 +156,22 @@ impl<W: Write> FileWriter<W> {
         num_rows: u64,
         row_group: RowGroupIterColumns<'_, E>,
     ) -> ParquetResult<()>
+    where
+        ParquetError: From<E>,
+        E: std::error::Error,
+    {
+        self.write_with_sorting_columns(num_rows, row_group, None)
+    }
+
+    /// Writes a row group to the file, recording `sorting_columns` in its metadata.
+    ///
+    /// The caller must ensure the row group is sorted according to `sorting_columns`.
+    pub fn write_with_sorting_columns<E>(
+        &mut self,
+        num_rows: u64,
+        row_group: RowGroupIterColumns<'_, E>,
+        sorting_columns: Option<Vec<SortingColumn>>,
+    ) -> ParquetResult<()>
     where
         ParquetError: From<E>,
         E: std::error::Error,
@@ -164,7 +180,7 @@ impl<W: Write> FileWriter<W> {
             self.start()?;
         }
         let ordinal = self.row_groups.len();
-        let (group, specs, size) = write_row_group(
+        let (mut group, specs, size) = write_row_group(
             &mut self.writer,
             num_rows,
             self.offset,
@@ -172,6 +188,7 @@ impl<W: Write> FileWriter<W> {
             row_group,
             ordinal,
         )?;
+        group.sorting_columns = sorting_columns.filter(|x| !x.is_empty());
         self.offset += size;
         self.row_groups.push(group);
         self.page_specs.push(specs);
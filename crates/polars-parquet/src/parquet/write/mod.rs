@@ -16,6 +16,7 @@ mod dyn_iter;
 pub use compression::{Compressor, compress};
 pub use dyn_iter::{DynIter, DynStreamingIterator};
 pub use file::{FileWriter, write_metadata_sidecar};
+pub use polars_parquet_format::SortingColumn;
 pub use row_group::ColumnOffsetsMetadata;
 
 use crate::parquet::page::CompressedPage;
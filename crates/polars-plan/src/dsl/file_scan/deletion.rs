@@ -2,9 +2,6 @@ use std::sync::Arc;
 
 use polars_core::prelude::PlIndexMap;
 
-// Note, there are a lot of single variant enums here, but the intention is that we'll support
-// Delta deletion vectors as well at some point in the future.
-
 #[derive(Debug, Clone, Eq, PartialEq, strum_macros::IntoStaticStr)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
@@ -20,6 +17,36 @@ pub enum DeletionFilesList {
     //
     /// Iceberg positional deletes
     IcebergPositionDelete(Arc<PlIndexMap<usize, Arc<[String]>>>),
+    /// Delta deletion vectors. A data file has at most one deletion vector.
+    DeltaDeletionVector(Arc<PlIndexMap<usize, DeltaDeletionVector>>),
+}
+
+/// A Delta deletion vector of a data file.
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct DeltaDeletionVector {
+    pub location: DeltaDeletionVectorLocation,
+    /// Number of physical rows in the data file, if recorded in the transaction log. The filter
+    /// mask built from the deletion vector covers all of these rows.
+    pub num_rows: Option<u64>,
+}
+
+/// Location of a serialized Delta deletion vector (a roaring bitmap array of deleted row
+/// positions).
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum DeltaDeletionVectorLocation {
+    /// Bitmap bytes that were stored inline in the transaction log.
+    Inline(Arc<[u8]>),
+    /// Bitmap stored in a deletion vector file. `offset` points to the 4-byte size prefix of the
+    /// bitmap.
+    File {
+        path: String,
+        offset: u64,
+        size_in_bytes: u64,
+    },
 }
 
 impl DeletionFilesList {
@@ -31,6 +58,9 @@ impl DeletionFilesList {
             Some(IcebergPositionDelete(paths)) => {
                 (!paths.is_empty()).then_some(IcebergPositionDelete(paths))
             },
+            Some(DeltaDeletionVector(vectors)) => {
+                (!vectors.is_empty()).then_some(DeltaDeletionVector(vectors))
+            },
             None => None,
         }
     }
@@ -40,6 +70,7 @@ impl DeletionFilesList {
 
         match self {
             IcebergPositionDelete(paths) => paths.len(),
+            DeltaDeletionVector(vectors) => vectors.len(),
         }
     }
 }
@@ -56,6 +87,11 @@ impl std::hash::Hash for DeletionFilesList {
                     .first()
                     .map_or(0, |(_, paths)| Arc::as_ptr(paths) as *const () as usize);
 
+                addr.hash(state)
+            },
+            DeltaDeletionVector(vectors) => {
+                let addr = Arc::as_ptr(vectors) as *const () as usize;
+
                 addr.hash(state)
             },
         }
@@ -71,6 +107,10 @@ impl std::fmt::Display for DeletionFilesList {
                 let s = if paths.len() == 1 { "" } else { "s" };
                 write!(f, "iceberg-position-delete: {} source{s}", paths.len())?;
             },
+            DeltaDeletionVector(vectors) => {
+                let s = if vectors.len() == 1 { "" } else { "s" };
+                write!(f, "delta-deletion-vector: {} source{s}", vectors.len())?;
+            },
         }
 
         Ok(())
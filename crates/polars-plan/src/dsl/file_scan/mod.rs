@@ -7,6 +7,8 @@ use polars_core::utils::get_numeric_upcast_supertype_lossless;
 use polars_io::cloud::CloudOptions;
 #[cfg(feature = "csv")]
 use polars_io::csv::read::CsvReadOptions;
+#[cfg(feature = "flight")]
+use polars_io::flight::FlightScanOptions;
 #[cfg(feature = "ipc")]
 use polars_io::ipc::IpcScanOptions;
 #[cfg(feature = "parquet")]
@@ -57,6 +59,15 @@ pub enum FileScanDsl {
     #[cfg(feature = "ipc")]
     Ipc { options: IpcScanOptions },
 
+    #[cfg(feature = "avro")]
+    Avro,
+
+    #[cfg(feature = "orc")]
+    Orc,
+
+    #[cfg(feature = "flight")]
+    Flight { options: Arc<FlightScanOptions> },
+
     #[cfg(feature = "python")]
     PythonDataset {
         dataset_object: Arc<python_dataset::PythonDatasetProvider>,
@@ -102,6 +113,15 @@ pub enum FileScanIR {
         metadata: Option<Arc<arrow::io::ipc::read::FileMetadata>>,
     },
 
+    #[cfg(feature = "avro")]
+    Avro,
+
+    #[cfg(feature = "orc")]
+    Orc,
+
+    #[cfg(feature = "flight")]
+    Flight { options: Arc<FlightScanOptions> },
+
     #[cfg(feature = "python")]
     PythonDataset {
         dataset_object: Arc<python_dataset::PythonDatasetProvider>,
@@ -405,6 +425,17 @@ mod _file_scan_eq_hash {
             metadata: Option<usize>,
         },
 
+        #[cfg(feature = "avro")]
+        Avro,
+
+        #[cfg(feature = "orc")]
+        Orc,
+
+        #[cfg(feature = "flight")]
+        Flight {
+            options: &'a polars_io::flight::FlightScanOptions,
+        },
+
         #[cfg(feature = "python")]
         PythonDataset {
             dataset_object: usize,
@@ -445,6 +476,15 @@ mod _file_scan_eq_hash {
                     metadata: metadata.as_ref().map(arc_as_ptr),
                 },
 
+                #[cfg(feature = "avro")]
+                FileScanIR::Avro => FileScanEqHashWrap::Avro,
+
+                #[cfg(feature = "orc")]
+                FileScanIR::Orc => FileScanEqHashWrap::Orc,
+
+                #[cfg(feature = "flight")]
+                FileScanIR::Flight { options } => FileScanEqHashWrap::Flight { options },
+
                 #[cfg(feature = "python")]
                 FileScanIR::PythonDataset {
                     dataset_object,
@@ -0,0 +1,40 @@
+use super::*;
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum GeoFunction {
+    Area,
+    Length,
+    Centroid,
+    Bbox,
+    Distance,
+    Contains,
+    Intersects,
+    ToWkt,
+    FromWkt,
+}
+
+impl Display for GeoFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use GeoFunction::*;
+        let name = match self {
+            Area => "area",
+            Length => "length",
+            Centroid => "centroid",
+            Bbox => "bbox",
+            Distance => "distance",
+            Contains => "contains",
+            Intersects => "intersects",
+            ToWkt => "to_wkt",
+            FromWkt => "from_wkt",
+        };
+        write!(f, "geo.{name}")
+    }
+}
+
+impl From<GeoFunction> for FunctionExpr {
+    fn from(func: GeoFunction) -> Self {
+        FunctionExpr::GeoExpr(func)
+    }
+}
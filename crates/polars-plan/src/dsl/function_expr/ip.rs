@@ -0,0 +1,28 @@
+use super::*;
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub enum IpFunction {
+    Contains(PlSmallStr),
+    Network(u8),
+    Broadcast(u8),
+}
+
+impl Display for IpFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use IpFunction::*;
+        let name = match self {
+            Contains(_) => "contains",
+            Network(_) => "network",
+            Broadcast(_) => "broadcast",
+        };
+        write!(f, "ip.{name}")
+    }
+}
+
+impl From<IpFunction> for FunctionExpr {
+    fn from(func: IpFunction) -> Self {
+        FunctionExpr::IpExpr(func)
+    }
+}
@@ -0,0 +1,32 @@
+use super::*;
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+pub enum MapFunction {
+    Get,
+    ContainsKey,
+    Keys,
+    Values,
+    FromLists,
+}
+
+impl Display for MapFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use MapFunction::*;
+        let name = match self {
+            Get => "get",
+            ContainsKey => "contains_key",
+            Keys => "keys",
+            Values => "values",
+            FromLists => "from_lists",
+        };
+        write!(f, "map.{name}")
+    }
+}
+
+impl From<MapFunction> for FunctionExpr {
+    fn from(func: MapFunction) -> Self {
+        FunctionExpr::MapExpr(func)
+    }
+}
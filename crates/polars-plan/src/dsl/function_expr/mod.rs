@@ -14,7 +14,13 @@ mod correlation;
 mod datetime;
 #[cfg(feature = "dtype-extension")]
 mod extension;
+#[cfg(feature = "geo")]
+mod geo;
+#[cfg(feature = "dtype-ip")]
+mod ip;
 mod list;
+#[cfg(feature = "dtype-map")]
+mod map_;
 mod pow;
 #[cfg(feature = "random")]
 mod random;
@@ -30,6 +36,8 @@ mod strings;
 mod struct_;
 #[cfg(feature = "trigonometry")]
 mod trigonometry;
+#[cfg(feature = "dtype-variant")]
+mod variant;
 
 use std::fmt::{Display, Formatter};
 use std::hash::{Hash, Hasher};
@@ -58,6 +66,12 @@ pub use self::cat::CategoricalFunction;
 pub use self::datetime::TemporalFunction;
 #[cfg(feature = "dtype-extension")]
 pub use self::extension::ExtensionFunction;
+#[cfg(feature = "geo")]
+pub use self::geo::GeoFunction;
+#[cfg(feature = "dtype-ip")]
+pub use self::ip::IpFunction;
+#[cfg(feature = "dtype-map")]
+pub use self::map_::MapFunction;
 pub use self::pow::PowFunction;
 #[cfg(feature = "range")]
 pub use self::range::{DateRangeArgs, RangeFunction};
@@ -71,6 +85,8 @@ pub use self::strings::StringFunction;
 pub use self::struct_::StructFunction;
 #[cfg(feature = "trigonometry")]
 pub use self::trigonometry::TrigonometricFunction;
+#[cfg(feature = "dtype-variant")]
+pub use self::variant::VariantFunction;
 use super::*;
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -86,6 +102,14 @@ pub enum FunctionExpr {
     #[cfg(feature = "dtype-extension")]
     Extension(ExtensionFunction),
     ListExpr(ListFunction),
+    #[cfg(feature = "dtype-map")]
+    MapExpr(MapFunction),
+    #[cfg(feature = "dtype-variant")]
+    VariantExpr(VariantFunction),
+    #[cfg(feature = "geo")]
+    GeoExpr(GeoFunction),
+    #[cfg(feature = "dtype-ip")]
+    IpExpr(IpFunction),
     #[cfg(feature = "strings")]
     StringExpr(StringFunction),
     #[cfg(feature = "dtype-struct")]
@@ -387,6 +411,14 @@ impl Hash for FunctionExpr {
             #[cfg(feature = "dtype-extension")]
             Extension(f) => f.hash(state),
             ListExpr(f) => f.hash(state),
+            #[cfg(feature = "dtype-map")]
+            MapExpr(f) => f.hash(state),
+            #[cfg(feature = "dtype-variant")]
+            VariantExpr(f) => f.hash(state),
+            #[cfg(feature = "geo")]
+            GeoExpr(f) => f.hash(state),
+            #[cfg(feature = "dtype-ip")]
+            IpExpr(f) => f.hash(state),
             #[cfg(feature = "strings")]
             StringExpr(f) => f.hash(state),
             #[cfg(feature = "dtype-struct")]
@@ -683,6 +715,14 @@ impl Display for FunctionExpr {
             #[cfg(feature = "dtype-extension")]
             Extension(func) => return write!(f, "{func}"),
             ListExpr(func) => return write!(f, "{func}"),
+            #[cfg(feature = "dtype-map")]
+            MapExpr(func) => return write!(f, "{func}"),
+            #[cfg(feature = "dtype-variant")]
+            VariantExpr(func) => return write!(f, "{func}"),
+            #[cfg(feature = "geo")]
+            GeoExpr(func) => return write!(f, "{func}"),
+            #[cfg(feature = "dtype-ip")]
+            IpExpr(func) => return write!(f, "{func}"),
             #[cfg(feature = "strings")]
             StringExpr(func) => return write!(f, "{func}"),
             #[cfg(feature = "dtype-struct")]
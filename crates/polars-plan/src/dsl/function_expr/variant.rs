@@ -0,0 +1,31 @@
+use super::*;
+
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+#[derive(Clone, PartialEq, Hash, Debug)]
+pub enum VariantFunction {
+    Get {
+        path: PlSmallStr,
+        dtype: DataTypeExpr,
+    },
+    TypeName,
+    ToJson,
+}
+
+impl Display for VariantFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use VariantFunction::*;
+        let name = match self {
+            Get { .. } => "get",
+            TypeName => "type_name",
+            ToJson => "to_json",
+        };
+        write!(f, "variant.{name}")
+    }
+}
+
+impl From<VariantFunction> for FunctionExpr {
+    fn from(func: VariantFunction) -> Self {
+        FunctionExpr::VariantExpr(func)
+    }
+}
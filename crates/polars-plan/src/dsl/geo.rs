@@ -0,0 +1,52 @@
+use super::*;
+
+/// Specialized expressions for [`Series`] of WKB geometries.
+pub struct GeoNameSpace(pub(crate) Expr);
+
+impl GeoNameSpace {
+    /// Get the area of every geometry, zero for points and lines.
+    pub fn area(self) -> Expr {
+        self.0.map_unary(GeoFunction::Area)
+    }
+
+    /// Get the length of every geometry, the perimeter for polygons.
+    pub fn length(self) -> Expr {
+        self.0.map_unary(GeoFunction::Length)
+    }
+
+    /// Get the centroid of every geometry as a point.
+    pub fn centroid(self) -> Expr {
+        self.0.map_unary(GeoFunction::Centroid)
+    }
+
+    /// Get the bounding box of every geometry as a struct of `min_x`, `min_y`, `max_x` and
+    /// `max_y`.
+    pub fn bbox(self) -> Expr {
+        self.0.map_unary(GeoFunction::Bbox)
+    }
+
+    /// Get the minimum distance to the geometries of `other`.
+    pub fn distance(self, other: Expr) -> Expr {
+        self.0.map_binary(GeoFunction::Distance, other)
+    }
+
+    /// Check if every geometry contains the geometry of `other`.
+    pub fn contains(self, other: Expr) -> Expr {
+        self.0.map_binary(GeoFunction::Contains, other)
+    }
+
+    /// Check if every geometry intersects the geometry of `other`.
+    pub fn intersects(self, other: Expr) -> Expr {
+        self.0.map_binary(GeoFunction::Intersects, other)
+    }
+
+    /// Format every geometry as well-known text.
+    pub fn to_wkt(self) -> Expr {
+        self.0.map_unary(GeoFunction::ToWkt)
+    }
+
+    /// Parse a string column of well-known text into geometries.
+    pub fn from_wkt(self) -> Expr {
+        self.0.map_unary(GeoFunction::FromWkt)
+    }
+}
@@ -0,0 +1,23 @@
+use super::*;
+
+/// Specialized expressions for [`Series`] of IP addresses.
+pub struct IpNameSpace(pub(crate) Expr);
+
+impl IpNameSpace {
+    /// Check if every address lies in the network of the `cidr` block, e.g. `10.0.0.0/8`.
+    pub fn contains(self, cidr: &str) -> Expr {
+        self.0.map_unary(IpFunction::Contains(cidr.into()))
+    }
+
+    /// Get the network address of every address in a network with `prefix_len` leading network
+    /// bits.
+    pub fn network(self, prefix_len: u8) -> Expr {
+        self.0.map_unary(IpFunction::Network(prefix_len))
+    }
+
+    /// Get the broadcast address of the network of every address with `prefix_len` leading
+    /// network bits.
+    pub fn broadcast(self, prefix_len: u8) -> Expr {
+        self.0.map_unary(IpFunction::Broadcast(prefix_len))
+    }
+}
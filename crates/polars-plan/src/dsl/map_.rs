@@ -0,0 +1,31 @@
+use super::*;
+
+/// Specialized expressions for [`Series`] of [`DataType::Map`].
+pub struct MapNameSpace(pub(crate) Expr);
+
+impl MapNameSpace {
+    /// Get the value of the given key in every map, or null if the key is missing.
+    pub fn get(self, key: Expr) -> Expr {
+        self.0.map_binary(MapFunction::Get, key)
+    }
+
+    /// Check if every map contains the given key.
+    pub fn contains_key(self, key: Expr) -> Expr {
+        self.0.map_binary(MapFunction::ContainsKey, key)
+    }
+
+    /// Get the keys of every map as a list.
+    pub fn keys(self) -> Expr {
+        self.0.map_unary(MapFunction::Keys)
+    }
+
+    /// Get the values of every map as a list.
+    pub fn values(self) -> Expr {
+        self.0.map_unary(MapFunction::Values)
+    }
+}
+
+/// Create a map column from a list column of keys and a list column of values.
+pub fn map_from_lists(keys: Expr, values: Expr) -> Expr {
+    keys.map_binary(MapFunction::FromLists, values)
+}
@@ -26,7 +26,13 @@ mod format;
 mod from;
 pub mod function_expr;
 pub mod functions;
+#[cfg(feature = "geo")]
+mod geo;
+#[cfg(feature = "dtype-ip")]
+mod ip;
 mod list;
+#[cfg(feature = "dtype-map")]
+mod map_;
 mod match_to_schema;
 #[cfg(feature = "meta")]
 mod meta;
@@ -46,6 +52,8 @@ pub mod string;
 #[cfg(feature = "dtype-struct")]
 mod struct_;
 pub mod udf;
+#[cfg(feature = "dtype-variant")]
+mod variant;
 
 use std::fmt::Debug;
 use std::sync::Arc;
@@ -60,7 +68,13 @@ pub use expr::*;
 #[cfg(feature = "dtype-extension")]
 pub use extension::*;
 pub use function_expr::*;
+#[cfg(feature = "geo")]
+pub use geo::*;
+#[cfg(feature = "dtype-ip")]
+pub use ip::*;
 pub use list::*;
+#[cfg(feature = "dtype-map")]
+pub use map_::*;
 pub use match_to_schema::*;
 #[cfg(feature = "meta")]
 pub use meta::*;
@@ -80,6 +94,8 @@ pub use selector::{DataTypeSelector, Selector, TimeUnitSet, TimeZoneSet};
 #[cfg(feature = "dtype-struct")]
 pub use struct_::*;
 pub use udf::UserDefinedFunction;
+#[cfg(feature = "dtype-variant")]
+pub use variant::*;
 mod file_scan;
 pub use file_scan::*;
 use functions::lit;
@@ -1650,6 +1666,30 @@ impl Expr {
         extension::ExtensionNameSpace(self)
     }
 
+    /// Get the [`map_::MapNameSpace`].
+    #[cfg(feature = "dtype-map")]
+    pub fn map_(self) -> map_::MapNameSpace {
+        map_::MapNameSpace(self)
+    }
+
+    /// Get the [`variant::VariantNameSpace`].
+    #[cfg(feature = "dtype-variant")]
+    pub fn variant(self) -> variant::VariantNameSpace {
+        variant::VariantNameSpace(self)
+    }
+
+    /// Get the [`geo::GeoNameSpace`].
+    #[cfg(feature = "geo")]
+    pub fn geo(self) -> geo::GeoNameSpace {
+        geo::GeoNameSpace(self)
+    }
+
+    /// Get the [`ip::IpNameSpace`].
+    #[cfg(feature = "dtype-ip")]
+    pub fn ip(self) -> ip::IpNameSpace {
+        ip::IpNameSpace(self)
+    }
+
     /// Get the [`struct_::StructNameSpace`].
     #[cfg(feature = "dtype-struct")]
     pub fn struct_(self) -> struct_::StructNameSpace {
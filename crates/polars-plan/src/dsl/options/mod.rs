@@ -8,6 +8,8 @@ pub mod file_provider;
 pub mod sink;
 use polars_core::error::PolarsResult;
 use polars_core::prelude::*;
+#[cfg(feature = "avro")]
+use polars_io::avro::AvroWriterOptions;
 #[cfg(feature = "csv")]
 use polars_io::csv::write::CsvWriterOptions;
 #[cfg(feature = "ipc")]
@@ -350,6 +352,8 @@ pub enum FileWriteFormat {
     Csv(CsvWriterOptions),
     #[cfg(feature = "json")]
     NDJson(NDJsonWriterOptions),
+    #[cfg(feature = "avro")]
+    Avro(AvroWriterOptions),
 }
 
 impl FileWriteFormat {
@@ -363,6 +367,8 @@ impl FileWriteFormat {
             Self::Csv(_) => "csv",
             #[cfg(feature = "json")]
             Self::NDJson(_) => "jsonl",
+            #[cfg(feature = "avro")]
+            Self::Avro(_) => "avro",
 
             #[allow(unreachable_patterns)]
             _ => unreachable!("enable file type features"),
@@ -427,4 +433,11 @@ pub struct NDJsonReadOptions {
     pub ignore_errors: bool,
     pub schema: Option<SchemaRef>,
     pub schema_overwrite: Option<SchemaRef>,
+    /// Read the elements of the array at this JSON pointer of a JSON document, instead of reading
+    /// newline-delimited JSON. The empty string refers to a top-level array.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub json_array_pointer: Option<PlSmallStr>,
+    /// Encoding of the files, which are transcoded to UTF-8 while they are read.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub encoding: polars_io::utils::text_encoding::TextEncoding,
 }
@@ -0,0 +1,27 @@
+use super::*;
+
+/// Specialized expressions for [`Series`] of [`DataType::Variant`].
+pub struct VariantNameSpace(pub(crate) Expr);
+
+impl VariantNameSpace {
+    /// Extract the value at `path` of every variant, e.g. `$.a.b[0]`.
+    ///
+    /// Values are converted to `dtype`; missing values and values that can't be converted become
+    /// null. Pass [`DataType::Variant`] to keep the extracted values as variants.
+    pub fn get(self, path: &str, dtype: impl Into<DataTypeExpr>) -> Expr {
+        self.0.map_unary(VariantFunction::Get {
+            path: path.into(),
+            dtype: dtype.into(),
+        })
+    }
+
+    /// Get the type of every variant value, e.g. `"object"` or `"int64"`.
+    pub fn type_name(self) -> Expr {
+        self.0.map_unary(VariantFunction::TypeName)
+    }
+
+    /// Convert every variant to a JSON string.
+    pub fn to_json(self) -> Expr {
+        self.0.map_unary(VariantFunction::ToJson)
+    }
+}
@@ -0,0 +1,89 @@
+use polars_core::datatypes::extension::{geometry_dtype, is_geometry};
+
+use super::*;
+
+#[cfg_attr(feature = "ir_serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+pub enum IRGeoFunction {
+    Area,
+    Length,
+    Centroid,
+    Bbox,
+    Distance,
+    Contains,
+    Intersects,
+    ToWkt,
+    FromWkt,
+}
+
+impl<'a> FieldsMapper<'a> {
+    /// Validate that all dtypes are geometries.
+    pub fn ensure_is_geometry(self) -> PolarsResult<Self> {
+        for field in self.args() {
+            polars_ensure!(
+                is_geometry(field.dtype()),
+                InvalidOperation: format!("expected geometry datatype for geo operation, got: {:?}", field.dtype())
+            );
+        }
+        Ok(self)
+    }
+}
+
+impl IRGeoFunction {
+    pub(super) fn get_field(&self, mapper: FieldsMapper) -> PolarsResult<Field> {
+        use IRGeoFunction::*;
+        match self {
+            Area | Length | Distance => mapper.ensure_is_geometry()?.with_dtype(DataType::Float64),
+            Contains | Intersects => mapper.ensure_is_geometry()?.with_dtype(DataType::Boolean),
+            Centroid => mapper.ensure_is_geometry()?.with_dtype(geometry_dtype()),
+            Bbox => {
+                let fields = ["min_x", "min_y", "max_x", "max_y"]
+                    .map(|name| Field::new(PlSmallStr::from_static(name), DataType::Float64));
+                mapper
+                    .ensure_is_geometry()?
+                    .with_dtype(DataType::Struct(fields.into()))
+            },
+            ToWkt => mapper.ensure_is_geometry()?.with_dtype(DataType::String),
+            FromWkt => {
+                let dt = mapper.args()[0].dtype();
+                polars_ensure!(
+                    dt.is_string(),
+                    InvalidOperation: "geo.from_wkt expects a string column, got {}", dt
+                );
+                mapper.with_dtype(geometry_dtype())
+            },
+        }
+    }
+
+    pub fn function_options(&self) -> FunctionOptions {
+        use IRGeoFunction::*;
+        match self {
+            Area | Length | Centroid | Bbox | Distance | Contains | Intersects | ToWkt
+            | FromWkt => FunctionOptions::elementwise(),
+        }
+    }
+}
+
+impl Display for IRGeoFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use IRGeoFunction::*;
+        let name = match self {
+            Area => "area",
+            Length => "length",
+            Centroid => "centroid",
+            Bbox => "bbox",
+            Distance => "distance",
+            Contains => "contains",
+            Intersects => "intersects",
+            ToWkt => "to_wkt",
+            FromWkt => "from_wkt",
+        };
+        write!(f, "geo.{name}")
+    }
+}
+
+impl From<IRGeoFunction> for IRFunctionExpr {
+    fn from(func: IRGeoFunction) -> Self {
+        IRFunctionExpr::GeoExpr(func)
+    }
+}
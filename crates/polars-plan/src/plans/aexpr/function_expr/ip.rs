@@ -0,0 +1,51 @@
+use polars_core::datatypes::extension::ip_version;
+
+use super::*;
+
+#[cfg_attr(feature = "ir_serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub enum IRIpFunction {
+    Contains(PlSmallStr),
+    Network(u8),
+    Broadcast(u8),
+}
+
+impl IRIpFunction {
+    pub(super) fn get_field(&self, mapper: FieldsMapper) -> PolarsResult<Field> {
+        use IRIpFunction::*;
+        let dt = mapper.args()[0].dtype();
+        polars_ensure!(
+            ip_version(dt).is_some(),
+            InvalidOperation: "expected IP address datatype for ip operation, got: {:?}", dt
+        );
+        match self {
+            Contains(_) => mapper.with_dtype(DataType::Boolean),
+            Network(_) | Broadcast(_) => mapper.with_same_dtype(),
+        }
+    }
+
+    pub fn function_options(&self) -> FunctionOptions {
+        use IRIpFunction::*;
+        match self {
+            Contains(_) | Network(_) | Broadcast(_) => FunctionOptions::elementwise(),
+        }
+    }
+}
+
+impl Display for IRIpFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use IRIpFunction::*;
+        let name = match self {
+            Contains(_) => "contains",
+            Network(_) => "network",
+            Broadcast(_) => "broadcast",
+        };
+        write!(f, "ip.{name}")
+    }
+}
+
+impl From<IRIpFunction> for IRFunctionExpr {
+    fn from(func: IRIpFunction) -> Self {
+        IRFunctionExpr::IpExpr(func)
+    }
+}
@@ -0,0 +1,86 @@
+use super::*;
+
+#[cfg_attr(feature = "ir_serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+pub enum IRMapFunction {
+    Get,
+    ContainsKey,
+    Keys,
+    Values,
+    FromLists,
+}
+
+impl<'a> FieldsMapper<'a> {
+    /// Validate that the dtype is a map.
+    pub fn ensure_is_map(self) -> PolarsResult<Self> {
+        let dt = self.args()[0].dtype();
+        polars_ensure!(
+            dt.is_map(),
+            InvalidOperation: format!("expected Map datatype for map operation, got: {:?}", dt)
+        );
+        Ok(self)
+    }
+}
+
+impl IRMapFunction {
+    pub(super) fn get_field(&self, mapper: FieldsMapper) -> PolarsResult<Field> {
+        use IRMapFunction::*;
+        match self {
+            Get => mapper.ensure_is_map()?.map_dtype(|dt| match dt {
+                DataType::Map(_, value) => value.as_ref().clone(),
+                _ => unreachable!(),
+            }),
+            ContainsKey => mapper.ensure_is_map()?.with_dtype(DataType::Boolean),
+            Keys => mapper.ensure_is_map()?.map_dtype(|dt| match dt {
+                DataType::Map(key, _) => DataType::List(key.clone()),
+                _ => unreachable!(),
+            }),
+            Values => mapper.ensure_is_map()?.map_dtype(|dt| match dt {
+                DataType::Map(_, value) => DataType::List(value.clone()),
+                _ => unreachable!(),
+            }),
+            FromLists => {
+                let args = mapper.args();
+                polars_ensure!(
+                    args.len() == 2,
+                    InvalidOperation: "map.from_lists expects keys and values"
+                );
+                match (args[0].dtype(), args[1].dtype()) {
+                    (DataType::List(key), DataType::List(value)) => {
+                        mapper.with_dtype(DataType::Map(key.clone(), value.clone()))
+                    },
+                    (k, v) => polars_bail!(
+                        InvalidOperation: "map.from_lists expects two lists, got {} and {}", k, v
+                    ),
+                }
+            },
+        }
+    }
+
+    pub fn function_options(&self) -> FunctionOptions {
+        use IRMapFunction::*;
+        match self {
+            Get | ContainsKey | Keys | Values | FromLists => FunctionOptions::elementwise(),
+        }
+    }
+}
+
+impl Display for IRMapFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use IRMapFunction::*;
+        let name = match self {
+            Get => "get",
+            ContainsKey => "contains_key",
+            Keys => "keys",
+            Values => "values",
+            FromLists => "from_lists",
+        };
+        write!(f, "map.{name}")
+    }
+}
+
+impl From<IRMapFunction> for IRFunctionExpr {
+    fn from(func: IRMapFunction) -> Self {
+        IRFunctionExpr::MapExpr(func)
+    }
+}
@@ -18,7 +18,13 @@ mod datetime;
 mod extension;
 #[cfg(feature = "fused")]
 mod fused;
+#[cfg(feature = "geo")]
+mod geo;
+#[cfg(feature = "dtype-ip")]
+mod ip;
 mod list;
+#[cfg(feature = "dtype-map")]
+mod map_;
 #[cfg(feature = "ffi_plugin")]
 pub mod plugin;
 mod pow;
@@ -38,6 +44,8 @@ mod strings;
 mod struct_;
 #[cfg(feature = "trigonometry")]
 mod trigonometry;
+#[cfg(feature = "dtype-variant")]
+mod variant;
 
 use std::fmt::{Display, Formatter};
 use std::hash::{Hash, Hasher};
@@ -70,6 +78,12 @@ pub use self::cat::IRCategoricalFunction;
 pub use self::datetime::IRTemporalFunction;
 #[cfg(feature = "dtype-extension")]
 pub use self::extension::IRExtensionFunction;
+#[cfg(feature = "geo")]
+pub use self::geo::IRGeoFunction;
+#[cfg(feature = "dtype-ip")]
+pub use self::ip::IRIpFunction;
+#[cfg(feature = "dtype-map")]
+pub use self::map_::IRMapFunction;
 pub use self::pow::IRPowFunction;
 #[cfg(feature = "range")]
 pub use self::range::IRRangeFunction;
@@ -86,6 +100,8 @@ pub use self::strings::TZ_AWARE_RE;
 pub use self::struct_::IRStructFunction;
 #[cfg(feature = "trigonometry")]
 pub use self::trigonometry::IRTrigonometricFunction;
+#[cfg(feature = "dtype-variant")]
+pub use self::variant::IRVariantFunction;
 use super::*;
 
 #[cfg_attr(feature = "ir_serde", derive(serde::Serialize, serde::Deserialize))]
@@ -100,6 +116,14 @@ pub enum IRFunctionExpr {
     #[cfg(feature = "dtype-extension")]
     Extension(IRExtensionFunction),
     ListExpr(IRListFunction),
+    #[cfg(feature = "dtype-map")]
+    MapExpr(IRMapFunction),
+    #[cfg(feature = "dtype-variant")]
+    VariantExpr(IRVariantFunction),
+    #[cfg(feature = "geo")]
+    GeoExpr(IRGeoFunction),
+    #[cfg(feature = "dtype-ip")]
+    IpExpr(IRIpFunction),
     #[cfg(feature = "strings")]
     StringExpr(IRStringFunction),
     #[cfg(feature = "dtype-struct")]
@@ -401,6 +425,14 @@ impl Hash for IRFunctionExpr {
             #[cfg(feature = "dtype-extension")]
             Extension(f) => f.hash(state),
             ListExpr(f) => f.hash(state),
+            #[cfg(feature = "dtype-map")]
+            MapExpr(f) => f.hash(state),
+            #[cfg(feature = "dtype-variant")]
+            VariantExpr(f) => f.hash(state),
+            #[cfg(feature = "geo")]
+            GeoExpr(f) => f.hash(state),
+            #[cfg(feature = "dtype-ip")]
+            IpExpr(f) => f.hash(state),
             #[cfg(feature = "strings")]
             StringExpr(f) => f.hash(state),
             #[cfg(feature = "dtype-struct")]
@@ -705,6 +737,14 @@ impl Display for IRFunctionExpr {
             #[cfg(feature = "dtype-extension")]
             Extension(func) => return write!(f, "{func}"),
             ListExpr(func) => return write!(f, "{func}"),
+            #[cfg(feature = "dtype-map")]
+            MapExpr(func) => return write!(f, "{func}"),
+            #[cfg(feature = "dtype-variant")]
+            VariantExpr(func) => return write!(f, "{func}"),
+            #[cfg(feature = "geo")]
+            GeoExpr(func) => return write!(f, "{func}"),
+            #[cfg(feature = "dtype-ip")]
+            IpExpr(func) => return write!(f, "{func}"),
             #[cfg(feature = "strings")]
             StringExpr(func) => return write!(f, "{func}"),
             #[cfg(feature = "dtype-struct")]
@@ -999,6 +1039,14 @@ impl IRFunctionExpr {
             #[cfg(feature = "dtype-extension")]
             F::Extension(e) => e.function_options(),
             F::ListExpr(e) => e.function_options(),
+            #[cfg(feature = "dtype-map")]
+            F::MapExpr(e) => e.function_options(),
+            #[cfg(feature = "dtype-variant")]
+            F::VariantExpr(e) => e.function_options(),
+            #[cfg(feature = "geo")]
+            F::GeoExpr(e) => e.function_options(),
+            #[cfg(feature = "dtype-ip")]
+            F::IpExpr(e) => e.function_options(),
             #[cfg(feature = "strings")]
             F::StringExpr(e) => e.function_options(),
             #[cfg(feature = "dtype-struct")]
@@ -21,6 +21,14 @@ impl IRFunctionExpr {
             #[cfg(feature = "dtype-extension")]
             Extension(func) => func.get_field(mapper),
             ListExpr(func) => func.get_field(mapper),
+            #[cfg(feature = "dtype-map")]
+            MapExpr(func) => func.get_field(mapper),
+            #[cfg(feature = "dtype-variant")]
+            VariantExpr(func) => func.get_field(mapper),
+            #[cfg(feature = "geo")]
+            GeoExpr(func) => func.get_field(mapper),
+            #[cfg(feature = "dtype-ip")]
+            IpExpr(func) => func.get_field(mapper),
             #[cfg(feature = "strings")]
             StringExpr(s) => s.get_field(mapper),
             #[cfg(feature = "dtype-struct")]
@@ -539,6 +547,8 @@ impl<'a> FieldsMapper<'a> {
             dt @ DataType::Time => dt.clone(),
             #[cfg(feature = "dtype-decimal")]
             DataType::Decimal(..) => DataType::Float64,
+            #[cfg(feature = "dtype-decimal256")]
+            DataType::Decimal256(..) => DataType::Float64,
 
             // All other types get mapped to a single `null` of the same type.
             dt => dt.clone(),
@@ -571,6 +581,8 @@ impl<'a> FieldsMapper<'a> {
                 DataType::Float32 => false,
                 #[cfg(feature = "dtype-decimal")]
                 DataType::Decimal(..) => coerce_decimal,
+                #[cfg(feature = "dtype-decimal256")]
+                DataType::Decimal256(..) => coerce_decimal,
                 DataType::Boolean => true,
                 dt => dt.is_primitive_numeric(),
             };
@@ -685,6 +697,8 @@ impl<'a> FieldsMapper<'a> {
         self.map_dtype(|dtype| match dtype {
             Int8 | UInt8 | Int16 | UInt16 => Int64,
             Boolean => IDX_DTYPE,
+            #[cfg(feature = "dtype-decimal256")]
+            Decimal256(_, scale) => Decimal256(polars_compute::decimal::DEC256_MAX_PREC, *scale),
             dt => dt.clone(),
         })
     }
@@ -0,0 +1,61 @@
+use polars_ops::chunked_array::variant::VariantPath;
+
+use super::*;
+
+#[cfg_attr(feature = "ir_serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub enum IRVariantFunction {
+    Get { path: PlSmallStr, dtype: DataType },
+    TypeName,
+    ToJson,
+}
+
+impl<'a> FieldsMapper<'a> {
+    /// Validate that the dtype is a variant.
+    pub fn ensure_is_variant(self) -> PolarsResult<Self> {
+        let dt = self.args()[0].dtype();
+        polars_ensure!(
+            dt.is_variant(),
+            InvalidOperation: format!("expected Variant datatype for variant operation, got: {:?}", dt)
+        );
+        Ok(self)
+    }
+}
+
+impl IRVariantFunction {
+    pub(super) fn get_field(&self, mapper: FieldsMapper) -> PolarsResult<Field> {
+        use IRVariantFunction::*;
+        match self {
+            Get { path, dtype } => {
+                VariantPath::parse(path)?;
+                mapper.ensure_is_variant()?.with_dtype(dtype.clone())
+            },
+            TypeName | ToJson => mapper.ensure_is_variant()?.with_dtype(DataType::String),
+        }
+    }
+
+    pub fn function_options(&self) -> FunctionOptions {
+        use IRVariantFunction::*;
+        match self {
+            Get { .. } | TypeName | ToJson => FunctionOptions::elementwise(),
+        }
+    }
+}
+
+impl Display for IRVariantFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        use IRVariantFunction::*;
+        let name = match self {
+            Get { .. } => "get",
+            TypeName => "type_name",
+            ToJson => "to_json",
+        };
+        write!(f, "variant.{name}")
+    }
+}
+
+impl From<IRVariantFunction> for IRFunctionExpr {
+    fn from(func: IRVariantFunction) -> Self {
+        IRFunctionExpr::VariantExpr(func)
+    }
+}
@@ -1,5 +1,7 @@
 #[cfg(feature = "dtype-decimal")]
 use polars_compute::decimal::DEC128_MAX_PREC;
+#[cfg(feature = "dtype-decimal256")]
+use polars_compute::decimal::DEC256_MAX_PREC;
 use polars_core::series::arithmetic::NumericListOp;
 use polars_utils::format_pl_smallstr;
 use recursive::recursive;
@@ -198,6 +200,8 @@ impl AExpr {
                             },
                             Boolean => Some(IDX_DTYPE),
                             UInt8 | Int8 | Int16 | UInt16 => Some(Int64),
+                            #[cfg(feature = "dtype-decimal256")]
+                            Decimal256(_, scale) => Some(Decimal256(DEC256_MAX_PREC, *scale)),
                             _ => None,
                         };
                         if let Some(dt) = dt {
@@ -484,7 +488,6 @@ fn get_arithmetic_field(
     op: Operator,
     ctx: &ToFieldContext,
 ) -> PolarsResult<Field> {
-    use DataType::*;
     let left_ae = ctx.arena.get(left);
     let right_ae = ctx.arena.get(right);
 
@@ -494,13 +497,60 @@ fn get_arithmetic_field(
     // take the left field as a whole.
     // don't take dtype and name separate as that splits the tree every node
     // leading to quadratic behavior. # 4736
-    //
-    // further right_type is only determined when needed.
-    let mut left_field = left_ae.to_field_impl(ctx)?;
+    let left_field = left_ae.to_field_impl(ctx)?;
+    let right_type = right_ae.to_field_impl(ctx)?.dtype;
+
+    #[cfg(feature = "dtype-extension")]
+    if left_field.dtype.is_extension() || right_type.is_extension() {
+        return get_extension_arithmetic_field(left_ae, right_ae, left_field, right_type, op);
+    }
+
+    arithmetic_field(left_ae, right_ae, left_field, right_type, op)
+}
+
+/// Arithmetic on extension types is applied on their storage, the result keeps the extension type
+/// if the type preserves it for `op`. This matches `extension_arithmetic` in polars-core.
+#[cfg(feature = "dtype-extension")]
+fn get_extension_arithmetic_field(
+    left_ae: &AExpr,
+    right_ae: &AExpr,
+    left_field: Field,
+    right_type: DataType,
+    op: Operator,
+) -> PolarsResult<Field> {
+    use polars_core::datatypes::extension::{ExtensionOperation, arithmetic_extension_type};
+
+    let (typ, storage) = arithmetic_extension_type(&left_field.dtype, &right_type)?;
+    let left_storage = Field::new(left_field.name.clone(), left_field.dtype.to_storage());
+    let mut field = arithmetic_field(left_ae, right_ae, left_storage, right_type.to_storage(), op)?;
+
+    let ext_op = match op {
+        Operator::Plus => Some(ExtensionOperation::Add),
+        Operator::Minus => Some(ExtensionOperation::Sub),
+        Operator::Multiply => Some(ExtensionOperation::Mul),
+        Operator::Divide => Some(ExtensionOperation::Div),
+        Operator::Modulus => Some(ExtensionOperation::Rem),
+        _ => None,
+    };
+    if let Some(ext_op) = ext_op
+        && typ.keeps_extension(ext_op, storage, &field.dtype)
+    {
+        field.coerce(DataType::Extension(typ.clone(), Box::new(storage.clone())));
+    }
+    Ok(field)
+}
+
+fn arithmetic_field(
+    left_ae: &AExpr,
+    right_ae: &AExpr,
+    mut left_field: Field,
+    right_type: DataType,
+    op: Operator,
+) -> PolarsResult<Field> {
+    use DataType::*;
 
     let super_type = match op {
         Operator::Minus => {
-            let right_type = right_ae.to_field_impl(ctx)?.dtype;
             match (&left_field.dtype, &right_type) {
                 #[cfg(feature = "dtype-struct")]
                 (Struct(_), Struct(_)) => {
@@ -511,6 +561,12 @@ fn get_arithmetic_field(
                 (Struct(_), r) if r.is_numeric() => {
                     return Ok(left_field);
                 },
+                #[cfg(feature = "dtype-interval")]
+                (Date | Datetime(_, _), Interval) => left_field.dtype.clone(),
+                #[cfg(feature = "dtype-interval")]
+                (Duration(_), Interval) | (Interval, Duration(_)) | (Interval, Interval) => {
+                    Interval
+                },
                 (Duration(_), Datetime(_, _))
                 | (Datetime(_, _), Duration(_))
                 | (Duration(_), Date)
@@ -568,11 +624,15 @@ fn get_arithmetic_field(
                 (Decimal(_, scale_left), Decimal(_, scale_right)) => {
                     Decimal(DEC128_MAX_PREC, *scale_left.max(scale_right))
                 },
+                #[cfg(feature = "dtype-decimal256")]
+                (
+                    Decimal256(_, scale_left) | Decimal(_, scale_left),
+                    Decimal256(_, scale_right) | Decimal(_, scale_right),
+                ) => Decimal256(DEC256_MAX_PREC, *scale_left.max(scale_right)),
                 (left, right) => try_get_supertype(left, right)?,
             }
         },
         Operator::Plus => {
-            let right_type = right_ae.to_field_impl(ctx)?.dtype;
             match (&left_field.dtype, &right_type) {
                 #[cfg(feature = "dtype-struct")]
                 (Struct(_), Struct(_)) => {
@@ -583,6 +643,14 @@ fn get_arithmetic_field(
                 (Struct(_), r) if r.is_numeric() => {
                     return Ok(left_field);
                 },
+                #[cfg(feature = "dtype-interval")]
+                (Date | Datetime(_, _), Interval) => left_field.dtype.clone(),
+                #[cfg(feature = "dtype-interval")]
+                (Interval, Date | Datetime(_, _)) => right_type.clone(),
+                #[cfg(feature = "dtype-interval")]
+                (Duration(_), Interval) | (Interval, Duration(_)) | (Interval, Interval) => {
+                    Interval
+                },
                 (Duration(_), Datetime(_, _))
                 | (Datetime(_, _), Duration(_))
                 | (Duration(_), Date)
@@ -630,12 +698,15 @@ fn get_arithmetic_field(
                 (Decimal(_, scale_left), Decimal(_, scale_right)) => {
                     Decimal(DEC128_MAX_PREC, *scale_left.max(scale_right))
                 },
+                #[cfg(feature = "dtype-decimal256")]
+                (
+                    Decimal256(_, scale_left) | Decimal(_, scale_left),
+                    Decimal256(_, scale_right) | Decimal(_, scale_right),
+                ) => Decimal256(DEC256_MAX_PREC, *scale_left.max(scale_right)),
                 (left, right) => try_get_supertype(left, right)?,
             }
         },
         _ => {
-            let right_type = right_ae.to_field_impl(ctx)?.dtype;
-
             match (&left_field.dtype, &right_type) {
                 #[cfg(feature = "dtype-struct")]
                 (Struct(_), Struct(_)) => {
@@ -681,6 +752,15 @@ fn get_arithmetic_field(
                     left_field.coerce(dtype);
                     return Ok(left_field);
                 },
+                #[cfg(feature = "dtype-decimal256")]
+                (
+                    Decimal256(_, scale_left) | Decimal(_, scale_left),
+                    Decimal256(_, scale_right) | Decimal(_, scale_right),
+                ) => {
+                    let dtype = Decimal256(DEC256_MAX_PREC, *scale_left.max(scale_right));
+                    left_field.coerce(dtype);
+                    return Ok(left_field);
+                },
 
                 (l @ List(a), r @ List(b))
                     if ![a, b]
@@ -850,6 +930,11 @@ fn get_truediv_dtype(left_dtype: &DataType, right_dtype: &DataType) -> PolarsRes
         (Decimal(_, scale_left), Decimal(_, scale_right)) => {
             Decimal(DEC128_MAX_PREC, *scale_left.max(scale_right))
         },
+        #[cfg(feature = "dtype-decimal256")]
+        (
+            Decimal256(_, scale_left) | Decimal(_, scale_left),
+            Decimal256(_, scale_right) | Decimal(_, scale_right),
+        ) => Decimal256(DEC256_MAX_PREC, *scale_left.max(scale_right)),
         #[cfg(all(feature = "dtype-u8", feature = "dtype-f16"))]
         (UInt8 | Int8, Float16) => Float16,
         #[cfg(all(feature = "dtype-u16", feature = "dtype-f16"))]
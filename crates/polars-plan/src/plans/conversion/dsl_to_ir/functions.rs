@@ -127,6 +127,53 @@ pub(super) fn convert_functions(
                 E::Storage => IE::Storage,
             })
         },
+        #[cfg(feature = "dtype-map")]
+        F::MapExpr(map_function) => {
+            use {IRMapFunction as IM, MapFunction as M};
+            I::MapExpr(match map_function {
+                M::Get => IM::Get,
+                M::ContainsKey => IM::ContainsKey,
+                M::Keys => IM::Keys,
+                M::Values => IM::Values,
+                M::FromLists => IM::FromLists,
+            })
+        },
+        #[cfg(feature = "dtype-variant")]
+        F::VariantExpr(variant_function) => {
+            use {IRVariantFunction as IV, VariantFunction as V};
+            I::VariantExpr(match variant_function {
+                V::Get { path, dtype } => IV::Get {
+                    path,
+                    dtype: dtype.into_datatype(ctx.schema)?,
+                },
+                V::TypeName => IV::TypeName,
+                V::ToJson => IV::ToJson,
+            })
+        },
+        #[cfg(feature = "geo")]
+        F::GeoExpr(geo_function) => {
+            use {GeoFunction as G, IRGeoFunction as IG};
+            I::GeoExpr(match geo_function {
+                G::Area => IG::Area,
+                G::Length => IG::Length,
+                G::Centroid => IG::Centroid,
+                G::Bbox => IG::Bbox,
+                G::Distance => IG::Distance,
+                G::Contains => IG::Contains,
+                G::Intersects => IG::Intersects,
+                G::ToWkt => IG::ToWkt,
+                G::FromWkt => IG::FromWkt,
+            })
+        },
+        #[cfg(feature = "dtype-ip")]
+        F::IpExpr(ip_function) => {
+            use {IRIpFunction as II, IpFunction as Ip};
+            I::IpExpr(match ip_function {
+                Ip::Contains(cidr) => II::Contains(cidr),
+                Ip::Network(prefix_len) => II::Network(prefix_len),
+                Ip::Broadcast(prefix_len) => II::Broadcast(prefix_len),
+            })
+        },
         F::ListExpr(list_function) => {
             use {IRListFunction as IL, ListFunction as L};
             I::ListExpr(match list_function {
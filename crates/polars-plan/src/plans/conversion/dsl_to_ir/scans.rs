@@ -59,6 +59,21 @@ pub(super) async fn dsl_to_ir(
                     .expand_paths_with_hive_update(unified_scan_args)
                     .await?
             },
+            #[cfg(feature = "avro")]
+            FileScanDsl::Avro => {
+                sources
+                    .expand_paths_with_hive_update(unified_scan_args)
+                    .await?
+            },
+            #[cfg(feature = "orc")]
+            FileScanDsl::Orc => {
+                sources
+                    .expand_paths_with_hive_update(unified_scan_args)
+                    .await?
+            },
+            // The source is the location of the Flight service.
+            #[cfg(feature = "flight")]
+            FileScanDsl::Flight { .. } => sources.clone(),
             #[cfg(feature = "csv")]
             FileScanDsl::Csv { .. } => sources.expand_paths(unified_scan_args).await?,
             #[cfg(feature = "json")]
@@ -337,6 +352,144 @@ pub(super) async fn ipc_file_info(
     Ok((file_info, metadata))
 }
 
+#[cfg(feature = "avro")]
+pub(super) async fn avro_file_info(
+    sources: &ScanSources,
+    first_scan_source: ScanSourceRef<'_>,
+    row_index: Option<&RowIndex>,
+    cloud_options: Option<&polars_io::cloud::CloudOptions>,
+) -> PolarsResult<FileInfo> {
+    use polars_core::config;
+    use polars_core::error::feature_gated;
+
+    let run_async = sources.is_cloud_url() || (sources.is_paths() && config::force_async());
+
+    let cache_entries = {
+        if run_async {
+            feature_gated!("cloud", {
+                Some(
+                    polars_io::file_cache::init_entries_from_uri_list(
+                        sources.as_paths().unwrap()[..1].iter().cloned(),
+                        cloud_options,
+                    )
+                    .await?,
+                )
+            })
+        } else {
+            None
+        }
+    };
+
+    let mem_slice =
+        first_scan_source.to_buffer_possibly_async(run_async, cache_entries.as_ref(), 0)?;
+    let metadata = polars_io::avro::AvroFileMetadata::try_new(&mem_slice)?;
+
+    let file_info = FileInfo::new(
+        prepare_output_schema(
+            Schema::from_arrow_schema(metadata.schema.as_ref()),
+            row_index,
+        )?,
+        Some(Either::Left(metadata.schema)),
+        (None, usize::MAX),
+    );
+
+    Ok(file_info)
+}
+
+#[cfg(feature = "flight")]
+pub(super) async fn flight_file_info(
+    first_scan_source: ScanSourceRef<'_>,
+    options: &polars_io::flight::FlightScanOptions,
+    schema: Option<SchemaRef>,
+    row_index: Option<&RowIndex>,
+) -> PolarsResult<FileInfo> {
+    use polars_io::flight::{FlightDescriptor, flight_descriptor, flight_info_schema};
+
+    if let Some(schema) = schema {
+        return Ok(FileInfo::new(
+            prepare_output_schema(schema.as_ref().clone(), row_index)?,
+            Some(Either::Right(schema)),
+            (None, usize::MAX),
+        ));
+    }
+
+    let location = first_scan_source.to_include_path_name();
+
+    if verbose() {
+        eprintln!("sourcing flight scan schema from: '{location}'")
+    }
+
+    let options = options.clone();
+    let location = location.to_string();
+    // The client needs to run on the tokio runtime.
+    let schema = polars_io::pl_async::get_runtime()
+        .spawn(async move {
+            let info = options
+                .connect(&location)
+                .await?
+                .get_flight_info(FlightDescriptor {
+                    r#type: flight_descriptor::DescriptorType::Cmd as i32,
+                    cmd: options.ticket.clone(),
+                    ..Default::default()
+                })
+                .await?;
+            flight_info_schema(&info)
+        })
+        .await
+        .unwrap()?;
+
+    Ok(FileInfo::new(
+        prepare_output_schema(schema.as_ref().clone(), row_index)?,
+        Some(Either::Right(schema)),
+        (None, usize::MAX),
+    ))
+}
+
+#[cfg(feature = "orc")]
+pub(super) async fn orc_file_info(
+    sources: &ScanSources,
+    first_scan_source: ScanSourceRef<'_>,
+    row_index: Option<&RowIndex>,
+    cloud_options: Option<&polars_io::cloud::CloudOptions>,
+) -> PolarsResult<FileInfo> {
+    use polars_core::config;
+    use polars_core::error::feature_gated;
+
+    let run_async = sources.is_cloud_url() || (sources.is_paths() && config::force_async());
+
+    let cache_entries = {
+        if run_async {
+            feature_gated!("cloud", {
+                Some(
+                    polars_io::file_cache::init_entries_from_uri_list(
+                        sources.as_paths().unwrap()[..1].iter().cloned(),
+                        cloud_options,
+                    )
+                    .await?,
+                )
+            })
+        } else {
+            None
+        }
+    };
+
+    let mem_slice =
+        first_scan_source.to_buffer_possibly_async(run_async, cache_entries.as_ref(), 0)?;
+    let metadata = polars_io::orc::OrcFileMetadata::try_new(&mem_slice)?;
+
+    let num_rows = metadata.num_rows();
+    let n_sources = sources.len();
+    let known_size = if n_sources == 1 { Some(num_rows) } else { None };
+
+    let file_info = FileInfo::new(
+        prepare_output_schema(metadata.schema.as_ref().clone(), row_index)?,
+        Some(Either::Right(metadata.schema)),
+        (known_size, num_rows.saturating_mul(n_sources)),
+    );
+
+    Ok(file_info)
+}
+
 #[cfg(feature = "csv")]
 pub async fn csv_file_info(
     sources: &ScanSources,
@@ -378,7 +531,8 @@ pub async fn csv_file_info(
     let infer_schema_func = |i| {
         let source = sources.at(i);
         let mem_slice = source.to_buffer_possibly_async(run_async, cache_entries.as_ref(), i)?;
-        let mut reader = CompressedReader::try_new(mem_slice)?;
+        let mut reader = CompressedReader::try_new(mem_slice)?
+            .with_encoding(csv_options.parse_options.encoding)?;
 
         let mut first_row_len = 0;
         let (schema, _) = read_until_start_and_infer_schema(
@@ -446,6 +600,47 @@ pub async fn csv_file_info(
     ))
 }
 
+/// Sniff the CSV dialect from the first source and apply it to `csv_options`.
+#[cfg(feature = "csv")]
+pub async fn csv_sniff_dialect(
+    sources: &ScanSources,
+    first_scan_source: ScanSourceRef<'_>,
+    csv_options: &mut CsvReadOptions,
+    cloud_options: Option<&polars_io::cloud::CloudOptions>,
+) -> PolarsResult<()> {
+    use polars_core::config;
+    use polars_core::error::feature_gated;
+
+    let run_async = sources.is_cloud_url() || (sources.is_paths() && config::force_async());
+
+    let cache_entries = {
+        if run_async {
+            feature_gated!("cloud", {
+                Some(
+                    polars_io::file_cache::init_entries_from_uri_list(
+                        sources.as_paths().unwrap()[..1].iter().cloned(),
+                        cloud_options,
+                    )
+                    .await?,
+                )
+            })
+        } else {
+            None
+        }
+    };
+
+    let mem_slice =
+        first_scan_source.to_buffer_possibly_async(run_async, cache_entries.as_ref(), 0)?;
+    let mut reader =
+        CompressedReader::try_new(mem_slice)?.with_encoding(csv_options.parse_options.encoding)?;
+    let dialect = sniff_csv_dialect(csv_options, &mut reader)?;
+
+    *csv_options = dialect.apply(std::mem::take(csv_options));
+    csv_options.sniff_dialect = false;
+
+    Ok(())
+}
+
 #[cfg(feature = "json")]
 pub async fn ndjson_file_info(
     sources: &ScanSources,
@@ -480,12 +675,24 @@ pub async fn ndjson_file_info(
     } else {
         let mem_slice =
             first_scan_source.to_buffer_possibly_async(run_async, cache_entries.as_ref(), 0)?;
-        let mut reader = BufReader::new(CompressedReader::try_new(mem_slice)?);
+        let reader =
+            CompressedReader::try_new(mem_slice)?.with_encoding(ndjson_options.encoding)?;
 
-        Arc::new(polars_io::ndjson::infer_schema(
-            &mut reader,
-            ndjson_options.infer_schema_length,
-        )?)
+        let schema = if let Some(pointer) = &ndjson_options.json_array_pointer {
+            use polars_io::ndjson::json_array::JsonArrayReader;
+
+            polars_io::ndjson::infer_schema(
+                &mut BufReader::new(JsonArrayReader::try_new(reader, pointer)?),
+                ndjson_options.infer_schema_length,
+            )?
+        } else {
+            polars_io::ndjson::infer_schema(
+                &mut BufReader::new(reader),
+                ndjson_options.infer_schema_length,
+            )?
+        };
+
+        Arc::new(schema)
     };
 
     if let Some(overwriting_schema) = &ndjson_options.schema_overwrite {
@@ -630,9 +837,84 @@ this scan to succeed with an empty DataFrame.",
                 ))
             }
             .map_err(|e| e.context(failed_here!(ipc scan)))?,
+            #[cfg(feature = "avro")]
+            FileScanDsl::Avro => {
+                let first_scan_source =
+                    require_first_source("failed to retrieve first file schema (avro)", "")?;
+
+                if verbose() {
+                    eprintln!(
+                        "sourcing avro scan file schema from: '{}'",
+                        first_scan_source.to_include_path_name()
+                    )
+                }
+
+                let file_info = scans::avro_file_info(
+                    sources,
+                    first_scan_source,
+                    unified_scan_args.row_index.as_ref(),
+                    cloud_options,
+                )
+                .await?;
+
+                PolarsResult::Ok((file_info, FileScanIR::Avro))
+            }
+            .map_err(|e| e.context(failed_here!(avro scan)))?,
+            #[cfg(feature = "orc")]
+            FileScanDsl::Orc => {
+                let first_scan_source =
+                    require_first_source("failed to retrieve first file schema (orc)", "")?;
+
+                if verbose() {
+                    eprintln!(
+                        "sourcing orc scan file schema from: '{}'",
+                        first_scan_source.to_include_path_name()
+                    )
+                }
+
+                let file_info = scans::orc_file_info(
+                    sources,
+                    first_scan_source,
+                    unified_scan_args.row_index.as_ref(),
+                    cloud_options,
+                )
+                .await?;
+
+                PolarsResult::Ok((file_info, FileScanIR::Orc))
+            }
+            .map_err(|e| e.context(failed_here!(orc scan)))?,
+            #[cfg(feature = "flight")]
+            FileScanDsl::Flight { options } => {
+                let first_scan_source =
+                    require_first_source("failed to retrieve flight schema", "")?;
+
+                let file_info = scans::flight_file_info(
+                    first_scan_source,
+                    &options,
+                    unified_scan_args.schema.clone(),
+                    unified_scan_args.row_index.as_ref(),
+                )
+                .await?;
+
+                PolarsResult::Ok((file_info, FileScanIR::Flight { options }))
+            }
+            .map_err(|e| e.context(failed_here!(flight scan)))?,
             #[cfg(feature = "csv")]
             FileScanDsl::Csv { mut options } => {
                 {
+                    if options.sniff_dialect {
+                        let first_scan_source =
+                            require_first_source("failed to sniff csv dialect", "")?;
+
+                        scans::csv_sniff_dialect(
+                            sources,
+                            first_scan_source,
+                            Arc::make_mut(&mut options),
+                            cloud_options,
+                        )
+                        .await?;
+                    }
+
                     // TODO: This is a hack. We conditionally set `allow_missing_columns` to
                     // mimic existing behavior, but this should be taken from a user provided
                     // parameter instead.
@@ -396,6 +396,53 @@ pub fn ir_function_to_dsl(input: Vec<Expr>, function: IRFunctionExpr) -> Expr {
                 IE::Storage => E::Storage,
             })
         },
+        #[cfg(feature = "dtype-map")]
+        IF::MapExpr(f) => {
+            use {IRMapFunction as IM, MapFunction as M};
+            F::MapExpr(match f {
+                IM::Get => M::Get,
+                IM::ContainsKey => M::ContainsKey,
+                IM::Keys => M::Keys,
+                IM::Values => M::Values,
+                IM::FromLists => M::FromLists,
+            })
+        },
+        #[cfg(feature = "dtype-variant")]
+        IF::VariantExpr(f) => {
+            use {IRVariantFunction as IV, VariantFunction as V};
+            F::VariantExpr(match f {
+                IV::Get { path, dtype } => V::Get {
+                    path,
+                    dtype: dtype.into(),
+                },
+                IV::TypeName => V::TypeName,
+                IV::ToJson => V::ToJson,
+            })
+        },
+        #[cfg(feature = "geo")]
+        IF::GeoExpr(f) => {
+            use {GeoFunction as G, IRGeoFunction as IG};
+            F::GeoExpr(match f {
+                IG::Area => G::Area,
+                IG::Length => G::Length,
+                IG::Centroid => G::Centroid,
+                IG::Bbox => G::Bbox,
+                IG::Distance => G::Distance,
+                IG::Contains => G::Contains,
+                IG::Intersects => G::Intersects,
+                IG::ToWkt => G::ToWkt,
+                IG::FromWkt => G::FromWkt,
+            })
+        },
+        #[cfg(feature = "dtype-ip")]
+        IF::IpExpr(f) => {
+            use {IRIpFunction as II, IpFunction as Ip};
+            F::IpExpr(match f {
+                II::Contains(cidr) => Ip::Contains(cidr),
+                II::Network(prefix_len) => Ip::Network(prefix_len),
+                II::Broadcast(prefix_len) => Ip::Broadcast(prefix_len),
+            })
+        },
         IF::ListExpr(f) => {
             use {IRListFunction as IL, ListFunction as L};
             F::ListExpr(match f {
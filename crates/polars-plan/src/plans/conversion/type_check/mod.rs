@@ -105,6 +105,10 @@ impl OptimizationRule for TypeCheckRule {
                             polars_bail!(InvalidOperation: "duplicate parquet field overwrite for struct field `{name}`");
                         }
 
+                        if o.variant_shredding.is_some() && !dtype.is_variant() {
+                            polars_bail!(InvalidOperation: "cannot give variant shredding for non-variant column `{name}`");
+                        }
+
                         push_children(&mut stack, &o.children, dtype)?;
                     }
 
@@ -114,6 +118,9 @@ impl OptimizationRule for TypeCheckRule {
                                 if o.name.is_some() {
                                     polars_bail!(InvalidOperation: "parquet field overwrite list child cannot have name");
                                 };
+                                if o.variant_shredding.is_some() {
+                                    polars_bail!(InvalidOperation: "variant shredding is only supported for top-level columns");
+                                }
                                 push_children(&mut stack, &o.children, dt)?;
                             },
                             Item::Struct(fields, os) => {
@@ -135,6 +142,10 @@ impl OptimizationRule for TypeCheckRule {
                                         polars_bail!(InvalidOperation: "duplicate parquet field overwrite for struct field `{name}`");
                                     }
 
+                                    if o.variant_shredding.is_some() {
+                                        polars_bail!(InvalidOperation: "variant shredding is only supported for top-level columns");
+                                    }
+
                                     push_children(&mut stack, &o.children, field.dtype())?;
                                 }
                             },
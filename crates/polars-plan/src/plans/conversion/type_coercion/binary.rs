@@ -209,6 +209,24 @@ pub(super) fn process_binary(
 
     if op.is_arithmetic() {
         match (&type_left, &type_right) {
+            #[cfg(all(feature = "dtype-interval", feature = "offset_by"))]
+            (Date | Datetime(_, _), Interval) if matches!(op, Operator::Plus | Operator::Minus) => {
+                return Ok(Some(interval_offset_by(
+                    expr_arena,
+                    node_left,
+                    node_right,
+                    op == Operator::Minus,
+                )));
+            },
+            #[cfg(all(feature = "dtype-interval", feature = "offset_by"))]
+            (Interval, Date | Datetime(_, _)) if op == Operator::Plus => {
+                return Ok(Some(interval_offset_by(
+                    expr_arena, node_right, node_left, false,
+                )));
+            },
+            // Durations are cast to intervals below.
+            #[cfg(feature = "dtype-interval")]
+            (Duration(_), Interval) | (Interval, Duration(_)) => {},
             (Duration(_), Duration(_)) => return Ok(None),
             (Duration(_), r) if r.is_primitive_numeric() => return Ok(None),
             (String, a) | (a, String) if a.is_primitive_numeric() => {
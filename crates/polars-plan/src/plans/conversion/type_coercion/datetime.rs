@@ -2,6 +2,8 @@ use polars_core::chunked_array::cast::CastOptions;
 use polars_core::prelude::*;
 use polars_time::Duration;
 use polars_utils::arena::Arena;
+#[cfg(all(feature = "dtype-interval", feature = "offset_by"))]
+use polars_utils::arena::Node;
 
 use crate::plans::{AExpr, ExprIR, IRFunctionExpr, IRTemporalFunction, LiteralValue};
 
@@ -134,6 +136,37 @@ pub(super) fn convert_tz(
     Ok(())
 }
 
+/// Rewrite `date + interval` (or `date - interval`) into `date.dt.offset_by(interval)`.
+///
+/// Adding calendar months and days depends on the date it's added to, so this can't be done
+/// with plain arithmetic on the physical values.
+#[cfg(all(feature = "dtype-interval", feature = "offset_by"))]
+pub(super) fn interval_offset_by(
+    expr_arena: &mut Arena<AExpr>,
+    node_date: Node,
+    node_interval: Node,
+    negate: bool,
+) -> AExpr {
+    let mut offset = ExprIR::from_node(node_interval, expr_arena);
+    if negate {
+        let function = IRFunctionExpr::Negate;
+        let options = function.function_options();
+        let node = expr_arena.add(AExpr::Function {
+            input: vec![offset],
+            function,
+            options,
+        });
+        offset = ExprIR::from_node(node, expr_arena);
+    }
+    let function = IRFunctionExpr::TemporalExpr(IRTemporalFunction::OffsetBy);
+    let options = function.function_options();
+    AExpr::Function {
+        input: vec![ExprIR::from_node(node_date, expr_arena), offset],
+        function,
+        options,
+    }
+}
+
 #[doc(hidden)]
 #[cfg(feature = "dtype-datetime")]
 // Determine the output dtype, given a `Date`/`Datetime` dtype and optional time unit, time zone, and
@@ -14,6 +14,8 @@ use binary::process_binary;
     any(feature = "dtype-date", feature = "dtype-datetime")
 ))]
 use datetime::coerce_temporal_dt;
+#[cfg(all(feature = "dtype-interval", feature = "offset_by"))]
+use datetime::interval_offset_by;
 #[cfg(all(feature = "range", feature = "dtype-datetime"))]
 use datetime::{ensure_datetime, ensure_int, temporal_range_output_type};
 use polars_core::chunked_array::cast::CastOptions;
@@ -36,6 +36,7 @@ fn count_all_rows_csv(
                 parse_options.quote_char,
                 parse_options.comment_prefix.as_ref(),
                 parse_options.eol_char,
+                parse_options.encoding,
                 options.has_header,
                 options.skip_lines,
                 options.skip_rows,
@@ -49,6 +50,7 @@ fn count_all_rows_csv(
                     parse_options.quote_char,
                     parse_options.comment_prefix.as_ref(),
                     parse_options.eol_char,
+                    parse_options.encoding,
                     options.has_header,
                     options.skip_lines,
                     options.skip_rows,
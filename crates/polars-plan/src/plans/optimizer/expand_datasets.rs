@@ -288,6 +288,15 @@ pub(super) fn expand_datasets(
                             #[cfg(feature = "json")]
                             FileScanDsl::NDJson { options } => FileScanIR::NDJson { options },
 
+                            #[cfg(feature = "avro")]
+                            FileScanDsl::Avro => FileScanIR::Avro,
+
+                            #[cfg(feature = "orc")]
+                            FileScanDsl::Orc => FileScanIR::Orc,
+
+                            #[cfg(feature = "flight")]
+                            FileScanDsl::Flight { options } => FileScanIR::Flight { options },
+
                             #[cfg(feature = "python")]
                             FileScanDsl::PythonDataset { dataset_object } => {
                                 FileScanIR::PythonDataset {
@@ -451,6 +451,12 @@ impl ProjectionPushDown {
                     FileScanIR::NDJson { .. } => true,
                     #[cfg(feature = "ipc")]
                     FileScanIR::Ipc { .. } => true,
+                    #[cfg(feature = "avro")]
+                    FileScanIR::Avro => true,
+                    #[cfg(feature = "orc")]
+                    FileScanIR::Orc => true,
+                    #[cfg(feature = "flight")]
+                    FileScanIR::Flight { .. } => true,
                     #[cfg(feature = "csv")]
                     FileScanIR::Csv { .. } => true,
                     #[cfg(feature = "parquet")]
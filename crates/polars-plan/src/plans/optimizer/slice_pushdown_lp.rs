@@ -234,6 +234,15 @@ impl SlicePushDown {
                 #[cfg(feature = "json")]
                 FileScanIR::NDJson { .. } => true,
 
+                #[cfg(feature = "avro")]
+                FileScanIR::Avro => true,
+
+                #[cfg(feature = "orc")]
+                FileScanIR::Orc => true,
+
+                #[cfg(feature = "flight")]
+                FileScanIR::Flight { .. } => true,
+
                 #[cfg(feature = "python")]
                 FileScanIR::PythonDataset { .. } => true,
 
@@ -969,9 +969,17 @@ impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<CsvEncoding> {
         let parsed = match &*ob.extract::<PyBackedStr>()? {
             "utf8" => CsvEncoding::Utf8,
             "utf8-lossy" => CsvEncoding::LossyUtf8,
+            "latin1" => CsvEncoding::Latin1,
+            "windows-1252" => CsvEncoding::Windows1252,
+            "utf16-le" => CsvEncoding::Utf16Le,
+            "utf16-be" => CsvEncoding::Utf16Be,
+            "utf32-le" => CsvEncoding::Utf32Le,
+            "utf32-be" => CsvEncoding::Utf32Be,
+            "shift-jis" => CsvEncoding::ShiftJis,
             v => {
                 return Err(PyValueError::new_err(format!(
-                    "csv `encoding` must be one of {{'utf8', 'utf8-lossy'}}, got {v}",
+                    "`encoding` must be one of {{'utf8', 'utf8-lossy', 'latin1', 'windows-1252', \
+                    'utf16-le', 'utf16-be', 'utf32-le', 'utf32-be', 'shift-jis'}}, got {v}",
                 )));
             },
         };
@@ -11,6 +11,7 @@ use pyo3::pybacked::PyBackedStr;
 
 use super::PyDataFrame;
 use crate::conversion::Wrap;
+use crate::error::PyPolarsErr;
 use crate::file::{get_file_like, get_mmap_bytes_reader, get_mmap_bytes_reader_and_path};
 use crate::prelude::PyCompatLevel;
 use crate::utils::EnterPolarsExt;
@@ -66,6 +67,7 @@ impl PyDataFrame {
             offset,
         });
         let quote_char = quote_char.and_then(|s| s.as_bytes().first().copied());
+        let separator = CsvSeparator::new_from_str(separator).map_err(PyPolarsErr::from)?;
 
         let overwrite_dtype = overwrite_dtype.map(|overwrite_dtype| {
             overwrite_dtype
@@ -108,7 +110,7 @@ impl PyDataFrame {
                 .with_raise_if_empty(raise_if_empty)
                 .with_parse_options(
                     CsvParseOptions::default()
-                        .with_separator(separator.as_bytes()[0])
+                        .with_separator(separator)
                         .with_encoding(encoding.0)
                         .with_missing_is_null(!missing_utf8_is_empty_string)
                         .with_comment_prefix(comment_prefix)
@@ -6,6 +6,8 @@ use arrow::ffi::export_iterator;
 use either::Either;
 use parking_lot::Mutex;
 use polars::io::RowIndex;
+#[cfg(feature = "avro")]
+use polars::io::avro::AvroCompression;
 use polars::time::*;
 use polars_core::prelude::*;
 #[cfg(feature = "parquet")]
@@ -189,12 +191,7 @@ impl PyLazyFrame {
     ) -> PyResult<Self> {
         let null_values = null_values.map(|w| w.0);
         let quote_char = quote_char.and_then(|s| s.as_bytes().first()).copied();
-        let separator = separator
-            .as_bytes()
-            .first()
-            .ok_or_else(|| polars_err!(InvalidOperation: "`separator` cannot be empty"))
-            .copied()
-            .map_err(PyPolarsErr::from)?;
+        let separator = CsvSeparator::new_from_str(separator).map_err(PyPolarsErr::from)?;
         let eol_char = eol_char
             .as_bytes()
             .first()
@@ -287,7 +284,8 @@ impl PyLazyFrame {
     #[cfg(feature = "parquet")]
     #[staticmethod]
     #[pyo3(signature = (
-        sources, schema, scan_options, parallel, low_memory, use_statistics
+        sources, schema, scan_options, parallel, low_memory, use_statistics,
+        keep_dictionaries=false, keep_runs=false
     ))]
     fn new_from_parquet(
         sources: Wrap<ScanSources>,
@@ -296,6 +294,8 @@ impl PyLazyFrame {
         parallel: Wrap<ParallelStrategy>,
         low_memory: bool,
         use_statistics: bool,
+        keep_dictionaries: bool,
+        keep_runs: bool,
     ) -> PyResult<Self> {
         use crate::utils::to_py_err;
 
@@ -306,6 +306,8 @@ impl PyLazyFrame {
             parallel,
             low_memory,
             use_statistics,
+            keep_dictionaries,
+            keep_runs,
         };
 
         let sources = sources.0;
@@ -738,7 +740,7 @@ impl PyLazyFrame {
         compression_level: Option<u32>,
         check_extension: bool,
         include_header: bool,
-        separator: u8,
+        separator: &str,
         line_terminator: Wrap<PlSmallStr>,
         quote_char: u8,
         batch_size: NonZeroUsize,
@@ -752,6 +754,7 @@ impl PyLazyFrame {
         quote_style: Option<Wrap<QuoteStyle>>,
     ) -> PyResult<PyLazyFrame> {
         let quote_style = quote_style.map_or(QuoteStyle::default(), |wrap| wrap.0);
+        let separator = CsvSeparator::new_from_str(separator).map_err(PyPolarsErr::from)?;
         let null_value = null_value
             .map(|x| x.0)
             .unwrap_or(SerializeOptions::default().null);
@@ -826,6 +829,35 @@ impl PyLazyFrame {
         .map_err(Into::into)
     }
 
+    #[cfg(feature = "avro")]
+    #[pyo3(signature = (target, compression, name, sink_options))]
+    fn sink_avro(
+        &self,
+        py: Python<'_>,
+        target: PyFileSinkDestination,
+        compression: Wrap<Option<AvroCompression>>,
+        name: String,
+        sink_options: PySinkOptions,
+    ) -> PyResult<PyLazyFrame> {
+        let options = AvroWriterOptions {
+            compression: compression.0.map(Into::into),
+            name: name.into(),
+        };
+
+        let target = target.extract_file_sink_destination()?;
+        let unified_sink_args = sink_options.extract_unified_sink_args(target.cloud_scheme())?;
+
+        py.enter_polars(|| {
+            self.ldf
+                .read()
+                .clone()
+                .sink(target, FileWriteFormat::Avro(options), unified_sink_args)
+                .into()
+        })
+        .map(Into::into)
+        .map_err(Into::into)
+    }
+
     #[pyo3(signature = (function, maintain_order, chunk_size))]
     pub fn sink_batches(
         &self,
@@ -1582,12 +1614,22 @@ impl<'a, 'py> FromPyObject<'a, 'py> for Wrap<polars_io::parquet::write::ParquetF
             .map(|v| v.extract::<bool>())
             .transpose()?;
 
+        let variant_shredding = PyDictMethods::get_item(&parsed, "variant_shredding")?
+            .map(|v| v.extract::<Vec<(String, Wrap<DataType>)>>())
+            .transpose()?;
+        let variant_shredding = variant_shredding.map(|v| {
+            v.into_iter()
+                .map(|(path, dtype)| (path.into(), dtype.0))
+                .collect()
+        });
+
         Ok(Wrap(ParquetFieldOverwrites {
             name,
             children,
             field_id,
             metadata,
             required,
+            variant_shredding,
         }))
     }
 }
@@ -1,6 +1,6 @@
 #[cfg(feature = "iejoin")]
 use polars::prelude::JoinTypeOptionsIR;
-use polars::prelude::deletion::DeletionFilesList;
+use polars::prelude::deletion::{DeletionFilesList, DeltaDeletionVectorLocation};
 use polars::prelude::python_dsl::PythonScanSource;
 use polars::prelude::{ColumnMapping, PredicateFileSkip};
 use polars_core::prelude::IdxSize;
@@ -11,7 +11,7 @@ use polars_plan::prelude::{FileScanIR, FunctionIR, PythonPredicate, UnifiedScanA
 use pyo3::IntoPyObjectExt;
 use pyo3::exceptions::{PyNotImplementedError, PyValueError};
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyList, PyString};
+use pyo3::types::{PyBytes, PyDict, PyList, PyString};
 
 use super::expr_nodes::PyGroupbyOptions;
 use crate::PyDataFrame;
@@ -41,6 +41,12 @@ fn scan_type_to_pyobject(
         },
         #[cfg(feature = "ipc")]
         FileScanIR::Ipc { .. } => Err(PyNotImplementedError::new_err("ipc scan")),
+        #[cfg(feature = "avro")]
+        FileScanIR::Avro => Err(PyNotImplementedError::new_err("avro scan")),
+        #[cfg(feature = "orc")]
+        FileScanIR::Orc => Err(PyNotImplementedError::new_err("orc scan")),
+        #[cfg(feature = "flight")]
+        FileScanIR::Flight { .. } => Err(PyNotImplementedError::new_err("flight scan")),
         #[cfg(feature = "json")]
         FileScanIR::NDJson { options, .. } => {
             let options = serde_json::to_string(options)
@@ -135,6 +141,7 @@ impl PyFileOptions {
     /// One of:
     /// * None
     /// * ("iceberg-position-delete", dict[int, list[str]])
+    /// * ("delta-deletion-vector", dict[int, bytes | tuple[str, int, int]])
     #[getter]
     fn deletion_files(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
         Ok(match &self.inner.deletion_files {
@@ -152,6 +159,28 @@ impl PyFileOptions {
                     .into_any()
                     .unbind()
             },
+
+            Some(DeletionFilesList::DeltaDeletionVector(vectors)) => {
+                let out = PyDict::new(py);
+
+                for (k, v) in vectors.iter() {
+                    match &v.location {
+                        DeltaDeletionVectorLocation::Inline(bytes) => {
+                            out.set_item(*k, PyBytes::new(py, bytes))?
+                        },
+                        DeltaDeletionVectorLocation::File {
+                            path,
+                            offset,
+                            size_in_bytes,
+                        } => out.set_item(*k, (path, *offset, *size_in_bytes))?,
+                    }
+                }
+
+                ("delta-deletion-vector", out)
+                    .into_pyobject(py)?
+                    .into_any()
+                    .unbind()
+            },
         })
     }
 
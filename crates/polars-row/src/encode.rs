@@ -7,7 +7,7 @@ use arrow::array::{
 };
 use arrow::bitmap::Bitmap;
 use arrow::datatypes::ArrowDataType;
-use arrow::types::{NativeType, Offset};
+use arrow::types::{NativeType, Offset, i256};
 use polars_dtype::categorical::CatNative;
 use polars_utils::float16::pf16;
 
@@ -959,6 +959,7 @@ pub fn fixed_size(
             Some(RowEncodingContext::Decimal(precision)) => decimal::len_from_precision(*precision),
             _ => unreachable!(),
         },
+        D::Decimal256(_, _) => i256::ENCODED_LEN,
 
         D::Float16 => pf16::ENCODED_LEN,
         D::Float32 => f32::ENCODED_LEN,
@@ -7,6 +7,7 @@ macro_rules! with_match_arrow_primitive_type {(
 ) => ({
     macro_rules! __with_ty__ {( $_ $T:ident ) => ( $($body)* )}
     use arrow::datatypes::ArrowDataType::*;
+    use arrow::types::i256;
     use polars_utils::float16::pf16;
     match $key_type {
         Int8 => __with_ty__! { i8 },
@@ -14,6 +15,7 @@ macro_rules! with_match_arrow_primitive_type {(
         Int32 => __with_ty__! { i32 },
         Int64 => __with_ty__! { i64 },
         Int128 => __with_ty__! { i128 },
+        Decimal256(_, _) => __with_ty__! { i256 },
         UInt8 => __with_ty__! { u8 },
         UInt16 => __with_ty__! { u16 },
         UInt32 => __with_ty__! { u32 },
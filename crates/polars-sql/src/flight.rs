@@ -0,0 +1,28 @@
+use std::sync::Mutex;
+
+use polars_lazy::frame::LazyFrameFlightResolver;
+
+use crate::SQLContext;
+
+impl SQLContext {
+    /// Turn this context into a resolver for a Flight server.
+    ///
+    /// The tables registered in the context are served by name, and any other ticket is executed
+    /// as a SQL query against the context.
+    pub fn into_flight_resolver(self) -> LazyFrameFlightResolver {
+        let tables = self
+            .table_map
+            .iter()
+            .map(|(name, lf)| (name.clone(), lf.clone()))
+            .collect::<Vec<_>>();
+
+        let ctx = Mutex::new(self);
+        let resolver = LazyFrameFlightResolver::new()
+            .with_query_handler(move |query| ctx.lock().unwrap().execute(query));
+
+        for (name, lf) in tables {
+            resolver.register(name, lf);
+        }
+        resolver
+    }
+}
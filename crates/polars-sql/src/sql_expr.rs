@@ -18,6 +18,8 @@ use rand::Rng;
 use rand::distr::Alphanumeric;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+#[cfg(feature = "dtype-map")]
+use sqlparser::ast::Map as SQLMap;
 use sqlparser::ast::{
     AccessExpr, BinaryOperator as SQLBinaryOperator, CastFormat, CastKind, DataType as SQLDataType,
     DateTimeField, Expr as SQLExpr, Function as SQLFunction, Ident, Interval, Query as Subquery,
@@ -149,7 +151,7 @@ impl SQLExprVisitor<'_> {
                 subquery,
                 negated,
             } => self.visit_in_subquery(expr, subquery, *negated),
-            SQLExpr::Interval(interval) => Ok(lit(interval_to_duration(interval, true)?)),
+            SQLExpr::Interval(interval) => interval_to_lit(interval),
             SQLExpr::IsDistinctFrom(e1, e2) => {
                 Ok(self.visit_expr(e1)?.neq_missing(self.visit_expr(e2)?))
             },
@@ -194,6 +196,8 @@ impl SQLExprVisitor<'_> {
                 });
                 self.visit_like(*negated, expr, pattern, &escape_str, true)
             },
+            #[cfg(feature = "dtype-map")]
+            SQLExpr::Map(map) => self.visit_map_expr(map),
             SQLExpr::Nested(expr) => self.visit_expr(expr),
             SQLExpr::Position { expr, r#in } => Ok(
                 // note: SQL is 1-indexed
@@ -389,6 +393,10 @@ impl SQLExprVisitor<'_> {
     fn visit_subscript(&mut self, expr: &SQLExpr, subscript: &Subscript) -> PolarsResult<Expr> {
         let expr = self.visit_expr(expr)?;
         Ok(match subscript {
+            #[cfg(feature = "dtype-map")]
+            Subscript::Index { index } if self.is_map_subscript(&expr, index) => {
+                expr.map_().get(self.visit_expr(index)?)
+            },
             Subscript::Index { index } => {
                 let idx = adjust_one_indexed_param(self.visit_expr(index)?, true);
                 expr.list().get(idx, true)
@@ -399,6 +407,57 @@ impl SQLExprVisitor<'_> {
         })
     }
 
+    /// Whether a subscript addresses a map by key (eg: "map_col['k']") rather than a list by index.
+    #[cfg(feature = "dtype-map")]
+    fn is_map_subscript(&self, expr: &Expr, index: &SQLExpr) -> bool {
+        // list indices are integers, so a string subscript can only be a map key
+        if matches!(
+            index,
+            SQLExpr::Value(ValueWithSpan {
+                value: SQLValue::SingleQuotedString(_),
+                ..
+            })
+        ) {
+            return true;
+        }
+        match expr {
+            Expr::Column(name) => self
+                .active_schema
+                .is_some_and(|schema| matches!(schema.get(name), Some(DataType::Map(_, _)))),
+            Expr::Literal(lv) => matches!(lv.get_datatype(), DataType::Map(_, _)),
+            _ => false,
+        }
+    }
+
+    /// Visit a SQL `MAP` literal (eg: "MAP {'a': 1, 'b': 2}").
+    #[cfg(feature = "dtype-map")]
+    fn visit_map_expr(&mut self, map: &SQLMap) -> PolarsResult<Expr> {
+        let (keys, values): (Vec<SQLExpr>, Vec<SQLExpr>) = map
+            .entries
+            .iter()
+            .map(|entry| ((*entry.key).clone(), (*entry.value).clone()))
+            .unzip();
+        let keys = self
+            .array_expr_to_series(&keys)?
+            .with_name(PlSmallStr::from_static("key"));
+        let values = self
+            .array_expr_to_series(&values)?
+            .with_name(PlSmallStr::from_static("value"));
+        polars_ensure!(
+            !keys.has_nulls(),
+            SQLInterface: "MAP keys cannot be NULL"
+        );
+
+        let entries = StructChunked::from_series(
+            PlSmallStr::from_static("entries"),
+            keys.len(),
+            [keys, values].iter(),
+        )?
+        .into_series();
+        let map = entries.implode()?.into_map()?;
+        Ok(lit(map.into_series()))
+    }
+
     /// Handle implicit temporal string comparisons.
     ///
     /// eg: clauses such as -
@@ -1248,6 +1307,24 @@ pub(crate) fn interval_to_duration(interval: &Interval, fixed: bool) -> PolarsRe
     }
 }
 
+/// Convert a standalone interval into a literal.
+///
+/// Intervals with calendar months are only representable as a fixed duration if the
+/// `dtype-interval` feature is enabled, in which case they become an `Interval` literal.
+fn interval_to_lit(interval: &Interval) -> PolarsResult<Expr> {
+    #[cfg(feature = "dtype-interval")]
+    {
+        let duration = interval_to_duration(interval, false)?;
+        if duration.months() != 0 {
+            let Some(value) = duration.to_interval() else {
+                polars_bail!(SQLSyntax: "interval is out of range: {}", interval)
+            };
+            return Ok(lit(Scalar::new_interval(value)));
+        }
+    }
+    Ok(lit(interval_to_duration(interval, true)?))
+}
+
 pub(crate) fn parse_sql_expr(
     expr: &SQLExpr,
     ctx: &mut SQLContext,
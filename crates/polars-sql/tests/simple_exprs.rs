@@ -1039,3 +1039,36 @@ fn test_struct_field_selection() {
     .unwrap();
     assert!(df_sql.equals(&df_expected));
 }
+
+#[test]
+#[cfg(feature = "dtype-map")]
+fn test_map_subscript() {
+    let df = df! {
+        "k" => ["a", "b"],
+        "v" => [1i64, 2],
+    }
+    .unwrap()
+    .lazy()
+    .select([map_from_lists(col("k").implode(), col("v").implode()).alias("m")])
+    .collect()
+    .unwrap();
+
+    let mut context = SQLContext::new();
+    context.register("df", df.lazy());
+
+    let sql = r#"
+      SELECT
+        m['b'] AS b,
+        m['z'] AS z,
+        MAP {'x': 10, 'y': 20}['y'] AS y
+      FROM df
+    "#;
+    let df_sql = context.execute(sql).unwrap().collect().unwrap();
+    let df_expected = df! {
+        "b" => [Some(2i64)],
+        "z" => [None::<i64>],
+        "y" => [Some(20i64)],
+    }
+    .unwrap();
+    assert!(df_sql.equals_missing(&df_expected));
+}
@@ -0,0 +1,57 @@
+use std::sync::Arc;
+
+use arrow::io::avro::avro_schema::schema::Record;
+use polars_error::PolarsResult;
+use polars_io::avro::{AvroCompression, write_avro_header};
+use tokio::io::AsyncWriteExt as _;
+
+use crate::async_executor;
+use crate::nodes::io_sinks::components::sink_morsel::SinkMorselPermit;
+use crate::nodes::io_sinks::writers::avro::morsel_serializer::MorselSerializer;
+use crate::nodes::io_sinks::writers::interface::FileOpenTaskHandle;
+
+pub struct IOWriter {
+    pub file: FileOpenTaskHandle,
+    pub filled_serializer_rx: tokio::sync::mpsc::Receiver<(
+        async_executor::AbortOnDropHandle<PolarsResult<MorselSerializer>>,
+        SinkMorselPermit,
+    )>,
+    pub reuse_serializer_tx: tokio::sync::mpsc::Sender<MorselSerializer>,
+    pub record: Arc<Record>,
+    pub compression: Option<AvroCompression>,
+}
+
+impl IOWriter {
+    pub async fn run(self) -> PolarsResult<()> {
+        let IOWriter {
+            file,
+            mut filled_serializer_rx,
+            reuse_serializer_tx,
+            record,
+            compression,
+        } = self;
+
+        let (writable, sync_on_close) = file.await?;
+
+        // Note: Avro compresses the data blocks, so there is no external compression here.
+        let mut writer = writable.try_into_async_writeable()?;
+
+        let mut header = vec![];
+        write_avro_header(&mut header, &record, compression)?;
+        writer.write_all(&header).await?;
+
+        while let Some((handle, permit)) = filled_serializer_rx.recv().await {
+            let serializer = handle.await?;
+
+            writer.write_all(&serializer.serialized_data).await?;
+
+            drop(permit);
+
+            let _ = reuse_serializer_tx.send(serializer).await;
+        }
+
+        writer.close(sync_on_close).await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,107 @@
+use std::sync::Arc;
+
+use arrow::datatypes::ArrowSchemaRef;
+use arrow::io::avro::avro_schema::schema::Record;
+use arrow::record_batch::RecordBatch;
+use polars_core::frame::DataFrame;
+use polars_core::prelude::CompatLevel;
+use polars_error::PolarsResult;
+use polars_io::avro::{AvroBlockEncoder, AvroCompression};
+
+use crate::async_executor::{self, TaskPriority};
+use crate::async_primitives::connector;
+use crate::nodes::io_sinks::components::par_utils::rechunk_par;
+use crate::nodes::io_sinks::components::sink_morsel::{SinkMorsel, SinkMorselPermit};
+
+pub struct MorselSerializerPipeline {
+    pub morsel_rx: connector::Receiver<SinkMorsel>,
+    pub filled_serializer_tx: tokio::sync::mpsc::Sender<(
+        async_executor::AbortOnDropHandle<PolarsResult<MorselSerializer>>,
+        SinkMorselPermit,
+    )>,
+    pub reuse_serializer_rx: tokio::sync::mpsc::Receiver<MorselSerializer>,
+    pub max_serializers: usize,
+    pub arrow_schema: ArrowSchemaRef,
+    pub record: Arc<Record>,
+    pub compression: Option<AvroCompression>,
+}
+
+impl MorselSerializerPipeline {
+    pub async fn run(self) {
+        let MorselSerializerPipeline {
+            mut morsel_rx,
+            filled_serializer_tx,
+            mut reuse_serializer_rx,
+            max_serializers,
+            arrow_schema,
+            record,
+            compression,
+        } = self;
+
+        let mut num_created_serializers: usize = 0;
+
+        while let Ok(morsel) = morsel_rx.recv().await {
+            let morsel_serializer: MorselSerializer =
+                if let Ok(serializer) = reuse_serializer_rx.try_recv() {
+                    serializer
+                } else if num_created_serializers < max_serializers {
+                    num_created_serializers += 1;
+                    MorselSerializer {
+                        serialized_data: vec![],
+                        encoder: AvroBlockEncoder::default(),
+                        arrow_schema: Arc::clone(&arrow_schema),
+                        record: Arc::clone(&record),
+                        compression,
+                    }
+                } else if let Some(serializer) = reuse_serializer_rx.recv().await {
+                    serializer
+                } else {
+                    break;
+                };
+
+            let (df, morsel_permit) = morsel.into_inner();
+
+            let handle = async_executor::AbortOnDropHandle::new(async_executor::spawn(
+                TaskPriority::High,
+                morsel_serializer.serialize_morsel(df),
+            ));
+
+            if filled_serializer_tx
+                .send((handle, morsel_permit))
+                .await
+                .is_err()
+            {
+                break;
+            }
+        }
+    }
+}
+
+pub struct MorselSerializer {
+    pub serialized_data: Vec<u8>,
+    encoder: AvroBlockEncoder,
+    arrow_schema: ArrowSchemaRef,
+    record: Arc<Record>,
+    compression: Option<AvroCompression>,
+}
+
+impl MorselSerializer {
+    pub async fn serialize_morsel(mut self, mut df: DataFrame) -> PolarsResult<Self> {
+        rechunk_par(unsafe { df.columns_mut_retain_schema() }).await;
+
+        self.serialized_data.clear();
+
+        let height = df.height();
+        let arrays = df.rechunk_into_arrow(CompatLevel::oldest());
+        let chunk = RecordBatch::try_new(height, Arc::clone(&self.arrow_schema), arrays)?;
+
+        self.encoder.write_block(
+            &mut self.serialized_data,
+            &chunk,
+            &self.record,
+            self.compression,
+        )?;
+
+        Ok(self)
+    }
+}
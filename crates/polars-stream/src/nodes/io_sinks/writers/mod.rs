@@ -7,6 +7,8 @@ use polars_utils::IdxSize;
 
 use crate::nodes::io_sinks::writers::interface::FileWriterStarter;
 
+#[cfg(feature = "avro")]
+mod avro;
 #[cfg(feature = "csv")]
 mod csv;
 pub mod interface;
@@ -79,11 +81,29 @@ pub fn create_file_writer_starter(
                 initialized_state: Default::default(),
             },
         ) as _,
+        #[cfg(feature = "avro")]
+        FileWriteFormat::Avro(options) => {
+            use polars_core::prelude::CompatLevel;
+            use polars_io::avro::avro_record;
+            use polars_io::schema_to_arrow_checked;
+
+            Arc::new(crate::nodes::io_sinks::writers::avro::AvroWriterStarter {
+                options: options.clone(),
+                schema: file_schema.clone(),
+                arrow_schema: Arc::new(schema_to_arrow_checked(
+                    file_schema.as_ref(),
+                    CompatLevel::oldest(),
+                    "avro",
+                )?),
+                record: Arc::new(avro_record(file_schema.as_ref(), options.name.to_string())?),
+            }) as _
+        },
         #[cfg(not(any(
             feature = "parquet",
             feature = "ipc",
             feature = "csv",
-            feature = "json"
+            feature = "json",
+            feature = "avro"
         )))]
         _ => panic!("no enum variants on FileType (hint: missing feature flags?)"),
     })
@@ -56,10 +56,11 @@ impl IOWriter {
             let EncodedRowGroup {
                 num_rows,
                 data,
+                sorting_columns,
                 morsel_permit,
             } = handle.await?;
             assert_eq!(data.len(), num_leaf_columns);
-            parquet_writer.write_row_group(num_rows as u64, &data)?;
+            parquet_writer.write_row_group(num_rows as u64, &data, sorting_columns)?;
             drop(data);
             drop(morsel_permit);
         }
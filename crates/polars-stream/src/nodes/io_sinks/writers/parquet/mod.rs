@@ -2,10 +2,13 @@ use std::sync::Arc;
 
 use arrow::datatypes::ArrowSchemaRef;
 use polars_error::PolarsResult;
+#[cfg(feature = "dtype-variant")]
+use polars_io::parquet::write::VariantShredding;
 use polars_io::pl_async;
 use polars_io::prelude::{ParquetWriteOptions, get_column_write_options};
 use polars_parquet::write::{
-    ColumnWriteOptions, CompressedPage, SchemaDescriptor, Version, WriteOptions, to_parquet_schema,
+    ColumnWriteOptions, CompressedPage, SchemaDescriptor, SortingColumn, Version, WriteOptions,
+    to_parquet_schema,
 };
 use polars_utils::IdxSize;
 use polars_utils::index::NonZeroIdxSize;
@@ -33,13 +36,17 @@ pub struct ParquetWriterStarter {
 
 #[derive(Clone)]
 pub struct InitializedState {
+    arrow_schema: ArrowSchemaRef,
     column_options: Arc<Vec<ColumnWriteOptions>>,
     schema_descriptor: Arc<SchemaDescriptor>,
+    #[cfg(feature = "dtype-variant")]
+    variant_shredding: Arc<VariantShredding>,
 }
 
 struct EncodedRowGroup {
     num_rows: usize,
     data: Vec<Vec<CompressedPage>>,
+    sorting_columns: Option<Vec<SortingColumn>>,
     morsel_permit: SinkMorselPermit,
 }
 
@@ -81,23 +88,34 @@ impl FileWriterStarter for ParquetWriterStarter {
         num_pipelines: std::num::NonZeroUsize,
     ) -> PolarsResult<async_executor::JoinHandle<PolarsResult<()>>> {
         let InitializedState {
+            arrow_schema,
             column_options,
             schema_descriptor,
+            #[cfg(feature = "dtype-variant")]
+            variant_shredding,
         } = {
             let mut initialized_state = self.initialized_state.lock().unwrap();
 
             if initialized_state.is_none() {
+                #[cfg_attr(not(feature = "dtype-variant"), allow(unused_mut))]
+                let mut arrow_schema = self.arrow_schema.as_ref().clone();
+                #[cfg(feature = "dtype-variant")]
+                let variant_shredding = Arc::new(VariantShredding::try_new(
+                    &mut arrow_schema,
+                    &self.options.field_overwrites,
+                )?);
                 let column_options: Arc<Vec<ColumnWriteOptions>> = Arc::new(
-                    get_column_write_options(&self.arrow_schema, &self.options.field_overwrites),
+                    get_column_write_options(&arrow_schema, &self.options.field_overwrites),
                 );
-                let schema_descriptor = Arc::new(to_parquet_schema(
-                    &self.arrow_schema,
-                    column_options.as_ref(),
-                )?);
+                let schema_descriptor =
+                    Arc::new(to_parquet_schema(&arrow_schema, column_options.as_ref())?);
 
                 *initialized_state = Some(InitializedState {
+                    arrow_schema: Arc::new(arrow_schema),
                     column_options,
                     schema_descriptor,
+                    #[cfg(feature = "dtype-variant")]
+                    variant_shredding,
                 })
             };
 
@@ -116,7 +134,6 @@ impl FileWriterStarter for ParquetWriterStarter {
             data_page_size: self.options.data_page_size,
         };
 
-        let arrow_schema = Arc::clone(&self.arrow_schema);
         let num_leaf_columns = schema_descriptor.leaves().len();
 
         let io_handle = tokio_handle_ext::AbortOnDropHandle(
@@ -144,6 +161,8 @@ impl FileWriterStarter for ParquetWriterStarter {
                 write_options,
                 column_options,
                 num_leaf_columns,
+                #[cfg(feature = "dtype-variant")]
+                variant_shredding,
             }
             .run(),
         ));
@@ -2,6 +2,9 @@ use std::sync::Arc;
 
 use polars_core::prelude::CompatLevel;
 use polars_error::PolarsResult;
+#[cfg(feature = "dtype-variant")]
+use polars_io::parquet::write::VariantShredding;
+use polars_io::parquet::write::get_sorting_columns;
 use polars_parquet::parquet::error::ParquetResult;
 use polars_parquet::read::ParquetError;
 use polars_parquet::write::{
@@ -24,6 +27,8 @@ pub struct RowGroupEncoder {
     pub write_options: WriteOptions,
     pub column_options: Arc<Vec<ColumnWriteOptions>>,
     pub num_leaf_columns: usize,
+    #[cfg(feature = "dtype-variant")]
+    pub variant_shredding: Arc<VariantShredding>,
 }
 
 impl RowGroupEncoder {
@@ -35,16 +40,21 @@ impl RowGroupEncoder {
             write_options,
             column_options,
             num_leaf_columns,
+            #[cfg(feature = "dtype-variant")]
+            variant_shredding,
         } = self;
 
         while let Ok(morsel) = morsel_rx.recv().await {
             let schema_descriptor = Arc::clone(&schema_descriptor);
             let column_options = Arc::clone(&column_options);
+            #[cfg(feature = "dtype-variant")]
+            let variant_shredding = Arc::clone(&variant_shredding);
 
             let row_group_encode_handle = async_executor::AbortOnDropHandle::new(
                 async_executor::spawn(TaskPriority::High, async move {
                     let (df, morsel_permit) = morsel.into_inner();
                     let num_rows = df.height();
+                    let sorting_columns = get_sorting_columns(df.columns(), &schema_descriptor);
 
                     let mut data: Vec<Vec<CompressedPage>> = Vec::with_capacity(num_leaf_columns);
 
@@ -53,6 +63,8 @@ impl RowGroupEncoder {
                         df.into_columns().into_iter().enumerate().map(|(i, c)| {
                             let schema_descriptor = Arc::clone(&schema_descriptor);
                             let column_options = Arc::clone(&column_options);
+                            #[cfg(feature = "dtype-variant")]
+                            let variant_shredding = Arc::clone(&variant_shredding);
 
                             async move {
                                 let parquet_type = &schema_descriptor.fields()[i];
@@ -61,6 +73,8 @@ impl RowGroupEncoder {
                                     .as_materialized_series()
                                     .rechunk()
                                     .to_arrow(0, CompatLevel::newest());
+                                #[cfg(feature = "dtype-variant")]
+                                let array = variant_shredding.shred_column(i, array)?;
 
                                 let mut data: UnitVec<Vec<CompressedPage>> =
                                     UnitVec::with_capacity(num_leaf_columns);
@@ -98,6 +112,7 @@ impl RowGroupEncoder {
                     Ok(EncodedRowGroup {
                         num_rows,
                         data,
+                        sorting_columns,
                         morsel_permit,
                     })
                 }),
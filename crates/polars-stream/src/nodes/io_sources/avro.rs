@@ -0,0 +1,392 @@
+use std::ops::Range;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use async_trait::async_trait;
+use polars_buffer::Buffer;
+use polars_core::schema::{Schema, SchemaRef};
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_error::{PolarsResult, polars_err};
+use polars_io::RowIndex;
+use polars_io::avro::{AvroBlockSpan, AvroFileMetadata, decode_avro_block, split_avro_blocks};
+use polars_io::cloud::CloudOptions;
+use polars_io::utils::slice::SplitSlicePosition;
+use polars_plan::dsl::ScanSource;
+use polars_utils::IdxSize;
+use polars_utils::pl_str::PlSmallStr;
+use polars_utils::slice_enum::Slice;
+
+use super::multi_scan::reader_interface::output::FileReaderOutputRecv;
+use super::multi_scan::reader_interface::{
+    BeginReadArgs, FileReader, FileReaderCallbacks, calc_row_position_after_slice,
+};
+use crate::async_executor::{AbortOnDropHandle, spawn};
+use crate::morsel::{SourceToken, get_ideal_morsel_size};
+use crate::nodes::compute_node_prelude::*;
+use crate::nodes::io_sources::multi_scan::reader_interface::Projection;
+use crate::nodes::io_sources::multi_scan::reader_interface::output::FileReaderOutputSend;
+use crate::nodes::{MorselSeq, TaskPriority};
+
+pub mod builder {
+    use std::sync::Arc;
+
+    use polars_core::config;
+    use polars_io::cloud::CloudOptions;
+    use polars_plan::dsl::ScanSource;
+
+    use super::AvroFileReader;
+    use crate::nodes::io_sources::multi_scan::reader_interface::FileReader;
+    use crate::nodes::io_sources::multi_scan::reader_interface::builder::FileReaderBuilder;
+    use crate::nodes::io_sources::multi_scan::reader_interface::capabilities::ReaderCapabilities;
+
+    #[derive(Debug)]
+    pub struct AvroReaderBuilder {}
+
+    impl FileReaderBuilder for AvroReaderBuilder {
+        fn reader_name(&self) -> &str {
+            "avro"
+        }
+
+        fn reader_capabilities(&self) -> ReaderCapabilities {
+            use ReaderCapabilities as RC;
+
+            RC::NEEDS_FILE_CACHE_INIT | RC::ROW_INDEX | RC::PRE_SLICE
+        }
+
+        fn build_file_reader(
+            &self,
+            source: ScanSource,
+            cloud_options: Option<Arc<CloudOptions>>,
+            _scan_source_idx: usize,
+        ) -> Box<dyn FileReader> {
+            let scan_source = source;
+            let verbose = config::verbose();
+
+            let reader = AvroFileReader {
+                scan_source,
+                cloud_options,
+                verbose,
+                init_data: None,
+            };
+
+            Box::new(reader) as Box<dyn FileReader>
+        }
+    }
+}
+
+struct AvroFileReader {
+    scan_source: ScanSource,
+    #[expect(unused)] // Will be used when implementing cloud streaming.
+    cloud_options: Option<Arc<CloudOptions>>,
+    verbose: bool,
+    init_data: Option<InitializedState>,
+}
+
+#[derive(Clone)]
+struct InitializedState {
+    bytes: Buffer<u8>,
+    metadata: Arc<AvroFileMetadata>,
+    file_schema: SchemaRef,
+    blocks: Arc<[AvroBlockSpan]>,
+    n_rows_in_file: IdxSize,
+}
+
+#[async_trait]
+impl FileReader for AvroFileReader {
+    async fn initialize(&mut self) -> PolarsResult<()> {
+        if self.init_data.is_some() {
+            return Ok(());
+        }
+
+        let bytes = self
+            .scan_source
+            .as_scan_source_ref()
+            .to_buffer_async_assume_latest(self.scan_source.run_async())?;
+
+        let metadata = AvroFileMetadata::try_new(&bytes)?;
+        let blocks = split_avro_blocks(&bytes, &metadata)?;
+        let file_schema = Arc::new(Schema::from_arrow_schema(metadata.schema.as_ref()));
+
+        let n_rows_in_file: usize = blocks.iter().map(|x| x.num_rows).sum();
+        let n_rows_in_file = IdxSize::try_from(n_rows_in_file)
+            .map_err(|_| polars_err!(bigidx, ctx = "avro file", size = n_rows_in_file))?;
+
+        self.init_data = Some(InitializedState {
+            bytes,
+            metadata: Arc::new(metadata),
+            file_schema,
+            blocks: blocks.into(),
+            n_rows_in_file,
+        });
+
+        Ok(())
+    }
+
+    fn begin_read(
+        &mut self,
+        args: BeginReadArgs,
+    ) -> PolarsResult<(FileReaderOutputRecv, JoinHandle<PolarsResult<()>>)> {
+        let verbose = self.verbose;
+
+        let InitializedState {
+            bytes,
+            metadata,
+            file_schema,
+            blocks,
+            n_rows_in_file,
+        } = self.init_data.clone().unwrap();
+
+        let BeginReadArgs {
+            projection: Projection::Plain(projected_schema),
+            row_index,
+            pre_slice,
+            predicate: None,
+            cast_columns_policy: _,
+            num_pipelines,
+            disable_morsel_split: _,
+            callbacks:
+                FileReaderCallbacks {
+                    file_schema_tx,
+                    n_rows_in_file_tx,
+                    row_position_on_end_tx,
+                },
+        } = args
+        else {
+            panic!("unsupported args: {:?}", &args)
+        };
+
+        debug_assert!(!matches!(pre_slice, Some(Slice::Negative { .. })));
+
+        // All block row counts are known up-front, so every callback can be answered immediately.
+        if let Some(tx) = file_schema_tx {
+            _ = tx.send(file_schema.clone());
+        }
+
+        if let Some(tx) = n_rows_in_file_tx {
+            _ = tx.send(n_rows_in_file);
+        }
+
+        if let Some(tx) = row_position_on_end_tx {
+            _ = tx.send(calc_row_position_after_slice(
+                n_rows_in_file,
+                pre_slice.clone(),
+            ));
+        }
+
+        // Columns that are not in this file are left for the multi-scan pipeline to fill.
+        let output_names: Vec<PlSmallStr> = projected_schema
+            .iter_names()
+            .filter(|name| file_schema.contains(name))
+            .cloned()
+            .collect();
+
+        let projection_mask: Vec<bool> = file_schema
+            .iter_names()
+            .map(|name| projected_schema.contains(name))
+            .collect();
+
+        let slice_range: Range<usize> = pre_slice
+            .clone()
+            .map_or(0..usize::MAX, Range::<usize>::from);
+
+        let ideal_morsel_size = get_ideal_morsel_size();
+        let block_batches: Arc<[BlockBatch]> =
+            plan_block_batches(&blocks, slice_range, ideal_morsel_size).into();
+
+        if verbose {
+            eprintln!(
+                "[AvroFileReader]: \
+                project: {} / {}, \
+                pre_slice: {:?}, \
+                blocks: {}, \
+                block batches: {}",
+                output_names.len(),
+                file_schema.len(),
+                pre_slice,
+                blocks.len(),
+                block_batches.len(),
+            );
+        }
+
+        let decoder = Arc::new(BlockBatchDecoder {
+            bytes,
+            metadata,
+            blocks,
+            projection_mask,
+            output_names,
+            row_index,
+        });
+
+        let next_batch_idx = Arc::new(AtomicUsize::new(0));
+        let (morsel_senders, morsel_rx) = FileReaderOutputSend::new_parallel(num_pipelines);
+
+        let decode_handles = morsel_senders
+            .into_iter()
+            .map(|mut morsel_tx| {
+                let decoder = decoder.clone();
+                let block_batches = block_batches.clone();
+                let next_batch_idx = next_batch_idx.clone();
+                // Note: We don't use this (it is handled by the bridge). But morsels require a source token.
+                let source_token = SourceToken::new();
+
+                AbortOnDropHandle::new(spawn(TaskPriority::Low, async move {
+                    loop {
+                        let batch_idx = next_batch_idx.fetch_add(1, Ordering::Relaxed);
+
+                        let Some(batch) = block_batches.get(batch_idx) else {
+                            break;
+                        };
+
+                        let df = decoder.decode(batch)?;
+                        let morsel_seq = MorselSeq::new(batch_idx as u64);
+
+                        if morsel_tx
+                            .send_morsel(Morsel::new(df, morsel_seq, source_token.clone()))
+                            .await
+                            .is_err()
+                        {
+                            break;
+                        }
+                    }
+
+                    PolarsResult::Ok(())
+                }))
+            })
+            .collect::<Vec<_>>();
+
+        let finishing_handle = spawn(TaskPriority::Low, async move {
+            for handle in decode_handles {
+                handle.await?;
+            }
+
+            if verbose {
+                eprintln!("[AvroFileReader]: returning");
+            }
+
+            Ok(())
+        });
+
+        Ok((morsel_rx, finishing_handle))
+    }
+
+    async fn file_schema(&mut self) -> PolarsResult<SchemaRef> {
+        Ok(self.init_data.as_ref().unwrap().file_schema.clone())
+    }
+
+    async fn n_rows_in_file(&mut self) -> PolarsResult<IdxSize> {
+        Ok(self.init_data.as_ref().unwrap().n_rows_in_file)
+    }
+
+    async fn fast_n_rows_in_file(&mut self) -> PolarsResult<Option<IdxSize>> {
+        Ok(Some(self.init_data.as_ref().unwrap().n_rows_in_file))
+    }
+
+    async fn row_position_after_slice(
+        &mut self,
+        pre_slice: Option<Slice>,
+    ) -> PolarsResult<IdxSize> {
+        Ok(calc_row_position_after_slice(
+            self.init_data.as_ref().unwrap().n_rows_in_file,
+            pre_slice,
+        ))
+    }
+}
+
+/// A run of consecutive blocks that is decoded into a single morsel.
+struct BlockBatch {
+    /// Indices into the block list of the file.
+    blocks: Range<usize>,
+    /// (offset, len) of the rows to keep, relative to the start of the first block.
+    slice: (usize, usize),
+    /// Row position of the first block in the file.
+    row_offset: usize,
+}
+
+/// Groups the blocks overlapping `slice_range` into batches of roughly `ideal_morsel_size` rows.
+fn plan_block_batches(
+    blocks: &[AvroBlockSpan],
+    slice_range: Range<usize>,
+    ideal_morsel_size: usize,
+) -> Vec<BlockBatch> {
+    let mut out = vec![];
+    let mut current: Option<BlockBatch> = None;
+    let mut row_offset: usize = 0;
+
+    for (block_idx, block) in blocks.iter().enumerate() {
+        let block_row_offset = row_offset;
+        row_offset += block.num_rows;
+
+        match SplitSlicePosition::split_slice_at_file(
+            block_row_offset,
+            block.num_rows,
+            slice_range.clone(),
+        ) {
+            SplitSlicePosition::Before => continue,
+            SplitSlicePosition::After => break,
+            SplitSlicePosition::Overlapping(rows_offset, rows_len) => {
+                let batch = current.get_or_insert_with(|| BlockBatch {
+                    blocks: block_idx..block_idx,
+                    slice: (rows_offset, 0),
+                    row_offset: block_row_offset,
+                });
+
+                batch.blocks.end = block_idx + 1;
+                batch.slice.1 += rows_len;
+
+                if batch.slice.1 >= ideal_morsel_size {
+                    out.extend(current.take());
+                }
+            },
+        }
+    }
+
+    out.extend(current);
+    out
+}
+
+struct BlockBatchDecoder {
+    bytes: Buffer<u8>,
+    metadata: Arc<AvroFileMetadata>,
+    blocks: Arc<[AvroBlockSpan]>,
+    /// Projection mask over the file schema.
+    projection_mask: Vec<bool>,
+    /// Projected column names in output order.
+    output_names: Vec<PlSmallStr>,
+    row_index: Option<RowIndex>,
+}
+
+impl BlockBatchDecoder {
+    fn decode(&self, batch: &BlockBatch) -> PolarsResult<DataFrame> {
+        let blocks = &self.blocks[batch.blocks.clone()];
+
+        let df = if self.output_names.is_empty() {
+            DataFrame::empty_with_height(blocks.iter().map(|x| x.num_rows).sum())
+        } else {
+            let dfs = blocks
+                .iter()
+                .map(|span| {
+                    decode_avro_block(&self.bytes, *span, &self.metadata, &self.projection_mask)
+                        .map(DataFrame::from)
+                })
+                .collect::<PolarsResult<Vec<_>>>()?;
+
+            // Blocks are decoded in file order, reorder to match the projection.
+            accumulate_dataframes_vertical_unchecked(dfs).select(self.output_names.iter())?
+        };
+
+        let (rows_offset, rows_len) = batch.slice;
+        let mut df = df.slice(rows_offset as i64, rows_len);
+
+        if let Some(RowIndex { name, offset }) = &self.row_index {
+            let row_position = batch.row_offset + rows_offset;
+            let offset = IdxSize::try_from(row_position)
+                .ok()
+                .and_then(|x| x.checked_add(*offset))
+                .ok_or_else(|| polars_err!(bigidx, ctx = "avro file", size = row_position))?;
+
+            df = df.with_row_index(name.clone(), Some(offset))?;
+        }
+
+        Ok(df)
+    }
+}
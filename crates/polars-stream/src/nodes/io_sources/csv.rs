@@ -167,7 +167,8 @@ impl FileReader for CsvFileReader {
             _ => {},
         }
 
-        let mut reader = CompressedReader::try_new(self.cached_bytes.clone().unwrap())?;
+        let mut reader = CompressedReader::try_new(self.cached_bytes.clone().unwrap())?
+            .with_encoding(self.options.parse_options.encoding)?;
 
         let (inferred_schema, base_leftover) = read_until_start_and_infer_schema(
             &self.options,
@@ -0,0 +1,243 @@
+use std::ops::Range;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use polars_error::{PolarsResult, polars_err};
+use polars_io::RowIndex;
+use polars_io::flight::FlightScanOptions;
+use polars_io::pl_async::get_runtime;
+use polars_plan::dsl::ScanSource;
+use polars_utils::IdxSize;
+use polars_utils::pl_str::PlSmallStr;
+use polars_utils::slice_enum::Slice;
+
+use super::multi_scan::reader_interface::output::FileReaderOutputRecv;
+use super::multi_scan::reader_interface::{BeginReadArgs, FileReader, FileReaderCallbacks};
+use crate::async_executor::spawn;
+use crate::morsel::SourceToken;
+use crate::nodes::compute_node_prelude::*;
+use crate::nodes::io_sources::multi_scan::reader_interface::Projection;
+use crate::nodes::io_sources::multi_scan::reader_interface::output::FileReaderOutputSend;
+use crate::nodes::{MorselSeq, TaskPriority};
+
+pub mod builder {
+    use std::sync::Arc;
+
+    use polars_core::config;
+    use polars_io::cloud::CloudOptions;
+    use polars_io::flight::FlightScanOptions;
+    use polars_plan::dsl::ScanSource;
+
+    use super::FlightReader;
+    use crate::nodes::io_sources::multi_scan::reader_interface::FileReader;
+    use crate::nodes::io_sources::multi_scan::reader_interface::builder::FileReaderBuilder;
+    use crate::nodes::io_sources::multi_scan::reader_interface::capabilities::ReaderCapabilities;
+
+    #[derive(Debug)]
+    pub struct FlightReaderBuilder {
+        pub options: Arc<FlightScanOptions>,
+    }
+
+    impl FileReaderBuilder for FlightReaderBuilder {
+        fn reader_name(&self) -> &str {
+            "flight"
+        }
+
+        fn reader_capabilities(&self) -> ReaderCapabilities {
+            use ReaderCapabilities as RC;
+
+            RC::ROW_INDEX | RC::PRE_SLICE
+        }
+
+        fn build_file_reader(
+            &self,
+            source: ScanSource,
+            _cloud_options: Option<Arc<CloudOptions>>,
+            _scan_source_idx: usize,
+        ) -> Box<dyn FileReader> {
+            let reader = FlightReader {
+                scan_source: source,
+                options: self.options.clone(),
+                verbose: config::verbose(),
+            };
+
+            Box::new(reader) as Box<dyn FileReader>
+        }
+    }
+}
+
+/// Reads the record batches of a `DoGet` as they arrive. The scan source is the location of the
+/// Flight service.
+struct FlightReader {
+    scan_source: ScanSource,
+    options: Arc<FlightScanOptions>,
+    verbose: bool,
+}
+
+#[async_trait]
+impl FileReader for FlightReader {
+    async fn initialize(&mut self) -> PolarsResult<()> {
+        Ok(())
+    }
+
+    fn begin_read(
+        &mut self,
+        args: BeginReadArgs,
+    ) -> PolarsResult<(FileReaderOutputRecv, JoinHandle<PolarsResult<()>>)> {
+        let verbose = self.verbose;
+
+        let BeginReadArgs {
+            projection: Projection::Plain(projected_schema),
+            row_index,
+            pre_slice,
+            predicate: None,
+            cast_columns_policy: _,
+            num_pipelines: _,
+            disable_morsel_split: _,
+            callbacks:
+                FileReaderCallbacks {
+                    file_schema_tx,
+                    n_rows_in_file_tx,
+                    mut row_position_on_end_tx,
+                },
+        } = args
+        else {
+            panic!("unsupported args: {:?}", &args)
+        };
+
+        debug_assert!(!matches!(pre_slice, Some(Slice::Negative { .. })));
+
+        let location = self
+            .scan_source
+            .as_scan_source_ref()
+            .to_include_path_name()
+            .to_string();
+        let options = self.options.clone();
+
+        if verbose {
+            eprintln!(
+                "[FlightReader]: location: {}, project: {}, pre_slice: {:?}",
+                location,
+                projected_schema.len(),
+                pre_slice,
+            );
+        }
+
+        let slice_range: Range<usize> = pre_slice.map_or(0..usize::MAX, Range::<usize>::from);
+        let (mut morsel_tx, morsel_rx) = FileReaderOutputSend::new_serial();
+
+        let handle = spawn(TaskPriority::Low, async move {
+            // The client needs to be created on the tokio runtime, the stream can be polled
+            // from anywhere.
+            let (_client, mut stream) = get_runtime()
+                .spawn(async move {
+                    let client = options.connect(&location).await?;
+                    let stream = client.do_get(options.ticket.clone()).await?;
+                    PolarsResult::Ok((client, stream))
+                })
+                .await
+                .unwrap()?;
+
+            let file_schema = stream.schema().clone();
+            if let Some(tx) = file_schema_tx {
+                _ = tx.send(file_schema.clone());
+            }
+
+            // Columns that are not in the stream are left for the multi-scan pipeline to fill.
+            let output_names: Vec<PlSmallStr> = projected_schema
+                .iter_names()
+                .filter(|name| file_schema.contains(name))
+                .cloned()
+                .collect();
+
+            // Note: We don't use this (it is handled by the bridge). But morsels require a source token.
+            let source_token = SourceToken::new();
+            let mut output_closed = slice_range.is_empty();
+            let mut row_position: usize = 0;
+            let mut seq: u64 = 0;
+
+            while let Some(df) = stream.next_batch().await? {
+                let batch_start = row_position;
+                row_position += df.height();
+
+                if !output_closed {
+                    let start = slice_range.start.clamp(batch_start, row_position);
+                    let end = slice_range.end.clamp(batch_start, row_position);
+
+                    if start < end {
+                        let df = df.slice((start - batch_start) as i64, end - start);
+                        let df = project_batch(df, &output_names, row_index.as_ref(), start)?;
+
+                        if morsel_tx
+                            .send_morsel(Morsel::new(df, MorselSeq::new(seq), source_token.clone()))
+                            .await
+                            .is_err()
+                        {
+                            output_closed = true;
+                        }
+                        seq += 1;
+                    }
+
+                    if row_position >= slice_range.end {
+                        output_closed = true;
+                    }
+                }
+
+                if row_position >= slice_range.end
+                    && let Some(tx) = row_position_on_end_tx.take()
+                {
+                    _ = tx.send(idx_size(slice_range.end)?);
+                }
+
+                // The row counts are only known once the stream has ended.
+                if output_closed && n_rows_in_file_tx.is_none() && row_position_on_end_tx.is_none()
+                {
+                    break;
+                }
+            }
+
+            if let Some(tx) = row_position_on_end_tx {
+                _ = tx.send(idx_size(row_position.min(slice_range.end))?);
+            }
+
+            if let Some(tx) = n_rows_in_file_tx {
+                _ = tx.send(idx_size(row_position)?);
+            }
+
+            if verbose {
+                eprintln!("[FlightReader]: returning");
+            }
+
+            Ok(())
+        });
+
+        Ok((morsel_rx, handle))
+    }
+}
+
+fn project_batch(
+    df: DataFrame,
+    output_names: &[PlSmallStr],
+    row_index: Option<&RowIndex>,
+    row_position: usize,
+) -> PolarsResult<DataFrame> {
+    let mut df = if output_names.is_empty() {
+        DataFrame::empty_with_height(df.height())
+    } else {
+        df.select(output_names.iter().cloned())?
+    };
+
+    if let Some(RowIndex { name, offset }) = row_index {
+        let offset = idx_size(row_position)?
+            .checked_add(*offset)
+            .ok_or_else(|| polars_err!(bigidx, ctx = "flight stream", size = row_position))?;
+
+        df = df.with_row_index(name.clone(), Some(offset))?;
+    }
+
+    Ok(df)
+}
+
+fn idx_size(n: usize) -> PolarsResult<IdxSize> {
+    IdxSize::try_from(n).map_err(|_| polars_err!(bigidx, ctx = "flight stream", size = n))
+}
@@ -2,6 +2,7 @@ use std::sync::Arc;
 
 use polars_core::config;
 use polars_io::cloud::CloudOptions;
+use polars_io::utils::text_encoding::TextEncoding;
 use polars_plan::dsl::ScanSource;
 
 use crate::nodes::io_sources::multi_scan::reader_interface::FileReader;
@@ -38,6 +39,8 @@ impl FileReaderBuilder for LineReaderBuilder {
             cloud_options,
             chunk_reader_builder,
             count_rows_fn: polars_io::scan_lines::count_lines,
+            json_array_pointer: None,
+            encoding: TextEncoding::Utf8,
             cached_bytes: None,
             verbose,
         };
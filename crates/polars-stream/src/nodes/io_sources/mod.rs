@@ -1,13 +1,19 @@
 pub mod multi_scan;
 
+#[cfg(feature = "avro")]
+pub mod avro;
 pub mod batch;
 #[cfg(feature = "csv")]
 pub mod csv;
+#[cfg(feature = "flight")]
+pub mod flight;
 #[cfg(feature = "ipc")]
 pub mod ipc;
 #[cfg(feature = "scan_lines")]
 pub mod lines;
 #[cfg(any(feature = "json", feature = "scan_lines"))]
 pub mod ndjson;
+#[cfg(feature = "orc")]
+pub mod orc;
 #[cfg(feature = "parquet")]
 pub mod parquet;
@@ -9,6 +9,8 @@ use polars_core::utils::accumulate_dataframes_vertical_unchecked;
 use polars_error::{PolarsResult, feature_gated};
 use polars_io::cloud::CloudOptions;
 use polars_plan::dsl::deletion::DeletionFilesList;
+#[cfg(feature = "delta")]
+use polars_plan::dsl::deletion::{DeltaDeletionVector, DeltaDeletionVectorLocation};
 use polars_plan::dsl::{CastColumnsPolicy, ScanSource};
 use polars_utils::format_pl_smallstr;
 use polars_utils::pl_path::PlRefPath;
@@ -22,6 +24,8 @@ use crate::nodes::io_sources::multi_scan::reader_interface::builder::FileReaderB
 use crate::nodes::io_sources::multi_scan::reader_interface::{BeginReadArgs, FileReaderCallbacks};
 #[cfg(feature = "parquet")]
 use crate::nodes::io_sources::parquet::builder::ParquetReaderBuilder;
+#[cfg(feature = "delta")]
+use crate::utils::tokio_handle_ext;
 
 #[derive(Clone)]
 pub enum DeletionFilesProvider {
@@ -34,6 +38,11 @@ pub enum DeletionFilesProvider {
         reader_builder: ParquetReaderBuilder,
         projected_schema: SchemaRef,
     },
+
+    #[cfg(feature = "delta")]
+    DeltaDeletionVector {
+        vectors: Arc<PlIndexMap<usize, DeltaDeletionVector>>,
+    },
 }
 
 impl DeletionFilesProvider {
@@ -59,6 +68,8 @@ impl DeletionFilesProvider {
                         parallel: polars_io::prelude::ParallelStrategy::Auto,
                         low_memory: false,
                         use_statistics: false,
+                        keep_dictionaries: false,
+                        keep_runs: false,
                     }),
                     prefetch_limit: RelaxedCell::new_usize(0),
                     prefetch_semaphore: std::sync::OnceLock::new(),
@@ -77,6 +88,9 @@ impl DeletionFilesProvider {
                     ])),
                 }
             }),
+            DeletionFilesList::DeltaDeletionVector(vectors) => {
+                feature_gated!("delta", Self::DeltaDeletionVector { vectors })
+            },
         }
     }
 
@@ -258,6 +272,103 @@ impl DeletionFilesProvider {
 
                 Some(RowDeletionsInit::Initializing(handle))
             },
+
+            #[cfg(feature = "delta")]
+            Self::DeltaDeletionVector { vectors } => {
+                let vector = vectors.get(&scan_source_idx)?.clone();
+
+                if verbose {
+                    let location = match &vector.location {
+                        DeltaDeletionVectorLocation::Inline(_) => "inline",
+                        DeltaDeletionVectorLocation::File { path, .. } => path.as_str(),
+                    };
+
+                    eprintln!(
+                        "[DeletionFilesProvider[Delta]]: scan_source_idx: {scan_source_idx}, \
+                        deletion_vector: {location}"
+                    )
+                }
+
+                let handle =
+                    AbortOnDropHandle::new(async_executor::spawn(TaskPriority::Low, async move {
+                        let DeltaDeletionVector { location, num_rows } = vector;
+
+                        let positions = match location {
+                            DeltaDeletionVectorLocation::Inline(bytes) => {
+                                polars_io::delta::decode_deletion_vector(&bytes)?
+                            },
+                            DeltaDeletionVectorLocation::File {
+                                path,
+                                offset,
+                                size_in_bytes,
+                            } => {
+                                let io_runtime = polars_io::pl_async::get_runtime();
+
+                                tokio_handle_ext::AbortOnDropHandle(io_runtime.spawn(async move {
+                                    polars_io::delta::load_deletion_vector_file(
+                                        &path,
+                                        offset,
+                                        size_in_bytes,
+                                        cloud_options.as_deref(),
+                                    )
+                                    .await
+                                }))
+                                .await
+                                .unwrap()?
+                            },
+                        };
+
+                        let min_mask_len = positions
+                            .iter()
+                            .max()
+                            .map_or(0, |x| usize::try_from(*x).unwrap().saturating_add(1));
+
+                        // Cover all rows of the file if its row count is known.
+                        let filter_mask_len = match num_rows {
+                            Some(num_rows) => {
+                                let num_rows = usize::try_from(num_rows).unwrap();
+                                polars_error::polars_ensure!(
+                                    min_mask_len <= num_rows,
+                                    ComputeError:
+                                    "delta deletion vector of scan source {} deletes row {} of a \
+                                    file with {} rows",
+                                    scan_source_idx, min_mask_len - 1, num_rows
+                                );
+                                num_rows
+                            },
+                            None => min_mask_len,
+                        };
+
+                        let mut filter_mask = MutableBitmap::from_len_set(filter_mask_len);
+
+                        for idx in positions {
+                            filter_mask.set(usize::try_from(idx).unwrap(), false);
+                        }
+
+                        let bitmap = filter_mask.freeze();
+
+                        // Also trigger the bitcount to reduce blocking later down.
+                        bitmap.unset_bits();
+                        debug_assert!(bitmap.lazy_unset_bits().is_some());
+
+                        let mask = BooleanChunked::from_bitmap(PlSmallStr::EMPTY, bitmap);
+                        let mask = ExternalFilterMask::DeltaDeletionVector { mask };
+
+                        if verbose {
+                            let num_deleted_rows = mask.num_deleted_rows();
+
+                            eprintln!(
+                                "[DeletionFilesProvider[Delta]]: \
+                                scan_source_idx: {scan_source_idx}, \
+                                num_deleted_rows: {num_deleted_rows}",
+                            )
+                        }
+
+                        Ok(mask)
+                    }));
+
+                Some(RowDeletionsInit::Initializing(handle))
+            },
         }
     }
 }
@@ -285,6 +396,9 @@ impl RowDeletionsInit {
 pub enum ExternalFilterMask {
     /// Note: Iceberg positional deletes can have a mask length shorter than the actual data.
     IcebergPositionDelete { mask: BooleanChunked },
+    /// Note: The mask covers all rows of the file if its row count is known, otherwise it is only
+    /// as long as needed to cover the last deleted row.
+    DeltaDeletionVector { mask: BooleanChunked },
 }
 
 impl ExternalFilterMask {
@@ -292,6 +406,7 @@ impl ExternalFilterMask {
         use ExternalFilterMask::*;
         match self {
             IcebergPositionDelete { .. } => "IcebergPositionDelete",
+            DeltaDeletionVector { .. } => "DeltaDeletionVector",
         }
     }
 
@@ -310,7 +425,7 @@ impl ExternalFilterMask {
 
     pub fn filter_df(&self, df: &mut DataFrame) -> PolarsResult<()> {
         match self {
-            Self::IcebergPositionDelete { mask } => {
+            Self::IcebergPositionDelete { mask } | Self::DeltaDeletionVector { mask } => {
                 if !mask.is_empty() {
                     *df = if mask.len() < df.height() {
                         accumulate_dataframes_vertical_unchecked([
@@ -329,7 +444,7 @@ impl ExternalFilterMask {
 
     pub fn slice(&self, offset: usize, len: usize) -> Self {
         match self {
-            Self::IcebergPositionDelete { mask } => {
+            Self::IcebergPositionDelete { mask } | Self::DeltaDeletionVector { mask } => {
                 // This is not a valid offset, it's also a sentinel value from `RowCounter::MAX`.
                 assert_ne!(offset, usize::MAX);
                 let offset = offset.min(mask.len());
@@ -337,14 +452,17 @@ impl ExternalFilterMask {
 
                 let mask = mask.slice(i64::try_from(offset).unwrap(), len);
 
-                Self::IcebergPositionDelete { mask }
+                match self {
+                    Self::IcebergPositionDelete { .. } => Self::IcebergPositionDelete { mask },
+                    Self::DeltaDeletionVector { .. } => Self::DeltaDeletionVector { mask },
+                }
             },
         }
     }
 
     pub fn num_deleted_rows(&self) -> usize {
         match self {
-            Self::IcebergPositionDelete { mask } => mask
+            Self::IcebergPositionDelete { mask } | Self::DeltaDeletionVector { mask } => mask
                 .rechunk()
                 .downcast_get(0)
                 .unwrap()
@@ -401,7 +519,7 @@ impl ExternalFilterMask {
 
     fn get_mask(&self) -> Bitmap {
         match self {
-            Self::IcebergPositionDelete { mask } => {
+            Self::IcebergPositionDelete { mask } | Self::DeltaDeletionVector { mask } => {
                 mask.rechunk().downcast_get(0).unwrap().values().clone()
             },
         }
@@ -409,7 +527,7 @@ impl ExternalFilterMask {
 
     pub fn len(&self) -> usize {
         match self {
-            Self::IcebergPositionDelete { mask } => mask.len(),
+            Self::IcebergPositionDelete { mask } | Self::DeltaDeletionVector { mask } => mask.len(),
         }
     }
 }
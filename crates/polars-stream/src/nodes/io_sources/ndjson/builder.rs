@@ -22,7 +22,12 @@ impl FileReaderBuilder for polars_plan::dsl::NDJsonReadOptions {
     }
 
     fn reader_capabilities(&self) -> ReaderCapabilities {
-        ndjson_reader_capabilities()
+        if self.json_array_pointer.is_some() {
+            // JSON array documents can only be tokenized from the start.
+            ndjson_reader_capabilities() - ReaderCapabilities::NEGATIVE_PRE_SLICE
+        } else {
+            ndjson_reader_capabilities()
+        }
     }
 
     fn build_file_reader(
@@ -42,6 +47,8 @@ impl FileReaderBuilder for polars_plan::dsl::NDJsonReadOptions {
             cloud_options,
             chunk_reader_builder,
             count_rows_fn: polars_io::ndjson::count_rows,
+            json_array_pointer: self.json_array_pointer.clone(),
+            encoding: self.encoding,
             cached_bytes: None,
             verbose,
         };
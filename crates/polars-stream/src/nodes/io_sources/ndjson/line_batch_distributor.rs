@@ -4,6 +4,7 @@ use std::num::NonZeroUsize;
 use polars_buffer::Buffer;
 use polars_core::config;
 use polars_error::PolarsResult;
+use polars_io::ndjson::json_array::JsonArrayReader;
 use polars_io::utils::compression::CompressedReader;
 use polars_utils::mem::prefetch::prefetch_l2;
 
@@ -12,8 +13,27 @@ use crate::async_primitives::distributor_channel;
 
 const LF: u8 = b'\n';
 
+pub(super) enum LineSourceReader {
+    Lines(CompressedReader),
+    /// Elements of a JSON array document, transcoded into NDJSON lines.
+    JsonArray(JsonArrayReader),
+}
+
+impl LineSourceReader {
+    fn read_next_slice(
+        &mut self,
+        prev_leftover: &Buffer<u8>,
+        read_size: usize,
+    ) -> std::io::Result<(Buffer<u8>, usize)> {
+        match self {
+            Self::Lines(reader) => reader.read_next_slice(prev_leftover, read_size),
+            Self::JsonArray(reader) => reader.read_next_slice(prev_leftover, read_size),
+        }
+    }
+}
+
 pub(super) struct LineBatchDistributor {
-    pub(super) reader: CompressedReader,
+    pub(super) reader: LineSourceReader,
     pub(super) reverse: bool,
     pub(super) row_skipper: RowSkipper,
     pub(super) line_batch_distribute_tx: distributor_channel::Sender<LineBatch>,
@@ -10,9 +10,12 @@ use negative_slice_pass::MorselStreamReverser;
 use polars_buffer::Buffer;
 use polars_error::{PolarsResult, polars_bail, polars_err};
 use polars_io::cloud::CloudOptions;
+use polars_io::ndjson::json_array::JsonArrayReader;
 use polars_io::utils::compression::CompressedReader;
+use polars_io::utils::text_encoding::TextEncoding;
 use polars_plan::dsl::ScanSource;
 use polars_utils::IdxSize;
+use polars_utils::pl_str::PlSmallStr;
 use polars_utils::priority::Priority;
 use polars_utils::slice_enum::Slice;
 use row_index_limit_pass::ApplyRowIndexOrLimit;
@@ -28,7 +31,7 @@ use crate::nodes::compute_node_prelude::*;
 use crate::nodes::io_sources::multi_scan::reader_interface::Projection;
 use crate::nodes::io_sources::multi_scan::reader_interface::output::FileReaderOutputSend;
 use crate::nodes::io_sources::ndjson::chunk_reader::ChunkReaderBuilder;
-use crate::nodes::io_sources::ndjson::line_batch_distributor::RowSkipper;
+use crate::nodes::io_sources::ndjson::line_batch_distributor::{LineSourceReader, RowSkipper};
 use crate::nodes::{MorselSeq, TaskPriority};
 pub(super) mod chunk_reader;
 mod line_batch_distributor;
@@ -43,6 +46,9 @@ pub struct NDJsonFileReader {
     pub cloud_options: Option<Arc<CloudOptions>>,
     pub chunk_reader_builder: ChunkReaderBuilder,
     pub count_rows_fn: fn(&[u8]) -> usize,
+    /// Read the elements of the array at this JSON pointer instead of NDJSON lines.
+    pub json_array_pointer: Option<PlSmallStr>,
+    pub encoding: TextEncoding,
     // Cached on first access - we may be called multiple times e.g. on negative slice.
     pub cached_bytes: Option<Buffer<u8>>,
     pub verbose: bool,
@@ -92,7 +98,14 @@ impl FileReader for NDJsonFileReader {
         let is_empty_slice = pre_slice.as_ref().is_some_and(|x| x.len() == 0);
         let is_negative_slice = matches!(pre_slice, Some(Slice::Negative { .. }));
 
-        let reader = CompressedReader::try_new(self.cached_bytes.clone().unwrap())?;
+        let reader = CompressedReader::try_new(self.cached_bytes.clone().unwrap())?
+            .with_encoding(self.encoding)?;
+        let reader = if let Some(pointer) = &self.json_array_pointer {
+            assert!(!is_negative_slice);
+            LineSourceReader::JsonArray(JsonArrayReader::try_new(reader, pointer)?)
+        } else {
+            LineSourceReader::Lines(reader)
+        };
 
         // NDJSON: We just use the projected schema - the parser will automatically append NULL if
         // the field is not found.
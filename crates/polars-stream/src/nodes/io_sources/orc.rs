@@ -0,0 +1,559 @@
+use std::ops::Range;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use arrow::bitmap::Bitmap;
+use async_trait::async_trait;
+use polars_buffer::Buffer;
+use polars_core::prelude::{AnyValue, Column, DataType, IntoColumn, Series};
+use polars_core::schema::{Schema, SchemaRef};
+use polars_error::{PolarsResult, polars_err};
+use polars_io::RowIndex;
+use polars_io::cloud::CloudOptions;
+use polars_io::orc::{OrcFileMetadata, decode_orc_stripe};
+use polars_io::predicates::ScanIOPredicate;
+use polars_io::utils::slice::SplitSlicePosition;
+use polars_plan::dsl::{CastColumnsPolicy, ScanSource};
+use polars_utils::pl_str::PlSmallStr;
+use polars_utils::slice_enum::Slice;
+use polars_utils::{IdxSize, format_pl_smallstr};
+
+use super::multi_scan::reader_interface::output::FileReaderOutputRecv;
+use super::multi_scan::reader_interface::{
+    BeginReadArgs, FileReader, FileReaderCallbacks, calc_row_position_after_slice,
+};
+use crate::async_executor::{AbortOnDropHandle, spawn};
+use crate::morsel::{SourceToken, get_ideal_morsel_size};
+use crate::nodes::compute_node_prelude::*;
+use crate::nodes::io_sources::multi_scan::components::column_selector::ColumnSelector;
+use crate::nodes::io_sources::multi_scan::components::projection::MappedProjectionRef;
+use crate::nodes::io_sources::multi_scan::components::projection::builder::ProjectionBuilder;
+use crate::nodes::io_sources::multi_scan::reader_interface::Projection;
+use crate::nodes::io_sources::multi_scan::reader_interface::output::FileReaderOutputSend;
+use crate::nodes::{MorselSeq, TaskPriority};
+
+pub mod builder {
+    use std::sync::Arc;
+
+    use polars_core::config;
+    use polars_io::cloud::CloudOptions;
+    use polars_plan::dsl::ScanSource;
+
+    use super::OrcFileReader;
+    use crate::nodes::io_sources::multi_scan::reader_interface::FileReader;
+    use crate::nodes::io_sources::multi_scan::reader_interface::builder::FileReaderBuilder;
+    use crate::nodes::io_sources::multi_scan::reader_interface::capabilities::ReaderCapabilities;
+
+    #[derive(Debug)]
+    pub struct OrcReaderBuilder {}
+
+    impl FileReaderBuilder for OrcReaderBuilder {
+        fn reader_name(&self) -> &str {
+            "orc"
+        }
+
+        fn reader_capabilities(&self) -> ReaderCapabilities {
+            use ReaderCapabilities as RC;
+
+            RC::NEEDS_FILE_CACHE_INIT
+                | RC::ROW_INDEX
+                | RC::PRE_SLICE
+                | RC::PARTIAL_FILTER
+                | RC::MAPPED_COLUMN_PROJECTION
+        }
+
+        fn build_file_reader(
+            &self,
+            source: ScanSource,
+            cloud_options: Option<Arc<CloudOptions>>,
+            _scan_source_idx: usize,
+        ) -> Box<dyn FileReader> {
+            let scan_source = source;
+            let verbose = config::verbose();
+
+            let reader = OrcFileReader {
+                scan_source,
+                cloud_options,
+                verbose,
+                init_data: None,
+            };
+
+            Box::new(reader) as Box<dyn FileReader>
+        }
+    }
+}
+
+struct OrcFileReader {
+    scan_source: ScanSource,
+    #[expect(unused)] // Will be used when implementing cloud streaming.
+    cloud_options: Option<Arc<CloudOptions>>,
+    verbose: bool,
+    init_data: Option<InitializedState>,
+}
+
+#[derive(Clone)]
+struct InitializedState {
+    bytes: Buffer<u8>,
+    metadata: Arc<OrcFileMetadata>,
+    n_rows_in_file: IdxSize,
+}
+
+#[async_trait]
+impl FileReader for OrcFileReader {
+    async fn initialize(&mut self) -> PolarsResult<()> {
+        if self.init_data.is_some() {
+            return Ok(());
+        }
+
+        let bytes = self
+            .scan_source
+            .as_scan_source_ref()
+            .to_buffer_async_assume_latest(self.scan_source.run_async())?;
+
+        let metadata = OrcFileMetadata::try_new(&bytes)?;
+
+        let n_rows_in_file = metadata.num_rows();
+        let n_rows_in_file = IdxSize::try_from(n_rows_in_file)
+            .map_err(|_| polars_err!(bigidx, ctx = "orc file", size = n_rows_in_file))?;
+
+        self.init_data = Some(InitializedState {
+            bytes,
+            metadata: Arc::new(metadata),
+            n_rows_in_file,
+        });
+
+        Ok(())
+    }
+
+    fn begin_read(
+        &mut self,
+        args: BeginReadArgs,
+    ) -> PolarsResult<(FileReaderOutputRecv, JoinHandle<PolarsResult<()>>)> {
+        let verbose = self.verbose;
+
+        let InitializedState {
+            bytes,
+            metadata,
+            n_rows_in_file,
+        } = self.init_data.clone().unwrap();
+
+        let BeginReadArgs {
+            projection,
+            row_index,
+            pre_slice,
+            predicate,
+            cast_columns_policy,
+            num_pipelines,
+            disable_morsel_split,
+            callbacks:
+                FileReaderCallbacks {
+                    file_schema_tx,
+                    n_rows_in_file_tx,
+                    row_position_on_end_tx,
+                },
+        } = args;
+
+        debug_assert!(!matches!(pre_slice, Some(Slice::Negative { .. })));
+
+        let file_schema = metadata.schema.clone();
+
+        // Stripe row counts are stored in the file tail, so every callback can be answered
+        // immediately.
+        if let Some(tx) = file_schema_tx {
+            _ = tx.send(file_schema.clone());
+        }
+
+        if let Some(tx) = n_rows_in_file_tx {
+            _ = tx.send(n_rows_in_file);
+        }
+
+        if let Some(tx) = row_position_on_end_tx {
+            _ = tx.send(calc_row_position_after_slice(
+                n_rows_in_file,
+                pre_slice.clone(),
+            ));
+        }
+
+        let projections: Arc<[OrcColumnProjection]> =
+            resolve_orc_column_projections(&file_schema, projection, cast_columns_policy)?.into();
+
+        let slice_range: Range<usize> = pre_slice
+            .clone()
+            .map_or(0..usize::MAX, Range::<usize>::from);
+
+        let mut stripe_reads = plan_stripe_reads(&metadata, slice_range);
+        let n_stripes_in_slice = stripe_reads.len();
+
+        if let Some(predicate) = predicate.as_ref()
+            && let Some(skip_mask) = calc_stripe_skip_mask(
+                &stripe_reads,
+                predicate,
+                &metadata,
+                &projections,
+                row_index.as_ref(),
+            )?
+        {
+            let mut skip = skip_mask.iter();
+            stripe_reads.retain(|_| !skip.next().unwrap());
+
+            if verbose {
+                eprintln!(
+                    "[OrcFileReader]: Predicate pushdown: \
+                    reading {} / {} stripes",
+                    stripe_reads.len(),
+                    n_stripes_in_slice,
+                );
+            }
+        }
+
+        if verbose {
+            eprintln!(
+                "[OrcFileReader]: \
+                project: {} / {}, \
+                pre_slice: {:?}, \
+                stripes: {} / {}",
+                projections.len(),
+                file_schema.len(),
+                pre_slice,
+                stripe_reads.len(),
+                metadata.num_stripes(),
+            );
+        }
+
+        let decoder = Arc::new(StripeDecoder {
+            bytes,
+            metadata,
+            file_projection: projections.iter().map(|p| p.file_column_idx).collect(),
+            projections,
+            row_index,
+        });
+
+        let stripe_reads: Arc<[StripeRead]> = stripe_reads.into();
+        let ideal_morsel_size = get_ideal_morsel_size();
+        let next_read_idx = Arc::new(AtomicUsize::new(0));
+        let (morsel_senders, morsel_rx) = FileReaderOutputSend::new_parallel(num_pipelines);
+
+        let decode_handles = morsel_senders
+            .into_iter()
+            .map(|mut morsel_tx| {
+                let decoder = decoder.clone();
+                let stripe_reads = stripe_reads.clone();
+                let next_read_idx = next_read_idx.clone();
+                // Note: We don't use this (it is handled by the bridge). But morsels require a source token.
+                let source_token = SourceToken::new();
+
+                AbortOnDropHandle::new(spawn(TaskPriority::Low, async move {
+                    loop {
+                        let read_idx = next_read_idx.fetch_add(1, Ordering::Relaxed);
+
+                        let Some(stripe_read) = stripe_reads.get(read_idx) else {
+                            break;
+                        };
+
+                        let df = decoder.decode(stripe_read)?;
+                        let height = df.height();
+
+                        let morsel_height = if disable_morsel_split {
+                            height.max(1)
+                        } else {
+                            ideal_morsel_size
+                        };
+
+                        // Morsels are sequenced by the file row position of their first row,
+                        // which keeps the output in file order across pipelines.
+                        let first_row = stripe_read.row_offset + stripe_read.slice.0;
+
+                        for offset in (0..height).step_by(morsel_height) {
+                            let morsel_seq = MorselSeq::new((first_row + offset) as u64);
+                            let df = df.slice(offset as i64, morsel_height);
+
+                            if morsel_tx
+                                .send_morsel(Morsel::new(df, morsel_seq, source_token.clone()))
+                                .await
+                                .is_err()
+                            {
+                                return Ok(());
+                            }
+                        }
+                    }
+
+                    PolarsResult::Ok(())
+                }))
+            })
+            .collect::<Vec<_>>();
+
+        let finishing_handle = spawn(TaskPriority::Low, async move {
+            for handle in decode_handles {
+                handle.await?;
+            }
+
+            if verbose {
+                eprintln!("[OrcFileReader]: returning");
+            }
+
+            Ok(())
+        });
+
+        Ok((morsel_rx, finishing_handle))
+    }
+
+    async fn file_schema(&mut self) -> PolarsResult<SchemaRef> {
+        Ok(self.init_data.as_ref().unwrap().metadata.schema.clone())
+    }
+
+    async fn n_rows_in_file(&mut self) -> PolarsResult<IdxSize> {
+        Ok(self.init_data.as_ref().unwrap().n_rows_in_file)
+    }
+
+    async fn fast_n_rows_in_file(&mut self) -> PolarsResult<Option<IdxSize>> {
+        Ok(Some(self.init_data.as_ref().unwrap().n_rows_in_file))
+    }
+
+    async fn row_position_after_slice(
+        &mut self,
+        pre_slice: Option<Slice>,
+    ) -> PolarsResult<IdxSize> {
+        Ok(calc_row_position_after_slice(
+            self.init_data.as_ref().unwrap().n_rows_in_file,
+            pre_slice,
+        ))
+    }
+}
+
+/// A potentially mapped (i.e. casted and/or renamed) projection of a top-level file column.
+struct OrcColumnProjection {
+    /// Index of the column in the file schema.
+    file_column_idx: usize,
+    output_name: PlSmallStr,
+    transform: Option<ColumnSelector>,
+}
+
+impl OrcColumnProjection {
+    fn apply_transform(&self, column: Column) -> PolarsResult<Column> {
+        match &self.transform {
+            None => Ok(column),
+            Some(transform) => {
+                let output_height = column.len();
+                transform.select_from_columns(&[column], output_height)
+            },
+        }
+    }
+}
+
+fn resolve_orc_column_projections(
+    file_schema: &Schema,
+    projection: Projection,
+    cast_columns_policy: CastColumnsPolicy,
+) -> PolarsResult<Vec<OrcColumnProjection>> {
+    let projection: Projection = match projection {
+        Projection::Plain(projected_schema) => ProjectionBuilder::new(projected_schema, None, None)
+            .build_projection(Some(file_schema), None, cast_columns_policy, usize::MAX)?,
+        Projection::Mapped { .. } => projection,
+    };
+
+    Ok(projection
+        .iter_non_missing_columns()
+        .map(
+            |MappedProjectionRef {
+                 source_name,
+                 output_name,
+                 output_dtype: _,
+                 resolved_transform,
+             }| OrcColumnProjection {
+                file_column_idx: file_schema.index_of(source_name.as_str()).unwrap(),
+                output_name: output_name.clone(),
+                transform: resolved_transform
+                    .map(|t| t.attach_transforms(ColumnSelector::Position(0))),
+            },
+        )
+        .collect())
+}
+
+/// The rows to read from a single stripe.
+struct StripeRead {
+    stripe_idx: usize,
+    /// (offset, len) of the rows to keep, relative to the start of the stripe.
+    slice: (usize, usize),
+    /// Row position of the first row of the stripe in the file.
+    row_offset: usize,
+}
+
+fn plan_stripe_reads(metadata: &OrcFileMetadata, slice_range: Range<usize>) -> Vec<StripeRead> {
+    let mut out = vec![];
+    let mut row_offset: usize = 0;
+
+    for (stripe_idx, stripe) in metadata.footer.stripes.iter().enumerate() {
+        let num_rows = usize::try_from(stripe.number_of_rows).unwrap();
+        let stripe_row_offset = row_offset;
+        row_offset += num_rows;
+
+        if num_rows == 0 {
+            continue;
+        }
+
+        match SplitSlicePosition::split_slice_at_file(
+            stripe_row_offset,
+            num_rows,
+            slice_range.clone(),
+        ) {
+            SplitSlicePosition::Before => continue,
+            SplitSlicePosition::After => break,
+            SplitSlicePosition::Overlapping(rows_offset, rows_len) => out.push(StripeRead {
+                stripe_idx,
+                slice: (rows_offset, rows_len),
+                row_offset: stripe_row_offset,
+            }),
+        }
+    }
+
+    out
+}
+
+/// Evaluates the skip batch predicate against the stripe statistics. Set bits in the returned
+/// mask indicate stripes that can be skipped.
+fn calc_stripe_skip_mask(
+    stripe_reads: &[StripeRead],
+    predicate: &ScanIOPredicate,
+    metadata: &OrcFileMetadata,
+    projections: &[OrcColumnProjection],
+    row_index: Option<&RowIndex>,
+) -> PolarsResult<Option<Bitmap>> {
+    let Some(sbp) = predicate.skip_batch_predicate.as_ref() else {
+        return Ok(None);
+    };
+
+    let live_columns = &predicate.live_columns;
+    let num_stripes = stripe_reads.len();
+    let stripe_num_rows = |s: &StripeRead| {
+        usize::try_from(metadata.footer.stripes[s.stripe_idx].number_of_rows).unwrap()
+    };
+
+    let mut columns = Vec::with_capacity(1 + live_columns.len() * 3);
+
+    let lengths: Vec<IdxSize> = stripe_reads
+        .iter()
+        .map(|s| stripe_num_rows(s) as IdxSize)
+        .collect();
+
+    columns.push(Column::new("len".into(), lengths));
+
+    for projection in projections.iter() {
+        let c = &projection.output_name;
+
+        if !live_columns.contains(c) {
+            continue;
+        }
+
+        let dtype = metadata
+            .schema
+            .get_at_index(projection.file_column_idx)
+            .unwrap()
+            .1;
+
+        let mut min_values = Vec::with_capacity(num_stripes);
+        let mut max_values = Vec::with_capacity(num_stripes);
+        let mut null_counts: Vec<Option<IdxSize>> = Vec::with_capacity(num_stripes);
+
+        for s in stripe_reads {
+            match metadata.stripe_column_statistics(s.stripe_idx, projection.file_column_idx)? {
+                Some((min, max, null_count)) => {
+                    min_values.push(min.into_value());
+                    max_values.push(max.into_value());
+                    null_counts.push(Some(null_count));
+                },
+                None => {
+                    min_values.push(AnyValue::Null);
+                    max_values.push(AnyValue::Null);
+                    null_counts.push(None);
+                },
+            }
+        }
+
+        let to_column = |values: &[AnyValue]| {
+            Series::from_any_values_and_dtype(PlSmallStr::EMPTY, values, dtype, true)
+                .map(IntoColumn::into_column)
+        };
+
+        // Note: The transform may set the column name, so the names are set afterwards.
+        let min = projection.apply_transform(to_column(&min_values)?)?;
+        let max = projection.apply_transform(to_column(&max_values)?)?;
+
+        columns.extend([
+            min.with_name(format_pl_smallstr!("{c}_min")),
+            max.with_name(format_pl_smallstr!("{c}_max")),
+            Column::new(format_pl_smallstr!("{c}_nc"), null_counts),
+        ]);
+    }
+
+    if let Some(RowIndex { name, offset }) = row_index {
+        let (min, max): (Vec<IdxSize>, Vec<IdxSize>) = stripe_reads
+            .iter()
+            .map(|s| {
+                let start = offset.saturating_add(s.row_offset as IdxSize);
+                let end = start.saturating_add(stripe_num_rows(s).saturating_sub(1) as IdxSize);
+                (start, end)
+            })
+            .unzip();
+
+        columns.extend([
+            Column::new(format_pl_smallstr!("{name}_min"), min),
+            Column::new(format_pl_smallstr!("{name}_max"), max),
+            Column::new(
+                format_pl_smallstr!("{name}_nc"),
+                vec![0 as IdxSize; num_stripes],
+            ),
+        ]);
+    }
+
+    let statistics_df = DataFrame::new(num_stripes, columns)?;
+
+    sbp.evaluate_with_stat_df(&statistics_df).map(Some)
+}
+
+struct StripeDecoder {
+    bytes: Buffer<u8>,
+    metadata: Arc<OrcFileMetadata>,
+    projections: Arc<[OrcColumnProjection]>,
+    /// File column indices of `projections`.
+    file_projection: Vec<usize>,
+    row_index: Option<RowIndex>,
+}
+
+impl StripeDecoder {
+    fn decode(&self, stripe_read: &StripeRead) -> PolarsResult<DataFrame> {
+        let (rows_offset, rows_len) = stripe_read.slice;
+
+        let mut df = if self.projections.is_empty() {
+            DataFrame::empty_with_height(rows_len)
+        } else {
+            let df = decode_orc_stripe(
+                &self.bytes,
+                &self.metadata,
+                stripe_read.stripe_idx,
+                &self.file_projection,
+            )?
+            .slice(rows_offset as i64, rows_len);
+
+            let columns = self
+                .projections
+                .iter()
+                .zip(df.into_columns())
+                .map(|(projection, column)| projection.apply_transform(column))
+                .collect::<PolarsResult<Vec<_>>>()?;
+
+            DataFrame::new(rows_len, columns)?
+        };
+
+        if let Some(RowIndex { name, offset }) = &self.row_index {
+            let row_position = stripe_read.row_offset + rows_offset;
+            let offset = IdxSize::try_from(row_position)
+                .ok()
+                .and_then(|x| x.checked_add(*offset))
+                .ok_or_else(|| polars_err!(bigidx, ctx = "orc file", size = row_position))?;
+
+            df = df.with_row_index(name.clone(), Some(offset))?;
+        }
+
+        Ok(df)
+    }
+}
@@ -340,6 +340,8 @@ impl ParquetReadImpl {
             predicate_field_indices,
             non_predicate_field_indices,
             target_values_per_thread,
+            keep_dictionaries: self.options.keep_dictionaries,
+            keep_runs: self.options.keep_runs,
         }
     }
 }
@@ -5,13 +5,13 @@ use polars_core::frame::DataFrame;
 use polars_core::prelude::{ArrowField, BooleanChunked, ChunkFilter, Column, DataType, IntoColumn};
 use polars_core::series::Series;
 use polars_core::utils::arrow::bitmap::{Bitmap, MutableBitmap};
-use polars_error::PolarsResult;
+use polars_error::{PolarsResult, polars_ensure};
 use polars_io::RowIndex;
 use polars_io::predicates::{
     ColumnPredicateExpr, ColumnPredicates, ScanIOPredicate, SpecializedColumnPredicate,
 };
 pub use polars_io::prelude::_internal::PrefilterMaskSetting;
-use polars_io::prelude::try_set_sorted_flag;
+use polars_io::prelude::{try_set_sorted_flag, try_set_sorted_flag_column};
 use polars_parquet::read::{Filter, ParquetType, PredicateFilter, PrimitiveLogicalType};
 use polars_utils::pl_str::PlSmallStr;
 use polars_utils::{IdxSize, UnitVec};
@@ -34,6 +34,8 @@ pub(super) struct RowGroupDecoder {
     /// Indices into `projected_arrow_fields. This must be sorted.
     pub(super) non_predicate_field_indices: Arc<[usize]>,
     pub(super) target_values_per_thread: usize,
+    pub(super) keep_dictionaries: bool,
+    pub(super) keep_runs: bool,
 }
 
 impl RowGroupDecoder {
@@ -181,6 +183,8 @@ impl RowGroupDecoder {
 
         let projected_arrow_fields = projected_arrow_fields.clone();
         let row_group_data_2 = row_group_data.clone();
+        let keep_dictionaries = self.keep_dictionaries;
+        let keep_runs = self.keep_runs;
 
         let task_handles = {
             let projected_arrow_fields = projected_arrow_fields.clone();
@@ -211,6 +215,8 @@ impl RowGroupDecoder {
                                         projection.arrow_field(),
                                         &row_group_data,
                                         filter.clone(),
+                                        keep_dictionaries,
+                                        keep_runs,
                                         expected_num_rows,
                                     )?;
 
@@ -236,6 +242,8 @@ fn decode_column(
     arrow_field: &ArrowField,
     row_group_data: &RowGroupData,
     filter: Option<polars_parquet::read::Filter>,
+    keep_dictionaries: bool,
+    keep_runs: bool,
     expected_num_rows: usize,
 ) -> PolarsResult<(Column, Bitmap)> {
     let Some(iter) = row_group_data
@@ -265,6 +273,38 @@ fn decode_column(
         })
         .collect::<Vec<_>>();
 
+    if matches!(filter, None | Some(Filter::Range(_))) {
+        use polars_io::prelude::_internal::{to_dictionary_column, to_run_end_column};
+
+        let mut column = None;
+        if keep_dictionaries {
+            column = to_dictionary_column(&columns_to_deserialize, arrow_field, filter.as_ref())?;
+        }
+        if keep_runs && column.is_none() {
+            column = to_run_end_column(&columns_to_deserialize, arrow_field, filter.as_ref())?;
+        }
+        if let Some(mut column) = column {
+            polars_ensure!(
+                column.len() == expected_num_rows,
+                ComputeError: "parquet column '{}' decoded to {} rows, expected {}",
+                arrow_field.name, column.len(), expected_num_rows
+            );
+            if let Some(col_idxs) = row_group_data
+                .row_group_metadata
+                .columns_idxs_under_root_iter(&arrow_field.name)
+            {
+                if col_idxs.len() == 1 {
+                    try_set_sorted_flag_column(
+                        &mut column,
+                        col_idxs[0],
+                        &row_group_data.sorting_map,
+                    );
+                }
+            }
+            return Ok((column, Bitmap::default()));
+        }
+    }
+
     let skip_num_rows_check = matches!(filter, Some(Filter::Predicate(_)));
 
     let (arrays, pred_true_mask) = polars_io::prelude::_internal::to_deserializer(
@@ -357,6 +397,8 @@ fn decode_column_in_filter(
     use_column_predicates: bool,
     column_predicates: &ColumnPredicates,
     row_group_data: &RowGroupData,
+    keep_dictionaries: bool,
+    keep_runs: bool,
     projection_height: usize,
 ) -> PolarsResult<(Column, Bitmap)> {
     let mut filter = None;
@@ -382,7 +424,14 @@ fn decode_column_in_filter(
             }));
         }
     }
-    let (mut c, m) = decode_column(arrow_field, row_group_data, filter, projection_height)?;
+    let (mut c, m) = decode_column(
+        arrow_field,
+        row_group_data,
+        filter,
+        keep_dictionaries,
+        keep_runs,
+        projection_height,
+    )?;
 
     if let Some(constant) = constant {
         c = Column::new_scalar(c.name().clone(), constant.clone(), m.set_bits());
@@ -437,6 +486,8 @@ impl RowGroupDecoder {
             .len()
             .div_ceil(self.num_pipelines))
         .max(1);
+        let keep_dictionaries = self.keep_dictionaries;
+        let keep_runs = self.keep_runs;
         let task_handles = {
             let predicate_field_indices = self.predicate_field_indices.clone();
             let projected_arrow_fields = self.projected_arrow_fields.clone();
@@ -473,6 +524,8 @@ impl RowGroupDecoder {
                                         use_column_predicates,
                                         column_predicates.as_ref(),
                                         row_group_data.as_ref(),
+                                        keep_dictionaries,
+                                        keep_runs,
                                         projection_height,
                                     )?;
 
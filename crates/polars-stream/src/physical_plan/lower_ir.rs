@@ -664,6 +664,23 @@ pub fn lower_ir(
                     #[cfg(feature = "csv")]
                     FileScanIR::Csv { options } => Arc::new(Arc::clone(options)) as _,
 
+                    #[cfg(feature = "avro")]
+                    FileScanIR::Avro => {
+                        Arc::new(crate::nodes::io_sources::avro::builder::AvroReaderBuilder {}) as _
+                    },
+
+                    #[cfg(feature = "orc")]
+                    FileScanIR::Orc => {
+                        Arc::new(crate::nodes::io_sources::orc::builder::OrcReaderBuilder {}) as _
+                    },
+
+                    #[cfg(feature = "flight")]
+                    FileScanIR::Flight { options } => Arc::new(
+                        crate::nodes::io_sources::flight::builder::FlightReaderBuilder {
+                            options: options.clone(),
+                        },
+                    ) as _,
+
                     #[cfg(feature = "json")]
                     FileScanIR::NDJson { options } => Arc::new(options.clone()) as _,
 
@@ -58,9 +58,51 @@ fn apply_offsets_to_datetime(
     }
 }
 
-pub fn impl_offset_by(ts: &Series, offsets: &Series) -> PolarsResult<Series> {
-    let offsets = offsets.str()?;
+#[cfg(feature = "dtype-interval")]
+fn apply_intervals_to_datetime(
+    datetime: &Logical<DatetimeType, Int64Type>,
+    offsets: &IntervalChunked,
+    time_zone: Option<&Tz>,
+) -> PolarsResult<Int64Chunked> {
+    let offset_fn = match datetime.time_unit() {
+        TimeUnit::Milliseconds => Duration::add_ms,
+        TimeUnit::Microseconds => Duration::add_us,
+        TimeUnit::Nanoseconds => Duration::add_ns,
+    };
+    // The components of an interval carry their own sign, so we apply them one at a time:
+    // first the calendar months, then the calendar days and finally the fixed nanoseconds.
+    broadcast_try_binary_elementwise(
+        datetime.physical(),
+        offsets.physical(),
+        |timestamp_opt, offset_opt| match (timestamp_opt, offset_opt) {
+            (Some(timestamp), Some(offset)) => {
+                let offset = interval_from_i128(offset);
+                let t = offset_fn(
+                    &Duration::from_months(offset.months() as i64),
+                    timestamp,
+                    time_zone,
+                )?;
+                let t = offset_fn(&Duration::from_days(offset.days() as i64), t, time_zone)?;
+                offset_fn(&Duration::from_nsecs(offset.ns()), t, time_zone).map(Some)
+            },
+            _ => Ok(None),
+        },
+    )
+}
 
+fn apply_offset_series_to_datetime(
+    datetime: &Logical<DatetimeType, Int64Type>,
+    offsets: &Series,
+    time_zone: Option<&Tz>,
+) -> PolarsResult<Int64Chunked> {
+    match offsets.dtype() {
+        #[cfg(feature = "dtype-interval")]
+        DataType::Interval => apply_intervals_to_datetime(datetime, offsets.interval()?, time_zone),
+        _ => apply_offsets_to_datetime(datetime, offsets.str()?, time_zone),
+    }
+}
+
+pub fn impl_offset_by(ts: &Series, offsets: &Series) -> PolarsResult<Series> {
     polars_ensure!(
         ts.len() == offsets.len() || offsets.len() == 1 || ts.len() == 1,
         length_mismatch = "dt.offset_by",
@@ -78,8 +120,8 @@ pub fn impl_offset_by(ts: &Series, offsets: &Series) -> PolarsResult<Series> {
         DataType::Datetime(_, tz) => tz.clone(),
         _ => polars_bail!(InvalidOperation: "expected Date or Datetime, got {}", dtype),
     };
-    let preserve_sortedness = match offsets.len() {
-        1 => match offsets.get(0) {
+    let preserve_sortedness = match (offsets.dtype(), offsets.len()) {
+        (DataType::String, 1) => match offsets.str()?.get(0) {
             Some(offset) => {
                 let offset = Duration::try_parse(offset)?;
                 offset.is_constant_duration(tz.as_ref())
@@ -95,7 +137,7 @@ pub fn impl_offset_by(ts: &Series, offsets: &Series) -> PolarsResult<Series> {
                 .cast(&DataType::Datetime(TimeUnit::Microseconds, None))
                 .unwrap();
             let datetime = ts.datetime().unwrap();
-            let out = apply_offsets_to_datetime(datetime, offsets, None)?;
+            let out = apply_offset_series_to_datetime(datetime, offsets, None)?;
             out.cast(&DataType::Datetime(TimeUnit::Microseconds, None))
                 .unwrap()
                 .cast(&DataType::Date)
@@ -105,10 +147,12 @@ pub fn impl_offset_by(ts: &Series, offsets: &Series) -> PolarsResult<Series> {
 
             let out = match tz {
                 #[cfg(feature = "timezones")]
-                Some(tz) => {
-                    apply_offsets_to_datetime(datetime, offsets, tz.parse::<Tz>().ok().as_ref())?
-                },
-                _ => apply_offsets_to_datetime(datetime, offsets, None)?,
+                Some(tz) => apply_offset_series_to_datetime(
+                    datetime,
+                    offsets,
+                    tz.parse::<Tz>().ok().as_ref(),
+                )?,
+                _ => apply_offset_series_to_datetime(datetime, offsets, None)?,
             };
             out.cast(&DataType::Datetime(*tu, tz.clone()))
         },
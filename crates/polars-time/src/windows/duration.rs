@@ -11,6 +11,8 @@ use arrow::temporal_conversions::{
 };
 use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
 use polars_core::datatypes::DataType;
+#[cfg(feature = "dtype-interval")]
+use polars_core::datatypes::months_days_ns;
 use polars_core::prelude::{
     PolarsResult, TimeZone, datetime_to_timestamp_ms, datetime_to_timestamp_ns,
     datetime_to_timestamp_us, polars_bail,
@@ -493,6 +495,21 @@ impl Duration {
         self.negative
     }
 
+    /// Convert to a calendar interval, keeping the months, days and nanoseconds apart.
+    ///
+    /// Returns `None` if the months or days don't fit in 32 bits.
+    #[cfg(feature = "dtype-interval")]
+    pub fn to_interval(&self) -> Option<months_days_ns> {
+        let sign = if self.negative { -1 } else { 1 };
+        let months = i32::try_from(self.months).ok()?;
+        let days = i32::try_from(self.weeks.checked_mul(7)?.checked_add(self.days)?).ok()?;
+        Some(months_days_ns::new(
+            sign * months,
+            sign * days,
+            sign as i64 * self.nsecs,
+        ))
+    }
+
     /// Estimated duration of the window duration. Not a very good one if not a constant duration.
     #[doc(hidden)]
     pub const fn duration_ns(&self) -> i64 {
@@ -203,11 +203,14 @@
 //!     - `parquet` - Read Apache Parquet format
 //!     - `json` - JSON serialization
 //!     - `ipc` - Arrow's IPC format serialization
+//!     - `flight` - Read from and serve data over Arrow Flight
+//!     - `excel` - Read Excel (xlsx) and OpenDocument (ods) spreadsheets and write xlsx
 //!     - `decompress` - Automatically infer compression of csvs and decompress them.
 //!       Supported compressions:
 //!          - gzip
 //!          - zlib
 //!          - zstd
+//!     - `encoding` - Read csv and ndjson files in Windows-1252, UTF-16 or Shift-JIS encoding.
 //!
 //! [`StringChunked`]: crate::datatypes::StringChunked
 //! [column selection]: polars_lazy::dsl::col
@@ -227,6 +230,7 @@
 //!     - `diagonal_concat` - Concat diagonally thereby combining different schemas.
 //!     - `dataframe_arithmetic` - Arithmetic on ([`Dataframe`] and [`DataFrame`]s) and ([`DataFrame`] on [`Series`])
 //!     - `partition_by` - Split into multiple [`DataFrame`]s partitioned by groups.
+//!     - `compress_in_memory` - Keep integer columns bitpacked in memory.
 //! * [`Series`]/[`Expr`] operations:
 //!     - `is_in` - Check for membership in [`Series`].
 //!     - `zip_with` - [Zip two Series/ ChunkedArrays](crate::chunked_array::ops::ChunkZip).
@@ -300,12 +304,19 @@
 //! | Datetime                | dtype-datetime    |
 //! | Time                    | dtype-time        |
 //! | Duration                | dtype-duration    |
+//! | Interval                | dtype-interval    |
 //! | Int8                    | dtype-i8          |
 //! | Int16                   | dtype-i16         |
 //! | UInt8                   | dtype-u8          |
 //! | UInt16                  | dtype-u16         |
 //! | Categorical             | dtype-categorical |
 //! | Struct                  | dtype-struct      |
+//! | Map                     | dtype-map         |
+//! | Variant                 | dtype-variant     |
+//! | Decimal256              | dtype-decimal256  |
+//! | Geometry (WKB)          | geo               |
+//! | UUID                    | dtype-uuid        |
+//! | IPv4, IPv6              | dtype-ip          |
 //!
 //!
 //! Or you can choose one of the preconfigured pre-sets.
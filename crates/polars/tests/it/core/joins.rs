@@ -645,6 +645,51 @@ fn test_join_floats() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg_attr(miri, ignore)]
+#[cfg(feature = "dtype-decimal256")]
+fn test_decimal256_multi_key() -> PolarsResult<()> {
+    let dec =
+        |values: &[&str]| Series::new("d".into(), values).strict_cast(&DataType::Decimal256(40, 2));
+    let big = "123456789012345678901234567890.25";
+    let mut df = DataFrame::new_infer_height(vec![
+        dec(&[big, "-1.5", "2.00", "-1.5", big, "0"])?.into_column(),
+        Column::new("k".into(), [1, 2, 1, 1, 1, 2]),
+    ])?;
+
+    df = df.sort(["d", "k"], SortMultipleOptions::default())?;
+    assert_eq!(
+        df.column("d")?.cast(&DataType::String)?.str()?.get(0),
+        Some("-1.50")
+    );
+    assert_eq!(
+        Vec::from(df.column("k")?.i32()?),
+        [Some(1), Some(2), Some(2), Some(1), Some(1), Some(1)]
+    );
+    let last = df.column("d")?.cast(&DataType::String)?;
+    assert_eq!(last.str()?.get(5), Some(big));
+
+    assert_eq!(df.group_by(["d", "k"])?.get_groups().len(), 5);
+
+    let right = DataFrame::new_infer_height(vec![
+        dec(&[big, "-1.5", "7"])?.into_column(),
+        Column::new("k".into(), [1, 1, 1]),
+        Column::new("v".into(), ["a", "b", "c"]),
+    ])?;
+    let out = df.inner_join(&right, ["d", "k"], ["d", "k"])?;
+    assert_eq!(out.height(), 3);
+    assert_eq!(
+        out.column("v")?
+            .str()?
+            .into_iter()
+            .flatten()
+            .filter(|v| *v == "a")
+            .count(),
+        2
+    );
+    Ok(())
+}
+
 #[test]
 #[cfg_attr(miri, ignore)]
 #[cfg(feature = "lazy")]
@@ -0,0 +1,80 @@
+use std::path::PathBuf;
+
+use polars::io::avro::{AvroCodec, AvroCompression, AvroReader, AvroWriter, AvroWriterOptions};
+use polars::io::{SerReader, SerWriter};
+use polars::prelude::*;
+
+fn create_df() -> PolarsResult<DataFrame> {
+    let a = df!(
+        "id" => [1i64, 2, 3],
+        "name" => [Some("a"), None, Some("c")],
+        "value" => [1.5f64, 2.5, 3.5],
+    )?;
+    let b = df!(
+        "id" => [4i64, 5],
+        "name" => [Some("d"), Some("e")],
+        "value" => [4.5f64, 5.5],
+    )?;
+    a.vstack(&b)
+}
+
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("polars-test-{name}-{}.avro", std::process::id()))
+}
+
+#[test]
+fn test_scan_avro() -> PolarsResult<()> {
+    let mut df = create_df()?;
+    let path = temp_path("scan-avro");
+
+    // Every chunk is written as a separate data block.
+    AvroWriter::new(std::fs::File::create(&path)?)
+        .with_compression(Some(AvroCompression::Deflate))
+        .finish(&mut df)?;
+
+    let scan = || -> PolarsResult<LazyFrame> {
+        LazyFrame::scan_avro(PlRefPath::try_from_path(&path)?, UnifiedScanArgs::default())
+    };
+
+    let out = scan()?.collect()?;
+    assert!(out.equals_missing(&df));
+
+    let out = scan()?
+        .with_row_index("idx", None)
+        .select([col("idx"), col("name")])
+        .slice(2, 2)
+        .collect()?;
+    let expected = df
+        .with_row_index("idx".into(), None)?
+        .select(["idx", "name"])?
+        .slice(2, 2);
+    assert!(out.equals_missing(&expected));
+
+    std::fs::remove_file(&path)?;
+    Ok(())
+}
+
+#[test]
+fn test_sink_avro() -> PolarsResult<()> {
+    let df = create_df()?;
+    let path = temp_path("sink-avro");
+
+    df.clone()
+        .lazy()
+        .sink(
+            SinkDestination::File {
+                target: SinkTarget::Path(PlRefPath::try_from_path(&path)?),
+            },
+            FileWriteFormat::Avro(AvroWriterOptions {
+                compression: Some(AvroCodec::Snappy),
+                name: "record".into(),
+            }),
+            UnifiedSinkArgs::default(),
+        )?
+        .collect()?;
+
+    let out = AvroReader::new(std::fs::File::open(&path)?).finish()?;
+    std::fs::remove_file(&path)?;
+    assert!(out.equals_missing(&df));
+    Ok(())
+}
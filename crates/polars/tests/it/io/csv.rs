@@ -1401,3 +1401,155 @@ fn test_single_column_leading_empty_line_25166() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "encoding")]
+fn test_read_csv_utf16_with_bom() -> PolarsResult<()> {
+    let csv = "name,value\nMüller,1\n日本,2\n";
+    let mut bytes = vec![0xFF, 0xFE];
+    bytes.extend(csv.encode_utf16().flat_map(u16::to_le_bytes));
+
+    let file = Cursor::new(bytes);
+    let df = CsvReadOptions::default()
+        .map_parse_options(|parse_options| parse_options.with_encoding(CsvEncoding::Utf16Le))
+        .into_reader_with_file_handle(file)
+        .finish()?;
+
+    assert_eq!(df.shape(), (2, 2));
+    assert_eq!(df.column("name")?.str()?.get(0), Some("Müller"));
+    assert_eq!(df.column("name")?.str()?.get(1), Some("日本"));
+    assert_eq!(df.column("value")?.i64()?.get(1), Some(2));
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "encoding")]
+fn test_read_csv_windows_1252() -> PolarsResult<()> {
+    let file = Cursor::new(b"a;b\n\xC4pfel;\x80 5\n".to_vec());
+    let df = CsvReadOptions::default()
+        .map_parse_options(|parse_options| {
+            parse_options
+                .with_separator(b';')
+                .with_encoding(CsvEncoding::Windows1252)
+        })
+        .into_reader_with_file_handle(file)
+        .finish()?;
+
+    assert_eq!(df.column("a")?.str()?.get(0), Some("Äpfel"));
+    assert_eq!(df.column("b")?.str()?.get(0), Some("€ 5"));
+
+    Ok(())
+}
+
+#[test]
+fn test_read_csv_sniff_dialect() -> PolarsResult<()> {
+    let csv = "name;price;count\n\"a;b\";1,5;3\n# note\nc;2,25;4\nd;10,0;5\n";
+    let file = Cursor::new(csv);
+    let df = CsvReadOptions::default()
+        .with_has_header(false)
+        .with_sniff_dialect(true)
+        .into_reader_with_file_handle(file)
+        .finish()?;
+
+    assert_eq!(df.get_column_names(), &["name", "price", "count"]);
+    assert_eq!(df.column("name")?.str()?.get(0), Some("a;b"));
+    assert_eq!(df.column("price")?.dtype(), &DataType::Float64);
+    assert_eq!(df.column("price")?.f64()?.get(1), Some(2.25));
+    assert_eq!(df.column("count")?.i64()?.get(2), Some(5));
+
+    Ok(())
+}
+
+#[test]
+fn test_read_write_csv_multi_byte_separator() -> PolarsResult<()> {
+    let csv = "a~|~b~|~c\n1~|~\"x~|~y\"~|~2.5\n2~|~z|w~|~3.5\n";
+    let file = Cursor::new(csv);
+    assert!(CsvSeparator::new_from_str("").is_err());
+    assert!(CsvSeparator::new_from_str("|\n").is_err());
+    let separator = CsvSeparator::new_from_str("~|~")?;
+    let mut df = CsvReadOptions::default()
+        .map_parse_options(|parse_options| parse_options.with_separator(separator.clone()))
+        .into_reader_with_file_handle(file)
+        .finish()?;
+
+    assert_eq!(df.get_column_names(), &["a", "b", "c"]);
+    assert_eq!(df.column("b")?.str()?.get(0), Some("x~|~y"));
+    assert_eq!(df.column("b")?.str()?.get(1), Some("z|w"));
+    assert_eq!(df.column("c")?.f64()?.get(1), Some(3.5));
+
+    let separator = CsvSeparator::new_from_str("\x01\x02")?;
+    let mut buf: Vec<u8> = Vec::new();
+    CsvWriter::new(&mut buf)
+        .with_separator(separator.clone())
+        .finish(&mut df)?;
+    assert_eq!(
+        std::str::from_utf8(&buf).unwrap(),
+        "a\x01\x02b\x01\x02c\n1\x01\x02x~|~y\x01\x022.5\n2\x01\x02z|w\x01\x023.5\n"
+    );
+
+    let df_read = CsvReadOptions::default()
+        .map_parse_options(|parse_options| parse_options.with_separator(separator.clone()))
+        .into_reader_with_file_handle(Cursor::new(buf))
+        .finish()?;
+    assert!(df_read.equals(&df));
+
+    Ok(())
+}
+
+#[test]
+fn test_write_read_csv_colon_separator() -> PolarsResult<()> {
+    let mut df = df![
+        "a" => ["x::y", "x:", ":x", "x:y", "plain"],
+        "b" => [1i64, 2, 3, 4, 5],
+    ]?;
+    let separator = CsvSeparator::new_from_str("::")?;
+
+    let mut buf: Vec<u8> = Vec::new();
+    CsvWriter::new(&mut buf)
+        .with_separator(separator.clone())
+        .finish(&mut df)?;
+    assert_eq!(
+        std::str::from_utf8(&buf).unwrap(),
+        "a::b\n\"x::y\"::1\n\"x:\"::2\n:x::3\nx:y::4\nplain::5\n"
+    );
+
+    let df_read = CsvReadOptions::default()
+        .map_parse_options(|parse_options| parse_options.with_separator(separator.clone()))
+        .into_reader_with_file_handle(Cursor::new(buf))
+        .finish()?;
+    assert!(df_read.equals(&df));
+
+    Ok(())
+}
+
+#[test]
+fn test_read_csv_mixed_line_endings() -> PolarsResult<()> {
+    let csv = "a,b\r\n1,x\r2,y\r\n3,z\r";
+    let parse_options = CsvParseOptions::default().with_line_terminator("\r\n")?;
+    assert_eq!(parse_options.eol_char, b'\r');
+    let df = CsvReadOptions::default()
+        .with_parse_options(parse_options)
+        .into_reader_with_file_handle(Cursor::new(csv))
+        .finish()?;
+
+    assert_eq!(df.get_column_names(), &["a", "b"]);
+    assert_eq!(
+        df.column("a")?.i64()?.to_vec(),
+        &[Some(1), Some(2), Some(3)]
+    );
+    assert_eq!(
+        df.column("b")?.str()?.into_iter().collect::<Vec<_>>(),
+        &[Some("x"), Some("y"), Some("z")]
+    );
+
+    // Other line terminators of multiple bytes cannot be read.
+    assert!(
+        CsvParseOptions::default()
+            .with_line_terminator("\n\r")
+            .is_err()
+    );
+    assert!(CsvParseOptions::default().with_line_terminator("").is_err());
+
+    Ok(())
+}
@@ -0,0 +1,92 @@
+use std::sync::Arc;
+
+use polars::prelude::*;
+use polars_io::flight::FlightServer;
+
+use crate::io::create_df;
+
+#[test]
+fn test_scan_flight_registered_frame() -> PolarsResult<()> {
+    let df = create_df();
+
+    let resolver = LazyFrameFlightResolver::new();
+    resolver.register("temps", df.clone().lazy());
+    let resolver = Arc::new(resolver);
+    let server = FlightServer::new(resolver.clone()).serve("127.0.0.1:0".parse().unwrap())?;
+
+    let lf = LazyFrame::scan_flight(&server.location(), "temps", None)?;
+    assert!(lf.clone().collect()?.equals(&df));
+
+    let out = lf.filter(col("temp").gt(lit(5.0))).limit(2).collect()?;
+    assert_eq!(out.height(), 2);
+
+    // Frames can be (un)registered while serving.
+    resolver.register("hot", df.clone().lazy().filter(col("temp").gt(lit(20.0))));
+    let out = LazyFrame::scan_flight(&server.location(), "hot", None)?.collect()?;
+    assert_eq!(out.height(), 1);
+
+    assert!(resolver.unregister("hot").is_some());
+    assert!(LazyFrame::scan_flight(&server.location(), "hot", None).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn test_scan_flight_batches() -> PolarsResult<()> {
+    // Every chunk is sent as a record batch.
+    let df = create_df();
+    let mut chunked = df.clone();
+    chunked.vstack_mut(&df)?;
+    chunked.vstack_mut(&df)?;
+    assert_eq!(chunked.first_col_n_chunks(), 3);
+
+    let resolver = LazyFrameFlightResolver::new();
+    resolver.register("temps", chunked.clone().lazy());
+    let server = FlightServer::new(Arc::new(resolver)).serve("127.0.0.1:0".parse().unwrap())?;
+
+    let lf = LazyFrame::scan_flight(&server.location(), "temps", None)?;
+    assert!(lf.clone().collect()?.equals(&chunked));
+
+    // The slice spans all batches.
+    let out = lf
+        .clone()
+        .with_row_index("index", None)
+        .slice(3, 9)
+        .collect()?;
+    let expected = chunked
+        .clone()
+        .lazy()
+        .with_row_index("index", None)
+        .slice(3, 9)
+        .collect()?;
+    assert!(out.equals(&expected));
+
+    let out = lf.select([col("days")]).limit(2).collect()?;
+    assert!(out.equals(&chunked.select(["days"])?.head(Some(2))));
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "sql")]
+fn test_scan_flight_sql_context() -> PolarsResult<()> {
+    use polars::sql::SQLContext;
+
+    let mut ctx = SQLContext::new();
+    ctx.register("temps", create_df().lazy());
+    let server = FlightServer::new(Arc::new(ctx.into_flight_resolver()))
+        .serve("127.0.0.1:0".parse().unwrap())?;
+
+    let out = LazyFrame::scan_flight(
+        &server.location(),
+        "SELECT days FROM temps WHERE temp < 5",
+        None,
+    )?
+    .collect()?;
+    assert!(out.equals(&df!("days" => [3, 4])?));
+
+    let out = LazyFrame::scan_flight(&server.location(), "temps", None)?.collect()?;
+    assert_eq!(out.height(), 5);
+
+    Ok(())
+}
@@ -155,3 +155,68 @@ fn write_and_read_ipc_empty_series() {
     let df_read = IpcReader::new(buf).finish().unwrap();
     assert!(df.equals(&df_read));
 }
+
+#[test]
+#[cfg(all(feature = "dtype-uuid", feature = "dtype-ip"))]
+fn test_uuid_ip_roundtrip() -> PolarsResult<()> {
+    use polars_core::datatypes::extension::{IpVersion, ip_dtype, uuid_dtype};
+
+    let id = Series::new(
+        "id".into(),
+        [
+            Some("67e55044-10b1-426f-9247-bb680e5fe0c8"),
+            None,
+            Some("01890a5d-ac96-774b-bcce-b302099a8057"),
+        ],
+    )
+    .cast(&uuid_dtype())?;
+    let v4 = Series::new("v4".into(), [Some("192.168.1.10"), Some("10.0.0.1"), None])
+        .cast(&ip_dtype(IpVersion::V4))?;
+    let v6 = Series::new(
+        "v6".into(),
+        [None, Some("::1"), Some("2001:db8::ff00:42:8329")],
+    )
+    .cast(&ip_dtype(IpVersion::V6))?;
+    let mut df = DataFrame::new_infer_height(vec![id.into(), v4.into(), v6.into()])?;
+
+    // `arrow.uuid` is exported on its canonical fixed-size binary storage.
+    let ArrowDataType::Extension(ext) = uuid_dtype().to_arrow(CompatLevel::newest()) else {
+        panic!("expected an extension type");
+    };
+    assert_eq!(ext.inner, ArrowDataType::FixedSizeBinary(16));
+    let chunk = df
+        .column("id")?
+        .as_materialized_series()
+        .to_arrow(0, CompatLevel::newest());
+    assert_eq!(chunk.dtype(), &ArrowDataType::Extension(ext));
+
+    for compat_level in [CompatLevel::oldest(), CompatLevel::newest()] {
+        let mut buf = Cursor::new(Vec::new());
+        IpcWriter::new(&mut buf)
+            .with_compat_level(compat_level)
+            .finish(&mut df)?;
+        buf.set_position(0);
+        let read_df = IpcReader::new(buf).finish()?;
+        assert_eq!(read_df.schema(), df.schema());
+        assert!(df.equals_missing(&read_df));
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-map")]
+fn test_map_roundtrip() -> PolarsResult<()> {
+    let mut df = crate::io::create_map_df()?;
+
+    for compat_level in [CompatLevel::oldest(), CompatLevel::newest()] {
+        let mut buf = Cursor::new(Vec::new());
+        IpcWriter::new(&mut buf)
+            .with_compat_level(compat_level)
+            .finish(&mut df)?;
+        buf.set_position(0);
+        let read_df = IpcReader::new(buf).finish()?;
+        assert_eq!(read_df.schema(), df.schema());
+        assert!(df.equals_missing(&read_df));
+    }
+    Ok(())
+}
@@ -102,3 +102,50 @@ fn read_ndjson_with_trailing_newline() {
     .unwrap();
     assert!(expected.equals(&df));
 }
+
+#[test]
+#[cfg(all(feature = "lazy", feature = "new_streaming"))]
+fn test_scan_json_array_pointer() -> PolarsResult<()> {
+    let json = r#"{
+        "meta": {"items": [{"a": -1}], "count": 3},
+        "data": {"page": 1, "items": [
+            {"a": 1, "b": "x", "c": [1, 2]},
+            {"a": 2, "b": null, "c": []},
+            {"a": 3, "b": "z", "c": null}
+        ]}
+    }"#;
+    let tmp_dir = tempfile::tempdir()?;
+    let path = tmp_dir.path().join("pages.json");
+    std::fs::write(&path, json)?;
+
+    let scan = |pointer: &str| {
+        LazyJsonLineReader::new(PlRefPath::try_from_path(&path)?)
+            .with_json_array_pointer(Some(pointer.into()))
+            .finish()
+    };
+
+    let lf = scan("/data/items")?;
+    assert_eq!(
+        lf.clone().collect_schema()?.get("c"),
+        Some(&DataType::List(Box::new(DataType::Int64)))
+    );
+
+    let out = lf
+        .clone()
+        .select([col("a"), col("b")])
+        .collect_with_engine(Engine::Streaming)?;
+    let expected = df!(
+        "a" => [1i64, 2, 3],
+        "b" => [Some("x"), None, Some("z")],
+    )?;
+    assert!(out.equals_missing(&expected));
+
+    let out = lf
+        .filter(col("a").gt(lit(1)))
+        .select([col("c").list().len()])
+        .collect_with_engine(Engine::Streaming)?;
+    assert_eq!(Vec::from(out.column("c")?.idx()?), [Some(0), None]);
+
+    assert!(scan("/data/missing").and_then(|lf| lf.collect()).is_err());
+    Ok(())
+}
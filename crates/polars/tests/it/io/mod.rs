@@ -9,10 +9,14 @@ mod parquet;
 #[cfg(feature = "avro")]
 mod avro;
 
+#[cfg(all(feature = "flight", feature = "lazy"))]
+mod flight;
 #[cfg(feature = "ipc")]
 mod ipc;
 #[cfg(feature = "ipc_streaming")]
 mod ipc_stream;
+#[cfg(all(feature = "orc", feature = "lazy"))]
+mod orc;
 
 use polars::prelude::*;
 
@@ -21,3 +25,25 @@ pub(crate) fn create_df() -> DataFrame {
     let s1 = Column::new("temp".into(), [22.1, 19.9, 7., 2., 3.].as_ref());
     DataFrame::new_infer_height(vec![s0, s1]).unwrap()
 }
+
+/// A map column with null, empty and null-valued entries.
+#[cfg(feature = "dtype-map")]
+pub(crate) fn create_map_df() -> PolarsResult<DataFrame> {
+    let entries = |keys: &[&str], values: &[Option<i32>]| {
+        let keys = Series::new("key".into(), keys);
+        let values = Series::new("value".into(), values);
+        StructChunked::from_series("".into(), keys.len(), [keys, values].iter())
+            .map(|ca| ca.into_series())
+    };
+    let list: ListChunked = [
+        Some(entries(&["a", "b"], &[Some(1), None])?),
+        None,
+        Some(entries(&[], &[])?),
+        Some(entries(&["c"], &[Some(3)])?),
+    ]
+    .into_iter()
+    .collect();
+    let map = list.with_name("m".into()).into_map()?.into_series();
+    let id = Series::new("id".into(), [1, 2, 3, 4]);
+    DataFrame::new_infer_height(vec![id.into(), map.into()])
+}
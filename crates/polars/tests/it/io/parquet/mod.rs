@@ -206,3 +206,229 @@ fn test_vstack_empty_3220() -> PolarsResult<()> {
     assert!(stacked.equals(&read_df));
     Ok(())
 }
+
+#[test]
+fn test_read_run_end_encoded() -> PolarsResult<()> {
+    let sparse = (0..1000)
+        .map(|i| (i % 500 == 0).then_some(i as i64))
+        .collect::<Int64Chunked>();
+    let constant = StringChunked::full("b".into(), "abc", 1000);
+    let mut df = DataFrame::new_infer_height(vec![
+        sparse.with_name("a".into()).into_column(),
+        constant.into_column(),
+    ])?;
+
+    let mut buf = Cursor::new(Vec::new());
+    ParquetWriter::new(&mut buf).finish(&mut df)?;
+    buf.set_position(0);
+    let read_df = ParquetReader::new(&mut buf).finish()?;
+    for column in read_df.columns() {
+        assert!(column.as_run_end_column().is_none());
+    }
+    assert!(df.equals_missing(&read_df));
+
+    buf.set_position(0);
+    let read_df = ParquetReader::new(buf).with_keep_runs(true).finish()?;
+    for column in read_df.columns() {
+        assert!(column.as_run_end_column().is_some());
+    }
+    assert!(df.equals_missing(&read_df));
+    Ok(())
+}
+
+#[test]
+fn test_read_run_end_encoded_sorted() -> PolarsResult<()> {
+    use polars_core::series::IsSorted;
+
+    let mut sorted = Int64Chunked::from_iter_values("a".into(), (0..1000i64).map(|i| i / 100));
+    sorted.set_sorted_flag(IsSorted::Ascending);
+    let mut df = DataFrame::new_infer_height(vec![sorted.into_column()])?;
+
+    let mut buf = Cursor::new(Vec::new());
+    ParquetWriter::new(&mut buf).finish(&mut df)?;
+    let read_df = ParquetReader::new(buf).with_keep_runs(true).finish()?;
+
+    let column = read_df.column("a")?;
+    assert!(column.as_run_end_column().is_some());
+    assert_eq!(column.is_sorted_flag(), IsSorted::Ascending);
+    assert!(df.equals(&read_df));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dtype-interval")]
+fn test_interval_roundtrip() -> PolarsResult<()> {
+    let interval = Series::new("i".into(), [Some("1mo 2d 3s"), None, Some("-1y 5ms")])
+        .cast(&DataType::Interval)?;
+    let mut df = DataFrame::new_infer_height(vec![interval.into_column()])?;
+
+    let mut buf = Cursor::new(Vec::new());
+    ParquetWriter::new(&mut buf).finish(&mut df)?;
+    let read_df = ParquetReader::new(buf).finish()?;
+    assert_eq!(read_df.column("i")?.dtype(), &DataType::Interval);
+    assert!(df.equals_missing(&read_df));
+
+    // Parquet INTERVAL only stores whole milliseconds.
+    let interval = Series::new("i".into(), ["1us"]).cast(&DataType::Interval)?;
+    let mut df = DataFrame::new_infer_height(vec![interval.into_column()])?;
+    let mut buf = Cursor::new(Vec::new());
+    assert!(ParquetWriter::new(&mut buf).finish(&mut df).is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "dtype-variant", feature = "dtype-struct"))]
+fn test_variant_detection() -> PolarsResult<()> {
+    let variant = Series::new("v".into(), [Some(r#"{"a": 1}"#), None, Some("[1, 2]")])
+        .cast(&DataType::Variant)?;
+    // A struct with the layout of a variant.
+    let metadata = Series::new("metadata".into(), [b"m".as_slice(), b"m", b"m"]);
+    let value = Series::new("value".into(), [b"x".as_slice(), b"y", b"z"]);
+    let layout = StructChunked::from_series("s".into(), 3, [metadata, value].iter())?;
+    let mut df = DataFrame::new_infer_height(vec![variant.into_column(), layout.into_column()])?;
+
+    let mut buf = Cursor::new(Vec::new());
+    ParquetWriter::new(&mut buf).finish(&mut df)?;
+    let read_df = ParquetReader::new(buf).finish()?;
+    assert_eq!(read_df.column("v")?.dtype(), &DataType::Variant);
+    assert_eq!(read_df.column("s")?.dtype(), df.column("s")?.dtype());
+    assert!(read_df.column("s")?.equals_missing(df.column("s")?));
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "dtype-uuid", feature = "dtype-ip"))]
+fn test_uuid_ip_roundtrip() -> PolarsResult<()> {
+    use polars_core::datatypes::extension::{IpVersion, ip_dtype, uuid_dtype};
+
+    let id = Series::new(
+        "id".into(),
+        [
+            Some("67e55044-10b1-426f-9247-bb680e5fe0c8"),
+            None,
+            Some("01890a5d-ac96-774b-bcce-b302099a8057"),
+        ],
+    )
+    .cast(&uuid_dtype())?;
+    let v4 = Series::new("v4".into(), [Some("192.168.1.10"), Some("10.0.0.1"), None])
+        .cast(&ip_dtype(IpVersion::V4))?;
+    let v6 = Series::new(
+        "v6".into(),
+        [None, Some("::1"), Some("2001:db8::ff00:42:8329")],
+    )
+    .cast(&ip_dtype(IpVersion::V6))?;
+    let mut df = DataFrame::new_infer_height(vec![id.into(), v4.into(), v6.into()])?;
+
+    let mut buf = Cursor::new(Vec::new());
+    ParquetWriter::new(&mut buf).finish(&mut df)?;
+    let read_df = ParquetReader::new(buf).finish()?;
+    assert_eq!(read_df.schema(), df.schema());
+    assert!(df.equals_missing(&read_df));
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "geo", feature = "lazy"))]
+fn test_geoparquet_sink_roundtrip() -> PolarsResult<()> {
+    use polars_core::datatypes::extension::{geometry_dtype, wkt_to_wkb};
+
+    let wkt = StringChunked::new(
+        "geom".into(),
+        [Some("POINT (1 2)"), None, Some("LINESTRING (0 0, 1 1)")],
+    );
+    let geom = wkt_to_wkb(&wkt, true)?
+        .into_series()
+        .cast(&geometry_dtype())?;
+    let df =
+        DataFrame::new_infer_height(vec![Column::new("id".into(), [1i64, 2, 3]), geom.into()])?;
+
+    // File sinks run on the streaming engine, which shares the footer writer of the eager one.
+    let tmp_dir = tempfile::tempdir()?;
+    let path = tmp_dir.path().join("geo.parquet");
+    df.clone()
+        .lazy()
+        .sink(
+            SinkDestination::File {
+                target: SinkTarget::Path(PlRefPath::try_from_path(&path)?),
+            },
+            FileWriteFormat::Parquet(std::sync::Arc::new(ParquetWriteOptions::default())),
+            UnifiedSinkArgs::default(),
+        )?
+        .collect()?;
+
+    let mut file = std::fs::File::open(&path)?;
+    let metadata = polars_parquet::read::read_metadata(&mut file).unwrap();
+    let geo = metadata
+        .key_value_metadata()
+        .iter()
+        .flatten()
+        .find(|kv| kv.key == "geo")
+        .and_then(|kv| kv.value.clone());
+    assert!(geo.is_some_and(|geo| geo.contains(r#""primary_column":"geom""#)));
+
+    let read_df = ParquetReader::new(std::fs::File::open(&path)?).finish()?;
+    assert_eq!(read_df.schema(), df.schema());
+    assert!(df.equals_missing(&read_df));
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "dtype-date", feature = "lazy"))]
+fn test_sorted_flag_roundtrip() -> PolarsResult<()> {
+    use polars_core::series::IsSorted;
+
+    let mut s = Series::new("s".into(), [None, Some("a"), Some("b"), Some("b")]);
+    s.set_sorted_flag(IsSorted::Ascending);
+    let mut d = Int32Chunked::new("d".into(), [30, 20, 10, 0])
+        .into_date()
+        .into_series();
+    d.set_sorted_flag(IsSorted::Descending);
+    let x = Series::new("x".into(), [2i64, 1, 3, 0]);
+    let mut df = DataFrame::new_infer_height(vec![x.into(), s.into(), d.into()])?;
+
+    let expected = df.clone();
+
+    let check = |out: &DataFrame| -> PolarsResult<()> {
+        assert!(out.equals_missing(&expected));
+        assert_eq!(out.column("s")?.is_sorted_flag(), IsSorted::Ascending);
+        assert_eq!(out.column("d")?.is_sorted_flag(), IsSorted::Descending);
+        assert_eq!(out.column("x")?.is_sorted_flag(), IsSorted::Not);
+        Ok(())
+    };
+
+    let tmp_dir = tempfile::tempdir()?;
+    let path = tmp_dir.path().join("eager.parquet");
+    ParquetWriter::new(std::fs::File::create(&path)?).finish(&mut df)?;
+    check(&ParquetReader::new(std::fs::File::open(&path)?).finish()?)?;
+
+    let path = tmp_dir.path().join("sink.parquet");
+    df.clone()
+        .lazy()
+        .sink(
+            SinkDestination::File {
+                target: SinkTarget::Path(PlRefPath::try_from_path(&path)?),
+            },
+            FileWriteFormat::Parquet(std::sync::Arc::new(ParquetWriteOptions::default())),
+            UnifiedSinkArgs::default(),
+        )?
+        .collect()?;
+    check(&ParquetReader::new(std::fs::File::open(&path)?).finish()?)?;
+
+    let out = LazyFrame::scan_parquet(PlRefPath::try_from_path(&path)?, Default::default())?
+        .collect_with_engine(Engine::Streaming)?;
+    check(&out)
+}
+
+#[test]
+#[cfg(feature = "dtype-map")]
+fn test_map_roundtrip() -> PolarsResult<()> {
+    let mut df = crate::io::create_map_df()?;
+    assert!(matches!(df.column("m")?.dtype(), DataType::Map(_, _)));
+
+    let mut buf = Cursor::new(Vec::new());
+    ParquetWriter::new(&mut buf).finish(&mut df)?;
+    let read_df = ParquetReader::new(buf).finish()?;
+    assert_eq!(read_df.schema(), df.schema());
+    assert!(df.equals_missing(&read_df));
+    Ok(())
+}
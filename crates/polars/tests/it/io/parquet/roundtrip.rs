@@ -88,3 +88,37 @@ fn roundtrip_binview() -> PolarsResult<()> {
         ],
     )
 }
+
+#[test]
+fn roundtrip_sorting_columns() -> PolarsResult<()> {
+    use polars::prelude::*;
+    use polars_core::series::IsSorted;
+
+    let mut a = Series::new("a".into(), [1i64, 2, 3]);
+    a.set_sorted_flag(IsSorted::Ascending);
+    let b = Series::new("b".into(), [3i64, 1, 2]);
+    let mut c = Series::new("c".into(), [Some(3i32), Some(1), None]);
+    c.set_sorted_flag(IsSorted::Descending);
+
+    let mut df = DataFrame::new_infer_height(vec![a.into(), b.into(), c.into()])?;
+
+    let mut buf = Cursor::new(vec![]);
+    ParquetWriter::new(&mut buf).finish(&mut df)?;
+
+    let md = read_metadata(&mut buf).unwrap();
+    let sorting_columns = md.row_groups[0].sorting_columns().unwrap();
+    assert_eq!(
+        sorting_columns
+            .iter()
+            .map(|s| (s.column_idx, s.descending, s.nulls_first))
+            .collect::<Vec<_>>(),
+        [(0, false, false), (2, true, false)]
+    );
+
+    buf.set_position(0);
+    let out = ParquetReader::new(buf).finish()?;
+    assert!(out.equals_missing(&df));
+    assert_eq!(out.column("a")?.is_sorted_flag(), IsSorted::Ascending);
+
+    Ok(())
+}
@@ -92,3 +92,40 @@ fn test_out_of_range_date_year_11991() {
     let is_leap = s.is_leap_year().unwrap();
     assert_eq!(is_leap.get(0), None);
 }
+
+#[test]
+#[cfg(all(feature = "dtype-interval", feature = "dtype-date", feature = "lazy"))]
+fn test_date_interval_arithmetic() {
+    use chrono::NaiveDate;
+
+    let dates = [
+        NaiveDate::from_ymd_opt(2024, 1, 31).unwrap(),
+        NaiveDate::from_ymd_opt(2024, 2, 29).unwrap(),
+    ];
+    let df = df![
+        "date" => dates,
+        "interval" => ["1mo 1d", "-1y"],
+    ]
+    .unwrap()
+    .lazy()
+    .with_column(col("interval").cast(DataType::Interval))
+    .select([
+        (col("date") + col("interval")).alias("add"),
+        (col("date") - col("interval")).alias("sub"),
+    ])
+    .collect()
+    .unwrap();
+
+    let expected = df![
+        "add" => [
+            NaiveDate::from_ymd_opt(2024, 3, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2023, 2, 28).unwrap(),
+        ],
+        "sub" => [
+            NaiveDate::from_ymd_opt(2023, 12, 30).unwrap(),
+            NaiveDate::from_ymd_opt(2025, 2, 28).unwrap(),
+        ],
+    ]
+    .unwrap();
+    assert_eq!(df, expected);
+}
@@ -976,7 +976,7 @@ class PyLazyFrame:
         compression_level: int | None,
         check_extension: bool,
         include_header: bool,
-        separator: int,
+        separator: str,
         line_terminator: str,
         quote_char: int,
         batch_size: int,
@@ -3197,9 +3197,9 @@ class DataFrame:
         >>> path: pathlib.Path = dirpath / "new_file.csv"
         >>> df.write_csv(path, separator=",")
         """
-        from polars.io.csv._utils import _check_arg_is_1byte
+        from polars.io.csv._utils import _check_arg_is_1byte, _check_separator
 
-        _check_arg_is_1byte("separator", separator, can_be_empty=False)
+        _check_separator(separator)
         _check_arg_is_1byte("quote_char", quote_char, can_be_empty=True)
         if not null_value:
             null_value = None
@@ -28,6 +28,15 @@ def _check_arg_is_1byte(
             raise ValueError(msg)
 
 
+def _check_separator(separator: str) -> None:
+    if not separator:
+        msg = "separator should not be empty"
+        raise ValueError(msg)
+    if "\n" in separator:
+        msg = f"separator={separator!r} should not contain a newline"
+        raise ValueError(msg)
+
+
 def _update_columns(df: DataFrame, new_columns: Sequence[str]) -> DataFrame:
     if df.width > len(new_columns):
         cols = df.columns
@@ -32,7 +32,11 @@ from polars.io._utils import (
 from polars.io.cloud.credential_provider._builder import (
     _init_credential_provider_builder,
 )
-from polars.io.csv._utils import _check_arg_is_1byte, _update_columns
+from polars.io.csv._utils import (
+    _check_arg_is_1byte,
+    _check_separator,
+    _update_columns,
+)
 from polars.io.csv.batched_reader import BatchedCsvReader
 
 with contextlib.suppress(ImportError):  # Module not available when building docs
@@ -126,7 +130,7 @@ def read_csv(
         list is shorter than the width of the DataFrame the remaining
         columns will have their original name.
     separator
-        Single byte character to use as separator in the file.
+        Character or string to use as separator in the file.
     comment_prefix
         A string used to indicate the start of a comment line. Comment lines are skipped
         during parsing. Common examples of comment prefixes are `#` and `//`.
@@ -230,7 +234,9 @@ def read_csv(
     eol_char
         Single byte end of line character (default: `\n`). When encountering a file
         with windows line endings (`\r\n`), one can go with the default `\n`. The extra
-        `\r` will be removed when processed.
+        `\r` will be removed when processed. When set to `\r`, files mixing `\r` and
+        `\r\n` line endings can be read. Other multi-byte line terminators are not
+        supported.
     raise_if_empty
         When there is no data in the source, `NoDataError` is raised. If this parameter
         is set to False, an empty DataFrame (with no columns) is returned instead.
@@ -287,7 +293,7 @@ def read_csv(
     │ 3   ┆ Charlie ┆ 2002-03-08 │
     └─────┴─────────┴────────────┘
     """
-    _check_arg_is_1byte("separator", separator, can_be_empty=False)
+    _check_separator(separator)
     _check_arg_is_1byte("quote_char", quote_char, can_be_empty=True)
     _check_arg_is_1byte("eol_char", eol_char, can_be_empty=False)
 
@@ -816,7 +822,7 @@ def read_csv_batched(
         list is shorter than the width of the DataFrame the remaining
         columns will have their original name.
     separator
-        Single byte character to use as separator in the file.
+        Character or string to use as separator in the file.
     comment_prefix
         A string used to indicate the start of a comment line. Comment lines are skipped
         during parsing. Common examples of comment prefixes are `#` and `//`.
@@ -894,7 +900,9 @@ def read_csv_batched(
     eol_char
         Single byte end of line character (default: `\n`). When encountering a file
         with windows line endings (`\r\n`), one can go with the default `\n`. The extra
-        `\r` will be removed when processed.
+        `\r` will be removed when processed. When set to `\r`, files mixing `\r` and
+        `\r\n` line endings can be read. Other multi-byte line terminators are not
+        supported.
     raise_if_empty
         When there is no data in the source,`NoDataError` is raised. If this parameter
         is set to False, `None` will be returned from `next_batches(n)` instead.
@@ -1143,7 +1151,7 @@ def scan_csv(
         column names will be autogenerated in the following format: `column_x`, with
         `x` being an enumeration over every column in the dataset, starting at 1.
     separator
-        Single byte character to use as separator in the file.
+        Character or string to use as separator in the file.
     comment_prefix
         A string used to indicate the start of a comment line. Comment lines are skipped
         during parsing. Common examples of comment prefixes are `#` and `//`.
@@ -1361,7 +1369,7 @@ def scan_csv(
             else:
                 return new_columns  # type: ignore[return-value]
 
-    _check_arg_is_1byte("separator", separator, can_be_empty=False)
+    _check_separator(separator)
     _check_arg_is_1byte("quote_char", quote_char, can_be_empty=True)
 
     if isinstance(source, (str, Path)):
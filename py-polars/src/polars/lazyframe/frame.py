@@ -3776,9 +3776,9 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         --------
         PartitionBy
         """
-        from polars.io.csv._utils import _check_arg_is_1byte
+        from polars.io.csv._utils import _check_arg_is_1byte, _check_separator
 
-        _check_arg_is_1byte("separator", separator, can_be_empty=False)
+        _check_separator(separator)
         _check_arg_is_1byte("quote_char", quote_char, can_be_empty=False)
         if not null_value:
             null_value = None
@@ -3819,7 +3819,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             compression_level=compression_level,
             check_extension=check_extension,
             include_header=include_header,
-            separator=ord(separator),
+            separator=separator,
             line_terminator=line_terminator,
             quote_char=ord(quote_char),
             batch_size=batch_size,
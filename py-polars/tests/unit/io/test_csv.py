@@ -1026,6 +1026,21 @@ def test_write_csv_separator() -> None:
     assert_frame_equal(df, pl.read_csv(f, separator="\t"))
 
 
+def test_read_write_csv_multi_byte_separator() -> None:
+    df = pl.DataFrame({"a": [1, 2], "b": ["x|y", "z"]})
+    f = io.BytesIO()
+    df.write_csv(f, separator="~|~")
+    f.seek(0)
+    assert f.read() == b"a~|~b\n1~|~x|y\n2~|~z\n"
+    f.seek(0)
+    assert_frame_equal(df, pl.read_csv(f, separator="~|~"))
+    f.seek(0)
+    assert_frame_equal(df, pl.scan_csv(f, separator="~|~").collect())
+
+    with pytest.raises(ValueError, match="separator should not be empty"):
+        pl.read_csv(f, separator="")
+
+
 def test_write_csv_line_terminator() -> None:
     df = pl.DataFrame({"a": [1, 2, 3], "b": [1, 2, 3]})
     f = io.BytesIO()